@@ -408,6 +408,68 @@ impl<T: CoordNum> Polygon<T> {
         self.interiors.push(new_interior);
     }
 
+    /// Remove and return the interior ring at `index`, shifting the interior rings after it
+    /// down.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, Polygon};
+    ///
+    /// let mut polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.)]),
+    ///     vec![
+    ///         LineString::from(vec![(1., 1.), (1., 2.), (2., 2.)]),
+    ///         LineString::from(vec![(3., 3.), (3., 3.5), (3.5, 3.5)]),
+    ///     ],
+    /// );
+    ///
+    /// let removed = polygon.remove_interior(0);
+    ///
+    /// assert_eq!(removed, LineString::from(vec![(1., 1.), (1., 2.), (2., 2.), (1., 1.)]));
+    /// assert_eq!(
+    ///     polygon.interiors(),
+    ///     &[LineString::from(vec![(3., 3.), (3., 3.5), (3.5, 3.5), (3., 3.)])]
+    /// );
+    /// ```
+    pub fn remove_interior(&mut self, index: usize) -> LineString<T> {
+        self.interiors.remove(index)
+    }
+
+    /// Keep only the interior rings for which `f` returns `true`, in their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{LineString, Polygon};
+    ///
+    /// let mut polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.)]),
+    ///     vec![
+    ///         LineString::from(vec![(1., 1.), (1., 2.), (2., 2.)]),
+    ///         LineString::from(vec![(3., 3.), (3., 3.5), (3.5, 3.5)]),
+    ///     ],
+    /// );
+    ///
+    /// // Keep the holes which reach further than x = 3
+    /// polygon.retain_interiors(|interior| interior.coords().any(|c| c.x > 3.));
+    ///
+    /// assert_eq!(
+    ///     polygon.interiors(),
+    ///     &[LineString::from(vec![(3., 3.), (3., 3.5), (3.5, 3.5), (3., 3.)])]
+    /// );
+    /// ```
+    pub fn retain_interiors<F>(&mut self, f: F)
+    where
+        F: FnMut(&LineString<T>) -> bool,
+    {
+        self.interiors.retain(f);
+    }
+
     /// Wrap-around previous-vertex
     fn previous_vertex(&self, current_vertex: usize) -> usize
     where
@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{BoundingRect, Contains, ContainsPoints, Coord, Polygon, Rect, SegmentsInRect};
+
+fn grid(bounds: Rect, n: usize) -> Vec<Coord> {
+    let (width, height) = (bounds.width(), bounds.height());
+    (0..n * n)
+        .map(|i| Coord {
+            x: bounds.min().x + width * (i % n) as f64 / n as f64,
+            y: bounds.min().y + height * (i / n) as f64 / n as f64,
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let polygon = Polygon::<f64>::new(geo_test_fixtures::louisiana(), vec![]);
+    let bounds = polygon.bounding_rect().unwrap();
+    let coords = grid(bounds, 100);
+
+    c.bench_function(
+        "10,000 points in complex polygon, one at a time",
+        |bencher| {
+            bencher.iter(|| {
+                let polygon = criterion::black_box(&polygon);
+                criterion::black_box(&coords)
+                    .iter()
+                    .filter(|coord| polygon.contains(*coord))
+                    .count()
+            });
+        },
+    );
+
+    c.bench_function("10,000 points in complex polygon, batched", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(&polygon)
+                .contains_points(criterion::black_box(&coords))
+                .into_iter()
+                .filter(|inside| *inside)
+                .count()
+        });
+    });
+
+    c.bench_function("10,000 points in rect, batched", |bencher| {
+        let rect = Rect::new(bounds.center(), bounds.max());
+        bencher.iter(|| criterion::black_box(&rect).contains_points(criterion::black_box(&coords)));
+    });
+
+    c.bench_function("segments of complex linestring in rect", |bencher| {
+        let rect = Rect::new(bounds.center(), bounds.max());
+        bencher.iter(|| {
+            criterion::black_box(polygon.exterior()).segments_in_rect(criterion::black_box(&rect))
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -15,6 +15,18 @@ fn criterion_benchmark(c: &mut Criterion) {
             criterion::black_box(criterion::black_box(&ls).simplify(criterion::black_box(&0.01)));
         });
     });
+
+    c.bench_function("simplify prefiltered f64", |bencher| {
+        let ls = geo_test_fixtures::louisiana::<f64>();
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&ls).simplify_prefiltered(
+                    criterion::black_box(&0.002),
+                    criterion::black_box(&0.01),
+                ),
+            );
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,35 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::soa::SoaLineString;
+use geo::{BoundingRect, Centroid, EuclideanLength, LineString};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let line_string: LineString<f64> = geo_test_fixtures::norway_main();
+    let soa = SoaLineString::from(&line_string);
+
+    c.bench_function("length of LineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&line_string).euclidean_length());
+    });
+
+    c.bench_function("length of SoaLineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&soa).euclidean_length());
+    });
+
+    c.bench_function("bounding rect of LineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&line_string).bounding_rect());
+    });
+
+    c.bench_function("bounding rect of SoaLineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&soa).bounding_rect());
+    });
+
+    c.bench_function("centroid of LineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&line_string).centroid());
+    });
+
+    c.bench_function("centroid of SoaLineString", |bencher| {
+        bencher.iter(|| criterion::black_box(&soa).centroid());
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
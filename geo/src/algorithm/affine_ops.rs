@@ -4,7 +4,7 @@ use num_traits::ToPrimitive;
 use approx::{AbsDiffEq, RelativeEq};
 
 use crate::{Coord, CoordFloat, CoordNum, MapCoords, MapCoordsInPlace};
-use std::{fmt, ops::Mul, ops::Neg};
+use core::{fmt, ops::Mul, ops::Neg};
 
 /// Apply an [`AffineTransform`] like [`scale`](AffineTransform::scale),
 /// [`skew`](AffineTransform::skew), or [`rotate`](AffineTransform::rotate) to a
@@ -47,6 +47,13 @@ pub trait AffineOps<T: CoordNum> {
 
     /// Apply `transform` to mutate `self`.
     fn affine_transform_mut(&mut self, transform: &AffineTransform<T>);
+
+    /// Apply a projective `transform` immutably, outputting a new geometry.
+    #[must_use]
+    fn projective_transform(&self, transform: &ProjectiveTransform<T>) -> Self;
+
+    /// Apply a projective `transform` to mutate `self`.
+    fn projective_transform_mut(&mut self, transform: &ProjectiveTransform<T>);
 }
 
 impl<T: CoordNum, M: MapCoordsInPlace<T> + MapCoords<T, T, Output = Self>> AffineOps<T> for M {
@@ -57,6 +64,14 @@ impl<T: CoordNum, M: MapCoordsInPlace<T> + MapCoords<T, T, Output = Self>> Affin
     fn affine_transform_mut(&mut self, transform: &AffineTransform<T>) {
         self.map_coords_in_place(|c| transform.apply(c))
     }
+
+    fn projective_transform(&self, transform: &ProjectiveTransform<T>) -> Self {
+        self.map_coords(|c| transform.apply(c))
+    }
+
+    fn projective_transform_mut(&mut self, transform: &ProjectiveTransform<T>) {
+        self.map_coords_in_place(|c| transform.apply(c))
+    }
 }
 
 /// A general affine transformation matrix, and associated operations.
@@ -453,6 +468,234 @@ impl<U: CoordFloat> AffineTransform<U> {
     }
 }
 
+/// A projective transformation, or homography, and associated operations.
+///
+/// Projective transforms generalise [`AffineTransform`]s: they map straight lines to straight
+/// lines, but parallel lines may converge, as they do in a photograph taken at an angle. They're
+/// used to georeference scanned maps and aerial imagery, most often by
+/// [matching four control points](Self::from_control_points).
+///
+/// `ProjectiveTransform` is a row-major matrix:
+/// ```ignore
+/// [[a, b, c],
+/// [d, e, f],
+/// [g, h, i]]
+/// ```
+/// The equations for transforming coordinates `(x, y) -> (x', y')` are given as follows:
+///
+/// `x' = (ax + by + c) / (gx + hy + i)`
+///
+/// `y' = (dx + ey + f) / (gx + hy + i)`
+///
+/// Coordinates on the line `gx + hy + i = 0` are mapped to infinity, and have no finite image.
+///
+/// # Examples
+/// ```
+/// use geo::{AffineOps, ProjectiveTransform};
+/// use geo::{coord, polygon};
+/// use approx::assert_relative_eq;
+///
+/// // The corners of a map sheet, as pixels in a photograph of it
+/// let pixels = [
+///     coord! { x: 112., y: 940. },
+///     coord! { x: 1830., y: 1010. },
+///     coord! { x: 1705., y: 95. },
+///     coord! { x: 310., y: 160. },
+/// ];
+/// // and the same corners' known longitudes and latitudes
+/// let lon_lat = [
+///     coord! { x: -3.25, y: 55.9 },
+///     coord! { x: -3.0, y: 55.9 },
+///     coord! { x: -3.0, y: 56.0 },
+///     coord! { x: -3.25, y: 56.0 },
+/// ];
+///
+/// let transform = ProjectiveTransform::from_control_points(pixels, lon_lat).unwrap();
+/// let traced = polygon![
+///     (x: 112., y: 940.),
+///     (x: 1830., y: 1010.),
+///     (x: 1705., y: 95.),
+/// ];
+/// assert_relative_eq!(
+///     traced.projective_transform(&transform),
+///     polygon![
+///         (x: -3.25, y: 55.9),
+///         (x: -3.0, y: 55.9),
+///         (x: -3.0, y: 56.0),
+///     ],
+///     epsilon = 1e-12
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ProjectiveTransform<T: CoordNum = f64>([[T; 3]; 3]);
+
+impl<T: CoordNum> Default for ProjectiveTransform<T> {
+    fn default() -> Self {
+        // identity matrix
+        Self::identity()
+    }
+}
+
+impl<T: CoordNum> ProjectiveTransform<T> {
+    /// Create a new custom transform matrix, from its rows.
+    pub fn new(matrix: [[T; 3]; 3]) -> Self {
+        Self(matrix)
+    }
+
+    /// Create the identity matrix
+    pub fn identity() -> Self {
+        AffineTransform::identity().into()
+    }
+
+    /// Whether the transformation is equivalent to the [identity matrix](Self::identity),
+    /// that is, whether it's application will be a a no-op.
+    ///
+    /// Matrices which are multiples of each other describe the same transformation, so this
+    /// is `true` for any non-zero multiple of the identity matrix.
+    pub fn is_identity(&self) -> bool {
+        let scale = self.0[2][2];
+        scale != T::zero()
+            && self.0.iter().enumerate().all(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .all(|(j, value)| *value == if i == j { scale } else { T::zero() })
+            })
+    }
+
+    /// The rows of the transform matrix.
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        self.0
+    }
+
+    /// Create a new projective transformation by composing two `ProjectiveTransform`s.
+    ///
+    /// As with [`AffineTransform::compose`], the result is the matrix product of `self` and
+    /// `other`, which applies `other` first.
+    #[must_use]
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut matrix = [[T::zero(); 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).fold(T::zero(), |acc, k| acc + self.0[i][k] * other.0[k][j]);
+            }
+        }
+        Self(matrix)
+    }
+
+    /// Apply the current transform to a coordinate
+    pub fn apply(&self, coord: Coord<T>) -> Coord<T> {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+        let w = g * coord.x + h * coord.y + i;
+        Coord {
+            x: (a * coord.x + b * coord.y + c) / w,
+            y: (d * coord.x + e * coord.y + f) / w,
+        }
+    }
+}
+
+impl<T: CoordFloat> ProjectiveTransform<T> {
+    /// **Create** the projective transform which maps each of four `source` coordinates to the
+    /// corresponding `target` coordinate.
+    ///
+    /// Returns `None` if three of the `source` or three of the `target` coordinates are
+    /// collinear, since no such transform exists.
+    pub fn from_control_points(source: [Coord<T>; 4], target: [Coord<T>; 4]) -> Option<Self> {
+        let to_square = Self::from_unit_square(source)?.inverse()?;
+        Some(Self::from_unit_square(target)?.compose(&to_square))
+    }
+
+    /// The transform which maps the corners of the unit square, `(0, 0)`, `(1, 0)`, `(1, 1)`
+    /// and `(0, 1)`, to the given quadrilateral, following Heckbert (1989).
+    fn from_unit_square([p0, p1, p2, p3]: [Coord<T>; 4]) -> Option<Self> {
+        let d1 = p1 - p2;
+        let d2 = p3 - p2;
+        let d3 = p0 - p1 + p2 - p3;
+
+        let determinant = d1.x * d2.y - d2.x * d1.y;
+        if determinant == T::zero() {
+            return None;
+        }
+        let g = (d3.x * d2.y - d2.x * d3.y) / determinant;
+        let h = (d1.x * d3.y - d3.x * d1.y) / determinant;
+
+        let transform = Self([
+            [p1.x - p0.x + g * p1.x, p3.x - p0.x + h * p3.x, p0.x],
+            [p1.y - p0.y + g * p1.y, p3.y - p0.y + h * p3.y, p0.y],
+            [g, h, T::one()],
+        ]);
+        let [[a, b, _], [d, e, _], _] = transform.0;
+        // The corners' images are collinear if the square collapses
+        (a * e - b * d != T::zero()).then_some(transform)
+    }
+
+    /// Return the inverse of a given transform. Composing a transform with its inverse yields
+    /// the [identity matrix](Self::identity)
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.0;
+        let cofactors = [
+            [e * i - f * h, c * h - b * i, b * f - c * e],
+            [f * g - d * i, a * i - c * g, c * d - a * f],
+            [d * h - e * g, b * g - a * h, a * e - b * d],
+        ];
+        let determinant = a * cofactors[0][0] + b * cofactors[1][0] + c * cofactors[2][0];
+        if determinant == T::zero() || !determinant.is_finite() {
+            return None; // The matrix is not invertible
+        }
+        Some(Self(
+            cofactors.map(|row| row.map(|value| value / determinant)),
+        ))
+    }
+}
+
+impl<T: CoordNum> From<AffineTransform<T>> for ProjectiveTransform<T> {
+    fn from(transform: AffineTransform<T>) -> Self {
+        Self(transform.0)
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for ProjectiveTransform<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let mut mp_zipper = self.0.iter().flatten().zip(other.0.iter().flatten());
+        mp_zipper.all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> AbsDiffEq for ProjectiveTransform<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let mut mp_zipper = self.0.iter().flatten().zip(other.0.iter().flatten());
+        mp_zipper.all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
+    }
+}
+
 #[cfg(any(feature = "approx", test))]
 impl<T> RelativeEq for AffineTransform<T>
 where
@@ -526,7 +769,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{wkt, Point};
+    use crate::{coord, wkt, Point};
 
     // given a matrix with the shape
     // [[a, b, xoff],
@@ -585,4 +828,73 @@ mod tests {
         poly.affine_transform_mut(&identity);
         assert_eq!(expected, poly);
     }
+
+    #[test]
+    fn projective_from_control_points() {
+        let square = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 0., y: 1. },
+        ];
+        let trapezoid = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 4., y: 0. },
+            coord! { x: 3., y: 2. },
+            coord! { x: 1., y: 2. },
+        ];
+        let transform = ProjectiveTransform::from_control_points(square, trapezoid).unwrap();
+        for (source, target) in square.iter().zip(&trapezoid) {
+            assert_relative_eq!(transform.apply(*source), *target, epsilon = 1e-12);
+        }
+        // Straight lines stay straight, but midpoints move
+        let midpoint = transform.apply(coord! { x: 0.5, y: 0.5 });
+        assert_relative_eq!(midpoint, coord! { x: 2., y: 4. / 3. }, epsilon = 1e-12);
+
+        let back = ProjectiveTransform::from_control_points(trapezoid, square).unwrap();
+        assert_relative_eq!(
+            transform.compose(&back),
+            ProjectiveTransform::identity(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(transform.inverse().unwrap(), back, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn projective_degenerate_control_points() {
+        let square = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 0., y: 1. },
+        ];
+        let collinear = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+            coord! { x: 0., y: 1. },
+        ];
+        assert!(ProjectiveTransform::from_control_points(square, collinear).is_none());
+        assert!(ProjectiveTransform::from_control_points(collinear, square).is_none());
+    }
+
+    #[test]
+    fn projective_matches_affine() {
+        let affine = AffineTransform::rotate(30.0, (1.0, 2.0)).scaled(2.0, 3.0, (0.0, 0.0));
+        let projective = ProjectiveTransform::from(affine);
+        assert!(!projective.is_identity());
+
+        let poly = wkt! { POLYGON((0.0 0.0,0.0 2.0,1.0 2.0)) };
+        assert_relative_eq!(
+            poly.projective_transform(&projective),
+            poly.affine_transform(&affine),
+            epsilon = 1e-12
+        );
+
+        let mut poly_mut = poly.clone();
+        poly_mut.projective_transform_mut(&projective);
+        poly_mut.projective_transform_mut(&projective.inverse().unwrap());
+        assert_relative_eq!(poly_mut, poly, epsilon = 1e-12);
+        assert!(ProjectiveTransform::new([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]).is_identity());
+    }
 }
@@ -0,0 +1,215 @@
+//! Normalize and compare angles and bearings, and measure the angles at vertices.
+//!
+//! Angles are in degrees, as for [`Bearings`](crate::Bearings). Angles between directions are
+//! positive counter-clockwise, as in the plane, whereas bearings are clockwise from north, or
+//! from the positive `y` axis, as on a map.
+//!
+//! # Examples
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use geo::{coord, line_string};
+//! use geo::angles::{angle_difference, interior_angles, normalize_bearing, signed_angle};
+//!
+//! assert_eq!(normalize_bearing(-90.), 270.);
+//! // The short way round, through north
+//! assert_eq!(angle_difference(350., 10.), 20.);
+//!
+//! let corner = coord! { x: 0., y: 0. };
+//! let (east, north) = (coord! { x: 1., y: 0. }, coord! { x: 0., y: 1. });
+//! assert_relative_eq!(signed_angle(east, corner, north), 90.);
+//!
+//! // An L-shaped ring, with a reflex corner at (1, 1)
+//! let ring = line_string![
+//!     (x: 0., y: 0.),
+//!     (x: 2., y: 0.),
+//!     (x: 2., y: 1.),
+//!     (x: 1., y: 1.),
+//!     (x: 1., y: 2.),
+//!     (x: 0., y: 2.),
+//!     (x: 0., y: 0.),
+//! ];
+//! let angles = interior_angles(&ring);
+//! assert_relative_eq!(angles.as_slice(), [90., 90., 90., 270., 90., 90.].as_slice());
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{Coord, CoordFloat, LineString};
+
+/// `angle`, in degrees, in the range `(-180, 180]`.
+pub fn normalize_angle<T: CoordFloat>(angle: T) -> T {
+    let (half_turn, full_turn) = (T::from(180).unwrap(), T::from(360).unwrap());
+    let angle = angle % full_turn;
+    if angle > half_turn {
+        angle - full_turn
+    } else if angle <= -half_turn {
+        angle + full_turn
+    } else {
+        angle
+    }
+}
+
+/// `bearing`, in degrees, in the range `[0, 360)`.
+pub fn normalize_bearing<T: CoordFloat>(bearing: T) -> T {
+    let full_turn = T::from(360).unwrap();
+    let bearing = bearing % full_turn;
+    if bearing >= T::zero() {
+        bearing
+    } else {
+        // A tiny negative bearing rounds up to a full turn
+        let bearing = bearing + full_turn;
+        if bearing < full_turn {
+            bearing
+        } else {
+            T::zero()
+        }
+    }
+}
+
+/// `longitude`, in degrees, in the range `[-180, 180)`.
+pub fn normalize_longitude<T: CoordFloat>(longitude: T) -> T {
+    -normalize_angle(-longitude)
+}
+
+/// The angle, in degrees, to turn from the direction `from` to the direction `to` the short
+/// way round, in the range `(-180, 180]`: positive if `to` is counter-clockwise of `from`, for
+/// angles, or clockwise, for bearings.
+pub fn angle_difference<T: CoordFloat>(from: T, to: T) -> T {
+    normalize_angle(to - from)
+}
+
+/// The angle, in degrees, at `vertex` from the direction of `a` to the direction of `b`, in the
+/// range `(-180, 180]`: positive if `b` is counter-clockwise of `a`. It's zero if `a` or `b`
+/// is at `vertex`.
+pub fn signed_angle<T: CoordFloat>(a: Coord<T>, vertex: Coord<T>, b: Coord<T>) -> T {
+    let (a, b) = (a - vertex, b - vertex);
+    let cross = a.x * b.y - a.y * b.x;
+    let dot = a.x * b.x + a.y * b.y;
+    normalize_angle(cross.atan2(dot).to_degrees())
+}
+
+/// The angle, in degrees, by which a line from `a` through `vertex` to `b` turns at `vertex`,
+/// in the range `(-180, 180]`: positive if it turns counter-clockwise. It's zero if `a` or `b`
+/// is at `vertex`.
+pub fn turn_angle<T: CoordFloat>(a: Coord<T>, vertex: Coord<T>, b: Coord<T>) -> T {
+    let (u, v) = (vertex - a, b - vertex);
+    let cross = u.x * v.y - u.y * v.x;
+    let dot = u.x * v.x + u.y * v.y;
+    normalize_angle(cross.atan2(dot).to_degrees())
+}
+
+/// The interior angle, in degrees, at each vertex of `ring`, in order from its first vertex,
+/// in the range `[0, 360)`. Angles over 180 are at reflex vertices.
+///
+/// The interior is on the left of a counter-clockwise ring, and on the right of a clockwise
+/// one, so the angles don't depend on the orientation of the ring, and add up to `180 (n - 2)`
+/// for a simple ring of `n` vertices. Repeated vertices count once, and the ring needn't be
+/// closed.
+pub fn interior_angles<T: CoordFloat>(ring: &LineString<T>) -> Vec<T> {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    let n = coords.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let twice_area = (0..n).fold(T::zero(), |sum, i| {
+        let (a, b) = (coords[i], coords[(i + 1) % n]);
+        sum + a.x * b.y - b.x * a.y
+    });
+    (0..n)
+        .map(|i| {
+            let (previous, vertex, next) =
+                (coords[(i + n - 1) % n], coords[i], coords[(i + 1) % n]);
+            // Counter-clockwise from the next vertex round to the previous one, through the
+            // interior
+            let angle = if twice_area >= T::zero() {
+                signed_angle(next, vertex, previous)
+            } else {
+                signed_angle(previous, vertex, next)
+            };
+            normalize_bearing(angle)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string};
+
+    #[test]
+    fn normalizing() {
+        assert_eq!(normalize_angle(180.), 180.);
+        assert_eq!(normalize_angle(-180.), 180.);
+        assert_eq!(normalize_angle(540.), 180.);
+        assert_eq!(normalize_angle(-190.), 170.);
+        assert_eq!(normalize_angle(725.), 5.);
+        assert_eq!(normalize_longitude(180.), -180.);
+        assert_eq!(normalize_longitude(-180.), -180.);
+        assert_eq!(normalize_longitude(190.), -170.);
+        assert_eq!(normalize_bearing(360.), 0.);
+        assert_eq!(normalize_bearing(-450.), 270.);
+        assert_eq!(normalize_bearing(-1e-15), 0.);
+        assert!(normalize_bearing(-1e-15_f64) < 360.);
+        assert!(normalize_angle(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn differences() {
+        assert_eq!(angle_difference(10., 350.), -20.);
+        assert_eq!(angle_difference(350., 10.), 20.);
+        assert_eq!(angle_difference(0., 180.), 180.);
+        assert_eq!(angle_difference(180., 0.), 180.);
+        assert_eq!(angle_difference(-170., 170.), -20.);
+    }
+
+    #[test]
+    fn signed_angles() {
+        let origin = coord! { x: 0., y: 0. };
+        let east = coord! { x: 2., y: 0. };
+        let north = coord! { x: 0., y: 3. };
+        assert_relative_eq!(signed_angle(east, origin, north), 90.);
+        assert_relative_eq!(signed_angle(north, origin, east), -90.);
+        assert_relative_eq!(signed_angle(east, origin, coord! { x: -1., y: 0. }), 180.);
+        assert_relative_eq!(signed_angle(east, origin, coord! { x: 1., y: -1. }), -45.);
+        assert_eq!(signed_angle(east, origin, origin), 0.);
+
+        assert_relative_eq!(turn_angle(origin, east, coord! { x: 2., y: 1. }), 90.);
+        assert_relative_eq!(turn_angle(north, origin, east), 90.);
+        assert_relative_eq!(turn_angle(origin, east, coord! { x: 4., y: -2. }), -45.);
+        assert_eq!(turn_angle(origin, east, east), 0.);
+    }
+
+    #[test]
+    fn interior_angles_of_rings() {
+        let triangle = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.), (x: 0., y: 0.)];
+        let angles = interior_angles(&triangle);
+        assert_relative_eq!(angles.as_slice(), [90., 45., 45.].as_slice());
+        // Either way round
+        let mut reversed = triangle.clone();
+        reversed.0.reverse();
+        let angles = interior_angles(&reversed);
+        assert_relative_eq!(angles.as_slice(), [90., 45., 45.].as_slice());
+
+        // A star, with repeated vertices, not closed
+        let star: LineString = (0..10)
+            .flat_map(|i| {
+                let angle = (i as f64 * 36.).to_radians();
+                let radius = if i % 2 == 0 { 2. } else { 1. };
+                let coord = coord! { x: radius * angle.cos(), y: radius * angle.sin() };
+                [coord, coord]
+            })
+            .collect();
+        let angles = interior_angles(&star);
+        assert_eq!(angles.len(), 10);
+        assert_relative_eq!(angles.iter().sum::<f64>(), 8. * 180., epsilon = 1e-9);
+        assert!(angles.iter().step_by(2).all(|angle| *angle < 180.));
+        assert!(angles.iter().skip(1).step_by(2).all(|angle| *angle > 180.));
+
+        assert!(interior_angles(&line_string![(x: 0., y: 0.), (x: 1., y: 1.)]).is_empty());
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{
+    coord, BooleanOps, BoundingRect, Coord, GeoFloat, LineString, MapCoords, MultiLineString,
+    MultiPolygon, Polygon, Rect,
+};
+use alloc::{vec, vec::Vec};
+
+/// Split geometries which cross the antimeridian (the ±180° meridian) into valid multi-part
+/// geometries, and merge them back together in a continuous frame.
+///
+/// Coordinates are longitude (`x`) and latitude (`y`) in degrees. A segment is taken to cross the
+/// antimeridian when the longitudes of its ends differ by more than 180°, i.e. when the shorter
+/// way between them is across the antimeridian, unless both of its ends are at the same pole.
+/// The latitude at which a segment crosses is interpolated linearly in longitude and latitude.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Antimeridian};
+///
+/// let flight = line_string![(x: 170., y: 10.), (x: -170., y: 20.)];
+/// assert!(flight.crosses_antimeridian());
+///
+/// let split = flight.split_antimeridian();
+/// assert_eq!(split.0.len(), 2);
+/// assert_eq!(split.0[0], line_string![(x: 170., y: 10.), (x: 180., y: 15.)]);
+/// assert_eq!(split.0[1], line_string![(x: -180., y: 15.), (x: -170., y: 20.)]);
+///
+/// let merged = split.merge_antimeridian();
+/// assert_eq!(merged.0.len(), 1);
+/// assert_eq!(
+///     merged.0[0],
+///     line_string![(x: 170., y: 10.), (x: 180., y: 15.), (x: 190., y: 20.)]
+/// );
+/// ```
+pub trait Antimeridian<T: GeoFloat> {
+    type Output;
+
+    /// Whether any segment of `self` crosses the antimeridian.
+    fn crosses_antimeridian(&self) -> bool;
+
+    /// Split `self` along the antimeridian, so that no part of the result crosses it.
+    ///
+    /// Polygons which enclose a pole, i.e. whose exterior crosses the antimeridian an odd number
+    /// of times, are closed along the parallel of that pole. The enclosed pole is taken to be the
+    /// one whose hemisphere contains most of the exterior.
+    fn split_antimeridian(&self) -> Self::Output;
+
+    /// Shift `self` into a continuous frame where longitudes range from 0° to 360°, so that
+    /// parts which were split along the antimeridian meet again, and join them.
+    ///
+    /// Geometries which cross the prime meridian aren't continuous in this frame.
+    fn merge_antimeridian(&self) -> Self::Output;
+}
+
+impl<T: GeoFloat> Antimeridian<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn crosses_antimeridian(&self) -> bool {
+        self.0.windows(2).any(|pair| crosses(pair[0], pair[1]))
+    }
+
+    fn split_antimeridian(&self) -> Self::Output {
+        MultiLineString::new(split_line_string(self))
+    }
+
+    fn merge_antimeridian(&self) -> Self::Output {
+        MultiLineString::new(vec![self.map_coords(shift_east)])
+    }
+}
+
+impl<T: GeoFloat> Antimeridian<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn crosses_antimeridian(&self) -> bool {
+        self.iter()
+            .any(|line_string| line_string.crosses_antimeridian())
+    }
+
+    fn split_antimeridian(&self) -> Self::Output {
+        MultiLineString::new(self.iter().flat_map(split_line_string).collect())
+    }
+
+    /// Consecutive parts are joined if the end of one is the start of the next.
+    fn merge_antimeridian(&self) -> Self::Output {
+        let mut merged: Vec<LineString<T>> = vec![];
+        for line_string in self {
+            let shifted = line_string.map_coords(shift_east);
+            match merged.last_mut() {
+                Some(last) if last.0.last().is_some() && last.0.last() == shifted.0.first() => {
+                    last.0.extend(shifted.0.into_iter().skip(1))
+                }
+                _ => merged.push(shifted),
+            }
+        }
+        MultiLineString::new(merged)
+    }
+}
+
+impl<T: GeoFloat> Antimeridian<T> for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn crosses_antimeridian(&self) -> bool {
+        self.exterior().crosses_antimeridian()
+            || self
+                .interiors()
+                .iter()
+                .any(|interior| interior.crosses_antimeridian())
+    }
+
+    fn split_antimeridian(&self) -> Self::Output {
+        if !self.crosses_antimeridian() {
+            return MultiPolygon::new(vec![self.clone()]);
+        }
+        split_polygon(self)
+    }
+
+    fn merge_antimeridian(&self) -> Self::Output {
+        MultiPolygon::new(vec![self.map_coords(shift_east)])
+    }
+}
+
+impl<T: GeoFloat> Antimeridian<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn crosses_antimeridian(&self) -> bool {
+        self.iter().any(|polygon| polygon.crosses_antimeridian())
+    }
+
+    fn split_antimeridian(&self) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .flat_map(|polygon| polygon.split_antimeridian())
+                .collect(),
+        )
+    }
+
+    /// Polygons which touch or overlap in the shifted frame are unioned.
+    fn merge_antimeridian(&self) -> Self::Output {
+        self.iter()
+            .map(|polygon| MultiPolygon::new(vec![polygon.map_coords(shift_east)]))
+            .fold(MultiPolygon::new(vec![]), |merged, polygon| {
+                merged.union(&polygon)
+            })
+    }
+}
+
+fn half_turn<T: GeoFloat>() -> T {
+    T::from(180).unwrap()
+}
+
+fn full_turn<T: GeoFloat>() -> T {
+    T::from(360).unwrap()
+}
+
+/// Whether the segment from `start` to `end` crosses the antimeridian. Segments along a pole,
+/// such as those closing a split polygon which encloses it, are a single point on the sphere
+/// and don't cross it.
+fn crosses<T: GeoFloat>(start: Coord<T>, end: Coord<T>) -> bool {
+    let pole = T::from(90).unwrap();
+    (end.x - start.x).abs() > half_turn() && !(start.y == end.y && start.y.abs() == pole)
+}
+
+fn shift_east<T: GeoFloat>(coord: Coord<T>) -> Coord<T> {
+    if coord.x < T::zero() {
+        coord! { x: coord.x + full_turn(), y: coord.y }
+    } else {
+        coord
+    }
+}
+
+fn split_line_string<T: GeoFloat>(line_string: &LineString<T>) -> Vec<LineString<T>> {
+    let mut parts = vec![];
+    let mut current: Vec<Coord<T>> = vec![];
+    for &coord in line_string {
+        if let Some(&previous) = current.last() {
+            if crosses(previous, coord) {
+                let (edge, unwrapped) = if previous.x > T::zero() {
+                    (half_turn(), coord.x + full_turn())
+                } else {
+                    (-half_turn::<T>(), coord.x - full_turn())
+                };
+                let ratio = (edge - previous.x) / (unwrapped - previous.x);
+                let y = previous.y + (coord.y - previous.y) * ratio;
+                if previous.x != edge {
+                    current.push(coord! { x: edge, y: y });
+                }
+                // A part which only touches the antimeridian is dropped.
+                if current.len() > 1 {
+                    parts.push(LineString::new(core::mem::take(&mut current)));
+                } else {
+                    current.clear();
+                }
+                if coord.x != -edge {
+                    current.push(coord! { x: -edge, y: y });
+                }
+            }
+        }
+        current.push(coord);
+    }
+    if current.len() > 1 {
+        parts.push(LineString::new(current));
+    }
+    parts
+}
+
+/// Make the longitudes of `coords` continuous, by shifting each coordinate by a multiple of
+/// 360° so that it's within 180° of the previous one.
+fn unwrap_longitudes<T: GeoFloat>(coords: &[Coord<T>]) -> Vec<Coord<T>> {
+    let mut offset = T::zero();
+    let mut unwrapped: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for (index, &coord) in coords.iter().enumerate() {
+        if index > 0 {
+            let previous = coords[index - 1];
+            if crosses(previous, coord) {
+                if previous.x > coord.x {
+                    offset = offset + full_turn();
+                } else {
+                    offset = offset - full_turn();
+                }
+            }
+        }
+        unwrapped.push(coord! { x: coord.x + offset, y: coord.y });
+    }
+    unwrapped
+}
+
+fn split_polygon<T: GeoFloat>(polygon: &Polygon<T>) -> MultiPolygon<T> {
+    let mut exterior = unwrap_longitudes(&polygon.exterior().0);
+    if let (Some(&first), Some(&last)) = (exterior.first(), exterior.last()) {
+        if first.x != last.x {
+            // The exterior winds around a pole; close it along the pole's parallel.
+            let latitude_sum = exterior.iter().fold(T::zero(), |sum, coord| sum + coord.y);
+            let pole = if latitude_sum >= T::zero() {
+                T::from(90).unwrap()
+            } else {
+                T::from(-90).unwrap()
+            };
+            exterior.push(coord! { x: last.x, y: pole });
+            exterior.push(coord! { x: first.x, y: pole });
+            exterior.push(first);
+        }
+    }
+    let exterior = LineString::new(exterior);
+    let bounds = match exterior.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return MultiPolygon::new(vec![]),
+    };
+
+    // Move each interior into the same 360° window as the exterior.
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|interior| {
+            let mut unwrapped = unwrap_longitudes(&interior.0);
+            if let Some(first) = unwrapped.first() {
+                let turns = ((first.x - bounds.min().x) / full_turn()).floor();
+                if turns != T::zero() {
+                    for coord in &mut unwrapped {
+                        coord.x = coord.x - turns * full_turn();
+                    }
+                }
+            }
+            LineString::new(unwrapped)
+        })
+        .collect();
+    let unwrapped = Polygon::new(exterior, interiors);
+
+    // Clip the unwrapped polygon to each 360° band it overlaps, and shift that part back.
+    let band_index = |x: T| ((x + half_turn()) / full_turn()).floor().to_i64().unwrap();
+    let margin = T::one();
+    let mut parts = vec![];
+    for band in band_index(bounds.min().x)..=band_index(bounds.max().x) {
+        let offset = T::from(band).unwrap() * full_turn();
+        let band = Rect::new(
+            coord! { x: offset - half_turn(), y: bounds.min().y - margin },
+            coord! { x: offset + half_turn(), y: bounds.max().y + margin },
+        )
+        .to_polygon();
+        for part in unwrapped.intersection(&band) {
+            parts.push(part.map_coords(|coord| coord! { x: coord.x - offset, y: coord.y }));
+        }
+    }
+    MultiPolygon::new(parts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area, Contains};
+
+    #[test]
+    fn line_string_not_crossing() {
+        let line_string = line_string![(x: -170., y: 0.), (x: 0., y: 10.), (x: 170., y: 0.)];
+        assert!(!line_string.crosses_antimeridian());
+        assert_eq!(
+            line_string.split_antimeridian(),
+            MultiLineString::new(vec![line_string])
+        );
+    }
+
+    #[test]
+    fn line_string_crossing_repeatedly() {
+        let line_string = line_string![
+            (x: -175., y: 0.),
+            (x: 175., y: 10.),
+            (x: 180., y: 20.),
+            (x: -170., y: 30.),
+        ];
+        assert!(line_string.crosses_antimeridian());
+        let split = line_string.split_antimeridian();
+        assert_eq!(
+            split,
+            MultiLineString::new(vec![
+                line_string![(x: -175., y: 0.), (x: -180., y: 5.)],
+                line_string![(x: 180., y: 5.), (x: 175., y: 10.), (x: 180., y: 20.)],
+                line_string![(x: -180., y: 20.), (x: -170., y: 30.)],
+            ])
+        );
+        assert!(!split.crosses_antimeridian());
+
+        let merged = split.merge_antimeridian();
+        assert_eq!(
+            merged,
+            MultiLineString::new(vec![line_string![
+                (x: 185., y: 0.),
+                (x: 180., y: 5.),
+                (x: 175., y: 10.),
+                (x: 180., y: 20.),
+                (x: 190., y: 30.),
+            ]])
+        );
+    }
+
+    #[test]
+    fn polygon_crossing() {
+        let polygon = polygon![
+            (x: 170., y: -10.),
+            (x: -170., y: -10.),
+            (x: -170., y: 10.),
+            (x: 170., y: 10.),
+        ];
+        assert!(polygon.crosses_antimeridian());
+        let split = polygon.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        assert!(!split.crosses_antimeridian());
+        assert_relative_eq!(split.unsigned_area(), 400.);
+        assert!(split.contains(&point!(x: 175., y: 0.)));
+        assert!(split.contains(&point!(x: -175., y: 0.)));
+        assert!(!split.contains(&point!(x: 0., y: 0.)));
+
+        let merged = split.merge_antimeridian();
+        assert_eq!(merged.0.len(), 1);
+        assert_relative_eq!(merged.unsigned_area(), 400.);
+        assert!(merged.contains(&point!(x: 180., y: 0.)));
+    }
+
+    #[test]
+    fn polygon_with_crossing_interior() {
+        let polygon = polygon![
+            exterior: [
+                (x: 160., y: -10.),
+                (x: -160., y: -10.),
+                (x: -160., y: 10.),
+                (x: 160., y: 10.),
+            ],
+            interiors: [[
+                (x: -175., y: -5.),
+                (x: -175., y: 5.),
+                (x: 175., y: 5.),
+                (x: 175., y: -5.),
+            ]],
+        ];
+        let split = polygon.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        assert_relative_eq!(split.unsigned_area(), 800. - 100.);
+        assert!(!split.contains(&point!(x: 179., y: 0.)));
+        assert!(split.contains(&point!(x: 170., y: 0.)));
+    }
+
+    #[test]
+    fn polygon_enclosing_pole() {
+        let polygon = polygon![
+            (x: -120., y: 80.),
+            (x: 0., y: 80.),
+            (x: 120., y: 80.),
+        ];
+        assert!(polygon.crosses_antimeridian());
+        let split = polygon.split_antimeridian();
+        assert!(!split.crosses_antimeridian());
+        assert_relative_eq!(split.unsigned_area(), 3600., epsilon = 1e-9);
+        assert!(split.contains(&point!(x: 179., y: 85.)));
+        assert!(split.contains(&point!(x: -179., y: 85.)));
+        assert!(!split.contains(&point!(x: 0., y: 70.)));
+    }
+}
@@ -1,19 +1,22 @@
 use crate::geometry::*;
+use crate::soa::{SoaLineString, SoaMultiPoint};
+use crate::view::{LineStringSlice, PolygonRef};
 use crate::{CoordFloat, CoordNum};
+use alloc::vec::Vec;
 
-pub(crate) fn twice_signed_ring_area<T>(linestring: &LineString<T>) -> T
+pub(crate) fn twice_signed_ring_area<T>(ring: &[Coord<T>]) -> T
 where
     T: CoordNum,
 {
     // LineString with less than 3 points is empty, or a
     // single point, or is not closed.
-    if linestring.0.len() < 3 {
+    if ring.len() < 3 {
         return T::zero();
     }
 
     // Above test ensures the vector has at least 2 elements.
     // We check if linestring is closed, and return 0 otherwise.
-    if linestring.0.first().unwrap() != linestring.0.last().unwrap() {
+    if ring.first().unwrap() != ring.last().unwrap() {
         return T::zero();
     }
 
@@ -27,12 +30,11 @@ where
     // of the coordinates, but it is not fool-proof to
     // divide by the length of the linestring (eg. a long
     // line-string with T = u8)
-    let shift = linestring.0[0];
+    let shift = ring[0];
 
     let mut tmp = T::zero();
-    for line in linestring.lines() {
-        use crate::MapCoords;
-        let line = line.map_coords(|c| c - shift);
+    for coords in ring.windows(2) {
+        let line = Line::new(coords[0] - shift, coords[1] - shift);
         tmp = tmp + line.determinant();
     }
 
@@ -75,11 +77,36 @@ where
 }
 
 // Calculation of simple (no interior holes) Polygon area
-pub(crate) fn get_linestring_area<T>(linestring: &LineString<T>) -> T
+pub(crate) fn get_linestring_area<T>(ring: &[Coord<T>]) -> T
 where
     T: CoordFloat,
 {
-    twice_signed_ring_area(linestring) / (T::one() + T::one())
+    twice_signed_ring_area(ring) / (T::one() + T::one())
+}
+
+// Calculation of the area of a Polygon with the given rings
+fn get_polygon_area<'a, T>(
+    exterior: &[Coord<T>],
+    interiors: impl Iterator<Item = &'a [Coord<T>]>,
+) -> T
+where
+    T: CoordFloat + 'a,
+{
+    let area = get_linestring_area(exterior);
+
+    // We could use winding order here, but that would
+    // result in computing the shoelace formula twice.
+    let is_negative = area < T::zero();
+
+    let area = interiors.fold(area.abs(), |total, next| {
+        total - get_linestring_area(next).abs()
+    });
+
+    if is_negative {
+        -area
+    } else {
+        area
+    }
 }
 
 impl<T> Area<T> for Point<T>
@@ -129,21 +156,41 @@ where
     T: CoordFloat,
 {
     fn signed_area(&self) -> T {
-        let area = get_linestring_area(self.exterior());
+        get_polygon_area(
+            &self.exterior().0,
+            self.interiors().iter().map(|interior| &interior.0[..]),
+        )
+    }
 
-        // We could use winding order here, but that would
-        // result in computing the shoelace formula twice.
-        let is_negative = area < T::zero();
+    fn unsigned_area(&self) -> T {
+        self.signed_area().abs()
+    }
+}
 
-        let area = self.interiors().iter().fold(area.abs(), |total, next| {
-            total - get_linestring_area(next).abs()
-        });
+impl<T> Area<T> for LineStringSlice<'_, T>
+where
+    T: CoordNum,
+{
+    fn signed_area(&self) -> T {
+        T::zero()
+    }
 
-        if is_negative {
-            -area
-        } else {
-            area
-        }
+    fn unsigned_area(&self) -> T {
+        T::zero()
+    }
+}
+
+/// **Note.** As for `Polygon`, the holes need not all have
+/// the same orientation.
+impl<T> Area<T> for PolygonRef<'_, T>
+where
+    T: CoordFloat,
+{
+    fn signed_area(&self) -> T {
+        get_polygon_area(
+            self.exterior().0,
+            self.interiors().iter().map(|interior| interior.0),
+        )
     }
 
     fn unsigned_area(&self) -> T {
@@ -151,6 +198,26 @@ where
     }
 }
 
+macro_rules! zero_impl {
+    ($type:ident) => {
+        impl<T> Area<T> for $type<T>
+        where
+            T: CoordNum,
+        {
+            fn signed_area(&self) -> T {
+                T::zero()
+            }
+
+            fn unsigned_area(&self) -> T {
+                T::zero()
+            }
+        }
+    };
+}
+
+zero_impl!(SoaLineString);
+zero_impl!(SoaMultiPoint);
+
 impl<T> Area<T> for MultiPoint<T>
 where
     T: CoordNum,
@@ -259,10 +326,70 @@ where
     }
 }
 
+/// The signed planar area of each ring of a polygonal geometry, which is positive for a ring
+/// wound counter-clockwise, and negative for one wound clockwise.
+///
+/// Unlike [`Area`], which gives the area of the polygon, with those of its holes subtracted,
+/// this gives the area enclosed by each ring separately, with its own orientation, such as to
+/// filter holes by size, or check the winding of each ring.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, RingAreas};
+///
+/// let donut = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.)]],
+/// ];
+///
+/// // The exterior, and then the interiors
+/// assert_eq!(donut.ring_signed_areas(), [100., -4.]);
+/// assert_eq!(donut.signed_area(), 96.);
+/// ```
+pub trait RingAreas<T>
+where
+    T: CoordNum,
+{
+    type Output;
+
+    /// The signed area enclosed by each ring: for a `Polygon`, that of its exterior, and then
+    /// those of its interiors, in order, and for a `MultiPolygon`, those of each of its
+    /// polygons.
+    fn ring_signed_areas(&self) -> Self::Output;
+}
+
+impl<T> RingAreas<T> for Polygon<T>
+where
+    T: CoordFloat,
+{
+    type Output = Vec<T>;
+
+    fn ring_signed_areas(&self) -> Vec<T> {
+        core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(|ring| get_linestring_area(&ring.0))
+            .collect()
+    }
+}
+
+impl<T> RingAreas<T> for MultiPolygon<T>
+where
+    T: CoordFloat,
+{
+    type Output = Vec<Vec<T>>;
+
+    fn ring_signed_areas(&self) -> Vec<Vec<T>> {
+        self.iter()
+            .map(|polygon| polygon.ring_signed_areas())
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::Area;
-    use crate::{coord, polygon, wkt, Line, MultiPolygon, Polygon, Rect, Triangle};
+    use crate::{coord, polygon, wkt, Line, LineString, MultiPolygon, Polygon, Rect, Triangle};
+    use crate::{Area, RingAreas};
 
     // Area of the polygon
     #[test]
@@ -284,7 +411,7 @@ mod test {
     #[test]
     fn area_polygon_numerical_stability() {
         let polygon = {
-            use std::f64::consts::PI;
+            use core::f64::consts::PI;
             const NUM_VERTICES: usize = 10;
             const ANGLE_INC: f64 = 2. * PI / NUM_VERTICES as f64;
 
@@ -526,4 +653,21 @@ mod test {
             max_relative = 0.0001
         );
     }
+
+    #[test]
+    fn ring_signed_areas_test() {
+        // Wound the wrong way: a clockwise exterior, and counter-clockwise interiors
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0., y: 6.), (x: 5., y: 6.), (x: 5., y: 0.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 5.), (x: 3., y: 5.)],
+            ],
+        ];
+        assert_eq!(polygon.ring_signed_areas(), [-30., 1., 2.]);
+        assert_eq!(polygon.signed_area(), -27.);
+
+        let multi = MultiPolygon::new(vec![polygon, Polygon::new(LineString::new(vec![]), vec![])]);
+        assert_eq!(multi.ring_signed_areas(), [vec![-30., 1., 2.], vec![0.]]);
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{coord, BoundingRect, Coord, Ellipsoid, MapCoords, Point};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+
+/// A local azimuthal equidistant projection, for metric operations on small geometries whose
+/// coordinates are longitude (`x`) and latitude (`y`) in degrees.
+///
+/// Projected coordinates are in meters east (`x`) and north (`y`) of the projection's origin.
+/// Distances and bearings from the origin are exact, and distortion elsewhere grows slowly with
+/// the distance from it: it's a few parts per million within 10km, and about 0.1% at 100km. This
+/// makes it possible to buffer, measure or simplify small features with planar algorithms, and
+/// then convert the result back to longitude and latitude, without depending on PROJ.
+///
+/// Geodesics are calculated with the methods given by [Karney (2013)].
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, AzimuthalEquidistant, GeodesicArea};
+///
+/// let field = polygon![
+///     (x: 4.8990, y: 52.3700),
+///     (x: 4.9010, y: 52.3700),
+///     (x: 4.9010, y: 52.3712),
+///     (x: 4.8990, y: 52.3712),
+/// ];
+///
+/// let projection = AzimuthalEquidistant::around(&field).unwrap();
+/// let projected = projection.project(&field);
+/// // About 136m by 134m
+/// assert_relative_eq!(
+///     projected.unsigned_area(),
+///     field.geodesic_area_unsigned(),
+///     max_relative = 1e-6
+/// );
+///
+/// let round_tripped = projection.unproject(&projected);
+/// assert_relative_eq!(round_tripped, field, epsilon = 1e-12);
+/// ```
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+#[derive(Debug, Clone, Copy)]
+pub struct AzimuthalEquidistant {
+    origin: Point,
+    geodesic: Geodesic,
+}
+
+impl AzimuthalEquidistant {
+    /// A projection centered on `origin`, on the [WGS84](Ellipsoid::WGS84) ellipsoid.
+    pub fn new(origin: Point) -> Self {
+        Self::on_ellipsoid(origin, &Ellipsoid::WGS84)
+    }
+
+    /// A projection centered on `origin`, on the given ellipsoid.
+    pub fn on_ellipsoid(origin: Point, ellipsoid: &Ellipsoid) -> Self {
+        AzimuthalEquidistant {
+            origin,
+            geodesic: ellipsoid.geodesic(),
+        }
+    }
+
+    /// A projection centered on the center of `geometry`'s bounding rectangle, or `None` if it's
+    /// empty.
+    pub fn around<G>(geometry: &G) -> Option<Self>
+    where
+        G: BoundingRect<f64>,
+    {
+        let bounds = geometry.bounding_rect().into()?;
+        Some(Self::new(bounds.center().into()))
+    }
+
+    /// The longitude and latitude of the projection's origin.
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// Project longitude and latitude to meters east and north of the origin.
+    pub fn forward(&self, coord: Coord) -> Coord {
+        let (distance, azimuth, _, _): (f64, f64, f64, f64) =
+            self.geodesic
+                .inverse(self.origin.y(), self.origin.x(), coord.y, coord.x);
+        let (sin, cos) = azimuth.to_radians().sin_cos();
+        coord! { x: distance * sin, y: distance * cos }
+    }
+
+    /// Convert meters east and north of the origin back to longitude and latitude.
+    pub fn inverse(&self, coord: Coord) -> Coord {
+        let distance = coord.x.hypot(coord.y);
+        let azimuth = coord.x.atan2(coord.y).to_degrees();
+        let (lat, lon) = self
+            .geodesic
+            .direct(self.origin.y(), self.origin.x(), azimuth, distance);
+        coord! { x: lon, y: lat }
+    }
+
+    /// [`forward`](Self::forward) as a closure for [`MapCoords`] and
+    /// [`MapCoordsInPlace`](crate::MapCoordsInPlace).
+    pub fn forward_fn(&self) -> impl Fn(Coord) -> Coord + Copy + '_ {
+        move |coord| self.forward(coord)
+    }
+
+    /// [`inverse`](Self::inverse) as a closure for [`MapCoords`] and
+    /// [`MapCoordsInPlace`](crate::MapCoordsInPlace).
+    pub fn inverse_fn(&self) -> impl Fn(Coord) -> Coord + Copy + '_ {
+        move |coord| self.inverse(coord)
+    }
+
+    /// Project all the coordinates of `geometry`.
+    pub fn project<G>(&self, geometry: &G) -> G::Output
+    where
+        G: MapCoords<f64, f64>,
+    {
+        geometry.map_coords(self.forward_fn())
+    }
+
+    /// Convert all the coordinates of a projected `geometry` back to longitude and latitude.
+    pub fn unproject<G>(&self, geometry: &G) -> G::Output
+    where
+        G: MapCoords<f64, f64>,
+    {
+        geometry.map_coords(self.inverse_fn())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, EuclideanLength, GeodesicBearing, GeodesicLength};
+
+    #[test]
+    fn distances_from_origin_are_exact() {
+        let origin = point!(x: -74.006, y: 40.7128);
+        let projection = AzimuthalEquidistant::new(origin);
+        assert_eq!(projection.forward(origin.0), coord! { x: 0., y: 0. });
+
+        let london = point!(x: -0.1278, y: 51.5074);
+        let projected = Point::from(projection.forward(london.0));
+        assert_relative_eq!(
+            projected.0.x.hypot(projected.0.y),
+            5_585_233.579,
+            epsilon = 1e-3
+        );
+        let bearing = origin.geodesic_bearing(london);
+        assert_relative_eq!(
+            projected.0.x.atan2(projected.0.y).to_degrees(),
+            bearing,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            Point::from(projection.inverse(projected.0)),
+            london,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn local_lengths_are_preserved() {
+        let line_string = line_string![
+            (x: 13.400, y: 52.520),
+            (x: 13.410, y: 52.525),
+            (x: 13.420, y: 52.515),
+        ];
+        let projection = AzimuthalEquidistant::around(&line_string).unwrap();
+        assert_relative_eq!(
+            projection.project(&line_string).euclidean_length(),
+            line_string.geodesic_length(),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn other_ellipsoids() {
+        let projection =
+            AzimuthalEquidistant::on_ellipsoid(point!(x: 0., y: 0.), &Ellipsoid::sphere(1.));
+        let projected = projection.forward(coord! { x: 90., y: 0. });
+        assert_relative_eq!(projected, coord! { x: core::f64::consts::FRAC_PI_2, y: 0. });
+        assert!(AzimuthalEquidistant::around(&crate::MultiPoint::<f64>::new(vec![])).is_none());
+    }
+}
@@ -0,0 +1,305 @@
+//! Batch versions of hot inner loops, for classifying many coordinates at once.
+//!
+//! The kernels here process coordinates in fixed-width lanes with branch-free arithmetic, so that
+//! the compiler can vectorize them with the SIMD instructions of the target, without `unsafe` or
+//! a nightly toolchain. Building with `RUSTFLAGS="-C target-cpu=native"` lets it use the widest
+//! instructions available.
+
+use crate::{BoundingRect, Coord, CoordFloat, CoordNum, LineString, MultiPolygon, Polygon, Rect};
+use alloc::{vec, vec::Vec};
+
+/// The number of coordinates processed together by each kernel.
+const LANES: usize = 8;
+
+/// Test whether many coordinates are inside a geometry, in a single pass over its edges for each
+/// batch of coordinates.
+///
+/// For `Polygon`s and `MultiPolygon`s this uses the even-odd rule, with the crossings of a ray
+/// from each coordinate, so it's much faster than testing each coordinate with
+/// [`Contains`](crate::Contains) when there are many of them. Unlike `Contains`, it doesn't use
+/// robust predicates, so coordinates on, or within rounding error of, the boundary may be
+/// classified either way. For `Rect`s, the result is exactly that of `Contains`.
+///
+/// Coordinates are tested in batches, in the order given, and the edges of a polygon which are
+/// entirely above or below a batch are skipped, so it's fastest when nearby coordinates are
+/// adjacent, as in the rows of a grid, or a spatially sorted dataset.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Contains, ContainsPoints, Coord};
+///
+/// let polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+/// ];
+/// let coords: Vec<Coord> = (0..100)
+///     .map(|i| coord! { x: (i % 10) as f64 + 0.5, y: (i / 10) as f64 + 0.5 })
+///     .collect();
+///
+/// let inside = polygon.contains_points(&coords);
+/// assert_eq!(inside.iter().filter(|inside| **inside).count(), 96);
+/// for (coord, inside) in coords.iter().zip(inside) {
+///     assert_eq!(polygon.contains(coord), inside);
+/// }
+/// ```
+pub trait ContainsPoints<T: CoordNum> {
+    /// Whether each of `coords` is inside `self`.
+    fn contains_points(&self, coords: &[Coord<T>]) -> Vec<bool>;
+}
+
+impl<T> ContainsPoints<T> for Polygon<T>
+where
+    T: CoordFloat,
+{
+    fn contains_points(&self, coords: &[Coord<T>]) -> Vec<bool> {
+        let mut result = Vec::with_capacity(coords.len());
+        for_each_batch(coords, |xs, ys, len| {
+            let mut inside = [false; LANES];
+            polygon_crossings(self, xs, ys, &mut inside);
+            result.extend_from_slice(&inside[..len]);
+        });
+        result
+    }
+}
+
+impl<T> ContainsPoints<T> for MultiPolygon<T>
+where
+    T: CoordFloat,
+{
+    fn contains_points(&self, coords: &[Coord<T>]) -> Vec<bool> {
+        let polygons: Vec<(Rect<T>, &Polygon<T>)> = self
+            .iter()
+            .filter_map(|polygon| Some((polygon.bounding_rect()?, polygon)))
+            .collect();
+
+        let mut result = Vec::with_capacity(coords.len());
+        for_each_batch(coords, |xs, ys, len| {
+            let mut inside = [false; LANES];
+            for (bounds, polygon) in &polygons {
+                // Skip the polygon's edges if none of the batch is within its bounds
+                if in_bounds(bounds, xs, ys)[..len].contains(&true) {
+                    // The polygons' interiors are disjoint, so their crossings can be combined
+                    polygon_crossings(polygon, xs, ys, &mut inside);
+                }
+            }
+            result.extend_from_slice(&inside[..len]);
+        });
+        result
+    }
+}
+
+impl<T> ContainsPoints<T> for Rect<T>
+where
+    T: CoordNum,
+{
+    fn contains_points(&self, coords: &[Coord<T>]) -> Vec<bool> {
+        let (min, max) = (self.min(), self.max());
+        let mut result = Vec::with_capacity(coords.len());
+        for_each_batch(coords, |xs, ys, len| {
+            let mut inside = [false; LANES];
+            for lane in 0..LANES {
+                inside[lane] = (min.x < xs[lane])
+                    & (xs[lane] < max.x)
+                    & (min.y < ys[lane])
+                    & (ys[lane] < max.y);
+            }
+            result.extend_from_slice(&inside[..len]);
+        });
+        result
+    }
+}
+
+/// Find the segments of a `LineString` whose bounding rectangles intersect a given rectangle.
+///
+/// This is a cheap filter for the candidates of a more expensive test, such as an intersection
+/// with a small geometry whose bounding rectangle is `bounds`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Rect, SegmentsInRect};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+/// let bounds = Rect::new((8., -1.), (12., 1.));
+/// assert_eq!(line_string.segments_in_rect(&bounds), vec![0, 1]);
+/// ```
+pub trait SegmentsInRect<T: CoordNum> {
+    /// The indices of the segments, as yielded by [`LineString::lines`], whose bounding
+    /// rectangles intersect `bounds`, including those which only touch its boundary.
+    fn segments_in_rect(&self, bounds: &Rect<T>) -> Vec<usize>;
+}
+
+impl<T> SegmentsInRect<T> for LineString<T>
+where
+    T: CoordNum,
+{
+    fn segments_in_rect(&self, bounds: &Rect<T>) -> Vec<usize> {
+        let (min, max) = (bounds.min(), bounds.max());
+        let mut result = vec![];
+        if self.0.len() < 2 {
+            return result;
+        }
+
+        let batches = self.0.chunks(LANES).zip(self.0[1..].chunks(LANES));
+        for (batch, (starts, ends)) in batches.enumerate() {
+            let mut hits = [false; LANES];
+            for ((hit, start), end) in hits.iter_mut().zip(starts).zip(ends) {
+                let (x_min, x_max) = min_max(start.x, end.x);
+                let (y_min, y_max) = min_max(start.y, end.y);
+                *hit = (x_min <= max.x) & (min.x <= x_max) & (y_min <= max.y) & (min.y <= y_max);
+            }
+            result.extend(
+                (0..ends.len())
+                    .filter(|lane| hits[*lane])
+                    .map(|lane| batch * LANES + lane),
+            );
+        }
+        result
+    }
+}
+
+/// Call `f` with the `x` and `y` values of each batch of up to [`LANES`] coordinates, and the
+/// number of them. The lanes of a final, partial batch are padded with its first coordinate.
+fn for_each_batch<T: CoordNum>(
+    coords: &[Coord<T>],
+    mut f: impl FnMut(&[T; LANES], &[T; LANES], usize),
+) {
+    for batch in coords.chunks(LANES) {
+        let mut xs = [batch[0].x; LANES];
+        let mut ys = [batch[0].y; LANES];
+        for (lane, coord) in batch.iter().enumerate() {
+            xs[lane] = coord.x;
+            ys[lane] = coord.y;
+        }
+        f(&xs, &ys, batch.len());
+    }
+}
+
+/// Toggle `inside` for each lane whose ray towards positive `x` crosses an edge of `polygon`.
+fn polygon_crossings<T: CoordFloat>(
+    polygon: &Polygon<T>,
+    xs: &[T; LANES],
+    ys: &[T; LANES],
+    inside: &mut [bool; LANES],
+) {
+    let (y_min, y_max) = ys[1..]
+        .iter()
+        .fold((ys[0], ys[0]), |(min, max), y| (min.min(*y), max.max(*y)));
+    for ring in core::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        for edge in ring.0.windows(2) {
+            let (start, end) = (edge[0], edge[1]);
+            // Most edges are above or below all the lanes, and can't be crossed by their rays
+            if (start.y > y_max && end.y > y_max) || (start.y < y_min && end.y < y_min) {
+                continue;
+            }
+            let delta = end - start;
+            for lane in 0..LANES {
+                let upward = (start.y <= ys[lane]) & (ys[lane] < end.y);
+                let downward = (end.y <= ys[lane]) & (ys[lane] < start.y);
+                // Positive when the coordinate is to the left of the edge
+                let cross = delta.x * (ys[lane] - start.y) - (xs[lane] - start.x) * delta.y;
+                inside[lane] ^= (upward & (cross > T::zero())) | (downward & (cross < T::zero()));
+            }
+        }
+    }
+}
+
+/// Whether each lane is within `bounds`, including its boundary.
+fn in_bounds<T: CoordNum>(bounds: &Rect<T>, xs: &[T; LANES], ys: &[T; LANES]) -> [bool; LANES] {
+    let (min, max) = (bounds.min(), bounds.max());
+    let mut result = [false; LANES];
+    for lane in 0..LANES {
+        result[lane] =
+            (min.x <= xs[lane]) & (xs[lane] <= max.x) & (min.y <= ys[lane]) & (ys[lane] <= max.y);
+    }
+    result
+}
+
+fn min_max<T: CoordNum>(a: T, b: T) -> (T, T) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, Contains};
+
+    fn grid(n: usize, scale: f64) -> Vec<Coord> {
+        (0..n * n)
+            .map(|i| coord! { x: (i % n) as f64 * scale + 0.01, y: (i / n) as f64 * scale + 0.02 })
+            .collect()
+    }
+
+    #[test]
+    fn polygon_matches_contains() {
+        let polygon = Polygon::new(geo_test_fixtures::louisiana(), vec![]);
+        let bounds = polygon.bounding_rect().unwrap();
+        let coords: Vec<Coord> = grid(37, 0.2)
+            .into_iter()
+            .map(|coord| coord + bounds.min())
+            .collect();
+
+        let inside = polygon.contains_points(&coords);
+        assert_eq!(inside.len(), coords.len());
+        for (coord, inside) in coords.iter().zip(&inside) {
+            assert_eq!(polygon.contains(coord), *inside, "{coord:?}");
+        }
+        assert!(inside.iter().any(|inside| *inside));
+        assert!(polygon.contains_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn multi_polygon_matches_contains() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        ];
+        let triangle = polygon![(x: 5., y: 0.), (x: 9., y: 0.), (x: 5., y: 4.)];
+        let multi_polygon =
+            MultiPolygon::new(vec![square, triangle, Polygon::new(line_string![], vec![])]);
+
+        let coords = grid(19, 0.5);
+        let inside = multi_polygon.contains_points(&coords);
+        for (coord, inside) in coords.iter().zip(inside) {
+            assert_eq!(multi_polygon.contains(coord), inside, "{coord:?}");
+        }
+    }
+
+    #[test]
+    fn rect_matches_contains() {
+        let rect = Rect::new((1, 1), (4, 3));
+        let coords: Vec<Coord<i32>> = (0..30).map(|i| coord! { x: i % 6, y: i / 6 }).collect();
+        let inside = rect.contains_points(&coords);
+        for (coord, inside) in coords.iter().zip(inside) {
+            assert_eq!(rect.contains(coord), inside);
+        }
+    }
+
+    #[test]
+    fn segments_in_rect() {
+        let line_string: LineString = (0..20)
+            .map(|i| coord! { x: i as f64, y: (i % 2) as f64 })
+            .collect();
+        let bounds = Rect::new((4.5, 0.), (7., 0.5));
+        assert_eq!(line_string.segments_in_rect(&bounds), vec![4, 5, 6, 7]);
+
+        let all = Rect::new((-1., -1.), (20., 2.));
+        assert_eq!(
+            line_string.segments_in_rect(&all),
+            (0..19).collect::<Vec<_>>()
+        );
+        assert!(line_string![(x: 5., y: 0.)]
+            .segments_in_rect(&all)
+            .is_empty());
+    }
+}
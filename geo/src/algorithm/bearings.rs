@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use crate::angles::{angle_difference, normalize_angle};
+#[cfg(feature = "std")]
+use crate::{Ellipsoid, Point};
+use crate::{GeoFloat, HaversineBearing, Line, LineString};
+#[cfg(feature = "std")]
+use geographiclib_rs::InverseGeodesic;
+
+/// How [`Bearings`] measures the direction of segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BearingMetric {
+    /// The direction in the plane, clockwise from the positive `y` axis.
+    #[default]
+    Euclidean,
+    /// The direction of the great circle between longitude and latitude coordinates, as for
+    /// [`HaversineBearing`].
+    Haversine,
+    /// The direction of the geodesic between longitude and latitude coordinates on the
+    /// [WGS84](Ellipsoid::WGS84) ellipsoid, as for [`GeodesicBearing`](crate::GeodesicBearing).
+    #[cfg(feature = "std")]
+    Geodesic,
+}
+
+/// The heading of each segment of a path, and the turns between them, such as for detecting
+/// changes of direction in a GPS track.
+///
+/// Bearings and turn angles are in degrees, in the range `(-180, 180]`. A bearing is clockwise
+/// from north, or from the positive `y` axis, so east is 90°. A turn angle is positive for a
+/// turn to the right, clockwise, and negative for a turn to the left.
+///
+/// On a sphere or an ellipsoid, the direction of a segment changes along it, so a segment's
+/// bearing is its direction at its start, and turns are measured at each vertex, from the
+/// direction that the segment before it arrives in.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, BearingMetric, Bearings};
+///
+/// let track = line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 2.), (x: 0., y: 3.)];
+///
+/// let bearings = track.bearings(BearingMetric::Euclidean);
+/// assert_relative_eq!(bearings.as_slice(), [0., 45., -45.].as_slice());
+///
+/// let turns = track.turn_angles(BearingMetric::Euclidean);
+/// assert_relative_eq!(turns.as_slice(), [45., -90.].as_slice());
+///
+/// // At 60° north, the great circle to a point due east sets off north of east
+/// let track = line_string![(x: 0., y: 60.), (x: 10., y: 60.)];
+/// assert!(track.bearings(BearingMetric::Haversine)[0] < 90.);
+/// ```
+pub trait Bearings<T: GeoFloat> {
+    /// The bearing of each segment, in order. A segment whose ends are the same has a bearing
+    /// of zero.
+    fn bearings(&self, metric: BearingMetric) -> Vec<T>;
+
+    /// The turn at each vertex between two segments, in order. Segments whose ends are the same
+    /// have no direction, so they're skipped, such that a vertex repeated in a line string
+    /// counts once.
+    fn turn_angles(&self, metric: BearingMetric) -> Vec<T>;
+}
+
+impl<T: GeoFloat> Bearings<T> for LineString<T> {
+    fn bearings(&self, metric: BearingMetric) -> Vec<T> {
+        self.lines()
+            .map(|line| initial_and_final_bearings(line, metric).0)
+            .collect()
+    }
+
+    fn turn_angles(&self, metric: BearingMetric) -> Vec<T> {
+        let mut arriving: Option<T> = None;
+        let mut turns = Vec::with_capacity(self.0.len().saturating_sub(2));
+        for line in self.lines().filter(|line| line.start != line.end) {
+            let (initial, final_bearing) = initial_and_final_bearings(line, metric);
+            if let Some(arriving) = arriving {
+                turns.push(angle_difference(arriving, initial));
+            }
+            arriving = Some(final_bearing);
+        }
+        turns
+    }
+}
+
+/// The bearing of `line` at its start, and at its end.
+fn initial_and_final_bearings<T: GeoFloat>(line: Line<T>, metric: BearingMetric) -> (T, T) {
+    let half_turn = T::from(180).unwrap();
+    match metric {
+        BearingMetric::Euclidean => {
+            let delta = line.delta();
+            let bearing = delta.x.atan2(delta.y).to_degrees();
+            (bearing, bearing)
+        }
+        BearingMetric::Haversine => {
+            let (start, end) = (line.start_point(), line.end_point());
+            let back: T = end.haversine_bearing(start);
+            (
+                start.haversine_bearing(end),
+                normalize_angle(back + half_turn),
+            )
+        }
+        #[cfg(feature = "std")]
+        BearingMetric::Geodesic => {
+            let to_f64 =
+                |point: Point<T>| (point.x().to_f64().unwrap(), point.y().to_f64().unwrap());
+            let (start_lon, start_lat) = to_f64(line.start_point());
+            let (end_lon, end_lat) = to_f64(line.end_point());
+            let (_, initial, final_bearing, _): (f64, f64, f64, f64) = Ellipsoid::WGS84
+                .geodesic()
+                .inverse(start_lat, start_lon, end_lat, end_lon);
+            (T::from(initial).unwrap(), T::from(final_bearing).unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, Point};
+
+    #[test]
+    fn euclidean() {
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(
+            square.bearings(BearingMetric::Euclidean),
+            [90., 0., 0., -90., 180.]
+        );
+        // Counter clockwise, and the repeated vertex is skipped
+        assert_eq!(
+            square.turn_angles(BearingMetric::Euclidean),
+            [-90., -90., -90.]
+        );
+
+        let reversal = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(reversal.turn_angles(BearingMetric::Euclidean), [180.]);
+
+        assert!(line_string![(x: 0., y: 0.)]
+            .bearings(BearingMetric::Euclidean)
+            .is_empty());
+        assert!(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+            .turn_angles(BearingMetric::Euclidean)
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn spherical_and_ellipsoidal() {
+        use crate::GeodesicBearing;
+
+        let track = line_string![(x: 0., y: 60.), (x: 10., y: 60.), (x: 20., y: 60.)];
+
+        let haversine = track.bearings(BearingMetric::Haversine);
+        assert_eq!(
+            haversine[0],
+            Point::new(0., 60.).haversine_bearing(Point::new(10., 60.))
+        );
+        let geodesic = track.bearings(BearingMetric::Geodesic);
+        assert_relative_eq!(
+            geodesic[1],
+            Point::new(10., 60.).geodesic_bearing(Point::new(20., 60.)),
+            epsilon = 1e-12
+        );
+
+        // Each great circle arrives heading south of east, then the next leaves north of it,
+        // so following the parallel is a turn to the left
+        for metric in [BearingMetric::Haversine, BearingMetric::Geodesic] {
+            let turns = track.turn_angles(metric);
+            assert_eq!(turns.len(), 1);
+            assert!(turns[0] < -5. && turns[0] > -10., "{metric:?}: {turns:?}");
+        }
+        let haversine_turn = track.turn_angles(BearingMetric::Haversine)[0];
+        assert_relative_eq!(haversine_turn, 2. * (haversine[0] - 90.), epsilon = 1e-9);
+
+        // Along the equator, there's no turn
+        let equator = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 20., y: 0.)];
+        assert_relative_eq!(
+            equator.turn_angles(BearingMetric::Haversine)[0],
+            0.,
+            epsilon = 1e-9
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use bumpalo::Bump;
+
+use super::{BoolOp, ClipOp, OpType, Proc, Spec};
+use crate::sweep::{LineOrPoint, SweepError};
+use crate::view::{LineStringSlice, PolygonRef};
+use crate::{CoordsIter, GeoFloat};
+
+/// Boolean Operations on geometries whose coordinates are allocated in a [`Bump`] arena.
+///
+/// These are the operations of [`BooleanOps`](super::BooleanOps), on [`PolygonRef`]s and slices
+/// of them, rather than on owned `Polygon`s and `MultiPolygon`s. The inputs are read in place,
+/// rather than being cloned into owned geometries, and the coordinates of the output are
+/// allocated in `bump`, so that all of the geometries of, say, a tile can be freed at once with
+/// [`Bump::reset`]. The sweep still allocates its own working state on the heap.
+///
+/// # Examples
+///
+/// ```
+/// use geo::arena::Bump;
+/// use geo::view::{LineStringSlice, PolygonRef};
+/// use geo::{coord, Area, ArenaBooleanOps, EuclideanLength, OpType};
+///
+/// let bump = Bump::new();
+/// let square = |min: f64, max: f64| {
+///     PolygonRef::alloc_in(
+///         &bump,
+///         [(min, min), (max, min), (max, max), (min, max)].map(Into::into),
+///         [] as [[_; 0]; 0],
+///     )
+/// };
+/// let tile = square(0., 2.);
+///
+/// let overlap = tile.boolean_op_in(&square(1., 3.), OpType::Intersection, &bump);
+/// assert_eq!(overlap.len(), 1);
+/// assert_eq!(overlap[0].unsigned_area(), 1.);
+///
+/// let road = LineStringSlice::alloc_in(&bump, [coord! { x: -1., y: 1. }, coord! { x: 3., y: 1. }]);
+/// let clipped = tile.clip_in(&[road], false, &bump);
+/// assert_eq!(clipped.len(), 1);
+/// assert_eq!(clipped[0].euclidean_length(), 2.);
+/// ```
+pub trait ArenaBooleanOps {
+    type Scalar: GeoFloat;
+
+    /// The `op` of `self` and `other`, allocated in `bump`. See [`BooleanOps::boolean_op`].
+    ///
+    /// [`BooleanOps::boolean_op`]: super::BooleanOps::boolean_op
+    fn boolean_op_in<'bump>(
+        &self,
+        other: &Self,
+        op: OpType,
+        bump: &'bump Bump,
+    ) -> &'bump [PolygonRef<'bump, Self::Scalar>];
+
+    /// Clip the line strings `ls` with `self`, allocating the output in `bump`. See
+    /// [`BooleanOps::clip`].
+    ///
+    /// [`BooleanOps::clip`]: super::BooleanOps::clip
+    fn clip_in<'bump>(
+        &self,
+        ls: &[LineStringSlice<'_, Self::Scalar>],
+        invert: bool,
+        bump: &'bump Bump,
+    ) -> &'bump [LineStringSlice<'bump, Self::Scalar>];
+}
+
+impl<T: GeoFloat> ArenaBooleanOps for PolygonRef<'_, T> {
+    type Scalar = T;
+
+    fn boolean_op_in<'bump>(
+        &self,
+        other: &Self,
+        op: OpType,
+        bump: &'bump Bump,
+    ) -> &'bump [PolygonRef<'bump, T>] {
+        core::slice::from_ref(self).boolean_op_in(core::slice::from_ref(other), op, bump)
+    }
+
+    fn clip_in<'bump>(
+        &self,
+        ls: &[LineStringSlice<'_, T>],
+        invert: bool,
+        bump: &'bump Bump,
+    ) -> &'bump [LineStringSlice<'bump, T>] {
+        core::slice::from_ref(self).clip_in(ls, invert, bump)
+    }
+}
+
+impl<T: GeoFloat> ArenaBooleanOps for [PolygonRef<'_, T>] {
+    type Scalar = T;
+
+    fn boolean_op_in<'bump>(
+        &self,
+        other: &Self,
+        op: OpType,
+        bump: &'bump Bump,
+    ) -> &'bump [PolygonRef<'bump, T>] {
+        let spec = BoolInOp {
+            op: BoolOp::from(op),
+            bump,
+        };
+        let mut bop = Proc::new(spec, coords_count(self) + coords_count(other));
+        self.iter().for_each(|p| bop.add_polygon_ref(p, 0));
+        other.iter().for_each(|p| bop.add_polygon_ref(p, 1));
+        bop.sweep()
+    }
+
+    fn clip_in<'bump>(
+        &self,
+        ls: &[LineStringSlice<'_, T>],
+        invert: bool,
+        bump: &'bump Bump,
+    ) -> &'bump [LineStringSlice<'bump, T>] {
+        let spec = ClipInOp {
+            clip: ClipOp::new(invert),
+            bump,
+        };
+        let ls_count: usize = ls.iter().map(|l| l.0.len()).sum();
+        let mut bop = Proc::new(spec, coords_count(self) + ls_count);
+        self.iter().for_each(|p| bop.add_polygon_ref(p, 0));
+        ls.iter().enumerate().for_each(|(idx, l)| {
+            bop.add_line_string(l.0, idx + 1);
+        });
+        bop.sweep()
+    }
+}
+
+fn coords_count<T: GeoFloat>(polygons: &[PolygonRef<'_, T>]) -> usize {
+    polygons.iter().map(CoordsIter::coords_count).sum()
+}
+
+/// A [`BoolOp`] which assembles its output polygons in an arena.
+struct BoolInOp<'bump, T: GeoFloat> {
+    op: BoolOp<T>,
+    bump: &'bump Bump,
+}
+
+impl<'bump, T: GeoFloat + 'bump> Spec<T> for BoolInOp<'bump, T> {
+    type Region = <BoolOp<T> as Spec<T>>::Region;
+    type Output = &'bump [PolygonRef<'bump, T>];
+
+    fn infinity(&self) -> Self::Region {
+        self.op.infinity()
+    }
+
+    fn cross(&self, prev_region: Self::Region, idx: usize) -> Self::Region {
+        self.op.cross(prev_region, idx)
+    }
+
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize) {
+        self.op.output(regions, geom, idx)
+    }
+
+    fn finish(self) -> Result<Self::Output, SweepError> {
+        self.op.into_assembly().finish_in(self.bump)
+    }
+}
+
+/// A [`ClipOp`] which assembles its output lines in an arena.
+struct ClipInOp<'bump, T: GeoFloat> {
+    clip: ClipOp<T>,
+    bump: &'bump Bump,
+}
+
+impl<'bump, T: GeoFloat + 'bump> Spec<T> for ClipInOp<'bump, T> {
+    type Region = <ClipOp<T> as Spec<T>>::Region;
+    type Output = &'bump [LineStringSlice<'bump, T>];
+
+    fn infinity(&self) -> Self::Region {
+        self.clip.infinity()
+    }
+
+    fn cross(&self, prev_region: Self::Region, idx: usize) -> Self::Region {
+        self.clip.cross(prev_region, idx)
+    }
+
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize) {
+        self.clip.output(regions, geom, idx)
+    }
+
+    fn finish(self) -> Result<Self::Output, SweepError> {
+        Ok(self.clip.into_assembly().finish_in(self.bump))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BooleanOps, MultiLineString, MultiPolygon, Polygon, Translate};
+    use bumpalo::collections::Vec as BumpVec;
+    use wkt::TryFromWkt;
+
+    fn alloc_polygons<'bump>(
+        bump: &'bump Bump,
+        polygons: &MultiPolygon,
+    ) -> &'bump [PolygonRef<'bump, f64>] {
+        let polygons = polygons
+            .iter()
+            .map(|polygon| PolygonRef::from(polygon).clone_in(bump));
+        BumpVec::from_iter_in(polygons, bump).into_bump_slice()
+    }
+
+    fn to_multi_polygon(polygons: &[PolygonRef]) -> MultiPolygon {
+        polygons.iter().map(PolygonRef::to_polygon).collect()
+    }
+
+    #[test]
+    fn matches_boolean_ops() {
+        let bump = Bump::new();
+        let a = MultiPolygon::new(vec![Polygon::new(geo_test_fixtures::louisiana(), vec![])]);
+        let east_baton_rouge: Polygon = geo_test_fixtures::east_baton_rouge();
+        let b = MultiPolygon::new(vec![
+            east_baton_rouge.translate(0.5, -0.5),
+            east_baton_rouge.translate(0., -2.),
+        ]);
+        let (a_ref, b_ref) = (alloc_polygons(&bump, &a), alloc_polygons(&bump, &b));
+
+        for op in [
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ] {
+            assert_eq!(
+                to_multi_polygon(a_ref.boolean_op_in(b_ref, op, &bump)),
+                a.boolean_op(&b, op),
+            );
+        }
+    }
+
+    #[test]
+    fn holes_match_boolean_ops() {
+        let bump = Bump::new();
+        let a = MultiPolygon::try_from_wkt_str(
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2)))",
+        )
+        .unwrap();
+        let b = MultiPolygon::try_from_wkt_str("MULTIPOLYGON(((4 4,6 4,6 6,4 6,4 4)))").unwrap();
+        let (a_ref, b_ref) = (alloc_polygons(&bump, &a), alloc_polygons(&bump, &b));
+
+        for op in [OpType::Union, OpType::Xor, OpType::Difference] {
+            let polygons = a_ref.boolean_op_in(b_ref, op, &bump);
+            assert!(polygons
+                .iter()
+                .any(|polygon| !polygon.interiors().is_empty()));
+            assert_eq!(to_multi_polygon(polygons), a.boolean_op(&b, op));
+        }
+    }
+
+    #[test]
+    fn clip_matches_boolean_ops() {
+        let bump = Bump::new();
+        let polygon =
+            Polygon::try_from_wkt_str("POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))")
+                .unwrap();
+        let lines = MultiLineString::try_from_wkt_str(
+            "MULTILINESTRING((-1 3,11 3),(5 -5,5 5,15 5),(1 1,1 2))",
+        )
+        .unwrap();
+        let lines_in: Vec<_> = lines
+            .iter()
+            .map(|l| LineStringSlice::from(l).clone_in(&bump))
+            .collect();
+
+        for invert in [false, true] {
+            let clipped = PolygonRef::from(&polygon).clip_in(&lines_in, invert, &bump);
+            assert_eq!(
+                clipped
+                    .iter()
+                    .map(LineStringSlice::to_line_string)
+                    .collect::<MultiLineString>(),
+                polygon.clip(&lines, invert),
+            );
+        }
+    }
+}
@@ -1,15 +1,25 @@
-use std::{
-    cell::Cell,
-    collections::{BTreeMap, HashMap, VecDeque},
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    vec,
+    vec::Vec,
 };
+use core::cell::Cell;
 
 use crate::{
-    sweep::{compare_crossings, Cross, CrossingsIter, LineOrPoint, SweepPoint},
+    sweep::{Cross, CrossingsIter, LineOrPoint, SweepError, SweepPoint},
     utils::EitherIter,
     winding_order::WindingOrder,
     GeoFloat,
 };
-use geo_types::{LineString, MultiPolygon, Polygon};
+use geo_types::{Coord, LineString, MultiPolygon, Polygon};
+
+#[cfg(feature = "bumpalo")]
+use crate::{
+    arena::alloc_ring_in,
+    view::{LineStringSlice, PolygonRef},
+};
+#[cfg(feature = "bumpalo")]
+use bumpalo::{collections::Vec as BumpVec, Bump};
 
 /// Assemble polygons from boundary segments of the output region.
 ///
@@ -38,15 +48,46 @@ impl<T: GeoFloat> RegionAssembly<T> {
         trace!("add_edge: {edge:?}");
         self.segments.push(edge.into());
     }
-    /// Creates the final `MultiPolygon` from the edges previously added.
-    pub fn finish(self) -> MultiPolygon<T> {
+    /// Creates the final `MultiPolygon` from the edges previously added, or an
+    /// error if floating-point error leaves them unable to form rings.
+    pub fn finish(self) -> Result<MultiPolygon<T>, SweepError> {
+        let polygons = self.finish_with(LineString::new)?;
+        Ok(polygons
+            .into_iter()
+            .map(|(exterior, holes)| Polygon::new(exterior, holes))
+            .collect())
+    }
+
+    /// Creates the polygons from the previously added edges, with their rings
+    /// allocated in `bump`.
+    #[cfg(feature = "bumpalo")]
+    pub fn finish_in(self, bump: &Bump) -> Result<&[PolygonRef<'_, T>], SweepError> {
+        let polygons = self
+            .finish_with(|coords| alloc_ring_in(bump, coords))?
+            .into_iter()
+            .map(|(exterior, holes)| {
+                let holes = BumpVec::from_iter_in(holes, bump).into_bump_slice();
+                PolygonRef::with_interiors(exterior, holes)
+            });
+        Ok(BumpVec::from_iter_in(polygons, bump).into_bump_slice())
+    }
+
+    /// Assembles the rings of the output polygons, passing the coordinates of
+    /// each to `make_ring`. Returns the exterior and holes of each polygon.
+    fn finish_with<R, F: FnMut(Vec<Coord<T>>) -> R>(
+        self,
+        mut make_ring: F,
+    ) -> Result<Vec<(R, Vec<R>)>, SweepError> {
         let mut iter = CrossingsIter::new_simple(self.segments.iter());
         let mut snakes = vec![];
 
         while let Some(pt) = iter.next() {
             let num_segments = iter.intersections().len();
-            debug_assert!(num_segments % 2 == 0, "assembly segments must be eulierian");
-            iter.intersections_mut().sort_unstable_by(compare_crossings);
+            if num_segments % 2 != 0 {
+                warn!("assembly segments must be eulerian");
+                return Err(SweepError);
+            }
+            iter.sort_intersections()?;
 
             let first = &iter.intersections()[0];
             // We need to determine the previous region (to know whether this set of
@@ -56,7 +97,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                 // (since right crossings are ordered before left crossings). We should read
                 // prev_region and parent snake via `prev_active`, to know whether these edges
                 // are part of a hole or a shell.
-                iter.prev_active(first)
+                iter.prev_active(first)?
                     .map(|(_, seg)| (seg.region.get(), Some(seg.snake_idx.get())))
                     // If the expression is not Some, the snake will complete to an exterior ring
                     // anyway. Therefore we can set an arbitrary value for the parent snake index.
@@ -86,7 +127,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                     c.cross.region.set(true);
                     if parent_snake_idx.is_none() {
                         parent_snake_idx = Some(
-                            iter.prev_active(c)
+                            iter.prev_active(c)?
                                 .map(|(_, seg)| seg.snake_idx.get())
                                 // If the expression is not Some, the snake will complete to an
                                 // exterior ring anyway. Therefore can set an arbitrary value for
@@ -99,7 +140,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                     d.cross.region.set(false);
                     if parent_snake_idx.is_none() {
                         parent_snake_idx = Some(
-                            iter.prev_active(d)
+                            iter.prev_active(d)?
                                 .map(|(_, seg)| seg.snake_idx.get())
                                 // If the expression is not Some, the snake will complete to an
                                 // exterior ring anyway. Therefore can set an arbitrary value for
@@ -154,10 +195,12 @@ impl<T: GeoFloat> RegionAssembly<T> {
             }
         }
 
-        let (rings, snakes_idx_map) = rings_from_snakes(&mut snakes[..]);
+        iter.check()?;
+
+        let (rings, snakes_idx_map) = rings_from_snakes(&mut snakes[..])?;
 
         let mut polygons = vec![];
-        let mut children = HashMap::new();
+        let mut children = BTreeMap::new();
         // Assign each hole ring to a shell.
         for (ring_idx, ring) in rings.iter().enumerate() {
             if ring.is_hole {
@@ -165,7 +208,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                 let mut parent_snake_idx = ring.parent_snake_idx;
                 // Keep following the parent ring until a shell is found.
                 loop {
-                    parent_ring_idx = snakes_idx_map[&parent_snake_idx];
+                    parent_ring_idx = *snakes_idx_map.get(&parent_snake_idx).ok_or(SweepError)?;
                     let parent = &rings[parent_ring_idx];
                     if !parent.is_hole {
                         break;
@@ -186,15 +229,17 @@ impl<T: GeoFloat> RegionAssembly<T> {
             }
             let mut holes = vec![];
             for child_idx in children.remove(&ring_idx).unwrap_or_default() {
-                let ls = split_ring(&rings[child_idx].ls, |ls| holes.push(ls));
-                holes.push(ls);
+                let hole = split_ring(&rings[child_idx].ls, &mut make_ring, |hole| {
+                    holes.push(hole)
+                });
+                holes.push(hole);
             }
             debug!("ext: {ext:?}", ext = ring.ls);
-            let exterior = split_ring(&ring.ls, |ls| holes.push(ls));
-            polygons.push(Polygon::new(exterior, holes));
+            let exterior = split_ring(&ring.ls, &mut make_ring, |hole| holes.push(hole));
+            polygons.push((exterior, holes));
         }
 
-        polygons.into()
+        Ok(polygons)
     }
 }
 
@@ -252,6 +297,17 @@ impl<T: GeoFloat> LineAssembly<T> {
             .map(|pts| LineString::from_iter(pts.into_iter().map(|pt| *pt)))
             .collect()
     }
+
+    /// Creates the line strings from the previously added edges, with their
+    /// coordinates allocated in `bump`.
+    #[cfg(feature = "bumpalo")]
+    pub fn finish_in(self, bump: &Bump) -> &[LineStringSlice<'_, T>] {
+        let line_strings = self
+            .segments
+            .into_iter()
+            .map(|pts| LineStringSlice::alloc_in(bump, pts.into_iter().map(|pt| *pt)));
+        BumpVec::from_iter_in(line_strings, bump).into_bump_slice()
+    }
 }
 
 impl<T: GeoFloat> Default for LineAssembly<T> {
@@ -276,48 +332,49 @@ struct Ring<T: GeoFloat> {
 }
 
 /// Splits a ring `ls` that possibly includes self intersections into
-/// "well-behaved" rings (no self intersections). `cb` is called on "extra"
-/// rings. The returned ring is the ring starting from the first vertex (so if
-/// this vertex is guaranteed to be on the exterior, the returned ring will also
-/// be the exterior).
-fn split_ring<T: GeoFloat, F: FnMut(LineString<T>)>(
+/// "well-behaved" rings (no self intersections), each of which is created from
+/// its coordinates by `make_ring`. `cb` is called on "extra" rings. The returned
+/// ring is the ring starting from the first vertex (so if this vertex is
+/// guaranteed to be on the exterior, the returned ring will also be the
+/// exterior).
+fn split_ring<T: GeoFloat, R, F: FnMut(Vec<Coord<T>>) -> R, G: FnMut(R)>(
     ls: &LineString<T>,
-    mut cb: F,
-) -> LineString<T> {
+    mut make_ring: F,
+    mut cb: G,
+) -> R {
     let mut pts_map = BTreeMap::new();
     let mut exterior = vec![];
     for coord in ls.0.iter().copied() {
-        if let Some(idx) = pts_map.get(&SweepPoint::from(coord)) {
-            let new_ls: LineString<_> = exterior
-                .drain(idx..)
-                .inspect(|pt| {
-                    pts_map.remove(&SweepPoint::from(*pt)).unwrap();
-                })
-                .collect();
-            cb(new_ls);
+        if let Some(&idx) = pts_map.get(&SweepPoint::from(coord)) {
+            let new_ring = exterior.split_off(idx);
+            for pt in &new_ring {
+                pts_map.remove(&SweepPoint::from(*pt)).unwrap();
+            }
+            cb(make_ring(new_ring));
         }
         pts_map.insert(SweepPoint::from(coord), exterior.len());
         exterior.push(coord);
     }
-    LineString::from(exterior)
+    make_ring(exterior)
 }
 
+/// Rings, and a map from the index of each snake to the index of its ring.
+type SnakeRings<T> = (Vec<Ring<T>>, BTreeMap<usize, usize>);
+
 /// Turns every snake in `snakes` into its corresponding ring. Returns the
 /// computed rings (with possible self intersections), and a map from the snake
 /// index to the index of the ring it belongs to.
-fn rings_from_snakes<T: GeoFloat>(
-    snakes: &mut [Snake<T>],
-) -> (Vec<Ring<T>>, HashMap<usize, usize>) {
-    let mut snake_idx_map = HashMap::new();
+fn rings_from_snakes<T: GeoFloat>(snakes: &mut [Snake<T>]) -> Result<SnakeRings<T>, SweepError> {
+    let mut snake_idx_map = BTreeMap::new();
     let mut rings = vec![];
     for idx in 0..snakes.len() {
         if let Some(ls) = Snake::into_ring(snakes, idx, |midx| {
             snake_idx_map.insert(midx, rings.len());
-        }) {
+        })? {
             rings.push(ls);
         }
     }
-    (rings, snake_idx_map)
+    Ok((rings, snake_idx_map))
 }
 
 /// A line of vertices that "consumes" edges until it is "finished" (hits
@@ -370,11 +427,12 @@ impl<T: GeoFloat> Snake<T> {
 
     /// Turns the Snake in `slice` at `start_idx` into a ring by following the
     /// snakes at its start and end. Calls `idx_cb` for each snake id traversed.
+    /// Fails if the snakes don't form a ring.
     pub fn into_ring<F: FnMut(usize)>(
         slice: &mut [Self],
         start_idx: usize,
         mut idx_cb: F,
-    ) -> Option<Ring<T>> {
+    ) -> Result<Option<Ring<T>>, SweepError> {
         let mut output = vec![];
 
         let mut idx = start_idx;
@@ -384,17 +442,17 @@ impl<T: GeoFloat> Snake<T> {
             let el = &slice[idx];
             // This snake was already used to form another ring, so skip it.
             if el.points.is_empty() {
-                return None;
+                return Ok(None);
             }
             let last_el = &slice[el.start_pair];
 
             let start_l = LineOrPoint::new(el.points[0], el.points[1]);
             let end_l = LineOrPoint::new(el.points[0], last_el.points[1]);
-            use std::cmp::Ordering;
-            let ls_winding = match start_l.partial_cmp(&end_l).unwrap() {
-                Ordering::Less => WindingOrder::CounterClockwise,
-                Ordering::Greater => WindingOrder::Clockwise,
-                _ => unreachable!(),
+            use core::cmp::Ordering;
+            let ls_winding = match start_l.partial_cmp(&end_l) {
+                Some(Ordering::Less) => WindingOrder::CounterClockwise,
+                Some(Ordering::Greater) => WindingOrder::Clockwise,
+                _ => return Err(SweepError),
             };
             (el.parent_snake_idx, el.region != ls_winding)
         };
@@ -408,7 +466,7 @@ impl<T: GeoFloat> Snake<T> {
 
             let iter = el.points.drain(..);
             let iter = if at_start {
-                idx = el.end_pair.unwrap();
+                idx = el.end_pair.ok_or(SweepError)?;
                 EitherIter::A(iter)
             } else {
                 idx = el.start_pair;
@@ -423,11 +481,11 @@ impl<T: GeoFloat> Snake<T> {
         }
 
         let ls = LineString::new(output);
-        Some(Ring {
+        Ok(Some(Ring {
             ls,
             is_hole,
             parent_snake_idx,
-        })
+        }))
     }
 }
 
@@ -0,0 +1,279 @@
+use alloc::{vec, vec::Vec};
+
+use super::{BooleanOps, OpType};
+use crate::algorithm::clip_to_rect::clip_half_plane;
+use crate::orient::{Direction, Orient};
+use crate::{
+    Area, BoundingRect, Coord, GeoFloat, Intersects, IsConvex, LineString, MultiPolygon, Polygon,
+};
+
+/// The algorithm used to compute a boolean operation by [`BooleanOpsWith`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OverlayBackend<T> {
+    /// The plane sweep of [`BooleanOps`].
+    #[default]
+    Sweep,
+    /// The plane sweep, on inputs whose coordinates are first rounded to multiples of
+    /// `grid_size`, with the coordinates of the output rounded in the same way.
+    ///
+    /// Rounding merges vertices which are closer than the grid size, and makes nearly
+    /// coincident edges exactly coincident, which are the inputs that the sweep is most likely to
+    /// fail on. Unlike full snap rounding, edges which pass near a vertex without meeting it
+    /// aren't bent to it, so this is a fallback rather than a guarantee. A `grid_size` less than
+    /// or equal to zero is the same as [`Sweep`](Self::Sweep).
+    SnapRounded { grid_size: T },
+    /// Intersect convex polygons by clipping each of them to the edges of the other, as in the
+    /// [Sutherland–Hodgman
+    /// algorithm](https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm).
+    ///
+    /// This doesn't need to find every intersection between the edges, and so avoids the sweep's
+    /// failure modes on these inputs. It's only used when the operation is an intersection
+    /// and every polygon of both inputs is convex without holes; anything else falls back to the
+    /// [`Sweep`](Self::Sweep).
+    ConvexClip,
+}
+
+/// Boolean Operations on geometry, with a choice of the [`OverlayBackend`] which computes them.
+///
+/// These are the operations of [`BooleanOps`], for inputs on which its plane sweep gives wrong
+/// results or panics, such as polygons with nearly coincident edges.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, BooleanOpsWith, OpType, OverlayBackend};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// // A square whose edges are a tiny distance from those of the first
+/// let other = polygon![(x: 1., y: 1e-13), (x: 3., y: 0.), (x: 3., y: 2.), (x: 1., y: 2.)];
+///
+/// let backend = OverlayBackend::SnapRounded { grid_size: 1e-9 };
+/// let union = square.boolean_op_with(&other, OpType::Union, backend);
+/// assert_eq!(union.unsigned_area(), 6.);
+///
+/// let overlap = square.boolean_op_with(&other, OpType::Intersection, OverlayBackend::ConvexClip);
+/// approx::assert_relative_eq!(overlap.unsigned_area(), 2., epsilon = 1e-12);
+/// ```
+pub trait BooleanOpsWith: BooleanOps {
+    /// The `op` of `self` and `other`, computed with `backend`. See [`BooleanOps::boolean_op`].
+    fn boolean_op_with(
+        &self,
+        other: &Self,
+        op: OpType,
+        backend: OverlayBackend<Self::Scalar>,
+    ) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: GeoFloat> BooleanOpsWith for Polygon<T> {
+    fn boolean_op_with(
+        &self,
+        other: &Self,
+        op: OpType,
+        backend: OverlayBackend<T>,
+    ) -> MultiPolygon<T> {
+        match backend {
+            OverlayBackend::Sweep => self.boolean_op(other, op),
+            _ => MultiPolygon::new(vec![self.clone()]).boolean_op_with(
+                &MultiPolygon::new(vec![other.clone()]),
+                op,
+                backend,
+            ),
+        }
+    }
+}
+
+impl<T: GeoFloat> BooleanOpsWith for MultiPolygon<T> {
+    fn boolean_op_with(
+        &self,
+        other: &Self,
+        op: OpType,
+        backend: OverlayBackend<T>,
+    ) -> MultiPolygon<T> {
+        match backend {
+            OverlayBackend::SnapRounded { grid_size } if grid_size > T::zero() => {
+                let result = snap(self, grid_size).boolean_op(&snap(other, grid_size), op);
+                snap(&result, grid_size)
+            }
+            OverlayBackend::ConvexClip
+                if op == OpType::Intersection && is_convex(self) && is_convex(other) =>
+            {
+                convex_intersection(self, other)
+            }
+            _ => self.boolean_op(other, op),
+        }
+    }
+}
+
+/// Round the coordinates of `multi_polygon` to multiples of `grid_size`, dropping the rings which
+/// collapse.
+fn snap<T: GeoFloat>(multi_polygon: &MultiPolygon<T>, grid_size: T) -> MultiPolygon<T> {
+    let snap_ring = |ring: &LineString<T>| {
+        let mut coords: Vec<Coord<T>> = ring
+            .coords()
+            .map(|c| Coord {
+                x: (c.x / grid_size).round() * grid_size,
+                y: (c.y / grid_size).round() * grid_size,
+            })
+            .collect();
+        coords.dedup();
+        let ring = LineString::new(coords);
+        (ring.0.len() >= 4 && ring_area(&ring) != T::zero()).then_some(ring)
+    };
+    multi_polygon
+        .iter()
+        .filter_map(|polygon| {
+            let exterior = snap_ring(polygon.exterior())?;
+            let interiors = polygon.interiors().iter().filter_map(snap_ring).collect();
+            Some(Polygon::new(exterior, interiors))
+        })
+        .collect()
+}
+
+fn ring_area<T: GeoFloat>(ring: &LineString<T>) -> T {
+    Polygon::new(ring.clone(), vec![]).signed_area()
+}
+
+/// Whether every polygon of `multi_polygon` is convex, with some area, and without holes.
+fn is_convex<T: GeoFloat>(multi_polygon: &MultiPolygon<T>) -> bool {
+    multi_polygon.iter().all(|polygon| {
+        polygon.interiors().is_empty()
+            && polygon.exterior().is_convex()
+            && ring_area(polygon.exterior()) != T::zero()
+    })
+}
+
+/// The intersection of each polygon of `a` with each polygon of `b` which it overlaps. The
+/// polygons of a valid `MultiPolygon` don't overlap, so neither do those of the result.
+fn convex_intersection<T: GeoFloat>(a: &MultiPolygon<T>, b: &MultiPolygon<T>) -> MultiPolygon<T> {
+    let mut polygons = Vec::new();
+    for p in a {
+        for q in b {
+            let (Some(p_rect), Some(q_rect)) = (p.bounding_rect(), q.bounding_rect()) else {
+                continue;
+            };
+            if !p_rect.intersects(&q_rect) {
+                continue;
+            }
+            let mut coords = p.exterior().0.clone();
+            // The interior of `q` is to the left of its edges if it's counter-clockwise
+            let sign = if ring_area(q.exterior()) > T::zero() {
+                T::one()
+            } else {
+                -T::one()
+            };
+            for edge in q.exterior().lines() {
+                let direction = edge.delta();
+                let distance = |c: Coord<T>| {
+                    let offset = c - edge.start;
+                    sign * (direction.x * offset.y - direction.y * offset.x)
+                };
+                coords = clip_half_plane(&coords, &distance);
+            }
+            coords.dedup();
+            let ring = LineString::new(coords);
+            if ring.0.len() >= 4 && ring_area(&ring) != T::zero() {
+                polygons.push(Polygon::new(ring, vec![]).orient(Direction::Default));
+            }
+        }
+    }
+    MultiPolygon::new(polygons)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Translate};
+
+    fn square(min: f64, max: f64) -> Polygon {
+        polygon![(x: min, y: min), (x: max, y: min), (x: max, y: max), (x: min, y: max)]
+    }
+
+    #[test]
+    fn sweep_is_boolean_ops() {
+        let (a, b) = (square(0., 2.), square(1., 3.));
+        for op in [
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ] {
+            assert_eq!(
+                a.boolean_op_with(&b, op, OverlayBackend::Sweep),
+                a.boolean_op(&b, op)
+            );
+        }
+    }
+
+    #[test]
+    fn snap_rounded() {
+        let a = square(0., 2.);
+        let b = square(1., 3.).translate(1e-10, -1e-10);
+        let backend = OverlayBackend::SnapRounded { grid_size: 1e-6 };
+        let union = a.boolean_op_with(&b, OpType::Union, backend);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.unsigned_area(), 7.);
+        // Every output coordinate is on the grid
+        for c in union.0[0].exterior().coords() {
+            assert_eq!(c.x, (c.x / 1e-6).round() * 1e-6);
+            assert_eq!(c.y, (c.y / 1e-6).round() * 1e-6);
+        }
+        // A polygon narrower than the grid disappears
+        let sliver = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1e-8), (x: 0., y: 1e-8)];
+        let difference = sliver.boolean_op_with(&a, OpType::Difference, backend);
+        assert!(difference.0.is_empty());
+        // No grid is the same as the sweep
+        let backend = OverlayBackend::SnapRounded { grid_size: 0. };
+        assert_eq!(
+            a.boolean_op_with(&b, OpType::Union, backend),
+            a.boolean_op(&b, OpType::Union)
+        );
+    }
+
+    #[test]
+    fn convex_clip() {
+        let a = square(0., 2.);
+        let triangle = polygon![(x: 1., y: -1.), (x: 4., y: 1.), (x: 1., y: 3.)];
+        let clipped =
+            a.boolean_op_with(&triangle, OpType::Intersection, OverlayBackend::ConvexClip);
+        let swept = a.intersection(&triangle);
+        assert_eq!(clipped.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), swept.unsigned_area());
+        assert!(clipped.0[0].exterior().is_ccw_convex());
+
+        // Clockwise inputs, and polygons which don't meet
+        let cw = Polygon::new(
+            triangle.exterior().clone().0.into_iter().rev().collect(),
+            vec![],
+        );
+        let far = square(10., 11.);
+        let multi_polygon = MultiPolygon::new(vec![a.clone(), far]);
+        let clipped = multi_polygon.boolean_op_with(
+            &MultiPolygon::new(vec![cw]),
+            OpType::Intersection,
+            OverlayBackend::ConvexClip,
+        );
+        assert_eq!(clipped.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), swept.unsigned_area());
+    }
+
+    #[test]
+    fn convex_clip_falls_back() {
+        let a = square(0., 2.);
+        let concave = polygon![
+            (x: 1., y: -1.),
+            (x: 3., y: -1.),
+            (x: 3., y: 3.),
+            (x: 1., y: 3.),
+            (x: 1.5, y: 1.),
+        ];
+        for (other, op) in [
+            (&concave, OpType::Intersection),
+            (&square(1., 3.), OpType::Union),
+        ] {
+            assert_eq!(
+                a.boolean_op_with(other, op, OverlayBackend::ConvexClip),
+                a.boolean_op(other, op)
+            );
+        }
+    }
+}
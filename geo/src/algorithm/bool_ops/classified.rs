@@ -0,0 +1,92 @@
+use geo_types::{MultiLineString, MultiPolygon};
+
+use super::{BooleanOps, ClassifiedBoolOp, OpType, Proc};
+use crate::{CoordNum, CoordsIter, GeoFloat, Polygon};
+
+/// The output of a [`ClassifiedBooleanOps`] operation: the polygons of the result, and their
+/// boundary split by the input which each part of it came from.
+///
+/// Together, the three sets of lines are the boundary of `polygons`, so a part of the boundary
+/// which both inputs share, such as an edge common to a parcel before and after a survey, is
+/// only in `from_both`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassifiedOverlay<T: CoordNum> {
+    /// The result of the operation, as from [`BooleanOps::boolean_op`].
+    pub polygons: MultiPolygon<T>,
+    /// The parts of the boundary which are only on the boundary of the first input, `self`.
+    pub from_first: MultiLineString<T>,
+    /// The parts of the boundary which are only on the boundary of the second input, `other`.
+    pub from_second: MultiLineString<T>,
+    /// The parts of the boundary which are on the boundaries of both inputs.
+    pub from_both: MultiLineString<T>,
+}
+
+/// Boolean Operations on geometry which also classify the boundary of the result by the input
+/// it came from, such as to find which parts of the outline of a changed area were edited, for
+/// change detection.
+///
+/// The operations are those of [`BooleanOps`], computed by the same sweep.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, ClassifiedBooleanOps, EuclideanLength};
+///
+/// let before = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let after = polygon![(x: 1., y: 0.), (x: 3., y: 0.), (x: 3., y: 2.), (x: 1., y: 2.)];
+///
+/// let overlap = before.classified_intersection(&after);
+/// assert_eq!(overlap.polygons.unsigned_area(), 2.);
+/// // Its eastern edge is from `before`, its western edge from `after`, and the rest from both
+/// assert_eq!(overlap.from_first.euclidean_length(), 2.);
+/// assert_eq!(overlap.from_second.euclidean_length(), 2.);
+/// assert_eq!(overlap.from_both.euclidean_length(), 2.);
+/// ```
+pub trait ClassifiedBooleanOps: BooleanOps {
+    /// The `op` of `self` and `other`, with the boundary of the result classified.
+    fn classified_boolean_op(&self, other: &Self, op: OpType) -> ClassifiedOverlay<Self::Scalar>;
+
+    fn classified_intersection(&self, other: &Self) -> ClassifiedOverlay<Self::Scalar> {
+        self.classified_boolean_op(other, OpType::Intersection)
+    }
+    fn classified_union(&self, other: &Self) -> ClassifiedOverlay<Self::Scalar> {
+        self.classified_boolean_op(other, OpType::Union)
+    }
+    fn classified_xor(&self, other: &Self) -> ClassifiedOverlay<Self::Scalar> {
+        self.classified_boolean_op(other, OpType::Xor)
+    }
+    fn classified_difference(&self, other: &Self) -> ClassifiedOverlay<Self::Scalar> {
+        self.classified_boolean_op(other, OpType::Difference)
+    }
+}
+
+fn overlay<T: GeoFloat>(
+    (polygons, [from_first, from_second, from_both]): (MultiPolygon<T>, [MultiLineString<T>; 3]),
+) -> ClassifiedOverlay<T> {
+    ClassifiedOverlay {
+        polygons,
+        from_first,
+        from_second,
+        from_both,
+    }
+}
+
+impl<T: GeoFloat> ClassifiedBooleanOps for Polygon<T> {
+    fn classified_boolean_op(&self, other: &Self, op: OpType) -> ClassifiedOverlay<T> {
+        let spec = ClassifiedBoolOp::from(op);
+        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
+        bop.add_polygon(self, 0);
+        bop.add_polygon(other, 1);
+        overlay(bop.sweep())
+    }
+}
+
+impl<T: GeoFloat> ClassifiedBooleanOps for MultiPolygon<T> {
+    fn classified_boolean_op(&self, other: &Self, op: OpType) -> ClassifiedOverlay<T> {
+        let spec = ClassifiedBoolOp::from(op);
+        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
+        bop.add_multi_polygon(self, 0);
+        bop.add_multi_polygon(other, 1);
+        overlay(bop.sweep())
+    }
+}
@@ -0,0 +1,578 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use super::OpType;
+use crate::algorithm::clip_to_rect::clip_half_plane;
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::kernels::{Kernel, Orientation};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, GeoNum, Line, LineString, MultiPolygon, Polygon, Rect, Triangle};
+
+/// Boolean operations on [`Rect`]s and [`Triangle`]s, with each other and with themselves.
+///
+/// These are the operations of [`BooleanOps`](super::BooleanOps), but on the few vertices of
+/// the shapes rather than on polygons made from them: an intersection clips the first shape to
+/// the edges of the second, and the other operations join up the pieces of the edges of both
+/// which bound the result. Grids and meshes, which combine many pairs of small shapes, avoid
+/// the cost of building polygons and running the general sweep for each pair.
+///
+/// As with [`BooleanOps`](super::BooleanOps), a shape without area is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, ConvexBooleanOps, Rect, Triangle};
+///
+/// let cell = Rect::new((0., 0.), (2., 2.));
+/// let neighbour = Rect::new((2., 0.), (4., 2.));
+/// let triangle = Triangle::from([(1., 1.), (3., 1.), (1., 3.)]);
+///
+/// // Cells which share an edge merge into one polygon
+/// let union = cell.union(&neighbour);
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union.unsigned_area(), 8.);
+///
+/// assert_eq!(cell.intersection(&triangle).unsigned_area(), 1.);
+/// assert_eq!(cell.difference(&triangle).unsigned_area(), 3.);
+/// assert_eq!(triangle.difference(&cell).unsigned_area(), 1.);
+/// ```
+pub trait ConvexBooleanOps<Rhs = Self> {
+    type Scalar: GeoNum;
+
+    fn boolean_op(&self, other: &Rhs, op: OpType) -> MultiPolygon<Self::Scalar>;
+    fn intersection(&self, other: &Rhs) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Intersection)
+    }
+    fn union(&self, other: &Rhs) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Union)
+    }
+    fn xor(&self, other: &Rhs) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Xor)
+    }
+    fn difference(&self, other: &Rhs) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op(other, OpType::Difference)
+    }
+}
+
+impl<T: GeoFloat> ConvexBooleanOps for Rect<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<T> {
+        if op == OpType::Intersection {
+            let min = Coord {
+                x: self.min().x.max(other.min().x),
+                y: self.min().y.max(other.min().y),
+            };
+            let max = Coord {
+                x: self.max().x.min(other.max().x),
+                y: self.max().y.min(other.max().y),
+            };
+            return if min.x < max.x && min.y < max.y {
+                single(&[
+                    min,
+                    Coord { x: max.x, y: min.y },
+                    max,
+                    Coord { x: min.x, y: max.y },
+                ])
+            } else {
+                MultiPolygon::new(vec![])
+            };
+        }
+        overlay(ccw_rect(self), ccw_rect(other), op)
+    }
+}
+
+impl<T: GeoFloat> ConvexBooleanOps<Triangle<T>> for Rect<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Triangle<T>, op: OpType) -> MultiPolygon<T> {
+        overlay(ccw_rect(self), ccw_triangle(other), op)
+    }
+}
+
+impl<T: GeoFloat> ConvexBooleanOps<Rect<T>> for Triangle<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Rect<T>, op: OpType) -> MultiPolygon<T> {
+        overlay(ccw_triangle(self), ccw_rect(other), op)
+    }
+}
+
+impl<T: GeoFloat> ConvexBooleanOps for Triangle<T> {
+    type Scalar = T;
+
+    fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<T> {
+        overlay(ccw_triangle(self), ccw_triangle(other), op)
+    }
+}
+
+/// The corners of `rect`, counter-clockwise, or `None` if it has no area.
+pub(crate) fn ccw_rect<T: GeoNum>(rect: &Rect<T>) -> Option<[Coord<T>; 4]> {
+    let (min, max) = (rect.min(), rect.max());
+    (min.x != max.x && min.y != max.y).then_some([
+        min,
+        Coord { x: max.x, y: min.y },
+        max,
+        Coord { x: min.x, y: max.y },
+    ])
+}
+
+/// The vertices of `triangle`, counter-clockwise, or `None` if it has no area.
+pub(crate) fn ccw_triangle<T: GeoNum>(triangle: &Triangle<T>) -> Option<[Coord<T>; 3]> {
+    let [a, b, c] = triangle.to_array();
+    match T::Ker::orient2d(a, b, c) {
+        Orientation::CounterClockwise => Some([a, b, c]),
+        Orientation::Clockwise => Some([a, c, b]),
+        Orientation::Collinear => None,
+    }
+}
+
+/// Whether the convex polygons with counter-clockwise vertices `a` and `b` have no points,
+/// including those of their boundaries, in common: whether one of their edges has all the
+/// vertices of the other polygon strictly outside it.
+pub(crate) fn separated<T: GeoNum>(a: &[Coord<T>], b: &[Coord<T>]) -> bool {
+    let outside = |ring: &[Coord<T>], others: &[Coord<T>]| {
+        lines(ring).any(|line| {
+            others
+                .iter()
+                .all(|&c| T::Ker::orient2d(line.start, line.end, c) == Orientation::Clockwise)
+        })
+    };
+    outside(a, b) || outside(b, a)
+}
+
+/// Whether every vertex of `inner` is within the convex polygon with counter-clockwise
+/// vertices `outer`, and so the whole of `inner` is. With `strictly`, they must be within its
+/// interior.
+pub(crate) fn within<T: GeoNum>(inner: &[Coord<T>], outer: &[Coord<T>], strictly: bool) -> bool {
+    inner.iter().all(|&c| {
+        lines(outer).all(|line| match T::Ker::orient2d(line.start, line.end, c) {
+            Orientation::CounterClockwise => true,
+            Orientation::Collinear => !strictly,
+            Orientation::Clockwise => false,
+        })
+    })
+}
+
+fn lines<T: GeoNum>(ring: &[Coord<T>]) -> impl Iterator<Item = Line<T>> + '_ {
+    (0..ring.len()).map(move |i| Line::new(ring[i], ring[(i + 1) % ring.len()]))
+}
+
+fn polygon<T: GeoFloat>(ring: &[Coord<T>]) -> Polygon<T> {
+    Polygon::new(LineString::new(ring.to_vec()), vec![])
+}
+
+fn single<T: GeoFloat>(ring: &[Coord<T>]) -> MultiPolygon<T> {
+    MultiPolygon::new(vec![polygon(ring)])
+}
+
+fn overlay<T: GeoFloat, A, B>(a: Option<A>, b: Option<B>, op: OpType) -> MultiPolygon<T>
+where
+    A: AsRef<[Coord<T>]>,
+    B: AsRef<[Coord<T>]>,
+{
+    let (a, b) = match (&a, &b) {
+        (Some(a), Some(b)) => (a.as_ref(), b.as_ref()),
+        (Some(a), None) if op != OpType::Intersection => return single(a.as_ref()),
+        (None, Some(b)) if matches!(op, OpType::Union | OpType::Xor) => return single(b.as_ref()),
+        _ => return MultiPolygon::new(vec![]),
+    };
+    if separated(a, b) {
+        return match op {
+            OpType::Intersection => MultiPolygon::new(vec![]),
+            OpType::Difference => single(a),
+            OpType::Union | OpType::Xor => MultiPolygon::new(vec![polygon(a), polygon(b)]),
+        };
+    }
+    if op == OpType::Intersection {
+        return clip(a, b);
+    }
+    // One within the interior of the other, which is the only way to make a hole
+    if within(b, a, true) {
+        return match op {
+            OpType::Union => single(a),
+            _ => with_hole(a, b),
+        };
+    }
+    if within(a, b, true) {
+        return match op {
+            OpType::Union => single(b),
+            OpType::Difference => MultiPolygon::new(vec![]),
+            _ => with_hole(b, a),
+        };
+    }
+    if op == OpType::Union && within(b, a, false) {
+        return single(a);
+    }
+    if op == OpType::Union && within(a, b, false) {
+        return single(b);
+    }
+    join(a, b, op)
+}
+
+fn with_hole<T: GeoFloat>(exterior: &[Coord<T>], hole: &[Coord<T>]) -> MultiPolygon<T> {
+    let hole = LineString::new(hole.iter().rev().copied().collect());
+    MultiPolygon::new(vec![Polygon::new(
+        LineString::new(exterior.to_vec()),
+        vec![hole],
+    )])
+}
+
+/// The intersection of `a` and `b`, by clipping `a` to each of the edges of `b`.
+fn clip<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>]) -> MultiPolygon<T> {
+    let mut coords = a.to_vec();
+    coords.push(a[0]);
+    for edge in lines(b) {
+        let direction = edge.delta();
+        let distance = |c: Coord<T>| {
+            let offset = c - edge.start;
+            direction.x * offset.y - direction.y * offset.x
+        };
+        coords = clip_half_plane(&coords, &distance);
+    }
+    coords.pop();
+    coords.dedup();
+    let polygons = simplify(coords).map(|ring| polygon(&ring));
+    MultiPolygon::new(polygons.into_iter().collect())
+}
+
+/// Where a piece of the edge of one polygon is relative to the other polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Inside,
+    Outside,
+    /// On an edge of the other polygon, going the same way, so that both polygons are on the
+    /// same side of it
+    Same,
+    /// On an edge of the other polygon, going the opposite way
+    Opposite,
+}
+
+/// The result of `op` on `a` and `b`, whose interiors overlap, or which touch, found by
+/// splitting their edges where they meet, keeping the pieces which bound the result, and
+/// joining those up into rings.
+fn join<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>], op: OpType) -> MultiPolygon<T> {
+    let (a_pieces, b_pieces) = split_edges(a, b);
+    let mut edges = Vec::new();
+    for (pieces, other, is_a) in [(&a_pieces, b, true), (&b_pieces, a, false)] {
+        for &(start, end) in pieces {
+            let forward = match (op, side(start, end, other)) {
+                (OpType::Intersection, Side::Inside) => Some(true),
+                (OpType::Intersection | OpType::Union, Side::Same) if is_a => Some(true),
+                (OpType::Union | OpType::Xor, Side::Outside) => Some(true),
+                (OpType::Xor, Side::Inside) => Some(false),
+                (OpType::Difference, Side::Outside | Side::Opposite) if is_a => Some(true),
+                (OpType::Difference, Side::Inside) if !is_a => Some(false),
+                _ => None,
+            };
+            match forward {
+                Some(true) => edges.push((start, end)),
+                Some(false) => edges.push((end, start)),
+                None => {}
+            }
+        }
+    }
+    assemble(rings(edges))
+}
+
+/// The edges of `a` and of `b`, split at every point where they meet.
+#[allow(clippy::type_complexity)]
+fn split_edges<T: GeoFloat>(
+    a: &[Coord<T>],
+    b: &[Coord<T>],
+) -> (Vec<(Coord<T>, Coord<T>)>, Vec<(Coord<T>, Coord<T>)>) {
+    let mut a_points = vec![Vec::new(); a.len()];
+    let mut b_points = vec![Vec::new(); b.len()];
+    for (i, a_line) in lines(a).enumerate() {
+        for (j, b_line) in lines(b).enumerate() {
+            // The points are computed once for both edges, so that the pieces of each meet
+            // exactly
+            match line_intersection(a_line, b_line) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    a_points[i].push(intersection);
+                    b_points[j].push(intersection);
+                }
+                Some(LineIntersection::Collinear { intersection }) => {
+                    a_points[i].extend([intersection.start, intersection.end]);
+                    b_points[j].extend([intersection.start, intersection.end]);
+                }
+                None => {}
+            }
+        }
+    }
+    (split(a, a_points), split(b, b_points))
+}
+
+fn split<T: GeoFloat>(
+    ring: &[Coord<T>],
+    mut points: Vec<Vec<Coord<T>>>,
+) -> Vec<(Coord<T>, Coord<T>)> {
+    let mut pieces = Vec::new();
+    for (line, points) in lines(ring).zip(&mut points) {
+        let along =
+            |c: &Coord<T>| (*c - line.start).x * line.dx() + (*c - line.start).y * line.dy();
+        points.retain(|&c| c != line.start && c != line.end);
+        points.sort_by(|p, q| along(p).partial_cmp(&along(q)).unwrap_or(Ordering::Equal));
+        points.dedup();
+        let mut start = line.start;
+        for &point in points.iter().chain([&line.end]) {
+            pieces.push((start, point));
+            start = point;
+        }
+    }
+    pieces
+}
+
+/// Where the piece of an edge from `start` to `end` is relative to the polygon with
+/// counter-clockwise vertices `ring`, whose edges it doesn't cross.
+fn side<T: GeoFloat>(start: Coord<T>, end: Coord<T>, ring: &[Coord<T>]) -> Side {
+    let two = T::one() + T::one();
+    let mid = (start + end) / two;
+    for line in lines(ring) {
+        let collinear = |c| T::Ker::orient2d(line.start, line.end, c) == Orientation::Collinear;
+        if collinear(start) && collinear(end) {
+            // The polygon is all on one side of the line of its edge, so the piece is either on
+            // the edge or outside
+            let delta = line.delta();
+            let along = (mid - line.start).x * delta.x + (mid - line.start).y * delta.y;
+            if along <= T::zero() || along >= delta.x * delta.x + delta.y * delta.y {
+                return Side::Outside;
+            }
+            let direction = end - start;
+            return if direction.x * delta.x + direction.y * delta.y > T::zero() {
+                Side::Same
+            } else {
+                Side::Opposite
+            };
+        }
+    }
+    if lines(ring).any(|line| T::Ker::orient2d(line.start, line.end, mid) == Orientation::Clockwise)
+    {
+        Side::Outside
+    } else {
+        Side::Inside
+    }
+}
+
+/// Join up `edges` into rings, split where they touch themselves.
+///
+/// Where several edges leave a point, the ring turns as far left as it can, so that the
+/// pieces of the result which only touch there get rings of their own, and the only rings
+/// which touch themselves are those around holes.
+fn rings<T: GeoFloat>(mut edges: Vec<(Coord<T>, Coord<T>)>) -> Vec<Vec<Coord<T>>> {
+    let mut rings = Vec::new();
+    while let Some((start, mut end)) = edges.pop() {
+        let mut ring = vec![start];
+        let mut previous = start;
+        while end != start {
+            ring.push(end);
+            let incoming = end - previous;
+            let turn = |edge: &(Coord<T>, Coord<T>)| {
+                let outgoing = edge.1 - edge.0;
+                let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+                let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+                cross.atan2(dot)
+            };
+            let next = (0..edges.len())
+                .filter(|&i| edges[i].0 == end)
+                .max_by(|&i, &j| {
+                    turn(&edges[i])
+                        .partial_cmp(&turn(&edges[j]))
+                        .unwrap_or(Ordering::Equal)
+                });
+            let Some(next) = next else {
+                break;
+            };
+            previous = end;
+            end = edges.swap_remove(next).1;
+        }
+        split_ring(ring, &mut rings);
+    }
+    rings
+}
+
+fn split_ring<T: GeoFloat>(ring: Vec<Coord<T>>, rings: &mut Vec<Vec<Coord<T>>>) {
+    for i in 0..ring.len() {
+        if let Some(j) = (i + 1..ring.len()).find(|&j| ring[j] == ring[i]) {
+            let inner = ring[i..j].to_vec();
+            let outer = ring[..i].iter().chain(&ring[j..]).copied().collect();
+            split_ring(inner, rings);
+            split_ring(outer, rings);
+            return;
+        }
+    }
+    rings.extend(simplify(ring));
+}
+
+/// `ring` without the vertices which are in line with their neighbours, or `None` if nothing
+/// is left.
+fn simplify<T: GeoNum>(mut ring: Vec<Coord<T>>) -> Option<Vec<Coord<T>>> {
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let n = ring.len();
+        let (prev, next) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+        if T::Ker::orient2d(prev, ring[i], next) == Orientation::Collinear {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    (ring.len() >= 3).then_some(ring)
+}
+
+/// Make polygons of the counter-clockwise `rings`, with the clockwise ones as their holes.
+fn assemble<T: GeoFloat>(rings: Vec<Vec<Coord<T>>>) -> MultiPolygon<T> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .partition(|ring| twice_area(ring) > T::zero());
+    let mut polygons: Vec<Polygon<T>> = exteriors.iter().map(|ring| polygon(ring)).collect();
+    for hole in holes {
+        let outer = polygons.iter().position(|polygon| {
+            hole.iter()
+                .any(|c| polygon.coordinate_position(c) == CoordPos::Inside)
+        });
+        if let Some(outer) = outer {
+            polygons[outer].interiors_push(LineString::new(hole));
+        }
+    }
+    MultiPolygon::new(polygons)
+}
+
+fn twice_area<T: GeoFloat>(ring: &[Coord<T>]) -> T {
+    lines(ring).fold(T::zero(), |area, line| area + line.determinant())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Area, BooleanOps, Contains, Intersects, Relate, Winding};
+
+    /// Check that `op` gives the same result as the general overlay on polygons.
+    fn check<A, B>(a: &A, b: &B, a_polygon: Polygon, b_polygon: Polygon)
+    where
+        A: ConvexBooleanOps<B, Scalar = f64>,
+    {
+        for op in [
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ] {
+            let fast = a.boolean_op(b, op);
+            let general = a_polygon.boolean_op(&b_polygon, op);
+            assert_relative_eq!(
+                fast.unsigned_area(),
+                general.unsigned_area(),
+                epsilon = 1e-9
+            );
+            assert_eq!(
+                fast.0.len(),
+                general.0.len(),
+                "{op:?}: {fast:?} {general:?}"
+            );
+            // The same shapes, to within rounding
+            assert!(
+                fast.xor(&general).unsigned_area() < 1e-9,
+                "{op:?}: {fast:?}"
+            );
+            for polygon in &fast {
+                assert!(polygon.exterior().is_ccw());
+            }
+        }
+    }
+
+    #[test]
+    fn rects() {
+        let cell = Rect::new((0., 0.), (2., 2.));
+        for other in [
+            // Overlapping a corner, crossing, sharing an edge, part of an edge, or a corner
+            Rect::new((1., 1.), (3., 3.)),
+            Rect::new((-1., 0.5), (3., 1.5)),
+            Rect::new((2., 0.), (4., 2.)),
+            Rect::new((2., 1.), (4., 3.)),
+            Rect::new((2., 2.), (3., 3.)),
+            // Within, touching the edge or not, the same, and apart
+            Rect::new((0.5, 0.5), (1.5, 1.5)),
+            Rect::new((0., 0.5), (1., 1.5)),
+            Rect::new((0., 0.), (2., 2.)),
+            Rect::new((5., 5.), (6., 6.)),
+        ] {
+            check(&cell, &other, cell.to_polygon(), other.to_polygon());
+            check(&other, &cell, other.to_polygon(), cell.to_polygon());
+        }
+    }
+
+    #[test]
+    fn rects_and_triangles() {
+        let cell = Rect::new((0., 0.), (2., 2.));
+        for triangle in [
+            Triangle::from([(1., 1.), (3., 1.), (1., 3.)]),
+            // Clockwise, and through two corners
+            Triangle::from([(-1., 1.), (1., 3.), (3., 1.)]),
+            Triangle::from([(0., 0.), (2., 2.), (2., 0.)]),
+            Triangle::from([(0.5, 0.5), (1.5, 0.5), (1., 1.5)]),
+            // Within, touching the edges at its corners
+            Triangle::from([(0., 0.), (2., 1.), (1., 2.)]),
+            // Touching at a corner, from the inside
+            Triangle::from([(1., 2.), (0.5, 1.), (1.5, 1.)]),
+            Triangle::from([(-2., 0.), (2., -4.), (6., 0.)]),
+            Triangle::from([(-10., -10.), (10., -10.), (0., 10.)]),
+            Triangle::from([(5., 5.), (6., 5.), (5., 6.)]),
+        ] {
+            let (a, b) = (cell.to_polygon(), triangle.to_polygon());
+            check(&cell, &triangle, a.clone(), b.clone());
+            check(&triangle, &cell, b.clone(), a.clone());
+            check(&triangle, &triangle, b.clone(), b.clone());
+            let other = Triangle::from([(0., 0.), (2., 0.), (1., 2.)]);
+            check(&triangle, &other, b, other.to_polygon());
+        }
+    }
+
+    #[test]
+    fn predicates() {
+        let rects = [
+            Rect::new((0., 0.), (2., 2.)),
+            Rect::new((2., 2.), (3., 3.)),
+            Rect::new((0.5, 0.), (1.5, 1.)),
+            Rect::new((-1., -1.), (4., 4.)),
+            Rect::new((5., 0.), (6., 1.)),
+        ];
+        let triangles = [
+            Triangle::from([(1., 1.), (3., 1.), (1., 3.)]),
+            Triangle::from([(0., 0.), (2., 0.), (0., 2.)]),
+            Triangle::from([(2., 2.), (1., 4.), (3., 4.)]),
+            Triangle::from([(-10., -10.), (10., -10.), (0., 10.)]),
+            Triangle::from([(3., 0.), (4., 0.), (3., 1.)]),
+            // Without area
+            Triangle::from([(0., 0.), (1., 1.), (2., 2.)]),
+        ];
+        for rect in &rects {
+            for triangle in &triangles {
+                let matrix = rect.relate(triangle);
+                assert_eq!(rect.intersects(triangle), matrix.is_intersects());
+                assert_eq!(rect.contains(triangle), matrix.is_contains());
+                assert_eq!(triangle.contains(rect), matrix.is_within());
+            }
+        }
+        for a in &triangles {
+            for b in &triangles {
+                let matrix = a.relate(b);
+                assert_eq!(a.intersects(b), matrix.is_intersects());
+                assert_eq!(a.contains(b), matrix.is_contains());
+            }
+        }
+    }
+
+    #[test]
+    fn without_area() {
+        let cell = Rect::new((0., 0.), (2., 2.));
+        let flat = Triangle::from([(0., 0.), (1., 1.), (2., 2.)]);
+        assert!(cell.intersection(&flat).0.is_empty());
+        assert_eq!(cell.difference(&flat), single(&ccw_rect(&cell).unwrap()));
+        assert!(flat.difference(&cell).0.is_empty());
+        assert_eq!(flat.union(&cell).unsigned_area(), 4.);
+        let line = Rect::new((0., 1.), (4., 1.));
+        assert!(line.union(&line).0.is_empty());
+    }
+}
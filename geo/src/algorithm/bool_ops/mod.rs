@@ -1,6 +1,7 @@
 use geo_types::{MultiLineString, MultiPolygon};
 
-use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
+use crate::error::check_finite;
+use crate::{CoordsIter, Error, GeoFloat, GeoNum, Polygon};
 
 /// Boolean Operations on geometry.
 ///
@@ -39,6 +40,69 @@ pub trait BooleanOps: Sized {
         self.boolean_op(other, OpType::Difference)
     }
 
+    /// Perform a boolean operation, returning an error instead of panicking on bad input.
+    ///
+    /// A coordinate which isn't finite is reported as [`Error::NonFiniteCoordinate`]. For
+    /// [`Polygon`] and [`MultiPolygon`], a sweep which fails on other invalid input, such as
+    /// rings which cross each other, is reported as [`Error::BooleanOpFailed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, BooleanOps, Error};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    /// let nan = polygon![(x: 1., y: 1.), (x: f64::NAN, y: 1.), (x: 3., y: 3.)];
+    ///
+    /// assert_eq!(square.try_union(&square).unwrap().0.len(), 1);
+    /// assert!(matches!(
+    ///     square.try_union(&nan),
+    ///     Err(Error::NonFiniteCoordinate)
+    /// ));
+    /// ```
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<<Self as BooleanOps>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as BooleanOps>::Scalar>,
+    {
+        check_finite(self)?;
+        check_finite(other)?;
+        Ok(self.boolean_op(other, op))
+    }
+    fn try_intersection(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<<Self as BooleanOps>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as BooleanOps>::Scalar>,
+    {
+        self.try_boolean_op(other, OpType::Intersection)
+    }
+    fn try_union(&self, other: &Self) -> Result<MultiPolygon<<Self as BooleanOps>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as BooleanOps>::Scalar>,
+    {
+        self.try_boolean_op(other, OpType::Union)
+    }
+    fn try_xor(&self, other: &Self) -> Result<MultiPolygon<<Self as BooleanOps>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as BooleanOps>::Scalar>,
+    {
+        self.try_boolean_op(other, OpType::Xor)
+    }
+    fn try_difference(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<<Self as BooleanOps>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as BooleanOps>::Scalar>,
+    {
+        self.try_boolean_op(other, OpType::Difference)
+    }
+
     /// Clip a 1-D geometry with self.
     ///
     /// Returns the portion of `ls` that lies within `self` (known as the set-theoeretic
@@ -58,15 +122,39 @@ pub enum OpType {
     Xor,
 }
 
+/// The procedure for the `op` of the polygons `a` and `b`.
+fn polygons_proc<T: GeoFloat>(a: &Polygon<T>, b: &Polygon<T>, op: OpType) -> Proc<T, BoolOp<T>> {
+    let mut bop = Proc::new(BoolOp::from(op), a.coords_count() + b.coords_count());
+    bop.add_polygon(a, 0);
+    bop.add_polygon(b, 1);
+    bop
+}
+
+/// The procedure for the `op` of the multi-polygons `a` and `b`.
+fn multi_polygons_proc<T: GeoFloat>(
+    a: &MultiPolygon<T>,
+    b: &MultiPolygon<T>,
+    op: OpType,
+) -> Proc<T, BoolOp<T>> {
+    let mut bop = Proc::new(BoolOp::from(op), a.coords_count() + b.coords_count());
+    bop.add_multi_polygon(a, 0);
+    bop.add_multi_polygon(b, 1);
+    bop
+}
+
 impl<T: GeoFloat> BooleanOps for Polygon<T> {
     type Scalar = T;
 
     fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar> {
-        let spec = BoolOp::from(op);
-        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
-        bop.add_polygon(self, 0);
-        bop.add_polygon(other, 1);
-        bop.sweep()
+        polygons_proc(self, other, op).sweep()
+    }
+
+    fn try_boolean_op(&self, other: &Self, op: OpType) -> Result<MultiPolygon<T>, Error> {
+        check_finite(self)?;
+        check_finite(other)?;
+        polygons_proc(self, other, op)
+            .try_sweep()
+            .map_err(|_| Error::BooleanOpFailed)
     }
 
     fn clip(
@@ -78,7 +166,7 @@ impl<T: GeoFloat> BooleanOps for Polygon<T> {
         let mut bop = Proc::new(spec, self.coords_count() + ls.coords_count());
         bop.add_polygon(self, 0);
         ls.0.iter().enumerate().for_each(|(idx, l)| {
-            bop.add_line_string(l, idx + 1);
+            bop.add_line_string(&l.0, idx + 1);
         });
         bop.sweep()
     }
@@ -87,11 +175,15 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
     type Scalar = T;
 
     fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar> {
-        let spec = BoolOp::from(op);
-        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
-        bop.add_multi_polygon(self, 0);
-        bop.add_multi_polygon(other, 1);
-        bop.sweep()
+        multi_polygons_proc(self, other, op).sweep()
+    }
+
+    fn try_boolean_op(&self, other: &Self, op: OpType) -> Result<MultiPolygon<T>, Error> {
+        check_finite(self)?;
+        check_finite(other)?;
+        multi_polygons_proc(self, other, op)
+            .try_sweep()
+            .map_err(|_| Error::BooleanOpFailed)
     }
 
     fn clip(
@@ -103,7 +195,7 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
         let mut bop = Proc::new(spec, self.coords_count() + ls.coords_count());
         bop.add_multi_polygon(self, 0);
         ls.0.iter().enumerate().for_each(|(idx, l)| {
-            bop.add_line_string(l, idx + 1);
+            bop.add_line_string(&l.0, idx + 1);
         });
         bop.sweep()
     }
@@ -115,6 +207,18 @@ mod assembly;
 use assembly::*;
 mod spec;
 use spec::*;
+mod backend;
+pub use backend::{BooleanOpsWith, OverlayBackend};
+mod classified;
+pub use classified::{ClassifiedBooleanOps, ClassifiedOverlay};
+mod convex;
+pub use convex::ConvexBooleanOps;
+pub(crate) use convex::{ccw_rect, ccw_triangle, separated, within};
+
+#[cfg(feature = "bumpalo")]
+mod arena;
+#[cfg(feature = "bumpalo")]
+pub use arena::ArenaBooleanOps;
 
 #[cfg(test)]
 mod tests;
@@ -1,9 +1,12 @@
-use std::{cell::Cell, cmp::Ordering, fmt::Debug};
+use alloc::{format, vec::Vec};
+use core::{cell::Cell, cmp::Ordering, fmt::Debug};
 
 use super::{MultiPolygon, Spec};
+#[cfg(feature = "bumpalo")]
+use crate::view::PolygonRef;
 use crate::{
-    sweep::{compare_crossings, Cross, Crossing, CrossingsIter, LineOrPoint, SweepPoint},
-    CoordsIter, GeoFloat as Float, LineString, Polygon,
+    sweep::{Cross, Crossing, CrossingsIter, LineOrPoint, SweepError, SweepPoint},
+    Coord, GeoFloat as Float, Line, Polygon,
 };
 
 /// The state for computing the output shape of a collection of shapes (with
@@ -32,17 +35,27 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
     /// should be either 0 or 1 depending on whether it is the first or second
     /// input.
     pub(crate) fn add_polygon(&mut self, poly: &Polygon<T>, idx: usize) {
-        self.add_closed_ring(poly.exterior(), idx, false);
+        self.add_closed_ring(&poly.exterior().0, idx, false);
         for hole in poly.interiors() {
-            self.add_closed_ring(hole, idx, true);
+            self.add_closed_ring(&hole.0, idx, true);
         }
     }
 
-    /// Adds `ls` to the procedure, with the shape index as `idx`. `idx` should
-    /// be either 0 or 1 depending on whether it is the first or second input.
-    pub(crate) fn add_line_string(&mut self, ls: &LineString<T>, idx: usize) {
-        for line in ls.lines() {
-            let lp: LineOrPoint<_> = line.into();
+    /// Adds `poly` to the procedure, as for [`Self::add_polygon`].
+    #[cfg(feature = "bumpalo")]
+    pub(crate) fn add_polygon_ref(&mut self, poly: &PolygonRef<T>, idx: usize) {
+        self.add_closed_ring(poly.exterior().0, idx, false);
+        for hole in poly.interiors() {
+            self.add_closed_ring(hole.0, idx, true);
+        }
+    }
+
+    /// Adds the line string with coordinates `ls` to the procedure, with the
+    /// shape index as `idx`. `idx` should be either 0 or 1 depending on
+    /// whether it is the first or second input.
+    pub(crate) fn add_line_string(&mut self, ls: &[Coord<T>], idx: usize) {
+        for coords in ls.windows(2) {
+            let lp: LineOrPoint<_> = Line::new(coords[0], coords[1]).into();
             if !lp.is_line() {
                 continue;
             }
@@ -63,9 +76,9 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
     /// should be either 0 or 1 depending on whether it is the first or
     /// second input. `_is_hole` is not used right now; remove it once we fully
     /// handle floating-point issues.
-    fn add_closed_ring(&mut self, ring: &LineString<T>, idx: usize, _is_hole: bool) {
-        assert!(ring.is_closed());
-        if ring.coords_count() <= 3 {
+    fn add_closed_ring(&mut self, ring: &[Coord<T>], idx: usize, _is_hole: bool) {
+        assert!(ring.first() == ring.last());
+        if ring.len() <= 3 {
             return;
         }
 
@@ -74,7 +87,16 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
 
     /// Sweeps across the edges, splits them, then passes the resulting regions
     /// and edges to the spec, to compute the result shape.
-    pub fn sweep(mut self) -> S::Output {
+    ///
+    /// Panics if the sweep fails; see [`Self::try_sweep`].
+    pub fn sweep(self) -> S::Output {
+        self.try_sweep()
+            .unwrap_or_else(|_| panic!("unable to compare active segments!"))
+    }
+
+    /// Sweeps across the edges as for [`Self::sweep`], returning an error if
+    /// floating-point error leaves the edges too inconsistent to order.
+    pub fn try_sweep(mut self) -> Result<S::Output, SweepError> {
         let mut iter = CrossingsIter::from_iter(self.edges.iter());
 
         // Iterate through the intersection points (including end points).
@@ -87,7 +109,7 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
             // Sort crossings at `pt`. This means the end of segments will be ordered before
             // the start of any segments, and start/end segments will be ordered from bottom
             // to top.
-            iter.intersections_mut().sort_unstable_by(compare_crossings);
+            iter.sort_intersections()?;
 
             // Trace the crossings for debugging.
             for (idx, it) in iter.intersections().iter().enumerate() {
@@ -157,7 +179,7 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
 
             // Get the region of the previous edge in the output, or use the "infinity
             // point".
-            let prev = iter.prev_active(&botmost_start_segment);
+            let prev = iter.prev_active(&botmost_start_segment)?;
             trace!(
                 "prev-active(bot-most): {prev:?}",
                 prev = prev.map(|(_, p)| p.geom)
@@ -205,6 +227,7 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                 idx += 1;
             }
         }
+        iter.check()?;
         self.spec.finish()
     }
 }
@@ -259,8 +282,8 @@ impl<T: Float, S: Spec<T>> Edge<T, S> {
     }
 }
 
-impl<T: Float, S: Spec<T>> std::fmt::Debug for Edge<T, S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: Float, S: Spec<T>> core::fmt::Debug for Edge<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let line = self.geom.line();
         f.debug_struct("Edge")
             .field(
@@ -1,8 +1,11 @@
+use core::fmt::Debug;
 use geo_types::{MultiLineString, MultiPolygon};
-use std::fmt::Debug;
 
 use super::*;
-use crate::{sweep::LineOrPoint, GeoFloat, OpType};
+use crate::{
+    sweep::{LineOrPoint, SweepError},
+    GeoFloat, OpType,
+};
 
 /// A trait to compute the final shape of a collection of non-intersecting edges
 /// by tracking the regions formed by those edges.
@@ -22,8 +25,9 @@ pub trait Spec<T: GeoFloat> {
     /// before and after crossing `geom`. `idx` is the index of the shape that
     /// `geom` belongs to.
     fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize);
-    /// Finishes the assembly, producing the output value.
-    fn finish(self) -> Self::Output;
+    /// Finishes the assembly, producing the output value, or an error if the
+    /// edges can't be assembled.
+    fn finish(self) -> Result<Self::Output, SweepError>;
 }
 
 /// State for a boolean operation on two shapes.
@@ -41,6 +45,15 @@ impl<T: GeoFloat> From<OpType> for BoolOp<T> {
         }
     }
 }
+
+impl<T: GeoFloat> BoolOp<T> {
+    /// Returns the assembly of the output shape, to be finished by the caller.
+    #[cfg(feature = "bumpalo")]
+    pub fn into_assembly(self) -> RegionAssembly<T> {
+        self.assembly
+    }
+}
+
 impl<T: GeoFloat> Spec<T> for BoolOp<T> {
     type Region = Region;
     type Output = MultiPolygon<T>;
@@ -67,11 +80,62 @@ impl<T: GeoFloat> Spec<T> for BoolOp<T> {
         }
     }
 
-    fn finish(self) -> Self::Output {
+    fn finish(self) -> Result<Self::Output, SweepError> {
         self.assembly.finish()
     }
 }
 
+/// State for a boolean operation on two shapes, which also assembles the boundary of the output
+/// shape, split by the shapes its edges came from.
+pub struct ClassifiedBoolOp<T: GeoFloat> {
+    /// The operation, and the assembly of the output shape.
+    op: BoolOp<T>,
+    /// The assemblies of the boundary edges from only the first shape, from only the second, and
+    /// from both.
+    lines: [LineAssembly<T>; 3],
+}
+impl<T: GeoFloat> From<OpType> for ClassifiedBoolOp<T> {
+    fn from(ty: OpType) -> Self {
+        Self {
+            op: BoolOp::from(ty),
+            lines: Default::default(),
+        }
+    }
+}
+impl<T: GeoFloat> Spec<T> for ClassifiedBoolOp<T> {
+    type Region = Region;
+    type Output = (MultiPolygon<T>, [MultiLineString<T>; 3]);
+
+    fn infinity(&self) -> Self::Region {
+        self.op.infinity()
+    }
+
+    fn cross(&self, prev_region: Self::Region, idx: usize) -> Self::Region {
+        self.op.cross(prev_region, idx)
+    }
+
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize) {
+        if regions[0].is_ty(self.op.ty) ^ regions[1].is_ty(self.op.ty) {
+            // `geom` stands for a group of overlapping edges, which are from a shape if crossing
+            // them changes whether the region is inside it.
+            let from_first = regions[0].is_first ^ regions[1].is_first;
+            let from_second = regions[0].is_second ^ regions[1].is_second;
+            let origin = match (from_first, from_second) {
+                (true, false) => 0,
+                (false, true) => 1,
+                _ => 2,
+            };
+            self.lines[origin].add_edge(geom, 0);
+        }
+        self.op.output(regions, geom, idx);
+    }
+
+    fn finish(self) -> Result<Self::Output, SweepError> {
+        let lines = self.lines.map(|lines| MultiLineString::new(lines.finish()));
+        Ok((self.op.finish()?, lines))
+    }
+}
+
 /// State for clipping lines by some shape.
 pub struct ClipOp<T: GeoFloat> {
     /// Whether the clipping shape should be inverted (lines outside the
@@ -90,6 +154,12 @@ impl<T: GeoFloat> ClipOp<T> {
             assembly: Default::default(),
         }
     }
+
+    /// Returns the assembly of the output lines, to be finished by the caller.
+    #[cfg(feature = "bumpalo")]
+    pub fn into_assembly(self) -> LineAssembly<T> {
+        self.assembly
+    }
 }
 
 impl<T: GeoFloat> Spec<T> for ClipOp<T> {
@@ -118,8 +188,8 @@ impl<T: GeoFloat> Spec<T> for ClipOp<T> {
         }
     }
 
-    fn finish(self) -> Self::Output {
-        MultiLineString::new(self.assembly.finish())
+    fn finish(self) -> Result<Self::Output, SweepError> {
+        Ok(MultiLineString::new(self.assembly.finish()))
     }
 }
 
@@ -131,8 +201,8 @@ pub struct Region {
     /// Whether this region is inside the second shape.
     is_second: bool,
 }
-impl std::fmt::Debug for Region {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Region {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "[{f}{s}]",
@@ -26,7 +26,7 @@ pub(super) fn init_log() {
 }
 
 use super::*;
-type Result<T> = std::result::Result<T, Box<dyn Error>>;
+type Result<T> = core::result::Result<T, Box<dyn Error>>;
 
 fn check_sweep<T: GeoFloat + FromStr + Default + Display>(
     wkt1: &str,
@@ -230,3 +230,79 @@ fn test_issue_buffer_box() {
     let wkt2 = "MULTIPOLYGON(((-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-153.80312445248086 153.2197777241044,-266.86521779027504 149.53568721641966,-164.93595896333647 149.53568721641966)))";
     check_sweep::<f64>(wkt1, wkt2, OpType::Union).unwrap();
 }
+
+#[test]
+fn test_try_boolean_ops() {
+    use crate::{polygon, BooleanOps, MapCoords};
+    let square: Polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+    let shifted = square.map_coords(|coord| coord + (1., 1.).into());
+    assert_eq!(
+        square.try_intersection(&shifted).unwrap(),
+        square.intersection(&shifted)
+    );
+    assert_eq!(
+        square.try_difference(&shifted).unwrap(),
+        square.difference(&shifted)
+    );
+
+    let infinite = polygon![(x: 0., y: 0.), (x: f64::INFINITY, y: 0.), (x: 0., y: 1.)];
+    assert!(matches!(
+        MultiPolygon::new(vec![square]).try_xor(&MultiPolygon::new(vec![infinite])),
+        Err(crate::Error::NonFiniteCoordinate)
+    ));
+}
+
+#[test]
+fn test_try_boolean_op_failed() {
+    use crate::BooleanOps;
+    init_log();
+    // The inputs of `test_issue_buffer_box`, which the sweep can't order
+    let wkt1 = "MULTIPOLYGON(((-164.93595896333647 152.85701803397086,-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-51.873865625542294 255.14903655104297,-153.80312445248086 255.14903655104297,-266.86521779027504 251.46494604335822,-266.86521779027504 149.53568721641966,-164.93595896333647 152.85701803397086)))";
+    let wkt2 = "MULTIPOLYGON(((-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-153.80312445248086 153.2197777241044,-266.86521779027504 149.53568721641966,-164.93595896333647 149.53568721641966)))";
+    let poly1 = MultiPolygon::<f64>::try_from_wkt_str(wkt1).unwrap();
+    let poly2 = MultiPolygon::<f64>::try_from_wkt_str(wkt2).unwrap();
+    assert!(matches!(
+        poly1.try_union(&poly2),
+        Err(crate::Error::BooleanOpFailed)
+    ));
+}
+
+#[test]
+fn test_classified_boolean_ops() {
+    use crate::{polygon, BooleanOps, EuclideanLength};
+    let square: Polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    // Sharing the square's top edge, and poking out of its bottom
+    let other: Polygon = polygon![(x: 1., y: -1.), (x: 3., y: -1.), (x: 3., y: 4.), (x: 1., y: 4.)];
+    let boundary_length = |polygons: &MultiPolygon| -> f64 {
+        polygons
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .map(|ring| ring.euclidean_length())
+            .sum()
+    };
+
+    for op in [
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        let overlay = square.classified_boolean_op(&other, op);
+        assert_eq!(overlay.polygons, square.boolean_op(&other, op));
+        let classified = overlay.from_first.euclidean_length()
+            + overlay.from_second.euclidean_length()
+            + overlay.from_both.euclidean_length();
+        assert_relative_eq!(classified, boundary_length(&overlay.polygons));
+    }
+
+    let union = square.classified_union(&other);
+    assert_eq!(union.from_first.euclidean_length(), 12.);
+    assert_eq!(union.from_second.euclidean_length(), 4.);
+    assert_eq!(union.from_both.euclidean_length(), 2.);
+
+    // The edges of a difference from the second input are the boundary of the part removed
+    let difference = MultiPolygon::new(vec![square]).classified_difference(&other.into());
+    assert_eq!(difference.from_first.euclidean_length(), 12.);
+    assert_eq!(difference.from_second.euclidean_length(), 8.);
+    assert!(difference.from_both.0.is_empty());
+}
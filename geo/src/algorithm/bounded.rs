@@ -0,0 +1,287 @@
+use crate::{
+    BoundingRect, Centroid, Coord, CoordNum, CoordsIter, GeoFloat, GeoNum, Intersects, MapCoords,
+    MapCoordsInPlace, Point, Rect,
+};
+
+/// A geometry together with its bounding rectangle, which is calculated once, when the geometry
+/// is wrapped, rather than on every use.
+///
+/// `Bounded` geometries can be transformed by [`MapCoords`] and [`MapCoordsInPlace`], and so by
+/// [`AffineOps`](crate::AffineOps), [`Translate`](crate::Translate),
+/// [`Rotate`](crate::Rotate), [`Scale`](crate::Scale) and [`Skew`](crate::Skew), which
+/// recalculate the rectangle as they go. Other changes to the geometry go through
+/// [`Bounded::update`], so the rectangle can't become out of date.
+///
+/// [`Intersects`] between `Bounded` geometries, and with coordinates, points and rectangles,
+/// returns `false` straight away when the rectangles don't intersect, and as an
+/// [`RTreeObject`](rstar::RTreeObject) a `Bounded` geometry can be inserted into an
+/// [`RTree`](rstar::RTree) without calculating its envelope again.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Bounded, Intersects, Rect, Translate};
+///
+/// let parcel = Bounded::new(polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ]);
+/// assert_eq!(parcel.rect(), Some(Rect::new((0., 0.), (10., 10.))));
+///
+/// let moved = parcel.translate(100., 0.);
+/// assert_eq!(moved.rect(), Some(Rect::new((100., 0.), (110., 10.))));
+///
+/// assert!(parcel.intersects(&coord! { x: 5., y: 5. }));
+/// assert!(!parcel.intersects(&moved));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bounded<G: CoordsIter> {
+    geometry: G,
+    rect: Option<Rect<G::Scalar>>,
+}
+
+impl<G> Bounded<G>
+where
+    G: CoordsIter + BoundingRect<<G as CoordsIter>::Scalar>,
+{
+    /// Wrap `geometry`, calculating its bounding rectangle.
+    pub fn new(geometry: G) -> Self {
+        let rect = geometry.bounding_rect().into();
+        Bounded { geometry, rect }
+    }
+
+    /// Change the geometry with `f`, and then recalculate its bounding rectangle.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut G) -> R) -> R {
+        let result = f(&mut self.geometry);
+        self.rect = self.geometry.bounding_rect().into();
+        result
+    }
+}
+
+impl<G: CoordsIter> Bounded<G> {
+    /// The geometry.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// The bounding rectangle of the geometry, or `None` if it's empty.
+    pub fn rect(&self) -> Option<Rect<G::Scalar>> {
+        self.rect
+    }
+
+    /// Unwrap the geometry.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+}
+
+impl<G> From<G> for Bounded<G>
+where
+    G: CoordsIter + BoundingRect<<G as CoordsIter>::Scalar>,
+{
+    fn from(geometry: G) -> Self {
+        Bounded::new(geometry)
+    }
+}
+
+impl<T, G> BoundingRect<T> for Bounded<G>
+where
+    T: CoordNum,
+    G: CoordsIter<Scalar = T>,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.rect
+    }
+}
+
+impl<G> Centroid for Bounded<G>
+where
+    G: CoordsIter + Centroid,
+{
+    type Output = G::Output;
+
+    fn centroid(&self) -> Self::Output {
+        self.geometry.centroid()
+    }
+}
+
+impl<T, NT, G> MapCoords<T, NT> for Bounded<G>
+where
+    T: CoordNum,
+    NT: CoordNum,
+    G: CoordsIter<Scalar = T> + MapCoords<T, NT>,
+    G::Output: CoordsIter<Scalar = NT> + BoundingRect<NT>,
+{
+    type Output = Bounded<G::Output>;
+
+    fn map_coords(&self, func: impl Fn(Coord<T>) -> Coord<NT> + Copy) -> Self::Output {
+        Bounded::new(self.geometry.map_coords(func))
+    }
+
+    fn try_map_coords<E>(
+        &self,
+        func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+    ) -> Result<Self::Output, E> {
+        Ok(Bounded::new(self.geometry.try_map_coords(func)?))
+    }
+}
+
+impl<T, G> MapCoordsInPlace<T> for Bounded<G>
+where
+    T: CoordNum,
+    G: CoordsIter<Scalar = T> + MapCoordsInPlace<T> + BoundingRect<T>,
+{
+    fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) {
+        self.update(|geometry| geometry.map_coords_in_place(func))
+    }
+
+    fn try_map_coords_in_place<E>(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    ) -> Result<(), E> {
+        // The geometry may be partly mapped when this fails, so the rectangle is updated anyway
+        self.update(|geometry| geometry.try_map_coords_in_place(func))
+    }
+}
+
+impl<T, G, H> Intersects<Bounded<H>> for Bounded<G>
+where
+    T: GeoNum,
+    G: CoordsIter<Scalar = T> + Intersects<H>,
+    H: CoordsIter<Scalar = T>,
+{
+    fn intersects(&self, other: &Bounded<H>) -> bool {
+        match (self.rect, other.rect) {
+            (Some(a), Some(b)) => a.intersects(&b) && self.geometry.intersects(&other.geometry),
+            _ => false,
+        }
+    }
+}
+
+macro_rules! impl_intersects_with_bounded {
+    ($($rhs:ident),*) => {
+        $(
+            impl<T, G> Intersects<$rhs<T>> for Bounded<G>
+            where
+                T: GeoNum,
+                G: CoordsIter<Scalar = T> + Intersects<$rhs<T>>,
+            {
+                fn intersects(&self, other: &$rhs<T>) -> bool {
+                    match self.rect {
+                        Some(rect) => rect.intersects(other) && self.geometry.intersects(other),
+                        None => false,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_intersects_with_bounded!(Coord, Point, Rect);
+
+impl<T, G> rstar::RTreeObject for Bounded<G>
+where
+    T: GeoFloat + rstar::RTreeNum,
+    G: CoordsIter<Scalar = T>,
+{
+    type Envelope = rstar::AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        match self.rect {
+            Some(rect) => rstar::AABB::from_corners(rect.min().into(), rect.max().into()),
+            None => rstar::Envelope::new_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, AffineOps, AffineTransform, Polygon, Rotate, Translate,
+    };
+
+    fn square() -> Polygon {
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]
+    }
+
+    #[test]
+    fn transforms_keep_the_rect() {
+        let bounded = Bounded::new(square());
+        let rotated = bounded.rotate_around_centroid(45.);
+        let expected = square().rotate_around_centroid(45.).bounding_rect();
+        assert_eq!(rotated.rect(), expected);
+        assert_eq!(rotated.geometry(), &square().rotate_around_centroid(45.));
+
+        let mut scaled = bounded.clone();
+        scaled.affine_transform_mut(&AffineTransform::scale(3., 1., (0., 0.)));
+        assert_eq!(scaled.rect(), Some(Rect::new((0., 0.), (6., 2.))));
+
+        let converted: Bounded<Polygon<f32>> = bounded.map_coords(|c| Coord {
+            x: c.x as f32,
+            y: c.y as f32 + 1.,
+        });
+        assert_eq!(converted.rect(), Some(Rect::new((0., 1.), (2., 3.))));
+
+        let mut updated = bounded;
+        let old = updated.update(|polygon| {
+            let mut old = None;
+            polygon.exterior_mut(|exterior| {
+                old = Some(core::mem::replace(
+                    &mut exterior.0[2],
+                    Coord { x: 5., y: 4. },
+                ));
+            });
+            old
+        });
+        assert_eq!(old, Some(Coord { x: 2., y: 2. }));
+        assert_eq!(updated.rect(), Some(Rect::new((0., 0.), (5., 4.))));
+    }
+
+    #[test]
+    fn intersects() {
+        let a = Bounded::new(square());
+        let b = Bounded::new(line_string![(x: 1., y: 1.), (x: 5., y: 5.)]);
+        let c = Bounded::new(line_string![(x: 3., y: 0.), (x: 3., y: 5.)]);
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert!(b.intersects(&c));
+        assert!(a.intersects(&point!(x: 1., y: 1.)));
+        assert!(!a.intersects(&Rect::new((3., 3.), (4., 4.))));
+
+        // An L shape whose rectangle contains points it doesn't
+        let l = Bounded::new(polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 4.),
+            (x: 0., y: 4.),
+        ]);
+        assert!(!l.intersects(&Coord { x: 3., y: 3. }));
+
+        let empty = Bounded::new(Polygon::<f64>::new(line_string![], vec![]));
+        assert_eq!(empty.rect(), None);
+        assert!(!empty.intersects(&a));
+    }
+
+    #[test]
+    fn r_tree() {
+        let tree = rstar::RTree::bulk_load(vec![
+            Bounded::new(square()),
+            Bounded::new(square().translate(10., 0.)),
+        ]);
+        let found: Vec<_> = tree
+            .locate_in_envelope_intersecting(&rstar::AABB::from_corners(
+                point!(x: 9., y: 1.),
+                point!(x: 11., y: 1.),
+            ))
+            .collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].rect(), Some(Rect::new((10., 0.), (12., 2.))));
+    }
+}
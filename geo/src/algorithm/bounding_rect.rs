@@ -1,4 +1,6 @@
+use crate::soa::{SoaLineString, SoaMultiPoint};
 use crate::utils::{partial_max, partial_min};
+use crate::view::{LineStringSlice, PolygonRef};
 use crate::{coord, geometry::*, CoordNum, GeometryCow};
 use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 
@@ -120,6 +122,58 @@ where
     }
 }
 
+impl<T> BoundingRect<T> for LineStringSlice<'_, T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for a LineStringSlice
+    fn bounding_rect(&self) -> Self::Output {
+        get_bounding_rect(self.0.iter().cloned())
+    }
+}
+
+impl<T> BoundingRect<T> for SoaLineString<T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for an SoaLineString
+    fn bounding_rect(&self) -> Self::Output {
+        self.0.bounding_rect()
+    }
+}
+
+impl<T> BoundingRect<T> for SoaMultiPoint<T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for an SoaMultiPoint
+    fn bounding_rect(&self) -> Self::Output {
+        self.0.bounding_rect()
+    }
+}
+
+impl<T> BoundingRect<T> for PolygonRef<'_, T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for a PolygonRef
+    fn bounding_rect(&self) -> Self::Output {
+        self.exterior().bounding_rect()
+    }
+}
+
 impl<T> BoundingRect<T> for MultiPolygon<T>
 where
     T: CoordNum,
@@ -200,7 +254,7 @@ where
 }
 
 // Return a new rectangle that encompasses the provided rectangles
-fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
+pub(crate) fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
         coord! {
             x: partial_min(a.min().x, b.min().x),
@@ -1,8 +1,10 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::area::{get_linestring_area, Area};
 use crate::dimensions::{Dimensions, Dimensions::*, HasDimensions};
 use crate::geometry::*;
+use crate::soa::{segment_length, SoaLineString, SoaMultiPoint};
+use crate::view::{LineStringSlice, PolygonRef};
 use crate::EuclideanLength;
 use crate::GeoFloat;
 
@@ -116,7 +118,7 @@ where
     /// ```
     fn centroid(&self) -> Self::Output {
         let mut operation = CentroidOperation::new();
-        operation.add_line_string(self);
+        operation.add_line_string(&self.0);
         operation.centroid()
     }
 }
@@ -189,6 +191,78 @@ where
     }
 }
 
+impl<T> Centroid for LineStringSlice<'_, T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    /// The Centroid of a [`LineStringSlice`] is that of the [`LineString`] with its coordinates
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        operation.add_line_string(self.0);
+        operation.centroid()
+    }
+}
+
+impl<T> Centroid for SoaLineString<T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    /// The Centroid of an [`SoaLineString`] is that of the [`LineString`] with its coordinates
+    fn centroid(&self) -> Self::Output {
+        let [length, x, y] = self.0.segment_sums(|start, end| {
+            let length = segment_length(start, end);
+            [
+                length,
+                length * (start.x + end.x),
+                length * (start.y + end.y),
+            ]
+        });
+        if length == T::zero() {
+            // A line string with no length is empty, or a single point
+            return self.0.get(0).map(Point::from);
+        }
+
+        let two = T::one() + T::one();
+        Some(Point::new(x / (two * length), y / (two * length)))
+    }
+}
+
+impl<T> Centroid for SoaMultiPoint<T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    /// The Centroid of an [`SoaMultiPoint`] is the mean of its points
+    fn centroid(&self) -> Self::Output {
+        if self.0.is_empty() {
+            return None;
+        }
+        Some(Point::from(self.0.sums() / T::from(self.0.len())?))
+    }
+}
+
+impl<T> Centroid for PolygonRef<'_, T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    /// The Centroid of a [`PolygonRef`] is that of the [`Polygon`] with its rings
+    fn centroid(&self) -> Self::Output {
+        let mut operation = CentroidOperation::new();
+        operation.add_polygon_rings(
+            self.exterior().0,
+            self.interiors().iter().map(|interior| interior.0),
+        );
+        operation.centroid()
+    }
+}
+
 impl<T> Centroid for MultiPolygon<T>
 where
     T: GeoFloat,
@@ -439,7 +513,98 @@ where
     }
 }
 
-struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
+/// Calculation of the centroid of a collection whose members carry weights, such as the
+/// population of each of a set of points or districts, also known as their center of mass or
+/// mean center.
+///
+/// Each member counts towards the result with its own weight, as if all of its weight were at
+/// its [`Centroid`]; the size of the member doesn't affect it. Members without a centroid are
+/// ignored, along with their weights.
+///
+/// Returns `None` if the weights of the members with a centroid add up to zero.
+///
+/// # Panics
+///
+/// If the number of weights isn't the same as the number of members.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, MultiPoint, MultiPolygon, WeightedCentroid};
+///
+/// let towns = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 10., y: 0.)]);
+/// assert_eq!(
+///     towns.weighted_centroid(&[300., 100.]),
+///     Some(point!(x: 2.5, y: 0.)),
+/// );
+///
+/// let districts = MultiPolygon::new(vec![
+///     // centroid (1, 1)
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+///     // centroid (7, 1), with 4 times the area
+///     polygon![(x: 5., y: 0.), (x: 9., y: 0.), (x: 9., y: 2.), (x: 5., y: 2.)],
+/// ]);
+/// assert_eq!(
+///     districts.weighted_centroid(&[1000., 1000.]),
+///     Some(point!(x: 4., y: 1.)),
+/// );
+/// ```
+pub trait WeightedCentroid<T: GeoFloat> {
+    /// The mean of the centroids of the members of `self`, weighted by `weights`.
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>>;
+}
+
+impl<T: GeoFloat> WeightedCentroid<T> for [Point<T>] {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.iter().map(|point| Some(*point)), weights)
+    }
+}
+
+impl<T: GeoFloat> WeightedCentroid<T> for MultiPoint<T> {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        self.0.weighted_centroid(weights)
+    }
+}
+
+impl<T: GeoFloat> WeightedCentroid<T> for MultiLineString<T> {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.0.iter().map(Centroid::centroid), weights)
+    }
+}
+
+impl<T: GeoFloat> WeightedCentroid<T> for MultiPolygon<T> {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.0.iter().map(Centroid::centroid), weights)
+    }
+}
+
+impl<T: GeoFloat> WeightedCentroid<T> for GeometryCollection<T> {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.0.iter().map(Centroid::centroid), weights)
+    }
+}
+
+fn weighted_mean<T: GeoFloat>(
+    centroids: impl ExactSizeIterator<Item = Option<Point<T>>>,
+    weights: &[T],
+) -> Option<Point<T>> {
+    assert_eq!(
+        centroids.len(),
+        weights.len(),
+        "there must be a weight for each member"
+    );
+    let mut accumulated = Coord::zero();
+    let mut total = T::zero();
+    for (centroid, &weight) in centroids.zip(weights) {
+        if let Some(centroid) = centroid {
+            accumulated = accumulated + centroid.0 * weight;
+            total = total + weight;
+        }
+    }
+    (total != T::zero()).then(|| Point::from(accumulated / total))
+}
+
+struct CentroidOperation<T: GeoFloat>(Option<AccumulatedCentroid<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
     fn new() -> Self {
         CentroidOperation(None)
@@ -472,18 +637,18 @@ impl<T: GeoFloat> CentroidOperation<T> {
         }
     }
 
-    fn add_line_string(&mut self, line_string: &LineString<T>) {
+    fn add_line_string(&mut self, line_string: &[Coord<T>]) {
         if self.centroid_dimensions() > OneDimensional {
             return;
         }
 
-        if line_string.0.len() == 1 {
-            self.add_coord(line_string.0[0]);
+        if line_string.len() == 1 {
+            self.add_coord(line_string[0]);
             return;
         }
 
-        for line in line_string.lines() {
-            self.add_line(&line);
+        for coords in line_string.windows(2) {
+            self.add_line(&Line::new(coords[0], coords[1]));
         }
     }
 
@@ -493,21 +658,34 @@ impl<T: GeoFloat> CentroidOperation<T> {
         }
 
         for element in &multi_line_string.0 {
-            self.add_line_string(element);
+            self.add_line_string(&element.0);
         }
     }
 
     fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        self.add_polygon_rings(
+            &polygon.exterior().0,
+            polygon.interiors().iter().map(|interior| &interior.0[..]),
+        );
+    }
+
+    fn add_polygon_rings<'a>(
+        &mut self,
+        exterior: &[Coord<T>],
+        interiors: impl Iterator<Item = &'a [Coord<T>]>,
+    ) where
+        T: 'a,
+    {
         // Polygons which are completely covered by their interior rings have zero area, and
         // represent a unique degeneracy into a line_string which cannot be handled by accumulating
         // directly into `self`. Instead, we perform a sub-operation, inspect the result, and only
         // then incorporate the result into `self.
 
         let mut exterior_operation = CentroidOperation::new();
-        exterior_operation.add_ring(polygon.exterior());
+        exterior_operation.add_ring(exterior);
 
         let mut interior_operation = CentroidOperation::new();
-        for interior in polygon.interiors() {
+        for interior in interiors {
             interior_operation.add_ring(interior);
         }
 
@@ -517,7 +695,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
                 poly_weighted_centroid.sub_assign(interior_weighted_centroid);
                 if poly_weighted_centroid.weight.is_zero() {
                     // A polygon with no area `interiors` completely covers `exterior`, degenerating to a linestring
-                    self.add_line_string(polygon.exterior());
+                    self.add_line_string(exterior);
                     return;
                 }
             }
@@ -589,7 +767,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         match geometry {
             Geometry::Point(g) => self.add_coord(g.0),
             Geometry::Line(g) => self.add_line(g),
-            Geometry::LineString(g) => self.add_line_string(g),
+            Geometry::LineString(g) => self.add_line_string(&g.0),
             Geometry::Polygon(g) => self.add_polygon(g),
             Geometry::MultiPoint(g) => self.add_multi_point(g),
             Geometry::MultiLineString(g) => self.add_multi_line_string(g),
@@ -600,16 +778,16 @@ impl<T: GeoFloat> CentroidOperation<T> {
         }
     }
 
-    fn add_ring(&mut self, ring: &LineString<T>) {
-        debug_assert!(ring.is_closed());
+    fn add_ring(&mut self, ring: &[Coord<T>]) {
+        debug_assert!(ring.first() == ring.last());
 
         let area = get_linestring_area(ring);
         if area == T::zero() {
-            match ring.dimensions() {
+            match ring.first() {
                 // empty ring doesn't contribute to centroid
-                Empty => {}
+                None => {}
                 // degenerate ring is a point
-                ZeroDimensional => self.add_coord(ring[0]),
+                Some(first) if ring.iter().all(|coord| coord == first) => self.add_coord(*first),
                 // zero-area ring is a line string
                 _ => self.add_line_string(ring),
             }
@@ -617,11 +795,10 @@ impl<T: GeoFloat> CentroidOperation<T> {
         }
 
         // Since area is non-zero, we know the ring has at least one point
-        let shift = ring.0[0];
+        let shift = ring[0];
 
-        let accumulated_coord = ring.lines().fold(Coord::zero(), |accum, line| {
-            use crate::MapCoords;
-            let line = line.map_coords(|c| c - shift);
+        let accumulated_coord = ring.windows(2).fold(Coord::zero(), |accum, coords| {
+            let line = Line::new(coords[0] - shift, coords[1] - shift);
             let tmp = line.determinant();
             accum + (line.end + line.start) * tmp
         });
@@ -632,7 +809,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
     }
 
     fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coord<T>, weight: T) {
-        let weighted_centroid = WeightedCentroid {
+        let weighted_centroid = AccumulatedCentroid {
             dimensions,
             weight,
             accumulated: centroid * weight,
@@ -640,7 +817,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         self.add_weighted_centroid(weighted_centroid);
     }
 
-    fn add_weighted_centroid(&mut self, other: WeightedCentroid<T>) {
+    fn add_weighted_centroid(&mut self, other: AccumulatedCentroid<T>) {
         match self.0.as_mut() {
             Some(centroid) => centroid.add_assign(other),
             None => self.0 = Some(other),
@@ -649,7 +826,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
 }
 
 // Aggregated state for accumulating the centroid of a geometry or collection of geometries.
-struct WeightedCentroid<T: GeoFloat> {
+struct AccumulatedCentroid<T: GeoFloat> {
     weight: T,
     accumulated: Coord<T>,
     /// Collections of Geometries can have different dimensionality. Centroids must be considered
@@ -665,8 +842,8 @@ struct WeightedCentroid<T: GeoFloat> {
     dimensions: Dimensions,
 }
 
-impl<T: GeoFloat> WeightedCentroid<T> {
-    fn add_assign(&mut self, b: WeightedCentroid<T>) {
+impl<T: GeoFloat> AccumulatedCentroid<T> {
+    fn add_assign(&mut self, b: AccumulatedCentroid<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -677,7 +854,7 @@ impl<T: GeoFloat> WeightedCentroid<T> {
         }
     }
 
-    fn sub_assign(&mut self, b: WeightedCentroid<T>) {
+    fn sub_assign(&mut self, b: AccumulatedCentroid<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -811,7 +988,7 @@ mod test {
     #[test]
     fn centroid_polygon_numerical_stability() {
         let polygon = {
-            use std::f64::consts::PI;
+            use core::f64::consts::PI;
             const NUM_VERTICES: usize = 10;
             const ANGLE_INC: f64 = 2. * PI / NUM_VERTICES as f64;
 
@@ -1110,4 +1287,50 @@ mod test {
             .push(Rect::new(c(10., 10.), c(11., 11.)).into());
         assert_eq!(collection.centroid().unwrap(), point!(x: 10.5, y: 10.5));
     }
+
+    #[test]
+    fn weighted_centroid_test() {
+        let points = [p(0., 0.), p(4., 0.), p(0., 4.)];
+        assert_eq!(
+            points.weighted_centroid(&[2., 1., 1.]),
+            Some(point!(x: 1., y: 1.))
+        );
+        // Negative weights pull the centroid away
+        assert_eq!(
+            points.weighted_centroid(&[1., -1., 1.]),
+            Some(point!(x: -4., y: 4.))
+        );
+        assert_eq!(points.weighted_centroid(&[0., 0., 0.]), None);
+        let empty: [Point; 0] = [];
+        assert_eq!(empty.weighted_centroid(&[]), None);
+    }
+
+    #[test]
+    fn weighted_centroid_skips_empty_members() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![],
+            polygon![(x: 4., y: 0.), (x: 6., y: 0.), (x: 6., y: 2.), (x: 4., y: 2.)],
+        ]);
+        assert_eq!(
+            multi_polygon.weighted_centroid(&[1., 100., 3.]),
+            Some(point!(x: 4., y: 1.))
+        );
+        assert_eq!(multi_polygon.weighted_centroid(&[0., 1., 0.]), None);
+
+        let collection = GeometryCollection::new_from(vec![
+            p(0., 0.).into(),
+            line_string![(x: 2., y: 0.), (x: 2., y: 4.)].into(),
+        ]);
+        assert_eq!(
+            collection.weighted_centroid(&[1., 1.]),
+            Some(point!(x: 1., y: 1.))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_centroid_wrong_number_of_weights() {
+        MultiPoint::new(vec![p(0., 0.), p(1., 1.)]).weighted_centroid(&[1.]);
+    }
 }
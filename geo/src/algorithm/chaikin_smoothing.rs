@@ -1,4 +1,5 @@
-use std::ops::Mul;
+use alloc::vec::Vec;
+use core::ops::Mul;
 
 use num_traits::FromPrimitive;
 
@@ -25,6 +26,45 @@ where
     fn chaikin_smoothing(&self, n_iterations: usize) -> Self;
 }
 
+/// The most iterations of [`ChaikinSmoothingWithTolerance`], which cut the corners of a
+/// geometry by about `4^-16` as much as the first one.
+const MAX_TOLERANCE_ITERATIONS: usize = 16;
+
+/// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins
+/// algorithm, until it converges within a tolerance.
+pub trait ChaikinSmoothingWithTolerance<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    /// create a new geometry with the Chaikin smoothing being
+    /// applied until an iteration moves the geometry by at most
+    /// `tolerance`, so that further iterations would make little
+    /// difference.
+    ///
+    /// Each line or ring is smoothed separately, and with `max_vertices`
+    /// the smoothing stops before an iteration would give it more
+    /// vertices than that. It stops after 16 iterations in any case,
+    /// as a `tolerance` below the resolution of the coordinates would
+    /// never be reached. With a `tolerance` which isn't positive, and
+    /// no `max_vertices`, the geometry is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, ChaikinSmoothing, ChaikinSmoothingWithTolerance};
+    ///
+    /// let ls = line_string![(x: 0., y: 0.), (x: 10., y: 10.), (x: 20., y: 0.)];
+    ///
+    /// // Each iteration cuts the corner by a quarter as much as the last
+    /// let smooth = ls.chaikin_smoothing_with_tolerance(0.1, None);
+    /// assert_eq!(smooth, ls.chaikin_smoothing(4));
+    ///
+    /// let capped = ls.chaikin_smoothing_with_tolerance(0.1, Some(30));
+    /// assert_eq!(capped, ls.chaikin_smoothing(3));
+    /// ```
+    fn chaikin_smoothing_with_tolerance(&self, tolerance: T, max_vertices: Option<usize>) -> Self;
+}
+
 impl<T> ChaikinSmoothing<T> for LineString<T>
 where
     T: CoordFloat + FromPrimitive,
@@ -42,6 +82,32 @@ where
     }
 }
 
+impl<T> ChaikinSmoothingWithTolerance<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing_with_tolerance(&self, tolerance: T, max_vertices: Option<usize>) -> Self {
+        // Without a positive tolerance, nothing but the cap would stop the smoothing
+        if !(tolerance > T::zero() || max_vertices.is_some()) {
+            return self.clone();
+        }
+        let mut smooth = self.clone();
+        for _ in 0..MAX_TOLERANCE_ITERATIONS {
+            let next = smoothen_linestring(&smooth);
+            if max_vertices.is_some_and(|max_vertices| next.0.len() > max_vertices) {
+                break;
+            }
+            let deviation = smoothing_deviation(&smooth);
+            smooth = next;
+            // A NaN deviation never converges
+            if deviation.is_nan() || deviation <= tolerance {
+                break;
+            }
+        }
+        smooth
+    }
+}
+
 impl<T> ChaikinSmoothing<T> for MultiLineString<T>
 where
     T: CoordFloat + FromPrimitive,
@@ -56,6 +122,20 @@ where
     }
 }
 
+impl<T> ChaikinSmoothingWithTolerance<T> for MultiLineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing_with_tolerance(&self, tolerance: T, max_vertices: Option<usize>) -> Self {
+        MultiLineString::new(
+            self.0
+                .iter()
+                .map(|ls| ls.chaikin_smoothing_with_tolerance(tolerance, max_vertices))
+                .collect(),
+        )
+    }
+}
+
 impl<T> ChaikinSmoothing<T> for Polygon<T>
 where
     T: CoordFloat + FromPrimitive,
@@ -71,6 +151,22 @@ where
     }
 }
 
+impl<T> ChaikinSmoothingWithTolerance<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing_with_tolerance(&self, tolerance: T, max_vertices: Option<usize>) -> Self {
+        Polygon::new(
+            self.exterior()
+                .chaikin_smoothing_with_tolerance(tolerance, max_vertices),
+            self.interiors()
+                .iter()
+                .map(|ls| ls.chaikin_smoothing_with_tolerance(tolerance, max_vertices))
+                .collect(),
+        )
+    }
+}
+
 impl<T> ChaikinSmoothing<T> for MultiPolygon<T>
 where
     T: CoordFloat + FromPrimitive,
@@ -85,9 +181,23 @@ where
     }
 }
 
+impl<T> ChaikinSmoothingWithTolerance<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing_with_tolerance(&self, tolerance: T, max_vertices: Option<usize>) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|poly| poly.chaikin_smoothing_with_tolerance(tolerance, max_vertices))
+                .collect(),
+        )
+    }
+}
+
 macro_rules! blanket_run_chaikin_smoothing {
-    ($geo:expr, $n_iter:expr) => {{
-        let smooth = $geo.chaikin_smoothing($n_iter);
+    ($geo:expr, $method:ident($($arg:expr),*)) => {{
+        let smooth = $geo.$method($($arg),*);
         let geo: Geometry<T> = smooth.into();
         geo
     }};
@@ -99,10 +209,49 @@ where
 {
     fn chaikin_smoothing(&self, n_iterations: usize) -> Geometry<T> {
         match self {
-            Geometry::LineString(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::MultiLineString(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::Polygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::MultiPolygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
+            Geometry::LineString(child) => {
+                blanket_run_chaikin_smoothing!(child, chaikin_smoothing(n_iterations))
+            }
+            Geometry::MultiLineString(child) => {
+                blanket_run_chaikin_smoothing!(child, chaikin_smoothing(n_iterations))
+            }
+            Geometry::Polygon(child) => {
+                blanket_run_chaikin_smoothing!(child, chaikin_smoothing(n_iterations))
+            }
+            Geometry::MultiPolygon(child) => {
+                blanket_run_chaikin_smoothing!(child, chaikin_smoothing(n_iterations))
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+impl<T> ChaikinSmoothingWithTolerance<T> for Geometry<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing_with_tolerance(
+        &self,
+        tolerance: T,
+        max_vertices: Option<usize>,
+    ) -> Geometry<T> {
+        match self {
+            Geometry::LineString(child) => blanket_run_chaikin_smoothing!(
+                child,
+                chaikin_smoothing_with_tolerance(tolerance, max_vertices)
+            ),
+            Geometry::MultiLineString(child) => blanket_run_chaikin_smoothing!(
+                child,
+                chaikin_smoothing_with_tolerance(tolerance, max_vertices)
+            ),
+            Geometry::Polygon(child) => blanket_run_chaikin_smoothing!(
+                child,
+                chaikin_smoothing_with_tolerance(tolerance, max_vertices)
+            ),
+            Geometry::MultiPolygon(child) => blanket_run_chaikin_smoothing!(
+                child,
+                chaikin_smoothing_with_tolerance(tolerance, max_vertices)
+            ),
             _ => self.clone(),
         }
     }
@@ -141,6 +290,45 @@ where
     out_coords.into()
 }
 
+// the furthest that smoothing a linestring once moves it, which is
+// the furthest that any of its corners is cut off.
+fn smoothing_deviation<T>(linestring: &LineString<T>) -> T
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let coords = &linestring.0;
+    let corner_deviation = |previous: Coord<T>, corner: Coord<T>, next: Coord<T>| {
+        let (_, start) = smoothen_coordinates(previous, corner);
+        let (end, _) = smoothen_coordinates(corner, next);
+        point_segment_distance(corner, start, end)
+    };
+    let mut deviation = coords
+        .windows(3)
+        .map(|window| corner_deviation(window[0], window[1], window[2]))
+        .fold(T::zero(), T::max);
+    if linestring.is_closed() && coords.len() > 2 {
+        // the corner between the beginning and the end
+        let corner = corner_deviation(coords[coords.len() - 2], coords[0], coords[1]);
+        deviation = deviation.max(corner);
+    }
+    deviation
+}
+
+fn point_segment_distance<T: CoordFloat>(point: Coord<T>, start: Coord<T>, end: Coord<T>) -> T {
+    let delta = end - start;
+    let offset = point - start;
+    let length_squared = delta.x * delta.x + delta.y * delta.y;
+    let fraction = if length_squared > T::zero() {
+        ((offset.x * delta.x + offset.y * delta.y) / length_squared)
+            .max(T::zero())
+            .min(T::one())
+    } else {
+        T::zero()
+    };
+    let closest = start + delta * fraction;
+    (point.x - closest.x).hypot(point.y - closest.y)
+}
+
 fn smoothen_coordinates<T>(c0: Coord<T>, c1: Coord<T>) -> (Coord<T>, Coord<T>)
 where
     T: CoordFloat + Mul<T> + FromPrimitive,
@@ -158,7 +346,7 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::ChaikinSmoothing;
+    use crate::{ChaikinSmoothing, ChaikinSmoothingWithTolerance};
     use crate::{Geometry, LineString, Point, Polygon};
 
     #[test]
@@ -235,6 +423,79 @@ mod test {
         );
     }
 
+    #[test]
+    fn with_tolerance() {
+        let ls = LineString::from(vec![(3.0, 0.0), (6.0, 3.0), (3.0, 6.0), (0.0, 3.0)]);
+        // the first iteration cuts the corners by 0.75, and each one
+        // after that by about a quarter as much as the last
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.8, None),
+            ls.chaikin_smoothing(1)
+        );
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.5, None),
+            ls.chaikin_smoothing(2)
+        );
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.1, None),
+            ls.chaikin_smoothing(3)
+        );
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.01, None),
+            ls.chaikin_smoothing(5)
+        );
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.01, Some(20)),
+            ls.chaikin_smoothing(2)
+        );
+        assert_eq!(ls.chaikin_smoothing_with_tolerance(0.01, Some(7)), ls);
+        assert_eq!(ls.chaikin_smoothing_with_tolerance(0.0, None), ls);
+
+        // a cap with no tolerance smooths as much as it allows
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(0.0, Some(40)),
+            ls.chaikin_smoothing(3)
+        );
+
+        // straight lines are smooth already
+        let straight = LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+        assert_eq!(
+            straight.chaikin_smoothing_with_tolerance(0.1, None),
+            straight.chaikin_smoothing(1)
+        );
+
+        // the corner where a ring closes is cut too
+        let poly = Polygon::new(
+            LineString::from(vec![
+                (0.0, 0.0),
+                (0.0, 0.0),
+                (6.0, 0.0),
+                (6.0, 6.0),
+                (0.0, 0.0),
+            ]),
+            vec![],
+        );
+        let geometry: Geometry = poly.clone().into();
+        assert_eq!(
+            geometry.chaikin_smoothing_with_tolerance(0.5, Some(100)),
+            poly.chaikin_smoothing(2).into()
+        );
+    }
+
+    #[test]
+    fn with_unreachable_tolerance() {
+        // a tolerance below the resolution of the coordinates
+        let ls = LineString::from(vec![(3.0, 0.0), (6.0, 3.0), (3.0, 6.0), (0.0, 3.0)]);
+        assert_eq!(
+            ls.chaikin_smoothing_with_tolerance(1e-300, None),
+            ls.chaikin_smoothing(16)
+        );
+
+        let nan = LineString::from(vec![(3.0, 0.0), (f64::NAN, 3.0), (3.0, 6.0)]);
+        let smooth = nan.chaikin_smoothing_with_tolerance(0.1, None);
+        assert_eq!(smooth.0.len(), nan.chaikin_smoothing(1).0.len());
+    }
+
     #[test]
     fn polygon() {
         let poly = Polygon::new(
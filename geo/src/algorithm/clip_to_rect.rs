@@ -0,0 +1,325 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Coord, CoordFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon, Rect};
+
+/// Clip a geometry to a [`Rect`], with algorithms specialised for rectangles, which are much
+/// faster than the general [`BooleanOps`](crate::BooleanOps).
+///
+/// Lines are clipped with the [Liang–Barsky
+/// algorithm](https://en.wikipedia.org/wiki/Liang%E2%80%93Barsky_algorithm), and a `LineString`
+/// which leaves and re-enters the rectangle is split into several.
+///
+/// Polygons are clipped one ring at a time with the [Sutherland–Hodgman
+/// algorithm](https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm). The area of the
+/// result is exact, but parts of a ring which leave the rectangle and re-enter it elsewhere stay
+/// connected along the rectangle's edges, by edges which overlap each other, so the result may not
+/// be a valid polygon. This is what's usually wanted for rendering, for example of vector tiles;
+/// use `BooleanOps::intersection` when valid polygons are needed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Area, ClipToRect, Rect};
+///
+/// let rect = Rect::new((0., 0.), (10., 10.));
+///
+/// // A line which leaves the rectangle and comes back in
+/// let line_string = line_string![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 8.), (x: 5., y: 8.)];
+/// let clipped = line_string.clip_to_rect(&rect);
+/// assert_eq!(clipped.0.len(), 2);
+/// assert_eq!(clipped.0[0], line_string![(x: 5., y: 5.), (x: 10., y: 5.)]);
+///
+/// let polygon = polygon![(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)];
+/// let clipped = polygon.clip_to_rect(&rect).unwrap();
+/// assert_eq!(clipped.unsigned_area(), 25.);
+/// ```
+pub trait ClipToRect<T: CoordFloat> {
+    type Output;
+
+    /// The part of `self` which is inside `rect`, including its boundary.
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output;
+}
+
+impl<T: CoordFloat> ClipToRect<T> for Line<T> {
+    type Output = Option<Line<T>>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        clip_line(*self, rect).map(|(line, _, _)| line)
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let mut parts: Vec<LineString<T>> = vec![];
+        // Whether the last part ends at the end of the last line
+        let mut open = false;
+        for line in self.lines() {
+            match clip_line(line, rect) {
+                Some((clipped, start_clipped, end_clipped)) => {
+                    match parts.last_mut() {
+                        Some(part) if open && !start_clipped => part.0.push(clipped.end),
+                        _ => parts.push(LineString::new(vec![clipped.start, clipped.end])),
+                    }
+                    open = !end_clipped;
+                }
+                None => open = false,
+            }
+        }
+        if let [point] = self.0[..] {
+            if rect_contains(rect, point) {
+                parts.push(self.clone());
+            }
+        }
+        MultiLineString::new(parts)
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .flat_map(|line_string| line_string.clip_to_rect(rect))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for Polygon<T> {
+    type Output = Option<Polygon<T>>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let exterior = clip_ring(self.exterior(), rect)?;
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter_map(|ring| clip_ring(ring, rect))
+            .collect();
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .filter_map(|polygon| polygon.clip_to_rect(rect))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> ClipToRect<T> for Rect<T> {
+    type Output = Option<Rect<T>>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let min = Coord {
+            x: self.min().x.max(rect.min().x),
+            y: self.min().y.max(rect.min().y),
+        };
+        let max = Coord {
+            x: self.max().x.min(rect.max().x),
+            y: self.max().y.min(rect.max().y),
+        };
+        (min.x <= max.x && min.y <= max.y).then(|| Rect::new(min, max))
+    }
+}
+
+fn rect_contains<T: CoordFloat>(rect: &Rect<T>, coord: Coord<T>) -> bool {
+    rect.min().x <= coord.x
+        && coord.x <= rect.max().x
+        && rect.min().y <= coord.y
+        && coord.y <= rect.max().y
+}
+
+/// Clip `line` to `rect` with the Liang–Barsky algorithm, returning the clipped line and whether
+/// its start and end were moved.
+fn clip_line<T: CoordFloat>(line: Line<T>, rect: &Rect<T>) -> Option<(Line<T>, bool, bool)> {
+    let delta = line.delta();
+    let (mut t0, mut t1) = (T::zero(), T::one());
+    let (min, max) = (rect.min(), rect.max());
+    let edges = [
+        (-delta.x, line.start.x - min.x),
+        (delta.x, max.x - line.start.x),
+        (-delta.y, line.start.y - min.y),
+        (delta.y, max.y - line.start.y),
+    ];
+    for (p, q) in edges {
+        if p == T::zero() {
+            // Parallel to this edge, and outside it
+            if q < T::zero() {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < T::zero() {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let (start_clipped, end_clipped) = (t0 > T::zero(), t1 < T::one());
+    let start = if start_clipped {
+        line.start + delta * t0
+    } else {
+        line.start
+    };
+    let end = if end_clipped {
+        line.start + delta * t1
+    } else {
+        line.end
+    };
+    Some((Line::new(start, end), start_clipped, end_clipped))
+}
+
+/// Clip a closed ring to `rect` with the Sutherland–Hodgman algorithm, unless nothing of it is
+/// left.
+fn clip_ring<T: CoordFloat>(ring: &LineString<T>, rect: &Rect<T>) -> Option<LineString<T>> {
+    let (min, max) = (rect.min(), rect.max());
+    let mut coords = ring.0.clone();
+    let edges: [&dyn Fn(Coord<T>) -> T; 4] =
+        [&|c| c.x - min.x, &|c| max.x - c.x, &|c| c.y - min.y, &|c| {
+            max.y - c.y
+        }];
+    for distance in edges {
+        coords = clip_half_plane(&coords, distance);
+    }
+    coords.dedup();
+    (coords.len() >= 4).then(|| LineString::new(coords))
+}
+
+/// Clip a closed ring to the half-plane where `distance` isn't negative.
+pub(crate) fn clip_half_plane<T: CoordFloat>(
+    coords: &[Coord<T>],
+    distance: &dyn Fn(Coord<T>) -> T,
+) -> Vec<Coord<T>> {
+    let mut out = Vec::with_capacity(coords.len());
+    for window in coords.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        let (da, db) = (distance(a), distance(b));
+        if da >= T::zero() {
+            out.push(a);
+        }
+        if (da >= T::zero()) != (db >= T::zero()) {
+            out.push(a + (b - a) * (da / (da - db)));
+        }
+    }
+    if let Some(&first) = out.first() {
+        out.push(first);
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area, BooleanOps, EuclideanLength};
+
+    fn rect() -> Rect {
+        Rect::new((0., 0.), (10., 10.))
+    }
+
+    #[test]
+    fn lines() {
+        let line = Line::new((-5., 5.), (15., 5.));
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            Some(Line::new((0., 5.), (10., 5.)))
+        );
+        let line = Line::new((2., 3.), (4., 5.));
+        assert_eq!(line.clip_to_rect(&rect()), Some(line));
+        // Diagonally past a corner
+        let line = Line::new((9., 12.), (12., 9.));
+        assert_eq!(line.clip_to_rect(&rect()), None);
+        // Along an edge
+        let line = Line::new((-1., 10.), (1., 10.));
+        assert_eq!(
+            line.clip_to_rect(&rect()),
+            Some(Line::new((0., 10.), (1., 10.)))
+        );
+        let line = Line::new((11., 1.), (11., 5.));
+        assert_eq!(line.clip_to_rect(&rect()), None);
+    }
+
+    #[test]
+    fn line_strings() {
+        let line_string = line_string![
+            (x: -5., y: 5.),
+            (x: 5., y: 5.),
+            (x: 5., y: 15.),
+            (x: 7., y: 15.),
+            (x: 7., y: 5.),
+            (x: 8., y: 5.),
+        ];
+        let clipped = line_string.clip_to_rect(&rect());
+        assert_eq!(
+            clipped,
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 5.), (x: 5., y: 5.), (x: 5., y: 10.)],
+                line_string![(x: 7., y: 10.), (x: 7., y: 5.), (x: 8., y: 5.)],
+            ])
+        );
+        // The same as clipping through the general boolean operations
+        let expected = rect().to_polygon().clip(&line_string.clone().into(), false);
+        assert_relative_eq!(clipped.euclidean_length(), expected.euclidean_length());
+
+        let outside = line_string![(x: 20., y: 20.), (x: 30., y: 20.)];
+        assert!(outside.clip_to_rect(&rect()).0.is_empty());
+        let inside = line_string![(x: 2., y: 2.), (x: 3., y: 3.), (x: 2., y: 8.)];
+        assert_eq!(inside.clip_to_rect(&rect()).0, vec![inside]);
+    }
+
+    #[test]
+    fn polygons() {
+        // Concave, with a hole which is partly outside the rectangle
+        let polygon = polygon![
+            exterior: [
+                (x: -5., y: -5.),
+                (x: 15., y: -5.),
+                (x: 15., y: 15.),
+                (x: 5., y: 3.),
+                (x: -5., y: 15.),
+            ],
+            interiors: [[(x: -2., y: 0.5), (x: 2., y: 0.5), (x: 2., y: 1.5), (x: -2., y: 1.5)]],
+        ];
+        let clipped = polygon.clip_to_rect(&rect()).unwrap();
+        assert_eq!(clipped.interiors().len(), 1);
+        let expected = polygon.intersection(&rect().to_polygon());
+        assert_relative_eq!(
+            clipped.unsigned_area(),
+            expected.unsigned_area(),
+            epsilon = 1e-9
+        );
+
+        let outside = polygon![(x: 20., y: 20.), (x: 30., y: 20.), (x: 30., y: 30.)];
+        assert_eq!(outside.clip_to_rect(&rect()), None);
+        let covering =
+            polygon![(x: -1., y: -1.), (x: 11., y: -1.), (x: 11., y: 11.), (x: -1., y: 11.)];
+        let clipped = covering.clip_to_rect(&rect()).unwrap();
+        assert_eq!(clipped.unsigned_area(), 100.);
+        assert_eq!(clipped.exterior().0.len(), 5);
+
+        let multi_polygon = MultiPolygon::new(vec![outside, covering]);
+        assert_eq!(multi_polygon.clip_to_rect(&rect()).0, vec![clipped]);
+    }
+
+    #[test]
+    fn rects() {
+        let other = Rect::new((5., -5.), (15., 5.));
+        assert_eq!(
+            other.clip_to_rect(&rect()),
+            Some(Rect::new((5., 0.), (10., 5.)))
+        );
+        let other = Rect::new((11., 0.), (15., 5.));
+        assert_eq!(other.clip_to_rect(&rect()), None);
+    }
+}
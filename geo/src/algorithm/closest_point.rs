@@ -3,7 +3,7 @@ use crate::geometry::*;
 use crate::Closest;
 use crate::GeoFloat;
 
-use std::iter;
+use core::iter;
 
 /// Find the closest `Point` between a given geometry and an input `Point`.
 /// The closest point may intersect the geometry, be a single
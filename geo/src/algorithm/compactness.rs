@@ -0,0 +1,177 @@
+use crate::{Area, ConvexHull, GeoFloat, LineString, MinimumBoundingCircle, MultiPolygon, Polygon};
+
+/// Measures of how compact a polygonal geometry is, each of which is 1 for a circle and closer to
+/// 0 the more elongated or contorted the shape is, as used to assess electoral districts and the
+/// morphology of buildings and parcels.
+///
+/// Each returns `None` for geometries without area.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Compactness};
+/// use std::f64::consts::PI;
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert_relative_eq!(square.polsby_popper().unwrap(), PI / 4.);
+/// assert_relative_eq!(square.schwartzberg().unwrap(), (PI / 4.).sqrt());
+/// assert_relative_eq!(square.reock().unwrap(), 2. / PI);
+/// assert_relative_eq!(square.convex_hull_ratio().unwrap(), 1.);
+///
+/// // A U shape is much less compact than its convex hull
+/// let u = polygon![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 0.),
+///     (x: 3., y: 3.),
+///     (x: 2., y: 3.),
+///     (x: 2., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 3.),
+///     (x: 0., y: 3.),
+/// ];
+/// assert_relative_eq!(u.convex_hull_ratio().unwrap(), 7. / 9.);
+/// ```
+pub trait Compactness<T: GeoFloat> {
+    /// The [Polsby–Popper](https://en.wikipedia.org/wiki/Polsby%E2%80%93Popper_test) score,
+    /// 4πA / P², the ratio of the area to that of a circle with the same perimeter. The
+    /// perimeter includes the boundaries of holes.
+    fn polsby_popper(&self) -> Option<T>;
+
+    /// The Schwartzberg score, the ratio of the circumference of a circle with the same area to
+    /// the perimeter, which is the square root of the Polsby–Popper score.
+    fn schwartzberg(&self) -> Option<T> {
+        self.polsby_popper().map(|score| score.sqrt())
+    }
+
+    /// The Reock score, the ratio of the area to that of the
+    /// [`MinimumBoundingCircle`].
+    fn reock(&self) -> Option<T>;
+
+    /// The ratio of the area to that of the [`ConvexHull`].
+    fn convex_hull_ratio(&self) -> Option<T>;
+}
+
+impl<T: GeoFloat> Compactness<T> for Polygon<T> {
+    fn polsby_popper(&self) -> Option<T> {
+        let perimeter = self
+            .interiors()
+            .iter()
+            .fold(ring_length(self.exterior()), |sum, ring| {
+                sum + ring_length(ring)
+            });
+        polsby_popper(self.unsigned_area(), perimeter)
+    }
+
+    fn reock(&self) -> Option<T> {
+        reock(self.unsigned_area(), self.minimum_bounding_circle()?.radius)
+    }
+
+    fn convex_hull_ratio(&self) -> Option<T> {
+        ratio(self.unsigned_area(), self.convex_hull().unsigned_area())
+    }
+}
+
+impl<T: GeoFloat> Compactness<T> for MultiPolygon<T> {
+    fn polsby_popper(&self) -> Option<T> {
+        let perimeter = self
+            .iter()
+            .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .fold(T::zero(), |sum, ring| sum + ring_length(ring));
+        polsby_popper(self.unsigned_area(), perimeter)
+    }
+
+    fn reock(&self) -> Option<T> {
+        reock(self.unsigned_area(), self.minimum_bounding_circle()?.radius)
+    }
+
+    fn convex_hull_ratio(&self) -> Option<T> {
+        ratio(self.unsigned_area(), self.convex_hull().unsigned_area())
+    }
+}
+
+fn ring_length<T: GeoFloat>(ring: &LineString<T>) -> T {
+    ring.lines()
+        .fold(T::zero(), |sum, line| sum + line.dx().hypot(line.dy()))
+}
+
+fn pi<T: GeoFloat>() -> T {
+    T::from(core::f64::consts::PI).unwrap()
+}
+
+fn ratio<T: GeoFloat>(area: T, other: T) -> Option<T> {
+    (area > T::zero() && other > T::zero()).then(|| area / other)
+}
+
+fn polsby_popper<T: GeoFloat>(area: T, perimeter: T) -> Option<T> {
+    ratio(T::from(4).unwrap() * pi() * area, perimeter * perimeter)
+}
+
+fn reock<T: GeoFloat>(area: T, radius: T) -> Option<T> {
+    ratio(area, pi::<T>() * radius * radius)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Translate};
+
+    fn regular_polygon(sides: usize) -> Polygon {
+        let coords: Vec<(f64, f64)> = (0..sides)
+            .map(|i| {
+                let angle = i as f64 * core::f64::consts::TAU / sides as f64;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        Polygon::new(coords.into(), vec![])
+    }
+
+    #[test]
+    fn nearly_circular() {
+        let circle = regular_polygon(1000);
+        for score in [
+            circle.polsby_popper(),
+            circle.schwartzberg(),
+            circle.reock(),
+            circle.convex_hull_ratio(),
+        ] {
+            assert_relative_eq!(score.unwrap(), 1., epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn elongated() {
+        // A 10 x 1 rectangle
+        let rect = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 1.), (x: 0., y: 1.)];
+        let pi = core::f64::consts::PI;
+        assert_relative_eq!(rect.polsby_popper().unwrap(), 4. * pi * 10. / 484.);
+        assert_relative_eq!(rect.reock().unwrap(), 10. / (pi * 101. / 4.));
+        assert!(rect.polsby_popper() < regular_polygon(4).polsby_popper());
+    }
+
+    #[test]
+    fn holes_and_parts() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        ];
+        // Area 12 and perimeter 16 + 8
+        let pi = core::f64::consts::PI;
+        assert_relative_eq!(square.polsby_popper().unwrap(), 4. * pi * 12. / 576.);
+        assert_relative_eq!(square.convex_hull_ratio().unwrap(), 12. / 16.);
+
+        // Two parts far apart are much less compact than either
+        let multi_polygon = MultiPolygon::new(vec![square.clone(), square.translate(20., 0.)]);
+        assert_relative_eq!(
+            multi_polygon.polsby_popper().unwrap(),
+            square.polsby_popper().unwrap() / 2.
+        );
+        assert!(multi_polygon.reock().unwrap() < square.reock().unwrap() / 5.);
+        assert_relative_eq!(multi_polygon.convex_hull_ratio().unwrap(), 24. / 96.);
+
+        let flat = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(flat.polsby_popper(), None);
+        assert_eq!(flat.reock(), None);
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).convex_hull_ratio(), None);
+    }
+}
@@ -0,0 +1,563 @@
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{
+    Coord, CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Compress a geometry into a compact byte format, with each coordinate within a maximum
+/// error of where it was, for keeping many geometries in memory.
+///
+/// Coordinates are snapped to a square grid whose spacing is the maximum error, so that each
+/// of them moves by at most half of that along each axis, and by less than the maximum error
+/// in all. Each coordinate is then stored as its difference, in grid cells, from the one
+/// before it, as a variable length integer, so that the nearby coordinates of a detailed
+/// geometry take up a byte or two each rather than sixteen. The closing coordinates of rings
+/// aren't stored.
+///
+/// The geometry is restored, as a [`Geometry`] of the same type, with
+/// [`CompressedGeometry::decompress`].
+///
+/// # Errors
+///
+/// If the maximum error isn't positive and finite, or a coordinate isn't finite or is too
+/// far from the origin, more than 2<sup>40</sup> (about 10<sup>12</sup>) grid cells, to be
+/// snapped to the grid precisely.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Compress, EuclideanDistance, LineString};
+///
+/// let track: LineString = (0..1000)
+///     .map(|i| (i as f64 * 0.123, (i as f64 * 0.01).sin()))
+///     .collect();
+///
+/// let compressed = track.compress(0.001)?;
+/// assert!(compressed.as_bytes().len() < 4000);
+///
+/// let restored: LineString = compressed.decompress()?.try_into().unwrap();
+/// for (original, restored) in track.points().zip(restored.points()) {
+///     assert!(original.euclidean_distance(&restored) < 0.001);
+/// }
+/// # Ok::<(), geo::algorithm::compress::CompressionError>(())
+/// ```
+pub trait Compress<T: CoordFloat> {
+    /// Compress the geometry, moving each coordinate by less than `max_error`.
+    fn compress(&self, max_error: T) -> Result<CompressedGeometry, CompressionError>;
+}
+
+/// A geometry compressed by [`Compress`].
+///
+/// The format starts with the spacing of the grid, as a little-endian `f64`, and continues
+/// with the geometry: a byte for its type, and then its numbers of parts and of coordinates,
+/// and the coordinates, as [LEB128](https://en.wikipedia.org/wiki/LEB128) variable length
+/// integers, zigzag encoded where they're signed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompressedGeometry {
+    bytes: Vec<u8>,
+}
+
+impl CompressedGeometry {
+    /// Wrap bytes which were compressed earlier, as from [`CompressedGeometry::into_bytes`].
+    ///
+    /// The bytes are checked when they're decompressed.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        CompressedGeometry { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Restore the geometry, with each of its coordinates on the grid.
+    ///
+    /// # Errors
+    ///
+    /// If the bytes aren't a compressed geometry, or have been cut short.
+    pub fn decompress<T: CoordFloat>(&self) -> Result<Geometry<T>, CompressionError> {
+        Decoder::new(&self.bytes)?.geometry(0)
+    }
+}
+
+/// Why a geometry couldn't be compressed or decompressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionError {
+    /// The maximum error isn't positive and finite.
+    InvalidMaxError,
+    /// A coordinate isn't finite, or is too far from the origin to be snapped to the grid.
+    CoordOutOfRange,
+    /// The bytes aren't a compressed geometry.
+    InvalidData,
+}
+
+impl core::fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CompressionError::InvalidMaxError => {
+                write!(f, "the maximum error must be positive and finite")
+            }
+            CompressionError::CoordOutOfRange => {
+                write!(f, "a coordinate is out of the range of the grid")
+            }
+            CompressionError::InvalidData => write!(f, "invalid compressed geometry"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompressionError {}
+
+// The largest number of grid cells from the origin, so that the rounding errors of floating
+// point numbers are much smaller than the grid cells
+const MAX_CELLS: f64 = (1_u64 << 40) as f64;
+
+// Geometries nested deeper than this in collections are taken to be invalid data, rather
+// than overflowing the stack
+const MAX_DEPTH: usize = 64;
+
+mod tag {
+    pub const POINT: u8 = 1;
+    pub const LINE: u8 = 2;
+    pub const LINE_STRING: u8 = 3;
+    pub const POLYGON: u8 = 4;
+    pub const MULTI_POINT: u8 = 5;
+    pub const MULTI_LINE_STRING: u8 = 6;
+    pub const MULTI_POLYGON: u8 = 7;
+    pub const GEOMETRY_COLLECTION: u8 = 8;
+    pub const RECT: u8 = 9;
+    pub const TRIANGLE: u8 = 10;
+}
+
+struct Encoder {
+    bytes: Vec<u8>,
+    spacing: f64,
+    previous: (i64, i64),
+}
+
+impl Encoder {
+    fn new<T: CoordFloat>(max_error: T) -> Result<Self, CompressionError> {
+        let spacing = max_error
+            .to_f64()
+            .filter(|spacing| *spacing > 0. && spacing.is_finite())
+            .ok_or(CompressionError::InvalidMaxError)?;
+        Ok(Encoder {
+            bytes: spacing.to_le_bytes().to_vec(),
+            spacing,
+            previous: (0, 0),
+        })
+    }
+
+    fn finish(self) -> CompressedGeometry {
+        CompressedGeometry { bytes: self.bytes }
+    }
+
+    fn unsigned(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn signed(&mut self, value: i64) {
+        self.unsigned(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn count(&mut self, count: usize) {
+        self.unsigned(count as u64);
+    }
+
+    fn cells<T: CoordFloat>(&self, value: T) -> Result<i64, CompressionError> {
+        let cells = value
+            .to_f64()
+            .map(|value| Float::round(value / self.spacing))
+            .filter(|cells| cells.abs() < MAX_CELLS)
+            .ok_or(CompressionError::CoordOutOfRange)?;
+        Ok(cells as i64)
+    }
+
+    fn coord<T: CoordFloat>(&mut self, coord: Coord<T>) -> Result<(), CompressionError> {
+        let cells = (self.cells(coord.x)?, self.cells(coord.y)?);
+        self.signed(cells.0 - self.previous.0);
+        self.signed(cells.1 - self.previous.1);
+        self.previous = cells;
+        Ok(())
+    }
+
+    fn coords<T: CoordFloat>(&mut self, coords: &[Coord<T>]) -> Result<(), CompressionError> {
+        self.count(coords.len());
+        coords.iter().try_for_each(|&coord| self.coord(coord))
+    }
+
+    fn ring<T: CoordFloat>(&mut self, ring: &LineString<T>) -> Result<(), CompressionError> {
+        // The rings of polygons are closed, so the closing coordinate can be left out
+        self.coords(ring.0.split_last().map_or(&[], |(_, open)| open))
+    }
+
+    fn point<T: CoordFloat>(&mut self, point: &Point<T>) -> Result<(), CompressionError> {
+        self.coord(point.0)
+    }
+
+    fn line<T: CoordFloat>(&mut self, line: &Line<T>) -> Result<(), CompressionError> {
+        self.coord(line.start)?;
+        self.coord(line.end)
+    }
+
+    fn line_string<T: CoordFloat>(
+        &mut self,
+        line_string: &LineString<T>,
+    ) -> Result<(), CompressionError> {
+        self.coords(&line_string.0)
+    }
+
+    fn polygon<T: CoordFloat>(&mut self, polygon: &Polygon<T>) -> Result<(), CompressionError> {
+        self.count(polygon.interiors().len());
+        self.ring(polygon.exterior())?;
+        polygon
+            .interiors()
+            .iter()
+            .try_for_each(|interior| self.ring(interior))
+    }
+
+    fn multi_point<T: CoordFloat>(
+        &mut self,
+        multi_point: &MultiPoint<T>,
+    ) -> Result<(), CompressionError> {
+        self.count(multi_point.0.len());
+        multi_point.iter().try_for_each(|point| self.point(point))
+    }
+
+    fn multi_line_string<T: CoordFloat>(
+        &mut self,
+        multi_line_string: &MultiLineString<T>,
+    ) -> Result<(), CompressionError> {
+        self.count(multi_line_string.0.len());
+        multi_line_string
+            .iter()
+            .try_for_each(|line_string| self.line_string(line_string))
+    }
+
+    fn multi_polygon<T: CoordFloat>(
+        &mut self,
+        multi_polygon: &MultiPolygon<T>,
+    ) -> Result<(), CompressionError> {
+        self.count(multi_polygon.0.len());
+        multi_polygon
+            .iter()
+            .try_for_each(|polygon| self.polygon(polygon))
+    }
+
+    fn geometry_collection<T: CoordFloat>(
+        &mut self,
+        geometry_collection: &GeometryCollection<T>,
+    ) -> Result<(), CompressionError> {
+        self.count(geometry_collection.0.len());
+        geometry_collection
+            .iter()
+            .try_for_each(|geometry| self.geometry(geometry))
+    }
+
+    fn rect<T: CoordFloat>(&mut self, rect: &Rect<T>) -> Result<(), CompressionError> {
+        self.coord(rect.min())?;
+        self.coord(rect.max())
+    }
+
+    fn triangle<T: CoordFloat>(&mut self, triangle: &Triangle<T>) -> Result<(), CompressionError> {
+        self.coord(triangle.0)?;
+        self.coord(triangle.1)?;
+        self.coord(triangle.2)
+    }
+
+    fn geometry<T: CoordFloat>(&mut self, geometry: &Geometry<T>) -> Result<(), CompressionError> {
+        match geometry {
+            Geometry::Point(g) => self.tagged(tag::POINT, g, Self::point),
+            Geometry::Line(g) => self.tagged(tag::LINE, g, Self::line),
+            Geometry::LineString(g) => self.tagged(tag::LINE_STRING, g, Self::line_string),
+            Geometry::Polygon(g) => self.tagged(tag::POLYGON, g, Self::polygon),
+            Geometry::MultiPoint(g) => self.tagged(tag::MULTI_POINT, g, Self::multi_point),
+            Geometry::MultiLineString(g) => {
+                self.tagged(tag::MULTI_LINE_STRING, g, Self::multi_line_string)
+            }
+            Geometry::MultiPolygon(g) => self.tagged(tag::MULTI_POLYGON, g, Self::multi_polygon),
+            Geometry::GeometryCollection(g) => {
+                self.tagged(tag::GEOMETRY_COLLECTION, g, Self::geometry_collection)
+            }
+            Geometry::Rect(g) => self.tagged(tag::RECT, g, Self::rect),
+            Geometry::Triangle(g) => self.tagged(tag::TRIANGLE, g, Self::triangle),
+        }
+    }
+
+    fn tagged<G>(
+        &mut self,
+        tag: u8,
+        geometry: &G,
+        encode: impl FnOnce(&mut Self, &G) -> Result<(), CompressionError>,
+    ) -> Result<(), CompressionError> {
+        self.bytes.push(tag);
+        encode(self, geometry)
+    }
+}
+
+macro_rules! impl_compress {
+    ($($geometry:ident => $tag:ident, $encode:ident;)*) => {
+        $(
+            impl<T: CoordFloat> Compress<T> for $geometry<T> {
+                fn compress(&self, max_error: T) -> Result<CompressedGeometry, CompressionError> {
+                    let mut encoder = Encoder::new(max_error)?;
+                    encoder.tagged(tag::$tag, self, Encoder::$encode)?;
+                    Ok(encoder.finish())
+                }
+            }
+        )*
+    };
+}
+
+impl_compress! {
+    Point => POINT, point;
+    Line => LINE, line;
+    LineString => LINE_STRING, line_string;
+    Polygon => POLYGON, polygon;
+    MultiPoint => MULTI_POINT, multi_point;
+    MultiLineString => MULTI_LINE_STRING, multi_line_string;
+    MultiPolygon => MULTI_POLYGON, multi_polygon;
+    GeometryCollection => GEOMETRY_COLLECTION, geometry_collection;
+    Rect => RECT, rect;
+    Triangle => TRIANGLE, triangle;
+}
+
+impl<T: CoordFloat> Compress<T> for Geometry<T> {
+    fn compress(&self, max_error: T) -> Result<CompressedGeometry, CompressionError> {
+        let mut encoder = Encoder::new(max_error)?;
+        encoder.geometry(self)?;
+        Ok(encoder.finish())
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    spacing: f64,
+    previous: (i64, i64),
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, CompressionError> {
+        if bytes.len() < 8 {
+            return Err(CompressionError::InvalidData);
+        }
+        let (spacing, bytes) = bytes.split_at(8);
+        let spacing = f64::from_le_bytes(spacing.try_into().unwrap());
+        if !(spacing > 0. && spacing.is_finite()) {
+            return Err(CompressionError::InvalidData);
+        }
+        Ok(Decoder {
+            bytes,
+            spacing,
+            previous: (0, 0),
+        })
+    }
+
+    fn byte(&mut self) -> Result<u8, CompressionError> {
+        let (&byte, rest) = self
+            .bytes
+            .split_first()
+            .ok_or(CompressionError::InvalidData)?;
+        self.bytes = rest;
+        Ok(byte)
+    }
+
+    fn unsigned(&mut self) -> Result<u64, CompressionError> {
+        let mut value = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(CompressionError::InvalidData)
+    }
+
+    fn signed(&mut self) -> Result<i64, CompressionError> {
+        let value = self.unsigned()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn count(&mut self) -> Result<usize, CompressionError> {
+        let count = usize::try_from(self.unsigned()?).map_err(|_| CompressionError::InvalidData)?;
+        // Every part takes at least a byte, so there can't be more than there are bytes left
+        if count > self.bytes.len() {
+            return Err(CompressionError::InvalidData);
+        }
+        Ok(count)
+    }
+
+    fn coord<T: CoordFloat>(&mut self) -> Result<Coord<T>, CompressionError> {
+        let x = self.previous.0.saturating_add(self.signed()?);
+        let y = self.previous.1.saturating_add(self.signed()?);
+        self.previous = (x, y);
+        let value =
+            |cells: i64| T::from(cells as f64 * self.spacing).ok_or(CompressionError::InvalidData);
+        Ok(Coord {
+            x: value(x)?,
+            y: value(y)?,
+        })
+    }
+
+    fn coords<T: CoordFloat>(&mut self) -> Result<Vec<Coord<T>>, CompressionError> {
+        let count = self.count()?;
+        (0..count).map(|_| self.coord()).collect()
+    }
+
+    fn ring<T: CoordFloat>(&mut self) -> Result<LineString<T>, CompressionError> {
+        // Closed again even if snapping to the grid has collapsed it to a point
+        let mut coords = self.coords()?;
+        if let Some(&first) = coords.first() {
+            coords.push(first);
+        }
+        Ok(LineString::new(coords))
+    }
+
+    fn polygon<T: CoordFloat>(&mut self) -> Result<Polygon<T>, CompressionError> {
+        let interiors = self.count()?;
+        let exterior = self.ring()?;
+        let interiors = (0..interiors)
+            .map(|_| self.ring())
+            .collect::<Result<_, _>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    fn parts<G>(
+        &mut self,
+        mut decode: impl FnMut(&mut Self) -> Result<G, CompressionError>,
+    ) -> Result<Vec<G>, CompressionError> {
+        let count = self.count()?;
+        (0..count).map(|_| decode(self)).collect()
+    }
+
+    fn geometry<T: CoordFloat>(&mut self, depth: usize) -> Result<Geometry<T>, CompressionError> {
+        if depth > MAX_DEPTH {
+            return Err(CompressionError::InvalidData);
+        }
+        let geometry = match self.byte()? {
+            tag::POINT => Point(self.coord()?).into(),
+            tag::LINE => Line::new(self.coord()?, self.coord()?).into(),
+            tag::LINE_STRING => LineString::new(self.coords()?).into(),
+            tag::POLYGON => self.polygon()?.into(),
+            tag::MULTI_POINT => MultiPoint::new(self.parts(|d| Ok(Point(d.coord()?)))?).into(),
+            tag::MULTI_LINE_STRING => {
+                MultiLineString::new(self.parts(|d| Ok(LineString::new(d.coords()?)))?).into()
+            }
+            tag::MULTI_POLYGON => MultiPolygon::new(self.parts(Self::polygon)?).into(),
+            tag::GEOMETRY_COLLECTION => Geometry::GeometryCollection(GeometryCollection::new_from(
+                self.parts(|d| d.geometry(depth + 1))?,
+            )),
+            tag::RECT => Rect::new(self.coord()?, self.coord()?).into(),
+            tag::TRIANGLE => Triangle::new(self.coord()?, self.coord()?, self.coord()?).into(),
+            _ => return Err(CompressionError::InvalidData),
+        };
+        if depth == 0 && !self.bytes.is_empty() {
+            return Err(CompressionError::InvalidData);
+        }
+        Ok(geometry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, wkt, CoordsIter, EuclideanDistance};
+
+    fn assert_within(original: &Geometry, restored: &Geometry, max_error: f64) {
+        assert_eq!(original.coords_count(), restored.coords_count());
+        for (a, b) in original.coords_iter().zip(restored.coords_iter()) {
+            assert!(
+                Point(a).euclidean_distance(&Point(b)) < max_error,
+                "{a:?} {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let geometries: Vec<Geometry> = vec![
+            point!(x: -1.23456, y: 9876.54321).into(),
+            Line::new((0.1, 0.2), (-0.3, 0.4)).into(),
+            line_string![(x: 0., y: 0.), (x: 1e4, y: -1e4), (x: 1e4 + 0.5, y: 3.)].into(),
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.)]],
+            ]
+            .into(),
+            wkt!(MULTIPOINT(1. 2.,3. 4.)).into(),
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. 3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,0. 1.,0. 0.)),((5. 5.,6. 5.,5. 6.,5. 5.)))).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 1.).into(),
+                Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(LINESTRING(0. 0.,7. 7.)))),
+            ])),
+            Rect::new((-5., -5.), (5.5, 6.5)).into(),
+            Triangle::new((0., 0.).into(), (1., 0.).into(), (0., 1.).into()).into(),
+            LineString::<f64>::new(vec![]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+        ];
+        for max_error in [1e-7, 0.01, 3.] {
+            for geometry in &geometries {
+                let compressed = geometry.compress(max_error).unwrap();
+                let restored: Geometry = compressed.decompress().unwrap();
+                assert_within(geometry, &restored, max_error);
+            }
+        }
+
+        // Coordinates on the grid are restored exactly, and take a byte each
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 1., y: 2.), (x: 3., y: 2.)];
+        let compressed = line_string.compress(1.).unwrap();
+        assert_eq!(compressed.as_bytes().len(), 8 + 1 + 1 + 6);
+        assert_eq!(
+            compressed.decompress(),
+            Ok(Geometry::LineString(line_string.clone()))
+        );
+        let restored = CompressedGeometry::from_bytes(compressed.into_bytes());
+        assert_eq!(
+            restored.decompress::<f32>(),
+            Ok(line_string![(x: 0., y: 0.), (x: 1., y: 2.), (x: 3., y: 2.)].into())
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let point = point!(x: 1., y: 1.);
+        assert_eq!(point.compress(0.), Err(CompressionError::InvalidMaxError));
+        assert_eq!(
+            point.compress(f64::NAN),
+            Err(CompressionError::InvalidMaxError)
+        );
+        assert_eq!(
+            point!(x: f64::INFINITY, y: 0.).compress(1.),
+            Err(CompressionError::CoordOutOfRange)
+        );
+        assert_eq!(
+            point!(x: 1e20, y: 0.).compress(1.),
+            Err(CompressionError::CoordOutOfRange)
+        );
+
+        let bytes = line_string![coord! { x: 1., y: 2. }, coord! { x: 3., y: 4. }]
+            .compress(0.5)
+            .unwrap()
+            .into_bytes();
+        for invalid in [
+            bytes[..7].to_vec(),
+            bytes[..bytes.len() - 1].to_vec(),
+            [bytes.as_slice(), &[0]].concat(),
+            [&bytes[..8], &[42]].concat(),
+        ] {
+            let decompressed = CompressedGeometry::from_bytes(invalid).decompress::<f64>();
+            assert_eq!(decompressed, Err(CompressionError::InvalidData));
+        }
+    }
+}
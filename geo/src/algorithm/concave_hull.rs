@@ -1,11 +1,13 @@
 use crate::convex_hull::qhull;
+use crate::error::check_finite;
 use crate::utils::partial_min;
 use crate::{
-    coord, Centroid, Coord, CoordNum, EuclideanDistance, EuclideanLength, GeoFloat, Line,
-    LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    coord, Centroid, Coord, CoordNum, CoordsIter, Error, EuclideanDistance, EuclideanLength,
+    GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
 };
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use num_traits::ToPrimitive;
 use rstar::{RTree, RTreeNum};
-use std::collections::VecDeque;
 
 /// Returns a polygon which covers a geometry. Unlike convex hulls, which also cover
 /// their geometry, a concave hull does so while trying to further minimize its area by
@@ -44,6 +46,22 @@ use std::collections::VecDeque;
 pub trait ConcaveHull {
     type Scalar: CoordNum;
     fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar>;
+
+    /// The same as [`concave_hull`](Self::concave_hull), but returns an error rather than
+    /// panicking on a coordinate, or a `concavity`, which isn't finite.
+    fn try_concave_hull(
+        &self,
+        concavity: <Self as ConcaveHull>::Scalar,
+    ) -> Result<Polygon<<Self as ConcaveHull>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as ConcaveHull>::Scalar>,
+    {
+        if !concavity.to_f64().is_some_and(f64::is_finite) {
+            return Err(Error::InvalidParameter("concavity"));
+        }
+        check_finite(self)?;
+        Ok(self.concave_hull(concavity))
+    }
 }
 
 impl<T> ConcaveHull for Polygon<T>
@@ -446,4 +464,23 @@ mod test {
         ];
         assert_eq!(res.exterior().0, correct);
     }
+
+    #[test]
+    fn try_concave_hull_test() {
+        let square =
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)];
+        assert_eq!(
+            square.try_concave_hull(2.0).unwrap(),
+            square.concave_hull(2.0)
+        );
+        assert!(matches!(
+            square.try_concave_hull(f64::NAN),
+            Err(Error::InvalidParameter("concavity"))
+        ));
+        let nan = polygon![(x: 0.0, y: 0.0), (x: f64::NAN, y: 0.0), (x: 0.0, y: 1.0)];
+        assert!(matches!(
+            nan.try_concave_hull(2.0),
+            Err(Error::NonFiniteCoordinate)
+        ));
+    }
 }
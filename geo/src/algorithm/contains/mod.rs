@@ -43,6 +43,8 @@ mod line_string;
 mod point;
 mod polygon;
 mod rect;
+mod semantics;
+pub use semantics::{BoundarySemantics, ContainsWith};
 mod triangle;
 
 macro_rules! impl_contains_from_relate {
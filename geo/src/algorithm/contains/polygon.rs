@@ -1,5 +1,6 @@
 use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
 use crate::geometry::*;
+use crate::view::PolygonRef;
 use crate::Relate;
 use crate::{GeoFloat, GeoNum};
 
@@ -29,6 +30,30 @@ where
 impl_contains_from_relate!(Polygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
 impl_contains_geometry_for!(Polygon<T>);
 
+// ┌────────────────────────────────┐
+// │ Implementations for PolygonRef │
+// └────────────────────────────────┘
+
+impl<T> Contains<Coord<T>> for PolygonRef<'_, T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, coord: &Coord<T>) -> bool {
+        use crate::coordinate_position::{CoordPos, CoordinatePosition};
+
+        self.coordinate_position(coord) == CoordPos::Inside
+    }
+}
+
+impl<T> Contains<Point<T>> for PolygonRef<'_, T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.contains(&p.0)
+    }
+}
+
 // ┌──────────────────────────────────┐
 // │ Implementations for MultiPolygon │
 // └──────────────────────────────────┘
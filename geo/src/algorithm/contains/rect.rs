@@ -1,6 +1,7 @@
 use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use crate::bool_ops::{ccw_rect, ccw_triangle, within};
 use crate::geometry::*;
-use crate::{CoordNum, GeoFloat};
+use crate::{CoordNum, GeoFloat, Relate};
 
 // ┌──────────────────────────┐
 // │ Implementations for Rect │
@@ -41,5 +42,18 @@ where
     }
 }
 
-impl_contains_from_relate!(Rect<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Triangle<T>]);
+impl<T> Contains<Triangle<T>> for Rect<T>
+where
+    T: GeoFloat,
+{
+    fn contains(&self, other: &Triangle<T>) -> bool {
+        // With some area, a triangle is within a rectangle if its vertices are
+        match (ccw_rect(self), ccw_triangle(other)) {
+            (Some(rect), Some(triangle)) => within(&triangle, &rect, false),
+            _ => self.relate(other).is_contains(),
+        }
+    }
+}
+
+impl_contains_from_relate!(Rect<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>]);
 impl_contains_geometry_for!(Rect<T>);
@@ -0,0 +1,209 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::dimensions::Dimensions;
+use crate::geometry::*;
+use crate::{GeoFloat, GeoNum, Relate};
+
+/// Whether the boundary of a geometry counts as part of it, for [`ContainsWith`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundarySemantics {
+    /// The geometry includes its boundary, so a point on the edge of a polygon, or a polygon
+    /// which shares an edge with it from inside, is contained.
+    Inclusive,
+    /// The geometry excludes its boundary, so only what lies in its interior, without touching
+    /// its boundary, is contained.
+    Exclusive,
+}
+
+/// Checks if `rhs` is contained by `self`, with an explicit choice of whether the boundary of
+/// `self` counts, so that points and shapes which lie exactly on the boundary are treated
+/// consistently with the policy of the application.
+///
+/// [`Contains`](crate::Contains) follows the DE-9IM: a point on the boundary of a polygon isn't
+/// contained, while a polygon which touches the boundary from inside is. With
+/// [`Inclusive`](BoundarySemantics::Inclusive) semantics both are contained, which is the
+/// [covers](crate::algorithm::relate::IntersectionMatrix::is_covers) predicate, and with
+/// [`Exclusive`](BoundarySemantics::Exclusive) neither is, which is sometimes called "contains
+/// properly".
+///
+/// Points are tested against any geometry with a [`CoordinatePosition`], and other geometries
+/// against polygons, rectangles and triangles.
+///
+/// Neither semantics partitions the plane: a point on an edge shared by two tiles is in both of
+/// them with inclusive semantics, and in neither with exclusive semantics. To count each point
+/// once, such as with [`Tile::at`](crate::Tile::at), the edges need to be divided between them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, BoundarySemantics, Contains, ContainsWith};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let on_edge = point!(x: 2., y: 1.);
+///
+/// assert!(!square.contains(&on_edge));
+/// assert!(square.contains_inclusive(&on_edge));
+/// assert!(!square.contains_with(&on_edge, BoundarySemantics::Exclusive));
+///
+/// // A quarter of the square, sharing two of its edges
+/// let quarter = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert!(square.contains(&quarter));
+/// assert!(square.contains_inclusive(&quarter));
+/// assert!(!square.contains_exclusive(&quarter));
+/// ```
+pub trait ContainsWith<Rhs = Self> {
+    /// Whether `rhs` is contained by `self`, with or without the boundary of `self`.
+    fn contains_with(&self, rhs: &Rhs, semantics: BoundarySemantics) -> bool;
+
+    /// Whether `rhs` is contained by `self`, including its boundary.
+    fn contains_inclusive(&self, rhs: &Rhs) -> bool {
+        self.contains_with(rhs, BoundarySemantics::Inclusive)
+    }
+
+    /// Whether `rhs` is contained by the interior of `self`, not touching its boundary.
+    fn contains_exclusive(&self, rhs: &Rhs) -> bool {
+        self.contains_with(rhs, BoundarySemantics::Exclusive)
+    }
+}
+
+fn position_is_contained(position: CoordPos, semantics: BoundarySemantics) -> bool {
+    match position {
+        CoordPos::Inside => true,
+        CoordPos::OnBoundary => semantics == BoundarySemantics::Inclusive,
+        CoordPos::Outside => false,
+    }
+}
+
+impl<T, G> ContainsWith<Coord<T>> for G
+where
+    T: GeoNum,
+    G: CoordinatePosition<Scalar = T>,
+{
+    fn contains_with(&self, coord: &Coord<T>, semantics: BoundarySemantics) -> bool {
+        position_is_contained(self.coordinate_position(coord), semantics)
+    }
+}
+
+impl<T, G> ContainsWith<Point<T>> for G
+where
+    T: GeoNum,
+    G: CoordinatePosition<Scalar = T>,
+{
+    fn contains_with(&self, point: &Point<T>, semantics: BoundarySemantics) -> bool {
+        position_is_contained(self.coordinate_position(&point.0), semantics)
+    }
+}
+
+macro_rules! impl_contains_with_from_relate {
+    ($for:ident, [$($target:ident),*]) => {
+        $(
+            impl<T: GeoFloat> ContainsWith<$target<T>> for $for<T> {
+                fn contains_with(
+                    &self,
+                    target: &$target<T>,
+                    semantics: BoundarySemantics,
+                ) -> bool {
+                    let matrix = self.relate(target);
+                    match semantics {
+                        BoundarySemantics::Inclusive => matrix.is_covers(),
+                        BoundarySemantics::Exclusive => {
+                            let disjoint = |lhs, rhs| matrix.get(lhs, rhs) == Dimensions::Empty;
+                            matrix.is_contains()
+                                && disjoint(CoordPos::OnBoundary, CoordPos::Inside)
+                                && disjoint(CoordPos::OnBoundary, CoordPos::OnBoundary)
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_contains_with_for_areal {
+    ($($for:ident),*) => {
+        $(
+            impl_contains_with_from_relate!(
+                $for,
+                [Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, Rect, Triangle]
+            );
+        )*
+    };
+}
+
+impl_contains_with_for_areal!(Polygon, MultiPolygon, Rect, Triangle);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, Contains, Intersects};
+    use BoundarySemantics::*;
+
+    #[test]
+    fn points() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let points = [
+            point!(x: 3., y: 3.),
+            point!(x: 0., y: 2.),
+            point!(x: 1.5, y: 1.),
+            point!(x: 1.5, y: 1.5),
+            point!(x: 5., y: 5.),
+        ];
+        for point in points {
+            assert_eq!(
+                polygon.contains_inclusive(&point),
+                polygon.intersects(&point)
+            );
+            assert_eq!(polygon.contains_exclusive(&point), polygon.contains(&point));
+            assert_eq!(
+                polygon.contains_with(&point.0, Inclusive),
+                polygon.contains_inclusive(&point)
+            );
+        }
+        assert!(polygon.contains_inclusive(&points[2]));
+        assert!(!polygon.contains_exclusive(&points[2]));
+
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 10 });
+        assert!(rect.contains_inclusive(&coord! { x: 10, y: 5 }));
+        assert!(!rect.contains_exclusive(&coord! { x: 10, y: 5 }));
+
+        // The ends of a line string are its boundary
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        assert!(line_string.contains_exclusive(&point!(x: 1., y: 0.)));
+        assert!(!line_string.contains_exclusive(&point!(x: 0., y: 0.)));
+        assert!(line_string.contains_inclusive(&point!(x: 0., y: 0.)));
+    }
+
+    #[test]
+    fn geometries() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let inner = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+        let corner = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. });
+        let crossing = Rect::new(coord! { x: 3., y: 3. }, coord! { x: 5., y: 5. });
+
+        assert!(square.contains_exclusive(&inner));
+        assert!(square.contains_inclusive(&inner));
+        assert!(square.contains_inclusive(&corner));
+        assert!(!square.contains_exclusive(&corner));
+        assert!(square.contains_inclusive(&square));
+        assert!(!square.contains_exclusive(&square));
+        assert!(!square.contains_inclusive(&crossing));
+
+        // Lying along the boundary is contained only with inclusive semantics, unlike for
+        // `Contains`, by which a line on the boundary isn't in the interior at all
+        let edge = Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. });
+        assert!(!square.contains(&edge));
+        assert!(square.contains_inclusive(&edge));
+        assert!(!square.contains_exclusive(&edge));
+
+        let diagonal = Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+        assert!(square.contains(&diagonal));
+        assert!(!square.contains_exclusive(&diagonal));
+
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        let points = MultiPoint::from(vec![(1., 1.), (2., 2.)]);
+        assert!(triangle.contains_inclusive(&points));
+        assert!(!triangle.contains_exclusive(&points));
+    }
+}
@@ -1,6 +1,7 @@
 use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use crate::bool_ops::{ccw_rect, ccw_triangle, within};
 use crate::geometry::*;
-use crate::{kernels::Kernel, GeoFloat, GeoNum, Orientation};
+use crate::{kernels::Kernel, GeoFloat, GeoNum, Orientation, Relate};
 
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
@@ -48,5 +49,30 @@ where
     }
 }
 
-impl_contains_from_relate!(Triangle<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl<T> Contains<Rect<T>> for Triangle<T>
+where
+    T: GeoFloat,
+{
+    fn contains(&self, other: &Rect<T>) -> bool {
+        // With some area, a rectangle is within a triangle if its corners are
+        match (ccw_triangle(self), ccw_rect(other)) {
+            (Some(triangle), Some(rect)) => within(&rect, &triangle, false),
+            _ => self.relate(other).is_contains(),
+        }
+    }
+}
+
+impl<T> Contains<Triangle<T>> for Triangle<T>
+where
+    T: GeoFloat,
+{
+    fn contains(&self, other: &Triangle<T>) -> bool {
+        match (ccw_triangle(self), ccw_triangle(other)) {
+            (Some(a), Some(b)) => within(&b, &a, false),
+            _ => self.relate(other).is_contains(),
+        }
+    }
+}
+
+impl_contains_from_relate!(Triangle<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>]);
 impl_contains_geometry_for!(Triangle<T>);
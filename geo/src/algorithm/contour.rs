@@ -0,0 +1,442 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::{vec, vec::Vec};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::kernels::{Kernel, Orientation};
+use crate::{
+    coord, Area, Coord, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon, Raster,
+};
+
+/// Extract contours from a [`Raster`] of values, as lines of equal value, or as the areas of
+/// values between two levels.
+///
+/// The value of each cell is taken to be at its center, and values are interpolated linearly
+/// between the centers of neighbouring cells, so contours extend to half a cell from the edges of
+/// the raster's `bounds`. This is the [marching squares](https://en.wikipedia.org/wiki/Marching_squares)
+/// algorithm, but each square between four cell centers is split into four triangles around its
+/// center, whose value is the mean of the four, which resolves the ambiguous saddle cases.
+///
+/// Squares with a `NaN` value at any corner are treated as having no data, and have no contours.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, Contour, Raster, Rect};
+///
+/// // A peak of 2 in the middle of a 3 x 3 grid of 0s, covering (0, 0) - (3, 3)
+/// let mut raster = Raster::new(Rect::new((0., 0.), (3., 3.)), 3, 3, 0.);
+/// raster.values[4] = 2.;
+///
+/// let lines = raster.isolines(&[1.]);
+/// // A diamond around the center of the grid
+/// assert_eq!(lines[0].0.len(), 1);
+/// assert!(lines[0].0[0].is_closed());
+///
+/// let bands = raster.isobands(&[1., 3.]);
+/// approx::assert_relative_eq!(bands[0].unsigned_area(), 2. / 3.);
+/// ```
+pub trait Contour<T: GeoFloat> {
+    /// The lines along which the values are equal to each of `thresholds`.
+    ///
+    /// Lines which don't reach the edge of the data are closed.
+    fn isolines(&self, thresholds: &[T]) -> Vec<MultiLineString<T>>;
+
+    /// The areas in which the values are between each consecutive pair of `thresholds`, which
+    /// should be sorted in increasing order.
+    ///
+    /// There's one fewer band than `thresholds`. The outer rings of the polygons are
+    /// counter-clockwise, and their holes clockwise.
+    fn isobands(&self, thresholds: &[T]) -> Vec<MultiPolygon<T>>;
+}
+
+/// A point of the triangulated lattice of cell centers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Vertex {
+    /// A cell center, or the center of a square between four cell centers.
+    Node(usize),
+    /// The point where a level crosses the edge between two nodes, the smaller one first.
+    Crossing(usize, usize, usize),
+}
+
+/// The triangles of the lattice of cell centers, with the coordinates and values of its nodes.
+struct Lattice<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    values: Vec<T>,
+    /// Nodes of each triangle, counter-clockwise.
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<T: GeoFloat> Lattice<T> {
+    fn new(raster: &Raster<T, T>) -> Self {
+        let (rows, columns) = (raster.rows, raster.columns);
+        let mut lattice = Lattice {
+            coords: vec![],
+            values: raster.values.clone(),
+            triangles: vec![],
+        };
+        if rows < 2 || columns < 2 || raster.values.len() != rows * columns {
+            return lattice;
+        }
+        let size = raster.cell_size();
+        let half = T::from(0.5).unwrap();
+        let (left, top) = (raster.bounds.min().x, raster.bounds.max().y);
+        let at = |row: T, column: T| coord! { x: left + size.x * column, y: top - size.y * row };
+        for row in 0..rows {
+            for column in 0..columns {
+                let (row, column) = (T::from(row).unwrap(), T::from(column).unwrap());
+                lattice.coords.push(at(row + half, column + half));
+            }
+        }
+        let four = T::from(4).unwrap();
+        for row in 0..rows - 1 {
+            for column in 0..columns - 1 {
+                let top_left = row * columns + column;
+                let corners = [
+                    top_left,
+                    top_left + columns,
+                    top_left + columns + 1,
+                    top_left + 1,
+                ];
+                let values = corners.map(|corner| raster.values[corner]);
+                if values.iter().any(|value| value.is_nan()) {
+                    continue;
+                }
+                let center = lattice.coords.len();
+                let (row, column) = (T::from(row).unwrap(), T::from(column).unwrap());
+                lattice.coords.push(at(row + T::one(), column + T::one()));
+                lattice
+                    .values
+                    .push(values.iter().fold(T::zero(), |sum, &value| sum + value) / four);
+                // The corners go down, across and up, which is counter-clockwise as y decreases
+                // with rows
+                for i in 0..4 {
+                    lattice
+                        .triangles
+                        .push([corners[i], corners[(i + 1) % 4], center]);
+                }
+            }
+        }
+        lattice
+    }
+
+    fn coord(&self, vertex: Vertex, levels: &[T]) -> Coord<T> {
+        match vertex {
+            Vertex::Node(node) => self.coords[node],
+            Vertex::Crossing(a, b, level) => {
+                let (va, vb) = (self.values[a], self.values[b]);
+                let f = (levels[level] - va) / (vb - va);
+                self.coords[a] + (self.coords[b] - self.coords[a]) * f
+            }
+        }
+    }
+
+    /// The point where `level` crosses strictly between the nodes `a` and `b`, if it does.
+    fn crossing(&self, a: usize, b: usize, levels: &[T], level: usize) -> Option<Vertex> {
+        let (va, vb) = (self.values[a], self.values[b]);
+        let l = levels[level];
+        ((va < l && l < vb) || (vb < l && l < va))
+            .then(|| Vertex::Crossing(a.min(b), a.max(b), level))
+    }
+}
+
+impl<T: GeoFloat> Contour<T> for Raster<T, T> {
+    fn isolines(&self, thresholds: &[T]) -> Vec<MultiLineString<T>> {
+        let lattice = Lattice::new(self);
+        (0..thresholds.len())
+            .map(|level| {
+                let threshold = thresholds[level];
+                // Each segment joins the points at which the level crosses two edges of a
+                // triangle
+                let mut neighbours: BTreeMap<Vertex, Vec<Vertex>> = BTreeMap::new();
+                for triangle in &lattice.triangles {
+                    let mut ends = vec![];
+                    for i in 0..3 {
+                        let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                        let (above_a, above_b) = (
+                            lattice.values[a] >= threshold,
+                            lattice.values[b] >= threshold,
+                        );
+                        if above_a != above_b {
+                            ends.push(Vertex::Crossing(a.min(b), a.max(b), level));
+                        }
+                    }
+                    if let [start, end] = ends[..] {
+                        neighbours.entry(start).or_default().push(end);
+                        neighbours.entry(end).or_default().push(start);
+                    }
+                }
+                let lines = chain(neighbours)
+                    .into_iter()
+                    .map(|vertices| {
+                        vertices
+                            .into_iter()
+                            .map(|vertex| lattice.coord(vertex, thresholds))
+                            .collect()
+                    })
+                    .collect();
+                MultiLineString::new(lines)
+            })
+            .collect()
+    }
+
+    fn isobands(&self, thresholds: &[T]) -> Vec<MultiPolygon<T>> {
+        let lattice = Lattice::new(self);
+        (1..thresholds.len())
+            .map(|high| {
+                let low = high - 1;
+                // The boundary edges of the union of the parts of the triangles within the band,
+                // from which the edges between neighbouring parts cancel out
+                let mut edges: BTreeSet<(Vertex, Vertex)> = BTreeSet::new();
+                for triangle in &lattice.triangles {
+                    let mut part = vec![];
+                    for i in 0..3 {
+                        let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                        let value = lattice.values[a];
+                        if thresholds[low] <= value && value <= thresholds[high] {
+                            part.push(Vertex::Node(a));
+                        }
+                        let mut crossings: Vec<Vertex> = [low, high]
+                            .into_iter()
+                            .filter_map(|level| lattice.crossing(a, b, thresholds, level))
+                            .collect();
+                        // In order from `a` to `b`
+                        if lattice.values[a] > lattice.values[b] {
+                            crossings.reverse();
+                        }
+                        part.extend(crossings);
+                    }
+                    if part.len() < 3 {
+                        continue;
+                    }
+                    for i in 0..part.len() {
+                        let edge = (part[i], part[(i + 1) % part.len()]);
+                        if !edges.remove(&(edge.1, edge.0)) {
+                            edges.insert(edge);
+                        }
+                    }
+                }
+                let mut next: BTreeMap<Vertex, Vec<Vertex>> = BTreeMap::new();
+                for (a, b) in edges {
+                    next.entry(a).or_default().push(b);
+                }
+                let rings = rings(next)
+                    .into_iter()
+                    .map(|ring| {
+                        let coords: Vec<Coord<T>> = ring
+                            .into_iter()
+                            .map(|vertex| lattice.coord(vertex, thresholds))
+                            .collect();
+                        remove_collinear(coords)
+                    })
+                    .filter(|ring| ring.0.len() >= 4)
+                    .collect();
+                assemble(rings)
+            })
+            .collect()
+    }
+}
+
+/// Join segments, given the neighbours of each of their ends, into lines.
+fn chain(mut neighbours: BTreeMap<Vertex, Vec<Vertex>>) -> Vec<Vec<Vertex>> {
+    let mut lines = vec![];
+    // Open lines start at a vertex with a single neighbour, then the rest are closed
+    let mut starts: Vec<Vertex> = neighbours
+        .iter()
+        .filter(|(_, next)| next.len() == 1)
+        .map(|(vertex, _)| *vertex)
+        .collect();
+    starts.extend(neighbours.keys().copied().collect::<Vec<_>>());
+    for start in starts {
+        if neighbours.get(&start).map_or(true, Vec::is_empty) {
+            continue;
+        }
+        let mut line = vec![start];
+        let mut current = start;
+        while let Some(next) = neighbours.get_mut(&current).and_then(Vec::pop) {
+            if let Some(back) = neighbours.get_mut(&next) {
+                if let Some(position) = back.iter().position(|&vertex| vertex == current) {
+                    back.swap_remove(position);
+                }
+            }
+            line.push(next);
+            current = next;
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Join directed edges, given the ends of the edges from each vertex, into closed rings.
+fn rings(mut next: BTreeMap<Vertex, Vec<Vertex>>) -> Vec<Vec<Vertex>> {
+    let mut rings = vec![];
+    let starts: Vec<Vertex> = next.keys().copied().collect();
+    for start in starts {
+        while next.get(&start).is_some_and(|ends| !ends.is_empty()) {
+            let mut ring = vec![start];
+            let mut current = start;
+            while let Some(end) = next.get_mut(&current).and_then(Vec::pop) {
+                ring.push(end);
+                if end == start {
+                    break;
+                }
+                current = end;
+            }
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// Remove the vertices of a closed ring which are on the straight line between their neighbours.
+fn remove_collinear<T: GeoFloat>(mut coords: Vec<Coord<T>>) -> LineString<T> {
+    coords.pop();
+    let mut kept: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for (i, &coord) in coords.iter().enumerate() {
+        let prev = kept.last().copied().unwrap_or(coords[coords.len() - 1]);
+        let next = coords[(i + 1) % coords.len()];
+        if T::Ker::orient2d(prev, coord, next) != Orientation::Collinear {
+            kept.push(coord);
+        }
+    }
+    if let Some(&first) = kept.first() {
+        kept.push(first);
+    }
+    LineString::new(kept)
+}
+
+/// Build polygons from counter-clockwise exteriors and clockwise holes, putting each hole in the
+/// smallest exterior which contains it.
+fn assemble<T: GeoFloat>(rings: Vec<LineString<T>>) -> MultiPolygon<T> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .map(|ring| Polygon::new(ring, vec![]))
+        .partition(|polygon| polygon.signed_area() > T::zero());
+    let mut interiors = vec![vec![]; exteriors.len()];
+    for hole in holes {
+        let inside = hole.exterior().0[0];
+        let container = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, exterior)| exterior.coordinate_position(&inside) != CoordPos::Outside)
+            .min_by(|(_, a), (_, b)| {
+                a.unsigned_area()
+                    .partial_cmp(&b.unsigned_area())
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            });
+        if let Some((index, _)) = container {
+            interiors[index].push(hole.exterior().clone());
+        }
+    }
+    MultiPolygon::new(
+        exteriors
+            .into_iter()
+            .zip(interiors)
+            .map(|(exterior, interiors)| {
+                let (exterior, _) = exterior.into_inner();
+                Polygon::new(exterior, interiors)
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Contains, EuclideanLength, Point, Rect};
+
+    /// A raster of `f` at the centers of the cells of a 20 x 20 grid, covering (-1, -1) - (1, 1).
+    fn sampled(f: impl Fn(f64, f64) -> f64) -> Raster<f64> {
+        let mut raster = Raster::new(Rect::new((-1., -1.), (1., 1.)), 20, 20, 0.);
+        for row in 0..20 {
+            for column in 0..20 {
+                let center = raster.cell_rect(row, column).center();
+                raster.values[row * 20 + column] = f(center.x, center.y);
+            }
+        }
+        raster
+    }
+
+    #[test]
+    fn cone() {
+        let raster = sampled(|x, y| x.hypot(y));
+        let lines = raster.isolines(&[0.5, 2.]);
+        // The circle of radius 0.5, and nothing at 2
+        assert_eq!(lines[0].0.len(), 1);
+        assert!(lines[0].0[0].is_closed());
+        for coord in &lines[0].0[0].0 {
+            assert!((coord.x.hypot(coord.y) - 0.5).abs() < 0.01);
+        }
+        assert!((lines[0].euclidean_length() - core::f64::consts::PI).abs() < 0.02);
+        assert!(lines[1].0.is_empty());
+
+        let bands = raster.isobands(&[0., 0.5, 0.8]);
+        let disk = &bands[0];
+        assert_eq!(disk.0.len(), 1);
+        assert!(disk.0[0].interiors().is_empty());
+        assert!((disk.unsigned_area() - core::f64::consts::PI * 0.25).abs() < 0.01);
+        // An annulus, with the disk as its hole
+        let annulus = &bands[1];
+        assert_eq!(annulus.0.len(), 1);
+        assert_eq!(annulus.0[0].interiors().len(), 1);
+        assert!(!annulus.contains(&Point::new(0., 0.)));
+        assert!(annulus.contains(&Point::new(0.65, 0.)));
+        let expected = core::f64::consts::PI * (0.64 - 0.25);
+        assert!((annulus.unsigned_area() - expected).abs() < 0.02);
+    }
+
+    #[test]
+    fn open_lines_and_edges() {
+        // A plane, rising to the right
+        let raster = sampled(|x, _| x);
+        let lines = raster.isolines(&[0.]);
+        assert_eq!(lines[0].0.len(), 1);
+        let line = &lines[0].0[0];
+        assert!(!line.is_closed());
+        for coord in &line.0 {
+            assert_relative_eq!(coord.x, 0., epsilon = 1e-12);
+        }
+        // Contours stop at the outermost cell centers
+        assert_relative_eq!(line.euclidean_length(), 1.9, epsilon = 1e-12);
+
+        // Bands are clipped to the area of the data
+        let bands = raster.isobands(&[-2., 0., 2.]);
+        assert_relative_eq!(bands[0].unsigned_area(), 0.95 * 1.9, epsilon = 1e-12);
+        assert_relative_eq!(bands[1].unsigned_area(), 0.95 * 1.9, epsilon = 1e-12);
+        // The collinear points along the edges are removed
+        let whole = &raster.isobands(&[-2., 2.])[0];
+        assert_eq!(whole.0.len(), 1);
+        assert_eq!(whole.0[0].exterior().0.len(), 5);
+    }
+
+    #[test]
+    fn bands_cover_the_data() {
+        let raster = sampled(|x, y| (3. * x).sin() * (2. * y).cos());
+        let thresholds = [-1., -0.5, -0.1, 0.2, 0.7, 1.];
+        let bands = raster.isobands(&thresholds);
+        let total: f64 = bands.iter().map(|band| band.unsigned_area()).sum();
+        assert_relative_eq!(total, 1.9 * 1.9, epsilon = 1e-9);
+        for band in &bands {
+            for polygon in band {
+                assert!(polygon.signed_area() > 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn no_data() {
+        let mut raster = sampled(|x, _| x);
+        for row in 0..20 {
+            raster.values[row * 20 + 10] = f64::NAN;
+        }
+        // The squares on either side of the missing column have no data
+        let bands = raster.isobands(&[-2., 2.]);
+        assert_eq!(bands[0].0.len(), 2);
+        assert_relative_eq!(bands[0].unsigned_area(), (0.9 + 0.8) * 1.9, epsilon = 1e-12);
+        let lines = raster.isolines(&[0.]);
+        assert!(lines[0].0.is_empty());
+
+        let empty = Raster::new(Rect::new((0., 0.), (1., 1.)), 1, 1, 0.);
+        assert!(empty.isolines(&[0.])[0].0.is_empty());
+        assert!(empty.isobands(&[0.]).is_empty());
+    }
+}
@@ -1,6 +1,8 @@
+use crate::error::check_finite;
 use crate::geometry::{Coord, LineString, Polygon};
 use crate::kernels::*;
-use crate::GeoNum;
+use crate::{Error, GeoNum};
+use alloc::{vec, vec::Vec};
 
 /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
 ///
@@ -41,6 +43,28 @@ use crate::GeoNum;
 pub trait ConvexHull<'a, T> {
     type Scalar: GeoNum;
     fn convex_hull(&'a self) -> Polygon<Self::Scalar>;
+
+    /// The same as [`convex_hull`](Self::convex_hull), but returns an error rather than
+    /// panicking on a coordinate which isn't finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, ConvexHull, Error};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.), (x: 2., y: 0.)];
+    /// assert!(matches!(
+    ///     line_string.try_convex_hull(),
+    ///     Err(Error::NonFiniteCoordinate)
+    /// ));
+    /// ```
+    fn try_convex_hull(&'a self) -> Result<Polygon<<Self as ConvexHull<'a, T>>::Scalar>, Error>
+    where
+        Self: CoordsIter<Scalar = <Self as ConvexHull<'a, T>>::Scalar>,
+    {
+        check_finite(self)?;
+        Ok(self.convex_hull())
+    }
 }
 
 use crate::algorithm::CoordsIter;
@@ -106,7 +130,7 @@ where
 /// 3. return a _mutable ref_ to the removed head element
 fn swap_with_first_and_remove<'a, T>(slice: &mut &'a mut [T], idx: usize) -> &'a mut T {
     // temporarily replace `slice` with an empty value
-    let tmp = std::mem::take(slice);
+    let tmp = core::mem::take(slice);
     tmp.swap(0, idx);
     let (h, t) = tmp.split_first_mut().unwrap();
     *slice = t;
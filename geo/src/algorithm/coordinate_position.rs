@@ -1,8 +1,9 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::geometry::*;
 use crate::intersects::{point_in_rect, value_in_between};
 use crate::kernels::*;
+use crate::view::{LineStringSlice, PolygonRef};
 use crate::{BoundingRect, HasDimensions, Intersects};
 use crate::{GeoNum, GeometryCow};
 
@@ -175,6 +176,43 @@ where
     }
 }
 
+impl<T> CoordinatePosition for LineStringSlice<'_, T>
+where
+    T: GeoNum,
+{
+    type Scalar = T;
+    fn calculate_coordinate_position(
+        &self,
+        coord: &Coord<T>,
+        is_inside: &mut bool,
+        boundary_count: &mut usize,
+    ) {
+        if self.0.len() < 2 {
+            debug_assert!(false, "invalid line string with less than 2 coords");
+            return;
+        }
+
+        // optimization: return early if there's no chance of an intersection
+        // since self.0 is non-empty, safe to `unwrap`
+        if !self.bounding_rect().unwrap().intersects(coord) {
+            return;
+        }
+
+        // A closed linestring has no boundary, per SFS
+        if !self.is_closed() {
+            // since self.0 is non-empty, safe to `unwrap`
+            if coord == self.0.first().unwrap() || coord == self.0.last().unwrap() {
+                *boundary_count += 1;
+                return;
+            }
+        }
+
+        if self.lines().any(|line| line.intersects(coord)) {
+            *is_inside = true
+        }
+    }
+}
+
 impl<T> CoordinatePosition for Triangle<T>
 where
     T: GeoNum,
@@ -282,27 +320,70 @@ where
             return;
         }
 
-        match coord_pos_relative_to_ring(*coord, self.exterior()) {
-            CoordPos::Outside => {}
-            CoordPos::OnBoundary => {
-                *boundary_count += 1;
-            }
-            CoordPos::Inside => {
-                for hole in self.interiors() {
-                    match coord_pos_relative_to_ring(*coord, hole) {
-                        CoordPos::Outside => {}
-                        CoordPos::OnBoundary => {
-                            *boundary_count += 1;
-                            return;
-                        }
-                        CoordPos::Inside => {
-                            return;
-                        }
+        calculate_polygon_position(
+            coord,
+            &self.exterior().0,
+            self.interiors().iter().map(|hole| &hole.0[..]),
+            is_inside,
+            boundary_count,
+        );
+    }
+}
+
+impl<T> CoordinatePosition for PolygonRef<'_, T>
+where
+    T: GeoNum,
+{
+    type Scalar = T;
+    fn calculate_coordinate_position(
+        &self,
+        coord: &Coord<T>,
+        is_inside: &mut bool,
+        boundary_count: &mut usize,
+    ) {
+        if self.exterior().0.is_empty() {
+            return;
+        }
+
+        calculate_polygon_position(
+            coord,
+            self.exterior().0,
+            self.interiors().iter().map(|hole| hole.0),
+            is_inside,
+            boundary_count,
+        );
+    }
+}
+
+fn calculate_polygon_position<'a, T>(
+    coord: &Coord<T>,
+    exterior: &[Coord<T>],
+    holes: impl Iterator<Item = &'a [Coord<T>]>,
+    is_inside: &mut bool,
+    boundary_count: &mut usize,
+) where
+    T: GeoNum + 'a,
+{
+    match coord_pos_relative_to_ring_coords(*coord, exterior) {
+        CoordPos::Outside => {}
+        CoordPos::OnBoundary => {
+            *boundary_count += 1;
+        }
+        CoordPos::Inside => {
+            for hole in holes {
+                match coord_pos_relative_to_ring_coords(*coord, hole) {
+                    CoordPos::Outside => {}
+                    CoordPos::OnBoundary => {
+                        *boundary_count += 1;
+                        return;
+                    }
+                    CoordPos::Inside => {
+                        return;
                     }
                 }
-                // the coord is *outside* the interior holes, so it's *inside* the polygon
-                *is_inside = true;
             }
+            // the coord is *outside* the interior holes, so it's *inside* the polygon
+            *is_inside = true;
         }
     }
 }
@@ -389,16 +470,25 @@ pub fn coord_pos_relative_to_ring<T>(coord: Coord<T>, linestring: &LineString<T>
 where
     T: GeoNum,
 {
-    debug_assert!(linestring.is_closed());
+    coord_pos_relative_to_ring_coords(coord, &linestring.0)
+}
+
+/// Calculate the position of a `Coord` relative to a
+/// closed ring of coordinates.
+pub(crate) fn coord_pos_relative_to_ring_coords<T>(coord: Coord<T>, ring: &[Coord<T>]) -> CoordPos
+where
+    T: GeoNum,
+{
+    debug_assert!(ring.first() == ring.last());
 
     // LineString without points
-    if linestring.0.is_empty() {
+    if ring.is_empty() {
         return CoordPos::Outside;
     }
-    if linestring.0.len() == 1 {
+    if ring.len() == 1 {
         // If LineString has one point, it will not generate
         // any lines.  So, we handle this edge case separately.
-        return if coord == linestring.0[0] {
+        return if coord == ring[0] {
             CoordPos::OnBoundary
         } else {
             CoordPos::Outside
@@ -408,7 +498,7 @@ where
     // Use winding number algorithm with on boundary short-cicuit
     // See: https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm
     let mut winding_number = 0;
-    for line in linestring.lines() {
+    for line in LineStringSlice(ring).lines() {
         // Edge Crossing Rules:
         //   1. an upward edge includes its starting endpoint, and excludes its final endpoint;
         //   2. a downward edge excludes its starting endpoint, and includes its final endpoint;
@@ -1,9 +1,12 @@
-use std::fmt::Debug;
+use alloc::{boxed::Box, string::String};
+use core::fmt::Debug;
 
 use crate::geometry::*;
+use crate::soa::{SoaCoords, SoaLineString, SoaMultiPoint};
+use crate::view::{LineStringSlice, PolygonRef};
 use crate::{coord, CoordNum};
 
-use std::{fmt, iter, marker, slice};
+use core::{fmt, iter, marker, slice};
 
 type CoordinateChainOnce<T> = iter::Chain<iter::Once<Coord<T>>, iter::Once<Coord<T>>>;
 
@@ -100,8 +103,14 @@ pub trait CoordsIter {
 // └──────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for Point<T> {
-    type Iter<'a> = iter::Once<Coord<T>> where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a> where T: 'a;
+    type Iter<'a>
+        = iter::Once<Coord<T>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -123,10 +132,14 @@ impl<T: CoordNum> CoordsIter for Point<T> {
 // └─────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for Line<T> {
-    type Iter<'a> = iter::Chain<iter::Once<Coord<T>>, iter::Once<Coord<T>>>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = iter::Chain<iter::Once<Coord<T>>, iter::Once<Coord<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -150,10 +163,14 @@ impl<T: CoordNum> CoordsIter for Line<T> {
 type LineStringIter<'a, T> = iter::Copied<slice::Iter<'a, Coord<T>>>;
 
 impl<T: CoordNum> CoordsIter for LineString<T> {
-    type Iter<'a> = LineStringIter<'a, T>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = LineStringIter<'a, T>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -170,6 +187,81 @@ impl<T: CoordNum> CoordsIter for LineString<T> {
     }
 }
 
+// ┌────────────────────────────────────┐
+// │ Implementation for LineStringSlice │
+// └────────────────────────────────────┘
+
+impl<T: CoordNum> CoordsIter for LineStringSlice<'_, T> {
+    type Iter<'a>
+        = LineStringIter<'a, T>
+    where
+        Self: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        Self: 'a;
+    type Scalar = T;
+
+    fn coords_iter(&self) -> Self::Iter<'_> {
+        self.0.iter().copied()
+    }
+
+    /// Return the number of coordinates in the `LineStringSlice`.
+    fn coords_count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn exterior_coords_iter(&self) -> Self::ExteriorIter<'_> {
+        self.coords_iter()
+    }
+}
+
+// ┌───────────────────────────────────────────────────┐
+// │ Implementation for SoaLineString and SoaMultiPoint │
+// └───────────────────────────────────────────────────┘
+
+type SoaCoordsIter<'a, T> =
+    iter::Map<iter::Zip<slice::Iter<'a, T>, slice::Iter<'a, T>>, fn((&T, &T)) -> Coord<T>>;
+
+fn soa_coords_iter<T: CoordNum>(coords: &SoaCoords<T>) -> SoaCoordsIter<'_, T> {
+    coords
+        .xs()
+        .iter()
+        .zip(coords.ys())
+        .map(|(x, y)| coord! { x: *x, y: *y })
+}
+
+macro_rules! impl_soa_coords_iter {
+    ($type:ident) => {
+        impl<T: CoordNum> CoordsIter for $type<T> {
+            type Iter<'a>
+                = SoaCoordsIter<'a, T>
+            where
+                T: 'a;
+            type ExteriorIter<'a>
+                = Self::Iter<'a>
+            where
+                T: 'a;
+            type Scalar = T;
+
+            fn coords_iter(&self) -> Self::Iter<'_> {
+                soa_coords_iter(&self.0)
+            }
+
+            fn coords_count(&self) -> usize {
+                self.0.len()
+            }
+
+            fn exterior_coords_iter(&self) -> Self::ExteriorIter<'_> {
+                self.coords_iter()
+            }
+        }
+    };
+}
+
+impl_soa_coords_iter!(SoaLineString);
+impl_soa_coords_iter!(SoaMultiPoint);
+
 // ┌────────────────────────────┐
 // │ Implementation for Polygon │
 // └────────────────────────────┘
@@ -180,10 +272,14 @@ type PolygonIter<'a, T> = iter::Chain<
 >;
 
 impl<T: CoordNum> CoordsIter for Polygon<T> {
-    type Iter<'a> = PolygonIter<'a, T>
-    where T: 'a;
-    type ExteriorIter<'a> = LineStringIter<'a, T>
-    where T: 'a;
+    type Iter<'a>
+        = PolygonIter<'a, T>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = LineStringIter<'a, T>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -207,15 +303,64 @@ impl<T: CoordNum> CoordsIter for Polygon<T> {
     }
 }
 
+// ┌───────────────────────────────┐
+// │ Implementation for PolygonRef │
+// └───────────────────────────────┘
+
+type PolygonRefIter<'a, 'b, T> = iter::Chain<
+    LineStringIter<'a, T>,
+    iter::Flatten<
+        MapCoordsIter<'a, T, slice::Iter<'a, LineStringSlice<'b, T>>, LineStringSlice<'b, T>>,
+    >,
+>;
+
+impl<'b, T: CoordNum> CoordsIter for PolygonRef<'b, T> {
+    type Iter<'a>
+        = PolygonRefIter<'a, 'b, T>
+    where
+        Self: 'a;
+    type ExteriorIter<'a>
+        = LineStringIter<'a, T>
+    where
+        Self: 'a;
+    type Scalar = T;
+
+    fn coords_iter(&self) -> Self::Iter<'_> {
+        self.exterior()
+            .0
+            .iter()
+            .copied()
+            .chain(MapCoordsIter(self.interiors().iter(), marker::PhantomData).flatten())
+    }
+
+    /// Return the number of coordinates in the `PolygonRef`.
+    fn coords_count(&self) -> usize {
+        self.exterior().coords_count()
+            + self
+                .interiors()
+                .iter()
+                .map(|i| i.coords_count())
+                .sum::<usize>()
+    }
+
+    fn exterior_coords_iter(&self) -> Self::ExteriorIter<'_> {
+        self.exterior().0.iter().copied()
+    }
+}
+
 // ┌───────────────────────────────┐
 // │ Implementation for MultiPoint │
 // └───────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for MultiPoint<T> {
-    type Iter<'a> = iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, Point<T>>, Point<T>>>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, Point<T>>, Point<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -237,11 +382,14 @@ impl<T: CoordNum> CoordsIter for MultiPoint<T> {
 // └────────────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for MultiLineString<T> {
-    type Iter<'a> =
-        iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, LineString<T>>, LineString<T>>>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, LineString<T>>, LineString<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -266,11 +414,14 @@ impl<T: CoordNum> CoordsIter for MultiLineString<T> {
 // └─────────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for MultiPolygon<T> {
-    type Iter<'a> = iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, Polygon<T>>, Polygon<T>>>
-    where T: 'a;
-    type ExteriorIter<'a> =
-        iter::Flatten<MapExteriorCoordsIter<'a, T, slice::Iter<'a, Polygon<T>>, Polygon<T>>>
-    where T: 'a;
+    type Iter<'a>
+        = iter::Flatten<MapCoordsIter<'a, T, slice::Iter<'a, Polygon<T>>, Polygon<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = iter::Flatten<MapExteriorCoordsIter<'a, T, slice::Iter<'a, Polygon<T>>, Polygon<T>>>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -292,10 +443,14 @@ impl<T: CoordNum> CoordsIter for MultiPolygon<T> {
 // └───────────────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for GeometryCollection<T> {
-    type Iter<'a> = Box<dyn Iterator<Item = Coord<T>> + 'a>
-    where T: 'a;
-    type ExteriorIter<'a> = Box<dyn Iterator<Item = Coord<T>> + 'a>
-    where T: 'a;
+    type Iter<'a>
+        = Box<dyn Iterator<Item = Coord<T>> + 'a>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Box<dyn Iterator<Item = Coord<T>> + 'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -324,10 +479,14 @@ type RectIter<T> =
     iter::Chain<iter::Chain<CoordinateChainOnce<T>, iter::Once<Coord<T>>>, iter::Once<Coord<T>>>;
 
 impl<T: CoordNum> CoordsIter for Rect<T> {
-    type Iter<'a> = RectIter<T>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = RectIter<T>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -367,10 +526,14 @@ impl<T: CoordNum> CoordsIter for Rect<T> {
 // └─────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for Triangle<T> {
-    type Iter<'a> = iter::Chain<CoordinateChainOnce<T>, iter::Once<Coord<T>>>
-    where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a>
-    where T: 'a;
+    type Iter<'a>
+        = iter::Chain<CoordinateChainOnce<T>, iter::Once<Coord<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -394,10 +557,14 @@ impl<T: CoordNum> CoordsIter for Triangle<T> {
 // └─────────────────────────────┘
 
 impl<T: CoordNum> CoordsIter for Geometry<T> {
-    type Iter<'a> = GeometryCoordsIter<'a, T>
-    where T: 'a;
-    type ExteriorIter<'a> = GeometryExteriorCoordsIter<'a, T>
-    where T: 'a;
+    type Iter<'a>
+        = GeometryCoordsIter<'a, T>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = GeometryExteriorCoordsIter<'a, T>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -452,8 +619,14 @@ impl<T: CoordNum> CoordsIter for Geometry<T> {
 // └──────────────────────────┘
 
 impl<const N: usize, T: CoordNum> CoordsIter for [Coord<T>; N] {
-    type Iter<'a> = iter::Copied<slice::Iter<'a, Coord<T>>> where T: 'a;
-    type ExteriorIter<'a> = Self::Iter<'a> where T: 'a;
+    type Iter<'a>
+        = iter::Copied<slice::Iter<'a, Coord<T>>>
+    where
+        T: 'a;
+    type ExteriorIter<'a>
+        = Self::Iter<'a>
+    where
+        T: 'a;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -474,8 +647,16 @@ impl<const N: usize, T: CoordNum> CoordsIter for [Coord<T>; N] {
 // └──────────────────────────┘
 
 impl<'a, T: CoordNum> CoordsIter for &'a [Coord<T>] {
-    type Iter<'b> = iter::Copied<slice::Iter<'b, Coord<T>>> where T: 'b, 'a: 'b;
-    type ExteriorIter<'b> = Self::Iter<'b> where T: 'b, 'a: 'b;
+    type Iter<'b>
+        = iter::Copied<slice::Iter<'b, Coord<T>>>
+    where
+        T: 'b,
+        'a: 'b;
+    type ExteriorIter<'b>
+        = Self::Iter<'b>
+    where
+        T: 'b,
+        'a: 'b;
     type Scalar = T;
 
     fn coords_iter(&self) -> Self::Iter<'_> {
@@ -1,5 +1,6 @@
+use crate::algorithm::nvector::NVector;
 use crate::{HaversineBearing, HaversineDistance, MEAN_EARTH_RADIUS};
-use geo_types::{CoordFloat, Point};
+use geo_types::{CoordFloat, CoordNum, Point};
 use num_traits::FromPrimitive;
 
 /// Determine the cross track distance (also known as the cross track error) which is the shortest
@@ -51,8 +52,104 @@ where
     }
 }
 
+/// Determine the along track distance, which is the distance from the start of a great-circle
+/// path to the point on it closest to a given point.
+pub trait AlongTrackDistance<T: CoordNum, Rhs = Self> {
+    /// Determine the along track distance between `line_point_a` and the point closest to this
+    /// point on the great circle which passes through `line_point_a` and `line_point_b`.
+    ///
+    /// The distance is negative if the closest point lies behind `line_point_a`, when travelling
+    /// towards `line_point_b`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p = point!(x: -0.7972f64, y: 53.2611);
+    /// let line_point_a = point!(x: -1.7297f64, y: 53.3206);
+    /// let line_point_b = point!(x: 0.1334f64, y: 53.1887);
+    ///
+    /// let distance = p.along_track_distance(&line_point_a, &line_point_b);
+    ///
+    /// assert_eq!(
+    ///     62_332., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    fn along_track_distance(&self, line_point_a: &Rhs, line_point_b: &Rhs) -> T;
+
+    /// Determine the point closest to this point on the great circle which passes through
+    /// `line_point_a` and `line_point_b`. This is the point at the along track distance from
+    /// `line_point_a`, and at the [cross track distance](CrossTrackDistance) from this point.
+    ///
+    /// If this point is equidistant from the whole great circle (i.e. it is one of the circle's
+    /// poles), `line_point_a` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use approx::assert_relative_eq;
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p = point!(x: 10., y: 5.);
+    /// let line_point_a = point!(x: 0., y: 0.);
+    /// let line_point_b = point!(x: 20., y: 0.);
+    ///
+    /// let closest = p.along_track_point(&line_point_a, &line_point_b);
+    ///
+    /// assert_relative_eq!(closest, point!(x: 10., y: 0.), epsilon = 1e-9);
+    /// ```
+    fn along_track_point(&self, line_point_a: &Rhs, line_point_b: &Rhs) -> Point<T>;
+}
+
+impl<T> AlongTrackDistance<T, Point<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn along_track_distance(&self, line_point_a: &Point<T>, line_point_b: &Point<T>) -> T {
+        let a = NVector::from_point(*line_point_a);
+        let projected = match project(*self, a, *line_point_b) {
+            Some(projected) => projected,
+            None => return T::zero(),
+        };
+        let normal = a.cross(NVector::from_point(*line_point_b));
+        let angle = a.cross(projected).dot(normal).signum() * a.angle_to(projected);
+        T::from(MEAN_EARTH_RADIUS).unwrap() * angle
+    }
+
+    fn along_track_point(&self, line_point_a: &Point<T>, line_point_b: &Point<T>) -> Point<T> {
+        let a = NVector::from_point(*line_point_a);
+        match project(*self, a, *line_point_b) {
+            Some(projected) => projected.to_point(),
+            None => *line_point_a,
+        }
+    }
+}
+
+/// Project `point` onto the great circle through `a` and `b`, as a unit vector.
+///
+/// Returns `None` if `a` and `b` don't define a great circle, or if `point` is (nearly) one of
+/// its poles, in which case the direction of the projection is dominated by rounding errors.
+fn project<T: CoordFloat>(point: Point<T>, a: NVector<T>, b: Point<T>) -> Option<NVector<T>> {
+    let normal = a.cross(NVector::from_point(b)).normalize()?;
+    let point = NVector::from_point(point);
+    let projected = point - normal * normal.dot(point);
+    if projected.norm() <= T::epsilon() * T::from(1000).unwrap() {
+        return None;
+    }
+    projected.normalize()
+}
+
 #[cfg(test)]
 mod test {
+    use crate::AlongTrackDistance;
     use crate::CrossTrackDistance;
     use crate::HaversineDistance;
     use crate::Point;
@@ -113,4 +210,60 @@ mod test {
             epsilon = 1.0
         );
     }
+
+    #[test]
+    fn along_track_distance_matches_reference() {
+        // https://www.movable-type.co.uk/scripts/latlong.html
+        let p = Point::new(-0.7972, 53.2611);
+        let line_point_a = Point::new(-1.7297, 53.3206);
+        let line_point_b = Point::new(0.1334, 53.1887);
+        assert_relative_eq!(
+            p.along_track_distance(&line_point_a, &line_point_b),
+            62_331.5,
+            epsilon = 1.0
+        );
+    }
+
+    #[test]
+    fn along_track_distance_behind_start() {
+        let p = Point::new(-5., 3.);
+        let line_point_a = Point::new(0., 0.);
+        let line_point_b = Point::new(10., 0.);
+        assert_relative_eq!(
+            p.along_track_distance(&line_point_a, &line_point_b),
+            -Point::new(-5., 0.).haversine_distance(&line_point_a),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn along_track_point_is_at_cross_track_distance() {
+        let p = Point::new(-74.006f64, 40.7128f64);
+        let line_point_a = Point::new(-80.1918f64, 25.7617f64);
+        let line_point_b = Point::new(-120.7401f64, 47.7511f64);
+
+        let closest = p.along_track_point(&line_point_a, &line_point_b);
+        assert_relative_eq!(
+            closest.haversine_distance(&p),
+            p.cross_track_distance(&line_point_a, &line_point_b),
+            epsilon = 1.0e-6
+        );
+        assert_relative_eq!(
+            closest.haversine_distance(&line_point_a),
+            p.along_track_distance(&line_point_a, &line_point_b).abs(),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn along_track_point_at_pole_of_great_circle() {
+        let p = Point::new(0., 90.);
+        let line_point_a = Point::new(0., 0.);
+        let line_point_b = Point::new(10., 0.);
+        assert_eq!(
+            p.along_track_point(&line_point_a, &line_point_b),
+            line_point_a
+        );
+        assert_eq!(p.along_track_distance(&line_point_a, &line_point_b), 0.);
+    }
 }
@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+
+use crate::{Coord, GeoFloat, LineString};
+
+/// Measures of how a line string winds and bends, such as the meandering of a river or the
+/// sharpness of the bends in a road.
+///
+/// Curvatures are estimated at each vertex from the circle through it and its neighbours, the
+/// [Menger curvature](https://en.wikipedia.org/wiki/Menger_curvature), so they're only as
+/// accurate as the vertices are representative of the curve: densify or simplify the line
+/// string first to measure it at a suitable scale.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, Curvature};
+///
+/// // Three vertices on a circle of radius 10 around the origin, turning left
+/// let bend = line_string![(x: 10., y: 0.), (x: 6., y: 8.), (x: -6., y: 8.)];
+/// assert_relative_eq!(bend.curvatures()[0], 0.1);
+/// assert_relative_eq!(bend.radii_of_curvature()[0], 10.);
+///
+/// // 20.9 along the bend, between ends 17.9 apart
+/// assert_relative_eq!(bend.sinuosity().unwrap(), (80_f64.sqrt() + 12.) / 320_f64.sqrt());
+/// ```
+pub trait Curvature<T: GeoFloat> {
+    /// The ratio of the length of the line string to the distance between its ends, which is 1
+    /// for a straight line, and greater the more it winds.
+    ///
+    /// `None` if its ends are the same, such as for a closed ring.
+    fn sinuosity(&self) -> Option<T>;
+
+    /// The signed curvature at each vertex between two others, in order, which is the reciprocal
+    /// of the radius of the circle through the three vertices. It's positive where the line
+    /// string turns left, counter clockwise, negative where it turns right, and zero where it's
+    /// straight, or where the vertex is the same as one of its neighbours.
+    fn curvatures(&self) -> Vec<T>;
+
+    /// The radius of the circle through each vertex between two others and its neighbours, in
+    /// order, which is infinite where the line string is straight.
+    fn radii_of_curvature(&self) -> Vec<T> {
+        self.curvatures()
+            .into_iter()
+            .map(|curvature| curvature.abs().recip())
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Curvature<T> for LineString<T> {
+    fn sinuosity(&self) -> Option<T> {
+        let (first, last) = (self.0.first()?, self.0.last()?);
+        let distance = length(*last - *first);
+        if distance == T::zero() {
+            return None;
+        }
+        let length = self
+            .lines()
+            .fold(T::zero(), |sum, line| sum + length(line.delta()));
+        Some(length / distance)
+    }
+
+    fn curvatures(&self) -> Vec<T> {
+        self.0
+            .windows(3)
+            .map(|window| {
+                let (a, b, c) = (window[0], window[1], window[2]);
+                let (ab, bc, ca) = (b - a, c - b, a - c);
+                let lengths = length(ab) * length(bc) * length(ca);
+                if lengths == T::zero() {
+                    return T::zero();
+                }
+                // Twice the signed area of the triangle, over the product of its sides, is half
+                // the reciprocal of the circumradius
+                let twice_area = ab.x * bc.y - ab.y * bc.x;
+                (twice_area + twice_area) / lengths
+            })
+            .collect()
+    }
+}
+
+fn length<T: GeoFloat>(delta: Coord<T>) -> T {
+    delta.x.hypot(delta.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn curvature() {
+        // Turning right, around a circle of radius 5 centred at (0, 5)
+        let right = line_string![(x: -5., y: 5.), (x: 0., y: 10.), (x: 5., y: 5.), (x: 0., y: 0.)];
+        let curvatures = right.curvatures();
+        assert_eq!(curvatures.len(), 2);
+        for curvature in curvatures {
+            assert_relative_eq!(curvature, -0.2);
+        }
+        for radius in right.radii_of_curvature() {
+            assert_relative_eq!(radius, 5.);
+        }
+
+        let straight = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.), (x: 2., y: 2.)];
+        assert_eq!(straight.curvatures(), [0., 0.]);
+        assert_eq!(straight.radii_of_curvature(), [f64::INFINITY; 2]);
+
+        assert!(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+            .curvatures()
+            .is_empty());
+    }
+
+    #[test]
+    fn sinuosity() {
+        let straight = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        assert_relative_eq!(straight.sinuosity().unwrap(), 1.);
+
+        let meander = line_string![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_relative_eq!(meander.sinuosity().unwrap(), 2.);
+
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(ring.sinuosity(), None);
+        assert_eq!(LineString::<f64>::new(vec![]).sinuosity(), None);
+    }
+}
@@ -0,0 +1,460 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::utils::lex_cmp;
+use crate::{
+    Coord, CoordsIter, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Bring a geometry into a canonical form, so that geometries which are the same up to
+/// snapping to a grid, and to the way they're written down, are equal with `PartialEq`.
+///
+/// The canonical form:
+///
+/// - snaps each coordinate to the nearest multiple of `grid_size`, unless it's zero,
+/// - removes consecutive repeated coordinates, including those left by snapping,
+/// - starts each ring at its lexicographically least coordinate, with exteriors
+///   counter-clockwise and interiors clockwise,
+/// - runs each line string in the direction whose coordinates are lexicographically less, and
+/// - sorts the interiors of polygons, and the members of multi-part geometries and
+///   collections.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, CanonicalForm};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// // Clockwise, from another corner, and a little off
+/// let b = polygon![(x: 4., y: 4.), (x: 4.001, y: 0.), (x: 0., y: 0.), (x: 0., y: 4.)];
+///
+/// assert_ne!(a.canonical_form(0.), b.canonical_form(0.));
+/// assert!(a.snapped_eq(&b, 0.01));
+/// assert!(!a.snapped_eq(&b, 0.0001));
+/// ```
+pub trait CanonicalForm<T: GeoFloat>: Sized {
+    /// The geometry in canonical form, snapped to a grid of `grid_size`.
+    fn canonical_form(&self, grid_size: T) -> Self;
+
+    /// Whether the canonical forms of `self` and `other`, snapped to a grid of `grid_size`,
+    /// are equal.
+    fn snapped_eq(&self, other: &Self, grid_size: T) -> bool
+    where
+        Self: PartialEq,
+    {
+        self.canonical_form(grid_size) == other.canonical_form(grid_size)
+    }
+}
+
+fn snap<T: GeoFloat>(coord: Coord<T>, grid_size: T) -> Coord<T> {
+    let snap = |value: T| {
+        let value = if grid_size > T::zero() {
+            (value / grid_size).round() * grid_size
+        } else {
+            value
+        };
+        // Without negative zero, so that equal coordinates hash the same
+        if value == T::zero() {
+            T::zero()
+        } else {
+            value
+        }
+    };
+    Coord {
+        x: snap(coord.x),
+        y: snap(coord.y),
+    }
+}
+
+fn snapped<T: GeoFloat>(coords: &[Coord<T>], grid_size: T) -> Vec<Coord<T>> {
+    let mut coords: Vec<Coord<T>> = coords.iter().map(|c| snap(*c, grid_size)).collect();
+    coords.dedup();
+    coords
+}
+
+fn cmp_coords<T: GeoFloat>(
+    a: impl Iterator<Item = Coord<T>>,
+    b: impl Iterator<Item = Coord<T>>,
+) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(p), Some(q)) => match lex_cmp(&p, &q) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+/// A ring in canonical form, counter-clockwise if `exterior`, and clockwise otherwise.
+fn canonical_ring<T: GeoFloat>(
+    ring: &LineString<T>,
+    grid_size: T,
+    exterior: bool,
+) -> LineString<T> {
+    let mut coords = snapped(&ring.0, grid_size);
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    if coords.is_empty() {
+        return LineString::new(coords);
+    }
+    let twice_area = (0..coords.len()).fold(T::zero(), |sum, i| {
+        let (a, b) = (coords[i], coords[(i + 1) % coords.len()]);
+        sum + a.x * b.y - b.x * a.y
+    });
+    if (twice_area < T::zero()) == exterior {
+        coords.reverse();
+    }
+    let start = (0..coords.len())
+        .min_by(|&a, &b| lex_cmp(&coords[a], &coords[b]))
+        .unwrap();
+    coords.rotate_left(start);
+    coords.push(coords[0]);
+    LineString::new(coords)
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Point<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        Point(snap(self.0, grid_size))
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Line<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let (start, end) = (snap(self.start, grid_size), snap(self.end, grid_size));
+        if lex_cmp(&end, &start) == Ordering::Less {
+            Line::new(end, start)
+        } else {
+            Line::new(start, end)
+        }
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for LineString<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut coords = snapped(&self.0, grid_size);
+        if cmp_coords(coords.iter().rev().copied(), coords.iter().copied()) == Ordering::Less {
+            coords.reverse();
+        }
+        LineString::new(coords)
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Polygon<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut interiors: Vec<LineString<T>> = self
+            .interiors()
+            .iter()
+            .map(|ring| canonical_ring(ring, grid_size, false))
+            .collect();
+        interiors.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        Polygon::new(canonical_ring(self.exterior(), grid_size, true), interiors)
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for MultiPoint<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut points: Vec<Point<T>> = self.iter().map(|p| p.canonical_form(grid_size)).collect();
+        points.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+        MultiPoint::new(points)
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for MultiLineString<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut lines: Vec<LineString<T>> = self
+            .iter()
+            .map(|line| line.canonical_form(grid_size))
+            .collect();
+        lines.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        MultiLineString::new(lines)
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for MultiPolygon<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut polygons: Vec<Polygon<T>> = self
+            .iter()
+            .map(|polygon| polygon.canonical_form(grid_size))
+            .collect();
+        polygons.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        MultiPolygon::new(polygons)
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Rect<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        Rect::new(snap(self.min(), grid_size), snap(self.max(), grid_size))
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Triangle<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let ring = canonical_ring(&self.to_polygon().into_inner().0, grid_size, true);
+        match ring.0[..] {
+            [a, b, c, _] => Triangle::new(a, b, c),
+            // Snapped to a line or a point
+            _ => {
+                let mut coords = [self.0, self.1, self.2].map(|c| snap(c, grid_size));
+                coords.sort_by(lex_cmp);
+                Triangle::new(coords[0], coords[1], coords[2])
+            }
+        }
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for Geometry<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.canonical_form(grid_size)),
+            Geometry::Line(g) => Geometry::Line(g.canonical_form(grid_size)),
+            Geometry::LineString(g) => Geometry::LineString(g.canonical_form(grid_size)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.canonical_form(grid_size)),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.canonical_form(grid_size)),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.canonical_form(grid_size)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.canonical_form(grid_size)),
+            Geometry::GeometryCollection(g) => {
+                Geometry::GeometryCollection(g.canonical_form(grid_size))
+            }
+            Geometry::Rect(g) => Geometry::Rect(g.canonical_form(grid_size)),
+            Geometry::Triangle(g) => Geometry::Triangle(g.canonical_form(grid_size)),
+        }
+    }
+}
+
+impl<T: GeoFloat> CanonicalForm<T> for GeometryCollection<T> {
+    fn canonical_form(&self, grid_size: T) -> Self {
+        let mut geometries: Vec<Geometry<T>> =
+            self.iter().map(|g| g.canonical_form(grid_size)).collect();
+        geometries.sort_by(|a, b| {
+            kind(a)
+                .cmp(&kind(b))
+                .then_with(|| cmp_coords(a.coords_iter(), b.coords_iter()))
+        });
+        GeometryCollection::new_from(geometries)
+    }
+}
+
+fn kind<T: GeoFloat>(geometry: &Geometry<T>) -> u8 {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+/// The geometries of a dataset which are duplicates of each other, from [`deduplicate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Duplicates {
+    /// The index of the first of each distinct geometry, in order: the geometries to keep.
+    pub representatives: Vec<usize>,
+    /// The indices of the geometries which have duplicates, in groups of the same geometry,
+    /// each in order, so that it starts with its representative. The groups are in the order
+    /// of their representatives.
+    pub groups: Vec<Vec<usize>>,
+}
+
+impl Duplicates {
+    /// Whether the geometry at `index` is a duplicate of an earlier one.
+    pub fn is_duplicate(&self, index: usize) -> bool {
+        self.representatives.binary_search(&index).is_err()
+    }
+
+    /// The number of geometries which are duplicates of earlier ones.
+    pub fn duplicate_count(&self) -> usize {
+        self.groups.iter().map(|group| group.len() - 1).sum()
+    }
+}
+
+/// Find the geometries which are the same up to a coordinate tolerance, as those whose
+/// [canonical forms](CanonicalForm), snapped to a grid of `grid_size`, are equal.
+///
+/// Each canonical form is hashed, so that only geometries with the same hash are compared, and
+/// they're compared exactly, so that geometries whose hashes collide aren't taken for
+/// duplicates. Geometries a little less than `grid_size` apart can snap to different points of
+/// the grid, so they aren't always found to be duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{deduplicate, line_string, LineString};
+///
+/// let roads: Vec<LineString> = vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 5., y: 5.), (x: 5., y: 9.)],
+///     // The first, from another dataset, digitized the other way
+///     line_string![(x: 10.0001, y: 0.), (x: 0., y: -0.0001)],
+///     line_string![(x: 5., y: 5.), (x: 5., y: 9.)],
+/// ];
+/// let duplicates = deduplicate(&roads, 0.01);
+/// assert_eq!(duplicates.representatives, vec![0, 1]);
+/// assert_eq!(duplicates.groups, vec![vec![0, 2], vec![1, 3]]);
+/// assert!(duplicates.is_duplicate(2));
+/// ```
+pub fn deduplicate<'a, T, G>(
+    geometries: impl IntoIterator<Item = &'a G>,
+    grid_size: T,
+) -> Duplicates
+where
+    T: GeoFloat,
+    G: CanonicalForm<T> + CoordsIter<Scalar = T> + PartialEq + 'a,
+{
+    let forms: Vec<G> = geometries
+        .into_iter()
+        .map(|geometry| geometry.canonical_form(grid_size))
+        .collect();
+    // The first geometry of each distinct canonical form with a hash, and the geometries with
+    // the same form
+    let mut buckets: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    let mut group_of: Vec<Option<usize>> = vec![None; forms.len()];
+    let mut members: Vec<Vec<usize>> = vec![];
+    for (index, form) in forms.iter().enumerate() {
+        let bucket = buckets.entry(hash(form)).or_default();
+        match bucket.iter().find(|&&first| forms[first] == *form) {
+            Some(&first) => {
+                let group = *group_of[first].get_or_insert_with(|| {
+                    members.push(vec![first]);
+                    members.len() - 1
+                });
+                members[group].push(index);
+            }
+            None => bucket.push(index),
+        }
+    }
+    let mut representatives: Vec<usize> = buckets.into_values().flatten().collect();
+    representatives.sort_unstable();
+    Duplicates {
+        representatives,
+        groups: members,
+    }
+}
+
+/// The FNV-1a hash of the coordinates of a geometry.
+fn hash<T: GeoFloat, G: CoordsIter<Scalar = T>>(geometry: &G) -> u64 {
+    let mut hash = 0xcbf29ce484222325_u64;
+    let mut write = |value: u64| {
+        for byte in value.to_le_bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+    for coord in geometry.coords_iter() {
+        write(coord.x.to_f64().unwrap_or(f64::NAN).to_bits());
+        write(coord.y.to_f64().unwrap_or(f64::NAN).to_bits());
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn polygons_with_holes() {
+        let a = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+            ],
+        ];
+        // Clockwise, from other vertices, with the holes the other way round and in the other
+        // order, and a repeated vertex
+        let b = polygon![
+            exterior: [
+                (x: 10., y: 10.),
+                (x: 10., y: 0.),
+                (x: 0., y: 0.),
+                (x: 0., y: 0.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 6., y: 6.), (x: 6., y: 5.), (x: 5., y: 5.)],
+                [(x: 2., y: 2.), (x: 1., y: 2.), (x: 1., y: 1.), (x: 2., y: 1.)],
+            ],
+        ];
+        let canonical = a.canonical_form(0.);
+        assert_eq!(canonical, b.canonical_form(0.));
+        assert_eq!(canonical.exterior().0[0], Coord { x: 0., y: 0. });
+        assert_eq!(canonical.exterior().0[1], Coord { x: 10., y: 0. });
+        assert_eq!(canonical.interiors()[0].0[0], Coord { x: 1., y: 1. });
+        assert_eq!(canonical.interiors()[0].0[1], Coord { x: 1., y: 2. });
+        // Idempotent
+        assert_eq!(canonical.canonical_form(0.), canonical);
+    }
+
+    #[test]
+    fn snapping() {
+        let a = line_string![(x: 0.04, y: -0.04), (x: 1., y: 1.), (x: 1.01, y: 1.)];
+        let b = line_string![(x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(a.snapped_eq(&b, 0.1));
+        assert_eq!(a.canonical_form(0.1).0.len(), 2);
+        assert!(!a.snapped_eq(&b, 0.01));
+        // Negative zero hashes the same as zero
+        let c = line_string![(x: -0.04, y: 0.), (x: 1., y: 1.)];
+        assert_eq!(hash(&b.canonical_form(0.1)), hash(&c.canonical_form(0.1)));
+    }
+
+    #[test]
+    fn collections() {
+        let a = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 1., y: 1.).into(),
+            Line::new((1., 0.), (0., 0.)).into(),
+            MultiPoint::new(vec![point!(x: 2., y: 2.), point!(x: 0., y: 0.)]).into(),
+        ]));
+        let b = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 2., y: 2.)]).into(),
+            Line::new((0., 0.), (1., 0.)).into(),
+            point!(x: 1., y: 1.).into(),
+        ]));
+        assert!(a.snapped_eq(&b, 0.));
+        let triangle = Triangle::from([(0., 0.), (0., 1.), (1., 0.)]);
+        let rotated = Triangle::from([(1., 0.), (0., 0.), (0., 1.)]);
+        assert!(triangle.snapped_eq(&rotated, 0.));
+        // The same coordinates, but not the same geometry
+        let line_string = Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]);
+        let line = Geometry::from(Line::new((0., 0.), (1., 0.)));
+        assert!(!line_string.snapped_eq(&line, 0.));
+    }
+
+    #[test]
+    fn groups_and_representatives() {
+        let square = |x: f64, y: f64| polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 1., y: y + 1.), (x: x, y: y + 1.)];
+        let polygons = vec![
+            square(0., 0.),
+            square(5., 0.),
+            square(0., 0.).canonical_form(0.),
+            square(9., 9.),
+            square(5.001, 0.),
+            square(0.001, 0.),
+        ];
+        let duplicates = deduplicate(&polygons, 0.01);
+        assert_eq!(duplicates.representatives, vec![0, 1, 3]);
+        assert_eq!(duplicates.groups, vec![vec![0, 2, 5], vec![1, 4]]);
+        assert_eq!(duplicates.duplicate_count(), 3);
+        assert!(!duplicates.is_duplicate(3));
+        assert!(duplicates.is_duplicate(4));
+
+        let exact = deduplicate(&polygons, 0.);
+        assert_eq!(exact.representatives, vec![0, 1, 3, 4, 5]);
+        assert_eq!(exact.groups, vec![vec![0, 2]]);
+
+        let none = deduplicate::<f64, Polygon>(&[], 1.);
+        assert!(none.representatives.is_empty() && none.groups.is_empty());
+    }
+}
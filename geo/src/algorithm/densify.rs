@@ -2,6 +2,7 @@ use crate::{
     CoordFloat, EuclideanLength, Line, LineInterpolatePoint, LineString, MultiLineString,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use alloc::{vec, vec::Vec};
 
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
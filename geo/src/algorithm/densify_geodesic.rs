@@ -0,0 +1,184 @@
+use crate::{
+    CoordsIter, GeodesicIntermediate, Line, LineString, MultiLineString, MultiPolygon, Point,
+    Polygon, Rect, Triangle,
+};
+use alloc::{vec, vec::Vec};
+
+/// Returns a new geometry on an ellipsoidal model of the earth containing both existing and new
+/// coordinates, interpolated along geodesics, with a maximum distance of `max_distance` between
+/// them.
+///
+/// Note: `max_distance` must be greater than 0.
+///
+/// ## Units
+///
+/// `max_distance`: meters
+///
+/// # Examples
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, GeodesicLength, Line, LineString};
+/// use geo::DensifyGeodesic;
+///
+/// let line = Line::new(coord! {x: 0.0, y: 0.0}, coord! { x: 0.0, y: 1.0 });
+/// // known output
+/// let output: LineString = vec![[0.0, 0.0], [0.0, 0.5], [0.0, 1.0]].into();
+/// // densify
+/// let dense = line.densify_geodesic(100000.0);
+/// assert_relative_eq!(dense, output, epsilon = 1e-3);
+/// assert!(dense.lines().all(|line| line.geodesic_length() <= 100000.0));
+///```
+pub trait DensifyGeodesic {
+    type Output;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output;
+}
+
+// Helper for densification trait
+fn densify_line(line: Line, container: &mut Vec<Point>, max_distance: f64) {
+    assert!(max_distance > 0.0);
+    let (start, end) = line.points();
+    container.push(start);
+    container.extend(start.geodesic_intermediate_fill(&end, max_distance, false));
+}
+
+impl DensifyGeodesic for MultiPolygon {
+    type Output = MultiPolygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic for Polygon {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        let densified_exterior = self.exterior().densify_geodesic(max_distance);
+        let densified_interiors = self
+            .interiors()
+            .iter()
+            .map(|ring| ring.densify_geodesic(max_distance))
+            .collect();
+        Polygon::new(densified_exterior, densified_interiors)
+    }
+}
+
+impl DensifyGeodesic for MultiLineString {
+    type Output = MultiLineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .map(|linestring| linestring.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic for LineString {
+    type Output = LineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        if self.coords_count() == 0 {
+            return LineString::new(vec![]);
+        }
+
+        let mut new_line = vec![];
+        self.lines()
+            .for_each(|line| densify_line(line, &mut new_line, max_distance));
+        // we're done, push the last coordinate on to finish
+        new_line.push(Point::from(*self.0.last().unwrap()));
+        LineString::from(new_line)
+    }
+}
+
+impl DensifyGeodesic for Line {
+    type Output = LineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        let mut new_line = vec![];
+        densify_line(*self, &mut new_line, max_distance);
+        // we're done, push the last coordinate on to finish
+        new_line.push(self.end_point());
+        LineString::from(new_line)
+    }
+}
+
+impl DensifyGeodesic for Triangle {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+impl DensifyGeodesic for Rect {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, GeodesicLength};
+
+    #[test]
+    fn test_polygon_densify() {
+        let exterior: LineString = vec![
+            [4.925, 45.804],
+            [4.732, 45.941],
+            [4.935, 46.513],
+            [5.821, 46.103],
+            [5.627, 45.611],
+            [5.355, 45.883],
+            [4.925, 45.804],
+        ]
+        .into();
+        let polygon = Polygon::new(exterior.clone(), vec![]);
+
+        let dense = polygon.densify_geodesic(50000.0);
+        assert_eq!(dense.exterior().coords_count(), 10);
+        assert!(dense
+            .exterior()
+            .lines()
+            .all(|line| line.geodesic_length() <= 50000.0));
+        assert_relative_eq!(
+            dense.exterior().geodesic_length(),
+            exterior.geodesic_length(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_long_route_follows_geodesic() {
+        // New York City to Osaka
+        let line = Line::new(
+            coord! { x: -74.006, y: 40.7128 },
+            coord! { x: 135.5244559, y: 34.687455 },
+        );
+        let dense = line.densify_geodesic(500_000.0);
+        assert_eq!(dense.coords_count(), 24);
+        assert_relative_eq!(
+            dense.geodesic_length(),
+            line.geodesic_length(),
+            epsilon = 1e-6
+        );
+        // The route passes close to the north pole.
+        assert!(dense.coords().any(|coord| coord.y > 70.0));
+    }
+
+    #[test]
+    fn test_empty_linestring() {
+        let linestring = LineString::new(vec![]);
+        let dense = linestring.densify_geodesic(10.0);
+        assert_eq!(0, dense.coords_count());
+    }
+}
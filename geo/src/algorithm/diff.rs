@@ -0,0 +1,547 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use crate::{
+    Area, Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Compare two geometries, reporting the vertices and components which changed between them.
+///
+/// The components of a geometry are the members of a multi-geometry or `GeometryCollection`, or
+/// the geometry itself. A polygon's rings are compared separately, so that a changed hole doesn't
+/// mark the whole polygon as changed.
+///
+/// Components, rings and vertices are aligned as a text diff aligns lines, keeping the longest
+/// sequence of them which are unchanged. Between unchanged stretches, changed items are paired
+/// in order: paired vertices are reported as moved and paired components and rings are compared
+/// vertex by vertex, while unpaired items are reported as inserted or removed.
+///
+/// Two coordinates are considered unchanged if they're within `tolerance` of each other;
+/// [`diff`](Self::diff) compares them exactly. Note that the first and last vertices of a closed
+/// ring are the same point, so that moving it reports two moved vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::diff::{Change, Diff};
+/// use geo::{coord, polygon};
+///
+/// let before = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// let after = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 2.),
+///     (x: 4., y: 4.),
+///     (x: 0., y: 5.),
+/// ];
+///
+/// let diff = before.diff(&after);
+/// assert_eq!(
+///     diff.changes,
+///     vec![
+///         Change::VertexInserted {
+///             component: 0,
+///             ring: 0,
+///             index: 2,
+///             coord: coord! { x: 4., y: 2. },
+///         },
+///         Change::VertexMoved {
+///             component: 0,
+///             ring: 0,
+///             index: 3,
+///             from: coord! { x: 0., y: 4. },
+///             to: coord! { x: 0., y: 5. },
+///         },
+///     ]
+/// );
+/// assert_eq!(diff.area_delta, 2.);
+///
+/// // Within a tolerance of 1, only the inserted vertex is a change
+/// assert_eq!(before.diff_with_tolerance(&after, 1.).changes.len(), 1);
+/// ```
+pub trait Diff<T: GeoFloat> {
+    /// Compare `self` with `other`, exactly.
+    fn diff(&self, other: &Self) -> GeometryDiff<T> {
+        self.diff_with_tolerance(other, T::zero())
+    }
+
+    /// Compare `self` with `other`, considering coordinates within `tolerance` of each other to
+    /// be unchanged.
+    fn diff_with_tolerance(&self, other: &Self, tolerance: T) -> GeometryDiff<T>;
+}
+
+/// The differences between two geometries, as reported by [`Diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryDiff<T: GeoFloat> {
+    /// The changes from the first geometry to the second, in order.
+    pub changes: Vec<Change<T>>,
+    /// The unsigned area of the second geometry less that of the first.
+    pub area_delta: T,
+    /// The total length of the line strings and polygon rings of the second geometry less that of
+    /// the first.
+    pub length_delta: T,
+}
+
+impl<T: GeoFloat> GeometryDiff<T> {
+    /// Whether no vertices or components changed.
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A change reported by [`Diff`].
+///
+/// The `component`, `ring` and `index` of a removed or moved item are its position in the first
+/// geometry, and those of an inserted item its position in the second, except that the
+/// `component` of a changed ring or vertex is always that in the first geometry. The `ring` of a
+/// geometry other than a polygon is 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change<T: GeoFloat> {
+    ComponentRemoved {
+        component: usize,
+    },
+    ComponentInserted {
+        component: usize,
+    },
+    RingRemoved {
+        component: usize,
+        ring: usize,
+    },
+    RingInserted {
+        component: usize,
+        ring: usize,
+    },
+    VertexRemoved {
+        component: usize,
+        ring: usize,
+        index: usize,
+        coord: Coord<T>,
+    },
+    VertexInserted {
+        component: usize,
+        ring: usize,
+        index: usize,
+        coord: Coord<T>,
+    },
+    VertexMoved {
+        component: usize,
+        ring: usize,
+        index: usize,
+        from: Coord<T>,
+        to: Coord<T>,
+    },
+}
+
+/// The rings (or single coordinate sequence) of each component of a geometry.
+type Components<'a, T> = Vec<Vec<Cow<'a, [Coord<T>]>>>;
+
+trait ToComponents<T: GeoFloat> {
+    fn components(&self) -> Components<'_, T>;
+}
+
+impl<T: GeoFloat> ToComponents<T> for Point<T> {
+    fn components(&self) -> Components<'_, T> {
+        vec![vec![Cow::Borrowed(core::slice::from_ref(&self.0))]]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for Line<T> {
+    fn components(&self) -> Components<'_, T> {
+        vec![vec![Cow::Owned(vec![self.start, self.end])]]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for LineString<T> {
+    fn components(&self) -> Components<'_, T> {
+        vec![vec![Cow::Borrowed(&self.0)]]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for Polygon<T> {
+    fn components(&self) -> Components<'_, T> {
+        let rings = core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(|ring| Cow::Borrowed(&ring.0[..]))
+            .collect();
+        vec![rings]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for MultiPoint<T> {
+    fn components(&self) -> Components<'_, T> {
+        self.iter().flat_map(ToComponents::components).collect()
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for MultiLineString<T> {
+    fn components(&self) -> Components<'_, T> {
+        self.iter().flat_map(ToComponents::components).collect()
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for MultiPolygon<T> {
+    fn components(&self) -> Components<'_, T> {
+        self.iter().flat_map(ToComponents::components).collect()
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for Rect<T> {
+    fn components(&self) -> Components<'_, T> {
+        vec![vec![Cow::Owned(self.to_polygon().exterior().0.clone())]]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for Triangle<T> {
+    fn components(&self) -> Components<'_, T> {
+        vec![vec![Cow::Owned(self.to_polygon().exterior().0.clone())]]
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for GeometryCollection<T> {
+    fn components(&self) -> Components<'_, T> {
+        self.iter().flat_map(ToComponents::components).collect()
+    }
+}
+
+impl<T: GeoFloat> ToComponents<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn components(&self) -> Components<'_, T>;
+    }
+}
+
+macro_rules! impl_diff {
+    ($($type:ident),+) => {
+        $(
+            impl<T: GeoFloat> Diff<T> for $type<T> {
+                fn diff_with_tolerance(&self, other: &Self, tolerance: T) -> GeometryDiff<T> {
+                    diff_components(&self.components(), &other.components(), tolerance)
+                        .with_deltas(self.unsigned_area(), other.unsigned_area())
+                }
+            }
+        )+
+    };
+}
+
+impl_diff!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+/// The changes, and difference in length, between `old` and `new`.
+struct ComponentsDiff<T: GeoFloat> {
+    changes: Vec<Change<T>>,
+    length_delta: T,
+}
+
+impl<T: GeoFloat> ComponentsDiff<T> {
+    fn with_deltas(self, old_area: T, new_area: T) -> GeometryDiff<T> {
+        GeometryDiff {
+            changes: self.changes,
+            area_delta: new_area - old_area,
+            length_delta: self.length_delta,
+        }
+    }
+}
+
+fn diff_components<T: GeoFloat>(
+    old: &Components<T>,
+    new: &Components<T>,
+    tolerance: T,
+) -> ComponentsDiff<T> {
+    let total_length = |components: &Components<T>| {
+        components
+            .iter()
+            .flatten()
+            .map(|ring| ring_length(ring))
+            .fold(T::zero(), |sum, length| sum + length)
+    };
+    let same_coord =
+        |a: &Coord<T>, b: &Coord<T>| a == b || (a.x - b.x).hypot(a.y - b.y) <= tolerance;
+    let same_ring = |a: &Cow<[Coord<T>]>, b: &Cow<[Coord<T>]>| {
+        a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| same_coord(a, b))
+    };
+    let same_component = |a: &Vec<Cow<[Coord<T>]>>, b: &Vec<Cow<[Coord<T>]>>| {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_ring(a, b))
+    };
+
+    let mut changes = vec![];
+    for edit in align(old, new, same_component) {
+        let (component, old_rings, new_rings) = match edit {
+            Edit::Keep(..) => continue,
+            Edit::Remove(component) => {
+                changes.push(Change::ComponentRemoved { component });
+                continue;
+            }
+            Edit::Insert(component) => {
+                changes.push(Change::ComponentInserted { component });
+                continue;
+            }
+            Edit::Replace(component, new_component) => {
+                (component, &old[component], &new[new_component])
+            }
+        };
+        for edit in align(old_rings, new_rings, same_ring) {
+            let (ring, old_coords, new_coords) = match edit {
+                Edit::Keep(..) => continue,
+                Edit::Remove(ring) => {
+                    changes.push(Change::RingRemoved { component, ring });
+                    continue;
+                }
+                Edit::Insert(ring) => {
+                    changes.push(Change::RingInserted { component, ring });
+                    continue;
+                }
+                Edit::Replace(ring, new_ring) => (ring, &old_rings[ring], &new_rings[new_ring]),
+            };
+            changes.extend(align(old_coords, new_coords, same_coord).filter_map(
+                |edit| match edit {
+                    Edit::Keep(..) => None,
+                    Edit::Remove(index) => Some(Change::VertexRemoved {
+                        component,
+                        ring,
+                        index,
+                        coord: old_coords[index],
+                    }),
+                    Edit::Insert(index) => Some(Change::VertexInserted {
+                        component,
+                        ring,
+                        index,
+                        coord: new_coords[index],
+                    }),
+                    Edit::Replace(index, new_index) => Some(Change::VertexMoved {
+                        component,
+                        ring,
+                        index,
+                        from: old_coords[index],
+                        to: new_coords[new_index],
+                    }),
+                },
+            ));
+        }
+    }
+
+    ComponentsDiff {
+        changes,
+        length_delta: total_length(new) - total_length(old),
+    }
+}
+
+fn ring_length<T: GeoFloat>(ring: &[Coord<T>]) -> T {
+    ring.windows(2)
+        .map(|w| (w[1].x - w[0].x).hypot(w[1].y - w[0].y))
+        .fold(T::zero(), |sum, length| sum + length)
+}
+
+/// An edit aligning an item of the old sequence with one of the new sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    /// The old item at the first index is unchanged as the new item at the second.
+    Keep(usize, usize),
+    /// The old item at the first index was changed to the new item at the second.
+    Replace(usize, usize),
+    Remove(usize),
+    Insert(usize),
+}
+
+/// Above this many cells, the table of common subsequence lengths is too large, and the
+/// differing middles of the sequences are instead aligned by position.
+const MAX_TABLE_SIZE: usize = 1 << 22;
+
+/// Align `old` and `new`, keeping a longest common subsequence of items for which `same` holds,
+/// and pairing the other items between kept items in order.
+fn align<A>(old: &[A], new: &[A], same: impl Fn(&A, &A) -> bool) -> impl Iterator<Item = Edit> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| same(a, b)).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| same(a, b))
+        .count();
+    let (old_end, new_end) = (old.len() - suffix, new.len() - suffix);
+    let (old_middle, new_middle) = (&old[prefix..old_end], &new[prefix..new_end]);
+
+    // The indices of the kept items in the middle, in order
+    let mut kept = vec![];
+    let (n, m) = (old_middle.len(), new_middle.len());
+    if n > 0 && m > 0 && (n + 1) * (m + 1) <= MAX_TABLE_SIZE {
+        // lengths[i * (m + 1) + j] is the length of the longest common subsequence of
+        // old_middle[i..] and new_middle[j..]
+        let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lengths[i * (m + 1) + j] = if same(&old_middle[i], &new_middle[j]) {
+                    lengths[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if same(&old_middle[i], &new_middle[j])
+                && lengths[i * (m + 1) + j] == lengths[(i + 1) * (m + 1) + j + 1] + 1
+            {
+                kept.push((prefix + i, prefix + j));
+                i += 1;
+                j += 1;
+            } else if lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+    }
+    kept.push((old_end, new_end));
+
+    let mut edits: Vec<Edit> = (0..prefix).map(|i| Edit::Keep(i, i)).collect();
+    let (mut i, mut j) = (prefix, prefix);
+    for (kept_i, kept_j) in kept {
+        // Pair the changed items before the kept ones
+        while i < kept_i && j < kept_j {
+            edits.push(Edit::Replace(i, j));
+            i += 1;
+            j += 1;
+        }
+        edits.extend((i..kept_i).map(Edit::Remove));
+        edits.extend((j..kept_j).map(Edit::Insert));
+        if kept_i < old_end {
+            edits.push(Edit::Keep(kept_i, kept_j));
+        }
+        (i, j) = (kept_i + 1, kept_j + 1);
+    }
+    edits.extend((old_end..old.len()).map(|i| Edit::Keep(i, i + new_end - old_end)));
+    edits.into_iter()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, Translate};
+
+    #[test]
+    fn align_edits() {
+        let edits: Vec<_> = align(b"abcdefg", b"abXdeYZg", |a, b| a == b).collect();
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Keep(0, 0),
+                Edit::Keep(1, 1),
+                Edit::Replace(2, 2),
+                Edit::Keep(3, 3),
+                Edit::Keep(4, 4),
+                Edit::Replace(5, 5),
+                Edit::Insert(6),
+                Edit::Keep(6, 7),
+            ]
+        );
+        let edits: Vec<_> = align(b"abc", b"", |a, b| a == b).collect();
+        assert_eq!(
+            edits,
+            vec![Edit::Remove(0), Edit::Remove(1), Edit::Remove(2)]
+        );
+    }
+
+    #[test]
+    fn identical() {
+        let polygon: Polygon = geo_test_fixtures::east_baton_rouge();
+        let diff = polygon.diff(&polygon);
+        assert!(diff.is_unchanged());
+        assert_eq!(diff.area_delta, 0.);
+        assert_eq!(diff.length_delta, 0.);
+    }
+
+    #[test]
+    fn line_string_vertices() {
+        let before = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)];
+        let after = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 3., y: 1.)];
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::VertexRemoved {
+                    component: 0,
+                    ring: 0,
+                    index: 1,
+                    coord: coord! { x: 1., y: 0. },
+                },
+                Change::VertexMoved {
+                    component: 0,
+                    ring: 0,
+                    index: 3,
+                    from: coord! { x: 3., y: 0. },
+                    to: coord! { x: 3., y: 1. },
+                },
+            ]
+        );
+        assert_eq!(diff.area_delta, 0.);
+        assert_relative_eq!(diff.length_delta, 2f64.sqrt() - 1.);
+    }
+
+    #[test]
+    fn components_and_rings() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let hole = line_string![(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.), (x: 1., y: 1.)];
+        let with_hole = Polygon::new(square.exterior().clone(), vec![hole]);
+        let before = MultiPolygon::new(vec![
+            square.clone(),
+            square.translate(10., 0.),
+            square.translate(20., 0.),
+        ]);
+        let after = MultiPolygon::new(vec![
+            with_hole,
+            square.translate(20., 0.),
+            square.translate(30., 0.),
+        ]);
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::RingInserted {
+                    component: 0,
+                    ring: 1,
+                },
+                Change::ComponentRemoved { component: 1 },
+                Change::ComponentInserted { component: 2 },
+            ]
+        );
+        assert_eq!(diff.area_delta, -1.);
+        assert_eq!(diff.length_delta, 4.);
+    }
+
+    #[test]
+    fn tolerance() {
+        let before: Polygon = geo_test_fixtures::east_baton_rouge();
+        let after = before.translate(1e-9, 0.);
+        let exact = before.diff(&after);
+        assert_eq!(exact.changes.len(), before.exterior().0.len());
+        assert!(exact
+            .changes
+            .iter()
+            .all(|change| matches!(change, Change::VertexMoved { .. })));
+        assert!(before.diff_with_tolerance(&after, 1e-8).is_unchanged());
+    }
+
+    #[test]
+    fn geometries() {
+        let before = Geometry::from(point!(x: 0., y: 0.));
+        let after = Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]);
+        assert_eq!(
+            before.diff(&after).changes,
+            vec![Change::VertexInserted {
+                component: 0,
+                ring: 0,
+                index: 1,
+                coord: coord! { x: 1., y: 0. },
+            }]
+        );
+    }
+}
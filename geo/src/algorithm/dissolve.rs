@@ -0,0 +1,315 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::orient::{Direction, Orient};
+use crate::utils::lex_cmp;
+use crate::{
+    Area, BooleanOps, Contains, Coord, GeoFloat, InteriorPoint, LineString, MultiPolygon, Polygon,
+    SharedArcs,
+};
+
+/// Which polygons [`Dissolve`] merges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DissolveMode {
+    /// Merge polygons which share edges, by removing the boundaries between them, as for the
+    /// units of a polygonal coverage such as parcels or administrative areas.
+    ///
+    /// Boundaries are only recognised as shared if they have the same vertices on both sides,
+    /// as for [`SharedArcs`], and the polygons mustn't overlap. This doesn't need a full
+    /// overlay, so it's faster and exact.
+    SharedEdges,
+    /// Merge polygons which intersect in any way, including overlapping, with their union by
+    /// [`BooleanOps`].
+    Intersecting,
+}
+
+/// Merge polygons which are next to each other, or intersect, into larger polygons, as with the
+/// "dissolve" of vector GIS.
+///
+/// The polygons can first be grouped by a key, such as an attribute of each of them, with
+/// [`dissolve_by`](Self::dissolve_by), so that only polygons in the same group are merged.
+/// Polygons which only touch at points stay separate polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, Dissolve, DissolveMode};
+///
+/// // Four parcels in a row, two farmland and two forest
+/// let parcels = [0., 1., 2., 3.].map(|x| {
+///     polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+/// });
+/// let land_use = ["farm", "farm", "forest", "farm"];
+///
+/// let all = parcels.dissolve(DissolveMode::SharedEdges);
+/// assert_eq!(all.0.len(), 1);
+/// assert_eq!(all.unsigned_area(), 4.);
+///
+/// let by_use = parcels.dissolve_by(DissolveMode::SharedEdges, |i, _| land_use[i]);
+/// assert_eq!(by_use.len(), 2);
+/// let (key, farms) = &by_use[0];
+/// assert_eq!(*key, "farm");
+/// // The first two are merged, and the last one is separate
+/// assert_eq!(farms.0.len(), 2);
+/// assert_eq!(farms.unsigned_area(), 3.);
+/// ```
+pub trait Dissolve<T: GeoFloat> {
+    /// Merge all the polygons.
+    fn dissolve(&self, mode: DissolveMode) -> MultiPolygon<T>;
+
+    /// Merge the polygons with the same key, as given for each of them, with its index, by
+    /// `key`, returning the result for each key in order.
+    fn dissolve_by<K: Ord>(
+        &self,
+        mode: DissolveMode,
+        key: impl FnMut(usize, &Polygon<T>) -> K,
+    ) -> Vec<(K, MultiPolygon<T>)>;
+}
+
+impl<T: GeoFloat> Dissolve<T> for [Polygon<T>] {
+    fn dissolve(&self, mode: DissolveMode) -> MultiPolygon<T> {
+        let polygons: Vec<&Polygon<T>> = self.iter().collect();
+        dissolve(&polygons, mode)
+    }
+
+    fn dissolve_by<K: Ord>(
+        &self,
+        mode: DissolveMode,
+        mut key: impl FnMut(usize, &Polygon<T>) -> K,
+    ) -> Vec<(K, MultiPolygon<T>)> {
+        let mut groups: BTreeMap<K, Vec<&Polygon<T>>> = BTreeMap::new();
+        for (i, polygon) in self.iter().enumerate() {
+            groups.entry(key(i, polygon)).or_default().push(polygon);
+        }
+        groups
+            .into_iter()
+            .map(|(key, polygons)| (key, dissolve(&polygons, mode)))
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Dissolve<T> for MultiPolygon<T> {
+    fn dissolve(&self, mode: DissolveMode) -> MultiPolygon<T> {
+        self.0.dissolve(mode)
+    }
+
+    fn dissolve_by<K: Ord>(
+        &self,
+        mode: DissolveMode,
+        key: impl FnMut(usize, &Polygon<T>) -> K,
+    ) -> Vec<(K, MultiPolygon<T>)> {
+        self.0.dissolve_by(mode, key)
+    }
+}
+
+fn dissolve<T: GeoFloat>(polygons: &[&Polygon<T>], mode: DissolveMode) -> MultiPolygon<T> {
+    match mode {
+        DissolveMode::SharedEdges => dissolve_shared_edges(polygons),
+        DissolveMode::Intersecting => union_all(polygons),
+    }
+}
+
+/// The union of `polygons`, combining them in pairs so that each union is of inputs of similar
+/// sizes.
+fn union_all<T: GeoFloat>(polygons: &[&Polygon<T>]) -> MultiPolygon<T> {
+    let mut unions: Vec<MultiPolygon<T>> = polygons
+        .iter()
+        .map(|&polygon| MultiPolygon::new(vec![polygon.clone()]))
+        .collect();
+    while unions.len() > 1 {
+        let mut pairs = unions.into_iter();
+        let mut merged = Vec::with_capacity(pairs.len() / 2 + 1);
+        while let Some(a) = pairs.next() {
+            merged.push(match pairs.next() {
+                Some(b) => a.union(&b),
+                None => a,
+            });
+        }
+        unions = merged;
+    }
+    unions.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
+
+/// Merge `polygons` by keeping only the arcs of their boundaries which aren't shared, and
+/// following them around into rings.
+fn dissolve_shared_edges<T: GeoFloat>(polygons: &[&Polygon<T>]) -> MultiPolygon<T> {
+    // With exteriors counter-clockwise and holes clockwise, the interior is to the left of every
+    // arc, so the remaining arcs can be followed in their directions
+    let oriented: Vec<Polygon<T>> = polygons
+        .iter()
+        .map(|polygon| polygon.orient(Direction::Default))
+        .collect();
+    let shared = SharedArcs::new(&oriented);
+    let mut uses = vec![0_usize; shared.arcs().len()];
+    for i in 0..shared.len() {
+        for arc_ref in shared.rings(i).iter().flatten() {
+            uses[arc_ref.index] += 1;
+        }
+    }
+    let mut arcs: Vec<Vec<Coord<T>>> = vec![];
+    for i in 0..shared.len() {
+        for arc_ref in shared.rings(i).iter().flatten() {
+            if uses[arc_ref.index] == 1 {
+                let mut coords = shared.arcs()[arc_ref.index].0.clone();
+                if arc_ref.reversed {
+                    coords.reverse();
+                }
+                arcs.push(coords);
+            }
+        }
+    }
+
+    let (mut exteriors, mut holes) = (vec![], vec![]);
+    for ring in link_arcs(arcs) {
+        let ring = Polygon::new(LineString::new(ring), vec![]);
+        match ring.signed_area().partial_cmp(&T::zero()) {
+            Some(Ordering::Greater) => exteriors.push((ring, vec![])),
+            Some(Ordering::Less) => holes.push(ring),
+            _ => {}
+        }
+    }
+
+    // Each hole is in the smallest exterior which contains it
+    for hole_polygon in holes {
+        let Some(point) = hole_polygon.interior_point() else {
+            continue;
+        };
+        let smallest = exteriors
+            .iter_mut()
+            .filter(|(exterior, _)| exterior.contains(&point))
+            .min_by(|(a, _), (b, _)| a.unsigned_area().total_cmp(&b.unsigned_area()));
+        if let Some((_, interiors)) = smallest {
+            interiors.push(hole_polygon.into_inner().0);
+        }
+    }
+    exteriors
+        .into_iter()
+        .map(|(exterior, interiors)| Polygon::new(exterior.into_inner().0, interiors))
+        .collect()
+}
+
+/// Join `arcs` end to start into closed rings. Where more than one arc starts at the end of
+/// another, the one which turns most sharply to the left is taken, which keeps the interior to
+/// the left and separates rings which touch at a vertex.
+fn link_arcs<T: GeoFloat>(arcs: Vec<Vec<Coord<T>>>) -> Vec<Vec<Coord<T>>> {
+    let mut starts: Vec<(Coord<T>, usize)> = arcs
+        .iter()
+        .enumerate()
+        .map(|(i, arc)| (arc[0], i))
+        .collect();
+    starts.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+    let mut used = vec![false; arcs.len()];
+
+    let mut rings = vec![];
+    for first in 0..arcs.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut ring = arcs[first].clone();
+        let start = ring[0];
+        while ring[ring.len() - 1] != start {
+            let end = ring[ring.len() - 1];
+            let incoming = end - ring[ring.len() - 2];
+            let from = starts.partition_point(|(coord, _)| lex_cmp(coord, &end).is_lt());
+            let next = starts[from..]
+                .iter()
+                .take_while(|(coord, _)| *coord == end)
+                .map(|&(_, i)| i)
+                .filter(|&i| !used[i])
+                .max_by(|&a, &b| {
+                    let turn = |i: usize| left_turn(incoming, arcs[i][1] - arcs[i][0]);
+                    turn(a).total_cmp(&turn(b))
+                });
+            // The arcs of valid input always close, but rings of invalid input may not
+            let Some(next) = next else {
+                break;
+            };
+            used[next] = true;
+            ring.extend(arcs[next][1..].iter().copied());
+        }
+        if ring[ring.len() - 1] == start {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// The angle by which `outgoing` turns to the left of `incoming`, in (-π, π], where turning
+/// back is π.
+fn left_turn<T: GeoFloat>(incoming: Coord<T>, outgoing: Coord<T>) -> T {
+    let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+    let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+    cross.atan2(dot)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Relate};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![(x: x, y: y), (x: x + size, y: y), (x: x + size, y: y + size), (x: x, y: y + size)]
+    }
+
+    #[test]
+    fn ring_of_squares() {
+        // A 3 x 3 grid without its middle, which dissolves into a square with a hole
+        let squares: Vec<Polygon> = (0..9)
+            .filter(|&i| i != 4)
+            .map(|i| square((i % 3) as f64, (i / 3) as f64, 1.))
+            .collect();
+        let expected = polygon![
+            exterior: [(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        for mode in [DissolveMode::SharedEdges, DissolveMode::Intersecting] {
+            let dissolved = squares.dissolve(mode);
+            assert_eq!(dissolved.0.len(), 1);
+            assert_eq!(dissolved.0[0].interiors().len(), 1);
+            assert!(dissolved.relate(&expected).is_equal_topo());
+            assert_eq!(dissolved.unsigned_area(), 8.);
+        }
+    }
+
+    #[test]
+    fn touching_at_points() {
+        // Diagonal neighbours stay separate, whichever way round the rings go
+        let mut reversed = square(1., 1., 1.);
+        reversed.exterior_mut(|exterior| exterior.0.reverse());
+        let squares = [square(0., 0., 1.), reversed, square(2., 0., 1.)];
+        let dissolved = squares.dissolve(DissolveMode::SharedEdges);
+        assert_eq!(dissolved.0.len(), 3);
+        for polygon in &dissolved {
+            assert_eq!(polygon.unsigned_area(), 1.);
+        }
+    }
+
+    #[test]
+    fn modes_and_groups() {
+        // Overlapping squares only merge when dissolving intersecting polygons
+        let squares = MultiPolygon::new(vec![
+            square(0., 0., 2.),
+            square(1., 1., 2.),
+            square(5., 0., 1.),
+            square(6., 0., 1.),
+        ]);
+        let intersecting = squares.dissolve(DissolveMode::Intersecting);
+        assert_eq!(intersecting.0.len(), 2);
+        assert_eq!(intersecting.unsigned_area(), 9.);
+
+        let groups = squares.dissolve_by(DissolveMode::Intersecting, |i, _| i % 2);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, 0);
+        assert_eq!(groups[0].1.unsigned_area(), 5.);
+        assert_eq!(groups[1].1.unsigned_area(), 5.);
+
+        let empty: Vec<Polygon> = vec![];
+        assert!(empty.dissolve(DissolveMode::SharedEdges).0.is_empty());
+        assert!(empty.dissolve(DissolveMode::Intersecting).0.is_empty());
+        assert!(empty
+            .dissolve_by(DissolveMode::SharedEdges, |i, _| i)
+            .is_empty());
+    }
+}
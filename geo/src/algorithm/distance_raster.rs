@@ -0,0 +1,244 @@
+use alloc::{vec, vec::Vec};
+
+use rstar::{PointDistance, RTree, RTreeNum};
+
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, LinesIter, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Raster, Rect, Triangle,
+};
+
+/// Calculate a [`Raster`] of the Euclidean distance from the center of each cell to the
+/// nearest part of a geometry: a proximity surface, which can be thresholded, or contoured
+/// with [`Contour`](crate::Contour) into bands of distance.
+///
+/// Distances are to points, to lines, and to the boundaries of polygons, so cells inside a
+/// polygon have the distance to its nearest edge, rather than `0`. Use
+/// [`Rasterize::rasterize_mask`](crate::Rasterize::rasterize_mask) to tell cells inside
+/// apart from those outside. Where a geometry is empty, every distance is infinite.
+///
+/// The segments of the geometry are indexed once, so each cell takes a nearest neighbour
+/// search rather than a pass over every segment.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, DistanceRaster, GeometryCollection, Point, Rect};
+///
+/// let road = line_string![(x: 0., y: 0.5), (x: 4., y: 0.5)];
+/// let well = Point::new(3.5, 3.5);
+/// let features = GeometryCollection::new_from(vec![road.into(), well.into()]);
+///
+/// let distances = features.distance_raster(Rect::new((0., 0.), (4., 4.)), 4, 4);
+/// // Row 0 is at the top, so the road runs along row 3
+/// assert_eq!(distances.get(3, 0), Some(&0.));
+/// assert_eq!(distances.get(2, 1), Some(&1.));
+/// assert_eq!(distances.get(0, 3), Some(&0.));
+/// assert_eq!(distances.get(0, 0), Some(&3.));
+/// ```
+pub trait DistanceRaster<T: GeoFloat + RTreeNum> {
+    /// A grid of `rows` by `columns` cells covering `bounds`, with the distance from the center
+    /// of each cell to `self`.
+    fn distance_raster(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<T, T>;
+}
+
+/// The segments which distances are measured to, with points as segments of no length.
+trait Segments<T: GeoFloat> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>);
+}
+
+impl<T: GeoFloat> Segments<T> for Point<T> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+        segments.push(Line::new(self.0, self.0));
+    }
+}
+
+impl<T: GeoFloat> Segments<T> for MultiPoint<T> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+        segments.extend(self.iter().map(|point| Line::new(point.0, point.0)));
+    }
+}
+
+impl<T: GeoFloat> Segments<T> for Line<T> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+        segments.push(*self);
+    }
+}
+
+macro_rules! segments_from_lines {
+    ($($type:ident),*) => {
+        $(
+            impl<T: GeoFloat> Segments<T> for $type<T> {
+                fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+                    let start = segments.len();
+                    segments.extend(self.lines_iter());
+                    // A linear geometry of a single coordinate is a point
+                    if segments.len() == start {
+                        if let Some(coord) = crate::CoordsIter::coords_iter(self).next() {
+                            segments.push(Line::new(coord, coord));
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+segments_from_lines!(
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon,
+    Rect,
+    Triangle
+);
+
+impl<T: GeoFloat> Segments<T> for Geometry<T> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+        match self {
+            Geometry::Point(g) => g.push_segments(segments),
+            Geometry::Line(g) => g.push_segments(segments),
+            Geometry::LineString(g) => g.push_segments(segments),
+            Geometry::Polygon(g) => g.push_segments(segments),
+            Geometry::MultiPoint(g) => g.push_segments(segments),
+            Geometry::MultiLineString(g) => g.push_segments(segments),
+            Geometry::MultiPolygon(g) => g.push_segments(segments),
+            Geometry::GeometryCollection(g) => g.push_segments(segments),
+            Geometry::Rect(g) => g.push_segments(segments),
+            Geometry::Triangle(g) => g.push_segments(segments),
+        }
+    }
+}
+
+impl<T: GeoFloat> Segments<T> for GeometryCollection<T> {
+    fn push_segments(&self, segments: &mut Vec<Line<T>>) {
+        for geometry in self {
+            geometry.push_segments(segments);
+        }
+    }
+}
+
+macro_rules! impl_distance_raster {
+    ($($type:ident),*) => {
+        $(
+            impl<T: GeoFloat + RTreeNum> DistanceRaster<T> for $type<T> {
+                fn distance_raster(
+                    &self,
+                    bounds: Rect<T>,
+                    rows: usize,
+                    columns: usize,
+                ) -> Raster<T, T> {
+                    let mut segments = vec![];
+                    self.push_segments(&mut segments);
+                    distances(segments, bounds, rows, columns)
+                }
+            }
+        )*
+    };
+}
+
+impl_distance_raster!(
+    Point,
+    MultiPoint,
+    Line,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    Geometry,
+    GeometryCollection
+);
+
+fn distances<T: GeoFloat + RTreeNum>(
+    segments: Vec<Line<T>>,
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+) -> Raster<T, T> {
+    let mut raster = Raster::new(bounds, rows, columns, T::infinity());
+    if segments.is_empty() || raster.values.is_empty() {
+        return raster;
+    }
+    let tree = RTree::bulk_load(segments);
+    let size = raster.cell_size();
+    let half = T::from(0.5).unwrap();
+    for row in 0..rows {
+        let y = bounds.max().y - size.y * (T::from(row).unwrap() + half);
+        for column in 0..columns {
+            let x = bounds.min().x + size.x * (T::from(column).unwrap() + half);
+            let center = Point::from(Coord { x, y });
+            if let Some(nearest) = tree.nearest_neighbor(&center) {
+                raster.values[row * columns + column] = nearest.distance_2(&center).sqrt();
+            }
+        }
+    }
+    raster
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, EuclideanDistance};
+
+    #[test]
+    fn polygon_boundary() {
+        let square = polygon![(x: 1., y: 1.), (x: 7., y: 1.), (x: 7., y: 7.), (x: 1., y: 7.)];
+        let distances = square.distance_raster(Rect::new((0., 0.), (8., 8.)), 8, 8);
+        let expected = [0.5, 0.5, 1.5, 2.5, 2.5, 1.5, 0.5, 0.5];
+        assert_eq!(distances.values[3 * 8..4 * 8], expected);
+        // Diagonally out from a corner
+        assert_relative_eq!(distances.values[0], 0.5_f64.hypot(0.5));
+    }
+
+    #[test]
+    fn matches_distances_to_each_geometry() {
+        let mut state = 3_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 10_000) as f64 / 1000.
+        };
+        let mut geometries: Vec<Geometry> = vec![];
+        for _ in 0..5 {
+            geometries.push(Point::new(next(), next()).into());
+            geometries.push(LineString::from(vec![(next(), next()), (next(), next())]).into());
+            let (x, y) = (next(), next());
+            geometries
+                .push(polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 0.5, y: y + 2.)].into());
+        }
+        let collection = GeometryCollection::new_from(geometries.clone());
+        let bounds = Rect::new((-2., -1.), (12., 11.));
+        let distances = collection.distance_raster(bounds, 30, 35);
+        for row in 0..30 {
+            for column in 0..35 {
+                let center = Point::from(distances.cell_rect(row, column).center());
+                let expected = geometries
+                    .iter()
+                    .map(|geometry| match geometry {
+                        Geometry::Polygon(polygon) => center.euclidean_distance(polygon.exterior()),
+                        geometry => center.euclidean_distance(geometry),
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                assert_relative_eq!(
+                    distances.get(row, column).unwrap(),
+                    &expected,
+                    epsilon = 1e-9
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let bounds = Rect::new((0., 0.), (2., 2.));
+        let distances = MultiPoint::<f64>::new(vec![]).distance_raster(bounds, 2, 2);
+        assert_eq!(distances.values, vec![f64::INFINITY; 4]);
+        assert!(Point::new(1., 1.)
+            .distance_raster(bounds, 0, 0)
+            .values
+            .is_empty());
+        // A line string of a single coordinate is a point
+        let single = LineString::from(vec![(0.5, 0.5)]);
+        assert_eq!(single.distance_raster(bounds, 2, 2).get(1, 0), Some(&0.));
+    }
+}
@@ -0,0 +1,413 @@
+use crate::{
+    Area, BooleanOps, BoundingRect, Coord, GeoFloat, Intersects, Line, LineString, MultiLineString,
+    Polygon, Triangle,
+};
+
+/// Calculate the length of a line geometry in three dimensions, with an elevation for each of
+/// its coordinates, in the same units as the coordinates.
+///
+/// The elevations are in the order of the coordinates, so a [`MultiLineString`] takes the
+/// elevations of each of its `LineString`s in turn. Unlike
+/// [`EuclideanLength`](crate::EuclideanLength), which measures the length projected onto the
+/// plane, this includes the climbs and descents along the line, so it's the length to use for
+/// the distance along a trail or a pipe.
+///
+/// # Panics
+///
+/// If there isn't exactly one elevation for each coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, EuclideanLength, Length3D};
+///
+/// // A trail 400m across the map, which climbs 300m and comes back down 300m
+/// let trail = line_string![(x: 0., y: 0.), (x: 200., y: 0.), (x: 400., y: 0.)];
+/// let elevations = [1000., 1300., 1000.];
+/// assert_eq!(trail.euclidean_length(), 400.);
+/// assert_relative_eq!(trail.length_3d(&elevations), 721.1103, epsilon = 1e-4);
+/// ```
+pub trait Length3D<T: GeoFloat> {
+    fn length_3d(&self, elevations: &[T]) -> T;
+}
+
+impl<T: GeoFloat> Length3D<T> for Line<T> {
+    fn length_3d(&self, elevations: &[T]) -> T {
+        assert_eq!(
+            elevations.len(),
+            2,
+            "there must be an elevation for each coordinate"
+        );
+        distance_3d(self.start, elevations[0], self.end, elevations[1])
+    }
+}
+
+impl<T: GeoFloat> Length3D<T> for LineString<T> {
+    fn length_3d(&self, elevations: &[T]) -> T {
+        assert_eq!(
+            elevations.len(),
+            self.0.len(),
+            "there must be an elevation for each coordinate"
+        );
+        self.0
+            .windows(2)
+            .zip(elevations.windows(2))
+            .fold(T::zero(), |sum, (c, z)| {
+                sum + distance_3d(c[0], z[0], c[1], z[1])
+            })
+    }
+}
+
+impl<T: GeoFloat> Length3D<T> for MultiLineString<T> {
+    fn length_3d(&self, elevations: &[T]) -> T {
+        let count = self.iter().map(|line_string| line_string.0.len()).sum();
+        assert_eq!(
+            elevations.len(),
+            count,
+            "there must be an elevation for each coordinate"
+        );
+        let mut start = 0;
+        self.iter().fold(T::zero(), |sum, line_string| {
+            let end = start + line_string.0.len();
+            let length = line_string.length_3d(&elevations[start..end]);
+            start = end;
+            sum + length
+        })
+    }
+}
+
+/// Calculate the area of a surface in three dimensions, such as a triangulated irregular
+/// network (TIN) of terrain, with an elevation for each vertex of its triangles.
+///
+/// The elevations are in the order of the vertices, so a slice of triangles takes three
+/// elevations for each triangle in turn. The area is that of the sloping surface, which is
+/// larger than its [`Area`](crate::Area) on the plane wherever the surface isn't flat.
+///
+/// # Panics
+///
+/// If there isn't exactly one elevation for each vertex.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, Area, SurfaceArea3D, Triangle};
+///
+/// // A plot of land sloping up at 45° towards the north
+/// let plot = [
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. }, coord! { x: 10., y: 10. }),
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. }, coord! { x: 0., y: 10. }),
+/// ];
+/// let elevations = [0., 0., 10., 0., 10., 10.];
+/// let flat_area: f64 = plot.iter().map(|triangle| triangle.unsigned_area()).sum();
+/// assert_relative_eq!(flat_area, 100.);
+/// assert_relative_eq!(plot.surface_area_3d(&elevations), 100. * 2f64.sqrt());
+/// ```
+pub trait SurfaceArea3D<T: GeoFloat> {
+    fn surface_area_3d(&self, elevations: &[T]) -> T;
+}
+
+impl<T: GeoFloat> SurfaceArea3D<T> for Triangle<T> {
+    fn surface_area_3d(&self, elevations: &[T]) -> T {
+        assert_eq!(
+            elevations.len(),
+            3,
+            "there must be an elevation for each vertex"
+        );
+        let [x, y, z] = normal(self, elevations);
+        (x * x + y * y + z * z).sqrt() / T::from(2).unwrap()
+    }
+}
+
+impl<T: GeoFloat> SurfaceArea3D<T> for [Triangle<T>] {
+    fn surface_area_3d(&self, elevations: &[T]) -> T {
+        assert_eq!(
+            elevations.len(),
+            3 * self.len(),
+            "there must be an elevation for each vertex"
+        );
+        self.iter()
+            .zip(elevations.chunks(3))
+            .fold(T::zero(), |sum, (triangle, elevations)| {
+                sum + triangle.surface_area_3d(elevations)
+            })
+    }
+}
+
+/// The slope and aspect of terrain, from [`TerrainSlope`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlopeAspect<T: GeoFloat> {
+    /// The angle of the surface from the horizontal, in degrees from 0° to 90°.
+    pub slope: T,
+    /// The direction the surface faces, which is the direction which is most steeply downhill,
+    /// in degrees clockwise from north (the positive y-axis) from 0° up to 360°. `None` for
+    /// flat surfaces.
+    pub aspect: Option<T>,
+}
+
+/// Calculate the slope and aspect of terrain, with an elevation for each vertex of its
+/// triangles, in the same units as the coordinates.
+///
+/// For a slice of triangles, such as a triangulated irregular network (TIN), which takes three
+/// elevations for each triangle in turn, these are aggregated over the triangles weighted by
+/// their areas on the plane: the slope is the mean slope, and the aspect is the mean of the
+/// directions which the triangles face.
+///
+/// Returns `None` if there are no triangles with area on the plane.
+///
+/// # Panics
+///
+/// If there isn't exactly one elevation for each vertex.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, polygon, TerrainSlope, Triangle};
+///
+/// // A hillside rising by 1m for each meter to the north, so sloping at 45° and facing south
+/// let hillside = [
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. }, coord! { x: 10., y: 10. }),
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. }, coord! { x: 0., y: 10. }),
+/// ];
+/// let elevations = [0., 0., 10., 0., 10., 10.];
+/// let slope_aspect = hillside[0].slope_aspect(&elevations[..3]).unwrap();
+/// assert_relative_eq!(slope_aspect.slope, 45.);
+/// assert_relative_eq!(slope_aspect.aspect.unwrap(), 180.);
+///
+/// // Over the western half of the hillside
+/// let field = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 10.), (x: 0., y: 10.)];
+/// let slope_aspect = hillside.slope_aspect_within(&elevations, &field).unwrap();
+/// assert_relative_eq!(slope_aspect.slope, 45.);
+/// ```
+pub trait TerrainSlope<T: GeoFloat> {
+    fn slope_aspect(&self, elevations: &[T]) -> Option<SlopeAspect<T>>;
+
+    /// The slope and aspect of the part of the terrain within `region`, with the triangles
+    /// weighted by the areas of their intersections with it.
+    fn slope_aspect_within(&self, elevations: &[T], region: &Polygon<T>) -> Option<SlopeAspect<T>>;
+}
+
+impl<T: GeoFloat> TerrainSlope<T> for Triangle<T> {
+    fn slope_aspect(&self, elevations: &[T]) -> Option<SlopeAspect<T>> {
+        core::slice::from_ref(self).slope_aspect(elevations)
+    }
+
+    fn slope_aspect_within(&self, elevations: &[T], region: &Polygon<T>) -> Option<SlopeAspect<T>> {
+        core::slice::from_ref(self).slope_aspect_within(elevations, region)
+    }
+}
+
+impl<T: GeoFloat> TerrainSlope<T> for [Triangle<T>] {
+    fn slope_aspect(&self, elevations: &[T]) -> Option<SlopeAspect<T>> {
+        mean_slope_aspect(self, elevations, |triangle| triangle.unsigned_area())
+    }
+
+    fn slope_aspect_within(&self, elevations: &[T], region: &Polygon<T>) -> Option<SlopeAspect<T>> {
+        let bounds = region.bounding_rect()?;
+        mean_slope_aspect(self, elevations, |triangle| {
+            if !triangle.bounding_rect().intersects(&bounds) {
+                return T::zero();
+            }
+            triangle.to_polygon().intersection(region).unsigned_area()
+        })
+    }
+}
+
+/// The slope and aspect of `triangles`, weighted by `weight`.
+fn mean_slope_aspect<T: GeoFloat>(
+    triangles: &[Triangle<T>],
+    elevations: &[T],
+    weight: impl Fn(&Triangle<T>) -> T,
+) -> Option<SlopeAspect<T>> {
+    assert_eq!(
+        elevations.len(),
+        3 * triangles.len(),
+        "there must be an elevation for each vertex"
+    );
+    let (mut total, mut slope) = (T::zero(), T::zero());
+    // The sum of the weighted unit vectors in the directions the triangles face
+    let (mut east, mut north) = (T::zero(), T::zero());
+    for (triangle, elevations) in triangles.iter().zip(elevations.chunks(3)) {
+        let [x, y, z] = normal(triangle, elevations);
+        if z == T::zero() {
+            continue;
+        }
+        let w = weight(triangle);
+        if w <= T::zero() {
+            continue;
+        }
+        // The normal points up and away from the slope, so in the direction the surface faces
+        let (x, y, z) = if z < T::zero() {
+            (-x, -y, -z)
+        } else {
+            (x, y, z)
+        };
+        let horizontal = x.hypot(y);
+        total = total + w;
+        slope = slope + horizontal.atan2(z) * w;
+        if horizontal > T::zero() {
+            east = east + x / horizontal * w;
+            north = north + y / horizontal * w;
+        }
+    }
+    if total <= T::zero() {
+        return None;
+    }
+
+    let tolerance = T::epsilon() * T::from(16).unwrap() * total;
+    let aspect = (east.hypot(north) > tolerance).then(|| {
+        let degrees = east.atan2(north).to_degrees();
+        if degrees < T::zero() {
+            degrees + T::from(360).unwrap()
+        } else {
+            degrees
+        }
+    });
+    Some(SlopeAspect {
+        slope: (slope / total).to_degrees(),
+        aspect,
+    })
+}
+
+fn distance_3d<T: GeoFloat>(a: Coord<T>, a_z: T, b: Coord<T>, b_z: T) -> T {
+    let (dx, dy, dz) = (b.x - a.x, b.y - a.y, b_z - a_z);
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// The cross product of two sides of `triangle` lifted to `elevations`, which is perpendicular
+/// to it with twice its area as its length.
+fn normal<T: GeoFloat>(triangle: &Triangle<T>, elevations: &[T]) -> [T; 3] {
+    let (u, v) = (triangle.1 - triangle.0, triangle.2 - triangle.0);
+    let (u_z, v_z) = (elevations[1] - elevations[0], elevations[2] - elevations[0]);
+    [
+        u.y * v_z - u_z * v.y,
+        u_z * v.x - u.x * v_z,
+        u.x * v.y - u.y * v.x,
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, EuclideanLength};
+
+    #[test]
+    fn lengths() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 3., y: 4. });
+        assert_relative_eq!(line.length_3d(&[0., 12.]), 13.);
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 4.), (x: 3., y: 4.)];
+        assert_relative_eq!(line_string.length_3d(&[0., 12., 20.]), 21.);
+        // Flat lines are their planar length
+        assert_relative_eq!(
+            line_string.length_3d(&[5., 5., 5.]),
+            line_string.euclidean_length()
+        );
+
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.)],
+            line_string![(x: 10., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 0., y: 1.)],
+        ]);
+        assert_relative_eq!(
+            multi_line_string.length_3d(&[0., 12., 100., 0., 1., 1.]),
+            15.
+        );
+        assert_eq!(LineString::<f64>::new(vec![]).length_3d(&[]), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "there must be an elevation for each coordinate")]
+    fn missing_elevations() {
+        line_string![(x: 0., y: 0.), (x: 3., y: 4.)].length_3d(&[0.]);
+    }
+
+    #[test]
+    fn surface_areas() {
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 4., y: 0. },
+            coord! { x: 0., y: 3. },
+        );
+        assert_relative_eq!(
+            triangle.surface_area_3d(&[7., 7., 7.]),
+            triangle.unsigned_area()
+        );
+        // Tilted along y by a slope of 4 / 3, so stretched by 5 / 3 along y
+        assert_relative_eq!(triangle.surface_area_3d(&[0., 0., 4.]), 10.);
+        // The orientation of the triangle doesn't matter
+        let reversed = Triangle::new(triangle.0, triangle.2, triangle.1);
+        assert_relative_eq!(reversed.surface_area_3d(&[0., 4., 0.]), 10.);
+        // A vertical triangle has no area on the plane, but does have a surface area
+        let vertical = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 1., y: 0. },
+        );
+        assert_relative_eq!(vertical.surface_area_3d(&[0., 0., 3.]), 3.);
+
+        let triangles = [triangle, reversed];
+        assert_relative_eq!(triangles.surface_area_3d(&[0., 0., 4., 0., 4., 0.]), 20.);
+    }
+
+    fn tin() -> ([Triangle; 4], [f64; 12]) {
+        // A ridge along x = 1, from (0, 0) to (2, 2), with the west side sloping up to the east
+        // at 45° and the east side sloping down to the east at about 63°
+        let c = |x, y| coord! { x: x, y: y };
+        let triangles = [
+            Triangle::new(c(0., 0.), c(1., 0.), c(1., 2.)),
+            Triangle::new(c(0., 0.), c(1., 2.), c(0., 2.)),
+            Triangle::new(c(1., 0.), c(2., 0.), c(2., 2.)),
+            Triangle::new(c(1., 0.), c(2., 2.), c(1., 2.)),
+        ];
+        let elevations = [0., 1., 1., 0., 1., 0., 1., -1., -1., 1., -1., 1.];
+        (triangles, elevations)
+    }
+
+    #[test]
+    fn triangle_slope_aspect() {
+        let (triangles, elevations) = tin();
+        let west = triangles[0].slope_aspect(&elevations[..3]).unwrap();
+        assert_relative_eq!(west.slope, 45.);
+        assert_relative_eq!(west.aspect.unwrap(), 270.);
+        let east = triangles[2].slope_aspect(&elevations[6..9]).unwrap();
+        assert_relative_eq!(east.slope, 2f64.atan().to_degrees());
+        assert_relative_eq!(east.aspect.unwrap(), 90.);
+        // The orientation of the triangle doesn't matter
+        let reversed = Triangle::new(triangles[2].0, triangles[2].2, triangles[2].1);
+        let reversed = reversed.slope_aspect(&[1., -1., -1.]).unwrap();
+        assert_relative_eq!(reversed.slope, east.slope);
+        assert_relative_eq!(reversed.aspect.unwrap(), 90.);
+
+        let flat = triangles[0].slope_aspect(&[3., 3., 3.]).unwrap();
+        assert_eq!((flat.slope, flat.aspect), (0., None));
+        let vertical = Triangle::new(c(0., 0.), c(1., 1.), c(2., 2.));
+        assert_eq!(vertical.slope_aspect(&[0., 1., 3.]), None);
+
+        fn c(x: f64, y: f64) -> Coord {
+            coord! { x: x, y: y }
+        }
+    }
+
+    #[test]
+    fn aggregate_slope_aspect() {
+        let (triangles, elevations) = tin();
+        let steep = 2f64.atan().to_degrees();
+        let ridge = triangles.slope_aspect(&elevations).unwrap();
+        assert_relative_eq!(ridge.slope, (45. + steep) / 2.);
+        // Facing east and west equally
+        assert_eq!(ridge.aspect, None);
+
+        // Two thirds of the region is on the east side
+        let region = polygon![(x: 0.5, y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0.5, y: 2.)];
+        let within = triangles.slope_aspect_within(&elevations, &region).unwrap();
+        assert_relative_eq!(within.slope, (45. + 2. * steep) / 3., epsilon = 1e-9);
+        assert_relative_eq!(within.aspect.unwrap(), 90., epsilon = 1e-9);
+
+        let elsewhere = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)];
+        assert_eq!(triangles.slope_aspect_within(&elevations, &elsewhere), None);
+        assert_eq!(<[Triangle]>::slope_aspect(&[], &[]), None);
+    }
+}
@@ -0,0 +1,105 @@
+use geographiclib_rs::Geodesic;
+
+/// An ellipsoid of revolution, used as the model of a body's surface by the geodesic
+/// algorithms, such as [`GeodesicDistance`](crate::GeodesicDistance) and
+/// [`GeodesicArea`](crate::GeodesicArea).
+///
+/// Those algorithms use [`Ellipsoid::WGS84`]. Their `OnEllipsoid` counterparts, such as
+/// [`GeodesicDistanceOnEllipsoid`](crate::GeodesicDistanceOnEllipsoid), take another ellipsoid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Ellipsoid, GeodesicDistance, GeodesicDistanceOnEllipsoid};
+///
+/// let p1 = point!(x: -74.006, y: 40.7128);
+/// let p2 = point!(x: -0.1278, y: 51.5074);
+///
+/// let wgs84 = p1.geodesic_distance(&p2);
+/// let grs80 = p1.geodesic_distance_on_ellipsoid(&p2, &Ellipsoid::GRS80);
+/// assert!((wgs84 - grs80).abs() < 1e-3);
+///
+/// // Mars
+/// let mars = Ellipsoid::new(3_396_190., 1. / 169.894_447_2);
+/// assert_eq!(p1.geodesic_distance_on_ellipsoid(&p2, &mars).round(), 2_977_524.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    /// The equatorial radius, in meters.
+    pub semi_major_axis: f64,
+
+    /// The flattening, `(a - b) / a` for a semi-major axis `a` and a semi-minor axis `b`. It's
+    /// zero for a sphere, and negative for a prolate ellipsoid.
+    pub flattening: f64,
+}
+
+impl Ellipsoid {
+    /// The World Geodetic System 1984 ellipsoid, used by GPS.
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6_378_137.,
+        flattening: 1. / 298.257_223_563,
+    };
+
+    /// The Geodetic Reference System 1980 ellipsoid, used by NAD83 and ETRS89.
+    pub const GRS80: Ellipsoid = Ellipsoid {
+        semi_major_axis: 6_378_137.,
+        flattening: 1. / 298.257_222_101,
+    };
+
+    /// A sphere with the mean radius of the earth.
+    pub const SPHERE: Ellipsoid = Ellipsoid {
+        semi_major_axis: crate::MEAN_EARTH_RADIUS,
+        flattening: 0.,
+    };
+
+    pub fn new(semi_major_axis: f64, flattening: f64) -> Self {
+        Ellipsoid {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// A sphere with the given radius, in meters.
+    pub fn sphere(radius: f64) -> Self {
+        Ellipsoid::new(radius, 0.)
+    }
+
+    /// The polar radius, in meters.
+    pub fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1. - self.flattening)
+    }
+
+    pub(crate) fn geodesic(&self) -> Geodesic {
+        Geodesic::new(self.semi_major_axis, self.flattening)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        assert_relative_eq!(
+            Ellipsoid::WGS84.semi_minor_axis(),
+            6_356_752.314_245,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            Ellipsoid::GRS80.semi_minor_axis(),
+            6_356_752.314_140,
+            epsilon = 1e-6
+        );
+        assert_eq!(
+            Ellipsoid::SPHERE.semi_minor_axis(),
+            crate::MEAN_EARTH_RADIUS
+        );
+
+        let wgs84 = Ellipsoid::WGS84.geodesic();
+        assert_eq!(
+            wgs84.equatorial_radius(),
+            Geodesic::wgs84().equatorial_radius()
+        );
+        assert_eq!(wgs84.flattening(), Geodesic::wgs84().flattening());
+    }
+}
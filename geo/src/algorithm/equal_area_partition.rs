@@ -0,0 +1,304 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Area, BooleanOps, BoundingRect, Coord, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// How [`EqualAreaPartition`] cuts a polygon into parts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartitionMethod<T> {
+    /// Cut the polygon into strips with parallel straight cuts, in order along the direction
+    /// `angle` degrees counter-clockwise from the x-axis: with an angle of 0 the cuts are
+    /// vertical, and the strips go from west to east.
+    Strips { angle: T },
+    /// Cut the polygon in two across its longer side, into parts with areas in proportion to
+    /// the numbers of parts to be made from each, and cut each of those the same way, for
+    /// parts which are more compact than strips.
+    Recursive,
+}
+
+/// Divide a polygon into a number of parts of equal area, such as sales territories or
+/// search sectors, with straight cuts.
+///
+/// The areas of the parts are equal to within a tiny fraction of the area of the polygon.
+/// Each part is a `MultiPolygon`, since a straight cut through a polygon which isn't convex
+/// can leave a part in several pieces. A polygon without area has no parts.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, BoundingRect, EqualAreaPartition, PartitionMethod};
+///
+/// // A right triangle, with its right angle at the origin
+/// let field = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+///
+/// let strips = field.equal_area_partition(2, PartitionMethod::Strips { angle: 0. });
+/// assert_eq!(strips.len(), 2);
+/// for strip in &strips {
+///     assert_relative_eq!(strip.unsigned_area(), 4., epsilon = 1e-6);
+/// }
+/// // The cut is vertical, at x = 4 - √8
+/// let west = strips[0].bounding_rect().unwrap();
+/// assert_relative_eq!(west.max().x, 4. - 8_f64.sqrt(), epsilon = 1e-6);
+///
+/// let parts = field.equal_area_partition(4, PartitionMethod::Recursive);
+/// assert_eq!(parts.len(), 4);
+/// for part in &parts {
+///     assert_relative_eq!(part.unsigned_area(), 2., epsilon = 1e-6);
+/// }
+/// ```
+pub trait EqualAreaPartition<T: GeoFloat> {
+    /// Divide the polygon into `parts` parts of equal area, cut by `method`.
+    fn equal_area_partition(
+        &self,
+        parts: usize,
+        method: PartitionMethod<T>,
+    ) -> Vec<MultiPolygon<T>>;
+}
+
+impl<T: GeoFloat> EqualAreaPartition<T> for Polygon<T> {
+    fn equal_area_partition(
+        &self,
+        parts: usize,
+        method: PartitionMethod<T>,
+    ) -> Vec<MultiPolygon<T>> {
+        MultiPolygon::new(vec![self.clone()]).equal_area_partition(parts, method)
+    }
+}
+
+impl<T: GeoFloat> EqualAreaPartition<T> for MultiPolygon<T> {
+    fn equal_area_partition(
+        &self,
+        parts: usize,
+        method: PartitionMethod<T>,
+    ) -> Vec<MultiPolygon<T>> {
+        if parts == 0 || self.unsigned_area() <= T::zero() {
+            return vec![];
+        }
+        if parts == 1 {
+            return vec![self.clone()];
+        }
+        match method {
+            PartitionMethod::Strips { angle } => {
+                let radians = angle.to_radians();
+                let direction = Coord {
+                    x: radians.cos(),
+                    y: radians.sin(),
+                };
+                strips(self, direction, parts)
+            }
+            PartitionMethod::Recursive => {
+                let mut partition = Vec::with_capacity(parts);
+                recursive_partition(self.clone(), parts, &mut partition);
+                partition
+            }
+        }
+    }
+}
+
+/// Cut `multi_polygon` into `parts` strips of equal area, in order along `direction`.
+fn strips<T: GeoFloat>(
+    multi_polygon: &MultiPolygon<T>,
+    direction: Coord<T>,
+    parts: usize,
+) -> Vec<MultiPolygon<T>> {
+    let cutter = Cutter::new(multi_polygon, direction);
+    let parts_t = T::from(parts).unwrap();
+    let mut cuts = vec![cutter.start];
+    for k in 1..parts {
+        let fraction = T::from(k).unwrap() / parts_t;
+        let previous = cuts[k - 1];
+        cuts.push(cutter.cut(multi_polygon, fraction, previous));
+    }
+    cuts.push(cutter.end);
+    cuts.windows(2)
+        .map(|cut| cutter.clip(multi_polygon, cut[0], cut[1]))
+        .collect()
+}
+
+/// Cut `multi_polygon` in two across its longer side, with areas in proportion to the numbers
+/// of parts to make from each half, and add the parts of each half to `partition`.
+fn recursive_partition<T: GeoFloat>(
+    multi_polygon: MultiPolygon<T>,
+    parts: usize,
+    partition: &mut Vec<MultiPolygon<T>>,
+) {
+    if parts == 1 {
+        partition.push(multi_polygon);
+        return;
+    }
+    let (width, height) = extent(&multi_polygon);
+    let direction = if width >= height {
+        Coord {
+            x: T::one(),
+            y: T::zero(),
+        }
+    } else {
+        Coord {
+            x: T::zero(),
+            y: T::one(),
+        }
+    };
+    let first_parts = parts / 2;
+    let fraction = T::from(first_parts).unwrap() / T::from(parts).unwrap();
+    let cutter = Cutter::new(&multi_polygon, direction);
+    let cut = cutter.cut(&multi_polygon, fraction, cutter.start);
+    let first = cutter.clip(&multi_polygon, cutter.start, cut);
+    let second = cutter.clip(&multi_polygon, cut, cutter.end);
+    recursive_partition(first, first_parts, partition);
+    recursive_partition(second, parts - first_parts, partition);
+}
+
+fn extent<T: GeoFloat>(multi_polygon: &MultiPolygon<T>) -> (T, T) {
+    multi_polygon
+        .bounding_rect()
+        .map(|rect| (rect.width(), rect.height()))
+        .unwrap_or((T::zero(), T::zero()))
+}
+
+/// Clips geometries to the strips between lines across a direction.
+struct Cutter<T: GeoFloat> {
+    direction: Coord<T>,
+    /// Beyond the extent of the geometry before its start along the direction, and after its
+    /// end
+    start: T,
+    end: T,
+    /// Beyond the extent of the geometry on either side of the direction
+    left: T,
+    right: T,
+    area: T,
+}
+
+impl<T: GeoFloat> Cutter<T> {
+    fn new(multi_polygon: &MultiPolygon<T>, direction: Coord<T>) -> Self {
+        let across = Coord {
+            x: -direction.y,
+            y: direction.x,
+        };
+        let (mut start, mut end) = (T::infinity(), T::neg_infinity());
+        let (mut right, mut left) = (T::infinity(), T::neg_infinity());
+        for coord in multi_polygon
+            .iter()
+            .flat_map(|polygon| &polygon.exterior().0)
+        {
+            let along = dot(*coord, direction);
+            let side = dot(*coord, across);
+            start = start.min(along);
+            end = end.max(along);
+            right = right.min(side);
+            left = left.max(side);
+        }
+        let margin = (end - start).max(left - right);
+        Cutter {
+            direction,
+            start: start - margin,
+            end: end + margin,
+            left: left + margin,
+            right: right - margin,
+            area: multi_polygon.unsigned_area(),
+        }
+    }
+
+    /// The part of `multi_polygon` between `from` and `to` along the direction.
+    fn clip(&self, multi_polygon: &MultiPolygon<T>, from: T, to: T) -> MultiPolygon<T> {
+        let across = Coord {
+            x: -self.direction.y,
+            y: self.direction.x,
+        };
+        let at = |along: T, side: T| self.direction * along + across * side;
+        let strip = Polygon::new(
+            LineString::new(vec![
+                at(from, self.right),
+                at(to, self.right),
+                at(to, self.left),
+                at(from, self.left),
+                at(from, self.right),
+            ]),
+            vec![],
+        );
+        multi_polygon.intersection(&MultiPolygon::new(vec![strip]))
+    }
+
+    /// Where to cut `multi_polygon`, after `from` along the direction, so that `fraction` of its
+    /// area comes before the cut.
+    fn cut(&self, multi_polygon: &MultiPolygon<T>, fraction: T, from: T) -> T {
+        let target = self.area * fraction;
+        let tolerance = self.area * T::epsilon().sqrt();
+        let (mut low, mut high) = (from, self.end);
+        let mut cut = (low + high) / (T::one() + T::one());
+        for _ in 0..100 {
+            cut = (low + high) / (T::one() + T::one());
+            let area = self.clip(multi_polygon, self.start, cut).unsigned_area();
+            if (area - target).abs() <= tolerance {
+                break;
+            }
+            if area < target {
+                low = cut;
+            } else {
+                high = cut;
+            }
+        }
+        cut
+    }
+}
+
+fn dot<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.x + a.y * b.y
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn strips_at_an_angle() {
+        // An L shape, whose strips can be in pieces
+        let polygon: Polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 6., y: 0.),
+            (x: 6., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 6.),
+            (x: 0., y: 6.),
+        ];
+        for angle in [0., 45., 90., 200.] {
+            let strips = polygon.equal_area_partition(5, PartitionMethod::Strips { angle });
+            assert_eq!(strips.len(), 5);
+            for strip in &strips {
+                assert_relative_eq!(strip.unsigned_area(), 4., epsilon = 1e-6);
+            }
+        }
+        // Diagonal strips cut across both arms of the L
+        let strips = polygon.equal_area_partition(5, PartitionMethod::Strips { angle: 45. });
+        assert_eq!(strips[4].0.len(), 2);
+
+        assert!(polygon
+            .equal_area_partition(0, PartitionMethod::Recursive)
+            .is_empty());
+        assert_eq!(
+            polygon.equal_area_partition(1, PartitionMethod::Strips { angle: 0. }),
+            vec![MultiPolygon::new(vec![polygon])]
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        // Two separate squares, of different sizes
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)],
+            polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 1.), (x: 10., y: 1.)],
+        ]);
+        let parts = multi_polygon.equal_area_partition(5, PartitionMethod::Recursive);
+        assert_eq!(parts.len(), 5);
+        for part in &parts {
+            assert_relative_eq!(part.unsigned_area(), 2., epsilon = 1e-6);
+        }
+        let area: f64 = parts.iter().map(|part| part.unsigned_area()).sum();
+        assert_relative_eq!(area, 10., epsilon = 1e-9);
+
+        let flat: Polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert!(flat
+            .equal_area_partition(2, PartitionMethod::Recursive)
+            .is_empty());
+    }
+}
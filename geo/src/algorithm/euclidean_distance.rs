@@ -1,3 +1,4 @@
+use crate::intersects::MIN_SEGMENT_PAIRS;
 use crate::utils::{coord_pos_relative_to_ring, CoordPos};
 use crate::EuclideanLength;
 use crate::Intersects;
@@ -5,7 +6,8 @@ use crate::{
     Coord, GeoFloat, GeoNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
     MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use num_traits::{float::FloatConst, Bounded, Float, Signed};
+use alloc::vec::Vec;
+use num_traits::{float::FloatConst, Bounded, Signed};
 
 use rstar::primitives::CachedEnvelope;
 use rstar::RTree;
@@ -321,11 +323,7 @@ where
             && ring_contains_point(other, Point::from(self.0[0]))
         {
             // check each ring distance, returning the minimum
-            let mut mindist: T = Float::max_value();
-            for ring in other.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(self, ring))
-            }
-            mindist
+            line_strings_distance(&[self], &other.interiors().iter().collect::<Vec<_>>())
         } else {
             nearest_neighbour_distance(self, other.exterior())
         }
@@ -380,19 +378,17 @@ where
             && ring_contains_point(self, Point::from(poly2.exterior().0[0]))
         {
             // check each ring distance, returning the minimum
-            let mut mindist: T = Float::max_value();
-            for ring in self.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(poly2.exterior(), ring))
-            }
-            return mindist;
+            return line_strings_distance(
+                &[poly2.exterior()],
+                &self.interiors().iter().collect::<Vec<_>>(),
+            );
         } else if !poly2.interiors().is_empty()
             && ring_contains_point(poly2, Point::from(self.exterior().0[0]))
         {
-            let mut mindist: T = Float::max_value();
-            for ring in poly2.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(self.exterior(), ring))
-            }
-            return mindist;
+            return line_strings_distance(
+                &[self.exterior()],
+                &poly2.interiors().iter().collect::<Vec<_>>(),
+            );
         }
         nearest_neighbour_distance(self.exterior(), poly2.exterior())
     }
@@ -446,6 +442,67 @@ impl_euclidean_distance_to_polygonlike_geometry!(Polygon<T>,             [Rect<T
 impl_euclidean_distance_to_polygonlike_geometry!(MultiPolygon<T>,        [Rect<T>, Triangle<T>]);
 impl_euclidean_distance_to_polygonlike_geometry!(GeometryCollection<T>,  [Rect<T>, Triangle<T>]);
 
+// ┌─────────────────────────────────────┐
+// │ Implementations for MultiLineString │
+// └─────────────────────────────────────┘
+
+// The segments of all the line strings are compared at once, rather than building an R* tree
+// of `other` for each of them.
+
+/// MultiLineString to LineString
+impl<T> EuclideanDistance<T, LineString<T>> for MultiLineString<T>
+where
+    T: GeoFloat + FloatConst + RTreeNum,
+{
+    fn euclidean_distance(&self, other: &LineString<T>) -> T {
+        if self.intersects(other) {
+            T::zero()
+        } else {
+            line_strings_distance(&self.iter().collect::<Vec<_>>(), &[other])
+        }
+    }
+}
+
+/// MultiLineString to MultiLineString
+impl<T> EuclideanDistance<T, MultiLineString<T>> for MultiLineString<T>
+where
+    T: GeoFloat + FloatConst + RTreeNum,
+{
+    fn euclidean_distance(&self, other: &MultiLineString<T>) -> T {
+        if self.intersects(other) {
+            T::zero()
+        } else {
+            line_strings_distance(
+                &self.iter().collect::<Vec<_>>(),
+                &other.iter().collect::<Vec<_>>(),
+            )
+        }
+    }
+}
+
+/// MultiLineString to Polygon
+impl<T> EuclideanDistance<T, Polygon<T>> for MultiLineString<T>
+where
+    T: GeoFloat + FloatConst + RTreeNum,
+{
+    fn euclidean_distance(&self, other: &Polygon<T>) -> T {
+        if self.intersects(other) {
+            return T::zero();
+        }
+        // Line strings in a hole are nearest to the interior rings, and the others to the
+        // exterior
+        let (in_holes, outside): (Vec<_>, Vec<_>) = self
+            .iter()
+            .filter(|line_string| !line_string.0.is_empty())
+            .partition(|line_string| {
+                !other.interiors().is_empty()
+                    && ring_contains_point(other, Point::from(line_string.0[0]))
+            });
+        line_strings_distance(&in_holes, &other.interiors().iter().collect::<Vec<_>>())
+            .min(line_strings_distance(&outside, &[other.exterior()]))
+    }
+}
+
 // ┌───────────────────────────────────────────┐
 // │ Implementations for multi geometry types  │
 // └───────────────────────────────────────────┘
@@ -470,7 +527,7 @@ macro_rules! impl_euclidean_distance_for_iter_geometry {
 }
 
 impl_euclidean_distance_for_iter_geometry!(MultiPoint<T>,         [Point<T>, MultiPoint<T>, Line<T>, LineString<T>, MultiLineString<T>, Polygon<T>, MultiPolygon<T>, GeometryCollection<T>]);
-impl_euclidean_distance_for_iter_geometry!(MultiLineString<T>,    [Point<T>, MultiPoint<T>, Line<T>, LineString<T>, MultiLineString<T>, Polygon<T>, MultiPolygon<T>, GeometryCollection<T>]);
+impl_euclidean_distance_for_iter_geometry!(MultiLineString<T>,    [Point<T>, MultiPoint<T>, Line<T>, MultiPolygon<T>, GeometryCollection<T>]);
 impl_euclidean_distance_for_iter_geometry!(MultiPolygon<T>,       [Point<T>, MultiPoint<T>, Line<T>, LineString<T>, MultiLineString<T>, Polygon<T>, MultiPolygon<T>, GeometryCollection<T>]);
 impl_euclidean_distance_for_iter_geometry!(GeometryCollection<T>, [Point<T>, MultiPoint<T>, Line<T>, LineString<T>, MultiLineString<T>, Polygon<T>, MultiPolygon<T>, GeometryCollection<T>]);
 
@@ -586,19 +643,71 @@ pub fn nearest_neighbour_distance<T>(geom1: &LineString<T>, geom2: &LineString<T
 where
     T: GeoFloat + RTreeNum,
 {
-    let tree_a = RTree::bulk_load(geom1.lines().map(CachedEnvelope::new).collect());
-    let tree_b = RTree::bulk_load(geom2.lines().map(CachedEnvelope::new).collect());
-    // Return minimum distance between all geom a points and geom b lines, and all geom b points and geom a lines
-    geom2
-        .points()
-        .fold(<T as Bounded>::max_value(), |acc, point| {
-            let nearest = tree_a.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
+    line_strings_distance(&[geom1], &[geom2])
+}
+
+/// The minimum distance between the segments of the line strings `a` and those of `b`, which
+/// must not intersect.
+///
+/// Small inputs are compared segment by segment, and larger ones using R* trees of their
+/// segments and nearest-neighbour lookups.
+fn line_strings_distance<T>(a: &[&LineString<T>], b: &[&LineString<T>]) -> T
+where
+    T: GeoFloat + RTreeNum,
+{
+    let lines = |geoms: &[&LineString<T>]| -> usize { geoms.iter().map(|g| g.lines().len()).sum() };
+    // As the segments don't intersect, the nearest points of any two of them include an
+    // endpoint, so return the minimum distance between all a points and b lines, and all b
+    // points and a lines
+    if lines(a).saturating_mul(lines(b)) <= MIN_SEGMENT_PAIRS {
+        return points_lines_distance(b, a).min(points_lines_distance(a, b));
+    }
+    let tree = |geoms: &[&LineString<T>]| {
+        RTree::bulk_load(
+            geoms
+                .iter()
+                .flat_map(|g| g.lines())
+                .map(CachedEnvelope::new)
+                .collect(),
+        )
+    };
+    points_tree_distance(b, &tree(a)).min(points_tree_distance(a, &tree(b)))
+}
+
+/// The minimum distance between the points of `points` and the lines of `lines`.
+fn points_lines_distance<T>(points: &[&LineString<T>], lines: &[&LineString<T>]) -> T
+where
+    T: GeoFloat,
+{
+    points
+        .iter()
+        .flat_map(|g| g.points())
+        .flat_map(|point| {
+            lines
+                .iter()
+                .flat_map(|g| g.lines())
+                .map(move |line| line.euclidean_distance(&point))
+        })
+        .fold(<T as Bounded>::max_value(), |acc, distance| {
+            acc.min(distance)
+        })
+}
+
+/// The minimum distance between the points of `points` and the lines in `tree`.
+fn points_tree_distance<T>(points: &[&LineString<T>], tree: &RTree<CachedEnvelope<Line<T>>>) -> T
+where
+    T: GeoFloat + RTreeNum,
+{
+    points
+        .iter()
+        .flat_map(|g| g.points())
+        .filter_map(|point| {
+            tree.nearest_neighbor(&point)
+                .map(|nearest| nearest.euclidean_distance(&point))
+        })
+        .fold(<T as Bounded>::max_value(), |acc, distance| {
+            acc.min(distance)
         })
-        .min(geom1.points().fold(Bounded::max_value(), |acc, point| {
-            let nearest = tree_b.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
-        }))
 }
 
 #[cfg(test)]
@@ -626,7 +735,7 @@ mod test {
         // Results agree with Shapely
         assert_relative_eq!(dist, 2.0485900789263356);
         assert_relative_eq!(dist2, 1.118033988749895);
-        assert_relative_eq!(dist3, std::f64::consts::SQRT_2); // workaround clippy::correctness error approx_constant (1.4142135623730951)
+        assert_relative_eq!(dist3, core::f64::consts::SQRT_2); // workaround clippy::correctness error approx_constant (1.4142135623730951)
         assert_relative_eq!(dist4, 1.5811388300841898);
         // Point is on the line
         let zero_dist = line_segment_distance(p1, p1, p2);
@@ -1085,6 +1194,41 @@ mod test {
         assert_relative_eq!(ring.euclidean_distance(&poly_in_ring), 5.992772737231033);
     }
     #[test]
+    fn test_large_multilinestring_distance() {
+        use crate::Translate;
+
+        let ring = geo_test_fixtures::ring::<f64>();
+        let poly_in_ring = geo_test_fixtures::poly_in_ring::<f64>();
+        let shell = geo_test_fixtures::shell::<f64>();
+        let parts = MultiLineString::new(vec![
+            poly_in_ring.clone(),
+            poly_in_ring.translate(1000., 0.),
+            ring.translate(0., -1000.),
+        ]);
+        let polygon = Polygon::new(shell.clone(), vec![ring.clone()]);
+        let per_part = |distance: &dyn Fn(&LineString<f64>) -> f64| {
+            parts.iter().map(distance).fold(f64::MAX, f64::min)
+        };
+        assert_relative_eq!(
+            parts.euclidean_distance(&ring),
+            per_part(&|part| part.euclidean_distance(&ring))
+        );
+        assert_relative_eq!(
+            parts.euclidean_distance(&polygon),
+            per_part(&|part| part.euclidean_distance(&polygon))
+        );
+        assert_relative_eq!(parts.euclidean_distance(&ring), 5.992772737231033);
+        assert_relative_eq!(polygon.euclidean_distance(&parts), 5.992772737231033);
+        let others = MultiLineString::new(vec![shell.translate(0., 2000.), ring]);
+        assert_relative_eq!(
+            parts.euclidean_distance(&others),
+            others
+                .iter()
+                .map(|other| parts.euclidean_distance(other))
+                .fold(f64::MAX, f64::min)
+        );
+    }
+    #[test]
     // Line-Polygon test: closest point on Polygon is NOT nearest to a Line end-point
     fn test_line_polygon_simple() {
         let line = Line::from([(0.0, 0.0), (0.0, 3.0)]);
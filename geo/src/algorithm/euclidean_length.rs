@@ -1,5 +1,7 @@
-use std::iter::Sum;
+use core::iter::Sum;
 
+use crate::soa::{segment_length, SoaLineString};
+use crate::view::LineStringSlice;
 use crate::{CoordFloat, Line, LineString, MultiLineString};
 
 /// Calculation of the length
@@ -44,6 +46,27 @@ where
     }
 }
 
+impl<T> EuclideanLength<T> for LineStringSlice<'_, T>
+where
+    T: CoordFloat + Sum,
+{
+    fn euclidean_length(&self) -> T {
+        self.lines().map(|line| line.euclidean_length()).sum()
+    }
+}
+
+impl<T> EuclideanLength<T> for SoaLineString<T>
+where
+    T: CoordFloat,
+{
+    fn euclidean_length(&self) -> T {
+        let [length] = self
+            .0
+            .segment_sums(|start, end| [segment_length(start, end)]);
+        length
+    }
+}
+
 impl<T> EuclideanLength<T> for MultiLineString<T>
 where
     T: CoordFloat + Sum,
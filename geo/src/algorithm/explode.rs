@@ -0,0 +1,366 @@
+use alloc::{vec, vec::Vec};
+use core::slice;
+
+use crate::{
+    CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Iterate over the single-part geometries which make up a multi-part geometry: the `Point`s of
+/// a `MultiPoint`, the `LineString`s of a `MultiLineString` and the `Polygon`s of a
+/// `MultiPolygon`.
+///
+/// `GeometryCollection`s and `Geometry`s are flattened recursively, through nested collections
+/// and the multi-part geometries in them, into [`PartRef`]s when borrowed and single-part
+/// `Geometry`s when consumed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Explode, Geometry, GeometryCollection, MultiPolygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let multi_polygon = MultiPolygon::new(vec![square.clone(), square.clone()]);
+/// assert_eq!(multi_polygon.explode().count(), 2);
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::MultiPolygon(multi_polygon),
+///     Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+///         Geometry::Point(point!(x: 5., y: 5.)),
+///     ])),
+/// ]);
+/// let parts: Vec<Geometry> = collection.into_explode().collect();
+/// assert_eq!(
+///     parts,
+///     vec![
+///         Geometry::Polygon(square.clone()),
+///         Geometry::Polygon(square),
+///         Geometry::Point(point!(x: 5., y: 5.)),
+///     ]
+/// );
+/// ```
+pub trait Explode {
+    type Part<'a>
+    where
+        Self: 'a;
+    type Iter<'a>: Iterator<Item = Self::Part<'a>>
+    where
+        Self: 'a;
+    type IntoPart;
+    type IntoIter: Iterator<Item = Self::IntoPart>;
+
+    /// Iterate over references to the parts of a geometry.
+    fn explode(&self) -> Self::Iter<'_>;
+
+    /// Iterate over the parts of a geometry, consuming it.
+    fn into_explode(self) -> Self::IntoIter;
+}
+
+macro_rules! impl_explode_for_multi {
+    ($multi:ident, $part:ident) => {
+        impl<T: CoordNum> Explode for $multi<T> {
+            type Part<'a>
+                = &'a $part<T>
+            where
+                T: 'a;
+            type Iter<'a>
+                = slice::Iter<'a, $part<T>>
+            where
+                T: 'a;
+            type IntoPart = $part<T>;
+            type IntoIter = vec::IntoIter<$part<T>>;
+
+            fn explode(&self) -> Self::Iter<'_> {
+                self.0.iter()
+            }
+
+            fn into_explode(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+    };
+}
+
+impl_explode_for_multi!(MultiPoint, Point);
+impl_explode_for_multi!(MultiLineString, LineString);
+impl_explode_for_multi!(MultiPolygon, Polygon);
+
+impl<T: CoordNum> Explode for GeometryCollection<T> {
+    type Part<'a>
+        = PartRef<'a, T>
+    where
+        T: 'a;
+    type Iter<'a>
+        = ExplodeIter<'a, T>
+    where
+        T: 'a;
+    type IntoPart = Geometry<T>;
+    type IntoIter = IntoExplodeIter<T>;
+
+    fn explode(&self) -> Self::Iter<'_> {
+        ExplodeIter {
+            single: None,
+            stack: vec![Pending::Geometries(self.0.iter())],
+        }
+    }
+
+    fn into_explode(self) -> Self::IntoIter {
+        IntoExplodeIter {
+            single: None,
+            stack: vec![IntoPending::Geometries(self.0.into_iter())],
+        }
+    }
+}
+
+impl<T: CoordNum> Explode for Geometry<T> {
+    type Part<'a>
+        = PartRef<'a, T>
+    where
+        T: 'a;
+    type Iter<'a>
+        = ExplodeIter<'a, T>
+    where
+        T: 'a;
+    type IntoPart = Geometry<T>;
+    type IntoIter = IntoExplodeIter<T>;
+
+    fn explode(&self) -> Self::Iter<'_> {
+        let mut iter = ExplodeIter {
+            single: None,
+            stack: Vec::new(),
+        };
+        iter.push(self);
+        iter
+    }
+
+    fn into_explode(self) -> Self::IntoIter {
+        let mut iter = IntoExplodeIter {
+            single: None,
+            stack: Vec::new(),
+        };
+        iter.push(self);
+        iter
+    }
+}
+
+/// A reference to a single-part geometry, from [`Explode::explode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartRef<'a, T: CoordNum> {
+    Point(&'a Point<T>),
+    Line(&'a Line<T>),
+    LineString(&'a LineString<T>),
+    Polygon(&'a Polygon<T>),
+    Rect(&'a Rect<T>),
+    Triangle(&'a Triangle<T>),
+}
+
+impl<T: CoordNum> From<PartRef<'_, T>> for Geometry<T> {
+    fn from(part: PartRef<'_, T>) -> Self {
+        match part {
+            PartRef::Point(g) => Geometry::Point(*g),
+            PartRef::Line(g) => Geometry::Line(*g),
+            PartRef::LineString(g) => Geometry::LineString(g.clone()),
+            PartRef::Polygon(g) => Geometry::Polygon(g.clone()),
+            PartRef::Rect(g) => Geometry::Rect(*g),
+            PartRef::Triangle(g) => Geometry::Triangle(*g),
+        }
+    }
+}
+
+/// Iterator over the parts of a [`Geometry`] or [`GeometryCollection`], from
+/// [`Explode::explode`].
+#[derive(Debug)]
+pub struct ExplodeIter<'a, T: CoordNum> {
+    single: Option<PartRef<'a, T>>,
+    stack: Vec<Pending<'a, T>>,
+}
+
+#[derive(Debug)]
+enum Pending<'a, T: CoordNum> {
+    Points(slice::Iter<'a, Point<T>>),
+    LineStrings(slice::Iter<'a, LineString<T>>),
+    Polygons(slice::Iter<'a, Polygon<T>>),
+    Geometries(slice::Iter<'a, Geometry<T>>),
+}
+
+impl<'a, T: CoordNum> ExplodeIter<'a, T> {
+    fn push(&mut self, geometry: &'a Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.single = Some(PartRef::Point(g)),
+            Geometry::Line(g) => self.single = Some(PartRef::Line(g)),
+            Geometry::LineString(g) => self.single = Some(PartRef::LineString(g)),
+            Geometry::Polygon(g) => self.single = Some(PartRef::Polygon(g)),
+            Geometry::Rect(g) => self.single = Some(PartRef::Rect(g)),
+            Geometry::Triangle(g) => self.single = Some(PartRef::Triangle(g)),
+            Geometry::MultiPoint(g) => self.stack.push(Pending::Points(g.0.iter())),
+            Geometry::MultiLineString(g) => self.stack.push(Pending::LineStrings(g.0.iter())),
+            Geometry::MultiPolygon(g) => self.stack.push(Pending::Polygons(g.0.iter())),
+            Geometry::GeometryCollection(g) => self.stack.push(Pending::Geometries(g.0.iter())),
+        }
+    }
+}
+
+impl<'a, T: CoordNum> Iterator for ExplodeIter<'a, T> {
+    type Item = PartRef<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(part) = self.single.take() {
+                return Some(part);
+            }
+            let part = match self.stack.last_mut()? {
+                Pending::Points(iter) => iter.next().map(PartRef::Point),
+                Pending::LineStrings(iter) => iter.next().map(PartRef::LineString),
+                Pending::Polygons(iter) => iter.next().map(PartRef::Polygon),
+                Pending::Geometries(iter) => match iter.next() {
+                    Some(geometry) => {
+                        self.push(geometry);
+                        continue;
+                    }
+                    None => None,
+                },
+            };
+            match part {
+                Some(part) => return Some(part),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the parts of a [`Geometry`] or [`GeometryCollection`], from
+/// [`Explode::into_explode`].
+#[derive(Debug)]
+pub struct IntoExplodeIter<T: CoordNum> {
+    single: Option<Geometry<T>>,
+    stack: Vec<IntoPending<T>>,
+}
+
+#[derive(Debug)]
+enum IntoPending<T: CoordNum> {
+    Points(vec::IntoIter<Point<T>>),
+    LineStrings(vec::IntoIter<LineString<T>>),
+    Polygons(vec::IntoIter<Polygon<T>>),
+    Geometries(vec::IntoIter<Geometry<T>>),
+}
+
+impl<T: CoordNum> IntoExplodeIter<T> {
+    fn push(&mut self, geometry: Geometry<T>) {
+        match geometry {
+            Geometry::MultiPoint(g) => self.stack.push(IntoPending::Points(g.0.into_iter())),
+            Geometry::MultiLineString(g) => {
+                self.stack.push(IntoPending::LineStrings(g.0.into_iter()))
+            }
+            Geometry::MultiPolygon(g) => self.stack.push(IntoPending::Polygons(g.0.into_iter())),
+            Geometry::GeometryCollection(g) => {
+                self.stack.push(IntoPending::Geometries(g.0.into_iter()))
+            }
+            single => self.single = Some(single),
+        }
+    }
+}
+
+impl<T: CoordNum> Iterator for IntoExplodeIter<T> {
+    type Item = Geometry<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(part) = self.single.take() {
+                return Some(part);
+            }
+            let part = match self.stack.last_mut()? {
+                IntoPending::Points(iter) => iter.next().map(Geometry::Point),
+                IntoPending::LineStrings(iter) => iter.next().map(Geometry::LineString),
+                IntoPending::Polygons(iter) => iter.next().map(Geometry::Polygon),
+                IntoPending::Geometries(iter) => match iter.next() {
+                    Some(geometry) => {
+                        self.push(geometry);
+                        continue;
+                    }
+                    None => None,
+                },
+            };
+            match part {
+                Some(part) => return Some(part),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+
+    fn nested() -> Geometry {
+        Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::Point(point!(x: 0., y: 0.)),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            Geometry::MultiLineString(MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 2., y: 2.), (x: 3., y: 3.)],
+            ])),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::MultiPoint(MultiPoint::new(vec![])),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::Line(
+                    Line::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 0. }),
+                )])),
+                Geometry::MultiPolygon(MultiPolygon::new(vec![polygon![
+                    (x: 0., y: 0.),
+                    (x: 1., y: 0.),
+                    (x: 1., y: 1.),
+                ]])),
+            ])),
+        ]))
+    }
+
+    #[test]
+    fn multi_parts() {
+        let multi_point = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]);
+        let borrowed: Vec<&Point> = multi_point.explode().collect();
+        assert_eq!(borrowed, vec![&multi_point.0[0], &multi_point.0[1]]);
+        let owned: Vec<Point> = multi_point.clone().into_explode().collect();
+        assert_eq!(owned, multi_point.0);
+
+        let empty: MultiPolygon = MultiPolygon::new(vec![]);
+        assert_eq!(empty.explode().count(), 0);
+    }
+
+    #[test]
+    fn flattens_collections() {
+        let geometry = nested();
+        let borrowed: Vec<Geometry> = geometry.explode().map(Geometry::from).collect();
+        let owned: Vec<Geometry> = geometry.into_explode().collect();
+        assert_eq!(borrowed, owned);
+        let kinds: Vec<&str> = owned
+            .iter()
+            .map(|geometry| match geometry {
+                Geometry::Point(_) => "point",
+                Geometry::Line(_) => "line",
+                Geometry::LineString(_) => "line string",
+                Geometry::Polygon(_) => "polygon",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec!["point", "line string", "line string", "line", "polygon"]
+        );
+
+        // Single-part geometries are their only part
+        let point = Geometry::Point(point!(x: 3., y: 4.));
+        assert_eq!(
+            point.explode().collect::<Vec<_>>(),
+            vec![PartRef::Point(&point!(x: 3., y: 4.))]
+        );
+        assert_eq!(
+            point.clone().into_explode().collect::<Vec<_>>(),
+            vec![point]
+        );
+    }
+}
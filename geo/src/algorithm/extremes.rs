@@ -28,6 +28,40 @@ pub trait Extremes<'a, T: CoordNum> {
     fn extremes(&'a self) -> Option<Outcome<T>>;
 }
 
+/// Find the supporting coordinates of a geometry along a direction: those whose projections
+/// onto it, their dot products with it, are the smallest and largest.
+///
+/// The projections are scaled by the length of the direction, so a unit vector gives
+/// distances along it. Comparing the ranges of the projections of two geometries along the
+/// normals of their edges is the separating axis test for collisions of convex shapes.
+///
+/// # Examples
+///
+/// ```
+/// use geo::extremes::ExtremesAlong;
+/// use geo::{coord, polygon};
+///
+/// // a diamond shape
+/// let polygon = polygon![
+///     (x: 1.0, y: 0.0),
+///     (x: 2.0, y: 1.0),
+///     (x: 1.0, y: 2.0),
+///     (x: 0.0, y: 1.0),
+///     (x: 1.0, y: 0.0),
+/// ];
+///
+/// // the extremes along the direction of the diagonal
+/// let along = polygon.extremes_along(coord! { x: 1.0, y: 1.0 }).unwrap();
+///
+/// assert_eq!(along.min.index, 0);
+/// assert_eq!(along.max.index, 1);
+/// assert_eq!(along.min_projection, 1.);
+/// assert_eq!(along.max_projection, 3.);
+/// ```
+pub trait ExtremesAlong<'a, T: CoordNum> {
+    fn extremes_along(&'a self, direction: Coord<T>) -> Option<DirectionalOutcome<T>>;
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Extreme<T: CoordNum> {
     pub index: usize,
@@ -42,6 +76,14 @@ pub struct Outcome<T: CoordNum> {
     pub y_max: Extreme<T>,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct DirectionalOutcome<T: CoordNum> {
+    pub min: Extreme<T>,
+    pub max: Extreme<T>,
+    pub min_projection: T,
+    pub max_projection: T,
+}
+
 impl<'a, T, G> Extremes<'a, T> for G
 where
     G: CoordsIter<Scalar = T>,
@@ -79,6 +121,40 @@ where
     }
 }
 
+impl<'a, T, G> ExtremesAlong<'a, T> for G
+where
+    G: CoordsIter<Scalar = T>,
+    T: CoordNum,
+{
+    fn extremes_along(&'a self, direction: Coord<T>) -> Option<DirectionalOutcome<T>> {
+        let project = |coord: Coord<T>| coord.x * direction.x + coord.y * direction.y;
+        let mut iter = self.exterior_coords_iter().enumerate();
+
+        let mut outcome = iter.next().map(|(index, coord)| DirectionalOutcome {
+            min: Extreme { index, coord },
+            max: Extreme { index, coord },
+            min_projection: project(coord),
+            max_projection: project(coord),
+        })?;
+
+        for (index, coord) in iter {
+            let projection = project(coord);
+
+            if projection < outcome.min_projection {
+                outcome.min = Extreme { coord, index };
+                outcome.min_projection = projection;
+            }
+
+            if projection > outcome.max_projection {
+                outcome.max = Extreme { coord, index };
+                outcome.max_projection = projection;
+            }
+        }
+
+        Some(outcome)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -128,4 +204,50 @@ mod test {
 
         assert!(actual.is_none());
     }
+
+    #[test]
+    fn along_direction() {
+        let polygon = polygon![
+            (x: 1.0, y: 0.0),
+            (x: 2.0, y: 1.0),
+            (x: 1.0, y: 2.0),
+            (x: 0.0, y: 1.0),
+            (x: 1.0, y: 0.0),
+        ];
+
+        // the axes agree with the extremes
+        let extremes = polygon.extremes().unwrap();
+        let along_x = polygon.extremes_along(coord! { x: 1.0, y: 0.0 }).unwrap();
+        assert_eq!(along_x.min, extremes.x_min);
+        assert_eq!(along_x.max, extremes.x_max);
+        let along_y = polygon.extremes_along(coord! { x: 0.0, y: -2.0 }).unwrap();
+        assert_eq!(along_y.min, extremes.y_max);
+        assert_eq!(along_y.max, extremes.y_min);
+        assert_eq!(
+            (along_y.min_projection, along_y.max_projection),
+            (-4.0, 0.0)
+        );
+
+        let skewed = polygon.extremes_along(coord! { x: -1.0, y: 0.5 }).unwrap();
+        assert_eq!(
+            skewed,
+            DirectionalOutcome {
+                min: Extreme {
+                    index: 1,
+                    coord: coord! { x: 2.0, y: 1.0 }
+                },
+                max: Extreme {
+                    index: 3,
+                    coord: coord! { x: 0.0, y: 1.0 }
+                },
+                min_projection: -1.5,
+                max_projection: 0.5,
+            }
+        );
+
+        let multi_point: MultiPoint<f32> = MultiPoint::new(vec![]);
+        assert!(multi_point
+            .extremes_along(coord! { x: 1.0, y: 0.0 })
+            .is_none());
+    }
 }
@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+
+use num_traits::FromPrimitive;
+
+use crate::angles::signed_angle;
+use crate::{Coord, CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Round the corners of a geometry with circular arcs of a given radius.
+///
+/// Each corner is replaced by an arc of radius `radius` which is tangent to both of its edges.
+/// The arc is approximated by segments whose midpoints are at most `tolerance` from it. A corner
+/// is left sharp if its arc doesn't fit: the arcs of neighbouring corners can each use at most
+/// half of the edge between them, and the arc at the end of an edge which ends an open
+/// linestring can use all of it. Straight and fully reversed corners are left as they are.
+///
+/// The start and end vertices of an open linestring are preserved, and every vertex of a closed
+/// linestring, including its closing vertex, is a corner.
+///
+/// A `radius` or `tolerance` less than or equal to zero will return an unaltered version of the
+/// geometry.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, Fillet};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let rounded = square.fillet(2., 0.001);
+///
+/// // Each corner loses the area between a 2 x 2 square and a quarter circle
+/// assert_relative_eq!(rounded.unsigned_area(), 100. - (4. - std::f64::consts::PI) * 4., epsilon = 0.01);
+///
+/// // A radius of 6 doesn't fit in the corners of the square
+/// assert_eq!(square.fillet(6., 0.001), square);
+/// ```
+pub trait Fillet<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn fillet(&self, radius: T, tolerance: T) -> Self;
+}
+
+impl<T> Fillet<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn fillet(&self, radius: T, tolerance: T) -> Self {
+        if radius <= T::zero() || tolerance <= T::zero() {
+            return self.clone();
+        }
+        let mut coords = self.0.clone();
+        coords.dedup();
+        let closed = self.is_closed();
+        if closed {
+            coords.pop();
+        }
+        let n = coords.len();
+        if n < 3 {
+            return self.clone();
+        }
+
+        // The length of the edge from each vertex to the next, available to the arc at each of
+        // its ends
+        let two = T::from(2).unwrap();
+        let available = |i: usize, j: usize| {
+            let length = (coords[j] - coords[i]).x.hypot((coords[j] - coords[i]).y);
+            if !closed && (i == 0 || j == n - 1) {
+                length
+            } else {
+                length / two
+            }
+        };
+
+        let mut out = Vec::new();
+        let corners = if closed { 0..n } else { 1..n - 1 };
+        if !closed {
+            out.push(coords[0]);
+        }
+        for i in corners {
+            let (prev, next) = ((i + n - 1) % n, (i + 1) % n);
+            let arc = corner_arc(
+                coords[prev],
+                coords[i],
+                coords[next],
+                radius,
+                tolerance,
+                available(prev, i).min(available(i, next)),
+            );
+            match arc {
+                Some(arc) => out.extend(arc),
+                None => out.push(coords[i]),
+            }
+        }
+        if closed {
+            out.push(out[0]);
+        } else {
+            out.push(coords[n - 1]);
+        }
+        // Arcs which use all of an edge between them meet at a single point
+        out.dedup();
+        out.into()
+    }
+}
+
+impl<T> Fillet<T> for MultiLineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn fillet(&self, radius: T, tolerance: T) -> Self {
+        MultiLineString::new(
+            self.0
+                .iter()
+                .map(|ls| ls.fillet(radius, tolerance))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Fillet<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn fillet(&self, radius: T, tolerance: T) -> Self {
+        Polygon::new(
+            self.exterior().fillet(radius, tolerance),
+            self.interiors()
+                .iter()
+                .map(|ls| ls.fillet(radius, tolerance))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Fillet<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn fillet(&self, radius: T, tolerance: T) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|poly| poly.fillet(radius, tolerance))
+                .collect(),
+        )
+    }
+}
+
+/// The points of the arc of `radius` tangent to both edges of the corner at `corner`, or `None`
+/// if the arc would reach further than `available` along either edge.
+fn corner_arc<T>(
+    prev: Coord<T>,
+    corner: Coord<T>,
+    next: Coord<T>,
+    radius: T,
+    tolerance: T,
+    available: T,
+) -> Option<Vec<Coord<T>>>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let unit = |c: Coord<T>| c / c.x.hypot(c.y);
+    let (u, v) = (unit(prev - corner), unit(next - corner));
+    // The angle between the edges, positive if the next one is counter-clockwise of the previous
+    let signed = signed_angle(prev, corner, next);
+    let angle = signed.abs().to_radians();
+    let half = angle / T::from(2).unwrap();
+    if half.sin() <= T::epsilon() || half.cos() <= T::epsilon() {
+        return None;
+    }
+    let tangent = radius / half.tan();
+    // Allowing for rounding errors, so that an arc which exactly fits isn't skipped
+    if tangent > available * (T::one() + T::from(16).unwrap() * T::epsilon()) {
+        return None;
+    }
+
+    let start = corner + u * tangent;
+    let bisector = unit(u + v);
+    let center = corner + bisector * (radius / half.sin());
+    let sweep = T::from(core::f64::consts::PI).unwrap() - angle;
+    // The angle of the arc covered by each segment, so that it's within tolerance of the arc
+    let step = if tolerance >= radius {
+        sweep
+    } else {
+        T::from(2).unwrap() * (T::one() - tolerance / radius).acos()
+    };
+    let segments = (sweep / step).ceil().max(T::one());
+    let count = segments.to_usize().unwrap_or(1);
+    let step = sweep / segments;
+    // Turn from `prev` towards `next`, around the center
+    let step = if signed > T::zero() { -step } else { step };
+
+    let offset = start - center;
+    let arc = (0..=count)
+        .map(|k| {
+            let (sin, cos) = (step * T::from(k).unwrap()).sin_cos();
+            center
+                + Coord {
+                    x: offset.x * cos - offset.y * sin,
+                    y: offset.x * sin + offset.y * cos,
+                }
+        })
+        .collect();
+    Some(arc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area, EuclideanDistance, Point};
+
+    #[test]
+    fn open_line_string() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let rounded = line_string.fillet(1., 0.001);
+        assert_eq!(rounded.0.first(), line_string.0.first());
+        assert_eq!(rounded.0.last(), line_string.0.last());
+        assert_relative_eq!(rounded.0[1], Coord { x: 9., y: 0. });
+        let end = rounded.0[rounded.0.len() - 2];
+        assert_relative_eq!(end, Coord { x: 10., y: 1. }, epsilon = 1e-12);
+        // The arc is centered on (9, 1)
+        for coord in &rounded.0[1..rounded.0.len() - 1] {
+            assert_relative_eq!(
+                Point::from(*coord).euclidean_distance(&Point::new(9., 1.)),
+                1.,
+                epsilon = 1e-12
+            );
+        }
+        // The end edges can be used completely
+        assert_ne!(line_string.fillet(10., 0.001), line_string);
+        assert_eq!(line_string.fillet(10.1, 0.001), line_string);
+    }
+
+    #[test]
+    fn tolerance() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let coarse = line_string.fillet(5., 1.);
+        let fine = line_string.fillet(5., 0.01);
+        assert!(fine.0.len() > coarse.0.len());
+        for window in fine.0[1..fine.0.len() - 1].windows(2) {
+            let middle = (window[0] + window[1]) / 2.;
+            let distance = Point::from(middle).euclidean_distance(&Point::new(5., 5.));
+            assert!(5. - distance <= 0.01);
+        }
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 4., y: 6.), (x: 6., y: 6.), (x: 6., y: 4.)]],
+        ];
+        let rounded = polygon.fillet(1., 0.0001);
+        assert!(rounded.exterior().is_closed());
+        assert_relative_eq!(
+            rounded.exterior().0[0],
+            Coord { x: 0., y: 1. },
+            epsilon = 1e-12
+        );
+        let corner_loss = 4. - core::f64::consts::PI;
+        // The hole's corners, whose edges are 2 long, can be rounded with a radius of 1 at most
+        assert_relative_eq!(
+            rounded.unsigned_area(),
+            (100. - corner_loss) - (4. - corner_loss),
+            epsilon = 1e-3
+        );
+        assert_eq!(
+            polygon.fillet(1.1, 0.0001).interiors()[0],
+            polygon.interiors()[0]
+        );
+    }
+
+    #[test]
+    fn straight_and_spike() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(line_string.fillet(0.1, 0.01), line_string);
+    }
+
+    #[test]
+    fn no_radius() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(polygon.fillet(0., 0.01), polygon);
+        assert_eq!(polygon.fillet(1., 0.), polygon);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{AffineOps, AffineTransform, BoundingRect, CoordFloat, Rect};
+
+/// How [`FitInto`] scales the two axes of a geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AspectRatio {
+    /// Scale both axes by the same factor, so the shape is unchanged, and center the geometry
+    /// along the axis which has room to spare.
+    #[default]
+    Preserve,
+    /// Scale each axis separately, so the geometry's bounding rectangle fills the target.
+    Stretch,
+}
+
+/// An affine transformation which scales and translates a geometry so that its bounding
+/// rectangle fits into a target rectangle, as for drawing thumbnails of geometries or
+/// normalizing them before comparing their shapes.
+///
+/// The target rectangle is shrunk by `padding` on each of its sides first. An axis along which
+/// the geometry has no extent, such as the y-axis of a horizontal line, isn't scaled, and the
+/// geometry is centered along it.
+///
+/// ## Performance
+///
+/// To fit many geometries into the same target together, such as the layers of a map, find the
+/// transform of their combined bounding rectangle once with
+/// [`fit_into_transform`](Self::fit_into_transform), and apply it to each of them with
+/// [`AffineOps`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, AspectRatio, BoundingRect, FitInto, Rect};
+///
+/// // A track in projected coordinates, to draw on a 100 x 100 tile with a margin of 10
+/// let track = line_string![(x: 500_000., y: 4_000_000.), (x: 502_000., y: 4_001_000.)];
+/// let tile = Rect::new((0., 0.), (100., 100.));
+///
+/// let fitted = track.fit_into(tile, AspectRatio::Preserve, 10.);
+/// assert_eq!(fitted.bounding_rect(), Some(Rect::new((10., 30.), (90., 70.))));
+///
+/// let stretched = track.fit_into(tile, AspectRatio::Stretch, 10.);
+/// assert_eq!(stretched.bounding_rect(), Some(Rect::new((10., 10.), (90., 90.))));
+/// ```
+pub trait FitInto<T: CoordFloat> {
+    /// The transformation which fits the bounding rectangle of a geometry into `target`, or
+    /// `None` if the geometry is empty.
+    fn fit_into_transform(
+        &self,
+        target: Rect<T>,
+        aspect_ratio: AspectRatio,
+        padding: T,
+    ) -> Option<AffineTransform<T>>;
+
+    /// Scale and translate a geometry so that its bounding rectangle fits into `target`.
+    #[must_use]
+    fn fit_into(&self, target: Rect<T>, aspect_ratio: AspectRatio, padding: T) -> Self;
+
+    /// Mutable version of [`fit_into`](Self::fit_into)
+    fn fit_into_mut(&mut self, target: Rect<T>, aspect_ratio: AspectRatio, padding: T);
+}
+
+impl<T, IR, G> FitInto<T> for G
+where
+    T: CoordFloat,
+    IR: Into<Option<Rect<T>>>,
+    G: Clone + AffineOps<T> + BoundingRect<T, Output = IR>,
+{
+    fn fit_into_transform(
+        &self,
+        target: Rect<T>,
+        aspect_ratio: AspectRatio,
+        padding: T,
+    ) -> Option<AffineTransform<T>> {
+        let bounds = self.bounding_rect().into()?;
+        let two = T::one() + T::one();
+        let width = (target.width() - two * padding).max(T::zero());
+        let height = (target.height() - two * padding).max(T::zero());
+
+        // An axis without extent has no factor, and takes the other's
+        let factor = |room: T, extent: T| (extent > T::zero()).then(|| room / extent);
+        let (x_factor, y_factor) = match (
+            factor(width, bounds.width()),
+            factor(height, bounds.height()),
+        ) {
+            (Some(x), Some(y)) => match aspect_ratio {
+                AspectRatio::Preserve => (x.min(y), x.min(y)),
+                AspectRatio::Stretch => (x, y),
+            },
+            (Some(x), None) => (x, x),
+            (None, Some(y)) => (y, y),
+            (None, None) => (T::one(), T::one()),
+        };
+
+        let (from, to) = (bounds.center(), target.center());
+        Some(AffineTransform::new(
+            x_factor,
+            T::zero(),
+            to.x - x_factor * from.x,
+            T::zero(),
+            y_factor,
+            to.y - y_factor * from.y,
+        ))
+    }
+
+    fn fit_into(&self, target: Rect<T>, aspect_ratio: AspectRatio, padding: T) -> Self {
+        match self.fit_into_transform(target, aspect_ratio, padding) {
+            Some(transform) => self.affine_transform(&transform),
+            // Empty geometries have nothing to fit
+            None => self.clone(),
+        }
+    }
+
+    fn fit_into_mut(&mut self, target: Rect<T>, aspect_ratio: AspectRatio, padding: T) {
+        if let Some(transform) = self.fit_into_transform(target, aspect_ratio, padding) {
+            self.affine_transform_mut(&transform);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, LineString, MultiPoint, Polygon};
+
+    #[test]
+    fn fits_into_target() {
+        let polygon: Polygon = polygon![(x: -4., y: 2.), (x: 4., y: 2.), (x: 0., y: 10.)];
+        let target = Rect::new((10., 10.), (30., 20.));
+        let fitted = polygon.fit_into(target, AspectRatio::Preserve, 0.);
+        assert_eq!(
+            fitted,
+            polygon![(x: 15., y: 10.), (x: 25., y: 10.), (x: 20., y: 20.)]
+        );
+
+        let stretched = polygon.fit_into(target, AspectRatio::Stretch, 2.);
+        assert_eq!(
+            stretched.bounding_rect(),
+            Some(Rect::new((12., 12.), (28., 18.)))
+        );
+
+        let mut in_place = polygon;
+        in_place.fit_into_mut(target, AspectRatio::Preserve, 0.);
+        assert_eq!(in_place, fitted);
+
+        // The transform can be reused for other geometries
+        let transform = fitted
+            .fit_into_transform(Rect::new((0., 0.), (1., 1.)), AspectRatio::Preserve, 0.)
+            .unwrap();
+        assert_eq!(transform.apply((20., 20.).into()), (0.5, 1.).into());
+    }
+
+    #[test]
+    fn degenerate() {
+        let target = Rect::new((0., 0.), (10., 10.));
+
+        // Lines along an axis are scaled along it, and centered across it
+        let horizontal: LineString = line_string![(x: 0., y: 5.), (x: 2., y: 5.)];
+        let fitted = horizontal.fit_into(target, AspectRatio::Stretch, 1.);
+        assert_eq!(fitted, line_string![(x: 1., y: 5.), (x: 9., y: 5.)]);
+
+        let point = point!(x: 100., y: 100.);
+        assert_eq!(
+            point.fit_into(target, AspectRatio::Preserve, 0.),
+            point!(x: 5., y: 5.)
+        );
+
+        // Too much padding shrinks the geometry to the center
+        let square: Polygon =
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let shrunk = square.fit_into(target, AspectRatio::Preserve, 6.);
+        assert_eq!(shrunk.bounding_rect(), Some(Rect::new((5., 5.), (5., 5.))));
+
+        let empty: MultiPoint = MultiPoint::new(vec![]);
+        assert_eq!(
+            empty.fit_into_transform(target, AspectRatio::Preserve, 0.),
+            None
+        );
+        assert_eq!(empty.fit_into(target, AspectRatio::Preserve, 0.), empty);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::coords_iter::CoordsIter;
 use crate::euclidean_distance::EuclideanDistance;
 use crate::{GeoFloat, LineString, Point};
+use alloc::{vec, vec::Vec};
 use num_traits::FromPrimitive;
 
 /// Determine the similarity between two `LineStrings` using the [Frechet distance].
@@ -1,5 +1,6 @@
 use crate::geometry::*;
-use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+use crate::Ellipsoid;
+use geographiclib_rs::{PolygonArea, Winding};
 
 /// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
 ///
@@ -161,33 +162,75 @@ pub trait GeodesicArea<T> {
     fn geodesic_perimeter_area_unsigned(&self) -> (T, T);
 }
 
+/// Determine the perimeter and area of a geometry on a given ellipsoid, rather than on
+/// [WGS84](Ellipsoid::WGS84) as [`GeodesicArea`] does.
+pub trait GeodesicAreaOnEllipsoid<T> {
+    /// Determine the perimeter and area of a geometry on the given ellipsoid, in a
+    /// `(perimeter, area)` tuple.
+    ///
+    /// The area follows the same winding conventions as
+    /// [`geodesic_perimeter_area_signed`](GeodesicArea::geodesic_perimeter_area_signed).
+    ///
+    /// # Units
+    ///
+    /// - return value: (meter, meter²)
+    fn geodesic_perimeter_area_signed_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (T, T);
+
+    /// Determine the perimeter and unsigned area of a geometry on the given ellipsoid, in a
+    /// `(perimeter, area)` tuple.
+    ///
+    /// The area follows the same assumptions as
+    /// [`geodesic_perimeter_area_unsigned`](GeodesicArea::geodesic_perimeter_area_unsigned).
+    ///
+    /// # Units
+    ///
+    /// - return value: (meter, meter²)
+    fn geodesic_perimeter_area_unsigned_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (T, T);
+}
+
 impl GeodesicArea<f64> for Polygon {
     fn geodesic_perimeter(&self) -> f64 {
-        let (perimeter, _area) = geodesic_area(self, true, false, false);
+        let (perimeter, _area) = geodesic_area(self, &Ellipsoid::WGS84, true, false, false);
         perimeter
     }
 
     fn geodesic_area_signed(&self) -> f64 {
-        let (_perimeter, area) = geodesic_area(self, true, false, false);
+        let (_perimeter, area) = geodesic_area(self, &Ellipsoid::WGS84, true, false, false);
         area
     }
 
     fn geodesic_area_unsigned(&self) -> f64 {
-        let (_perimeter, area) = geodesic_area(self, false, false, false);
+        let (_perimeter, area) = geodesic_area(self, &Ellipsoid::WGS84, false, false, false);
         area
     }
 
     fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
-        geodesic_area(self, true, false, false)
+        geodesic_area(self, &Ellipsoid::WGS84, true, false, false)
     }
 
     fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
-        geodesic_area(self, false, false, false)
+        geodesic_area(self, &Ellipsoid::WGS84, false, false, false)
     }
 }
 
-fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool) -> (f64, f64) {
-    let g = Geodesic::wgs84();
+impl GeodesicAreaOnEllipsoid<f64> for Polygon {
+    fn geodesic_perimeter_area_signed_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (f64, f64) {
+        geodesic_area(self, ellipsoid, true, false, false)
+    }
+
+    fn geodesic_perimeter_area_unsigned_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (f64, f64) {
+        geodesic_area(self, ellipsoid, false, false, false)
+    }
+}
+
+fn geodesic_area(
+    poly: &Polygon,
+    ellipsoid: &Ellipsoid,
+    sign: bool,
+    reverse: bool,
+    exterior_only: bool,
+) -> (f64, f64) {
+    let g = ellipsoid.geodesic();
 
     let (exterior_winding, interior_winding) = if reverse {
         (Winding::Clockwise, Winding::CounterClockwise)
@@ -233,7 +276,8 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
     )
 }
 
-/// Generate a `GeodesicArea` implementation where the result is zero.
+/// Generate `GeodesicArea` and `GeodesicAreaOnEllipsoid` implementations where the result is
+/// zero.
 macro_rules! zero_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
@@ -257,11 +301,21 @@ macro_rules! zero_impl {
                 (0.0, 0.0)
             }
         }
+
+        impl GeodesicAreaOnEllipsoid<f64> for $type {
+            fn geodesic_perimeter_area_signed_on_ellipsoid(&self, _: &Ellipsoid) -> (f64, f64) {
+                (0.0, 0.0)
+            }
+
+            fn geodesic_perimeter_area_unsigned_on_ellipsoid(&self, _: &Ellipsoid) -> (f64, f64) {
+                (0.0, 0.0)
+            }
+        }
     };
 }
 
-/// Generate a `GeodesicArea` implementation which delegates to the `Polygon`
-/// implementation.
+/// Generate `GeodesicArea` and `GeodesicAreaOnEllipsoid` implementations which delegate to the
+/// `Polygon` implementations.
 macro_rules! to_polygon_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
@@ -285,11 +339,29 @@ macro_rules! to_polygon_impl {
                 self.to_polygon().geodesic_perimeter_area_unsigned()
             }
         }
+
+        impl GeodesicAreaOnEllipsoid<f64> for $type {
+            fn geodesic_perimeter_area_signed_on_ellipsoid(
+                &self,
+                ellipsoid: &Ellipsoid,
+            ) -> (f64, f64) {
+                self.to_polygon()
+                    .geodesic_perimeter_area_signed_on_ellipsoid(ellipsoid)
+            }
+
+            fn geodesic_perimeter_area_unsigned_on_ellipsoid(
+                &self,
+                ellipsoid: &Ellipsoid,
+            ) -> (f64, f64) {
+                self.to_polygon()
+                    .geodesic_perimeter_area_unsigned_on_ellipsoid(ellipsoid)
+            }
+        }
     };
 }
 
-/// Generate a `GeodesicArea` implementation which calculates the area for each of its
-/// sub-components and sums them up.
+/// Generate `GeodesicArea` and `GeodesicAreaOnEllipsoid` implementations which calculate the
+/// area for each of its sub-components and sum them up.
 macro_rules! sum_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
@@ -324,6 +396,32 @@ macro_rules! sum_impl {
                     })
             }
         }
+
+        impl GeodesicAreaOnEllipsoid<f64> for $type {
+            fn geodesic_perimeter_area_signed_on_ellipsoid(
+                &self,
+                ellipsoid: &Ellipsoid,
+            ) -> (f64, f64) {
+                self.iter()
+                    .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
+                        let (perimeter, area) =
+                            next.geodesic_perimeter_area_signed_on_ellipsoid(ellipsoid);
+                        (total_perimeter + perimeter, total_area + area)
+                    })
+            }
+
+            fn geodesic_perimeter_area_unsigned_on_ellipsoid(
+                &self,
+                ellipsoid: &Ellipsoid,
+            ) -> (f64, f64) {
+                self.iter()
+                    .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
+                        let (perimeter, area) =
+                            next.geodesic_perimeter_area_unsigned_on_ellipsoid(ellipsoid);
+                        (total_perimeter + perimeter, total_area + area)
+                    })
+            }
+        }
     };
 }
 
@@ -347,6 +445,13 @@ impl GeodesicArea<f64> for Geometry<f64> {
     }
 }
 
+impl GeodesicAreaOnEllipsoid<f64> for Geometry<f64> {
+    crate::geometry_delegate_impl! {
+        fn geodesic_perimeter_area_signed_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (f64, f64);
+        fn geodesic_perimeter_area_unsigned_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> (f64, f64);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -664,4 +769,31 @@ mod test {
         let area = polygon_large_with_hole.geodesic_area_unsigned();
         assert_relative_eq!(area, 46154562709.8, epsilon = 0.1);
     }
+
+    #[test]
+    fn test_octant_on_sphere() {
+        let octant = polygon![(x: 0., y: 0.), (x: 90., y: 0.), (x: 0., y: 90.)];
+        let radius = 1000.;
+        let (perimeter, area) =
+            octant.geodesic_perimeter_area_unsigned_on_ellipsoid(&Ellipsoid::sphere(radius));
+        assert_relative_eq!(
+            perimeter,
+            1.5 * core::f64::consts::PI * radius,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            area,
+            core::f64::consts::PI * radius * radius / 2.,
+            epsilon = 1e-6
+        );
+
+        let multi_polygon = MultiPolygon::new(vec![octant.clone(), octant]);
+        let (_, area) =
+            multi_polygon.geodesic_perimeter_area_signed_on_ellipsoid(&Ellipsoid::sphere(radius));
+        assert_relative_eq!(
+            area,
+            core::f64::consts::PI * radius * radius,
+            epsilon = 1e-6
+        );
+    }
 }
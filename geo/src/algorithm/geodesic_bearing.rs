@@ -1,6 +1,6 @@
-use crate::Point;
+use crate::{Ellipsoid, Point};
 use geo_types::CoordNum;
-use geographiclib_rs::{Geodesic, InverseGeodesic};
+use geographiclib_rs::InverseGeodesic;
 
 /// Returns the bearing to another Point in degrees on a geodesic.
 ///
@@ -47,15 +47,53 @@ pub trait GeodesicBearing<T: CoordNum> {
     fn geodesic_bearing_distance(&self, point: Point<T>) -> (T, T);
 }
 
+/// Returns the bearing to another Point in degrees on a geodesic of a given ellipsoid, rather
+/// than on [WGS84](Ellipsoid::WGS84) as [`GeodesicBearing`] does.
+pub trait GeodesicBearingOnEllipsoid<T: CoordNum> {
+    /// Returns the bearing to another Point in degrees on the given ellipsoid.
+    fn geodesic_bearing_on_ellipsoid(&self, point: Point<T>, ellipsoid: &Ellipsoid) -> T;
+
+    /// Returns the bearing and distance to another Point in a (bearing, distance) tuple on the
+    /// given ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, zero degrees is north. East is 90°.
+    /// - `distance`: meters
+    fn geodesic_bearing_distance_on_ellipsoid(
+        &self,
+        point: Point<T>,
+        ellipsoid: &Ellipsoid,
+    ) -> (T, T);
+}
+
 impl GeodesicBearing<f64> for Point<f64> {
     fn geodesic_bearing(&self, rhs: Point<f64>) -> f64 {
-        let (azi1, _, _) = Geodesic::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x());
-        azi1
+        self.geodesic_bearing_on_ellipsoid(rhs, &Ellipsoid::WGS84)
     }
 
     fn geodesic_bearing_distance(&self, rhs: Point<f64>) -> (f64, f64) {
+        self.geodesic_bearing_distance_on_ellipsoid(rhs, &Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicBearingOnEllipsoid<f64> for Point<f64> {
+    fn geodesic_bearing_on_ellipsoid(&self, rhs: Point<f64>, ellipsoid: &Ellipsoid) -> f64 {
+        let (azi1, _, _) = ellipsoid
+            .geodesic()
+            .inverse(self.y(), self.x(), rhs.y(), rhs.x());
+        azi1
+    }
+
+    fn geodesic_bearing_distance_on_ellipsoid(
+        &self,
+        rhs: Point<f64>,
+        ellipsoid: &Ellipsoid,
+    ) -> (f64, f64) {
         let (distance, azi1, _, _) =
-            Geodesic::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x());
+            ellipsoid
+                .geodesic()
+                .inverse(self.y(), self.x(), rhs.y(), rhs.x());
         (azi1, distance)
     }
 }
@@ -98,4 +136,16 @@ mod test {
         assert_relative_eq!(bearing, 45., epsilon = 1.0e-11);
         assert_relative_eq!(distance, 10000.0, epsilon = 1.0e-9);
     }
+
+    #[test]
+    fn consistent_with_destination_on_ellipsoid() {
+        use crate::algorithm::GeodesicDestinationOnEllipsoid;
+        let ellipsoid = Ellipsoid::new(6_000_000., 0.01);
+        let p_1 = point!(x: 9.177789688110352, y: 48.776781529534965);
+        let p_2 = p_1.geodesic_destination_on_ellipsoid(45., 10000., &ellipsoid);
+        let (bearing, distance) = p_1.geodesic_bearing_distance_on_ellipsoid(p_2, &ellipsoid);
+        assert_relative_eq!(bearing, 45., epsilon = 1.0e-11);
+        assert_relative_eq!(distance, 10000.0, epsilon = 1.0e-8);
+        assert!((p_1.geodesic_bearing_distance(p_2).1 - distance).abs() > 1.);
+    }
 }
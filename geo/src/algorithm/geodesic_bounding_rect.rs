@@ -0,0 +1,362 @@
+use crate::angles::normalize_longitude;
+use crate::{
+    coord, Coord, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+};
+use alloc::{vec, vec::Vec};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+
+/// Calculation of the bounding rectangle of a geometry on an ellipsoidal model of the earth,
+/// whose edges are geodesics.
+///
+/// Coordinates are longitude (`x`) and latitude (`y`) in degrees. Unlike
+/// [`BoundingRect`](crate::BoundingRect), this accounts for:
+///
+/// - geodesics reaching latitudes further from the equator than their ends,
+/// - polygons enclosing a pole, which extend to that pole and cover all longitudes, and
+/// - geometries crossing the antimeridian. The longitudes of their bounds are a continuous range
+///   which is as narrow as possible, so the maximum longitude may be greater than 180°.
+///
+/// Polygons are taken to enclose a pole if their exterior winds around it, and the pole is the
+/// one whose hemisphere contains most of the exterior.
+///
+/// This uses the geodesic methods given by [Karney (2013)].
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicBoundingRect {
+    type Output: Into<Option<Rect>>;
+
+    /// Return the geodesic bounding rectangle of a geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{line_string, GeodesicBoundingRect};
+    ///
+    /// let line_string = line_string![(x: 170., y: 50.), (x: -170., y: 50.)];
+    ///
+    /// let bounding_rect = line_string.geodesic_bounding_rect().unwrap();
+    ///
+    /// // Crossing the antimeridian
+    /// assert_eq!(bounding_rect.min().x, 170.);
+    /// assert_eq!(bounding_rect.max().x, 190.);
+    ///
+    /// // The geodesic bulges north of its ends
+    /// assert_eq!(bounding_rect.min().y, 50.);
+    /// assert_relative_eq!(bounding_rect.max().y, 50.43, epsilon = 0.01);
+    /// ```
+    fn geodesic_bounding_rect(&self) -> Self::Output;
+}
+
+impl GeodesicBoundingRect for Point {
+    type Output = Rect;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        Rect::new(self.0, self.0)
+    }
+}
+
+impl GeodesicBoundingRect for Line {
+    type Output = Rect;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        bounds.add_path(&[self.start, self.end]);
+        bounds.rect().expect("a line has coordinates")
+    }
+}
+
+impl GeodesicBoundingRect for MultiPoint {
+    type Output = Option<Rect>;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        for point in self {
+            bounds.add_path(&[point.0]);
+        }
+        bounds.rect()
+    }
+}
+
+impl GeodesicBoundingRect for LineString {
+    type Output = Option<Rect>;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        bounds.add_path(&self.0);
+        bounds.rect()
+    }
+}
+
+impl GeodesicBoundingRect for MultiLineString {
+    type Output = Option<Rect>;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        for line_string in self {
+            bounds.add_path(&line_string.0);
+        }
+        bounds.rect()
+    }
+}
+
+impl GeodesicBoundingRect for Polygon {
+    type Output = Option<Rect>;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        bounds.add_polygon(self);
+        bounds.rect()
+    }
+}
+
+impl GeodesicBoundingRect for MultiPolygon {
+    type Output = Option<Rect>;
+
+    fn geodesic_bounding_rect(&self) -> Self::Output {
+        let mut bounds = Bounds::new();
+        for polygon in self {
+            bounds.add_polygon(polygon);
+        }
+        bounds.rect()
+    }
+}
+
+/// The latitude range, and the longitude ranges, covered by the parts of a geometry.
+struct Bounds {
+    geodesic: Geodesic,
+    min_latitude: f64,
+    max_latitude: f64,
+    /// Continuous ranges of longitude, whose ends may be outside `[-180, 180]`.
+    longitudes: Vec<(f64, f64)>,
+}
+
+impl Bounds {
+    fn new() -> Self {
+        Bounds {
+            geodesic: Geodesic::wgs84(),
+            min_latitude: f64::INFINITY,
+            max_latitude: f64::NEG_INFINITY,
+            longitudes: vec![],
+        }
+    }
+
+    /// Add the vertices of `coords` and the geodesics between them, returning the net change in
+    /// longitude from the first coordinate to the last.
+    fn add_path(&mut self, coords: &[Coord]) -> f64 {
+        let first = match coords.first() {
+            Some(first) => *first,
+            None => return 0.,
+        };
+        let mut longitude = first.x;
+        let (mut min_longitude, mut max_longitude) = (longitude, longitude);
+        self.add_latitude(first.y);
+        for pair in coords.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            self.add_latitude(end.y);
+            if let Some(latitude) = self.vertex_latitude(start, end) {
+                self.add_latitude(latitude);
+            }
+            longitude += normalize_longitude(end.x - start.x);
+            min_longitude = min_longitude.min(longitude);
+            max_longitude = max_longitude.max(longitude);
+        }
+        self.longitudes.push((min_longitude, max_longitude));
+        longitude - first.x
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon) {
+        let winding = self.add_path(&polygon.exterior().0);
+        if winding.abs() > 180. {
+            let latitude_sum: f64 = polygon.exterior().0.iter().map(|coord| coord.y).sum();
+            self.add_latitude(if latitude_sum >= 0. { 90. } else { -90. });
+            self.longitudes.push((-180., 180.));
+        }
+        // Interiors can't extend past the exterior
+    }
+
+    fn add_latitude(&mut self, latitude: f64) {
+        self.min_latitude = self.min_latitude.min(latitude);
+        self.max_latitude = self.max_latitude.max(latitude);
+    }
+
+    /// The latitude of the point furthest from the equator on the geodesic from `start` to
+    /// `end`, if it's between them.
+    fn vertex_latitude(&self, start: Coord, end: Coord) -> Option<f64> {
+        let (_, azimuth_start, azimuth_end, _): (f64, f64, f64, f64) =
+            self.geodesic.inverse(start.y, start.x, end.y, end.x);
+        let (heading_start, heading_end) = (
+            azimuth_start.to_radians().cos(),
+            azimuth_end.to_radians().cos(),
+        );
+        // The geodesic only turns back towards the equator at its vertex.
+        let sign = if heading_start > 0. && heading_end < 0. {
+            1.
+        } else if heading_start < 0. && heading_end > 0. {
+            -1.
+        } else {
+            return None;
+        };
+
+        // By Clairaut's relation, the cosine of the reduced latitude times the sine of the
+        // azimuth is constant along a geodesic, and the azimuth at its vertex is ±90°.
+        let flattening = self.geodesic.flattening();
+        let reduced_latitude = ((1. - flattening) * start.y.to_radians().tan()).atan();
+        let clairaut = (reduced_latitude.cos() * azimuth_start.to_radians().sin()).abs();
+        let vertex_reduced_latitude = clairaut.min(1.).acos();
+        let vertex_latitude = (vertex_reduced_latitude.tan() / (1. - flattening)).atan();
+        Some(sign * vertex_latitude.to_degrees())
+    }
+
+    /// The smallest longitude range containing all `longitudes`, on the circle.
+    fn rect(&self) -> Option<Rect> {
+        if self.longitudes.is_empty() {
+            return None;
+        }
+        let mut ranges: Vec<(f64, f64)> = vec![];
+        for &(min, max) in &self.longitudes {
+            if max - min >= 360. {
+                return Some(self.rect_from(-180., 180.));
+            }
+            let start = normalize_longitude(min);
+            let end = start + (max - min);
+            // Split ranges past the antimeridian, so they merge with those across it
+            if end > 180. {
+                ranges.push((start, 180.));
+                ranges.push((-180., end - 360.));
+            } else {
+                ranges.push((start, end));
+            }
+        }
+        ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut merged: Vec<(f64, f64)> = vec![];
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        // The bounds are the complement of the largest gap between the ranges.
+        let (first, last) = (merged[0], merged[merged.len() - 1]);
+        let mut largest_gap = first.0 + 360. - last.1;
+        let (mut min, mut max) = (first.0, last.1);
+        for pair in merged.windows(2) {
+            let gap = pair[1].0 - pair[0].1;
+            if gap > largest_gap {
+                largest_gap = gap;
+                min = pair[1].0;
+                max = pair[0].1 + 360.;
+            }
+        }
+        if largest_gap <= 0. {
+            return Some(self.rect_from(-180., 180.));
+        }
+        Some(self.rect_from(min, max))
+    }
+
+    fn rect_from(&self, min_longitude: f64, max_longitude: f64) -> Rect {
+        Rect::new(
+            coord! { x: min_longitude, y: self.min_latitude },
+            coord! { x: max_longitude, y: self.max_latitude },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, BoundingRect};
+    use geographiclib_rs::DirectGeodesic;
+
+    /// The largest latitude reached by points sampled along the geodesic.
+    fn sampled_max_latitude(start: Point, end: Point) -> f64 {
+        let geodesic = Geodesic::wgs84();
+        let (distance, azimuth, _, _): (f64, f64, f64, f64) =
+            geodesic.inverse(start.y(), start.x(), end.y(), end.x());
+        (0..=10_000)
+            .map(|step| {
+                let (latitude, _) =
+                    geodesic.direct(start.y(), start.x(), azimuth, distance * step as f64 / 1e4);
+                latitude
+            })
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[test]
+    fn geodesic_bulges_past_vertices() {
+        let line = Line::new(coord! { x: -60., y: 40. }, coord! { x: 60., y: 45. });
+        let planar = line.bounding_rect();
+        let bounds = line.geodesic_bounding_rect();
+        assert_eq!(bounds.min(), planar.min());
+        assert_eq!(bounds.max().x, planar.max().x);
+        assert!(bounds.max().y > 60.);
+        assert_relative_eq!(
+            bounds.max().y,
+            sampled_max_latitude(line.start_point(), line.end_point()),
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn southern_vertex() {
+        let line_string = line_string![(x: 100., y: -30.), (x: 160., y: -30.)];
+        let bounds = line_string.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds.max().y, -30.);
+        assert!(bounds.min().y < -33.);
+    }
+
+    #[test]
+    fn meridians_and_parallels_of_equator() {
+        let line_string = line_string![(x: 10., y: -20.), (x: 10., y: 20.), (x: 30., y: 0.)];
+        let bounds = line_string.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds, line_string.bounding_rect().unwrap());
+    }
+
+    #[test]
+    fn polygon_enclosing_pole() {
+        let polygon = polygon![
+            (x: -120., y: -70.),
+            (x: 0., y: -70.),
+            (x: 120., y: -70.),
+        ];
+        let bounds = polygon.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds.min(), coord! { x: -180., y: -90. });
+        assert_eq!(bounds.max(), coord! { x: 180., y: -70. });
+    }
+
+    #[test]
+    fn multi_polygon_across_antimeridian() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 170., y: 0.), (x: 180., y: 0.), (x: 180., y: 1.)],
+            polygon![(x: -180., y: 0.), (x: -175., y: 0.), (x: -180., y: 1.)],
+        ]);
+        let bounds = multi_polygon.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds.min().x, 170.);
+        assert_eq!(bounds.max().x, 185.);
+    }
+
+    #[test]
+    fn line_across_antimeridian_and_line_past_it() {
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 170., y: 0.), (x: -170., y: 0.)],
+            line_string![(x: -175., y: 1.), (x: -172., y: 1.)],
+        ]);
+        let bounds = multi_line_string.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds.min().x, 170.);
+        assert_eq!(bounds.max().x, 190.);
+    }
+
+    #[test]
+    fn points() {
+        let multi_point = MultiPoint::new(vec![point!(x: -170., y: 1.), point!(x: 175., y: -2.)]);
+        let bounds = multi_point.geodesic_bounding_rect().unwrap();
+        assert_eq!(bounds.min(), coord! { x: 175., y: -2. });
+        assert_eq!(bounds.max(), coord! { x: 190., y: 1. });
+
+        let point = point!(x: 1., y: 2.);
+        assert_eq!(point.geodesic_bounding_rect(), point.bounding_rect());
+        assert!(MultiPoint::new(vec![]).geodesic_bounding_rect().is_none());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::algorithm::geodesic_cross_track::closest_point;
+use crate::{Closest, Contains, Ellipsoid, GeodesicDistance, GeodesicDistanceOnEllipsoid};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use geographiclib_rs::InverseGeodesic;
+
+/// A point this many meters or less from the query point is considered to be at it.
+const INTERSECTION_TOLERANCE: f64 = 1e-6;
+
+/// Calculates the closest `Point` on a geometry to a given `Point`, on an ellipsoidal model of
+/// the earth, treating the edges of the geometry as geodesics.
+///
+/// Similar to [`HaversineClosestPoint`](crate::HaversineClosestPoint), but without the error
+/// of up to about 0.5% of a spherical model, which over segments hundreds of kilometers
+/// long amounts to hundreds of meters. Coordinates are longitude (x) and latitude (y), in
+/// degrees.
+///
+/// The closest point on each edge is found by iteratively intercepting the geodesic through
+/// it, with the method of [Baselga & Martínez‑Llario (2018)], as for
+/// [`GeodesicCrossTrack`](crate::GeodesicCrossTrack), and then limiting it to the edge's end
+/// points.
+///
+/// See [`Closest<F>`] for a description of the return states. A point within a micrometer of
+/// the geometry, or inside a polygon, is an `Intersection`. As for `HaversineClosestPoint`,
+/// whether a point is inside a polygon is determined in the plane of the coordinates.
+///
+/// [Baselga & Martínez‑Llario (2018)]: https://doi.org/10.1007/s11200-017-1020-z
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{Closest, GeodesicClosestPoint, GeodesicDistance, HaversineClosestPoint};
+/// use geo::{Line, Point};
+///
+/// // A flight from Paris to New York City, and a ship in the North Atlantic
+/// let route = Line::new((2.5479, 49.0097), (-73.7781, 40.6413));
+/// let ship = Point::new(-30., 55.);
+///
+/// let Closest::SinglePoint(closest) = route.geodesic_closest_point(&ship) else {
+///     panic!("Closest::SinglePoint expected");
+/// };
+/// assert_relative_eq!(ship.geodesic_distance(&closest), ship.geodesic_distance(&route));
+///
+/// // The spherical model is more than a kilometer out
+/// let Closest::SinglePoint(spherical) = route.haversine_closest_point(&ship) else {
+///     panic!("Closest::SinglePoint expected");
+/// };
+/// assert!(closest.geodesic_distance(&spherical) > 1_000.);
+/// ```
+pub trait GeodesicClosestPoint {
+    /// The point on this geometry closest to `from`, on the [WGS84](Ellipsoid::WGS84)
+    /// ellipsoid.
+    fn geodesic_closest_point(&self, from: &Point) -> Closest<f64> {
+        self.geodesic_closest_point_on_ellipsoid(from, &Ellipsoid::WGS84)
+    }
+
+    /// The point on this geometry closest to `from`, on the given ellipsoid.
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64>;
+}
+
+impl<G: GeodesicClosestPoint> GeodesicClosestPoint for &G {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        (*self).geodesic_closest_point_on_ellipsoid(from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for Point {
+    fn geodesic_closest_point_on_ellipsoid(&self, from: &Point, _: &Ellipsoid) -> Closest<f64> {
+        if self == from {
+            Closest::Intersection(*self)
+        } else {
+            Closest::SinglePoint(*self)
+        }
+    }
+}
+
+impl GeodesicClosestPoint for Coord {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        Point::from(*self).geodesic_closest_point_on_ellipsoid(from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for Line {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        let (start, end) = (self.start_point(), self.end_point());
+        if start == *from || end == *from {
+            return Closest::Intersection(*from);
+        }
+
+        let geodesic = ellipsoid.geodesic();
+        let length: f64 = geodesic.inverse(start.y(), start.x(), end.y(), end.x());
+        let closest = if length == 0. {
+            start
+        } else {
+            let (along_track, closest) =
+                closest_point(&geodesic, ellipsoid.semi_major_axis, *from, start, end);
+            if along_track <= 0. {
+                start
+            } else if along_track >= length {
+                end
+            } else {
+                closest
+            }
+        };
+
+        let distance: f64 = geodesic.inverse(closest.y(), closest.x(), from.y(), from.x());
+        if distance <= INTERSECTION_TOLERANCE {
+            Closest::Intersection(*from)
+        } else {
+            Closest::SinglePoint(closest)
+        }
+    }
+}
+
+impl GeodesicClosestPoint for LineString {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        match self.0.as_slice() {
+            [] => Closest::Indeterminate,
+            [coord] => coord.geodesic_closest_point_on_ellipsoid(from, ellipsoid),
+            _ => closest_of(self.lines(), from, ellipsoid),
+        }
+    }
+}
+
+impl GeodesicClosestPoint for Polygon {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        if self.contains(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(
+            core::iter::once(self.exterior()).chain(self.interiors()),
+            from,
+            ellipsoid,
+        )
+    }
+}
+
+impl GeodesicClosestPoint for Rect {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        if self.contains(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(self.to_lines(), from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for Triangle {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        if self.contains(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(self.to_lines(), from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for MultiPoint {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        closest_of(self, from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for MultiLineString {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        closest_of(self, from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for MultiPolygon {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        closest_of(self, from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for GeometryCollection {
+    fn geodesic_closest_point_on_ellipsoid(
+        &self,
+        from: &Point,
+        ellipsoid: &Ellipsoid,
+    ) -> Closest<f64> {
+        closest_of(self, from, ellipsoid)
+    }
+}
+
+impl GeodesicClosestPoint for Geometry {
+    crate::geometry_delegate_impl! {
+        fn geodesic_closest_point_on_ellipsoid(&self, from: &Point, ellipsoid: &Ellipsoid) -> Closest<f64>;
+    }
+}
+
+/// The closest of the closest points of `parts`, which is indeterminate if any of them is, or
+/// there aren't any.
+fn closest_of<G, I>(parts: I, from: &Point, ellipsoid: &Ellipsoid) -> Closest<f64>
+where
+    G: GeodesicClosestPoint,
+    I: IntoIterator<Item = G>,
+{
+    let mut min_distance = f64::INFINITY;
+    let mut closest = Closest::Indeterminate;
+    for part in parts {
+        match part.geodesic_closest_point_on_ellipsoid(from, ellipsoid) {
+            intersection @ Closest::Intersection(_) => return intersection,
+            Closest::SinglePoint(point) => {
+                let distance = from.geodesic_distance_on_ellipsoid(&point, ellipsoid);
+                if distance < min_distance {
+                    min_distance = distance;
+                    closest = Closest::SinglePoint(point);
+                }
+            }
+            Closest::Indeterminate => return Closest::Indeterminate,
+        }
+    }
+    closest
+}
+
+/// The distance from `point` to its closest point on `geometry`, which is zero if they
+/// intersect, or if `geometry` is empty, as for [`EuclideanDistance`](crate::EuclideanDistance).
+fn distance_to<G: GeodesicClosestPoint>(point: &Point, geometry: &G, ellipsoid: &Ellipsoid) -> f64 {
+    match geometry.geodesic_closest_point_on_ellipsoid(point, ellipsoid) {
+        Closest::SinglePoint(closest) => point.geodesic_distance_on_ellipsoid(&closest, ellipsoid),
+        Closest::Intersection(_) | Closest::Indeterminate => 0.,
+    }
+}
+
+macro_rules! impl_geodesic_distance_to_point {
+    ($type:ident) => {
+        impl GeodesicDistance<f64, $type> for Point {
+            /// The distance from the point to the closest point of the geodesic segments.
+            fn geodesic_distance(&self, rhs: &$type) -> f64 {
+                distance_to(self, rhs, &Ellipsoid::WGS84)
+            }
+        }
+
+        impl GeodesicDistanceOnEllipsoid<f64, $type> for Point {
+            fn geodesic_distance_on_ellipsoid(&self, rhs: &$type, ellipsoid: &Ellipsoid) -> f64 {
+                distance_to(self, rhs, ellipsoid)
+            }
+        }
+
+        impl GeodesicDistance<f64, Point> for $type {
+            fn geodesic_distance(&self, rhs: &Point) -> f64 {
+                distance_to(rhs, self, &Ellipsoid::WGS84)
+            }
+        }
+
+        impl GeodesicDistanceOnEllipsoid<f64, Point> for $type {
+            fn geodesic_distance_on_ellipsoid(&self, rhs: &Point, ellipsoid: &Ellipsoid) -> f64 {
+                distance_to(rhs, self, ellipsoid)
+            }
+        }
+    };
+}
+
+impl_geodesic_distance_to_point!(Line);
+impl_geodesic_distance_to_point!(LineString);
+impl_geodesic_distance_to_point!(MultiLineString);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, GeodesicCrossTrack, HaversineClosestPoint};
+
+    fn single_point(closest: Closest<f64>) -> Point {
+        match closest {
+            Closest::SinglePoint(point) => point,
+            closest => panic!("Closest::SinglePoint expected, not {closest:?}"),
+        }
+    }
+
+    #[test]
+    fn line_interior() {
+        let line = Line::new((-80.1918, 25.7617), (-120.7401, 47.7511));
+        let p = Point::new(-74.006, 40.7128);
+        let closest = single_point(line.geodesic_closest_point(&p));
+
+        let along_track = p.geodesic_along_track_point(&line.start_point(), &line.end_point());
+        assert_relative_eq!(closest, along_track, epsilon = 1e-9);
+        assert_relative_eq!(
+            p.geodesic_distance(&line),
+            p.geodesic_cross_track_distance(&line.start_point(), &line.end_point()),
+            epsilon = 1e-6
+        );
+        assert_eq!(line.geodesic_distance(&p), p.geodesic_distance(&line));
+
+        // Close to, but measurably different from, the spherical closest point
+        let spherical = single_point(line.haversine_closest_point(&p));
+        let offset = closest.geodesic_distance(&spherical);
+        assert!(offset > 100. && offset < 20_000., "{offset}");
+    }
+
+    #[test]
+    fn line_end_points() {
+        let line = Line::new((0., 0.), (10., 0.));
+        assert_eq!(
+            single_point(line.geodesic_closest_point(&Point::new(-5., 3.))),
+            line.start_point()
+        );
+        assert_eq!(
+            single_point(line.geodesic_closest_point(&Point::new(12., -1.))),
+            line.end_point()
+        );
+        assert_eq!(
+            line.geodesic_closest_point(&line.end_point()),
+            Closest::Intersection(line.end_point())
+        );
+        let on_line = Point::new(4., 0.);
+        assert_eq!(
+            line.geodesic_closest_point(&on_line),
+            Closest::Intersection(on_line)
+        );
+
+        let degenerate = Line::new((1., 1.), (1., 1.));
+        assert_eq!(
+            single_point(degenerate.geodesic_closest_point(&Point::new(2., 2.))),
+            Point::new(1., 1.)
+        );
+    }
+
+    #[test]
+    fn line_string_and_polygon() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let p = Point::new(12., 5.);
+        let closest = single_point(line_string.geodesic_closest_point(&p));
+        assert_relative_eq!(closest.x(), 10., epsilon = 1e-9);
+        assert_relative_eq!(closest.y(), 5., epsilon = 0.1);
+        assert_relative_eq!(
+            p.geodesic_distance(&line_string),
+            p.geodesic_distance(&closest),
+            epsilon = 1e-6
+        );
+
+        assert_eq!(
+            LineString::new(vec![]).geodesic_closest_point(&p),
+            Closest::Indeterminate
+        );
+        assert_eq!(p.geodesic_distance(&LineString::new(vec![])), 0.);
+
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(
+            polygon.geodesic_closest_point(&Point::new(5., 5.)),
+            Closest::Intersection(Point::new(5., 5.))
+        );
+        assert_relative_eq!(
+            single_point(polygon.geodesic_closest_point(&p)),
+            closest,
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            Geometry::Polygon(polygon.clone()).geodesic_closest_point(&p),
+            polygon.geodesic_closest_point(&p)
+        );
+    }
+
+    #[test]
+    fn other_ellipsoids() {
+        let line = Line::new((0., 0.), (0., 10.));
+        let p = Point::new(1., 5.);
+        // On a sphere, the foot of the great circle through `p` perpendicular to a meridian
+        let foot = Point::new(
+            0.,
+            (5_f64.to_radians().tan() / 1_f64.to_radians().cos())
+                .atan()
+                .to_degrees(),
+        );
+        for ellipsoid in [Ellipsoid::SPHERE, Ellipsoid::sphere(1_000.)] {
+            let closest = single_point(line.geodesic_closest_point_on_ellipsoid(&p, &ellipsoid));
+            assert_relative_eq!(closest, foot, epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{CoordNum, Point, MEAN_EARTH_RADIUS};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+
+/// The along track distance converges to well below this many meters.
+const TOLERANCE: f64 = 1e-9;
+
+/// In practice the solution converges within a few iterations.
+const MAX_ITERATIONS: usize = 32;
+
+/// Determine the cross track and along track distances between a point and a geodesic path,
+/// on an ellipsoidal model of the earth.
+///
+/// The point closest to `self` on the geodesic is found with the iterative method of
+/// [Baselga & Martínez‑Llario (2018)], using the geodesic methods given by [Karney (2013)].
+///
+/// [Baselga & Martínez‑Llario (2018)]: https://doi.org/10.1007/s11200-017-1020-z
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicCrossTrack<T: CoordNum, Rhs = Self> {
+    /// Determine the shortest distance between this point and the geodesic which passes through
+    /// `line_point_a` and `line_point_b`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p = point!(x: -0.7972, y: 53.2611);
+    /// let line_point_a = point!(x: -1.7297, y: 53.3206);
+    /// let line_point_b = point!(x: 0.1334, y: 53.1887);
+    ///
+    /// let distance = p.geodesic_cross_track_distance(&line_point_a, &line_point_b);
+    ///
+    /// assert_eq!(
+    ///     307., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    fn geodesic_cross_track_distance(&self, line_point_a: &Rhs, line_point_b: &Rhs) -> T;
+
+    /// Determine the distance along the geodesic which passes through `line_point_a` and
+    /// `line_point_b`, from `line_point_a` to the point on it closest to this point.
+    ///
+    /// The distance is negative if the closest point lies behind `line_point_a`, when travelling
+    /// towards `line_point_b`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p = point!(x: -0.7972, y: 53.2611);
+    /// let line_point_a = point!(x: -1.7297, y: 53.3206);
+    /// let line_point_b = point!(x: 0.1334, y: 53.1887);
+    ///
+    /// let distance = p.geodesic_along_track_distance(&line_point_a, &line_point_b);
+    ///
+    /// assert_eq!(
+    ///     62_534., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    fn geodesic_along_track_distance(&self, line_point_a: &Rhs, line_point_b: &Rhs) -> T;
+
+    /// Determine the point closest to this point on the geodesic which passes through
+    /// `line_point_a` and `line_point_b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use approx::assert_relative_eq;
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p = point!(x: 10., y: 5.);
+    /// let line_point_a = point!(x: 0., y: 0.);
+    /// let line_point_b = point!(x: 20., y: 0.);
+    ///
+    /// let closest = p.geodesic_along_track_point(&line_point_a, &line_point_b);
+    ///
+    /// assert_relative_eq!(closest, point!(x: 10., y: 0.), epsilon = 1e-9);
+    /// ```
+    fn geodesic_along_track_point(&self, line_point_a: &Rhs, line_point_b: &Rhs) -> Point<T>;
+}
+
+impl GeodesicCrossTrack<f64> for Point {
+    fn geodesic_cross_track_distance(&self, line_point_a: &Point, line_point_b: &Point) -> f64 {
+        let closest = self.geodesic_along_track_point(line_point_a, line_point_b);
+        Geodesic::wgs84().inverse(closest.y(), closest.x(), self.y(), self.x())
+    }
+
+    fn geodesic_along_track_distance(&self, line_point_a: &Point, line_point_b: &Point) -> f64 {
+        closest_point(
+            &Geodesic::wgs84(),
+            MEAN_EARTH_RADIUS,
+            *self,
+            *line_point_a,
+            *line_point_b,
+        )
+        .0
+    }
+
+    fn geodesic_along_track_point(&self, line_point_a: &Point, line_point_b: &Point) -> Point {
+        closest_point(
+            &Geodesic::wgs84(),
+            MEAN_EARTH_RADIUS,
+            *self,
+            *line_point_a,
+            *line_point_b,
+        )
+        .1
+    }
+}
+
+/// The along track distance from `a`, and the position, of the point closest to `p` on the
+/// `geodesic` through `a` and `b`, each step estimated on a sphere of the given `radius`.
+pub(crate) fn closest_point(
+    geodesic: &Geodesic,
+    radius: f64,
+    p: Point,
+    a: Point,
+    b: Point,
+) -> (f64, Point) {
+    let (_, azimuth, _, _): (f64, f64, f64, f64) = geodesic.inverse(a.y(), a.x(), b.y(), b.x());
+
+    let mut along_track = 0.;
+    let mut closest = a;
+    let mut closest_azimuth = azimuth;
+    for _ in 0..MAX_ITERATIONS {
+        let (distance, azimuth_to_p, _, _): (f64, f64, f64, f64) =
+            geodesic.inverse(closest.y(), closest.x(), p.y(), p.x());
+        if distance == 0. {
+            break;
+        }
+
+        // Solve the right spherical triangle formed by `closest`, `p`, and the foot of the
+        // perpendicular from `p`, to estimate the remaining distance along the geodesic.
+        let angle = (azimuth_to_p - closest_azimuth).to_radians();
+        let arc = distance / radius;
+        let step = radius * (arc.sin() * angle.cos()).atan2(arc.cos());
+
+        along_track += step;
+        let (lat, lon, azimuth_at_closest) = geodesic.direct(a.y(), a.x(), azimuth, along_track);
+        closest = Point::new(lon, lat);
+        closest_azimuth = azimuth_at_closest;
+
+        if step.abs() < TOLERANCE {
+            break;
+        }
+    }
+    (along_track, closest)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AlongTrackDistance, CrossTrackDistance, GeodesicDistance};
+
+    #[test]
+    fn close_to_spherical() {
+        let p = Point::new(-0.7972, 53.2611);
+        let line_point_a = Point::new(-1.7297, 53.3206);
+        let line_point_b = Point::new(0.1334, 53.1887);
+
+        let cross_track = p.geodesic_cross_track_distance(&line_point_a, &line_point_b);
+        let along_track = p.geodesic_along_track_distance(&line_point_a, &line_point_b);
+        let spherical_cross_track = p.cross_track_distance(&line_point_a, &line_point_b);
+        let spherical_along_track = p.along_track_distance(&line_point_a, &line_point_b);
+
+        assert_relative_eq!(cross_track, spherical_cross_track, max_relative = 0.01);
+        assert_relative_eq!(along_track, spherical_along_track, max_relative = 0.01);
+    }
+
+    #[test]
+    fn closest_point_is_perpendicular() {
+        let p = Point::new(-74.006, 40.7128);
+        let line_point_a = Point::new(-80.1918, 25.7617);
+        let line_point_b = Point::new(-120.7401, 47.7511);
+
+        let closest = p.geodesic_along_track_point(&line_point_a, &line_point_b);
+        let cross_track = p.geodesic_cross_track_distance(&line_point_a, &line_point_b);
+        assert_relative_eq!(closest.geodesic_distance(&p), cross_track, epsilon = 1e-6);
+
+        // Moving along the geodesic in either direction increases the distance to `p`.
+        let geodesic = Geodesic::wgs84();
+        let (_, azimuth, _, _): (f64, f64, f64, f64) = geodesic.inverse(
+            line_point_a.y(),
+            line_point_a.x(),
+            line_point_b.y(),
+            line_point_b.x(),
+        );
+        let along_track = p.geodesic_along_track_distance(&line_point_a, &line_point_b);
+        for offset in [-1., 1.] {
+            let (lat, lon) = geodesic.direct(
+                line_point_a.y(),
+                line_point_a.x(),
+                azimuth,
+                along_track + offset,
+            );
+            assert!(Point::new(lon, lat).geodesic_distance(&p) > cross_track);
+        }
+        assert_relative_eq!(
+            closest.geodesic_distance(&line_point_a),
+            along_track,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn point_behind_start() {
+        let p = Point::new(-5., 3.);
+        let line_point_a = Point::new(0., 0.);
+        let line_point_b = Point::new(10., 0.);
+
+        let along_track = p.geodesic_along_track_distance(&line_point_a, &line_point_b);
+        assert_relative_eq!(
+            along_track,
+            -Point::new(-5., 0.).geodesic_distance(&line_point_a),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            p.geodesic_along_track_point(&line_point_a, &line_point_b),
+            Point::new(-5., 0.),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn point_on_geodesic() {
+        let line_point_a = Point::new(0., 0.);
+        let line_point_b = Point::new(10., 10.);
+        assert_eq!(
+            line_point_a.geodesic_cross_track_distance(&line_point_a, &line_point_b),
+            0.
+        );
+        assert_eq!(
+            line_point_a.geodesic_along_track_distance(&line_point_a, &line_point_b),
+            0.
+        );
+    }
+}
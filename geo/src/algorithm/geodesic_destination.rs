@@ -1,6 +1,6 @@
-use crate::Point;
+use crate::{Ellipsoid, Point};
 use geo_types::CoordNum;
-use geographiclib_rs::{DirectGeodesic, Geodesic};
+use geographiclib_rs::DirectGeodesic;
 
 /// Returns a new Point using the distance to the existing Point and a bearing for the direction on a geodesic.
 ///
@@ -34,9 +34,41 @@ pub trait GeodesicDestination<T: CoordNum> {
     fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T>;
 }
 
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction on
+/// a geodesic of a given ellipsoid, rather than on [WGS84](Ellipsoid::WGS84) as
+/// [`GeodesicDestination`] does.
+pub trait GeodesicDestinationOnEllipsoid<T: CoordNum> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction,
+    /// on the given ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, zero degrees is north
+    /// - `distance`: meters
+    fn geodesic_destination_on_ellipsoid(
+        &self,
+        bearing: T,
+        distance: T,
+        ellipsoid: &Ellipsoid,
+    ) -> Point<T>;
+}
+
 impl GeodesicDestination<f64> for Point<f64> {
     fn geodesic_destination(&self, bearing: f64, distance: f64) -> Point<f64> {
-        let (lat, lon) = Geodesic::wgs84().direct(self.y(), self.x(), bearing, distance);
+        self.geodesic_destination_on_ellipsoid(bearing, distance, &Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicDestinationOnEllipsoid<f64> for Point<f64> {
+    fn geodesic_destination_on_ellipsoid(
+        &self,
+        bearing: f64,
+        distance: f64,
+        ellipsoid: &Ellipsoid,
+    ) -> Point<f64> {
+        let (lat, lon) = ellipsoid
+            .geodesic()
+            .direct(self.y(), self.x(), bearing, distance);
         Point::new(lon, lat)
     }
 }
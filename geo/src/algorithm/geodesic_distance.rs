@@ -1,5 +1,5 @@
-use crate::Point;
-use geographiclib_rs::{Geodesic, InverseGeodesic};
+use crate::{Ellipsoid, Point};
+use geographiclib_rs::InverseGeodesic;
 
 /// Determine the distance between two geometries on an ellipsoidal model of the earth.
 ///
@@ -39,8 +39,43 @@ pub trait GeodesicDistance<T, Rhs = Self> {
     fn geodesic_distance(&self, rhs: &Rhs) -> T;
 }
 
+/// Determine the distance between two geometries on a given ellipsoid, rather than on
+/// [WGS84](Ellipsoid::WGS84) as [`GeodesicDistance`] does.
+pub trait GeodesicDistanceOnEllipsoid<T, Rhs = Self> {
+    /// Determine the distance between two geometries on the given ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    /// ```rust
+    /// use geo::prelude::*;
+    /// use geo::{point, Ellipsoid, GeodesicDistanceOnEllipsoid};
+    ///
+    /// let p1 = point!(x: -74.006, y: 40.7128);
+    /// let p2 = point!(x: -0.1278, y: 51.5074);
+    ///
+    /// let distance = p1.geodesic_distance_on_ellipsoid(&p2, &Ellipsoid::SPHERE);
+    ///
+    /// assert_eq!(
+    ///     5_570_230., // meters
+    ///     distance.round()
+    /// );
+    /// ```
+    fn geodesic_distance_on_ellipsoid(&self, rhs: &Rhs, ellipsoid: &Ellipsoid) -> T;
+}
+
 impl GeodesicDistance<f64> for Point {
     fn geodesic_distance(&self, rhs: &Point) -> f64 {
-        Geodesic::wgs84().inverse(self.y(), self.x(), rhs.y(), rhs.x())
+        self.geodesic_distance_on_ellipsoid(rhs, &Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicDistanceOnEllipsoid<f64> for Point {
+    fn geodesic_distance_on_ellipsoid(&self, rhs: &Point, ellipsoid: &Ellipsoid) -> f64 {
+        ellipsoid
+            .geodesic()
+            .inverse(self.y(), self.x(), rhs.y(), rhs.x())
     }
 }
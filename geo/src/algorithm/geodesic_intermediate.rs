@@ -1,4 +1,5 @@
 use crate::{CoordFloat, Point};
+use alloc::vec::Vec;
 use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
 
 /// Returns a new Point along a route between two existing points on an ellipsoidal model of the earth
@@ -33,6 +34,50 @@ pub trait GeodesicIntermediate<T: CoordFloat> {
         max_dist: T,
         include_ends: bool,
     ) -> Vec<Point<T>>;
+
+    /// Returns `n` points evenly spaced along the geodesic between two existing points, which
+    /// divide it into `n + 1` segments of equal length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{GeodesicDistance, GeodesicIntermediate};
+    /// use geo::Point;
+    ///
+    /// let p1 = Point::new(10.0, 20.0);
+    /// let p2 = Point::new(125.0, 25.0);
+    /// let route = p1.geodesic_intermediate_points(&p2, 4, true);
+    /// assert_eq!(route.len(), 6);
+    /// assert_relative_eq!(route[1], Point::new(29.842907, 29.951445), epsilon = 1.0e-6);
+    /// assert_relative_eq!(
+    ///     route[0].geodesic_distance(&route[1]),
+    ///     route[4].geodesic_distance(&route[5]),
+    ///     epsilon = 1.0e-6
+    /// );
+    /// ```
+    fn geodesic_intermediate_points(
+        &self,
+        other: &Point<T>,
+        n: usize,
+        include_ends: bool,
+    ) -> Vec<Point<T>> {
+        let mut points = Vec::with_capacity(n + 2);
+        if include_ends {
+            points.push(self.geodesic_intermediate(other, T::zero()));
+        }
+
+        let segments = T::from(n + 1).unwrap();
+        for step in 1..=n {
+            let f = T::from(step).unwrap() / segments;
+            points.push(self.geodesic_intermediate(other, f));
+        }
+
+        if include_ends {
+            points.push(*other);
+        }
+        points
+    }
 }
 
 impl GeodesicIntermediate<f64> for Point {
@@ -56,33 +101,55 @@ impl GeodesicIntermediate<f64> for Point {
         let (total_distance, azi1, _azi2, _a12) =
             g.inverse(self.y(), self.x(), other.y(), other.x());
 
-        if total_distance <= max_dist {
-            return if include_ends {
-                vec![*self, *other]
-            } else {
-                vec![]
-            };
-        }
-
-        let number_of_points = (total_distance / max_dist).ceil();
-        let interval = 1.0 / number_of_points;
+        let number_of_segments = (total_distance / max_dist).ceil().max(1.0);
+        intermediate_points(
+            &g,
+            *self,
+            *other,
+            (total_distance, azi1),
+            number_of_segments as usize - 1,
+            include_ends,
+        )
+    }
 
-        let mut current_step = interval;
-        let mut points = if include_ends { vec![*self] } else { vec![] };
+    fn geodesic_intermediate_points(
+        &self,
+        other: &Point,
+        n: usize,
+        include_ends: bool,
+    ) -> Vec<Point> {
+        let g = Geodesic::wgs84();
+        let (total_distance, azi1, _azi2, _a12) =
+            g.inverse(self.y(), self.x(), other.y(), other.x());
+        intermediate_points(&g, *self, *other, (total_distance, azi1), n, include_ends)
+    }
+}
 
-        while current_step < 1.0 {
-            let (lat2, lon2) = g.direct(self.y(), self.x(), azi1, total_distance * current_step);
-            let point = Point::new(lon2, lat2);
-            points.push(point);
-            current_step += interval;
-        }
+/// `n` points evenly spaced along the geodesic from `start` to `end`, whose length is
+/// `total_distance` and whose azimuth at `start` is `azi1`.
+fn intermediate_points(
+    g: &Geodesic,
+    start: Point,
+    end: Point,
+    (total_distance, azi1): (f64, f64),
+    n: usize,
+    include_ends: bool,
+) -> Vec<Point> {
+    let mut points = Vec::with_capacity(n + 2);
+    if include_ends {
+        points.push(start);
+    }
 
-        if include_ends {
-            points.push(*other);
-        }
+    let interval = total_distance / (n + 1) as f64;
+    for step in 1..=n {
+        let (lat2, lon2) = g.direct(start.y(), start.x(), azi1, interval * step as f64);
+        points.push(Point::new(lon2, lat2));
+    }
 
-        points
+    if include_ends {
+        points.push(end);
     }
+    points
 }
 
 #[cfg(test)]
@@ -125,4 +192,55 @@ mod tests {
         let route = p1.geodesic_intermediate_fill(&p2, max_dist, include_ends);
         assert_eq!(route, vec![p1, i50, p2]);
     }
+
+    #[test]
+    fn fill_without_ends_test() {
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        assert!(p1.geodesic_intermediate_fill(&p2, 1e7, false).is_empty());
+        assert_eq!(p1.geodesic_intermediate_fill(&p2, 1e7, true), vec![p1, p2]);
+        assert_eq!(p1.geodesic_intermediate_fill(&p2, 1e5, false).len(), 13);
+    }
+
+    #[test]
+    fn intermediate_points_test() {
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        let points = p1.geodesic_intermediate_points(&p2, 3, false);
+        assert_eq!(points.len(), 3);
+        for (index, point) in points.iter().enumerate() {
+            let expected = p1.geodesic_intermediate(&p2, (index + 1) as f64 / 4.0);
+            assert_relative_eq!(*point, expected, epsilon = 1.0e-9);
+        }
+        assert_eq!(p1.geodesic_intermediate_points(&p2, 0, true), vec![p1, p2]);
+    }
+
+    #[test]
+    fn default_intermediate_points_test() {
+        // Only implements the required methods, by way of `Point`
+        struct Wrapper(Point);
+        impl GeodesicIntermediate<f64> for Wrapper {
+            fn geodesic_intermediate(&self, other: &Point, f: f64) -> Point {
+                self.0.geodesic_intermediate(other, f)
+            }
+            fn geodesic_intermediate_fill(
+                &self,
+                other: &Point,
+                max_dist: f64,
+                include_ends: bool,
+            ) -> Vec<Point> {
+                self.0
+                    .geodesic_intermediate_fill(other, max_dist, include_ends)
+            }
+        }
+
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        let points = Wrapper(p1).geodesic_intermediate_points(&p2, 3, true);
+        let expected = p1.geodesic_intermediate_points(&p2, 3, true);
+        assert_eq!(points.len(), expected.len());
+        for (point, expected) in points.iter().zip(&expected) {
+            assert_relative_eq!(*point, *expected, epsilon = 1.0e-9);
+        }
+    }
 }
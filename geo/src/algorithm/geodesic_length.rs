@@ -1,5 +1,5 @@
-use crate::GeodesicDistance;
-use crate::{Line, LineString, MultiLineString};
+use crate::GeodesicDistanceOnEllipsoid;
+use crate::{Ellipsoid, Line, LineString, MultiLineString};
 
 /// Determine the length of a geometry on an ellipsoidal model of the earth.
 ///
@@ -45,19 +45,42 @@ pub trait GeodesicLength<T, RHS = Self> {
     fn geodesic_length(&self) -> T;
 }
 
+/// Determine the length of a geometry on a given ellipsoid, rather than on
+/// [WGS84](Ellipsoid::WGS84) as [`GeodesicLength`] does.
+pub trait GeodesicLengthOnEllipsoid<T> {
+    /// Determine the length of a geometry on the given ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    fn geodesic_length_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> T;
+}
+
 impl GeodesicLength<f64> for Line {
     /// The units of the returned value is meters.
     fn geodesic_length(&self) -> f64 {
+        self.geodesic_length_on_ellipsoid(&Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicLengthOnEllipsoid<f64> for Line {
+    fn geodesic_length_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> f64 {
         let (start, end) = self.points();
-        start.geodesic_distance(&end)
+        start.geodesic_distance_on_ellipsoid(&end, ellipsoid)
     }
 }
 
 impl GeodesicLength<f64> for LineString {
     fn geodesic_length(&self) -> f64 {
+        self.geodesic_length_on_ellipsoid(&Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicLengthOnEllipsoid<f64> for LineString {
+    fn geodesic_length_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> f64 {
         let mut length = 0.0;
         for line in self.lines() {
-            length += line.geodesic_length();
+            length += line.geodesic_length_on_ellipsoid(ellipsoid);
         }
         length
     }
@@ -65,9 +88,15 @@ impl GeodesicLength<f64> for LineString {
 
 impl GeodesicLength<f64> for MultiLineString {
     fn geodesic_length(&self) -> f64 {
+        self.geodesic_length_on_ellipsoid(&Ellipsoid::WGS84)
+    }
+}
+
+impl GeodesicLengthOnEllipsoid<f64> for MultiLineString {
+    fn geodesic_length_on_ellipsoid(&self, ellipsoid: &Ellipsoid) -> f64 {
         let mut length = 0.0;
         for line_string in &self.0 {
-            length += line_string.geodesic_length();
+            length += line_string.geodesic_length_on_ellipsoid(ellipsoid);
         }
         length
     }
@@ -0,0 +1,321 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+use crate::{BoundingRect, GeoFloat, Intersects, MonotonicPolygons, MultiPolygon, Point};
+
+/// What happened to an entity at a geofence, in a [`GeofenceEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeofenceEventKind {
+    /// The entity moved into the fence, or was first seen in it.
+    Enter,
+    /// The entity moved out of the fence, or was [removed](Geofencer::remove) while in it.
+    Exit,
+    /// The entity has been in the fence for the [dwell time](Geofencer::dwell_time) since it
+    /// entered, which is reported once for each visit.
+    Dwell,
+}
+
+/// An entity entering, exiting or dwelling in a fence of a [`Geofencer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeofenceEvent {
+    /// The index of the fence, in the order the fences were given.
+    pub fence: usize,
+    /// What happened.
+    pub kind: GeofenceEventKind,
+    /// The timestamp of the update at which it happened.
+    pub timestamp: u64,
+}
+
+/// Track entities, such as vehicles or phones, moving through a set of polygonal geofences,
+/// reporting when each of them enters, exits or dwells in a fence.
+///
+/// The fences are prepared once: each is subdivided into [`MonotonicPolygons`], so that
+/// testing whether a point is in it takes `O(log n)` time in the number of its vertices, and
+/// their bounding rectangles are indexed together in an R-tree, so that each point is only
+/// tested against the fences whose bounding rectangles contain it. Fences may overlap, and an
+/// entity may be in any number of them at once. A point on the boundary of a fence is in it.
+///
+/// Each entity is identified by a key of any ordered type, such as a device ID, and its
+/// position is updated with a timestamp, in any unit, such as milliseconds since the epoch.
+/// Updates older than the latest one for the entity are ignored, so that late deliveries don't
+/// produce spurious events.
+///
+/// The events of an update are in the order of exits, then entries, then dwells, each in the
+/// order of their fences.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, GeofenceEvent, GeofenceEventKind::*, Geofencer};
+///
+/// let depot = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let yard = polygon![(x: 5., y: 5.), (x: 20., y: 5.), (x: 20., y: 20.), (x: 5., y: 20.)];
+/// // Report vehicles which stay in a fence for five minutes, in milliseconds
+/// let mut geofencer = Geofencer::new([depot, yard]).dwell_time(300_000);
+///
+/// assert_eq!(geofencer.update("truck 1", 0, point!(x: 2., y: 2.)), [
+///     GeofenceEvent { fence: 0, kind: Enter, timestamp: 0 },
+/// ]);
+/// assert_eq!(geofencer.update("truck 1", 60_000, point!(x: 7., y: 7.)), [
+///     GeofenceEvent { fence: 1, kind: Enter, timestamp: 60_000 },
+/// ]);
+/// assert_eq!(geofencer.update("truck 1", 360_000, point!(x: 15., y: 15.)), [
+///     GeofenceEvent { fence: 0, kind: Exit, timestamp: 360_000 },
+///     GeofenceEvent { fence: 1, kind: Dwell, timestamp: 360_000 },
+/// ]);
+/// assert_eq!(geofencer.fences_of(&"truck 1").collect::<Vec<_>>(), [1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Geofencer<K, T: GeoFloat + RTreeNum = f64> {
+    fences: Vec<MonotonicPolygons<T>>,
+    tree: RTree<GeomWithData<Rectangle<[T; 2]>, usize>>,
+    dwell_time: Option<u64>,
+    entities: BTreeMap<K, Entity>,
+}
+
+#[derive(Debug, Clone)]
+struct Entity {
+    timestamp: u64,
+    /// The fences the entity is in, in order
+    visits: Vec<Visit>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Visit {
+    fence: usize,
+    entered: u64,
+    dwelled: bool,
+}
+
+impl<K: Ord, T: GeoFloat + RTreeNum> Geofencer<K, T> {
+    /// Prepare `fences`, which are `Polygon`s or `MultiPolygon`s, to track entities through.
+    /// Events refer to fences by their position in this iterator.
+    ///
+    /// No dwell events are reported, unless a [dwell time](Self::dwell_time) is set.
+    pub fn new<I, G>(fences: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+        G: Into<MultiPolygon<T>>,
+    {
+        let mut rectangles = vec![];
+        let fences = fences
+            .into_iter()
+            .enumerate()
+            .map(|(index, fence)| {
+                let fence = fence.into();
+                if let Some(rect) = fence.bounding_rect() {
+                    let rectangle = Rectangle::from_corners(rect.min().into(), rect.max().into());
+                    rectangles.push(GeomWithData::new(rectangle, index));
+                }
+                MonotonicPolygons::from(fence)
+            })
+            .collect();
+        Geofencer {
+            fences,
+            tree: RTree::bulk_load(rectangles),
+            dwell_time: None,
+            entities: BTreeMap::new(),
+        }
+    }
+
+    /// Report a [`Dwell`](GeofenceEventKind::Dwell) event when an entity has been in a fence
+    /// for `dwell_time` since it entered, in the unit of the timestamps.
+    #[must_use]
+    pub fn dwell_time(mut self, dwell_time: u64) -> Self {
+        self.dwell_time = Some(dwell_time);
+        self
+    }
+
+    /// The indices of the fences which contain `point`, in order.
+    pub fn fences_containing(&self, point: Point<T>) -> Vec<usize> {
+        let mut fences: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&AABB::from_point(point.into()))
+            .map(|candidate| candidate.data)
+            .filter(|&fence| self.fences[fence].intersects(&point.0))
+            .collect();
+        fences.sort_unstable();
+        fences
+    }
+
+    /// Update the position of `entity` to `point`, at `timestamp`, returning the events it
+    /// caused.
+    pub fn update(&mut self, entity: K, timestamp: u64, point: Point<T>) -> Vec<GeofenceEvent> {
+        if self
+            .entities
+            .get(&entity)
+            .is_some_and(|state| timestamp < state.timestamp)
+        {
+            return vec![];
+        }
+        let inside = self.fences_containing(point);
+        let state = self.entities.entry(entity).or_insert(Entity {
+            timestamp,
+            visits: vec![],
+        });
+        state.timestamp = timestamp;
+
+        let event = |fence, kind| GeofenceEvent {
+            fence,
+            kind,
+            timestamp,
+        };
+        let mut events: Vec<GeofenceEvent> = state
+            .visits
+            .iter()
+            .filter(|visit| inside.binary_search(&visit.fence).is_err())
+            .map(|visit| event(visit.fence, GeofenceEventKind::Exit))
+            .collect();
+
+        let mut visits = Vec::with_capacity(inside.len());
+        let mut previous = state.visits.iter().peekable();
+        for fence in inside {
+            while previous.next_if(|visit| visit.fence < fence).is_some() {}
+            match previous.next_if(|visit| visit.fence == fence) {
+                Some(&visit) => visits.push(visit),
+                None => {
+                    events.push(event(fence, GeofenceEventKind::Enter));
+                    visits.push(Visit {
+                        fence,
+                        entered: timestamp,
+                        dwelled: false,
+                    });
+                }
+            }
+        }
+
+        if let Some(dwell_time) = self.dwell_time {
+            for visit in &mut visits {
+                if !visit.dwelled && timestamp - visit.entered >= dwell_time {
+                    visit.dwelled = true;
+                    events.push(event(visit.fence, GeofenceEventKind::Dwell));
+                }
+            }
+        }
+        state.visits = visits;
+        events
+    }
+
+    /// Stop tracking `entity`, such as when it goes offline, returning an
+    /// [`Exit`](GeofenceEventKind::Exit) event, at `timestamp`, for each fence it was in.
+    pub fn remove(&mut self, entity: &K, timestamp: u64) -> Vec<GeofenceEvent> {
+        let Some(state) = self.entities.remove(entity) else {
+            return vec![];
+        };
+        state
+            .visits
+            .iter()
+            .map(|visit| GeofenceEvent {
+                fence: visit.fence,
+                kind: GeofenceEventKind::Exit,
+                timestamp,
+            })
+            .collect()
+    }
+
+    /// The indices of the fences `entity` is in, in order.
+    pub fn fences_of<'a>(&'a self, entity: &K) -> impl Iterator<Item = usize> + 'a {
+        self.entities
+            .get(entity)
+            .into_iter()
+            .flat_map(|state| state.visits.iter().map(|visit| visit.fence))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains, Polygon};
+    use GeofenceEventKind::*;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn containment_matches_polygons() {
+        let fences: Vec<Polygon> = (0..20)
+            .map(|i| square(f64::from(i % 5) * 3., f64::from(i / 5) * 3., 4.))
+            .collect();
+        let geofencer: Geofencer<u32> = Geofencer::new(fences.clone());
+        for i in 0..40 {
+            for j in 0..40 {
+                let point = point!(x: f64::from(i) * 0.4 + 0.1, y: f64::from(j) * 0.4 + 0.1);
+                let expected: Vec<usize> = (0..fences.len())
+                    .filter(|&fence| fences[fence].contains(&point))
+                    .collect();
+                assert_eq!(geofencer.fences_containing(point), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn events() {
+        let mut geofencer = Geofencer::new([square(0., 0., 10.), square(5., 5., 10.)]);
+        let event = |fence, kind, timestamp| GeofenceEvent {
+            fence,
+            kind,
+            timestamp,
+        };
+
+        assert_eq!(
+            geofencer.update(1, 10, point!(x: 7., y: 7.)),
+            [event(0, Enter, 10), event(1, Enter, 10)]
+        );
+        assert_eq!(geofencer.update(2, 10, point!(x: 20., y: 20.)), []);
+        assert_eq!(geofencer.update(1, 20, point!(x: 8., y: 8.)), []);
+        // A late update is ignored
+        assert_eq!(geofencer.update(1, 15, point!(x: 30., y: 30.)), []);
+        assert_eq!(geofencer.fences_of(&1).collect::<Vec<_>>(), [0, 1]);
+
+        assert_eq!(
+            geofencer.update(1, 30, point!(x: 1., y: 1.)),
+            [event(1, Exit, 30)]
+        );
+        assert_eq!(
+            geofencer.update(2, 30, point!(x: 12., y: 12.)),
+            [event(1, Enter, 30)]
+        );
+        assert_eq!(geofencer.remove(&2, 40), [event(1, Exit, 40)]);
+        assert_eq!(geofencer.remove(&2, 40), []);
+        assert_eq!(geofencer.fences_of(&2).count(), 0);
+    }
+
+    #[test]
+    fn dwell() {
+        let mut geofencer = Geofencer::new([square(0., 0., 10.)]).dwell_time(100);
+        let inside = point!(x: 5., y: 5.);
+        assert_eq!(geofencer.update("a", 0, inside).len(), 1);
+        assert_eq!(geofencer.update("a", 99, inside), []);
+        assert_eq!(
+            geofencer.update("a", 100, inside),
+            [GeofenceEvent {
+                fence: 0,
+                kind: Dwell,
+                timestamp: 100
+            }]
+        );
+        // Only once for each visit
+        assert_eq!(geofencer.update("a", 500, inside), []);
+        geofencer.update("a", 600, point!(x: 50., y: 50.));
+        geofencer.update("a", 700, inside);
+        assert_eq!(geofencer.update("a", 800, inside)[0].kind, Dwell);
+
+        // A dwell time of zero reports a dwell along with the entry
+        let mut geofencer = Geofencer::new([square(0., 0., 10.)]).dwell_time(0);
+        let kinds: Vec<_> = geofencer
+            .update("b", 0, inside)
+            .iter()
+            .map(|event| event.kind)
+            .collect();
+        assert_eq!(kinds, [Enter, Dwell]);
+    }
+}
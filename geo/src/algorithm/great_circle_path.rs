@@ -0,0 +1,310 @@
+use alloc::{vec, vec::Vec};
+
+use crate::angles::normalize_longitude;
+use crate::{coord, Coord, GeoFloat, Line, LineString, MultiLineString};
+
+/// Segments aren't subdivided more than this many times, into at most 2¹⁶ parts, such as when
+/// their ends are antipodal, so that there's no single great circle between them.
+const MAX_DEPTH: usize = 16;
+
+/// Convert longitude and latitude lines into polylines which follow the great circles between
+/// their coordinates, to draw them on a map in longitude and latitude, such as a web map.
+///
+/// Drawn straight, a long segment strays far from the shortest path between its ends: the
+/// great circle from Paris to Los Angeles crosses Greenland, north of the Atlantic. Each segment is
+/// subdivided at great circle midpoints until every drawn segment deviates by at most
+/// `max_deviation` degrees of arc from the great circle, at its midpoint. Unlike
+/// [`DensifyHaversine`](crate::DensifyHaversine), this adds more points where the path curves
+/// more on the map, such as at high latitudes, and fewer along the equator and meridians.
+///
+/// The output is split where the path crosses the antimeridian, at the latitude the great
+/// circle crosses it, so that it's drawn as two parts, not one across the whole map. Longitudes
+/// are in the range `[-180, 180]`.
+///
+/// # Panics
+///
+/// If `max_deviation` isn't greater than zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{GreatCirclePath, Line};
+///
+/// // Tokyo to San Francisco, across the Pacific
+/// let flight = Line::new((139.78, 35.55), (-122.38, 37.62));
+/// let path = flight.great_circle_path(0.1);
+///
+/// assert_eq!(path.0.len(), 2);
+/// let (west, east) = (&path.0[0], &path.0[1]);
+/// assert_eq!(west.0.first().unwrap().x, 139.78);
+/// assert_eq!(west.0.last().unwrap().x, 180.);
+/// assert_eq!(east.0.first().unwrap().x, -180.);
+/// assert_eq!(east.0.last().unwrap().x, -122.38);
+///
+/// // The path curves north of both ends
+/// assert!(west.0.last().unwrap().y > 45.);
+/// ```
+pub trait GreatCirclePath<T: GeoFloat> {
+    /// The great circle path, with a maximum deviation of `max_deviation` degrees of arc, split
+    /// at the antimeridian.
+    fn great_circle_path(&self, max_deviation: T) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> GreatCirclePath<T> for Line<T> {
+    fn great_circle_path(&self, max_deviation: T) -> MultiLineString<T> {
+        path(&[self.start, self.end], max_deviation)
+    }
+}
+
+impl<T: GeoFloat> GreatCirclePath<T> for LineString<T> {
+    fn great_circle_path(&self, max_deviation: T) -> MultiLineString<T> {
+        path(&self.0, max_deviation)
+    }
+}
+
+impl<T: GeoFloat> GreatCirclePath<T> for MultiLineString<T> {
+    fn great_circle_path(&self, max_deviation: T) -> MultiLineString<T> {
+        MultiLineString::new(
+            self.iter()
+                .flat_map(|line_string| line_string.great_circle_path(max_deviation))
+                .collect(),
+        )
+    }
+}
+
+fn path<T: GeoFloat>(coords: &[Coord<T>], max_deviation: T) -> MultiLineString<T> {
+    assert!(max_deviation > T::zero());
+    let max_deviation = max_deviation.to_radians();
+
+    let mut dense = Vec::with_capacity(coords.len());
+    for (index, &coord) in coords.iter().enumerate() {
+        let coord = coord! { x: wrap_longitude(coord.x), y: coord.y };
+        if index > 0 {
+            subdivide(dense[dense.len() - 1], coord, max_deviation, 0, &mut dense);
+        }
+        dense.push(coord);
+    }
+    MultiLineString::new(split(dense))
+}
+
+/// Push the points strictly between `start` and `end` on the great circle through them, in
+/// order, subdividing until the straight segments between them are close enough to it.
+fn subdivide<T: GeoFloat>(
+    start: Coord<T>,
+    end: Coord<T>,
+    max_deviation: T,
+    depth: usize,
+    dense: &mut Vec<Coord<T>>,
+) {
+    if depth == MAX_DEPTH {
+        return;
+    }
+    let sum = add(to_vector(start), to_vector(end));
+    let norm = dot(sum, sum).sqrt();
+    if norm <= T::epsilon() {
+        // Antipodal, so there's no single great circle to follow
+        return;
+    }
+    let great_circle_midpoint = [sum[0] / norm, sum[1] / norm, sum[2] / norm];
+
+    let two = T::one() + T::one();
+    let drawn_midpoint = coord! {
+        x: start.x + wrap_longitude(end.x - start.x) / two,
+        y: (start.y + end.y) / two,
+    };
+    if angle(great_circle_midpoint, to_vector(drawn_midpoint)) <= max_deviation {
+        return;
+    }
+
+    let midpoint = from_vector(great_circle_midpoint);
+    subdivide(start, midpoint, max_deviation, depth + 1, dense);
+    dense.push(midpoint);
+    subdivide(midpoint, end, max_deviation, depth + 1, dense);
+}
+
+/// Split `coords` where consecutive coordinates are more than 180° of longitude apart, and so
+/// are joined across the antimeridian.
+fn split<T: GeoFloat>(coords: Vec<Coord<T>>) -> Vec<LineString<T>> {
+    let half_turn = T::from(180).unwrap();
+    let mut parts = vec![];
+    let mut current: Vec<Coord<T>> = vec![];
+    for coord in coords {
+        if let Some(&previous) = current.last() {
+            if (coord.x - previous.x).abs() > half_turn {
+                let edge = if previous.x > T::zero() {
+                    half_turn
+                } else {
+                    -half_turn
+                };
+                let y = if previous.x.abs() == half_turn {
+                    previous.y
+                } else if coord.x.abs() == half_turn {
+                    coord.y
+                } else {
+                    antimeridian_latitude(previous, coord)
+                };
+                if previous.x != edge {
+                    current.push(coord! { x: edge, y: y });
+                }
+                if current.len() > 1 {
+                    parts.push(LineString::new(core::mem::take(&mut current)));
+                } else {
+                    current.clear();
+                }
+                if coord.x != -edge {
+                    current.push(coord! { x: -edge, y: y });
+                }
+            }
+        }
+        current.push(coord);
+    }
+    if !current.is_empty() {
+        parts.push(LineString::new(current));
+    }
+    parts
+}
+
+/// The latitude at which the great circle through `start` and `end` crosses the antimeridian.
+fn antimeridian_latitude<T: GeoFloat>(start: Coord<T>, end: Coord<T>) -> T {
+    let normal = cross(to_vector(start), to_vector(end));
+    // The antimeridian at latitude φ is (-cos φ, 0, sin φ), perpendicular to the normal
+    if normal[2] == T::zero() {
+        // A great circle through the poles, so it crosses at one of them
+        T::from(90).unwrap().copysign(start.y + end.y)
+    } else {
+        (normal[0] / normal[2]).atan().to_degrees()
+    }
+}
+
+/// `longitude` in the range `[-180, 180]`, keeping longitudes of ±180 on the side of the
+/// antimeridian they're given on.
+fn wrap_longitude<T: GeoFloat>(longitude: T) -> T {
+    if longitude.abs() <= T::from(180).unwrap() {
+        longitude
+    } else {
+        normalize_longitude(longitude)
+    }
+}
+
+fn to_vector<T: GeoFloat>(coord: Coord<T>) -> [T; 3] {
+    let (longitude, latitude) = (coord.x.to_radians(), coord.y.to_radians());
+    [
+        latitude.cos() * longitude.cos(),
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+    ]
+}
+
+fn from_vector<T: GeoFloat>(vector: [T; 3]) -> Coord<T> {
+    coord! {
+        x: vector[1].atan2(vector[0]).to_degrees(),
+        y: vector[2].min(T::one()).max(-T::one()).asin().to_degrees(),
+    }
+}
+
+fn add<T: GeoFloat>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn dot<T: GeoFloat>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross<T: GeoFloat>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// The angle between unit vectors, in radians.
+fn angle<T: GeoFloat>(a: [T; 3], b: [T; 3]) -> T {
+    let normal = cross(a, b);
+    dot(normal, normal).sqrt().atan2(dot(a, b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, CrossTrackDistance, Point};
+
+    #[test]
+    fn follows_great_circle() {
+        // Paris to Los Angeles
+        let line = Line::new((2.35, 48.86), (-118.24, 34.05));
+        let path = line.great_circle_path(0.01);
+        assert_eq!(path.0.len(), 1);
+        let path = &path.0[0];
+        assert_eq!(path.0.first(), Some(&line.start));
+        assert_eq!(path.0.last(), Some(&line.end));
+        assert!(path.0.len() > 20);
+
+        // Every point is on the great circle, and the path passes over Greenland
+        for &coord in path {
+            let cross_track: f64 =
+                Point::from(coord).cross_track_distance(&line.start_point(), &line.end_point());
+            assert!(cross_track.abs() < 1e-3);
+        }
+        assert!(path.0.iter().any(|coord| coord.y > 60.));
+
+        // A coarser tolerance needs fewer points
+        assert!(line.great_circle_path(1.).0[0].0.len() < path.0.len());
+    }
+
+    #[test]
+    fn equator_and_meridians_are_straight() {
+        let equator = Line::new((0., 0.), (90., 0.));
+        assert_eq!(
+            equator.great_circle_path(0.01),
+            MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 90., y: 0.)]])
+        );
+        let meridian = Line::new((10., -60.), (10., 60.));
+        assert_eq!(
+            meridian.great_circle_path(0.01),
+            MultiLineString::new(vec![line_string![(x: 10., y: -60.), (x: 10., y: 60.)]])
+        );
+    }
+
+    #[test]
+    fn splits_at_antimeridian() {
+        let line_string = line_string![(x: 170., y: 50.), (x: -170., y: 50.), (x: -160., y: 40.)];
+        let path = line_string.great_circle_path(0.1);
+        assert_eq!(path.0.len(), 2);
+        let (west, east) = (&path.0[0], &path.0[1]);
+        let (west_end, east_start) = (*west.0.last().unwrap(), east.0[0]);
+        assert_eq!(west_end.x, 180.);
+        assert_eq!(east_start.x, -180.);
+        assert_eq!(west_end.y, east_start.y);
+        // By symmetry, the great circle crosses at its northernmost point
+        assert_relative_eq!(
+            west_end.y,
+            (50_f64.to_radians().tan() / 10_f64.to_radians().cos())
+                .atan()
+                .to_degrees(),
+            epsilon = 1e-9
+        );
+        assert!(west.0.iter().all(|coord| coord.x >= 170.));
+        assert!(east.0.iter().all(|coord| coord.x <= -160.));
+
+        // Longitudes out of range are wrapped
+        let wrapped = Line::new((170., 50.), (190., 50.)).great_circle_path(0.1);
+        assert_eq!(wrapped.0.len(), 2);
+        assert_eq!(wrapped.0[1].0.last(), Some(&coord! { x: -170., y: 50. }));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(
+            LineString::<f64>::new(vec![]).great_circle_path(1.),
+            MultiLineString::new(vec![])
+        );
+        assert_eq!(
+            line_string![(x: 1., y: 2.)].great_circle_path(1.),
+            MultiLineString::new(vec![line_string![(x: 1., y: 2.)]])
+        );
+        // Antipodal ends aren't subdivided
+        let antipodal = Line::new((0., 0.), (180., 0.)).great_circle_path(1.);
+        assert_eq!(antipodal.0[0].0.len(), 2);
+    }
+}
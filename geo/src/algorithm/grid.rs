@@ -0,0 +1,276 @@
+use crate::{
+    coord, BooleanOps, BoundingRect, Coord, GeoFloat, LineString, MultiPolygon, Polygon, Rect,
+};
+use alloc::{vec, vec::Vec};
+
+/// Tile the bounding rectangle of a geometry with square or hexagonal cells.
+///
+/// The cells cover the whole bounding rectangle, so cells along its edges may extend past it. Use
+/// [`GridCells::clip`] to restrict the cells to an area of interest.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, Grid, Rect};
+///
+/// let bounds = Rect::new((0., 0.), (10., 5.));
+/// let grid = bounds.square_grid(2.5);
+///
+/// // 4 columns and 2 rows
+/// assert_eq!(grid.cells.0.len(), 8);
+/// assert_eq!(grid.cells.unsigned_area(), 50.);
+///
+/// let cell = grid.cell(1, 3).unwrap();
+/// assert_eq!(cell.exterior().0[0], geo::coord! { x: 7.5, y: 2.5 });
+/// ```
+pub trait Grid<T: GeoFloat> {
+    /// Tile the bounding rectangle of `self` with squares whose sides are `cell_size` long.
+    ///
+    /// Cells are aligned with the minimum corner of the bounding rectangle. Row `0` is at the
+    /// bottom (minimum `y`) and column `0` is at the left (minimum `x`).
+    ///
+    /// Returns an empty grid if `self` is empty or `cell_size` isn't positive.
+    fn square_grid(&self, cell_size: T) -> GridCells<T>;
+
+    /// Tile the bounding rectangle of `self` with flat-topped regular hexagons whose sides are
+    /// `cell_size` long.
+    ///
+    /// The center of the cell at row `0`, column `0` is the minimum corner of the bounding
+    /// rectangle. Odd columns are shifted up by half a cell.
+    ///
+    /// Returns an empty grid if `self` is empty or `cell_size` isn't positive.
+    fn hexagonal_grid(&self, cell_size: T) -> GridCells<T>;
+}
+
+/// The cells of a [`Grid`], along with the row and column of each cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridCells<T: GeoFloat> {
+    /// The cells, in row-major order.
+    pub cells: MultiPolygon<T>,
+
+    /// The position of each polygon of `cells` within the grid.
+    pub indices: Vec<GridIndex>,
+}
+
+/// The position of a cell within a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridIndex {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl<T: GeoFloat> GridCells<T> {
+    /// The cell at `row` and `column`, if there is one.
+    ///
+    /// If the cell was split into several polygons by [`GridCells::clip`], this is the first of
+    /// them.
+    pub fn cell(&self, row: usize, column: usize) -> Option<&Polygon<T>> {
+        let index = GridIndex { row, column };
+        let position = self.indices.partition_point(|other| *other < index);
+        match self.indices.get(position) {
+            Some(found) if *found == index => Some(&self.cells.0[position]),
+            _ => None,
+        }
+    }
+
+    /// Intersect each cell with `area`, removing cells which don't overlap it.
+    ///
+    /// A cell may be split into several polygons, each of which keeps the cell's index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Area, Grid, MultiPolygon};
+    ///
+    /// let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+    /// let grid = triangle.square_grid(1.).clip(&MultiPolygon::from(triangle.clone()));
+    ///
+    /// // Cells above the hypotenuse are removed
+    /// assert_eq!(grid.cells.0.len(), 10);
+    /// approx::assert_relative_eq!(grid.cells.unsigned_area(), triangle.unsigned_area());
+    /// ```
+    pub fn clip(&self, area: &MultiPolygon<T>) -> GridCells<T> {
+        let mut cells = vec![];
+        let mut indices = vec![];
+        for (cell, index) in self.cells.iter().zip(&self.indices) {
+            for part in MultiPolygon::from(cell.clone()).intersection(area) {
+                cells.push(part);
+                indices.push(*index);
+            }
+        }
+        GridCells {
+            cells: MultiPolygon::new(cells),
+            indices,
+        }
+    }
+}
+
+impl<T, G> Grid<T> for G
+where
+    T: GeoFloat,
+    G: BoundingRect<T>,
+{
+    fn square_grid(&self, cell_size: T) -> GridCells<T> {
+        let mut grid = GridCells {
+            cells: MultiPolygon::new(vec![]),
+            indices: vec![],
+        };
+        let bounds = match self.bounding_rect().into() {
+            Some(bounds) if cell_size > T::zero() => bounds,
+            _ => return grid,
+        };
+        let count = |extent: T| {
+            (extent / cell_size)
+                .ceil()
+                .to_usize()
+                .unwrap_or_default()
+                .max(1)
+        };
+        let (rows, columns) = (count(bounds.height()), count(bounds.width()));
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let min = bounds.min()
+                    + coord! {
+                        x: T::from(column).unwrap() * cell_size,
+                        y: T::from(row).unwrap() * cell_size,
+                    };
+                let max = min + coord! { x: cell_size, y: cell_size };
+                grid.cells.0.push(Rect::new(min, max).to_polygon());
+                grid.indices.push(GridIndex { row, column });
+            }
+        }
+        grid
+    }
+
+    fn hexagonal_grid(&self, cell_size: T) -> GridCells<T> {
+        let mut grid = GridCells {
+            cells: MultiPolygon::new(vec![]),
+            indices: vec![],
+        };
+        let bounds = match self.bounding_rect().into() {
+            Some(bounds) if cell_size > T::zero() => bounds,
+            _ => return grid,
+        };
+        let two = T::one() + T::one();
+        let column_spacing = cell_size * T::from(1.5).unwrap();
+        let height = cell_size * T::from(3).unwrap().sqrt();
+
+        // Keep adding rows and columns while the cells reach into the bounds.
+        let mut centers = vec![];
+        let mut column = 0;
+        loop {
+            let x = bounds.min().x + T::from(column).unwrap() * column_spacing;
+            if x - cell_size >= bounds.max().x {
+                break;
+            }
+            let offset = if column % 2 == 1 {
+                height / two
+            } else {
+                T::zero()
+            };
+            let mut row = 0;
+            loop {
+                let y = bounds.min().y + T::from(row).unwrap() * height + offset;
+                if y - height / two >= bounds.max().y {
+                    break;
+                }
+                centers.push((GridIndex { row, column }, coord! { x: x, y: y }));
+                row += 1;
+            }
+            column += 1;
+        }
+        centers.sort_by_key(|(index, _)| *index);
+
+        for (index, center) in centers {
+            grid.cells.0.push(hexagon(center, cell_size));
+            grid.indices.push(index);
+        }
+        grid
+    }
+}
+
+/// A flat-topped regular hexagon with a counter-clockwise exterior.
+fn hexagon<T: GeoFloat>(center: Coord<T>, size: T) -> Polygon<T> {
+    let half = size / (T::one() + T::one());
+    let half_height = half * T::from(3).unwrap().sqrt();
+    let exterior = LineString::from(vec![
+        center + coord! { x: size, y: T::zero() },
+        center + coord! { x: half, y: half_height },
+        center + coord! { x: -half, y: half_height },
+        center + coord! { x: -size, y: T::zero() },
+        center + coord! { x: -half, y: -half_height },
+        center + coord! { x: half, y: -half_height },
+    ]);
+    Polygon::new(exterior, vec![])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Area, Contains, Intersects, Winding};
+
+    #[test]
+    fn square_grid_covers_bounds() {
+        let bounds = Rect::new((1., 1.), (4.5, 3.));
+        let grid = bounds.square_grid(1.);
+        assert_eq!(grid.cells.0.len(), 8);
+        assert_eq!(grid.indices.len(), 8);
+        assert_eq!(grid.indices[5], GridIndex { row: 1, column: 1 });
+        assert!(grid.cell(1, 3).unwrap().contains(&point!(x: 4.2, y: 2.5)));
+        assert!(grid.cell(2, 0).is_none());
+    }
+
+    #[test]
+    fn degenerate_bounds() {
+        let point = point!(x: 3., y: 3.);
+        let grid = point.square_grid(1.);
+        assert_eq!(grid.cells.0.len(), 1);
+        assert!(grid.cells.intersects(&point));
+
+        let grid = point.hexagonal_grid(1.);
+        assert_eq!(grid.cells.0.len(), 1);
+        assert!(grid.cells.contains(&point));
+
+        assert!(point.square_grid(0.).cells.0.is_empty());
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .hexagonal_grid(1.)
+            .cells
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn hexagonal_grid_covers_bounds() {
+        let bounds = Rect::new((0., 0.), (10., 7.));
+        let grid = bounds.hexagonal_grid(1.);
+        let cell_area = 1.5 * 3f64.sqrt();
+        for cell in &grid.cells {
+            assert!(cell.exterior().is_ccw());
+            assert_relative_eq!(cell.unsigned_area(), cell_area, epsilon = 1e-9);
+        }
+        for i in 0..=40 {
+            for j in 0..=28 {
+                let point = point!(x: i as f64 * 0.25, y: j as f64 * 0.25);
+                assert!(grid.cells.intersects(&point), "{point:?}");
+            }
+        }
+        // Cells are sorted in row-major order
+        assert!(grid.indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(grid.cell(0, 0).unwrap().contains(&point!(x: 0.1, y: 0.1)));
+    }
+
+    #[test]
+    fn clip_to_polygon() {
+        let triangle = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 3.)];
+        let grid = triangle
+            .hexagonal_grid(0.5)
+            .clip(&MultiPolygon::from(triangle.clone()));
+        assert_relative_eq!(
+            grid.cells.unsigned_area(),
+            triangle.unsigned_area(),
+            epsilon = 1e-9
+        );
+        assert_eq!(grid.cells.0.len(), grid.indices.len());
+    }
+}
@@ -1,8 +1,15 @@
 use crate::algorithm::EuclideanDistance;
+use crate::line_intersection::{line_intersection, LineIntersection};
 use crate::CoordsIter;
 use crate::GeoFloat;
-use geo_types::{Coord, Point};
+use crate::{coord, BoundingRect, Intersects, LinesIter};
+use alloc::{vec, vec::Vec};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 use num_traits::Bounded;
+use rstar::{RTree, RTreeNum, RTreeObject};
 
 /// Determine the distance between two geometries using the [Hausdorff distance formula].
 ///
@@ -10,8 +17,11 @@ use num_traits::Bounded;
 /// distance of a point in one set to the nearest point in another set. Hausdorff distance
 /// is often used to measure the amount of mismatch between two sets.
 ///
+/// The distance is between the coordinates of the geometries. See
+/// [`DirectedHausdorffDistance`] for the distance each way, between every point along their
+/// lines and the boundaries of their polygons.
+///
 /// [Hausdorff distance formula]: https://en.wikipedia.org/wiki/Hausdorff_distance
-
 pub trait HausdorffDistance<T>
 where
     T: GeoFloat,
@@ -71,10 +81,430 @@ where
     }
 }
 
+/// The farthest that any point of one geometry is from another, with the point it's at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectedHausdorff<T: GeoFloat> {
+    /// The distance from `point` to `nearest`.
+    pub distance: T,
+    /// The point of the first geometry farthest from the second.
+    pub point: Point<T>,
+    /// The point of the second geometry nearest to `point`.
+    pub nearest: Point<T>,
+}
+
+// Seal the trait which gathers the parts of geometries, so that its method isn't on the public
+// interface.
+mod private {
+    use super::*;
+
+    pub trait Linework<T: GeoFloat> {
+        /// Gather the segments of the geometry, with points as segments of no length, and its
+        /// polygons.
+        fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>);
+    }
+}
+
+use private::Linework;
+
+/// Calculate the one-sided, or directed, Hausdorff distances from a geometry to another: how
+/// far its farthest point is from the other, and where.
+///
+/// [`directed_hausdorff`](Self::directed_hausdorff) measures between the points, lines and
+/// boundaries of polygons of both geometries, such as whether a simplified boundary is within
+/// a distance of the original everywhere. The Hausdorff distance between the lines and
+/// boundaries of two geometries is the larger of the directed distances each way.
+/// [`max_protrusion`](Self::max_protrusion) is how far a geometry, including the interiors of
+/// its polygons, reaches outside of another, so points in the polygons of the other are at no
+/// distance from it.
+///
+/// Distances are measured from every point along the lines and boundaries of `self`, not only
+/// from its vertices, to a relative precision of about the square root of the machine
+/// epsilon, by bisecting its segments where the farthest point could be. The interiors of the
+/// polygons of `self` are searched by subdividing them into cells, to a relative precision of
+/// about the fourth root of the machine epsilon.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, point, polygon, DirectedHausdorffDistance};
+///
+/// let parcel = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// // A simplification which cuts off one corner, and bulges out of one side
+/// let simplified = polygon![
+///     (x: 0., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 12., y: 5.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let protrusion = simplified.max_protrusion(&parcel).unwrap();
+/// assert_relative_eq!(protrusion.distance, 2.);
+/// assert_eq!(protrusion.point, point!(x: 12., y: 5.));
+/// assert_eq!(protrusion.nearest, point!(x: 10., y: 5.));
+/// // And the corner cut off protrudes from the simplification
+/// let cut_off = parcel.max_protrusion(&simplified).unwrap();
+/// assert_relative_eq!(cut_off.distance, 2f64.sqrt());
+/// assert_eq!(cut_off.point, point!(x: 0., y: 0.));
+///
+/// // Whereas the side of the parcel is farther from the boundary of the bulge
+/// let directed = parcel.directed_hausdorff(&simplified).unwrap();
+/// assert_relative_eq!(directed.distance, 10. / 29f64.sqrt(), epsilon = 1e-6);
+///
+/// // Between the middles of the segments, rather than at their vertices
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let track = line_string![(x: 0., y: 0.), (x: 5., y: 3.), (x: 10., y: 0.)];
+/// assert_relative_eq!(track.directed_hausdorff(&road).unwrap().distance, 3.);
+/// ```
+pub trait DirectedHausdorffDistance<T: GeoFloat + RTreeNum>: Linework<T> {
+    /// The farthest point of `self` from `rhs`, measured between their points, lines and
+    /// boundaries of polygons, or `None` if either is empty.
+    fn directed_hausdorff<Rhs: Linework<T>>(&self, rhs: &Rhs) -> Option<DirectedHausdorff<T>> {
+        directed_hausdorff(self, rhs, false)
+    }
+
+    /// The farthest point of `self`, including the interiors of its polygons, from `rhs`,
+    /// where points in the polygons of `rhs` are at no distance from it, or `None` if either
+    /// is empty.
+    fn max_protrusion<Rhs: Linework<T>>(&self, rhs: &Rhs) -> Option<DirectedHausdorff<T>> {
+        directed_hausdorff(self, rhs, true)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum, G: Linework<T>> DirectedHausdorffDistance<T> for G {}
+
+impl<T: GeoFloat> Linework<T> for Point<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, _polygons: &mut Vec<Polygon<T>>) {
+        segments.push(Line::new(self.0, self.0));
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiPoint<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, _polygons: &mut Vec<Polygon<T>>) {
+        segments.extend(self.iter().map(|point| Line::new(point.0, point.0)));
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Line<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, _polygons: &mut Vec<Polygon<T>>) {
+        segments.push(*self);
+    }
+}
+
+/// Push the segments of a linear geometry, which is a point if it has a single coordinate.
+fn push_lines<'a, T: GeoFloat, G>(geometry: &'a G, segments: &mut Vec<Line<T>>)
+where
+    G: LinesIter<'a, Scalar = T> + CoordsIter<Scalar = T>,
+{
+    let start = segments.len();
+    segments.extend(geometry.lines_iter());
+    if segments.len() == start {
+        if let Some(coord) = geometry.coords_iter().next() {
+            segments.push(Line::new(coord, coord));
+        }
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for LineString<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, _polygons: &mut Vec<Polygon<T>>) {
+        push_lines(self, segments);
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiLineString<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, _polygons: &mut Vec<Polygon<T>>) {
+        for line_string in self {
+            push_lines(line_string, segments);
+        }
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Polygon<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        push_lines(self, segments);
+        polygons.push(self.clone());
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiPolygon<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        for polygon in self {
+            polygon.push_linework(segments, polygons);
+        }
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Rect<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        self.to_polygon().push_linework(segments, polygons);
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Triangle<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        self.to_polygon().push_linework(segments, polygons);
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Geometry<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        match self {
+            Geometry::Point(g) => g.push_linework(segments, polygons),
+            Geometry::Line(g) => g.push_linework(segments, polygons),
+            Geometry::LineString(g) => g.push_linework(segments, polygons),
+            Geometry::Polygon(g) => g.push_linework(segments, polygons),
+            Geometry::MultiPoint(g) => g.push_linework(segments, polygons),
+            Geometry::MultiLineString(g) => g.push_linework(segments, polygons),
+            Geometry::MultiPolygon(g) => g.push_linework(segments, polygons),
+            Geometry::GeometryCollection(g) => g.push_linework(segments, polygons),
+            Geometry::Rect(g) => g.push_linework(segments, polygons),
+            Geometry::Triangle(g) => g.push_linework(segments, polygons),
+        }
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for GeometryCollection<T> {
+    fn push_linework(&self, segments: &mut Vec<Line<T>>, polygons: &mut Vec<Polygon<T>>) {
+        for geometry in self {
+            geometry.push_linework(segments, polygons);
+        }
+    }
+}
+
+/// The parameter along `line` of the projection of `coord`, from `0` at its start to `1` at
+/// its end.
+fn parameter<T: GeoFloat>(line: &Line<T>, coord: Coord<T>) -> T {
+    let delta = line.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+    if length_2 == T::zero() {
+        T::zero()
+    } else {
+        let offset = coord - line.start;
+        (offset.x * delta.x + offset.y * delta.y) / length_2
+    }
+}
+
+fn at<T: GeoFloat>(line: &Line<T>, t: T) -> Coord<T> {
+    line.start + line.delta() * t
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let delta = a - b;
+    delta.x.hypot(delta.y)
+}
+
+/// The distance from `coord` to `line`, and the point of `line` nearest to it.
+fn nearest_on<T: GeoFloat>(line: &Line<T>, coord: Coord<T>) -> (T, Coord<T>) {
+    let t = parameter(line, coord).max(T::zero()).min(T::one());
+    let nearest = at(line, t);
+    (distance(coord, nearest), nearest)
+}
+
+/// A part of a segment of the first geometry, with the distances from its ends to the
+/// second, and the segments of the second nearest to them.
+struct Interval<'a, T: GeoFloat> {
+    start: Coord<T>,
+    end: Coord<T>,
+    start_distance: T,
+    end_distance: T,
+    start_nearest: &'a Line<T>,
+    end_nearest: &'a Line<T>,
+}
+
+fn consider<T: GeoFloat>(
+    farthest: &mut DirectedHausdorff<T>,
+    distance: T,
+    point: Coord<T>,
+    nearest: Coord<T>,
+) {
+    if distance > farthest.distance {
+        *farthest = DirectedHausdorff {
+            distance,
+            point: point.into(),
+            nearest: nearest.into(),
+        };
+    }
+}
+
+fn directed_hausdorff<T, G, Rhs>(
+    geometry: &G,
+    rhs: &Rhs,
+    protrusion: bool,
+) -> Option<DirectedHausdorff<T>>
+where
+    T: GeoFloat + RTreeNum,
+    G: Linework<T> + ?Sized,
+    Rhs: Linework<T>,
+{
+    let (mut segments, mut polygons) = (Vec::new(), Vec::new());
+    geometry.push_linework(&mut segments, &mut polygons);
+    let (mut rhs_segments, mut rhs_polygons) = (Vec::new(), Vec::new());
+    rhs.push_linework(&mut rhs_segments, &mut rhs_polygons);
+    if segments.is_empty() || rhs_segments.is_empty() {
+        return None;
+    }
+    if !protrusion {
+        rhs_polygons.clear();
+    }
+    let tree = RTree::bulk_load(rhs_segments);
+
+    let nearest = |coord: Coord<T>| {
+        let line = tree.nearest_neighbor(&Point::from(coord)).unwrap();
+        let (distance, nearest) = nearest_on(line, coord);
+        (distance, nearest, line)
+    };
+    let inside_rhs = |coord: Coord<T>| {
+        rhs_polygons
+            .iter()
+            .any(|polygon| polygon.intersects(&coord))
+    };
+    // If no point is outside of the polygons of `rhs`, any point is the farthest
+    let mut farthest = DirectedHausdorff {
+        distance: T::zero(),
+        point: segments[0].start_point(),
+        nearest: segments[0].start_point(),
+    };
+
+    let two = T::one() + T::one();
+    let precision = T::epsilon().sqrt();
+    let mut stack = Vec::new();
+    for segment in &segments {
+        // Each part of the segment between the boundaries of polygons is inside or outside of
+        // them, as its middle is
+        let mut cuts = vec![T::zero(), T::one()];
+        if !rhs_polygons.is_empty() {
+            for other in tree.locate_in_envelope_intersecting(&segment.envelope()) {
+                match line_intersection(*segment, *other) {
+                    Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                        cuts.push(parameter(segment, intersection));
+                    }
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        cuts.push(parameter(segment, intersection.start));
+                        cuts.push(parameter(segment, intersection.end));
+                    }
+                    None => {}
+                }
+            }
+            cuts.sort_by(|a, b| a.total_cmp(b));
+            cuts.dedup();
+        }
+        let length = distance(segment.start, segment.end);
+        let tolerance = precision * length;
+        for cut in cuts.windows(2) {
+            let (start, end) = (at(segment, cut[0]), at(segment, cut[1]));
+            let middle = (start + end) / two;
+            if inside_rhs(middle) {
+                continue;
+            }
+            let (start_distance, start_point, start_nearest) = nearest(start);
+            let (end_distance, end_point, end_nearest) = nearest(end);
+            consider(&mut farthest, start_distance, start, start_point);
+            consider(&mut farthest, end_distance, end, end_point);
+            stack.push(Interval {
+                start,
+                end,
+                start_distance,
+                end_distance,
+                start_nearest,
+                end_nearest,
+            });
+            while let Some(interval) = stack.pop() {
+                let span = distance(interval.start, interval.end);
+                if span <= tolerance {
+                    continue;
+                }
+                // The distance to each segment is convex along the interval, so it's no more
+                // than at the farther end, and the distance to the nearest segment is no more
+                // than that to these, nor can it change faster than along the interval
+                let bound = [interval.start_nearest, interval.end_nearest]
+                    .into_iter()
+                    .map(|line| {
+                        nearest_on(line, interval.start)
+                            .0
+                            .max(nearest_on(line, interval.end).0)
+                    })
+                    .fold(
+                        (interval.start_distance + interval.end_distance + span) / two,
+                        T::min,
+                    );
+                if bound <= farthest.distance + tolerance {
+                    continue;
+                }
+                let middle = (interval.start + interval.end) / two;
+                let (middle_distance, middle_point, middle_nearest) = nearest(middle);
+                consider(&mut farthest, middle_distance, middle, middle_point);
+                stack.push(Interval {
+                    start: interval.start,
+                    end: middle,
+                    start_distance: interval.start_distance,
+                    end_distance: middle_distance,
+                    start_nearest: interval.start_nearest,
+                    end_nearest: middle_nearest,
+                });
+                stack.push(Interval {
+                    start: middle,
+                    end: interval.end,
+                    start_distance: middle_distance,
+                    end_distance: interval.end_distance,
+                    start_nearest: middle_nearest,
+                    end_nearest: interval.end_nearest,
+                });
+            }
+        }
+    }
+
+    // The farthest point inside a polygon of `self` can be away from its boundary, such as in
+    // the middle of a hole of `rhs`, so search its interior by cells, each of which is no
+    // farther than its centre is plus half of its diagonal
+    if protrusion {
+        let interior_precision = precision.sqrt();
+        for polygon in &polygons {
+            let Some(rect) = polygon.bounding_rect() else {
+                continue;
+            };
+            let half = rect.width().max(rect.height()) / two;
+            let tolerance = interior_precision * half * two;
+            let mut cells = vec![(rect.center(), half)];
+            while let Some((centre, half)) = cells.pop() {
+                let inside = polygon.intersects(&centre);
+                let reach = half * two.sqrt();
+                if !inside && polygon.euclidean_distance(&Point::from(centre)) > reach {
+                    continue;
+                }
+                // Within a polygon of `rhs`, a cell only reaches as far as it crosses its boundary
+                let (distance, nearest, _) = nearest(centre);
+                let (distance, nearest, bound) = if inside_rhs(centre) {
+                    (T::zero(), centre, reach - distance)
+                } else {
+                    (distance, nearest, distance + reach)
+                };
+                if inside {
+                    consider(&mut farthest, distance, centre, nearest);
+                }
+                if half <= tolerance || bound <= farthest.distance + tolerance {
+                    continue;
+                }
+                let quarter = half / two;
+                for (x, y) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                    let offset = coord! {
+                        x: quarter * T::from(x).unwrap(),
+                        y: quarter * T::from(y).unwrap(),
+                    };
+                    cells.push((centre + offset, quarter));
+                }
+            }
+        }
+    }
+    Some(farthest)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::HausdorffDistance;
-    use crate::{line_string, polygon, MultiPoint, MultiPolygon};
+    use crate::{line_string, point, polygon, MultiPoint, MultiPolygon};
+    use crate::{DirectedHausdorffDistance, EuclideanDistance, HausdorffDistance};
+    use crate::{Intersects, LineString, Point, Polygon};
 
     #[test]
     fn hd_mpnt_mpnt() {
@@ -133,4 +563,139 @@ mod test {
             epsilon = 1.0e-6
         )
     }
+
+    #[test]
+    fn directed_between_segments() {
+        let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let track = line_string![(x: 0., y: 0.), (x: 5., y: 3.), (x: 10., y: 0.)];
+        // The farthest vertices are farther apart than the lines
+        assert_relative_eq!(road.hausdorff_distance(&track), 34_f64.sqrt());
+        let there = road.directed_hausdorff(&track).unwrap();
+        assert_relative_eq!(there.distance, 15. / 34_f64.sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(there.point, point!(x: 5., y: 0.), epsilon = 1e-6);
+        assert_relative_eq!(
+            there.nearest.euclidean_distance(&there.point),
+            there.distance
+        );
+        let back = track.directed_hausdorff(&road).unwrap();
+        assert_relative_eq!(back.distance, 3.);
+        assert_eq!(back.point, point!(x: 5., y: 3.));
+        assert_eq!(back.nearest, point!(x: 5., y: 0.));
+
+        // The same lines are no distance apart
+        let same = track.directed_hausdorff(&track).unwrap();
+        assert_eq!(same.distance, 0.);
+        assert_eq!(same.point, same.nearest);
+    }
+
+    #[test]
+    fn protrusion() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let crossing = line_string![(x: -3., y: 5.), (x: 5., y: 5.), (x: 11., y: 5.)];
+        let protrusion = crossing.max_protrusion(&square).unwrap();
+        assert_eq!(protrusion.distance, 3.);
+        assert_eq!(protrusion.point, point!(x: -3., y: 5.));
+        assert_eq!(protrusion.nearest, point!(x: 0., y: 5.));
+        // Whereas the middle of the line is farther from the boundary
+        assert_eq!(crossing.directed_hausdorff(&square).unwrap().distance, 5.);
+
+        // A polygon inside another doesn't protrude at all
+        let inside = polygon![(x: 2., y: 2.), (x: 8., y: 2.), (x: 5., y: 8.)];
+        let protrusion = inside.max_protrusion(&square).unwrap();
+        assert_eq!(protrusion.distance, 0.);
+        assert_eq!(protrusion.point, protrusion.nearest);
+
+        // Nor does a polygon with holes, between them
+        let holes = Polygon::new(
+            square.exterior().clone(),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (2., 3.),
+                (3., 3.),
+                (3., 2.),
+            ])],
+        );
+        let sides = line_string![(x: 1., y: 9.), (x: 9., y: 9.), (x: 9., y: 1.)];
+        assert_eq!(sides.max_protrusion(&holes).unwrap().distance, 0.);
+        let across = line_string![(x: 1., y: 1.), (x: 4., y: 4.)];
+        let protrusion = across.max_protrusion(&holes).unwrap();
+        assert_relative_eq!(protrusion.distance, 0.5, epsilon = 1e-6);
+        assert_relative_eq!(protrusion.point, point!(x: 2.5, y: 2.5), epsilon = 1e-6);
+
+        // Whereas a polygon over a hole protrudes from its middle, away from its boundary
+        let hollow = Polygon::new(
+            square.exterior().clone(),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (8., 2.),
+                (8., 8.),
+                (2., 8.),
+            ])],
+        );
+        let over = polygon![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)];
+        let protrusion = over.max_protrusion(&hollow).unwrap();
+        assert_relative_eq!(protrusion.distance, 3., epsilon = 1e-3);
+        assert_relative_eq!(protrusion.point, point!(x: 5., y: 5.), epsilon = 1e-3);
+        assert_eq!(over.directed_hausdorff(&hollow).unwrap().distance, 1.);
+
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert!(crossing.max_protrusion(&empty).is_none());
+        assert!(empty.directed_hausdorff(&square).is_none());
+    }
+
+    #[test]
+    fn matches_sampling() {
+        let mut state = 7_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 10_000) as f64 / 1000.
+        };
+        for _ in 0..20 {
+            let a: LineString = (0..5).map(|_| (next(), next())).collect();
+            let b = polygon![
+                (x: next(), y: next()),
+                (x: next(), y: next()),
+                (x: next(), y: next()),
+            ];
+            // Densely along the line, the distances are no farther than the farthest, and
+            // nearly as far
+            let samples: Vec<Point> = a
+                .lines()
+                .flat_map(|line| {
+                    (0..=1000)
+                        .map(move |i| Point::from(line.start + line.delta() * (i as f64 / 1000.)))
+                })
+                .collect();
+            let spacing = a
+                .lines()
+                .map(|line| line.delta().x.hypot(line.delta().y))
+                .fold(0., f64::max)
+                / 1000.;
+            let directed = samples
+                .iter()
+                .map(|c| c.euclidean_distance(b.exterior()))
+                .fold(0., f64::max);
+            let protrusion = samples
+                .iter()
+                .map(|c| {
+                    if b.intersects(c) {
+                        0.
+                    } else {
+                        c.euclidean_distance(b.exterior())
+                    }
+                })
+                .fold(0., f64::max);
+            let there = a.directed_hausdorff(&b).unwrap();
+            assert!(there.distance >= directed - 1e-9);
+            assert!(there.distance <= directed + spacing);
+            assert_relative_eq!(
+                there.point.euclidean_distance(b.exterior()),
+                there.distance,
+                epsilon = 1e-9
+            );
+            let outside = a.max_protrusion(&b).unwrap();
+            assert!(outside.distance >= protrusion - 1e-9);
+            assert!(outside.distance <= protrusion + spacing);
+        }
+    }
 }
@@ -101,7 +101,7 @@ where
             return Closest::SinglePoint(p1);
         }
 
-        let pi = T::from(std::f64::consts::PI).unwrap();
+        let pi = T::from(core::f64::consts::PI).unwrap();
         let crs_ad = p1.haversine_bearing(*from).to_radians();
         let crs_ab = p1.haversine_bearing(p2).to_radians();
         let crs_ba = if crs_ab > T::zero() {
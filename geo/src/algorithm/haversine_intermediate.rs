@@ -1,4 +1,5 @@
 use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use alloc::{vec, vec::Vec};
 use num_traits::FromPrimitive;
 
 /// Returns a new Point along a great circle route between two existing points
@@ -0,0 +1,364 @@
+use crate::algorithm::nvector::NVector;
+use crate::line_intersection::LineIntersection;
+use crate::{GeoFloat, Line, Point};
+use alloc::{vec, vec::Vec};
+
+/// Calculate the intersection of two great-circle arcs on a sphere.
+///
+/// Unlike [`line_intersection`](crate::line_intersection::line_intersection), which treats
+/// longitude and latitude as planar coordinates, this follows the true shortest paths between
+/// the endpoints on a sphere, which for long segments can be very different.
+///
+/// Coordinates are in degrees, longitude (x) in the [-180; 180] range and latitude (y) in the
+/// [-90; 90] range. Each `Line` is taken to be the shorter of the two great-circle arcs between
+/// its endpoints.
+///
+/// The computations are performed on 3D unit vectors
+/// (see <https://www.movable-type.co.uk/scripts/latlong-vectors.html>).
+pub trait HaversineIntersection<T: GeoFloat> {
+    /// Return the intersection of the great-circle arcs `self` and `other`, if any.
+    ///
+    /// Arcs on the same great circle may overlap, in which case the overlapping arc is returned
+    /// as [`LineIntersection::Collinear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::line_intersection::LineIntersection;
+    /// use geo::{coord, HaversineIntersection, Line};
+    ///
+    /// // An arc along the 60th parallel bulges north on its way between the two points
+    /// let arc = Line::new(coord! { x: -60., y: 60. }, coord! { x: 60., y: 60. });
+    /// let meridian = Line::new(coord! { x: 0., y: 50. }, coord! { x: 0., y: 80. });
+    ///
+    /// match arc.haversine_intersection(&meridian) {
+    ///     Some(LineIntersection::SinglePoint { intersection, is_proper }) => {
+    ///         assert_relative_eq!(intersection.x, 0., epsilon = 1e-9);
+    ///         assert_relative_eq!(intersection.y, 73.897, epsilon = 1e-3);
+    ///         assert!(is_proper);
+    ///     }
+    ///     _ => panic!("expected a single intersection point"),
+    /// }
+    /// ```
+    fn haversine_intersection(&self, other: &Line<T>) -> Option<LineIntersection<T>>;
+}
+
+impl<T: GeoFloat> HaversineIntersection<T> for Line<T> {
+    fn haversine_intersection(&self, other: &Line<T>) -> Option<LineIntersection<T>> {
+        let a = Arc::new(self);
+        let b = Arc::new(other);
+
+        let (normal_a, normal_b) = match (a.normal, b.normal) {
+            (Some(normal_a), Some(normal_b)) => (normal_a, normal_b),
+            // At least one of the arcs is a single point
+            (None, _) => return b.contains(a.start).then(|| single_point(a.start, false)),
+            (_, None) => return a.contains(b.start).then(|| single_point(b.start, false)),
+        };
+
+        match normal_a.cross(normal_b).normalize() {
+            Some(crossing) => [crossing, -crossing]
+                .into_iter()
+                .find(|candidate| a.contains(*candidate) && b.contains(*candidate))
+                .map(|candidate| {
+                    // Snap to endpoints, so that arcs meeting at a shared vertex report exactly
+                    // that vertex.
+                    let endpoints = [a.line.start, a.line.end, b.line.start, b.line.end];
+                    match [a.start, a.end, b.start, b.end]
+                        .into_iter()
+                        .zip(endpoints)
+                        .find(|(endpoint, _)| endpoint.angle_to(candidate) <= tolerance())
+                    {
+                        Some((_, endpoint)) => LineIntersection::SinglePoint {
+                            intersection: endpoint,
+                            is_proper: false,
+                        },
+                        None => single_point(candidate, true),
+                    }
+                }),
+            None => {
+                // Both arcs lie on the same great circle, so they intersect in the endpoints that
+                // lie on both arcs.
+                let mut shared: Vec<NVector<T>> = vec![];
+                for endpoint in [a.start, a.end, b.start, b.end] {
+                    if a.contains(endpoint)
+                        && b.contains(endpoint)
+                        && shared
+                            .iter()
+                            .all(|other| other.angle_to(endpoint) > tolerance())
+                    {
+                        shared.push(endpoint);
+                    }
+                }
+                match shared[..] {
+                    [] => None,
+                    [point] => Some(single_point(point, false)),
+                    [first, second, ..] => {
+                        let (start, end) = if a.start.angle_to(first) <= a.start.angle_to(second) {
+                            (first, second)
+                        } else {
+                            (second, first)
+                        };
+                        Some(LineIntersection::Collinear {
+                            intersection: Line::new(start.to_point(), end.to_point()),
+                        })
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Calculate the intersection of two great circles, each given by a point on it and the bearing
+/// of the circle at that point.
+///
+/// Two distinct great circles intersect at two antipodal points. This returns the one ahead of
+/// both starting points, following their bearings, if there is one; otherwise, the one closest
+/// to the midpoint of the starting points.
+///
+/// Coordinates are longitude and latitude, and bearings are clockwise from north, all in degrees.
+///
+/// Returns `None` if the two great circles coincide, or if either point is at a pole, where
+/// bearings are undefined.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::haversine_intersection::haversine_bearing_intersection;
+/// use geo::point;
+///
+/// // From https://www.movable-type.co.uk/scripts/latlong.html
+/// let intersection = haversine_bearing_intersection(
+///     point!(x: 0.2545, y: 51.8853),
+///     108.55,
+///     point!(x: 2.5735, y: 49.0034),
+///     32.44,
+/// )
+/// .unwrap();
+///
+/// assert_relative_eq!(intersection, point!(x: 4.5084, y: 50.9078), epsilon = 1e-3);
+/// ```
+pub fn haversine_bearing_intersection<T: GeoFloat>(
+    point_a: Point<T>,
+    bearing_a: T,
+    point_b: Point<T>,
+    bearing_b: T,
+) -> Option<Point<T>> {
+    let a = NVector::from_point(point_a);
+    let b = NVector::from_point(point_b);
+    let direction_a = direction(a, bearing_a)?;
+    let direction_b = direction(b, bearing_b)?;
+
+    let crossing = a
+        .cross(direction_a)
+        .cross(b.cross(direction_b))
+        .normalize()?;
+    let ahead_a = direction_a.dot(crossing);
+    let ahead_b = direction_b.dot(crossing);
+    let intersection = if ahead_a > T::zero() && ahead_b > T::zero() {
+        crossing
+    } else if ahead_a < T::zero() && ahead_b < T::zero() {
+        -crossing
+    } else if (a + b).dot(crossing) >= T::zero() {
+        crossing
+    } else {
+        -crossing
+    };
+    Some(intersection.to_point())
+}
+
+/// The direction of travel at `point` when heading along `bearing` (in degrees), as a unit
+/// vector tangent to the sphere.
+fn direction<T: GeoFloat>(point: NVector<T>, bearing: T) -> Option<NVector<T>> {
+    let north_pole = NVector::new(T::zero(), T::zero(), T::one());
+    let east = north_pole.cross(point).normalize()?;
+    let north = point.cross(east);
+    let (sin, cos) = bearing.to_radians().sin_cos();
+    Some(north * cos + east * sin)
+}
+
+/// Angular tolerance, in radians, for deciding whether points coincide or lie on an arc.
+fn tolerance<T: GeoFloat>() -> T {
+    T::epsilon() * T::from(1000).unwrap()
+}
+
+fn single_point<T: GeoFloat>(point: NVector<T>, is_proper: bool) -> LineIntersection<T> {
+    LineIntersection::SinglePoint {
+        intersection: point.to_point().0,
+        is_proper,
+    }
+}
+
+/// A great-circle arc, as unit vectors.
+struct Arc<T: GeoFloat> {
+    line: Line<T>,
+    start: NVector<T>,
+    end: NVector<T>,
+    /// The unit normal of the arc's great circle, or `None` if the arc is a single point.
+    normal: Option<NVector<T>>,
+}
+
+impl<T: GeoFloat> Arc<T> {
+    fn new(line: &Line<T>) -> Self {
+        let start = NVector::from_point(line.start_point());
+        let end = NVector::from_point(line.end_point());
+        let normal = if start.angle_to(end) <= tolerance() {
+            None
+        } else {
+            start.cross(end).normalize()
+        };
+        Arc {
+            line: *line,
+            start,
+            end,
+            normal,
+        }
+    }
+
+    /// Whether `point` lies on the arc.
+    fn contains(&self, point: NVector<T>) -> bool {
+        let tolerance = tolerance();
+        match self.normal {
+            None => self.start.angle_to(point) <= tolerance,
+            Some(normal) => {
+                normal.dot(point).abs() <= tolerance
+                    && self.start.cross(point).dot(normal) >= -tolerance
+                    && point.cross(self.end).dot(normal) >= -tolerance
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point};
+
+    fn line(start: (f64, f64), end: (f64, f64)) -> Line<f64> {
+        Line::new(
+            coord! { x: start.0, y: start.1 },
+            coord! { x: end.0, y: end.1 },
+        )
+    }
+
+    fn single(intersection: Option<LineIntersection<f64>>) -> (Point<f64>, bool) {
+        match intersection {
+            Some(LineIntersection::SinglePoint {
+                intersection,
+                is_proper,
+            }) => (intersection.into(), is_proper),
+            other => panic!("expected a single point, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn crossing_arcs() {
+        let equator = line((-10., 0.), (10., 0.));
+        let meridian = line((0., -10.), (0., 10.));
+        let (point, is_proper) = single(equator.haversine_intersection(&meridian));
+        assert_relative_eq!(point, point!(x: 0., y: 0.), epsilon = 1e-12);
+        assert!(is_proper);
+    }
+
+    #[test]
+    fn disjoint_arcs() {
+        let a = line((-10., 0.), (10., 0.));
+        let b = line((0., 5.), (0., 10.));
+        assert_eq!(a.haversine_intersection(&b), None);
+
+        // The great circles intersect on the far side of the sphere
+        let c = line((170., -10.), (170., 10.));
+        assert_eq!(a.haversine_intersection(&c), None);
+    }
+
+    #[test]
+    fn crossing_the_antimeridian() {
+        let a = line((170., 10.), (-170., -10.));
+        let b = line((170., -10.), (-170., 10.));
+        let (point, is_proper) = single(a.haversine_intersection(&b));
+        assert!(is_proper);
+        assert_relative_eq!(point.x().abs(), 180., epsilon = 1e-9);
+        assert_relative_eq!(point.y(), 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn shared_endpoint() {
+        let a = line((-10., 20.), (15., 30.));
+        let b = line((15., 30.), (40., -5.));
+        let (point, is_proper) = single(a.haversine_intersection(&b));
+        assert_eq!(point, point!(x: 15., y: 30.));
+        assert!(!is_proper);
+    }
+
+    #[test]
+    fn overlapping_arcs() {
+        let a = line((0., 0.), (20., 0.));
+        let b = line((30., 0.), (10., 0.));
+        match a.haversine_intersection(&b) {
+            Some(LineIntersection::Collinear { intersection }) => {
+                assert_relative_eq!(intersection.start_point(), point!(x: 10., y: 0.));
+                assert_relative_eq!(intersection.end_point(), point!(x: 20., y: 0.));
+            }
+            other => panic!("expected an overlap, got {other:?}"),
+        }
+
+        let c = line((20., 0.), (25., 0.));
+        let (point, _) = single(a.haversine_intersection(&c));
+        assert_relative_eq!(point, point!(x: 20., y: 0.));
+
+        let d = line((25., 0.), (30., 0.));
+        assert_eq!(a.haversine_intersection(&d), None);
+    }
+
+    #[test]
+    fn degenerate_arcs() {
+        let a = line((-10., 0.), (10., 0.));
+        let on = line((5., 0.), (5., 0.));
+        let (point, is_proper) = single(a.haversine_intersection(&on));
+        assert_relative_eq!(point, point!(x: 5., y: 0.));
+        assert!(!is_proper);
+        let off = line((5., 1.), (5., 1.));
+        assert_eq!(a.haversine_intersection(&off), None);
+        assert_eq!(off.haversine_intersection(&a), None);
+    }
+
+    #[test]
+    fn bearings() {
+        // Heading east along the equator and north along the prime meridian
+        let intersection =
+            haversine_bearing_intersection(point!(x: -20., y: 0.), 90., point!(x: 0., y: -20.), 0.)
+                .unwrap();
+        assert_relative_eq!(intersection, point!(x: 0., y: 0.), epsilon = 1e-12);
+
+        // Heading west and south, both reach the antipodal intersection first
+        let intersection: Point = haversine_bearing_intersection(
+            point!(x: -20., y: 0.),
+            270.,
+            point!(x: 0., y: -20.),
+            180.,
+        )
+        .unwrap();
+        assert_relative_eq!(intersection.x().abs(), 180., epsilon = 1e-9);
+        assert_relative_eq!(intersection.y(), 0., epsilon = 1e-9);
+
+        // Heading towards different intersections, the nearer one is returned
+        let intersection = haversine_bearing_intersection(
+            point!(x: -20., y: 0.),
+            90.,
+            point!(x: 0., y: -20.),
+            180.,
+        )
+        .unwrap();
+        assert_relative_eq!(intersection, point!(x: 0., y: 0.), epsilon = 1e-12);
+
+        // Coincident great circles
+        assert!(haversine_bearing_intersection(
+            point!(x: -20., y: 0.),
+            90.,
+            point!(x: 0., y: 0.),
+            90.
+        )
+        .is_none());
+    }
+}
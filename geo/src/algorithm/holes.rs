@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+
+use crate::area::get_linestring_area;
+use crate::{CoordFloat, Polygon, RingAreas};
+
+/// Measure and fill in the holes of a `Polygon`.
+///
+/// To remove holes by their position, or by any other test, use
+/// [`Polygon::remove_interior`] and [`Polygon::retain_interiors`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Holes};
+///
+/// let mut polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 1., y: 5.), (x: 5., y: 5.), (x: 5., y: 1.)],
+///         [(x: 7., y: 7.), (x: 8., y: 7.), (x: 8., y: 8.), (x: 7., y: 8.)],
+///     ],
+/// ];
+/// assert_eq!(polygon.hole_areas(), vec![16., 1.]);
+///
+/// polygon.fill_holes(1.);
+/// assert_eq!(polygon.hole_areas(), vec![16.]);
+/// ```
+pub trait Holes<T: CoordFloat> {
+    /// The area enclosed by each interior ring, in their order.
+    fn hole_areas(&self) -> Vec<T>;
+
+    /// Remove the interior rings which enclose an area of at most `max_area`.
+    fn fill_holes(&mut self, max_area: T);
+}
+
+impl<T: CoordFloat> Holes<T> for Polygon<T> {
+    fn hole_areas(&self) -> Vec<T> {
+        // The first ring is the exterior
+        self.ring_signed_areas()
+            .into_iter()
+            .skip(1)
+            .map(|area| area.abs())
+            .collect()
+    }
+
+    fn fill_holes(&mut self, max_area: T) {
+        self.retain_interiors(|interior| get_linestring_area(&interior.0).abs() > max_area);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, LineString};
+
+    #[test]
+    fn holes() {
+        let mut polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                // Wound either way
+                [(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+                [(x: 4., y: 4.), (x: 4., y: 5.), (x: 5., y: 5.), (x: 5., y: 4.)],
+                [(x: 6., y: 6.), (x: 6., y: 9.), (x: 9., y: 9.), (x: 9., y: 6.)],
+            ],
+        ];
+        assert_eq!(polygon.hole_areas(), vec![4., 1., 9.]);
+
+        polygon.fill_holes(0.5);
+        assert_eq!(polygon.hole_areas().len(), 3);
+        polygon.fill_holes(4.);
+        assert_eq!(polygon.hole_areas(), vec![9.]);
+
+        let mut no_holes =
+            Polygon::new(LineString::from(vec![(0., 0.), (1., 0.), (0., 1.)]), vec![]);
+        assert!(no_holes.hole_areas().is_empty());
+        no_holes.fill_holes(1.);
+        assert_eq!(no_holes.exterior().0.len(), 4);
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+use crate::{BoundingRect, CoordNum, Intersects, Rect};
+
+/// A set of geometries, such as the features of a layer, indexed once to test many other
+/// geometries against, for whether they intersect any of them, the first of them, or all of
+/// them.
+///
+/// The bounding rectangles of the geometries are indexed in an R-tree, so that a query is only
+/// tested with [`Intersects`] against the geometries whose bounding rectangles intersect its
+/// own, and [`intersects_any`](Self::intersects_any) and
+/// [`first_intersecting`](Self::first_intersecting) stop at the first geometry it intersects.
+/// Empty geometries, which have no bounding rectangle, never intersect anything.
+///
+/// Geometries are referred to by their indices, in the order they were given.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, IntersectionIndex, Polygon};
+///
+/// let parcels: Vec<Polygon> = (0..10)
+///     .map(|i| {
+///         let x = f64::from(i) * 10.;
+///         polygon![(x: x, y: 0.), (x: x + 8., y: 0.), (x: x + 8., y: 8.), (x: x, y: 8.)]
+///     })
+///     .collect();
+/// let index = IntersectionIndex::new(parcels);
+///
+/// // A road along the gap between the first two parcels, and then across the next two
+/// let road = line_string![(x: 9., y: -5.), (x: 9., y: 5.), (x: 29., y: 5.)];
+/// assert!(index.intersects_any(&road));
+/// assert_eq!(index.first_intersecting(&road).map(|(i, _)| i), Some(1));
+/// assert_eq!(index.all_intersecting(&road), [1, 2]);
+///
+/// let path = line_string![(x: 9., y: -5.), (x: 9., y: 15.)];
+/// assert!(!index.intersects_any(&path));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntersectionIndex<G, T: CoordNum + RTreeNum = f64> {
+    geometries: Vec<G>,
+    tree: RTree<GeomWithData<Rectangle<[T; 2]>, usize>>,
+}
+
+impl<G, T> IntersectionIndex<G, T>
+where
+    T: CoordNum + RTreeNum,
+    G: BoundingRect<T>,
+{
+    /// Index `geometries`.
+    pub fn new<I>(geometries: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        let geometries: Vec<G> = geometries.into_iter().collect();
+        let rectangles = geometries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, geometry)| {
+                let rect = geometry.bounding_rect().into()?;
+                let rectangle = Rectangle::from_corners(rect.min().into(), rect.max().into());
+                Some(GeomWithData::new(rectangle, index))
+            })
+            .collect();
+        IntersectionIndex {
+            geometries,
+            tree: RTree::bulk_load(rectangles),
+        }
+    }
+}
+
+impl<G, T: CoordNum + RTreeNum> IntersectionIndex<G, T> {
+    /// Whether `query` intersects any of the geometries.
+    pub fn intersects_any<Q>(&self, query: &Q) -> bool
+    where
+        Q: BoundingRect<T>,
+        G: Intersects<Q>,
+    {
+        let Some(envelope) = envelope(query) else {
+            return false;
+        };
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .any(|candidate| self.geometries[candidate.data].intersects(query))
+    }
+
+    /// The geometry with the least index which `query` intersects, and its index.
+    ///
+    /// The geometries whose bounding rectangles intersect that of `query` are tested in order,
+    /// up to the first which it intersects.
+    pub fn first_intersecting<Q>(&self, query: &Q) -> Option<(usize, &G)>
+    where
+        Q: BoundingRect<T>,
+        G: Intersects<Q>,
+    {
+        self.candidates(query)
+            .into_iter()
+            .map(|index| (index, &self.geometries[index]))
+            .find(|(_, geometry)| geometry.intersects(query))
+    }
+
+    /// The indices of the geometries which `query` intersects, in order.
+    pub fn all_intersecting<Q>(&self, query: &Q) -> Vec<usize>
+    where
+        Q: BoundingRect<T>,
+        G: Intersects<Q>,
+    {
+        let mut intersecting = self.candidates(query);
+        intersecting.retain(|&index| self.geometries[index].intersects(query));
+        intersecting
+    }
+
+    /// The indices of the geometries whose bounding rectangles intersect that of `query`, in
+    /// order.
+    fn candidates<Q: BoundingRect<T>>(&self, query: &Q) -> Vec<usize> {
+        let Some(envelope) = envelope(query) else {
+            return vec![];
+        };
+        let mut candidates: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|candidate| candidate.data)
+            .collect();
+        candidates.sort_unstable();
+        candidates
+    }
+
+    /// The geometry at `index`, if there is one.
+    pub fn get(&self, index: usize) -> Option<&G> {
+        self.geometries.get(index)
+    }
+
+    /// The geometries, in order.
+    pub fn geometries(&self) -> &[G] {
+        &self.geometries
+    }
+
+    /// The number of geometries.
+    pub fn len(&self) -> usize {
+        self.geometries.len()
+    }
+
+    /// Whether there are no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.geometries.is_empty()
+    }
+}
+
+fn envelope<T, Q>(query: &Q) -> Option<AABB<[T; 2]>>
+where
+    T: CoordNum + RTreeNum,
+    Q: BoundingRect<T>,
+{
+    let rect: Rect<T> = query.bounding_rect().into()?;
+    Some(AABB::from_corners(rect.min().into(), rect.max().into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Geometry, LineString, MultiPolygon, Point};
+
+    #[test]
+    fn matches_brute_force() {
+        // Triangles from a linear congruential generator
+        let mut state = 42_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 1000) as f64 / 10.
+        };
+        let mut triangles = vec![];
+        for _ in 0..200 {
+            let (x, y) = (next(), next());
+            let (width, height) = (next() / 5., next() / 5.);
+            triangles.push(polygon![(x: x, y: y), (x: x + width, y: y), (x: x, y: y + height)]);
+        }
+        let index = IntersectionIndex::new(triangles.clone());
+        assert_eq!(index.len(), 200);
+
+        for _ in 0..100 {
+            let query = point!(x: next(), y: next());
+            let expected: Vec<usize> = (0..triangles.len())
+                .filter(|&i| triangles[i].intersects(&query))
+                .collect();
+            assert_eq!(index.all_intersecting(&query), expected);
+            assert_eq!(index.intersects_any(&query), !expected.is_empty());
+            assert_eq!(
+                index.first_intersecting(&query).map(|(i, _)| i),
+                expected.first().copied()
+            );
+        }
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let index: IntersectionIndex<Geometry> = IntersectionIndex::new([
+            Geometry::MultiPolygon(MultiPolygon::new(vec![])),
+            Geometry::Point(point!(x: 1., y: 1.)),
+        ]);
+        assert_eq!(index.all_intersecting(&point!(x: 1., y: 1.)), [1]);
+        assert!(!index.intersects_any(&LineString::<f64>::new(vec![])));
+        assert_eq!(index.get(1), Some(&Geometry::Point(point!(x: 1., y: 1.))));
+
+        let empty = IntersectionIndex::<Point>::new([]);
+        assert!(empty.is_empty());
+        assert!(empty.first_intersecting(&point!(x: 0., y: 0.)).is_none());
+    }
+}
@@ -1,26 +1,167 @@
+use super::monotone_chain::{segment_coords, use_chains, MonotoneChains};
 use super::{has_disjoint_bboxes, Intersects};
+use crate::coordinate_position::CoordPos;
 use crate::BoundingRect;
 use crate::*;
 
-// Blanket implementation using self.lines().any().
+// Seal the trait which tests the segments of a line string together, so that its method isn't
+// on the public interface.
+mod private {
+    use super::*;
+
+    pub trait SegmentsIntersect<Rhs>: Intersects<Rhs> + Sized {
+        /// Whether any of `segments`, the consecutive segments of a line string, intersects
+        /// `rhs`.
+        fn any_segment_intersects<I>(mut segments: I, rhs: &Rhs) -> bool
+        where
+            I: ExactSizeIterator<Item = Self>,
+        {
+            segments.any(|segment| segment.intersects(rhs))
+        }
+    }
+}
+
+use private::SegmentsIntersect;
+
+// Geometries without a faster test than testing each segment in turn.
+macro_rules! segments_intersect_impl {
+    ($($k:ty),*) => {
+        $(
+            impl<T> SegmentsIntersect<$k> for Line<T>
+            where
+                T: CoordNum,
+                Line<T>: Intersects<$k>,
+            {
+            }
+        )*
+    };
+}
+segments_intersect_impl!(
+    Coord<T>,
+    Point<T>,
+    MultiPoint<T>,
+    Line<T>,
+    MultiPolygon<T>,
+    Rect<T>,
+    Triangle<T>,
+    Geometry<T>,
+    GeometryCollection<T>
+);
+
+// Blanket implementation using Line<T>::any_segment_intersects, which tests
+// each segment in turn unless there is a faster test for G.
 impl<T, G> Intersects<G> for LineString<T>
 where
     T: CoordNum,
-    Line<T>: Intersects<G>,
+    Line<T>: SegmentsIntersect<G>,
     G: BoundingRect<T>,
 {
     fn intersects(&self, geom: &G) -> bool {
         if has_disjoint_bboxes(self, geom) {
             return false;
         }
-        self.lines().any(|l| l.intersects(geom))
+        Line::any_segment_intersects(self.lines(), geom)
     }
 }
 symmetric_intersects_impl!(Coord<T>, LineString<T>);
-symmetric_intersects_impl!(Line<T>, LineString<T>);
 symmetric_intersects_impl!(Rect<T>, LineString<T>);
 symmetric_intersects_impl!(Triangle<T>, LineString<T>);
 
+// Large geometries are compared using monotone chains, which avoids testing every pair of
+// segments.
+impl<T> Intersects<LineString<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, line_string: &LineString<T>) -> bool {
+        line_string.intersects(self)
+    }
+}
+
+impl<T> SegmentsIntersect<LineString<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn any_segment_intersects<I>(mut segments: I, line_string: &LineString<T>) -> bool
+    where
+        I: ExactSizeIterator<Item = Self>,
+    {
+        if !use_chains(segments.len(), line_string.lines().len()) {
+            return segments.any(|segment| segment.intersects(line_string));
+        }
+        let coords = segment_coords(segments);
+        MonotoneChains::new(&coords).intersects(&MonotoneChains::new(&line_string.0))
+    }
+}
+
+impl<T> Intersects<MultiLineString<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, multi_line_string: &MultiLineString<T>) -> bool {
+        multi_line_string.intersects(self)
+    }
+}
+
+impl<T> SegmentsIntersect<MultiLineString<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn any_segment_intersects<I>(mut segments: I, multi_line_string: &MultiLineString<T>) -> bool
+    where
+        I: ExactSizeIterator<Item = Self>,
+    {
+        let len = segments.len();
+        if !multi_line_string
+            .iter()
+            .any(|line_string| use_chains(len, line_string.lines().len()))
+        {
+            return segments.any(|segment| segment.intersects(multi_line_string));
+        }
+        // Collect the segments once, rather than for each line string
+        let line_string = LineString::new(segment_coords(segments));
+        multi_line_string
+            .iter()
+            .any(|other| line_string.intersects(other))
+    }
+}
+
+impl<T> Intersects<Polygon<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, polygon: &Polygon<T>) -> bool {
+        polygon.intersects(self)
+    }
+}
+
+impl<T> SegmentsIntersect<Polygon<T>> for Line<T>
+where
+    T: GeoNum,
+{
+    fn any_segment_intersects<I>(mut segments: I, polygon: &Polygon<T>) -> bool
+    where
+        I: ExactSizeIterator<Item = Self>,
+    {
+        let len = segments.len();
+        let rings = || core::iter::once(polygon.exterior()).chain(polygon.interiors());
+        if !rings().any(|ring| use_chains(len, ring.lines().len())) {
+            return segments.any(|segment| segment.intersects(polygon));
+        }
+
+        let coords = segment_coords(segments);
+        let chains = MonotoneChains::new(&coords);
+        if rings().any(|ring| chains.intersects(&MonotoneChains::new(&ring.0))) {
+            return true;
+        }
+        // Without crossing the polygon's boundary, the line string is either entirely inside
+        // or entirely outside it.
+        coords
+            .first()
+            .is_some_and(|coord| polygon.coordinate_position(coord) != CoordPos::Outside)
+    }
+}
+
 // Blanket implementation from LineString<T>
 impl<T, G> Intersects<G> for MultiLineString<T>
 where
@@ -37,6 +178,5 @@ where
 }
 
 symmetric_intersects_impl!(Point<T>, MultiLineString<T>);
-symmetric_intersects_impl!(Line<T>, MultiLineString<T>);
 symmetric_intersects_impl!(Rect<T>, MultiLineString<T>);
 symmetric_intersects_impl!(Triangle<T>, MultiLineString<T>);
@@ -66,19 +66,24 @@ macro_rules! symmetric_intersects_impl {
 
 mod collections;
 mod coordinate;
+mod index;
 mod line;
 mod line_string;
+mod monotone_chain;
 mod point;
 mod polygon;
 mod rect;
 mod triangle;
 
+pub use index::IntersectionIndex;
+pub(crate) use monotone_chain::MIN_SEGMENT_PAIRS;
+
 // Helper function to check value lies between min and max.
 // Only makes sense if min <= max (or always false)
 #[inline]
 fn value_in_range<T>(value: T, min: T, max: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     value >= min && value <= max
 }
@@ -88,7 +93,7 @@ where
 #[inline]
 pub(crate) fn value_in_between<T>(value: T, bound_1: T, bound_2: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     if bound_1 < bound_2 {
         value_in_range(value, bound_1, bound_2)
@@ -513,6 +518,43 @@ mod test {
         assert!(!poly2.intersects(&line0));
     }
     #[test]
+    fn large_geometries_intersect_test() {
+        use crate::Translate;
+
+        let louisiana: LineString = geo_test_fixtures::louisiana();
+        let polygon = Polygon::new(louisiana.clone(), vec![]);
+        for (dx, dy) in [(0., 0.), (0.3, -0.2), (1.5, 1.), (4., 0.), (20., 0.)] {
+            let moved = louisiana.translate(dx, dy);
+            let all_pairs = louisiana
+                .lines()
+                .any(|a| moved.lines().any(|b| a.intersects(&b)));
+            assert_eq!(louisiana.intersects(&moved), all_pairs, "{dx} {dy}");
+            assert_eq!(polygon.intersects(&moved), all_pairs, "{dx} {dy}");
+            assert_eq!(
+                polygon.intersects(&Polygon::new(moved, vec![])),
+                all_pairs,
+                "{dx} {dy}"
+            );
+        }
+
+        // Contained without crossing the boundary
+        let circle = |radius: f64| -> LineString {
+            let center: Point = geo_test_fixtures::baton_rouge();
+            (0..=200)
+                .map(|i| {
+                    let (sin, cos) = (i as f64 * core::f64::consts::TAU / 200.).sin_cos();
+                    coord! { x: center.x() + radius * cos, y: center.y() + radius * sin }
+                })
+                .collect()
+        };
+        assert!(!louisiana.intersects(&circle(0.1)));
+        assert!(polygon.intersects(&circle(0.1)));
+        let with_hole = Polygon::new(louisiana.clone(), vec![circle(0.1)]);
+        assert!(!with_hole.intersects(&circle(0.05)));
+        assert!(with_hole.intersects(&Polygon::new(circle(0.1), vec![])));
+        assert!(!with_hole.intersects(&Polygon::new(circle(0.05), vec![circle(0.01)])));
+    }
+    #[test]
     // See https://github.com/georust/geo/issues/419
     fn rect_test_419() {
         let a = Rect::new(
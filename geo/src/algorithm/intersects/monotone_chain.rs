@@ -0,0 +1,193 @@
+use super::Intersects;
+use crate::{Coord, GeoNum, Line, Rect};
+use alloc::{vec, vec::Vec};
+
+/// The number of pairs of segments above which two geometries are tested for intersection using
+/// monotone chains, rather than by testing every pair.
+pub(crate) const MIN_SEGMENT_PAIRS: usize = 1024;
+
+/// Whether testing geometries with `a` and `b` segments for intersection using monotone chains
+/// is likely to be faster than testing every pair of their segments.
+pub(super) fn use_chains(a: usize, b: usize) -> bool {
+    a.saturating_mul(b) > MIN_SEGMENT_PAIRS
+}
+
+/// The coordinates of `segments`, the consecutive segments of a line string.
+pub(super) fn segment_coords<T: GeoNum>(
+    segments: impl ExactSizeIterator<Item = Line<T>>,
+) -> Vec<Coord<T>> {
+    let mut coords = Vec::with_capacity(segments.len() + 1);
+    for segment in segments {
+        if coords.is_empty() {
+            coords.push(segment.start);
+        }
+        coords.push(segment.end);
+    }
+    coords
+}
+
+/// The coordinates of a sequence of segments, split into chains along which both `x` and `y`
+/// are monotone, like the monotone chains of JTS.
+///
+/// The bounding rectangle of any part of a chain is that of its first and last coordinates,
+/// so two chains can be searched for intersecting segments by bisection, in time logarithmic in
+/// their lengths when their segments don't intersect.
+pub(super) struct MonotoneChains<'a, T: GeoNum> {
+    /// Chains, with their bounding rectangles, sorted by their minimum `x`.
+    chains: Vec<(Rect<T>, &'a [Coord<T>])>,
+}
+
+impl<'a, T: GeoNum> MonotoneChains<'a, T> {
+    pub(super) fn new(coords: &'a [Coord<T>]) -> Self {
+        let mut chains = vec![];
+        let mut start = 0;
+        while start + 1 < coords.len() {
+            let heading = direction(coords[start], coords[start + 1]);
+            let mut end = start + 1;
+            while end + 1 < coords.len() && direction(coords[end], coords[end + 1]) == heading {
+                end += 1;
+            }
+            let chain = &coords[start..=end];
+            chains.push((bounds(chain), chain));
+            start = end;
+        }
+        chains.sort_unstable_by(|(a, _), (b, _)| {
+            a.min()
+                .x
+                .partial_cmp(&b.min().x)
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        MonotoneChains { chains }
+    }
+
+    /// Whether any segment of `self` intersects any segment of `other`.
+    ///
+    /// The chains are swept in order of their minimum `x`, so that each is only tested against
+    /// the chains of `other` which overlap it along the `x` axis.
+    pub(super) fn intersects(&self, other: &MonotoneChains<T>) -> bool {
+        let (mut ours, mut theirs) = (
+            self.chains.iter().peekable(),
+            other.chains.iter().peekable(),
+        );
+        let mut active_ours: Vec<&(Rect<T>, &[Coord<T>])> = vec![];
+        let mut active_theirs: Vec<&(Rect<T>, &[Coord<T>])> = vec![];
+
+        loop {
+            let next_is_ours = match (ours.peek(), theirs.peek()) {
+                (Some((a, _)), Some((b, _))) => a.min().x <= b.min().x,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => return false,
+            };
+            let (next, active, others) = if next_is_ours {
+                (ours.next().unwrap(), &mut active_ours, &mut active_theirs)
+            } else {
+                (theirs.next().unwrap(), &mut active_theirs, &mut active_ours)
+            };
+
+            let (bounds, chain) = next;
+            others.retain(|(other_bounds, _)| other_bounds.max().x >= bounds.min().x);
+            if others.iter().any(|(other_bounds, other)| {
+                bounds.intersects(other_bounds) && chains_intersect(chain, other)
+            }) {
+                return true;
+            }
+            active.push(next);
+        }
+    }
+}
+
+/// Whether the monotone chains `a` and `b` have any intersecting segments.
+fn chains_intersect<T: GeoNum>(a: &[Coord<T>], b: &[Coord<T>]) -> bool {
+    if !bounds(a).intersects(&bounds(b)) {
+        return false;
+    }
+    match (a, b) {
+        ([a_start, a_end], [b_start, b_end]) => {
+            Line::new(*a_start, *a_end).intersects(&Line::new(*b_start, *b_end))
+        }
+        _ if a.len() >= b.len() => {
+            let middle = a.len() / 2;
+            chains_intersect(&a[..=middle], b) || chains_intersect(&a[middle..], b)
+        }
+        _ => {
+            let middle = b.len() / 2;
+            chains_intersect(a, &b[..=middle]) || chains_intersect(a, &b[middle..])
+        }
+    }
+}
+
+/// The bounding rectangle of a monotone chain.
+fn bounds<T: GeoNum>(chain: &[Coord<T>]) -> Rect<T> {
+    Rect::new(chain[0], chain[chain.len() - 1])
+}
+
+/// Whether a segment is non-decreasing in `x` and in `y`.
+fn direction<T: GeoNum>(start: Coord<T>, end: Coord<T>) -> (bool, bool) {
+    (end.x >= start.x, end.y >= start.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, LineString};
+
+    fn zigzag(n: usize, offset: f64, phase: usize) -> LineString {
+        (0..n)
+            .map(|i| coord! { x: i as f64, y: offset + ((i + phase) % 2) as f64 })
+            .collect()
+    }
+
+    #[test]
+    fn chains_are_monotone() {
+        let spiral: LineString = (0..200)
+            .map(|i| {
+                let (sin, cos) = (i as f64 / 10.).sin_cos();
+                coord! { x: i as f64 * cos, y: i as f64 * sin }
+            })
+            .collect();
+        let chains = MonotoneChains::new(&spiral.0);
+        assert!(chains.chains.len() > 4);
+        assert_eq!(
+            chains
+                .chains
+                .iter()
+                .map(|(_, chain)| chain.len() - 1)
+                .sum::<usize>(),
+            199
+        );
+        for (bounds, chain) in &chains.chains {
+            for coord in chain.iter() {
+                assert!(bounds.intersects(coord));
+            }
+        }
+    }
+
+    #[test]
+    fn sweep_matches_all_pairs() {
+        let a = zigzag(100, 0., 0);
+        for (offset, expected) in [
+            (0., true),
+            (0.5, true),
+            (1., true),
+            (1.1, false),
+            (-1., true),
+            (-1.1, false),
+        ] {
+            let b = zigzag(100, offset, 1);
+            let all_pairs = a.lines().any(|a| b.lines().any(|b| a.intersects(&b)));
+            assert_eq!(all_pairs, expected, "{offset}");
+            assert_eq!(
+                MonotoneChains::new(&a.0).intersects(&MonotoneChains::new(&b.0)),
+                expected,
+                "{offset}"
+            );
+        }
+        // In phase, the segments are parallel
+        let b = zigzag(100, 0.5, 0);
+        assert!(!MonotoneChains::new(&a.0).intersects(&MonotoneChains::new(&b.0)));
+
+        let empty = MonotoneChains::new(&[]);
+        assert!(!empty.intersects(&MonotoneChains::new(&a.0)));
+    }
+}
@@ -1,5 +1,6 @@
 use super::{has_disjoint_bboxes, Intersects};
 use crate::coordinate_position::CoordPos;
+use crate::view::PolygonRef;
 use crate::{BoundingRect, CoordinatePosition};
 use crate::{
     Coord, CoordNum, GeoNum, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect,
@@ -17,6 +18,24 @@ where
 symmetric_intersects_impl!(Coord<T>, Polygon<T>);
 symmetric_intersects_impl!(Polygon<T>, Point<T>);
 
+impl<T> Intersects<Coord<T>> for PolygonRef<'_, T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, p: &Coord<T>) -> bool {
+        self.coordinate_position(p) != CoordPos::Outside
+    }
+}
+
+impl<T> Intersects<Point<T>> for PolygonRef<'_, T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        self.intersects(&p.0)
+    }
+}
+
 impl<T> Intersects<Line<T>> for Polygon<T>
 where
     T: GeoNum,
@@ -28,7 +47,6 @@ where
             || self.intersects(&line.end)
     }
 }
-symmetric_intersects_impl!(Line<T>, Polygon<T>);
 symmetric_intersects_impl!(Polygon<T>, LineString<T>);
 symmetric_intersects_impl!(Polygon<T>, MultiLineString<T>);
 
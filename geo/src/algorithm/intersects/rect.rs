@@ -1,4 +1,5 @@
 use super::Intersects;
+use crate::bool_ops::{ccw_rect, ccw_triangle, separated};
 use crate::*;
 
 impl<T> Intersects<Coord<T>> for Rect<T>
@@ -68,7 +69,10 @@ where
     T: GeoNum,
 {
     fn intersects(&self, rhs: &Triangle<T>) -> bool {
-        self.intersects(&rhs.to_polygon())
+        match (ccw_rect(self), ccw_triangle(rhs)) {
+            (Some(a), Some(b)) => !separated(&a, &b),
+            _ => self.intersects(&rhs.to_polygon()),
+        }
     }
 }
 symmetric_intersects_impl!(Triangle<T>, Rect<T>);
@@ -1,4 +1,5 @@
 use super::Intersects;
+use crate::bool_ops::{ccw_triangle, separated};
 use crate::*;
 
 impl<T> Intersects<Coord<T>> for Triangle<T>
@@ -47,6 +48,9 @@ where
     T: GeoNum,
 {
     fn intersects(&self, rhs: &Triangle<T>) -> bool {
-        self.to_polygon().intersects(&rhs.to_polygon())
+        match (ccw_triangle(self), ccw_triangle(rhs)) {
+            (Some(a), Some(b)) => !separated(&a, &b),
+            _ => self.to_polygon().intersects(&rhs.to_polygon()),
+        }
     }
 }
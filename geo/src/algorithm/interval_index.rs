@@ -0,0 +1,245 @@
+use alloc::vec::Vec;
+
+/// An interval of an [`IntervalIndex`], from `start` to `end` inclusive, with its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T, D> {
+    /// The least measure in the interval.
+    pub start: T,
+    /// The greatest measure in the interval, which is at least `start`.
+    pub end: T,
+    pub data: D,
+}
+
+/// An index of one dimensional intervals, such as ranges of measures along a [`Route`], or the
+/// time ranges of parts of trajectories, to find those which contain a measure, or overlap a
+/// range of measures.
+///
+/// This complements the R-trees used to index geometries in two dimensions: events along a
+/// road, such as speed limits or roadworks, are intervals of its mileposts, and the events at a
+/// location are those whose intervals contain the measure it's
+/// [projected](crate::Route::project) to.
+///
+/// The index is built once, from all its intervals, as an interval tree, and queries take
+/// `O(log n + k)` time, for `n` intervals and `k` results. Intervals are closed, so an interval
+/// overlaps another which it only touches at its end. An interval whose `start` is after its
+/// `end` is reversed, and one with a bound which can't be compared, such as NaN, is dropped.
+///
+/// [`Route`]: crate::Route
+///
+/// # Examples
+///
+/// ```
+/// use geo::IntervalIndex;
+///
+/// // Speed limits along a road, by milepost
+/// let limits = IntervalIndex::new([(12., 14.5, 50), (14.5, 17., 70), (13., 13.4, 30)]);
+///
+/// let at: Vec<_> = limits.containing(13.2).iter().map(|limit| limit.data).collect();
+/// assert_eq!(at, [50, 30]);
+///
+/// let along: Vec<_> = limits.overlapping(14., 15.).iter().map(|limit| limit.data).collect();
+/// assert_eq!(along, [50, 70]);
+///
+/// // Milepost 14.5 is the end of one limit, and the start of the next
+/// assert_eq!(limits.containing(14.5).len(), 2);
+/// assert!(limits.containing(20.).is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalIndex<T, D> {
+    /// The intervals, in order of their starts
+    intervals: Vec<Interval<T, D>>,
+    /// The greatest end of the intervals in the subtree at each interval, of the balanced binary
+    /// search tree over `intervals` whose root is the middle one
+    max_ends: Vec<T>,
+}
+
+impl<T: Copy + PartialOrd, D> IntervalIndex<T, D> {
+    /// Index `intervals`, each of which is a start, an end and its data.
+    pub fn new<I>(intervals: I) -> Self
+    where
+        I: IntoIterator<Item = (T, T, D)>,
+    {
+        let mut intervals: Vec<Interval<T, D>> = intervals
+            .into_iter()
+            .filter(|&(start, end, _)| is_comparable(start) && is_comparable(end))
+            .map(|(start, end, data)| {
+                if end < start {
+                    Interval {
+                        start: end,
+                        end: start,
+                        data,
+                    }
+                } else {
+                    Interval { start, end, data }
+                }
+            })
+            .collect();
+        // Every bound is comparable, so this can't fail
+        intervals.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap());
+        let mut max_ends: Vec<T> = intervals.iter().map(|interval| interval.end).collect();
+        augment(&mut max_ends, 0, intervals.len());
+        IntervalIndex {
+            intervals,
+            max_ends,
+        }
+    }
+
+    /// The intervals which contain `measure`, in order of their starts, which is a stabbing
+    /// query.
+    pub fn containing(&self, measure: T) -> Vec<&Interval<T, D>> {
+        self.overlapping(measure, measure)
+    }
+
+    /// The intervals which overlap the interval from `start` to `end`, in order of their
+    /// starts.
+    pub fn overlapping(&self, start: T, end: T) -> Vec<&Interval<T, D>> {
+        let mut found = Vec::new();
+        if is_comparable(start) && is_comparable(end) {
+            let (start, end) = if end < start {
+                (end, start)
+            } else {
+                (start, end)
+            };
+            self.search(start, end, 0, self.intervals.len(), &mut found);
+        }
+        found
+    }
+
+    /// Push the intervals in `lo..hi` which overlap `start` to `end` to `found`.
+    fn search<'a>(
+        &'a self,
+        start: T,
+        end: T,
+        lo: usize,
+        hi: usize,
+        found: &mut Vec<&'a Interval<T, D>>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_ends[mid] < start {
+            // Every interval in the subtree ends before the query starts
+            return;
+        }
+        self.search(start, end, lo, mid, found);
+        let interval = &self.intervals[mid];
+        if interval.start > end {
+            // So do all those after it
+            return;
+        }
+        if interval.end >= start {
+            found.push(interval);
+        }
+        self.search(start, end, mid + 1, hi, found);
+    }
+
+    /// The intervals, in order of their starts.
+    pub fn iter(&self) -> core::slice::Iter<'_, Interval<T, D>> {
+        self.intervals.iter()
+    }
+
+    /// The number of intervals.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Whether there are no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+/// Whether `value` can be compared, which a NaN can't be, even with itself.
+fn is_comparable<T: PartialOrd>(value: T) -> bool {
+    value.partial_cmp(&value).is_some()
+}
+
+/// Set each of `max_ends` in `lo..hi`, which start as the ends of the intervals, to the greatest
+/// end in its subtree, returning that of the root.
+fn augment<T: Copy + PartialOrd>(max_ends: &mut [T], lo: usize, hi: usize) -> Option<T> {
+    if lo >= hi {
+        return None;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let children = [augment(max_ends, lo, mid), augment(max_ends, mid + 1, hi)];
+    for child in children.into_iter().flatten() {
+        if child > max_ends[mid] {
+            max_ends[mid] = child;
+        }
+    }
+    Some(max_ends[mid])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_brute_force() {
+        // Intervals of all lengths, from a linear congruential generator
+        let mut state = 12345_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) % 1000
+        };
+        let intervals: Vec<(u64, u64, usize)> = (0..500)
+            .map(|i| {
+                let start = next();
+                (start, start + next() % (10 + 3 * (i % 50) as u64), i)
+            })
+            .collect();
+        let index = IntervalIndex::new(intervals.clone());
+        assert_eq!(index.len(), 500);
+
+        for _ in 0..200 {
+            let (start, end) = (next(), next());
+            let (low, high) = (start.min(end), start.max(end));
+            let mut expected: Vec<usize> = intervals
+                .iter()
+                .filter(|interval| interval.0 <= high && interval.1 >= low)
+                .map(|interval| interval.2)
+                .collect();
+            let mut found: Vec<usize> = index
+                .overlapping(start, end)
+                .iter()
+                .map(|interval| interval.data)
+                .collect();
+            assert!(index
+                .overlapping(start, end)
+                .windows(2)
+                .all(|pair| pair[0].start <= pair[1].start));
+            expected.sort_unstable();
+            found.sort_unstable();
+            assert_eq!(found, expected);
+
+            let stabbed = index.containing(start).len();
+            let expected = intervals
+                .iter()
+                .filter(|interval| (interval.0..=interval.1).contains(&start))
+                .count();
+            assert_eq!(stabbed, expected);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let index = IntervalIndex::new([(5., 1., 'a'), (f64::NAN, 2., 'b'), (3., 3., 'c')]);
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index.iter().next(),
+            Some(&Interval {
+                start: 1.,
+                end: 5.,
+                data: 'a'
+            })
+        );
+        assert_eq!(index.containing(3.).len(), 2);
+        assert_eq!(index.overlapping(4., 2.).len(), 2);
+        assert!(index.containing(f64::NAN).is_empty());
+
+        let empty = IntervalIndex::<f64, ()>::new([]);
+        assert!(empty.is_empty());
+        assert!(empty.overlapping(0., 1.).is_empty());
+    }
+}
@@ -0,0 +1,577 @@
+use alloc::{vec, vec::Vec};
+
+use crate::orient::{Direction, Orient};
+use crate::{Contains, Coord, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// Offset polygons inwards at many distances at once, by propagating the wavefront of their
+/// [straight skeleton](https://en.wikipedia.org/wiki/Straight_skeleton).
+///
+/// Each edge of the polygon moves inwards, parallel to itself, at unit speed, and each vertex
+/// moves along the bisector of its edges, so that the offset at a distance is bounded by the
+/// lines at exactly that distance from the original edges, with mitred rather than rounded
+/// corners. As the edges move, they shrink away, and parts of the polygon pinch off from each
+/// other where a reflex corner reaches the opposite side, or merge with its holes as they grow,
+/// until nothing is left. The wavefront is propagated through these events once, stopping at
+/// each of the distances in turn, so that a family of offsets, such as the bands of a setback
+/// map, takes one computation rather than one negative buffer each, and the offsets are nested
+/// inside each other exactly.
+///
+/// The offsets are in the order of the distances, which needn't be sorted. The polygons must be
+/// valid; the orientation of their rings doesn't matter.
+///
+/// # Panics
+///
+/// If a distance is negative or NaN.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, InwardOffsets};
+///
+/// // An L-shaped plot, with a reflex corner at (2, 2)
+/// let plot = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 2.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// let setbacks = plot.inward_offsets(&[0.5, 0.25, 1., 2.]);
+///
+/// assert_relative_eq!(setbacks[0].unsigned_area(), 5.);
+/// assert_relative_eq!(setbacks[1].unsigned_area(), 3.5 * 1.5 * 2. - 1.5 * 1.5);
+/// // Both arms are 2 wide, so they're gone a distance of 1 in
+/// assert_relative_eq!(setbacks[2].unsigned_area(), 0.);
+/// assert!(setbacks[3].0.is_empty());
+/// ```
+pub trait InwardOffsets<T: GeoFloat> {
+    fn inward_offsets(&self, distances: &[T]) -> Vec<MultiPolygon<T>>;
+
+    /// The inward offset at a single `distance`.
+    fn inward_offset(&self, distance: T) -> MultiPolygon<T> {
+        self.inward_offsets(&[distance]).pop().unwrap()
+    }
+}
+
+impl<T: GeoFloat> InwardOffsets<T> for Polygon<T> {
+    fn inward_offsets(&self, distances: &[T]) -> Vec<MultiPolygon<T>> {
+        offsets(core::slice::from_ref(self), distances)
+    }
+}
+
+impl<T: GeoFloat> InwardOffsets<T> for MultiPolygon<T> {
+    fn inward_offsets(&self, distances: &[T]) -> Vec<MultiPolygon<T>> {
+        offsets(&self.0, distances)
+    }
+}
+
+fn offsets<T: GeoFloat>(polygons: &[Polygon<T>], distances: &[T]) -> Vec<MultiPolygon<T>> {
+    assert!(
+        distances.iter().all(|distance| *distance >= T::zero()),
+        "offset distances must be zero or positive"
+    );
+    let mut order: Vec<usize> = (0..distances.len()).collect();
+    order.sort_by(|&a, &b| distances[a].total_cmp(&distances[b]));
+
+    let mut wavefront = Wavefront::new(polygons);
+    let mut offsets = vec![MultiPolygon::new(vec![]); distances.len()];
+    for index in order {
+        wavefront.propagate(distances[index]);
+        offsets[index] = wavefront.polygons_at(distances[index]);
+    }
+    offsets
+}
+
+/// The line which an edge of the polygon moves along: the points `x` where `normal · x` is
+/// `offset` plus the time.
+#[derive(Debug, Clone, Copy)]
+struct Edge<T: GeoFloat> {
+    direction: Coord<T>,
+    normal: Coord<T>,
+    offset: T,
+}
+
+/// A vertex of the wavefront, between the edge from the previous vertex and its own edge.
+#[derive(Debug, Clone, Copy)]
+struct Vertex<T: GeoFloat> {
+    /// At the current time of the wavefront
+    position: Coord<T>,
+    velocity: Coord<T>,
+    /// The edge from this vertex to the next
+    edge: usize,
+    previous: usize,
+    next: usize,
+    alive: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    /// The edge from a vertex shrinks to nothing
+    Edge(usize),
+    /// A reflex vertex reaches the edge from another vertex
+    Split { reflex: usize, start: usize },
+}
+
+/// The rings of the offset polygons, as circular lists of vertices, with the interior on the
+/// left of every edge.
+struct Wavefront<T: GeoFloat> {
+    edges: Vec<Edge<T>>,
+    vertices: Vec<Vertex<T>>,
+    time: T,
+    tolerance: T,
+}
+
+fn dot<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.x + a.y * b.y
+}
+
+fn cross<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+impl<T: GeoFloat> Wavefront<T> {
+    fn new(polygons: &[Polygon<T>]) -> Self {
+        let mut wavefront = Wavefront {
+            edges: vec![],
+            vertices: vec![],
+            time: T::zero(),
+            tolerance: T::zero(),
+        };
+        let mut scale = T::zero();
+        for polygon in polygons {
+            let polygon = polygon.orient(Direction::Default);
+            for ring in core::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                let mut coords = ring.0.clone();
+                coords.dedup();
+                if coords.len() > 1 && coords.first() == coords.last() {
+                    coords.pop();
+                }
+                if coords.len() < 3 {
+                    continue;
+                }
+                for coord in &coords {
+                    scale = scale.max(coord.x.abs()).max(coord.y.abs());
+                }
+                wavefront.push_ring(&coords);
+            }
+        }
+        wavefront.tolerance = scale.max(T::one()) * T::epsilon().sqrt() / T::from(16).unwrap();
+        for vertex in 0..wavefront.vertices.len() {
+            wavefront.update_velocity(vertex);
+        }
+        wavefront.clean_up();
+        wavefront
+    }
+
+    fn push_ring(&mut self, coords: &[Coord<T>]) {
+        let first = self.vertices.len();
+        let len = coords.len();
+        for (i, &start) in coords.iter().enumerate() {
+            let delta = coords[(i + 1) % len] - start;
+            let direction = delta / delta.x.hypot(delta.y);
+            let normal = Coord {
+                x: -direction.y,
+                y: direction.x,
+            };
+            self.edges.push(Edge {
+                direction,
+                normal,
+                offset: dot(normal, start),
+            });
+            self.vertices.push(Vertex {
+                position: start,
+                velocity: Coord::zero(),
+                edge: self.edges.len() - 1,
+                previous: first + (i + len - 1) % len,
+                next: first + (i + 1) % len,
+                alive: true,
+            });
+        }
+    }
+
+    fn incoming(&self, vertex: usize) -> Edge<T> {
+        self.edges[self.vertices[self.vertices[vertex].previous].edge]
+    }
+
+    fn outgoing(&self, vertex: usize) -> Edge<T> {
+        self.edges[self.vertices[vertex].edge]
+    }
+
+    /// Set the velocity at which `vertex` follows both of its edges.
+    fn update_velocity(&mut self, vertex: usize) {
+        let (incoming, outgoing) = (self.incoming(vertex), self.outgoing(vertex));
+        let sum = incoming.normal + outgoing.normal;
+        // Zero, rather than infinite, between antiparallel edges, which are cleaned up
+        let denominator = (T::one() + dot(incoming.normal, outgoing.normal)).max(T::epsilon());
+        self.vertices[vertex].velocity = sum / denominator;
+    }
+
+    fn is_reflex(&self, vertex: usize) -> bool {
+        cross(
+            self.incoming(vertex).direction,
+            self.outgoing(vertex).direction,
+        ) < -T::epsilon().sqrt()
+    }
+
+    /// Move the wavefront on to `time`, through every event before it.
+    fn propagate(&mut self, time: T) {
+        // Each event removes a vertex, or splits a ring in a way which soon leads to removing
+        // some, so this only guards against going round in circles on degenerate input
+        let mut budget = 64 + 16 * self.vertices.len() * self.vertices.len();
+        while let Some((delay, event)) = self.next_event() {
+            if self.time + delay > time || budget == 0 {
+                break;
+            }
+            budget -= 1;
+            self.advance(delay);
+            match event {
+                Event::Edge(start) => self.merge_next(start),
+                Event::Split { reflex, start } => self.split(reflex, start),
+            }
+            self.clean_up();
+        }
+        self.advance(time - self.time);
+    }
+
+    fn advance(&mut self, delay: T) {
+        for vertex in self.vertices.iter_mut().filter(|vertex| vertex.alive) {
+            vertex.position = vertex.position + vertex.velocity * delay;
+        }
+        self.time = self.time + delay;
+    }
+
+    /// The next event, and how long until it, preferring edge events at the same time.
+    fn next_event(&self) -> Option<(T, Event)> {
+        let mut next: Option<(T, Event)> = None;
+        let alive = || (0..self.vertices.len()).filter(|&vertex| self.vertices[vertex].alive);
+        for start in alive() {
+            let (vertex, end) = (
+                &self.vertices[start],
+                &self.vertices[self.vertices[start].next],
+            );
+            let direction = self.outgoing(start).direction;
+            let shrinking = dot(end.velocity - vertex.velocity, direction);
+            if shrinking < -T::epsilon() {
+                let length = dot(end.position - vertex.position, direction).max(T::zero());
+                let delay = length / -shrinking;
+                if !next.is_some_and(|(best, _)| delay >= best) {
+                    next = Some((delay, Event::Edge(start)));
+                }
+            }
+        }
+        for reflex in alive().filter(|&vertex| self.is_reflex(vertex)) {
+            let vertex = &self.vertices[reflex];
+            for start in alive().filter(|&start| start != reflex && start != vertex.previous) {
+                let edge = self.outgoing(start);
+                let distance = dot(edge.normal, vertex.position) - (edge.offset + self.time);
+                let closing = T::one() - dot(edge.normal, vertex.velocity);
+                if closing <= T::epsilon() || distance < -self.tolerance {
+                    continue;
+                }
+                let delay = distance.max(T::zero()) / closing;
+                if next.is_some_and(|(best, _)| delay >= best) {
+                    continue;
+                }
+                let (a, b) = (
+                    &self.vertices[start],
+                    &self.vertices[self.vertices[start].next],
+                );
+                let a_position = a.position + a.velocity * delay;
+                let b_position = b.position + b.velocity * delay;
+                let hit = vertex.position + vertex.velocity * delay;
+                let along = dot(hit - a_position, edge.direction);
+                let length = dot(b_position - a_position, edge.direction);
+                if along >= -self.tolerance && along <= length + self.tolerance {
+                    next = Some((delay, Event::Split { reflex, start }));
+                }
+            }
+        }
+        next
+    }
+
+    /// Merge the vertex after `vertex` into it, so that it's followed by the edge from the
+    /// merged vertex.
+    fn merge_next(&mut self, vertex: usize) {
+        let next = self.vertices[vertex].next;
+        let after = self.vertices[next].next;
+        let midpoint =
+            (self.vertices[vertex].position + self.vertices[next].position) / T::from(2).unwrap();
+        self.vertices[vertex].position = midpoint;
+        self.vertices[vertex].edge = self.vertices[next].edge;
+        self.vertices[vertex].next = after;
+        self.vertices[after].previous = vertex;
+        self.vertices[next].alive = false;
+        self.update_velocity(vertex);
+    }
+
+    /// Split the edge from `start` where `reflex` has reached it, which divides a ring in two,
+    /// or joins two rings into one.
+    fn split(&mut self, reflex: usize, start: usize) {
+        let Vertex {
+            position, previous, ..
+        } = self.vertices[reflex];
+        let end = self.vertices[start].next;
+        // The reflex vertex is replaced by one which leads onto the far part of the edge, and
+        // one which comes off the near part of it
+        let onto = self.vertices.len();
+        self.vertices.push(Vertex {
+            position,
+            velocity: Coord::zero(),
+            edge: self.vertices[start].edge,
+            previous,
+            next: end,
+            alive: true,
+        });
+        self.vertices[previous].next = onto;
+        self.vertices[end].previous = onto;
+        self.vertices[start].next = reflex;
+        self.vertices[reflex].previous = start;
+        self.update_velocity(onto);
+        self.update_velocity(reflex);
+    }
+
+    /// Remove the degenerate parts of the wavefront left by events: coincident vertices,
+    /// spikes without width, and rings without area.
+    fn clean_up(&mut self) {
+        let tolerance = self.tolerance;
+        let close = |a: Coord<T>, b: Coord<T>| (a - b).x.hypot((a - b).y) <= tolerance;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for vertex in 0..self.vertices.len() {
+                if !self.vertices[vertex].alive {
+                    continue;
+                }
+                let Vertex { previous, next, .. } = self.vertices[vertex];
+                if self.vertices[next].next == vertex {
+                    self.vertices[vertex].alive = false;
+                    self.vertices[next].alive = false;
+                    changed = true;
+                } else if close(self.vertices[vertex].position, self.vertices[next].position) {
+                    self.merge_next(vertex);
+                    changed = true;
+                } else if dot(
+                    self.incoming(vertex).direction,
+                    self.outgoing(vertex).direction,
+                ) < T::epsilon().sqrt() - T::one()
+                    && close(
+                        self.vertices[previous].position,
+                        self.vertices[next].position,
+                    )
+                {
+                    self.vertices[previous].next = next;
+                    self.vertices[next].previous = previous;
+                    self.vertices[vertex].alive = false;
+                    changed = true;
+                }
+            }
+        }
+        for ring in self.rings() {
+            let coords: Vec<Coord<T>> = ring
+                .iter()
+                .map(|&vertex| self.vertices[vertex].position)
+                .collect();
+            let (area, perimeter) = area_and_perimeter(&coords);
+            if area.abs() <= self.tolerance * perimeter {
+                for vertex in ring {
+                    self.vertices[vertex].alive = false;
+                }
+            }
+        }
+    }
+
+    /// The vertices of each ring, in order.
+    fn rings(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.vertices.len()];
+        let mut rings = vec![];
+        for first in 0..self.vertices.len() {
+            if !self.vertices[first].alive || visited[first] {
+                continue;
+            }
+            let mut ring = vec![];
+            let mut vertex = first;
+            while !visited[vertex] {
+                visited[vertex] = true;
+                ring.push(vertex);
+                vertex = self.vertices[vertex].next;
+            }
+            rings.push(ring);
+        }
+        rings
+    }
+
+    /// The polygons of the wavefront at `time`, which is no earlier than its current time,
+    /// and before its next event.
+    fn polygons_at(&self, time: T) -> MultiPolygon<T> {
+        let delay = time - self.time;
+        let mut exteriors: Vec<(T, Polygon<T>)> = vec![];
+        let mut interiors = vec![];
+        for ring in self.rings() {
+            let coords: Vec<Coord<T>> = ring
+                .iter()
+                .map(|&vertex| {
+                    let vertex = &self.vertices[vertex];
+                    vertex.position + vertex.velocity * delay
+                })
+                .collect();
+            let (area, _) = area_and_perimeter(&coords);
+            if area > T::zero() {
+                exteriors.push((area, Polygon::new(LineString::new(coords), vec![])));
+            } else {
+                interiors.push(LineString::new(coords));
+            }
+        }
+        // Each hole is in the smallest exterior around it
+        for interior in interiors {
+            let container = exteriors
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, exterior))| exterior.contains(&interior.0[0]))
+                .min_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b))
+                .map(|(index, _)| index);
+            if let Some(index) = container {
+                exteriors[index].1.interiors_push(interior);
+            }
+        }
+        MultiPolygon::new(exteriors.into_iter().map(|(_, polygon)| polygon).collect())
+    }
+}
+
+/// The signed area, positive if counter-clockwise, and the perimeter of a ring.
+fn area_and_perimeter<T: GeoFloat>(coords: &[Coord<T>]) -> (T, T) {
+    let mut twice_area = T::zero();
+    let mut perimeter = T::zero();
+    for (i, a) in coords.iter().enumerate() {
+        let b = coords[(i + 1) % coords.len()];
+        twice_area = twice_area + cross(*a, b);
+        perimeter = perimeter + (b - *a).x.hypot((b - *a).y);
+    }
+    (twice_area / T::from(2).unwrap(), perimeter)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, EuclideanDistance, Validation};
+
+    /// Check that each offset is valid, inside the polygon, at least its distance from the
+    /// boundary, and inside the offsets at lesser distances.
+    fn check(polygon: &Polygon, distances: &[f64]) -> Vec<MultiPolygon> {
+        let offsets = polygon.inward_offsets(distances);
+        let boundary = crate::MultiLineString::new(
+            core::iter::once(polygon.exterior().clone())
+                .chain(polygon.interiors().iter().cloned())
+                .collect(),
+        );
+        for (offset, distance) in offsets.iter().zip(distances) {
+            assert!(offset.is_valid(), "{distance}: {offset:?}");
+            for coord in crate::CoordsIter::coords_iter(offset) {
+                assert!(polygon.contains(&coord) || *distance == 0.);
+                let clearance = crate::Point::from(coord).euclidean_distance(&boundary);
+                assert!(clearance >= distance - 1e-9, "{distance}: {coord:?}");
+            }
+            for (other, other_distance) in offsets.iter().zip(distances) {
+                if other_distance < distance {
+                    assert!(
+                        other.unsigned_area() >= offset.unsigned_area() - 1e-9,
+                        "{other_distance} {distance}"
+                    );
+                }
+            }
+        }
+        offsets
+    }
+
+    #[test]
+    fn rectangle() {
+        let rectangle = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.), (x: 0., y: 4.)];
+        let offsets = check(&rectangle, &[0., 1., 1.5, 2., 3.]);
+        assert_relative_eq!(offsets[0].unsigned_area(), 40.);
+        assert_relative_eq!(
+            offsets[1],
+            MultiPolygon::new(vec![
+                polygon![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 3.), (x: 1., y: 3.)]
+            ])
+        );
+        assert_relative_eq!(offsets[2].unsigned_area(), 7.);
+        assert!(offsets[3].0.is_empty());
+        assert!(offsets[4].0.is_empty());
+    }
+
+    #[test]
+    fn dumbbell_splits() {
+        // Two squares joined by a neck 2 wide
+        let dumbbell = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 6., y: 1.),
+            (x: 6., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 6., y: 4.),
+            (x: 6., y: 3.),
+            (x: 4., y: 3.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let offsets = check(&dumbbell, &[0.5, 1.5, 0.99, 1.01]);
+        assert_eq!(offsets[0].0.len(), 1);
+        assert_relative_eq!(offsets[0].unsigned_area(), 9. + 3. + 9.);
+        assert_eq!(offsets[1].0.len(), 2);
+        assert_relative_eq!(offsets[1].unsigned_area(), 2., epsilon = 1e-9);
+        assert_eq!(offsets[2].0.len(), 1);
+        assert_eq!(offsets[3].0.len(), 2);
+    }
+
+    #[test]
+    fn holes_grow() {
+        // A courtyard near one side of a building
+        let building = polygon![
+            exterior: [(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let offsets = check(&building, &[0.5, 1.5, 3.]);
+        // The courtyard grows, until it breaks through the outer wall, 2 thick, at 1
+        assert_eq!(offsets[0].0[0].interiors().len(), 1);
+        assert_relative_eq!(
+            offsets[0].unsigned_area(),
+            19. * 9. - 5. * 7.,
+            epsilon = 1e-9
+        );
+        assert!(offsets[1]
+            .0
+            .iter()
+            .all(|polygon| polygon.interiors().is_empty()));
+        assert!(offsets[1].unsigned_area() > offsets[2].unsigned_area());
+        assert_relative_eq!(offsets[2].unsigned_area(), (20. - 6. - 3. - 3.) * 4.);
+    }
+
+    #[test]
+    fn random_star_shapes() {
+        // Star-shaped polygons, from a linear congruential generator
+        let mut state = 11_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 1000) as f64 / 1000.
+        };
+        for _ in 0..40 {
+            let sides = 5 + (next() * 12.) as usize;
+            let coords: Vec<Coord> = (0..sides)
+                .map(|i| {
+                    let angle =
+                        (i as f64 + 0.2 + next() * 0.6) / sides as f64 * core::f64::consts::TAU;
+                    let radius = 3. + next() * 7.;
+                    Coord {
+                        x: radius * angle.cos(),
+                        y: radius * angle.sin(),
+                    }
+                })
+                .collect();
+            let star = Polygon::new(LineString::new(coords), vec![]);
+            check(&star, &[0.25, 0.5, 1., 2., 4.]);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+
+use crate::angles::turn_angle;
+use crate::{Coord, CoordFloat, LineString, MultiLineString, Point};
+
+/// Where to draw a label on a line, and at what angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelAnchor<T: CoordFloat> {
+    /// The point of the line at the middle of the label.
+    pub point: Point<T>,
+    /// The direction of the line at `point`, in degrees counter-clockwise from the x-axis.
+    ///
+    /// It's in the range (-90°, 90°], so that text drawn at this angle is never upside down.
+    pub angle: T,
+}
+
+/// A straight enough part of a line to draw a label along, from [`LabelPoint::label_segment`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelSegment<T: CoordFloat> {
+    /// The part of the line.
+    pub line_string: LineString<T>,
+    /// Its length.
+    pub length: T,
+    /// The anchor at the middle of its length.
+    pub anchor: LabelAnchor<T>,
+}
+
+/// Find where to place labels on linear features.
+///
+/// The [`Centroid`](crate::Centroid) of a curved line can be far from it, so these find places
+/// on the line itself.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LabelPoint, LineString};
+///
+/// // A road which goes east, and then turns sharply to the north-west
+/// let road: LineString = line_string![
+///     (x: 0., y: 0.),
+///     (x: 6., y: 0.),
+///     (x: 8., y: 0.1),
+///     (x: 4., y: 4.),
+/// ];
+///
+/// let anchor = road.label_point().unwrap();
+/// assert!(anchor.point.y().abs() < 0.1);
+///
+/// // The longest part which doesn't turn by more than 30° at any vertex
+/// let segment = road.label_segment(5., 30.).unwrap();
+/// assert_eq!(segment.line_string, line_string![(x: 0., y: 0.), (x: 6., y: 0.), (x: 8., y: 0.1)]);
+/// assert_eq!(segment.anchor.point.y(), 0.);
+/// assert_eq!(segment.anchor.angle, 0.);
+///
+/// // No part is straight enough for a label this long
+/// assert_eq!(road.label_segment(9., 30.), None);
+/// ```
+pub trait LabelPoint<T: CoordFloat> {
+    /// The point at half of the length of the line, and the angle of the line there.
+    ///
+    /// For a `MultiLineString`, this is on its longest `LineString`. Returns `None` if the line
+    /// has no length.
+    fn label_point(&self) -> Option<LabelAnchor<T>>;
+
+    /// The longest part of the line which turns by at most `max_turn` degrees at each of its
+    /// vertices, if it's at least `min_length` long, with the anchor at the middle of it.
+    fn label_segment(&self, min_length: T, max_turn: T) -> Option<LabelSegment<T>>;
+}
+
+impl<T: CoordFloat> LabelPoint<T> for LineString<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        anchor(&self.0)
+    }
+
+    fn label_segment(&self, min_length: T, max_turn: T) -> Option<LabelSegment<T>> {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        // The runs of edges without a sharp turn between them, as the range of their coordinates
+        // and their length
+        let mut runs = Vec::new();
+        let mut start = 0;
+        let mut length = T::zero();
+        for i in 1..coords.len() {
+            if i >= 2 && turn_angle(coords[i - 2], coords[i - 1], coords[i]).abs() > max_turn {
+                runs.push((start, i, length));
+                start = i - 1;
+                length = T::zero();
+            }
+            length = length + distance(coords[i - 1], coords[i]);
+        }
+        if coords.len() >= 2 {
+            runs.push((start, coords.len(), length));
+        }
+        let best = runs.into_iter().fold(None, |best, run| match best {
+            Some((_, _, best_length)) if best_length >= run.2 => best,
+            _ => Some(run),
+        });
+
+        let (start, end, length) = best?;
+        if length < min_length || length <= T::zero() {
+            return None;
+        }
+        let part = &coords[start..end];
+        Some(LabelSegment {
+            line_string: LineString::new(part.to_vec()),
+            length,
+            anchor: anchor(part)?,
+        })
+    }
+}
+
+impl<T: CoordFloat> LabelPoint<T> for MultiLineString<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        self.iter()
+            .map(|line_string| (line_string, length(&line_string.0)))
+            .fold(
+                None,
+                |longest: Option<(&LineString<T>, T)>, (line_string, length)| match longest {
+                    Some((_, longest_length)) if longest_length >= length => longest,
+                    _ => Some((line_string, length)),
+                },
+            )?
+            .0
+            .label_point()
+    }
+
+    fn label_segment(&self, min_length: T, max_turn: T) -> Option<LabelSegment<T>> {
+        self.iter()
+            .filter_map(|line_string| line_string.label_segment(min_length, max_turn))
+            .fold(
+                None,
+                |longest: Option<LabelSegment<T>>, segment| match longest {
+                    Some(longest) if longest.length >= segment.length => Some(longest),
+                    _ => Some(segment),
+                },
+            )
+    }
+}
+
+fn distance<T: CoordFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+fn length<T: CoordFloat>(coords: &[Coord<T>]) -> T {
+    coords
+        .windows(2)
+        .fold(T::zero(), |sum, w| sum + distance(w[0], w[1]))
+}
+
+/// The anchor at half of the length of the line through `coords`.
+fn anchor<T: CoordFloat>(coords: &[Coord<T>]) -> Option<LabelAnchor<T>> {
+    let total = length(coords);
+    if total <= T::zero() {
+        return None;
+    }
+    let mut remaining = total / T::from(2).unwrap();
+    for window in coords.windows(2) {
+        let length = distance(window[0], window[1]);
+        if length <= T::zero() {
+            continue;
+        }
+        if remaining <= length {
+            let (a, b) = (window[0], window[1]);
+            let delta = b - a;
+            let point = a + delta * (remaining / length);
+            return Some(LabelAnchor {
+                point: point.into(),
+                angle: upright(delta.y.atan2(delta.x).to_degrees()),
+            });
+        }
+        remaining = remaining - length;
+    }
+    None
+}
+
+/// `angle` turned by a half turn if necessary to be in (-90°, 90°].
+fn upright<T: CoordFloat>(angle: T) -> T {
+    let (quarter, half) = (T::from(90).unwrap(), T::from(180).unwrap());
+    if angle > quarter {
+        angle - half
+    } else if angle <= -quarter {
+        angle + half
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, EuclideanLength};
+
+    #[test]
+    fn label_point() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 4.)];
+        let anchor = line_string.label_point().unwrap();
+        assert_eq!(anchor.point, Point::new(2., 1.));
+        assert_eq!(anchor.angle, 90.);
+
+        // Lines going west or south-west are labelled the same way up as lines going east
+        let west = line_string![(x: 4., y: 0.), (x: 0., y: 0.)];
+        assert_eq!(west.label_point().unwrap().angle, 0.);
+        let south_west = line_string![(x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_relative_eq!(south_west.label_point().unwrap().angle, 45.);
+        let south_east = line_string![(x: -1., y: 1.), (x: 0., y: 0.)];
+        assert_relative_eq!(south_east.label_point().unwrap().angle, -45.);
+
+        assert_eq!(line_string![(x: 1., y: 1.)].label_point(), None);
+        assert_eq!(
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)].label_point(),
+            None
+        );
+    }
+
+    #[test]
+    fn label_segment() {
+        // A zigzag whose middle is a long, gently curving stretch
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: 0.),
+            (x: 6., y: 0.5),
+            (x: 10., y: 0.),
+            (x: 11., y: 1.),
+        ];
+        let segment = line_string.label_segment(1., 20.).unwrap();
+        assert_eq!(
+            segment.line_string,
+            line_string![(x: 2., y: 0.), (x: 6., y: 0.5), (x: 10., y: 0.)]
+        );
+        assert_relative_eq!(segment.length, 2. * 4.0_f64.hypot(0.5));
+        assert_eq!(segment.anchor.point, Point::new(6., 0.5));
+
+        // A large enough turn allows the whole line
+        let segment = line_string.label_segment(1., 180.).unwrap();
+        assert_eq!(segment.line_string, line_string);
+        assert_relative_eq!(segment.length, line_string.euclidean_length());
+
+        assert_eq!(line_string.label_segment(9., 20.), None);
+        assert_eq!(line_string![(x: 1., y: 1.)].label_segment(0., 20.), None);
+    }
+
+    #[test]
+    fn multi_line_string() {
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 0., y: 9.)],
+        ]);
+        let anchor = multi_line_string.label_point().unwrap();
+        assert_eq!(anchor.point, Point::new(0., 7.));
+        let segment = multi_line_string.label_segment(0.5, 10.).unwrap();
+        assert_eq!(segment.length, 4.);
+        assert_eq!(MultiLineString::<f64>::new(vec![]).label_point(), None);
+    }
+}
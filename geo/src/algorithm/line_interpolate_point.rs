@@ -1,6 +1,6 @@
 use crate::coords_iter::CoordsIter;
 use crate::{CoordFloat, EuclideanLength, Line, LineString, Point};
-use std::ops::AddAssign;
+use core::ops::AddAssign;
 
 /// Returns an option of the point that lies a given fraction along the line.
 ///
@@ -68,7 +68,7 @@ where
 
 impl<T> LineInterpolatePoint<T> for LineString<T>
 where
-    T: CoordFloat + AddAssign + std::fmt::Debug,
+    T: CoordFloat + AddAssign + core::fmt::Debug,
     Line<T>: EuclideanLength<T>,
     LineString<T>: EuclideanLength<T>,
 {
@@ -0,0 +1,562 @@
+use alloc::collections::BinaryHeap;
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::sweep::SegmentIntersections;
+use crate::utils::lex_cmp;
+use crate::{Coord, EuclideanLength, GeoFloat, Line, LineIntersection, LineString, Point};
+
+/// A topological graph of a set of line strings, such as the roads, rivers or pipes of a
+/// dataset, for finding which parts are connected and the shortest paths between places.
+///
+/// The line strings are noded: the _nodes_ of the network are the endpoints of the line
+/// strings, and the points where they cross, touch or start or stop overlapping each other or
+/// themselves. The _edges_ are the parts of the line strings between consecutive nodes, each
+/// with its geometry and its length. A stretch where line strings overlap, along the same
+/// vertices, is a single edge. Consecutive repeated coordinates are ignored, and line strings
+/// without two distinct coordinates are left out.
+///
+/// The lengths of the edges are Euclidean, in the units of the coordinates, from
+/// [`new`](Self::new), or geodesic, in meters, from [`new_geodesic`](Self::new_geodesic).
+/// Paths can also be weighted by anything else about the edges, such as travel time, with
+/// [`shortest_path_by`](Self::shortest_path_by).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, LineNetwork};
+///
+/// let roads = [
+///     // A main road, which a side road crosses, and a lane branches off
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 4., y: -3.), (x: 4., y: 3.)],
+///     line_string![(x: 10., y: 0.), (x: 10., y: 5.), (x: 14., y: 5.)],
+///     // A separate track
+///     line_string![(x: 20., y: 0.), (x: 30., y: 0.)],
+/// ];
+/// let network = LineNetwork::new(&roads);
+///
+/// // The crossing at (4, 0) splits the main road and the side road
+/// assert_eq!(network.nodes().len(), 8);
+/// assert_eq!(network.edges().len(), 6);
+/// assert_eq!(network.connected_components().len(), 2);
+///
+/// let from = network.node_at(coord! { x: 4., y: -3. }).unwrap();
+/// let to = network.node_at(coord! { x: 14., y: 5. }).unwrap();
+/// let path = network.shortest_path(from, to).unwrap();
+/// assert_eq!(path.length, 3. + 6. + 5. + 4.);
+/// assert_eq!(
+///     path.geometry,
+///     line_string![
+///         (x: 4., y: -3.),
+///         (x: 4., y: 0.),
+///         (x: 10., y: 0.),
+///         (x: 10., y: 5.),
+///         (x: 14., y: 5.),
+///     ]
+/// );
+///
+/// let track = network.node_at(coord! { x: 20., y: 0. }).unwrap();
+/// assert!(network.shortest_path(from, track).is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineNetwork<T: GeoFloat> {
+    /// In lexicographic order, so that they can be found by binary search
+    nodes: Vec<Coord<T>>,
+    edges: Vec<NetworkEdge<T>>,
+    /// For each node, the edges which start or end at it
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// An edge of a [`LineNetwork`], between two of its nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkEdge<T: GeoFloat> {
+    /// The index of the node at the start of the edge.
+    pub start: usize,
+    /// The index of the node at the end of the edge, which is the same as the start for a
+    /// closed loop.
+    pub end: usize,
+    /// The part of the line string from the start node to the end node.
+    pub geometry: LineString<T>,
+    /// The length of the geometry.
+    pub length: T,
+    /// The index of the line string the edge is part of, the first of them if they overlap.
+    pub source: usize,
+}
+
+/// A path through a [`LineNetwork`], from [`LineNetwork::shortest_path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkPath<T: GeoFloat> {
+    /// The nodes along the path, from the first to the last.
+    pub nodes: Vec<usize>,
+    /// The edges along the path, one fewer than the nodes.
+    pub edges: Vec<usize>,
+    /// The geometry of the path, following each edge in the direction it's travelled, which is
+    /// the single coordinate of the node for a path from a node to itself.
+    pub geometry: LineString<T>,
+    /// The sum of the weights of the edges.
+    pub length: T,
+}
+
+impl<T: GeoFloat> LineNetwork<T> {
+    /// The network of `line_strings`, with the Euclidean lengths of its edges.
+    pub fn new(line_strings: &[LineString<T>]) -> Self {
+        Self::with_lengths(line_strings, |line_string| {
+            line_string
+                .lines()
+                .fold(T::zero(), |length, line| length + line.euclidean_length())
+        })
+    }
+
+    /// The network of `line_strings`, with the lengths of its edges measured by `length`.
+    fn with_lengths(line_strings: &[LineString<T>], length: impl Fn(&LineString<T>) -> T) -> Self {
+        let line_strings: Vec<Vec<Coord<T>>> = line_strings
+            .iter()
+            .map(|line_string| {
+                let mut coords = line_string.0.clone();
+                coords.dedup();
+                coords
+            })
+            .collect();
+        // The segments, with the line strings and positions they're from
+        let mut segments: Vec<(Line<T>, usize, usize)> = vec![];
+        for (source, coords) in line_strings.iter().enumerate() {
+            for (position, pair) in coords.windows(2).enumerate() {
+                segments.push((Line::new(pair[0], pair[1]), source, position));
+            }
+        }
+
+        // The points along each segment where it meets others
+        let mut splits: Vec<Vec<Coord<T>>> = vec![vec![]; segments.len()];
+        let lines: Vec<Line<T>> = segments.iter().map(|segment| segment.0).collect();
+        for intersection in SegmentIntersections::new(lines) {
+            let (first, second) = (segments[intersection.first], segments[intersection.second]);
+            let points = match intersection.intersection {
+                LineIntersection::SinglePoint { intersection, .. } => [intersection, intersection],
+                LineIntersection::Collinear { intersection } => {
+                    [intersection.start, intersection.end]
+                }
+            };
+            // Consecutive segments of a line string always meet at the vertex they share
+            let shared = (first.1 == second.1 && first.2 + 1 == second.2).then_some(first.0.end);
+            for point in points {
+                if Some(point) != shared {
+                    splits[intersection.first].push(point);
+                    splits[intersection.second].push(point);
+                }
+            }
+        }
+
+        let mut nodes: Vec<Coord<T>> = line_strings
+            .iter()
+            .filter(|coords| coords.len() >= 2)
+            .flat_map(|coords| [coords[0], coords[coords.len() - 1]])
+            .chain(splits.iter().flatten().copied())
+            .collect();
+        nodes.sort_by(lex_cmp);
+        nodes.dedup();
+        let node = |coord: &Coord<T>| nodes.binary_search_by(|node| lex_cmp(node, coord)).ok();
+
+        let mut pieces: Vec<NetworkEdge<T>> = vec![];
+        let mut splits = splits.into_iter();
+        for (source, coords) in line_strings.iter().enumerate() {
+            if coords.len() < 2 {
+                continue;
+            }
+            let mut start = node(&coords[0]).unwrap();
+            let mut geometry = vec![coords[0]];
+            for pair in coords.windows(2) {
+                let line = Line::new(pair[0], pair[1]);
+                let mut points = splits.next().unwrap();
+                let along = |point: &Coord<T>| {
+                    let delta = *point - line.start;
+                    delta.x * line.dx() + delta.y * line.dy()
+                };
+                points.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+                points.push(line.end);
+                for point in points {
+                    if geometry.last() == Some(&point) {
+                        continue;
+                    }
+                    geometry.push(point);
+                    if let Some(end) = node(&point) {
+                        let geometry =
+                            LineString::new(core::mem::replace(&mut geometry, vec![point]));
+                        pieces.push(NetworkEdge {
+                            start,
+                            end,
+                            length: length(&geometry),
+                            geometry,
+                            source,
+                        });
+                        start = end;
+                    }
+                }
+            }
+        }
+
+        // Overlapping stretches of line strings give the same edge, perhaps reversed
+        let compare_coords = |a: &[Coord<T>], b: &[Coord<T>]| {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| lex_cmp(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        };
+        let keys: Vec<Vec<Coord<T>>> = pieces
+            .iter()
+            .map(|edge| {
+                let forward = edge.geometry.0.clone();
+                let mut backward = forward.clone();
+                backward.reverse();
+                let reversed = match edge.end.cmp(&edge.start) {
+                    Ordering::Less => true,
+                    Ordering::Equal => compare_coords(&backward, &forward).is_lt(),
+                    Ordering::Greater => false,
+                };
+                if reversed {
+                    backward
+                } else {
+                    forward
+                }
+            })
+            .collect();
+        let compare_keys = |a: &Vec<Coord<T>>, b: &Vec<Coord<T>>| compare_coords(a, b);
+        let mut order: Vec<usize> = (0..pieces.len()).collect();
+        order.sort_by(|&a, &b| compare_keys(&keys[a], &keys[b]).then(a.cmp(&b)));
+        let mut duplicate = vec![false; pieces.len()];
+        for pair in order.windows(2) {
+            if compare_keys(&keys[pair[0]], &keys[pair[1]]).is_eq() {
+                duplicate[pair[1]] = true;
+            }
+        }
+        let mut duplicate = duplicate.into_iter();
+        pieces.retain(|_| !duplicate.next().unwrap());
+
+        let mut adjacency = vec![vec![]; nodes.len()];
+        for (index, edge) in pieces.iter().enumerate() {
+            adjacency[edge.start].push(index);
+            if edge.end != edge.start {
+                adjacency[edge.end].push(index);
+            }
+        }
+        LineNetwork {
+            nodes,
+            edges: pieces,
+            adjacency,
+        }
+    }
+
+    /// The coordinates of the nodes, in lexicographic order.
+    pub fn nodes(&self) -> &[Coord<T>] {
+        &self.nodes
+    }
+
+    /// The edges, in the order of the line strings they're from, and then along them.
+    pub fn edges(&self) -> &[NetworkEdge<T>] {
+        &self.edges
+    }
+
+    /// The indices of the edges which start or end at `node`.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such node.
+    pub fn edges_at(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// The index of the node at exactly `coord`, if there is one.
+    pub fn node_at(&self, coord: Coord<T>) -> Option<usize> {
+        self.nodes
+            .binary_search_by(|node| lex_cmp(node, &coord))
+            .ok()
+    }
+
+    /// The index of the node nearest to `point`, by Euclidean distance.
+    pub fn nearest_node(&self, point: &Point<T>) -> Option<usize> {
+        let distance_2 = |node: &Coord<T>| {
+            let delta = *node - point.0;
+            delta.x * delta.x + delta.y * delta.y
+        };
+        (0..self.nodes.len()).min_by(|&a, &b| {
+            distance_2(&self.nodes[a])
+                .partial_cmp(&distance_2(&self.nodes[b]))
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+
+    /// The sets of nodes which are connected to each other by edges, each in increasing order,
+    /// and in the order of their least nodes.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut component = vec![usize::MAX; self.nodes.len()];
+        let mut components = vec![];
+        for first in 0..self.nodes.len() {
+            if component[first] != usize::MAX {
+                continue;
+            }
+            let mut members = vec![first];
+            component[first] = components.len();
+            let mut next = 0;
+            while let Some(&node) = members.get(next) {
+                next += 1;
+                for &edge in &self.adjacency[node] {
+                    let edge = &self.edges[edge];
+                    let other = if edge.start == node {
+                        edge.end
+                    } else {
+                        edge.start
+                    };
+                    if component[other] == usize::MAX {
+                        component[other] = components.len();
+                        members.push(other);
+                    }
+                }
+            }
+            members.sort_unstable();
+            components.push(members);
+        }
+        components
+    }
+
+    /// The shortest path from the node `from` to the node `to`, by the lengths of the edges,
+    /// or `None` if they aren't connected.
+    ///
+    /// # Panics
+    ///
+    /// If either node doesn't exist.
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<NetworkPath<T>> {
+        self.shortest_path_by(from, to, |edge| edge.length)
+    }
+
+    /// The shortest path from the node `from` to the node `to`, by the `weight` of each edge,
+    /// such as the time to travel along it, which mustn't be negative, or `None` if they aren't
+    /// connected.
+    ///
+    /// The path is found with Dijkstra's algorithm, in O(E log E) time for E edges.
+    ///
+    /// # Panics
+    ///
+    /// If either node doesn't exist.
+    pub fn shortest_path_by(
+        &self,
+        from: usize,
+        to: usize,
+        mut weight: impl FnMut(&NetworkEdge<T>) -> T,
+    ) -> Option<NetworkPath<T>> {
+        assert!(
+            from < self.nodes.len() && to < self.nodes.len(),
+            "there is no such node"
+        );
+        let mut distances: Vec<Option<T>> = vec![None; self.nodes.len()];
+        // For each node reached, the edge it was reached by
+        let mut previous: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        distances[from] = Some(T::zero());
+        queue.push(Visit {
+            distance: T::zero(),
+            node: from,
+        });
+        while let Some(Visit { distance, node }) = queue.pop() {
+            if node == to {
+                break;
+            }
+            if distances[node].is_some_and(|best| distance > best) {
+                continue;
+            }
+            for &index in &self.adjacency[node] {
+                let edge = &self.edges[index];
+                let other = if edge.start == node {
+                    edge.end
+                } else {
+                    edge.start
+                };
+                let distance = distance + weight(edge);
+                if !distances[other].is_some_and(|best| distance >= best) {
+                    distances[other] = Some(distance);
+                    previous[other] = Some(index);
+                    queue.push(Visit {
+                        distance,
+                        node: other,
+                    });
+                }
+            }
+        }
+
+        let length = distances[to]?;
+        let (mut nodes, mut edges) = (vec![to], vec![]);
+        let mut node = to;
+        while node != from {
+            let index = previous[node].unwrap();
+            let edge = &self.edges[index];
+            node = if edge.end == node {
+                edge.start
+            } else {
+                edge.end
+            };
+            nodes.push(node);
+            edges.push(index);
+        }
+        nodes.reverse();
+        edges.reverse();
+
+        let mut geometry = vec![self.nodes[from]];
+        for (&index, &start) in edges.iter().zip(&nodes) {
+            let edge = &self.edges[index];
+            let coords = &edge.geometry.0[1..];
+            if edge.start == start {
+                geometry.extend_from_slice(coords);
+            } else {
+                geometry.extend(edge.geometry.0.iter().rev().skip(1));
+            }
+        }
+        Some(NetworkPath {
+            nodes,
+            edges,
+            geometry: LineString::new(geometry),
+            length,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl LineNetwork<f64> {
+    /// The network of `line_strings`, of longitudes and latitudes, with the geodesic lengths
+    /// of its edges in meters, as from [`GeodesicLength`](crate::GeodesicLength).
+    pub fn new_geodesic(line_strings: &[LineString<f64>]) -> Self {
+        use crate::GeodesicLength;
+        Self::with_lengths(line_strings, |line_string| line_string.geodesic_length())
+    }
+}
+
+/// A node reached at a distance, ordered so that the nearest is the greatest, for the
+/// max-heap of Dijkstra's algorithm.
+struct Visit<T: GeoFloat> {
+    distance: T,
+    node: usize,
+}
+
+impl<T: GeoFloat> PartialEq for Visit<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: GeoFloat> Eq for Visit<T> {}
+
+impl<T: GeoFloat> PartialOrd for Visit<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Visit<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point};
+
+    #[test]
+    fn overlaps_and_self_intersections() {
+        let network = LineNetwork::new(&[
+            // Two lines which share the stretch from (2, 0) to (6, 0), in opposite directions
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.), (x: 6., y: 0.)],
+            line_string![
+                (x: 8., y: 2.),
+                (x: 6., y: 0.),
+                (x: 4., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 2.),
+            ],
+            // A figure of eight, crossing itself at (1, 11)
+            line_string![
+                (x: 0., y: 10.),
+                (x: 2., y: 12.),
+                (x: 2., y: 10.),
+                (x: 0., y: 12.),
+                (x: 0., y: 10.),
+            ],
+            // Nothing but a repeated point
+            line_string![(x: 50., y: 50.), (x: 50., y: 50.)],
+        ]);
+        let at = |x, y| network.node_at(coord! { x: x, y: y }).unwrap();
+
+        // The shared stretch is split where the segments of the lines meet, at (4, 0), and each
+        // part of it is a single edge
+        let shared: Vec<_> = network
+            .edges()
+            .iter()
+            .filter(|edge| (edge.start, edge.end) == (at(2., 0.), at(4., 0.)))
+            .collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].length, 2.);
+        assert_eq!(shared[0].source, 0);
+        assert_eq!(network.edges_at(at(2., 0.)).len(), 3);
+        assert_eq!(network.edges_at(at(4., 0.)).len(), 2);
+
+        // Including the loop from the crossing back to itself
+        let crossing = at(1., 11.);
+        assert_eq!(network.edges_at(crossing).len(), 3);
+        assert_eq!(network.edges_at(at(0., 10.)).len(), 2);
+        assert_eq!(network.edges().len(), 5 + 3);
+        assert_eq!(network.connected_components().len(), 2);
+
+        let path = network.shortest_path(at(2., 2.), at(0., 0.)).unwrap();
+        assert_eq!(path.nodes, [at(2., 2.), at(2., 0.), at(0., 0.)]);
+        assert_eq!(path.length, 4.);
+        let path = network.shortest_path(at(8., 2.), at(8., 2.)).unwrap();
+        assert_eq!(path.geometry, line_string![(x: 8., y: 2.)]);
+        assert!(path.edges.is_empty());
+    }
+
+    #[test]
+    fn weighted_paths() {
+        // A short road with a low speed limit, and a longer one with a high limit
+        let roads = [
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 5., y: 5.), (x: 10., y: 0.)],
+        ];
+        let network = LineNetwork::new(&roads);
+        let (from, to) = (
+            network.nearest_node(&point!(x: -1., y: 1.)).unwrap(),
+            network.nearest_node(&point!(x: 11., y: -1.)).unwrap(),
+        );
+        assert_eq!(network.shortest_path(from, to).unwrap().edges, [0]);
+        let speeds = [30., 100.];
+        let time = network
+            .shortest_path_by(from, to, |edge| edge.length / speeds[edge.source])
+            .unwrap();
+        assert_eq!(time.edges, [1]);
+        assert_eq!(
+            time.geometry,
+            line_string![(x: 0., y: 0.), (x: 5., y: 5.), (x: 10., y: 0.)]
+        );
+
+        let backwards = network.shortest_path(to, from).unwrap();
+        assert_eq!(
+            backwards.geometry,
+            line_string![(x: 10., y: 0.), (x: 0., y: 0.)]
+        );
+
+        let empty = LineNetwork::<f64>::new(&[]);
+        assert!(empty.nearest_node(&point!(x: 0., y: 0.)).is_none());
+        assert!(empty.connected_components().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn geodesic_lengths() {
+        // A degree of longitude along the equator
+        let network = LineNetwork::new_geodesic(&[line_string![(x: 0., y: 0.), (x: 1., y: 0.)]]);
+        assert_relative_eq!(network.edges()[0].length, 111_319.49, epsilon = 0.01);
+    }
+}
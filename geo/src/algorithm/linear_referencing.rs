@@ -0,0 +1,372 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Coord, GeoFloat, LineString, Point};
+
+/// A route for linear referencing: a `LineString`, such as the centre line of a road, with a
+/// measure for each location along it, such as the mileposts of the road.
+///
+/// A route is calibrated by points along it whose measures are known. The measures between
+/// the calibration points are interpolated by the distance along the route, and those before
+/// the first or after the last are extrapolated at the rate between the two nearest
+/// calibration points, so a route measured from milepost 12 at one end to milepost 17 at the
+/// other, through milepost 15 in the middle, is measured in proportion to the distance along
+/// each half.
+///
+/// With a route, locations can be converted between measures and geometry: a measure is
+/// [located](Self::locate) at a point, a range of measures is [extracted](Self::extract) as an
+/// event along the route, and a point, such as the report of an incident, is
+/// [projected](Self::project) to the measure of the closest location on the route.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, point, Route};
+///
+/// // A road, calibrated by the mileposts at either end and in the middle
+/// let road = line_string![(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 200.)];
+/// let mileposts = [
+///     (point!(x: 0., y: 0.), 12.),
+///     (point!(x: 100., y: 50.), 15.),
+///     (point!(x: 100., y: 200.), 17.),
+/// ];
+/// let route = Route::new(road, &mileposts)?;
+/// assert_eq!(route.measure_range(), (12., 17.));
+///
+/// assert_eq!(route.locate(13.5), Some(point!(x: 75., y: 0.)));
+/// assert_eq!(
+///     route.extract(13., 16.),
+///     Some(line_string![(x: 50., y: 0.), (x: 100., y: 0.), (x: 100., y: 125.)])
+/// );
+/// assert_relative_eq!(route.project(point!(x: 110., y: 125.)), 16.);
+/// # Ok::<(), geo::algorithm::linear_referencing::CalibrationError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route<T: GeoFloat> {
+    line_string: LineString<T>,
+    // The distance along the line to each of its coordinates
+    distances: Vec<T>,
+    // The distance along the line, and the measure, of each calibration point, in order
+    calibration: Vec<(T, T)>,
+}
+
+/// Why a [`Route`] couldn't be calibrated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// The line has no length to measure.
+    EmptyRoute,
+    /// There are fewer than two calibration points, at different places along the line.
+    TooFewCalibrationPoints,
+    /// A measure isn't finite, or the measures don't all increase, or all decrease, along
+    /// the line.
+    NonMonotonicMeasures,
+}
+
+impl core::fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CalibrationError::EmptyRoute => write!(f, "the route has no length"),
+            CalibrationError::TooFewCalibrationPoints => {
+                write!(
+                    f,
+                    "a route needs two calibration points at different places"
+                )
+            }
+            CalibrationError::NonMonotonicMeasures => {
+                write!(f, "the measures must increase or decrease along the route")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CalibrationError {}
+
+impl<T: GeoFloat> Route<T> {
+    /// Calibrate `line_string` with the measures of points along it. Each point is taken to
+    /// be at the closest location on the line.
+    pub fn new(
+        line_string: LineString<T>,
+        calibration_points: &[(Point<T>, T)],
+    ) -> Result<Self, CalibrationError> {
+        let mut distances = Vec::with_capacity(line_string.0.len());
+        let mut total = T::zero();
+        for (i, coord) in line_string.0.iter().enumerate() {
+            if i > 0 {
+                total = total + distance(line_string.0[i - 1], *coord);
+            }
+            distances.push(total);
+        }
+        if total <= T::zero() {
+            return Err(CalibrationError::EmptyRoute);
+        }
+
+        let mut route = Route {
+            line_string,
+            distances,
+            calibration: Vec::with_capacity(calibration_points.len()),
+        };
+        for &(point, measure) in calibration_points {
+            if !measure.is_finite() {
+                return Err(CalibrationError::NonMonotonicMeasures);
+            }
+            let (along, _) = route.closest(point.0);
+            route.calibration.push((along, measure));
+        }
+        route.calibration.sort_by(|a, b| a.0.total_cmp(&b.0));
+        // Points at the same place must agree on its measure
+        for pair in route.calibration.windows(2) {
+            if pair[0].0 == pair[1].0 && pair[0].1 != pair[1].1 {
+                return Err(CalibrationError::NonMonotonicMeasures);
+            }
+        }
+        route.calibration.dedup_by(|a, b| a.0 == b.0);
+        if route.calibration.len() < 2 {
+            return Err(CalibrationError::TooFewCalibrationPoints);
+        }
+        let increasing = route.calibration[1].1 > route.calibration[0].1;
+        let monotonic = route.calibration.windows(2).all(|pair| {
+            if increasing {
+                pair[1].1 > pair[0].1
+            } else {
+                pair[1].1 < pair[0].1
+            }
+        });
+        if !monotonic {
+            return Err(CalibrationError::NonMonotonicMeasures);
+        }
+        Ok(route)
+    }
+
+    /// Calibrate `line_string` with the measures of its start and end, in proportion to the
+    /// distance along it in between.
+    pub fn from_range(
+        line_string: LineString<T>,
+        start_measure: T,
+        end_measure: T,
+    ) -> Result<Self, CalibrationError> {
+        let start = line_string
+            .0
+            .first()
+            .map(|&coord| (coord.into(), start_measure));
+        let end = line_string
+            .0
+            .last()
+            .map(|&coord| (coord.into(), end_measure));
+        let calibration_points: Vec<_> = start.into_iter().chain(end).collect();
+        Self::new(line_string, &calibration_points)
+    }
+
+    /// The line of the route.
+    pub fn line_string(&self) -> &LineString<T> {
+        &self.line_string
+    }
+
+    /// The length of the route, in the units of its coordinates.
+    pub fn length(&self) -> T {
+        *self.distances.last().unwrap()
+    }
+
+    /// The measures at the start and at the end of the route.
+    pub fn measure_range(&self) -> (T, T) {
+        (self.measure_at(T::zero()), self.measure_at(self.length()))
+    }
+
+    /// The measure at `distance` along the route from its start.
+    pub fn measure_at(&self, distance: T) -> T {
+        // The calibration points either side of the distance, or the nearest two
+        let i = self
+            .calibration
+            .partition_point(|&(along, _)| along <= distance)
+            .max(1)
+            .min(self.calibration.len() - 1);
+        let ((start, start_measure), (end, end_measure)) =
+            (self.calibration[i - 1], self.calibration[i]);
+        start_measure + (end_measure - start_measure) * (distance - start) / (end - start)
+    }
+
+    /// The distance along the route from its start at which it has `measure`, if it's within
+    /// the measure range of the route.
+    pub fn distance_at(&self, measure: T) -> Option<T> {
+        let (start, end) = self.measure_range();
+        if !(measure >= start.min(end) && measure <= start.max(end)) {
+            return None;
+        }
+        // Measures increase or decrease along the route, as `sign` does
+        let sign = if end > start { T::one() } else { -T::one() };
+        let i = self
+            .calibration
+            .partition_point(|&(_, m)| m * sign <= measure * sign)
+            .max(1)
+            .min(self.calibration.len() - 1);
+        let ((start, start_measure), (end, end_measure)) =
+            (self.calibration[i - 1], self.calibration[i]);
+        let along =
+            start + (end - start) * (measure - start_measure) / (end_measure - start_measure);
+        Some(along.max(T::zero()).min(self.length()))
+    }
+
+    /// The point on the route at `measure`, if it's within the measure range of the route.
+    pub fn locate(&self, measure: T) -> Option<Point<T>> {
+        self.distance_at(measure)
+            .map(|along| self.coord_at(along).into())
+    }
+
+    /// The part of the route from `from_measure` to `to_measure`, if they're within the
+    /// measure range of the route. The line goes in the direction of the measures, against
+    /// the direction of the route if `to_measure` comes before `from_measure` along it.
+    pub fn extract(&self, from_measure: T, to_measure: T) -> Option<LineString<T>> {
+        let from = self.distance_at(from_measure)?;
+        let to = self.distance_at(to_measure)?;
+        let (start, end) = (from.min(to), from.max(to));
+
+        let coords = &self.line_string.0;
+        let mut extracted = vec![self.coord_at(start)];
+        extracted.extend(
+            coords
+                .iter()
+                .zip(&self.distances)
+                .filter(|&(_, &along)| along > start && along < end)
+                .map(|(&coord, _)| coord),
+        );
+        extracted.push(self.coord_at(end));
+        if to < from {
+            extracted.reverse();
+        }
+        Some(LineString::new(extracted))
+    }
+
+    /// The measure of the closest location on the route to `point`.
+    pub fn project(&self, point: Point<T>) -> T {
+        let (along, _) = self.closest(point.0);
+        self.measure_at(along)
+    }
+
+    /// The coordinate at `along` the line, which must be within it.
+    fn coord_at(&self, along: T) -> Coord<T> {
+        let coords = &self.line_string.0;
+        // The first line which ends at or beyond `along`
+        let i = self
+            .distances
+            .partition_point(|&d| d < along)
+            .max(1)
+            .min(coords.len() - 1);
+        let (start, end) = (coords[i - 1], coords[i]);
+        let length = self.distances[i] - self.distances[i - 1];
+        if along >= self.distances[i] {
+            end
+        } else if length <= T::zero() || along <= self.distances[i - 1] {
+            start
+        } else {
+            start + (end - start) * (along - self.distances[i - 1]) / length
+        }
+    }
+
+    /// The distance along the line to the closest location on it to `coord`, and its distance
+    /// from `coord`.
+    fn closest(&self, coord: Coord<T>) -> (T, T) {
+        let coords = &self.line_string.0;
+        let mut closest = (T::zero(), distance(coords[0], coord));
+        for i in 1..coords.len() {
+            let (start, end) = (coords[i - 1], coords[i]);
+            let delta = end - start;
+            let length_squared = delta.x * delta.x + delta.y * delta.y;
+            if length_squared == T::zero() {
+                continue;
+            }
+            let offset = coord - start;
+            let fraction = ((offset.x * delta.x + offset.y * delta.y) / length_squared)
+                .max(T::zero())
+                .min(T::one());
+            let d = distance(start + delta * fraction, coord);
+            if d < closest.1 {
+                let along =
+                    self.distances[i - 1] + (self.distances[i] - self.distances[i - 1]) * fraction;
+                closest = (along, d);
+            }
+        }
+        closest
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn interpolates_and_extrapolates() {
+        let line_string: LineString =
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        // Measured from the middle of the first line, decreasing along the route
+        let route = Route::new(
+            line_string.clone(),
+            &[(point!(x: 5., y: -1.), 100.), (point!(x: 10., y: 5.), 90.)],
+        )
+        .unwrap();
+        assert_eq!(route.length(), 20.);
+        assert_eq!(route.measure_range(), (105., 85.));
+        assert_eq!(route.measure_at(10.), 95.);
+        assert_eq!(route.distance_at(95.), Some(10.));
+        assert_eq!(route.distance_at(110.), None);
+        assert_eq!(route.distance_at(80.), None);
+        assert_eq!(route.locate(85.), Some(point!(x: 10., y: 10.)));
+        assert_eq!(route.project(point!(x: -3., y: 4.)), 105.);
+
+        // Against the route, in the direction of the measures
+        assert_eq!(
+            route.extract(88., 102.),
+            Some(line_string![(x: 10., y: 7.), (x: 10., y: 0.), (x: 3., y: 0.)])
+        );
+        assert_eq!(
+            route.extract(102., 88.),
+            Some(line_string![(x: 3., y: 0.), (x: 10., y: 0.), (x: 10., y: 7.)])
+        );
+        assert_eq!(route.extract(102., 120.), None);
+
+        let uniform = Route::from_range(line_string, 0., 2.).unwrap();
+        assert_eq!(uniform.measure_range(), (0., 2.));
+        assert_eq!(uniform.locate(1.), Some(point!(x: 10., y: 0.)));
+        assert_eq!(uniform.extract(0., 2.), Some(uniform.line_string().clone()));
+    }
+
+    #[test]
+    fn calibration_errors() {
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        assert_eq!(
+            Route::from_range(line_string![(x: 1., y: 1.), (x: 1., y: 1.)], 0., 1.),
+            Err(CalibrationError::EmptyRoute)
+        );
+        assert_eq!(
+            Route::new(line_string.clone(), &[(point!(x: 1., y: 0.), 1.)]),
+            Err(CalibrationError::TooFewCalibrationPoints)
+        );
+        // The same place twice
+        assert_eq!(
+            Route::new(
+                line_string.clone(),
+                &[(point!(x: 1., y: 0.), 1.), (point!(x: 1., y: 5.), 1.)]
+            ),
+            Err(CalibrationError::TooFewCalibrationPoints)
+        );
+        assert_eq!(
+            Route::new(
+                line_string.clone(),
+                &[
+                    (point!(x: 1., y: 0.), 1.),
+                    (point!(x: 2., y: 0.), 3.),
+                    (point!(x: 3., y: 0.), 2.)
+                ]
+            ),
+            Err(CalibrationError::NonMonotonicMeasures)
+        );
+        assert_eq!(
+            Route::from_range(line_string, 0., f64::NAN),
+            Err(CalibrationError::NonMonotonicMeasures)
+        );
+    }
+}
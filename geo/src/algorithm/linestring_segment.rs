@@ -3,6 +3,7 @@ use crate::{
     Coord, Densify, DensifyHaversine, EuclideanLength, HaversineLength, LineString, LinesIter,
     MultiLineString,
 };
+use alloc::vec::Vec;
 
 /// Segments a LineString into `n` equal length LineStrings as a MultiLineString.
 /// `None` will be returned when `n` is equal to 0 or when a point
@@ -0,0 +1,308 @@
+use alloc::vec::Vec;
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::kernels::{Kernel, Orientation};
+use crate::{BoundingRect, Coord, CoordsIter, GeoFloat, Line, MultiPolygon, Point, Polygon, Rect};
+
+/// Find the longest straight line which lies within a polygon, its boundary included, such as
+/// to measure the length of a lake or of a parcel, which a bounding rectangle can't.
+///
+/// The longest line always passes through at least two of the vertices of the polygon, or
+/// through at least one if it's constrained to pass through a point or go in one of a set
+/// of directions, so only the lines through them are searched. For a polygon with `n`
+/// vertices this takes about `O(n³)` time, and `O(n²)` time when constrained, so
+/// [simplifying](crate::Simplify) a detailed polygon first is much faster.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Line, LongestChord};
+///
+/// // An L shaped lake, whose longest line cuts across its inner corner
+/// let lake = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 2.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+/// assert_eq!(lake.longest_chord(), Some(Line::new((10., 0.), (0., 2.5))));
+///
+/// // The longest horizontal line
+/// assert_eq!(
+///     lake.longest_chord_at_angles(&[0.]),
+///     Some(Line::new((0., 0.), (10., 0.)))
+/// );
+///
+/// // The longest line through a point in the lower arm, from corner to corner
+/// let through = lake.longest_chord_through(point!(x: 5., y: 1.));
+/// assert_eq!(through, Some(Line::new((10., 2.), (0., 0.))));
+/// ```
+pub trait LongestChord<T: GeoFloat> {
+    /// The longest line within the polygon, or `None` if it's empty.
+    fn longest_chord(&self) -> Option<Line<T>>;
+
+    /// The longest line within the polygon which passes through `point`, or `None` if the
+    /// point isn't within it.
+    fn longest_chord_through(&self, point: Point<T>) -> Option<Line<T>>;
+
+    /// The longest line within the polygon which is at one of `angles`, in degrees
+    /// counter-clockwise from the x-axis, or `None` if it's empty or there aren't any angles.
+    fn longest_chord_at_angles(&self, angles: &[T]) -> Option<Line<T>>;
+}
+
+impl<T: GeoFloat> LongestChord<T> for Polygon<T> {
+    fn longest_chord(&self) -> Option<Line<T>> {
+        let vertices = vertices(self);
+        let bounds = self.bounding_rect()?;
+        let mut longest = Longest::default();
+        for (i, &a) in vertices.iter().enumerate() {
+            for &b in &vertices[i + 1..] {
+                if a == b || bounded_length(bounds, a, b - a) <= longest.length {
+                    continue;
+                }
+                for (start, end) in intervals(self, a, b) {
+                    longest.add(chord(a, b - a, start, end));
+                }
+            }
+        }
+        longest.line
+    }
+
+    fn longest_chord_through(&self, point: Point<T>) -> Option<Line<T>> {
+        let origin = point.0;
+        if self.coordinate_position(&origin) == CoordPos::Outside {
+            return None;
+        }
+        let mut longest = Longest::default();
+        for vertex in vertices(self) {
+            if vertex == origin {
+                continue;
+            }
+            let through = intervals(self, origin, vertex)
+                .into_iter()
+                .find(|&(start, end)| start <= T::zero() && end >= T::zero());
+            if let Some((start, end)) = through {
+                longest.add(chord(origin, vertex - origin, start, end));
+            }
+        }
+        // Without another vertex to aim at, the polygon is a single point
+        longest.line.or(Some(Line::new(origin, origin)))
+    }
+
+    fn longest_chord_at_angles(&self, angles: &[T]) -> Option<Line<T>> {
+        let vertices = vertices(self);
+        let bounds = self.bounding_rect()?;
+        let mut longest = Longest::default();
+        for angle in angles {
+            let radians = angle.to_radians();
+            let direction = Coord {
+                x: radians.cos(),
+                y: radians.sin(),
+            };
+            for &vertex in &vertices {
+                if bounded_length(bounds, vertex, direction) <= longest.length {
+                    continue;
+                }
+                for (start, end) in intervals(self, vertex, vertex + direction) {
+                    longest.add(chord(vertex, direction, start, end));
+                }
+            }
+        }
+        longest.line
+    }
+}
+
+impl<T: GeoFloat> LongestChord<T> for MultiPolygon<T> {
+    fn longest_chord(&self) -> Option<Line<T>> {
+        let mut longest = Longest::default();
+        self.iter()
+            .filter_map(|polygon| polygon.longest_chord())
+            .for_each(|line| longest.add(line));
+        longest.line
+    }
+
+    fn longest_chord_through(&self, point: Point<T>) -> Option<Line<T>> {
+        let mut longest = Longest::default();
+        self.iter()
+            .filter_map(|polygon| polygon.longest_chord_through(point))
+            .for_each(|line| longest.add(line));
+        longest.line
+    }
+
+    fn longest_chord_at_angles(&self, angles: &[T]) -> Option<Line<T>> {
+        let mut longest = Longest::default();
+        self.iter()
+            .filter_map(|polygon| polygon.longest_chord_at_angles(angles))
+            .for_each(|line| longest.add(line));
+        longest.line
+    }
+}
+
+struct Longest<T: GeoFloat> {
+    line: Option<Line<T>>,
+    length: T,
+}
+
+impl<T: GeoFloat> Default for Longest<T> {
+    fn default() -> Self {
+        Longest {
+            line: None,
+            length: T::neg_infinity(),
+        }
+    }
+}
+
+impl<T: GeoFloat> Longest<T> {
+    fn add(&mut self, line: Line<T>) {
+        let length = line.dx().hypot(line.dy());
+        if length > self.length {
+            self.line = Some(line);
+            self.length = length;
+        }
+    }
+}
+
+/// The vertices of all the rings of the polygon, without their closing coordinates.
+fn vertices<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Coord<T>> {
+    core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.0.split_last().map_or(&[][..], |(_, open)| open))
+        .copied()
+        .collect()
+}
+
+/// The line from `start` to `end` along the line through `origin` in `direction`.
+fn chord<T: GeoFloat>(origin: Coord<T>, direction: Coord<T>, start: T, end: T) -> Line<T> {
+    Line::new(origin + direction * start, origin + direction * end)
+}
+
+/// The length of the part of the line through `origin` in `direction` within `bounds`, which
+/// no line within the polygon is longer than.
+fn bounded_length<T: GeoFloat>(bounds: Rect<T>, origin: Coord<T>, direction: Coord<T>) -> T {
+    let (mut low, mut high) = (T::neg_infinity(), T::infinity());
+    for (o, d, min, max) in [
+        (origin.x, direction.x, bounds.min().x, bounds.max().x),
+        (origin.y, direction.y, bounds.min().y, bounds.max().y),
+    ] {
+        if d != T::zero() {
+            let (a, b) = ((min - o) / d, (max - o) / d);
+            low = low.max(a.min(b));
+            high = high.min(a.max(b));
+        }
+    }
+    (high - low).max(T::zero()) * direction.x.hypot(direction.y)
+}
+
+/// The parts of the line through `origin` and `towards` which are within the polygon, as
+/// sorted and disjoint intervals of multiples of the distance from `origin` to `towards`.
+fn intervals<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    origin: Coord<T>,
+    towards: Coord<T>,
+) -> Vec<(T, T)> {
+    let direction = towards - origin;
+    let length_squared = direction.x * direction.x + direction.y * direction.y;
+    let along = |coord: Coord<T>| {
+        let offset = coord - origin;
+        (offset.x * direction.x + offset.y * direction.y) / length_squared
+    };
+
+    // Where the line meets the boundary
+    let mut crossings = Vec::new();
+    for line in polygon
+        .exterior()
+        .lines()
+        .chain(polygon.interiors().iter().flat_map(|ring| ring.lines()))
+    {
+        let start_side = T::Ker::orient2d(origin, towards, line.start);
+        let end_side = T::Ker::orient2d(origin, towards, line.end);
+        if start_side == Orientation::Collinear {
+            crossings.push(along(line.start));
+        }
+        if end_side == Orientation::Collinear {
+            crossings.push(along(line.end));
+        }
+        if start_side != Orientation::Collinear
+            && end_side != Orientation::Collinear
+            && start_side != end_side
+        {
+            let cross = |coord: Coord<T>| {
+                let offset = coord - origin;
+                direction.x * offset.y - direction.y * offset.x
+            };
+            let (a, b) = (cross(line.start), cross(line.end));
+            crossings.push(along(line.start + line.delta() * (a / (a - b))));
+        }
+    }
+    crossings.sort_by(|a, b| a.total_cmp(b));
+    crossings.dedup();
+
+    // Between the crossings, the line is either wholly within the polygon or wholly outside it
+    let mut intervals: Vec<(T, T)> = Vec::new();
+    for pair in crossings.windows(2) {
+        let middle = origin + direction * ((pair[0] + pair[1]) / (T::one() + T::one()));
+        if polygon.coordinate_position(&middle) == CoordPos::Outside {
+            continue;
+        }
+        match intervals.last_mut() {
+            Some(last) if last.1 == pair[0] => last.1 = pair[1],
+            _ => intervals.push((pair[0], pair[1])),
+        }
+    }
+    // A line which only touches the polygon
+    if intervals.is_empty() && polygon.coords_count() > 0 {
+        if let Some(&touch) = crossings.first() {
+            intervals.push((touch, touch));
+        }
+    }
+    intervals
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Centroid, EuclideanLength, Relate};
+
+    #[test]
+    fn longest_chords() {
+        // A square with a hole which blocks its diagonals
+        let polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        ];
+        // From a corner, past a corner of the hole, to the far side
+        let longest = polygon.longest_chord().unwrap();
+        assert_relative_eq!(longest, Line::new((0., 0.), (10., 20. / 3.)));
+        assert!(polygon.relate(&longest).is_covers());
+
+        // The centroid is in the hole
+        let through_centroid = polygon.longest_chord_through(polygon.centroid().unwrap());
+        assert_eq!(through_centroid, None);
+        // From a corner, passing under the hole
+        let through = polygon.longest_chord_through(point!(x: 2., y: 5.)).unwrap();
+        assert_relative_eq!(through.euclidean_length(), 139.0625_f64.sqrt());
+
+        let at_angles = polygon.longest_chord_at_angles(&[0., 90.]).unwrap();
+        assert_relative_eq!(at_angles.euclidean_length(), 10.);
+        assert_eq!(polygon.longest_chord_at_angles(&[]), None);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 5., y: 0.), (x: 8., y: 0.), (x: 8., y: 1.), (x: 5., y: 1.)],
+        ]);
+        assert_eq!(
+            multi_polygon.longest_chord().unwrap().euclidean_length(),
+            10_f64.sqrt()
+        );
+        assert_eq!(
+            multi_polygon.longest_chord_through(point!(x: 0.5, y: 0.5)),
+            Some(Line::new((1., 1.), (0., 0.)))
+        );
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).longest_chord(), None);
+    }
+}
@@ -26,6 +26,7 @@
 
 pub(crate) use crate::geometry::*;
 pub(crate) use crate::CoordNum;
+use alloc::vec::Vec;
 
 /// Map a function over all the coordinates in an object, returning a new one
 pub trait MapCoords<T, NT> {
@@ -84,7 +85,7 @@ pub trait MapCoords<T, NT> {
     ///
     /// let p1 = Point::new(10., 20.);
     /// let p2 = p1
-    ///     .try_map_coords(|Coord { x, y }| -> Result<_, std::convert::Infallible> {
+    ///     .try_map_coords(|Coord { x, y }| -> Result<_, core::convert::Infallible> {
     ///         Ok(Coord { x: x + 1000., y: y * 2. })
     ///     }).unwrap();
     ///
@@ -639,7 +640,7 @@ impl<T: CoordNum, NT: CoordNum> MapCoords<T, NT> for Rect<T> {
 impl<T: CoordNum> MapCoordsInPlace<T> for Rect<T> {
     fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T>) {
         let mut new_rect = Rect::new(func(self.min()), func(self.max()));
-        ::std::mem::swap(self, &mut new_rect);
+        ::core::mem::swap(self, &mut new_rect);
     }
 
     fn try_map_coords_in_place<E>(
@@ -647,7 +648,7 @@ impl<T: CoordNum> MapCoordsInPlace<T> for Rect<T> {
         func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
     ) -> Result<(), E> {
         let mut new_rect = Rect::new(func(self.min())?, func(self.max())?);
-        ::std::mem::swap(self, &mut new_rect);
+        ::core::mem::swap(self, &mut new_rect);
         Ok(())
     }
 }
@@ -675,7 +676,7 @@ impl<T: CoordNum> MapCoordsInPlace<T> for Triangle<T> {
     fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T>) {
         let mut new_triangle = Triangle::new(func(self.0), func(self.1), func(self.2));
 
-        ::std::mem::swap(self, &mut new_triangle);
+        ::core::mem::swap(self, &mut new_triangle);
     }
 
     fn try_map_coords_in_place<E>(
@@ -684,7 +685,7 @@ impl<T: CoordNum> MapCoordsInPlace<T> for Triangle<T> {
     ) -> Result<(), E> {
         let mut new_triangle = Triangle::new(func(self.0)?, func(self.1)?, func(self.2)?);
 
-        ::std::mem::swap(self, &mut new_triangle);
+        ::core::mem::swap(self, &mut new_triangle);
 
         Ok(())
     }
@@ -767,7 +768,7 @@ mod test {
         let rect = Rect::new((2, 2), (3, 3));
         // Rect's enforce that rect.min is up and left of p2.  Here we test that the points are
         // normalized into a valid rect, regardless of the order they are mapped.
-        let result: Result<_, std::convert::Infallible> = rect.try_map_coords(|pt| {
+        let result: Result<_, core::convert::Infallible> = rect.try_map_coords(|pt| {
             match pt.x_y() {
                 // old min point maps to new max point
                 (2, 2) => Ok((4, 4).into()),
@@ -0,0 +1,397 @@
+use alloc::vec::Vec;
+
+use num_traits::FromPrimitive;
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+use crate::{
+    Area, BooleanOps, BoundingRect, CoordsIter, FrechetDistance, GeoFloat, HausdorffDistance,
+    LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon, PrincipalAxes, Rect,
+};
+
+/// The measures of how alike two features are, which [`FeatureMatcher`] compares them by.
+///
+/// Every feature has the [Hausdorff distance](HausdorffDistance) between its vertices and
+/// those of another, and an orientation, the angle of its
+/// [principal axis](PrincipalAxes). Line strings also have the
+/// [Fréchet distance](FrechetDistance), which follows them along their lengths, and polygons
+/// the ratio of the area of their intersection to that of their union.
+pub trait MatchMeasures<T: GeoFloat>:
+    BoundingRect<T> + CoordsIter<Scalar = T> + PrincipalAxes<T>
+{
+    /// The Fréchet distance to `other`, if it applies to features of this type.
+    fn frechet_distance_to(&self, _other: &Self) -> Option<T> {
+        None
+    }
+
+    /// The ratio of the area of the intersection with `other` to that of their union, from 0
+    /// for features which don't overlap to 1 for features which cover each other, if it
+    /// applies to features of this type.
+    fn overlap_ratio(&self, _other: &Self) -> Option<T> {
+        None
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> MatchMeasures<T> for LineString<T> {
+    fn frechet_distance_to(&self, other: &Self) -> Option<T> {
+        Some(self.frechet_distance(other))
+    }
+}
+
+impl<T: GeoFloat> MatchMeasures<T> for MultiLineString<T> {}
+
+impl<T: GeoFloat> MatchMeasures<T> for MultiPoint<T> {}
+
+impl<T: GeoFloat> MatchMeasures<T> for Polygon<T> {
+    fn overlap_ratio(&self, other: &Self) -> Option<T> {
+        overlap_ratio(self, other)
+    }
+}
+
+impl<T: GeoFloat> MatchMeasures<T> for MultiPolygon<T> {
+    fn overlap_ratio(&self, other: &Self) -> Option<T> {
+        overlap_ratio(self, other)
+    }
+}
+
+fn overlap_ratio<T: GeoFloat, G>(a: &G, b: &G) -> Option<T>
+where
+    G: BooleanOps<Scalar = T> + Area<T>,
+{
+    let intersection = a.intersection(b).unsigned_area();
+    let union = a.unsigned_area() + b.unsigned_area() - intersection;
+    Some(if union > T::zero() {
+        (intersection / union).min(T::one())
+    } else {
+        T::zero()
+    })
+}
+
+/// A pair of features which [`FeatureMatcher`] found alike, with the measures of how alike.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CandidateMatch<T: GeoFloat> {
+    /// The index of the feature in the first dataset.
+    pub first: usize,
+    /// The index of the feature in the second dataset.
+    pub second: usize,
+    /// How alike the features are, from 0 to 1, as the mean of the scores of the criteria.
+    pub score: T,
+    /// The Hausdorff distance between the features.
+    pub hausdorff: T,
+    /// The Fréchet distance between the features, if they're line strings.
+    pub frechet: Option<T>,
+    /// The ratio of the area of the intersection of the features to that of their union, if
+    /// they're polygons.
+    pub overlap: Option<T>,
+    /// The difference between the orientations of the features, in degrees from 0 to 90, if
+    /// they both have one.
+    pub angle: Option<T>,
+}
+
+/// Pair up the features of two datasets which represent the same things, such as the roads of
+/// two maps, or building footprints from two surveys, for conflating them.
+///
+/// A pair is a candidate match if it meets every criterion which is set:
+///
+/// - a maximum [Hausdorff distance](HausdorffDistance) between the features
+/// - a maximum [Fréchet distance](FrechetDistance), for line strings
+/// - a minimum overlap ratio, of the area of their intersection to that of their union, for
+///   polygons
+/// - a maximum difference between the angles of their [principal axes](PrincipalAxes), in
+///   degrees, which suits elongated features such as roads and buildings, since the angle of
+///   a feature without a dominant direction is arbitrary
+///
+/// Criteria which don't apply to the type of the features are ignored. Each criterion met
+/// scores from 0 at its threshold to 1 for a perfect match, and a candidate's score is the mean
+/// of them. Only pairs whose bounding rectangles are within the greatest distance threshold of
+/// each other are measured, found with an R-tree, so that each feature is compared with its
+/// neighbours rather than the whole other dataset.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, FeatureMatcher};
+///
+/// let surveyed = [
+///     line_string![(x: 0., y: 0.), (x: 50., y: 0.), (x: 100., y: 0.)],
+///     line_string![(x: 0., y: 50.), (x: 0., y: 100.), (x: 0., y: 150.)],
+/// ];
+/// // The same roads, digitized a little differently, and a new one
+/// let digitized = [
+///     line_string![(x: 0., y: 52.), (x: 1., y: 100.), (x: 0., y: 149.)],
+///     line_string![(x: 1., y: 1.), (x: 50., y: 2.), (x: 99., y: 1.)],
+///     line_string![(x: 200., y: 0.), (x: 300., y: 0.)],
+/// ];
+///
+/// let matcher = FeatureMatcher::new().max_hausdorff(5.).max_frechet(5.).max_angle(10.);
+/// let matches = matcher.one_to_one(&surveyed, &digitized);
+/// let pairs: Vec<_> = matches.iter().map(|m| (m.first, m.second)).collect();
+/// assert_eq!(pairs.len(), 2);
+/// assert!(pairs.contains(&(0, 1)) && pairs.contains(&(1, 0)));
+/// assert!(matches.iter().all(|m| m.score > 0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FeatureMatcher<T: GeoFloat = f64> {
+    max_hausdorff: Option<T>,
+    max_frechet: Option<T>,
+    min_overlap: Option<T>,
+    max_angle: Option<T>,
+}
+
+impl<T: GeoFloat + RTreeNum> FeatureMatcher<T> {
+    /// A matcher without criteria, which matches every pair of features whose bounding
+    /// rectangles intersect, with a score of 1.
+    pub fn new() -> Self {
+        FeatureMatcher {
+            max_hausdorff: None,
+            max_frechet: None,
+            min_overlap: None,
+            max_angle: None,
+        }
+    }
+
+    /// Only match features within `distance` of each other, by the Hausdorff distance.
+    #[must_use]
+    pub fn max_hausdorff(mut self, distance: T) -> Self {
+        self.max_hausdorff = Some(distance);
+        self
+    }
+
+    /// Only match line strings within `distance` of each other, by the Fréchet distance.
+    #[must_use]
+    pub fn max_frechet(mut self, distance: T) -> Self {
+        self.max_frechet = Some(distance);
+        self
+    }
+
+    /// Only match polygons whose intersection has at least `ratio` of the area of their union.
+    #[must_use]
+    pub fn min_overlap(mut self, ratio: T) -> Self {
+        self.min_overlap = Some(ratio);
+        self
+    }
+
+    /// Only match features whose orientations differ by at most `degrees`.
+    #[must_use]
+    pub fn max_angle(mut self, degrees: T) -> Self {
+        self.max_angle = Some(degrees);
+        self
+    }
+
+    /// Every candidate match between a feature of `first` and one of `second`, from the best
+    /// score to the worst.
+    pub fn matches<G: MatchMeasures<T>>(
+        &self,
+        first: &[G],
+        second: &[G],
+    ) -> Vec<CandidateMatch<T>> {
+        // Features further apart than the distance thresholds can't match
+        let reach = [self.max_hausdorff, self.max_frechet]
+            .into_iter()
+            .flatten()
+            .fold(T::zero(), T::max);
+        let tree = RTree::bulk_load(
+            second
+                .iter()
+                .enumerate()
+                .filter_map(|(index, feature)| {
+                    let rect: Rect<T> = feature.bounding_rect().into()?;
+                    let rectangle = Rectangle::from_corners(rect.min().into(), rect.max().into());
+                    Some(GeomWithData::new(rectangle, index))
+                })
+                .collect(),
+        );
+
+        let mut matches = Vec::new();
+        for (i, feature) in first.iter().enumerate() {
+            let Some(rect): Option<Rect<T>> = feature.bounding_rect().into() else {
+                continue;
+            };
+            let envelope = AABB::from_corners(
+                [rect.min().x - reach, rect.min().y - reach],
+                [rect.max().x + reach, rect.max().y + reach],
+            );
+            let mut candidates: Vec<usize> = tree
+                .locate_in_envelope_intersecting(&envelope)
+                .map(|candidate| candidate.data)
+                .collect();
+            candidates.sort_unstable();
+            matches.extend(
+                candidates
+                    .into_iter()
+                    .filter_map(|j| self.measure(feature, &second[j], i, j)),
+            );
+        }
+        matches.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.first.cmp(&b.first))
+                .then(a.second.cmp(&b.second))
+        });
+        matches
+    }
+
+    /// The best candidate matches which pair each feature with at most one other, chosen
+    /// greedily from the best score down.
+    pub fn one_to_one<G: MatchMeasures<T>>(
+        &self,
+        first: &[G],
+        second: &[G],
+    ) -> Vec<CandidateMatch<T>> {
+        let mut first_matched = alloc::vec![false; first.len()];
+        let mut second_matched = alloc::vec![false; second.len()];
+        self.matches(first, second)
+            .into_iter()
+            .filter(|candidate| {
+                let free = !first_matched[candidate.first] && !second_matched[candidate.second];
+                if free {
+                    first_matched[candidate.first] = true;
+                    second_matched[candidate.second] = true;
+                }
+                free
+            })
+            .collect()
+    }
+
+    /// The candidate match between `a` and `b`, if they meet every criterion.
+    fn measure<G: MatchMeasures<T>>(
+        &self,
+        a: &G,
+        b: &G,
+        first: usize,
+        second: usize,
+    ) -> Option<CandidateMatch<T>> {
+        let mut scores = Vec::new();
+        let hausdorff = a.hausdorff_distance(b);
+        if let Some(max) = self.max_hausdorff {
+            scores.push(below(hausdorff, max)?);
+        }
+        let frechet = a.frechet_distance_to(b);
+        if let (Some(max), Some(frechet)) = (self.max_frechet, frechet) {
+            scores.push(below(frechet, max)?);
+        }
+        let overlap = a.overlap_ratio(b);
+        if let (Some(min), Some(overlap)) = (self.min_overlap, overlap) {
+            if overlap < min || overlap <= T::zero() {
+                return None;
+            }
+            scores.push(if min < T::one() {
+                (overlap - min) / (T::one() - min)
+            } else {
+                T::one()
+            });
+        }
+        let angle = a.principal_axes().zip(b.principal_axes()).map(|(a, b)| {
+            let difference = (a.angle - b.angle).abs();
+            let half_turn = T::from(180).unwrap();
+            difference.min(half_turn - difference)
+        });
+        if let Some(max) = self.max_angle {
+            scores.push(below(angle?, max)?);
+        }
+
+        let score = if scores.is_empty() {
+            T::one()
+        } else {
+            scores.iter().fold(T::zero(), |sum, score| sum + *score)
+                / T::from(scores.len()).unwrap()
+        };
+        Some(CandidateMatch {
+            first,
+            second,
+            score,
+            hausdorff,
+            frechet,
+            overlap,
+            angle,
+        })
+    }
+}
+
+/// The score of `value` against the threshold `max`, from 1 at zero to 0 at the threshold, or
+/// `None` beyond it.
+fn below<T: GeoFloat>(value: T, max: T) -> Option<T> {
+    if value > max {
+        None
+    } else if max > T::zero() {
+        Some(T::one() - value / max)
+    } else {
+        Some(T::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Rotate, Translate};
+
+    #[test]
+    fn buildings() {
+        let footprint = |x: f64, y: f64| {
+            polygon![
+                (x: x, y: y),
+                (x: x + 20., y: y),
+                (x: x + 20., y: y + 8.),
+                (x: x, y: y + 8.),
+            ]
+        };
+        let old = [footprint(0., 0.), footprint(50., 0.), footprint(100., 0.)];
+        let new = [
+            // Shifted slightly
+            footprint(101., 1.),
+            // Rotated so that it still overlaps, but is oriented across the original
+            footprint(50., 0.).rotate_around_centroid(90.),
+            footprint(0., 0.).translate(0.5, 0.),
+        ];
+
+        let matcher = FeatureMatcher::new().min_overlap(0.5);
+        let pairs: Vec<_> = matcher
+            .matches(&old, &new)
+            .iter()
+            .map(|m| (m.first, m.second))
+            .collect();
+        assert_eq!(pairs, [(0, 2), (2, 0)]);
+
+        // The rotated building overlaps enough, but with the wrong orientation
+        let matcher = FeatureMatcher::new().min_overlap(0.2).max_angle(15.);
+        let matches = matcher.matches(&old, &new);
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|m| m.angle.unwrap() < 1e-9));
+        let matches = FeatureMatcher::new().min_overlap(0.2).matches(&old, &new);
+        let rotated = matches.iter().find(|m| m.second == 1).unwrap();
+        assert_relative_eq!(rotated.angle.unwrap(), 90., epsilon = 1e-9);
+        assert_relative_eq!(rotated.overlap.unwrap(), 64. / (320. - 64.));
+        assert_eq!(rotated.frechet, None);
+
+        // The best match comes first
+        assert_eq!(matches[0].second, 2);
+        assert!(matches[0].score > matches[1].score);
+    }
+
+    #[test]
+    fn one_to_one() {
+        // Two copies of a road, both near the original
+        let original = [LineString::from(vec![(0., 0.), (10., 0.)])];
+        let copies = [
+            LineString::from(vec![(0., 2.), (10., 2.)]),
+            LineString::from(vec![(0., 1.), (10., 1.)]),
+        ];
+        let matcher = FeatureMatcher::new().max_hausdorff(3.).max_frechet(3.);
+        let matches = matcher.matches(&original, &copies);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].frechet, Some(1.));
+        assert_relative_eq!(matches[0].score, 2. / 3.);
+
+        let best = matcher.one_to_one(&original, &copies);
+        assert_eq!(best.len(), 1);
+        assert_eq!(best[0].second, 1);
+
+        assert!(matcher
+            .matches(&original, &[LineString::new(vec![])])
+            .is_empty());
+        assert!(FeatureMatcher::new()
+            .max_hausdorff(0.5)
+            .matches(&original, &copies)
+            .is_empty());
+    }
+}
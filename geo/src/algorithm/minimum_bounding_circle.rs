@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+
+use crate::{ConvexHull, Coord, CoordsIter, GeoFloat, Point};
+
+/// A circle, from [`MinimumBoundingCircle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingCircle<T: GeoFloat> {
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+/// Calculate the smallest circle which encloses a geometry.
+///
+/// Only the vertices of the convex hull of the geometry can be on the circle, so the circle is
+/// found from those, with the incremental form of [Welzl's
+/// algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm).
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, polygon, MinimumBoundingCircle};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let circle = square.minimum_bounding_circle().unwrap();
+/// assert_relative_eq!(circle.center, point!(x: 1., y: 1.));
+/// assert_relative_eq!(circle.radius, 2f64.sqrt());
+/// ```
+pub trait MinimumBoundingCircle<T: GeoFloat> {
+    fn minimum_bounding_circle(&self) -> Option<BoundingCircle<T>>;
+}
+
+impl<T, G> MinimumBoundingCircle<T> for G
+where
+    T: GeoFloat,
+    G: CoordsIter<Scalar = T>,
+{
+    fn minimum_bounding_circle(&self) -> Option<BoundingCircle<T>> {
+        let hull = self.convex_hull();
+        let mut points: Vec<Coord<T>> = hull.exterior().0.clone();
+        // The hull is closed
+        points.pop();
+        if points.is_empty() {
+            points.extend(self.coords_iter().take(1));
+        }
+        smallest_circle(&points)
+    }
+}
+
+/// The smallest circle enclosing `points`, by trying each point, pair and triple of points on its
+/// boundary only when the circle so far doesn't contain the next point.
+fn smallest_circle<T: GeoFloat>(points: &[Coord<T>]) -> Option<BoundingCircle<T>> {
+    let mut circle = Circle::point(*points.first()?);
+    for i in 1..points.len() {
+        if circle.contains(points[i]) {
+            continue;
+        }
+        circle = Circle::point(points[i]);
+        for j in 0..i {
+            if circle.contains(points[j]) {
+                continue;
+            }
+            circle = Circle::diameter(points[i], points[j]);
+            for k in 0..j {
+                if !circle.contains(points[k]) {
+                    circle = Circle::through(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    Some(BoundingCircle {
+        center: circle.center.into(),
+        radius: circle.radius,
+    })
+}
+
+struct Circle<T: GeoFloat> {
+    center: Coord<T>,
+    radius: T,
+}
+
+impl<T: GeoFloat> Circle<T> {
+    fn point(center: Coord<T>) -> Self {
+        Circle {
+            center,
+            radius: T::zero(),
+        }
+    }
+
+    fn diameter(a: Coord<T>, b: Coord<T>) -> Self {
+        let center = (a + b) / T::from(2).unwrap();
+        Circle {
+            center,
+            radius: distance(center, a).max(distance(center, b)),
+        }
+    }
+
+    /// The circle through `a`, `b` and `c`, or the circle on the furthest apart of them if
+    /// they're collinear.
+    fn through(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Self {
+        let (ab, ac) = (b - a, c - a);
+        let d = T::from(2).unwrap() * (ab.x * ac.y - ab.y * ac.x);
+        if d == T::zero() {
+            return [(a, b), (a, c), (b, c)]
+                .into_iter()
+                .map(|(p, q)| Circle::diameter(p, q))
+                .fold(Circle::point(a), |largest, circle| {
+                    if circle.radius > largest.radius {
+                        circle
+                    } else {
+                        largest
+                    }
+                });
+        }
+        let (ab2, ac2) = (ab.x * ab.x + ab.y * ab.y, ac.x * ac.x + ac.y * ac.y);
+        let offset = Coord {
+            x: (ac.y * ab2 - ab.y * ac2) / d,
+            y: (ab.x * ac2 - ac.x * ab2) / d,
+        };
+        let center = a + offset;
+        Circle {
+            center,
+            radius: distance(center, a)
+                .max(distance(center, b))
+                .max(distance(center, c)),
+        }
+    }
+
+    /// Whether `coord` is in the circle, allowing for rounding errors.
+    fn contains(&self, coord: Coord<T>) -> bool {
+        let tolerance = T::from(1e-12).unwrap() * (T::one() + self.radius);
+        distance(self.center, coord) <= self.radius + tolerance
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, MultiPoint, Polygon};
+
+    #[test]
+    fn triangles() {
+        // Obtuse: the circle is on the longest side
+        let obtuse = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.5)];
+        let circle = obtuse.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, point!(x: 2., y: 0.));
+        assert_relative_eq!(circle.radius, 2.);
+
+        // Equilateral: the circumcircle
+        let h = 3f64.sqrt();
+        let equilateral = polygon![(x: -1., y: 0.), (x: 1., y: 0.), (x: 0., y: h)];
+        let circle = equilateral.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, point!(x: 0., y: h / 3.), epsilon = 1e-12);
+        assert_relative_eq!(circle.radius, 2. * h / 3., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn encloses_every_point() {
+        let points: MultiPoint = (0..200)
+            .map(|i| {
+                let t = i as f64;
+                (
+                    (t * 7.3).sin() * (t * 0.37).cos() * 10.,
+                    (t * 3.1).cos() * 5.,
+                )
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let circle = points.minimum_bounding_circle().unwrap();
+        let mut on_circle = 0;
+        for point in &points {
+            let distance = distance(point.0, circle.center.0);
+            assert!(distance <= circle.radius + 1e-9);
+            if distance > circle.radius - 1e-9 {
+                on_circle += 1;
+            }
+        }
+        assert!(on_circle >= 2);
+    }
+
+    #[test]
+    fn degenerate() {
+        let point = point!(x: 3., y: 4.);
+        let circle = point.minimum_bounding_circle().unwrap();
+        assert_eq!((circle.center, circle.radius), (point, 0.));
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        let circle = line_string.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, point!(x: 1.5, y: 1.5));
+
+        let empty: Polygon = polygon![];
+        assert_eq!(empty.minimum_bounding_circle(), None);
+    }
+}
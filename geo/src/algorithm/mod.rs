@@ -4,7 +4,24 @@ pub use kernels::{Kernel, Orientation};
 
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
-pub use area::Area;
+pub use area::{Area, RingAreas};
+
+/// Normalize and compare angles and bearings, and measure the angles at vertices.
+pub mod angles;
+
+/// Split and merge geometries along the antimeridian.
+pub mod antimeridian;
+pub use antimeridian::Antimeridian;
+
+/// Project small geometries to meters around a local origin, and back.
+#[cfg(feature = "std")]
+pub mod azimuthal_equidistant;
+#[cfg(feature = "std")]
+pub use azimuthal_equidistant::AzimuthalEquidistant;
+
+/// Test many coordinates against a geometry at once, with vectorizable kernels.
+pub mod batch;
+pub use batch::{ContainsPoints, SegmentsInRect};
 
 /// Calculate the bearing to another `Point`, in degrees.
 #[deprecated(
@@ -16,41 +33,74 @@ pub mod bearing;
 #[deprecated(since = "0.24.1", note = "renamed to `HaversineBearing`")]
 pub use bearing::Bearing;
 
+/// Calculate the bearing of each segment of a `LineString`, and the turns between them.
+pub mod bearings;
+pub use bearings::{BearingMetric, Bearings};
+
 /// Boolean Ops such as union, xor, difference;
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+#[cfg(feature = "bumpalo")]
+pub use bool_ops::ArenaBooleanOps;
+pub use bool_ops::{
+    BooleanOps, BooleanOpsWith, ClassifiedBooleanOps, ClassifiedOverlay, ConvexBooleanOps, OpType,
+    OverlayBackend,
+};
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 pub use bounding_rect::BoundingRect;
 
+/// Wrap a `Geometry` with its bounding rectangle, calculated once.
+pub mod bounded;
+pub use bounded::Bounded;
+
+/// Calculate the smallest circle enclosing a `Geometry`.
+pub mod minimum_bounding_circle;
+pub use minimum_bounding_circle::{BoundingCircle, MinimumBoundingCircle};
+
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 pub mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
-pub use centroid::Centroid;
+pub use centroid::{Centroid, WeightedCentroid};
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 pub mod chaikin_smoothing;
-pub use chaikin_smoothing::ChaikinSmoothing;
+pub use chaikin_smoothing::{ChaikinSmoothing, ChaikinSmoothingWithTolerance};
 
 /// Calculate the signed approximate geodesic area of a `Geometry`.
 pub mod chamberlain_duquette_area;
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Clip a `Geometry` to a `Rect`, with algorithms specialised for rectangles.
+pub mod clip_to_rect;
+pub use clip_to_rect::ClipToRect;
+
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// Measure the compactness of a polygonal `Geometry`.
+pub mod compactness;
+pub use compactness::Compactness;
+
+/// Compress geometries into a compact byte format, within a maximum error.
+pub mod compress;
+pub use compress::{Compress, CompressedGeometry};
+
 /// Calculate the concave hull of a `Geometry`.
 pub mod concave_hull;
 pub use concave_hull::ConcaveHull;
 
 /// Determine whether `Geometry` `A` completely encloses `Geometry` `B`.
 pub mod contains;
-pub use contains::Contains;
+pub use contains::{BoundarySemantics, Contains, ContainsWith};
+
+/// Extract contour lines and bands from a `Raster` of values.
+pub mod contour;
+pub use contour::Contour;
 
 /// Convert the type of a geometry’s coordinate value.
 pub mod convert;
@@ -66,7 +116,11 @@ pub use convex_hull::ConvexHull;
 
 /// Cross track distance
 pub mod cross_track_distance;
-pub use cross_track_distance::CrossTrackDistance;
+pub use cross_track_distance::{AlongTrackDistance, CrossTrackDistance};
+
+/// Measure the sinuosity and curvature of a `LineString`.
+pub mod curvature;
+pub use curvature::Curvature;
 
 /// Determine whether a `Coord` lies inside, outside, or on the boundary of a geometry.
 pub mod coordinate_position;
@@ -76,18 +130,54 @@ pub use coordinate_position::CoordinatePosition;
 pub mod coords_iter;
 pub use coords_iter::CoordsIter;
 
+/// Find the geometries which are the same up to snapping to a grid and the way they're written down.
+pub mod deduplicate;
+pub use deduplicate::{deduplicate, CanonicalForm, Duplicates};
+
+/// Compare two geometries, reporting the vertices and components which changed between them.
+pub mod diff;
+pub use diff::Diff;
+
 /// Densify linear geometry components
 pub mod densify;
 pub use densify::Densify;
 
+/// Densify geometry components along geodesics
+#[cfg(feature = "std")]
+pub mod densify_geodesic;
+#[cfg(feature = "std")]
+pub use densify_geodesic::DensifyGeodesic;
+
 /// Densify spherical geometry components
 pub mod densify_haversine;
 pub use densify_haversine::DensifyHaversine;
 
+/// Merge polygons which share edges, or intersect, optionally grouped by a key.
+pub mod dissolve;
+pub use dissolve::{Dissolve, DissolveMode};
+
+/// Calculate a `Raster` of the distance from each cell to the nearest part of a geometry.
+pub mod distance_raster;
+pub use distance_raster::DistanceRaster;
+
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Calculate lengths, surface areas and slopes in three dimensions, with an elevation for each coordinate.
+pub mod elevation;
+pub use elevation::{Length3D, SlopeAspect, SurfaceArea3D, TerrainSlope};
+
+/// Divide polygons into parts of equal area with straight cuts.
+pub mod equal_area_partition;
+pub use equal_area_partition::{EqualAreaPartition, PartitionMethod};
+
+/// The ellipsoid used as the model of the earth by geodesic algorithms.
+#[cfg(feature = "std")]
+pub mod ellipsoid;
+#[cfg(feature = "std")]
+pub use ellipsoid::Ellipsoid;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
 pub use euclidean_distance::EuclideanDistance;
@@ -96,41 +186,95 @@ pub use euclidean_distance::EuclideanDistance;
 pub mod euclidean_length;
 pub use euclidean_length::EuclideanLength;
 
-/// Calculate the extreme coordinates and indices of a geometry.
+/// Iterate over the single-part geometries of multi-part geometries and collections.
+pub mod explode;
+pub use explode::{Explode, PartRef};
+
+/// Calculate the extreme coordinates and indices of a geometry, along the axes or any direction.
 pub mod extremes;
-pub use extremes::Extremes;
+pub use extremes::{Extremes, ExtremesAlong};
+
+/// Round the corners of a `Geometry` with circular arcs.
+pub mod fillet;
+pub use fillet::Fillet;
+
+/// Scale and translate a `Geometry` to fit into a rectangle.
+pub mod fit_into;
+pub use fit_into::{AspectRatio, FitInto};
 
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
 pub use frechet_distance::FrechetDistance;
 
 /// Calculate the bearing to another `Point` on a geodesic.
+#[cfg(feature = "std")]
 pub mod geodesic_bearing;
-pub use geodesic_bearing::GeodesicBearing;
+#[cfg(feature = "std")]
+pub use geodesic_bearing::{GeodesicBearing, GeodesicBearingOnEllipsoid};
+
+/// Calculate the bounding rectangle of a `Geometry` whose edges are geodesics.
+#[cfg(feature = "std")]
+pub mod geodesic_bounding_rect;
+#[cfg(feature = "std")]
+pub use geodesic_bounding_rect::GeodesicBoundingRect;
+
+/// Calculate the closest point on a geometry whose edges are geodesics to a given point.
+#[cfg(feature = "std")]
+pub mod geodesic_closest_point;
+#[cfg(feature = "std")]
+pub use geodesic_closest_point::GeodesicClosestPoint;
+
+/// Calculate the cross track and along track distances of a `Point` from a geodesic.
+#[cfg(feature = "std")]
+pub mod geodesic_cross_track;
+#[cfg(feature = "std")]
+pub use geodesic_cross_track::GeodesicCrossTrack;
 
 /// Returns a new Point using a distance and bearing on a geodesic.
+#[cfg(feature = "std")]
 pub mod geodesic_destination;
-pub use geodesic_destination::GeodesicDestination;
+#[cfg(feature = "std")]
+pub use geodesic_destination::{GeodesicDestination, GeodesicDestinationOnEllipsoid};
 
 /// Calculate the Geodesic distance between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_distance;
-pub use geodesic_distance::GeodesicDistance;
+#[cfg(feature = "std")]
+pub use geodesic_distance::{GeodesicDistance, GeodesicDistanceOnEllipsoid};
 
 /// Calculate the Geodesic area and perimeter of polygons.
+#[cfg(feature = "std")]
 pub mod geodesic_area;
-pub use geodesic_area::GeodesicArea;
+#[cfg(feature = "std")]
+pub use geodesic_area::{GeodesicArea, GeodesicAreaOnEllipsoid};
 
 /// Calculate a new `Point` lying on a Geodesic arc between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_intermediate;
+#[cfg(feature = "std")]
 pub use geodesic_intermediate::GeodesicIntermediate;
 
 /// Calculate the Geodesic length of a line.
+#[cfg(feature = "std")]
 pub mod geodesic_length;
-pub use geodesic_length::GeodesicLength;
+#[cfg(feature = "std")]
+pub use geodesic_length::{GeodesicLength, GeodesicLengthOnEllipsoid};
+
+/// Track entities moving through polygonal geofences, reporting enter, exit and dwell events.
+pub mod geofence;
+pub use geofence::{GeofenceEvent, GeofenceEventKind, Geofencer};
+
+/// Convert lines into polylines following great circles, for drawing on a map.
+pub mod great_circle_path;
+pub use great_circle_path::GreatCirclePath;
+
+/// Tile a `Geometry`'s bounding rectangle with square or hexagonal cells.
+pub mod grid;
+pub use grid::Grid;
 
-/// Calculate the Hausdorff distance between two geometries.
+/// Calculate the Hausdorff distance between two geometries, and the directed distances each way.
 pub mod hausdorff_distance;
-pub use hausdorff_distance::HausdorffDistance;
+pub use hausdorff_distance::{DirectedHausdorff, DirectedHausdorffDistance, HausdorffDistance};
 
 /// Calculate the bearing to another `Point`, in degrees.
 pub mod haversine_bearing;
@@ -148,6 +292,10 @@ pub use haversine_distance::HaversineDistance;
 pub mod haversine_intermediate;
 pub use haversine_intermediate::HaversineIntermediate;
 
+/// Calculate the intersection of two great-circle arcs.
+pub mod haversine_intersection;
+pub use haversine_intersection::HaversineIntersection;
+
 /// Calculate the Haversine length of a Line.
 pub mod haversine_length;
 pub use haversine_length::HaversineLength;
@@ -156,13 +304,26 @@ pub use haversine_length::HaversineLength;
 pub mod haversine_closest_point;
 pub use haversine_closest_point::HaversineClosestPoint;
 
+/// Measure and fill in the holes of a `Polygon`.
+pub mod holes;
+pub use holes::Holes;
+
 /// Calculate a representative `Point` inside a `Geometry`
 pub mod interior_point;
 pub use interior_point::InteriorPoint;
 
+/// Offset polygons inwards at many distances at once, by propagating their straight skeleton.
+pub mod inward_offsets;
+pub use inward_offsets::InwardOffsets;
+
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
-pub use intersects::Intersects;
+pub use intersects::{IntersectionIndex, Intersects};
+
+/// Index one dimensional intervals, such as ranges of measures, to find those containing a
+/// measure or overlapping a range.
+pub mod interval_index;
+pub use interval_index::{Interval, IntervalIndex};
 
 /// Determines whether a `LineString` is convex.
 pub mod is_convex;
@@ -172,10 +333,18 @@ pub use is_convex::IsConvex;
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
 
+/// Find where to place labels on a `LineString` or `MultiLineString`.
+pub mod label_point;
+pub use label_point::{LabelAnchor, LabelPoint, LabelSegment};
+
 /// Interpolate a point along a `Line` or `LineString`.
 pub mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
 
+/// Build a topological network of line strings, for connected components and shortest paths.
+pub mod line_network;
+pub use line_network::{LineNetwork, NetworkEdge, NetworkPath};
+
 /// Computes the intersection of two Lines.
 pub mod line_intersection;
 pub use line_intersection::LineIntersection;
@@ -184,6 +353,10 @@ pub use line_intersection::LineIntersection;
 pub mod line_locate_point;
 pub use line_locate_point::LineLocatePoint;
 
+/// Calibrate a `LineString` with measures, and convert between measures and locations along it.
+pub mod linear_referencing;
+pub use linear_referencing::Route;
+
 /// Iterate over the lines in a geometry.
 pub mod lines_iter;
 pub use lines_iter::LinesIter;
@@ -192,21 +365,53 @@ pub use lines_iter::LinesIter;
 pub mod linestring_segment;
 pub use linestring_segment::{LineStringSegmentize, LineStringSegmentizeHaversine};
 
+/// Find the longest straight line within a polygon.
+pub mod longest_chord;
+pub use longest_chord::LongestChord;
+
+/// Pair up the features of two datasets by their distances, overlaps and orientations, for conflation.
+pub mod matching;
+pub use matching::{CandidateMatch, FeatureMatcher, MatchMeasures};
+
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 
-/// Orient a `Polygon`'s exterior and interior rings.
+/// Interpolate between two `LineString`s or `Polygon`s.
+pub mod morph;
+pub use morph::Morph;
+
+/// Flatten, tidy and merge the members of `GeometryCollection`s.
+pub mod normalize_collection;
+pub use normalize_collection::NormalizeCollection;
+
+/// Orient the exterior and interior rings of the polygons in a geometry, and check their winding orders.
 pub mod orient;
-pub use orient::Orient;
+pub use orient::{Orient, RingWindings};
+
+/// Calculate the principal axes of a `Geometry`.
+pub mod principal_axes;
+pub use principal_axes::{Axes, PrincipalAxes};
+
+/// Compute the area, centroid and intersections of `f32` geometries in `f64`.
+pub mod promote_f64;
+pub use promote_f64::{promoted_line_intersection, PromoteF64};
 
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
 
+/// Scan-convert a `Geometry` into a grid of cells.
+pub mod rasterize;
+pub use rasterize::{Raster, Rasterize};
+
+/// Decompose rectilinear polygons into non-overlapping `Rect`s.
+pub mod rect_decomposition;
+pub use rect_decomposition::RectDecomposition;
+
 /// Relate two geometries based on DE-9IM
 pub mod relate;
-pub use relate::Relate;
+pub use relate::{BoundaryNodeRule, Relate, RelateWithBoundaryNodeRule};
 
 /// Remove (consecutive) repeated points
 pub mod remove_repeated_points;
@@ -216,25 +421,108 @@ pub use remove_repeated_points::RemoveRepeatedPoints;
 pub mod rotate;
 pub use rotate::Rotate;
 
+/// Generate random `Point`s inside a `Geometry`.
+#[cfg(all(feature = "rand", feature = "earcutr"))]
+pub mod sample_points;
+#[cfg(all(feature = "rand", feature = "earcutr"))]
+pub use sample_points::SamplePoints;
+
 /// Scale a `Geometry` up or down by a factor
 pub mod scale;
 pub use scale::Scale;
 
+/// Calculate the second moments of area of a polygonal `Geometry`.
+pub mod second_moments;
+pub use second_moments::{AreaMoments, SecondMoments};
+
+/// Split collections of geometries into chunks of nearby geometries, by sort-tile-recursive or
+/// a Hilbert curve.
+pub mod spatial_partition;
+pub use spatial_partition::{PartitionStrategy, SpatialChunk, SpatialPartition};
+
+/// Find and remove the parts of a `LineString` which double back over it.
+pub mod self_overlap;
+pub use self_overlap::SelfOverlap;
+
+/// Remove small rings from polygons, merge slivers into their neighbours in a coverage, and fill
+/// narrow gaps in it.
+pub mod slivers;
+pub use slivers::{FillGaps, MergeSlivers, RemoveSmallRings};
+
 /// Skew a `Geometry` by shearing it at angles along the x and y dimensions
 pub mod skew;
 pub use skew::Skew;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 pub mod affine_ops;
-pub use affine_ops::{AffineOps, AffineTransform};
+pub use affine_ops::{AffineOps, AffineTransform, ProjectiveTransform};
+
+/// Calculate the centroid and an interior point of a `Geometry` on a sphere.
+pub mod spherical_centroid;
+pub use spherical_centroid::{SphericalCentroid, SphericalInteriorPoint};
+
+/// Decompose polygons into the arcs of their boundaries, shared between neighbours.
+pub mod shared_arcs;
+pub use shared_arcs::{ArcRef, SharedArcs};
 
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 pub use simplify::{Simplify, SimplifyIdx};
 
+/// Simplify adjacent polygons, simplifying their shared boundaries identically.
+pub mod simplify_coverage;
+pub use simplify_coverage::SimplifyCoverage;
+
+/// Simplify `Geometries` of longitude/latitude coordinates on an ellipsoid, with tolerances in
+/// meters.
+#[cfg(feature = "std")]
+pub mod simplify_geodesic;
+#[cfg(feature = "std")]
+pub use simplify_geodesic::{SimplifyGeodesic, SimplifyVwGeodesic};
+
+/// Simplify `Geometries` of longitude/latitude coordinates on a sphere, with tolerances in meters.
+pub mod simplify_haversine;
+pub use simplify_haversine::{SimplifyHaversine, SimplifyVwHaversine};
+
+/// Simplify `Geometries` by dropping points close to the point before them.
+pub mod simplify_radial;
+pub use simplify_radial::SimplifyRadial;
+
+/// Simplify a stream of coordinates as they arrive.
+pub mod simplify_stream;
+pub use simplify_stream::StreamingSimplifier;
+
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplify_vw;
-pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
+pub use simplify_vw::{
+    LevelOfDetail, SimplifyVw, SimplifyVwBudget, SimplifyVwIdx, SimplifyVwPreserve,
+};
+
+/// Split a `LineString` into pieces at distances along it or at points on it.
+pub mod split_line;
+pub use split_line::SplitLine;
+
+/// Summarize a geometry's vertices, components, extent, length, area and segment lengths.
+pub mod stats;
+pub use stats::{GeometryStats, Stats};
+
+/// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` with a spline through their vertices.
+pub mod spline_smoothing;
+pub use spline_smoothing::{SplineSmoothing, SplineSmoothingConfig};
+
+/// Distances, lengths and areas in typed units, with the metric which measured them.
+pub mod units;
+pub use units::{Measure, Quantity};
+
+/// Parallel variants of algorithms, using rayon.
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(all(feature = "rayon", feature = "earcutr"))]
+pub use parallel::ParTriangulateEarcut;
+#[cfg(feature = "rayon")]
+pub use parallel::{
+    ParArea, ParLength, ParMapCoords, ParMapCoordsInPlace, ParPredicates, ParSimplify,
+};
 
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
@@ -242,26 +530,70 @@ pub mod transform;
 #[cfg(feature = "use-proj")]
 pub use transform::Transform;
 
+/// Apply a sequence of coordinate operations to a geometry in a single pass.
+pub mod transform_pipeline;
+pub use transform_pipeline::TransformPipeline;
+
+/// Transform a geometry with any fallible coordinate transformer, such as a projection engine.
+pub mod transform_with;
+pub use transform_with::{CoordinateTransformer, TransformWith};
+
+/// Find and clip to the XYZ tiles of web maps which a `Geometry` intersects, and prepare it for
+/// encoding in vector tiles.
+pub mod tiles;
+pub use tiles::{ClipToTile, PrepareTile, Tile, TileCoverage};
+
+/// Determine the similarity between the shapes of outlines using their turning functions.
+pub mod turning_function;
+pub use turning_function::TurningDistance;
+
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
 pub use translate::Translate;
 
+/// Pick, cast rays at, and interpolate elevations over triangles, in a bounding volume hierarchy.
+pub mod triangle_bvh;
+pub use triangle_bvh::{RayHit, TriangleBvh};
+
 /// Triangulate polygons using an [ear-cutting algorithm](https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf).
 #[cfg(feature = "earcutr")]
 pub mod triangulate_earcut;
 #[cfg(feature = "earcutr")]
 pub use triangulate_earcut::TriangulateEarcut;
 
+/// Test whether a `Polygon` contains points by looking up the triangles of its triangulation.
+#[cfg(feature = "earcutr")]
+pub mod triangulated_polygon;
+#[cfg(feature = "earcutr")]
+pub use triangulated_polygon::TriangulatedPolygon;
+
 /// Triangulate polygons using an (un)constrained [Delaunay Triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation) algorithm.
 #[cfg(feature = "spade")]
 pub mod triangulate_spade;
 #[cfg(feature = "spade")]
 pub use triangulate_spade::TriangulateSpade;
 
+/// Locate the `Polygon` containing a `Point` using a trapezoidal map.
+pub mod trapezoidal_map;
+pub use trapezoidal_map::TrapezoidalMap;
+
+/// Convert geometries between longitude/latitude and Universal Transverse Mercator zones.
+#[cfg(feature = "std")]
+pub mod utm;
+#[cfg(feature = "std")]
+pub use utm::Utm;
+
 /// Vector Operations for 2D coordinates
 mod vector_ops;
 pub use vector_ops::Vector2DOps;
 
+/// Unit vector representation of points on a sphere
+mod nvector;
+
+/// Check that geometries are valid, and mark them as such.
+pub mod validation;
+pub use validation::{InvalidGeometry, Validated, Validation};
+
 /// Calculate the Vincenty distance between two `Point`s.
 pub mod vincenty_distance;
 pub use vincenty_distance::VincentyDistance;
@@ -270,6 +602,14 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Calculate the region of a `Polygon` visible from a `Point`.
+pub mod visibility_polygon;
+pub use visibility_polygon::VisibilityPolygon;
+
+/// Convert geometries between longitude/latitude and Web Mercator.
+pub mod web_mercator;
+pub use web_mercator::WebMercator;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -278,13 +618,17 @@ pub use winding_order::Winding;
 pub mod within;
 pub use within::Within;
 
+/// Aggregate weighted points into statistics for polygonal zones.
+pub mod zonal_stats;
+pub use zonal_stats::{ZonalStats, ZoneStats};
+
 /// Planar sweep algorithm and related utils
 pub mod sweep;
 
 /// Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
 pub mod outlier_detection;
 
-pub use outlier_detection::OutlierDetection;
+pub use outlier_detection::{OutlierDetection, OutlierMetric};
 
 /// Monotonic polygon subdivision
 pub mod monotone;
@@ -1,5 +1,6 @@
 mod mono_poly;
 use crate::{Coord, GeoNum, Intersects, MultiPolygon, Polygon};
+use alloc::vec::Vec;
 pub use mono_poly::MonoPoly;
 
 mod segment;
@@ -1,6 +1,7 @@
 use crate::sweep::{Active, Event, EventType, LineOrPoint, SweepPoint, VecSet};
 use crate::{GeoNum, Orientation};
-use std::{collections::BinaryHeap, fmt::Debug};
+use alloc::collections::BinaryHeap;
+use core::fmt::Debug;
 
 use super::{RcSegment, Segment};
 
@@ -103,7 +104,10 @@ impl<T: GeoNum, P: Debug + Clone> SimpleSweep<T, P> {
 
         match &event.ty {
             LineLeft => {
-                let mut idx = self.active_segments.index_not_of(segment);
+                let mut idx = self
+                    .active_segments
+                    .index_not_of(segment)
+                    .expect("segment already found in active-vec-set");
                 for is_next in [false, true] {
                     let (active, split) = if !is_next {
                         if idx > 0 {
@@ -155,7 +159,10 @@ impl<T: GeoNum, P: Debug + Clone> SimpleSweep<T, P> {
                 self.active_segments.insert_at(idx, segment.clone());
             }
             LineRight => {
-                let idx = self.active_segments.index_of(segment);
+                let idx = self
+                    .active_segments
+                    .index_of(segment)
+                    .expect("segment not found in active-vec-set");
                 self.active_segments.remove_at(idx);
 
                 if idx > 0 && idx < self.active_segments.len() {
@@ -1,4 +1,4 @@
-use std::{fmt::Display, str::FromStr};
+use core::{fmt::Display, str::FromStr};
 
 use approx::RelativeEq;
 use geo_types::Polygon;
@@ -21,9 +21,9 @@ pub(super) fn init_log() {
 }
 
 fn twice_polygon_area<T: GeoNum + Signed>(poly: &Polygon<T>) -> T {
-    let mut area = twice_signed_ring_area(poly.exterior()).abs();
+    let mut area = twice_signed_ring_area(&poly.exterior().0).abs();
     for interior in poly.interiors() {
-        area = area - twice_signed_ring_area(interior).abs();
+        area = area - twice_signed_ring_area(&interior.0).abs();
     }
     area
 }
@@ -0,0 +1,317 @@
+use alloc::vec::Vec;
+
+use crate::winding_order::Winding;
+use crate::{Centroid, Coord, GeoFloat, LineString, Polygon};
+
+/// Interpolate between two geometries, for example to animate a transition between two
+/// boundaries.
+///
+/// `morph(other, t)` returns the geometry a fraction `t` of the way from `self` to `other`, so
+/// that `t = 0` gives the shape of `self` and `t = 1` that of `other`. Values of `t` outside
+/// `[0, 1]` extrapolate.
+///
+/// The vertices of the two geometries are put into correspondence by their position along the
+/// geometry, as a fraction of its length: each geometry is resampled at the positions of both its
+/// own vertices and those of the other, so that both of their shapes are kept exactly at the
+/// ends. For polygons, the rings are first given the same winding order, and the start of the
+/// ring of `other` is rotated to the position which best matches the start of that of `self`,
+/// relative to their centroids, so that the shape doesn't twist during the transition. Interior
+/// rings are paired by index; a ring without a counterpart shrinks to, or grows from, its
+/// centroid.
+///
+/// If either geometry is empty, a clone of `self` is returned for `t < 0.5`, and of `other`
+/// otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, Centroid, Morph, Point};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// // A diamond, with the opposite winding order and a different first vertex
+/// let diamond = polygon![(x: 11., y: 2.), (x: 10., y: 1.), (x: 11., y: 0.), (x: 12., y: 1.)];
+///
+/// let halfway = square.morph(&diamond, 0.5);
+/// assert_relative_eq!(halfway.centroid().unwrap(), Point::new(6., 1.));
+/// assert_relative_eq!(square.morph(&diamond, 0.).unsigned_area(), 4.);
+/// assert_relative_eq!(square.morph(&diamond, 1.).unsigned_area(), 2.);
+/// ```
+pub trait Morph<T: GeoFloat> {
+    fn morph(&self, other: &Self, t: T) -> Self;
+}
+
+impl<T: GeoFloat> Morph<T> for LineString<T> {
+    fn morph(&self, other: &Self, t: T) -> Self {
+        if self.0.is_empty() || other.0.is_empty() {
+            return step(self, other, t);
+        }
+        LineString::new(interpolate_paths(&self.0, &other.0, t))
+    }
+}
+
+impl<T: GeoFloat> Morph<T> for Polygon<T> {
+    fn morph(&self, other: &Self, t: T) -> Self {
+        if self.exterior().0.is_empty() || other.exterior().0.is_empty() {
+            return step(self, other, t);
+        }
+        let exterior = morph_rings(self.exterior(), other.exterior(), t, true);
+
+        let (interiors, other_interiors) = (self.interiors(), other.interiors());
+        let mut holes: Vec<_> = interiors
+            .iter()
+            .zip(other_interiors)
+            .map(|(hole, other_hole)| morph_rings(hole, other_hole, t, false))
+            .collect();
+        if t < T::one() {
+            for hole in &interiors[other_interiors.len().min(interiors.len())..] {
+                holes.push(shrink_to_centroid(hole, t));
+            }
+        }
+        if t > T::zero() {
+            for hole in &other_interiors[interiors.len().min(other_interiors.len())..] {
+                holes.push(shrink_to_centroid(hole, T::one() - t));
+            }
+        }
+        Polygon::new(exterior, holes)
+    }
+}
+
+fn step<G: Clone, T: GeoFloat>(a: &G, b: &G, t: T) -> G {
+    if t < T::from(0.5).unwrap() {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+fn lerp<T: GeoFloat>(a: Coord<T>, b: Coord<T>, t: T) -> Coord<T> {
+    a + (b - a) * t
+}
+
+/// Interpolate between the rings `a` and `b`, which are wound counter-clockwise if `ccw`, and
+/// clockwise otherwise.
+fn morph_rings<T: GeoFloat>(
+    a: &LineString<T>,
+    b: &LineString<T>,
+    t: T,
+    ccw: bool,
+) -> LineString<T> {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    a.close();
+    b.close();
+    if ccw {
+        a.make_ccw_winding();
+        b.make_ccw_winding();
+    } else {
+        a.make_cw_winding();
+        b.make_cw_winding();
+    }
+    let b = rotate_ring(&b.0, best_start(&a.0, &b.0));
+    LineString::new(interpolate_paths(&a.0, &b, t))
+}
+
+fn shrink_to_centroid<T: GeoFloat>(ring: &LineString<T>, t: T) -> LineString<T> {
+    match Polygon::new(ring.clone(), Vec::new()).centroid() {
+        Some(centroid) => ring.0.iter().map(|&c| lerp(c, centroid.0, t)).collect(),
+        None => ring.clone(),
+    }
+}
+
+/// The cumulative lengths along `coords`, normalized to end at one. All are zero if `coords` has
+/// no length.
+fn fractions<T: GeoFloat>(coords: &[Coord<T>]) -> Vec<T> {
+    let mut total = T::zero();
+    let mut lengths = Vec::with_capacity(coords.len());
+    lengths.push(total);
+    for w in coords.windows(2) {
+        total = total + (w[1].x - w[0].x).hypot(w[1].y - w[0].y);
+        lengths.push(total);
+    }
+    if total > T::zero() {
+        lengths
+            .iter_mut()
+            .for_each(|length| *length = *length / total);
+    }
+    lengths
+}
+
+/// The point a `fraction` of the way along `coords`, whose cumulative normalized lengths are
+/// `fractions`.
+fn sample<T: GeoFloat>(coords: &[Coord<T>], fractions: &[T], fraction: T) -> Coord<T> {
+    let i = fractions.partition_point(|&f| f <= fraction);
+    if i == 0 {
+        return coords[0];
+    }
+    if i == fractions.len() {
+        return coords[coords.len() - 1];
+    }
+    let (start, end) = (fractions[i - 1], fractions[i]);
+    lerp(coords[i - 1], coords[i], (fraction - start) / (end - start))
+}
+
+/// Resample `a` and `b` at the fractions along them of both their vertices, and interpolate
+/// between the corresponding points.
+fn interpolate_paths<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>], t: T) -> Vec<Coord<T>> {
+    let (a_fractions, b_fractions) = (fractions(a), fractions(b));
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let (from_a, from_b) = match (a_fractions.get(i), b_fractions.get(j)) {
+            (Some(&fa), Some(&fb)) if fa == fb => {
+                i += 1;
+                j += 1;
+                (a[i - 1], b[j - 1])
+            }
+            (Some(&fa), Some(&fb)) if fa < fb => {
+                i += 1;
+                (a[i - 1], sample(b, &b_fractions, fa))
+            }
+            (Some(&fa), None) => {
+                i += 1;
+                (a[i - 1], sample(b, &b_fractions, fa))
+            }
+            (_, Some(&fb)) => {
+                j += 1;
+                (sample(a, &a_fractions, fb), b[j - 1])
+            }
+            (None, None) => unreachable!(),
+        };
+        merged.push(lerp(from_a, from_b, t));
+    }
+    merged
+}
+
+/// The number of points of a ring compared when choosing its start.
+const START_SAMPLES: usize = 64;
+
+/// The fraction along the closed ring `b` whose point, relative to the centroid of `b`, best
+/// matches the start of the closed ring `a` relative to its centroid.
+fn best_start<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>]) -> T {
+    let samples = |coords: &[Coord<T>]| {
+        let fractions = fractions(coords);
+        let n = T::from(START_SAMPLES).unwrap();
+        let points: Vec<_> = (0..START_SAMPLES)
+            .map(|k| sample(coords, &fractions, T::from(k).unwrap() / n))
+            .collect();
+        let mean = points.iter().fold(Coord::zero(), |sum, &point| sum + point) / n;
+        points.into_iter().map(move |point| point - mean)
+    };
+    let (a, b): (Vec<_>, Vec<_>) = (samples(a).collect(), samples(b).collect());
+    let cost = |offset: usize| {
+        a.iter()
+            .enumerate()
+            .map(|(k, p)| {
+                let q = b[(k + offset) % START_SAMPLES];
+                (p.x - q.x).powi(2) + (p.y - q.y).powi(2)
+            })
+            .fold(T::zero(), |sum, cost| sum + cost)
+    };
+    let mut best = (0, cost(0));
+    for offset in 1..START_SAMPLES {
+        let cost = cost(offset);
+        if cost < best.1 {
+            best = (offset, cost);
+        }
+    }
+    T::from(best.0).unwrap() / T::from(START_SAMPLES).unwrap()
+}
+
+/// The closed ring `coords`, starting and ending at the point a `fraction` of the way along it.
+fn rotate_ring<T: GeoFloat>(coords: &[Coord<T>], fraction: T) -> Vec<Coord<T>> {
+    let fractions = fractions(coords);
+    let start = sample(coords, &fractions, fraction);
+    let i = fractions.partition_point(|&f| f <= fraction);
+    if i == 0 || i == fractions.len() {
+        return coords.to_vec();
+    }
+    // Vertices after the start, around the ring back to the start, skipping the closing vertex
+    let after = coords[i..coords.len() - 1].iter().chain(&coords[..i]);
+    let mut rotated = Vec::with_capacity(coords.len() + 1);
+    rotated.push(start);
+    rotated.extend(after.filter(|&&c| c != start));
+    rotated.push(start);
+    rotated
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area, HausdorffDistance, Point, Rotate, Translate};
+
+    #[test]
+    fn line_string_ends() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 4., y: 0.)];
+        let b = line_string![(x: 0., y: 2.), (x: 2., y: 4.)];
+        let halfway = a.morph(&b, 0.5);
+        assert_eq!(
+            halfway,
+            line_string![(x: 0., y: 1.), (x: 0.75, y: 1.25), (x: 3., y: 2.)]
+        );
+        // Both ends keep their shape, with the vertices of the other inserted
+        assert_eq!(
+            a.morph(&b, 0.),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 4., y: 0.)]
+        );
+        assert_eq!(
+            a.morph(&b, 1.),
+            line_string![(x: 0., y: 2.), (x: 0.5, y: 2.5), (x: 2., y: 4.)]
+        );
+    }
+
+    #[test]
+    fn translated_polygon() {
+        let polygon: Polygon = geo_test_fixtures::east_baton_rouge();
+        let moved = polygon.translate(1., 1.);
+        let halfway = polygon.morph(&moved, 0.5);
+        assert_relative_eq!(
+            halfway.unsigned_area(),
+            polygon.unsigned_area(),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            halfway.centroid().unwrap(),
+            polygon.centroid().unwrap() + Point::new(0.5, 0.5),
+            epsilon = 1e-9
+        );
+        assert!(polygon.morph(&moved, 0.).hausdorff_distance(&polygon) < 1e-12);
+        assert!(polygon.morph(&moved, 1.).hausdorff_distance(&moved) < 1e-12);
+    }
+
+    #[test]
+    fn aligns_start_and_winding() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        // The same square, starting at another vertex, and clockwise
+        let same = polygon![(x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 0.), (x: 0., y: 2.)];
+        for t in [0.25, 0.5, 0.75] {
+            let morphed = square.morph(&same, t);
+            assert_relative_eq!(morphed.unsigned_area(), 4.);
+            assert!(morphed.hausdorff_distance(&square) < 1e-12);
+        }
+
+        // Turning a square by a small angle doesn't collapse it on the way
+        let turned = square.rotate_around_centroid(20.);
+        assert!(square.morph(&turned, 0.5).unsigned_area() > 3.5);
+    }
+
+    #[test]
+    fn unmatched_holes() {
+        let with_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+        ];
+        let without_hole = Polygon::new(with_hole.exterior().clone(), vec![]);
+        assert_relative_eq!(with_hole.morph(&without_hole, 0.5).unsigned_area(), 15.);
+        assert_relative_eq!(without_hole.morph(&with_hole, 0.5).unsigned_area(), 15.);
+        assert_eq!(with_hole.morph(&without_hole, 1.).interiors().len(), 0);
+        assert_eq!(without_hole.morph(&with_hole, 0.).interiors().len(), 0);
+    }
+
+    #[test]
+    fn empty() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        assert_eq!(square.morph(&empty, 0.25), square);
+        assert_eq!(square.morph(&empty, 0.75), empty);
+    }
+}
@@ -0,0 +1,258 @@
+use alloc::vec::Vec;
+
+use crate::{
+    Explode, GeoNum, Geometry, GeometryCollection, HasDimensions, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, PartRef, Point, Polygon,
+};
+
+/// Tidy up the heterogeneous `GeometryCollection`s which come out of overlays and parsers, so
+/// they're easier to consume: flatten nested collections, drop empty members, and merge the
+/// members of each kind, points, lines and polygons, into a single multi-part geometry.
+///
+/// `Line`s are merged as `LineString`s, and `Rect`s and `Triangle`s as `Polygon`s. Merging
+/// doesn't dissolve overlapping polygons, so the `MultiPolygon` may not be valid; use
+/// [`unary_union`](crate::unary_union) for that.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, Geometry, GeometryCollection, MultiPoint};
+/// use geo::NormalizeCollection;
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 0., y: 0.)),
+///     Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+///         Geometry::Point(point!(x: 1., y: 1.)),
+///         Geometry::LineString(line_string![]),
+///     ])),
+/// ]);
+/// assert!(collection.is_homogeneous());
+///
+/// let flat = collection.flatten();
+/// assert_eq!(flat.len(), 3);
+/// assert_eq!(flat.drop_empty().len(), 2);
+///
+/// assert_eq!(
+///     collection.to_multi_geometry(),
+///     Some(Geometry::MultiPoint(MultiPoint::new(vec![
+///         point!(x: 0., y: 0.),
+///         point!(x: 1., y: 1.),
+///     ])))
+/// );
+///
+/// let mixed = GeometryCollection::new_from(vec![
+///     Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)]),
+///     Geometry::Point(point!(x: 5., y: 5.)),
+///     Geometry::Point(point!(x: 6., y: 6.)),
+/// ]);
+/// assert!(!mixed.is_homogeneous());
+/// // A `MultiPoint` and a `MultiPolygon`
+/// assert_eq!(mixed.merge().len(), 2);
+/// ```
+pub trait NormalizeCollection<T: GeoNum> {
+    /// The members of the collection, with the members of nested collections in place of the
+    /// nested collections, in order.
+    fn flatten(&self) -> GeometryCollection<T>;
+
+    /// The collection without its empty members, or the empty members of nested collections,
+    /// or the nested collections which only had empty members.
+    fn drop_empty(&self) -> GeometryCollection<T>;
+
+    /// The non-empty parts of the collection, through nested collections, merged into a
+    /// `MultiPoint`, a `MultiLineString` and a `MultiPolygon`, in that order, leaving out
+    /// those which would be empty.
+    fn merge(&self) -> GeometryCollection<T>;
+
+    /// Whether the non-empty parts of the collection, through nested collections, are all of
+    /// the same kind: all points, all lines or all polygons. An empty collection is.
+    fn is_homogeneous(&self) -> bool;
+
+    /// The non-empty parts of the collection, through nested collections, merged into a single
+    /// `MultiPoint`, `MultiLineString` or `MultiPolygon`, or `None` if they aren't all of the
+    /// same kind, or there aren't any.
+    fn to_multi_geometry(&self) -> Option<Geometry<T>>;
+}
+
+impl<T: GeoNum> NormalizeCollection<T> for GeometryCollection<T> {
+    fn flatten(&self) -> GeometryCollection<T> {
+        let mut flat = Vec::with_capacity(self.len());
+        flatten_into(self, &mut flat);
+        GeometryCollection::new_from(flat)
+    }
+
+    fn drop_empty(&self) -> GeometryCollection<T> {
+        GeometryCollection::new_from(
+            self.iter()
+                .filter_map(|geometry| match geometry {
+                    Geometry::GeometryCollection(collection) => {
+                        let collection = collection.drop_empty();
+                        (!collection.is_empty()).then_some(Geometry::GeometryCollection(collection))
+                    }
+                    geometry => (!geometry.is_empty()).then(|| geometry.clone()),
+                })
+                .collect(),
+        )
+    }
+
+    fn merge(&self) -> GeometryCollection<T> {
+        let parts = Parts::new(self);
+        let mut merged = Vec::with_capacity(3);
+        if !parts.points.is_empty() {
+            merged.push(Geometry::MultiPoint(MultiPoint::new(parts.points)));
+        }
+        if !parts.line_strings.is_empty() {
+            merged.push(Geometry::MultiLineString(MultiLineString::new(
+                parts.line_strings,
+            )));
+        }
+        if !parts.polygons.is_empty() {
+            merged.push(Geometry::MultiPolygon(MultiPolygon::new(parts.polygons)));
+        }
+        GeometryCollection::new_from(merged)
+    }
+
+    fn is_homogeneous(&self) -> bool {
+        let mut kinds = self.explode().filter(|part| !is_empty(*part)).map(Kind::of);
+        match kinds.next() {
+            Some(first) => kinds.all(|kind| kind == first),
+            None => true,
+        }
+    }
+
+    fn to_multi_geometry(&self) -> Option<Geometry<T>> {
+        let mut merged = self.merge();
+        match merged.0.len() {
+            1 => merged.0.pop(),
+            _ => None,
+        }
+    }
+}
+
+fn flatten_into<T: GeoNum>(collection: &GeometryCollection<T>, flat: &mut Vec<Geometry<T>>) {
+    for geometry in collection {
+        match geometry {
+            Geometry::GeometryCollection(nested) => flatten_into(nested, flat),
+            geometry => flat.push(geometry.clone()),
+        }
+    }
+}
+
+fn is_empty<T: GeoNum>(part: PartRef<'_, T>) -> bool {
+    match part {
+        PartRef::LineString(line_string) => line_string.is_empty(),
+        PartRef::Polygon(polygon) => polygon.is_empty(),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Point,
+    Line,
+    Polygon,
+}
+
+impl Kind {
+    fn of<T: GeoNum>(part: PartRef<'_, T>) -> Self {
+        match part {
+            PartRef::Point(_) => Kind::Point,
+            PartRef::Line(_) | PartRef::LineString(_) => Kind::Line,
+            PartRef::Polygon(_) | PartRef::Rect(_) | PartRef::Triangle(_) => Kind::Polygon,
+        }
+    }
+}
+
+/// The non-empty single-part geometries of a collection, by kind.
+struct Parts<T: GeoNum> {
+    points: Vec<Point<T>>,
+    line_strings: Vec<LineString<T>>,
+    polygons: Vec<Polygon<T>>,
+}
+
+impl<T: GeoNum> Parts<T> {
+    fn new(collection: &GeometryCollection<T>) -> Self {
+        let mut parts = Parts {
+            points: Vec::new(),
+            line_strings: Vec::new(),
+            polygons: Vec::new(),
+        };
+        for part in collection.explode().filter(|part| !is_empty(*part)) {
+            match part {
+                PartRef::Point(point) => parts.points.push(*point),
+                PartRef::Line(line) => parts.line_strings.push((*line).into()),
+                PartRef::LineString(line_string) => parts.line_strings.push(line_string.clone()),
+                PartRef::Polygon(polygon) => parts.polygons.push(polygon.clone()),
+                PartRef::Rect(rect) => parts.polygons.push(rect.to_polygon()),
+                PartRef::Triangle(triangle) => parts.polygons.push(triangle.to_polygon()),
+            }
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, Rect};
+
+    #[test]
+    fn normalize() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Line(Line::new((0., 0.), (1., 1.))),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::MultiPolygon(MultiPolygon::new(vec![square.clone()])),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+                Geometry::Polygon(Polygon::new(line_string![], vec![])),
+            ])),
+            Geometry::Rect(Rect::new((5., 5.), (6., 6.))),
+            Geometry::LineString(line_string![(x: 2., y: 2.), (x: 3., y: 2.)]),
+        ]);
+
+        let flat = collection.flatten();
+        assert_eq!(flat.len(), 5);
+        assert!(flat
+            .iter()
+            .all(|geometry| !matches!(geometry, Geometry::GeometryCollection(_))));
+
+        let non_empty = collection.drop_empty();
+        assert_eq!(non_empty.len(), 4);
+        assert_eq!(
+            non_empty[1],
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::MultiPolygon(MultiPolygon::new(vec![square.clone()]))
+            ]))
+        );
+
+        assert!(!collection.is_homogeneous());
+        assert_eq!(collection.to_multi_geometry(), None);
+        assert_eq!(
+            collection.merge(),
+            GeometryCollection::new_from(vec![
+                Geometry::MultiLineString(MultiLineString::new(vec![
+                    line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                    line_string![(x: 2., y: 2.), (x: 3., y: 2.)],
+                ])),
+                Geometry::MultiPolygon(MultiPolygon::new(vec![
+                    square,
+                    Rect::new((5., 5.), (6., 6.)).to_polygon(),
+                ])),
+            ])
+        );
+
+        let empty = GeometryCollection::<f64>::new_from(vec![]);
+        assert!(empty.is_homogeneous());
+        assert_eq!(empty.to_multi_geometry(), None);
+        let points = GeometryCollection::new_from(vec![
+            Geometry::MultiPoint(MultiPoint::new(vec![point!(x: 0., y: 0.)])),
+            Geometry::Polygon(Polygon::new(line_string![], vec![])),
+        ]);
+        assert!(points.is_homogeneous());
+        assert_eq!(
+            points.to_multi_geometry(),
+            Some(Geometry::MultiPoint(MultiPoint::new(vec![
+                point!(x: 0., y: 0.)
+            ])))
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Points on the unit sphere represented as 3D unit vectors ("n-vectors").
+//!
+//! Many spherical computations (great-circle intersections, along-track distances, centroids)
+//! are simpler and better conditioned in this representation than in terms of latitude and
+//! longitude. See <https://www.movable-type.co.uk/scripts/latlong-vectors.html>.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::{CoordFloat, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct NVector<T: CoordFloat> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: CoordFloat> NVector<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        NVector { x, y, z }
+    }
+
+    /// The unit vector of a point, whose `x` and `y` are longitude and latitude in degrees.
+    pub fn from_point(point: Point<T>) -> Self {
+        let (sin_lat, cos_lat) = point.y().to_radians().sin_cos();
+        let (sin_lon, cos_lon) = point.x().to_radians().sin_cos();
+        NVector::new(cos_lat * cos_lon, cos_lat * sin_lon, sin_lat)
+    }
+
+    /// The longitude and latitude, in degrees, of the point in the direction of `self`.
+    pub fn to_point(self) -> Point<T> {
+        let lat = self.z.atan2((self.x * self.x + self.y * self.y).sqrt());
+        let lon = self.y.atan2(self.x);
+        Point::new(lon.to_degrees(), lat.to_degrees())
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        NVector::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn norm(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Scale `self` to unit length, or `None` if it is (nearly) the zero vector.
+    pub fn normalize(self) -> Option<Self> {
+        let norm = self.norm();
+        if norm <= T::epsilon() {
+            None
+        } else {
+            Some(self * (T::one() / norm))
+        }
+    }
+
+    /// The angle between `self` and `other` in radians, in `[0, π]`.
+    pub fn angle_to(self, other: Self) -> T {
+        self.cross(other).norm().atan2(self.dot(other))
+    }
+}
+
+impl<T: CoordFloat> Add for NVector<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        NVector::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: CoordFloat> Sub for NVector<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        NVector::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: CoordFloat> Neg for NVector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        NVector::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: CoordFloat> Mul<T> for NVector<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        NVector::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn round_trip() {
+        let point = point!(x: -74.006, y: 40.7128);
+        let round_tripped = NVector::from_point(point).to_point();
+        assert_relative_eq!(point, round_tripped, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn angle_between_points() {
+        let a = NVector::from_point(point!(x: 0., y: 0.));
+        let b = NVector::from_point(point!(x: 90., y: 0.));
+        assert_relative_eq!(a.angle_to(b), core::f64::consts::FRAC_PI_2);
+        let normal = a.cross(b);
+        assert_relative_eq!(normal.x, 0., epsilon = 1e-12);
+        assert_relative_eq!(normal.y, 0., epsilon = 1e-12);
+        assert_relative_eq!(normal.z, 1., epsilon = 1e-12);
+    }
+}
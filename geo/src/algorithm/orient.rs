@@ -1,4 +1,6 @@
-use crate::{GeoNum, MultiPolygon, Polygon};
+use alloc::{vec, vec::Vec};
+
+use crate::{GeoNum, Geometry, GeometryCollection, MultiPolygon, Polygon};
 
 use crate::winding_order::{Winding, WindingOrder};
 
@@ -83,6 +85,141 @@ where
     }
 }
 
+impl<T> Orient for GeometryCollection<T>
+where
+    T: GeoNum,
+{
+    fn orient(&self, direction: Direction) -> GeometryCollection<T> {
+        GeometryCollection::new_from(self.iter().map(|g| g.orient(direction)).collect())
+    }
+}
+
+/// Orients the polygons of a `Geometry`, including those nested in geometry collections.
+/// Geometries without rings, and `Rect`s and `Triangle`s, are unchanged.
+impl<T> Orient for Geometry<T>
+where
+    T: GeoNum,
+{
+    fn orient(&self, direction: Direction) -> Geometry<T> {
+        match self {
+            Geometry::Polygon(g) => Geometry::Polygon(g.orient(direction)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.orient(direction)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.orient(direction)),
+            g => g.clone(),
+        }
+    }
+}
+
+/// The winding orders of a polygon's rings, or `None` for rings which have no winding order,
+/// such as rings with fewer than three distinct coordinates.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PolygonWinding {
+    pub exterior: Option<WindingOrder>,
+    pub interiors: Vec<Option<WindingOrder>>,
+}
+
+impl PolygonWinding {
+    /// True iff every ring with a winding order is wound according to `direction`
+    pub fn is_oriented(&self, direction: Direction) -> bool {
+        let matches = |winding: &Option<WindingOrder>, expected| {
+            winding.map(|winding| winding == expected).unwrap_or(true)
+        };
+        matches(&self.exterior, direction.exterior())
+            && self
+                .interiors
+                .iter()
+                .all(|interior| matches(interior, direction.interior()))
+    }
+}
+
+/// Report the winding orders of the rings of every polygon in a geometry, including those
+/// nested in multi-polygons and geometry collections, to check the orientation which
+/// [`Orient`] applies.
+///
+/// # Examples
+///
+/// ```
+/// use geo::orient::{Direction, Orient, PolygonWinding};
+/// use geo::winding_order::WindingOrder;
+/// use geo::{polygon, Geometry, GeometryCollection, RingWindings};
+///
+/// let clockwise = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 0.)];
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Polygon(clockwise.clone()),
+///     Geometry::GeometryCollection(GeometryCollection::new_from(vec![clockwise.into()])),
+/// ]);
+///
+/// let clockwise_winding = PolygonWinding {
+///     exterior: Some(WindingOrder::Clockwise),
+///     interiors: vec![],
+/// };
+/// assert_eq!(collection.ring_windings(), vec![clockwise_winding.clone(), clockwise_winding]);
+/// assert!(!collection.is_oriented(Direction::Default));
+///
+/// let oriented = collection.orient(Direction::Default);
+/// assert!(oriented.is_oriented(Direction::Default));
+/// ```
+pub trait RingWindings {
+    /// The winding orders of the rings of each polygon, in the order the polygons appear in
+    /// the geometry
+    fn ring_windings(&self) -> Vec<PolygonWinding>;
+
+    /// True iff every ring with a winding order is wound according to `direction`
+    fn is_oriented(&self, direction: Direction) -> bool {
+        self.ring_windings()
+            .iter()
+            .all(|polygon| polygon.is_oriented(direction))
+    }
+}
+
+impl<T> RingWindings for Polygon<T>
+where
+    T: GeoNum,
+{
+    fn ring_windings(&self) -> Vec<PolygonWinding> {
+        vec![PolygonWinding {
+            exterior: self.exterior().winding_order(),
+            interiors: self
+                .interiors()
+                .iter()
+                .map(|interior| interior.winding_order())
+                .collect(),
+        }]
+    }
+}
+
+impl<T> RingWindings for MultiPolygon<T>
+where
+    T: GeoNum,
+{
+    fn ring_windings(&self) -> Vec<PolygonWinding> {
+        self.iter().flat_map(|poly| poly.ring_windings()).collect()
+    }
+}
+
+impl<T> RingWindings for GeometryCollection<T>
+where
+    T: GeoNum,
+{
+    fn ring_windings(&self) -> Vec<PolygonWinding> {
+        self.iter().flat_map(|g| g.ring_windings()).collect()
+    }
+}
+
+impl<T> RingWindings for Geometry<T>
+where
+    T: GeoNum,
+{
+    fn ring_windings(&self) -> Vec<PolygonWinding> {
+        match self {
+            Geometry::Polygon(g) => g.ring_windings(),
+            Geometry::MultiPolygon(g) => g.ring_windings(),
+            Geometry::GeometryCollection(g) => g.ring_windings(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
 /// and its inner ring(s) oriented clockwise. Selecting `Reversed` will result in a Polygon
 /// with a clockwise-oriented exterior ring, and counter-clockwise interior ring(s)
@@ -94,6 +231,19 @@ pub enum Direction {
     Reversed,
 }
 
+impl Direction {
+    fn exterior(self) -> WindingOrder {
+        match self {
+            Direction::Default => WindingOrder::CounterClockwise,
+            Direction::Reversed => WindingOrder::Clockwise,
+        }
+    }
+
+    fn interior(self) -> WindingOrder {
+        self.exterior().inverse()
+    }
+}
+
 // orient a Polygon according to convention
 // by default, the exterior ring will be oriented ccw
 // and the interior ring(s) will be oriented clockwise
@@ -104,18 +254,10 @@ where
     let interiors = poly
         .interiors()
         .iter()
-        .map(|l| {
-            l.clone_to_winding_order(match direction {
-                Direction::Default => WindingOrder::Clockwise,
-                Direction::Reversed => WindingOrder::CounterClockwise,
-            })
-        })
+        .map(|l| l.clone_to_winding_order(direction.interior()))
         .collect();
 
-    let ext_ring = poly.exterior().clone_to_winding_order(match direction {
-        Direction::Default => WindingOrder::CounterClockwise,
-        Direction::Reversed => WindingOrder::Clockwise,
-    });
+    let ext_ring = poly.exterior().clone_to_winding_order(direction.exterior());
 
     Polygon::new(ext_ring, interiors)
 }
@@ -123,7 +265,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{LineString, Polygon};
+    use crate::{point, polygon, LineString, Polygon};
     #[test]
     fn test_polygon_orientation() {
         // a diamond shape, oriented clockwise outside
@@ -145,4 +287,44 @@ mod test {
         assert_eq!(oriented.exterior().0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors()[0].0, oriented_int_ls.0);
     }
+
+    #[test]
+    fn test_nested_orientation() {
+        let cw = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let ccw = cw.orient(Direction::Default);
+        let geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 0., y: 0.).into(),
+            MultiPolygon::new(vec![cw.clone(), ccw.clone()]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![cw.clone().into()])),
+        ]));
+        let windings = geometry.ring_windings();
+        assert_eq!(windings.len(), 3);
+        assert_eq!(windings[0].exterior, Some(WindingOrder::Clockwise));
+        assert_eq!(windings[0].interiors, vec![Some(WindingOrder::Clockwise)]);
+        assert!(windings[1].is_oriented(Direction::Default));
+        assert!(!geometry.is_oriented(Direction::Default));
+        assert!(!geometry.is_oriented(Direction::Reversed));
+
+        let oriented = geometry.orient(Direction::Default);
+        assert!(oriented.is_oriented(Direction::Default));
+        assert_eq!(
+            oriented,
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 0., y: 0.).into(),
+                MultiPolygon::new(vec![ccw.clone(), ccw.clone()]).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![ccw.into()])),
+            ]))
+        );
+        assert!(oriented
+            .orient(Direction::Reversed)
+            .is_oriented(Direction::Reversed));
+
+        // Degenerate rings have no winding order to check
+        let degenerate: Polygon = polygon![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(degenerate.ring_windings()[0].exterior, None);
+        assert!(degenerate.is_oriented(Direction::Default));
+    }
 }
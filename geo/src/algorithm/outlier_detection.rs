@@ -1,7 +1,9 @@
-use std::iter::Sum;
-use std::ops::RangeInclusive;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::RangeInclusive;
 
-use crate::{GeoFloat, MultiPoint, Point};
+use crate::{GeoFloat, MultiPoint, Point, MEAN_EARTH_RADIUS};
 
 use rstar::primitives::GeomWithData;
 use rstar::RTree;
@@ -112,6 +114,87 @@ where
     /// A [`PreparedDetector`] can efficiently recompute outliers with different `k_neigbhours` values.
     fn prepared_detector(&self) -> PreparedDetector<T>;
 
+    /// The points which are scored, in the order of their scores.
+    ///
+    /// The default implementation takes them from a
+    /// [`prepared_detector`](Self::prepared_detector), which builds a spatial index of them, so
+    /// implementations which can borrow their points should override it.
+    fn scored_points(&self) -> &[Point<T>] {
+        self.prepared_detector().points
+    }
+
+    /// The LOF score of each point, as for [`outliers`](Self::outliers), but measuring the
+    /// distances between points with `metric`.
+    ///
+    /// Whereas [`outliers`](Self::outliers) measures neighbourhoods by squared distances, every
+    /// metric measures linear distances, so that the scores are comparable between metrics.
+    ///
+    /// For longitude and latitude points, [`OutlierMetric::Haversine`] avoids the bias of
+    /// distances in degrees, in which points far from the equator are closer together in
+    /// longitude than they are on the ground, and so look denser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection, OutlierMetric};
+    ///
+    /// // A ring of sensors at 60° north, a degree of longitude apart, and one a degree further
+    /// // north: only half a degree of longitude away, but twice as far on the ground
+    /// let mut sensors: Vec<_> = (0..8).map(|i| point!(x: i as f64, y: 60.)).collect();
+    /// sensors.push(point!(x: 3.5, y: 61.));
+    ///
+    /// let planar = sensors.outliers_with_metric(3, OutlierMetric::Euclidean);
+    /// let haversine = sensors.outliers_with_metric(3, OutlierMetric::Haversine);
+    /// assert!(haversine[8] > planar[8]);
+    /// ```
+    fn outliers_with_metric(&self, k_neighbours: usize, metric: OutlierMetric) -> Vec<T>
+    where
+        T: Sum,
+    {
+        self.prepared_detector_with_metric(metric)
+            .outliers(k_neighbours)
+    }
+
+    /// Create a prepared outlier detector, as for [`prepared_detector`](Self::prepared_detector),
+    /// which measures the distances between points with `metric`.
+    fn prepared_detector_with_metric(&self, metric: OutlierMetric) -> PreparedDetector<'_, T>
+    where
+        T: Sum,
+    {
+        PreparedDetector::new(self.scored_points(), metric)
+    }
+
+    /// The LOF score of each point, as for [`outliers`](Self::outliers), but measuring the
+    /// distances between points with the `distance` function, such as a travel time.
+    ///
+    /// Without a spatial index for an arbitrary distance, the neighbours of each point are
+    /// found by measuring the distance to every other point, so this takes `O(n²)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection, Point};
+    ///
+    /// let points = vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.0, y: 1.0),
+    ///     point!(x: 3.0, y: 0.0),
+    ///     point!(x: 1.0, y: 1.0),
+    /// ];
+    /// // The Manhattan distance
+    /// let scores = points.outliers_with_distance(2, |a: &Point, b: &Point| {
+    ///     (a.x() - b.x()).abs() + (a.y() - b.y()).abs()
+    /// });
+    /// assert_eq!(scores[2], 2.0);
+    /// ```
+    fn outliers_with_distance<F>(&self, k_neighbours: usize, distance: F) -> Vec<T>
+    where
+        T: Sum,
+        F: Fn(&Point<T>, &Point<T>) -> T,
+    {
+        lof_with_distance(self.scored_points(), k_neighbours, distance)
+    }
+
     /// Perform successive runs with `k_neighbours` values between `bounds`,
     /// generating an ensemble of LOF scores, which may be aggregated using e.g. min, max, or mean
     ///
@@ -169,52 +252,155 @@ pub struct PreparedDetector<'a, T>
 where
     T: GeoFloat,
 {
-    tree: RTree<GeomWithData<Point<T>, usize>>,
+    index: Index<T>,
     points: &'a [Point<T>],
 }
 
+/// The distance between points which [`OutlierDetection`] measures neighbourhoods with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutlierMetric {
+    /// The Euclidean distance between the coordinates.
+    #[default]
+    Euclidean,
+    /// The [haversine distance](crate::HaversineDistance) between points whose coordinates are
+    /// longitudes and latitudes in degrees.
+    Haversine,
+}
+
+#[derive(Clone, Debug)]
+enum Index<T: GeoFloat> {
+    Planar(RTree<GeomWithData<Point<T>, usize>>),
+    // Measuring neighbourhoods by squared distances, as `OutlierDetection::outliers` always has
+    SquaredPlanar(RTree<GeomWithData<Point<T>, usize>>),
+    // The points as unit vectors, whose chord distances are in the same order as their
+    // distances on the sphere
+    Sphere(RTree<GeomWithData<[T; 3], usize>>),
+}
+
 impl<'a, T> PreparedDetector<'a, T>
 where
     T: GeoFloat + Sum,
 {
     /// Create a new "prepared" detector which allows repeated LOF algorithm calls with varying neighbour sizes
-    fn new(points: &'a [Point<T>]) -> Self {
-        let geoms: Vec<GeomWithData<_, usize>> = points
-            .iter()
-            .enumerate()
-            .map(|(idx, point)| GeomWithData::new(*point, idx))
-            .collect();
-        let tree = RTree::bulk_load(geoms);
-        Self { tree, points }
+    fn new(points: &'a [Point<T>], metric: OutlierMetric) -> Self {
+        let index = match metric {
+            OutlierMetric::Euclidean => Index::Planar(planar_tree(points)),
+            OutlierMetric::Haversine => Index::Sphere(RTree::bulk_load(
+                points
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, point)| GeomWithData::new(unit_vector(point), idx))
+                    .collect(),
+            )),
+        };
+        Self { index, points }
+    }
+
+    /// Create a detector measuring neighbourhoods by squared Euclidean distances
+    fn squared_euclidean(points: &'a [Point<T>]) -> Self {
+        let index = Index::SquaredPlanar(planar_tree(points));
+        Self { index, points }
     }
 
     /// See [`OutlierDetection::outliers`] for usage
     pub fn outliers(&self, kneighbours: usize) -> Vec<T> {
-        lof(self.points, &self.tree, kneighbours)
+        debug_assert!(kneighbours > 0);
+        if self.points.len() <= kneighbours || kneighbours < 1 {
+            // no point in trying to run the algorithm in this case
+            return self.points.iter().map(|_| T::one()).collect();
+        }
+        let knn_dists: Vec<Vec<(usize, T)>> = match &self.index {
+            Index::Planar(tree) | Index::SquaredPlanar(tree) => {
+                let squared = matches!(self.index, Index::SquaredPlanar(_));
+                self.points
+                    .iter()
+                    .map(|point| {
+                        tree.nearest_neighbor_iter_with_distance_2(point)
+                            .take(kneighbours)
+                            .map(|(neighbour, distance_2)| {
+                                let distance = if squared {
+                                    distance_2
+                                } else {
+                                    distance_2.sqrt()
+                                };
+                                (neighbour.data, distance)
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+            Index::Sphere(tree) => {
+                let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+                let two = T::one() + T::one();
+                self.points
+                    .iter()
+                    .map(|point| {
+                        tree.nearest_neighbor_iter_with_distance_2(&unit_vector(point))
+                            .take(kneighbours)
+                            .map(|(neighbour, chord_2)| {
+                                let angle = two * (chord_2.sqrt() / two).min(T::one()).asin();
+                                (neighbour.data, radius * angle)
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+        };
+        lof(&knn_dists)
     }
 }
 
-fn lof<T>(
-    points: &[Point<T>],
-    tree: &RTree<GeomWithData<Point<T>, usize>>,
-    kneighbours: usize,
-) -> Vec<T>
+fn planar_tree<T: GeoFloat>(points: &[Point<T>]) -> RTree<GeomWithData<Point<T>, usize>> {
+    RTree::bulk_load(
+        points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| GeomWithData::new(*point, idx))
+            .collect(),
+    )
+}
+
+fn unit_vector<T: GeoFloat>(point: &Point<T>) -> [T; 3] {
+    let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// The LOF score of each point, measuring distances with `distance`, finding the nearest
+/// neighbours of each point by measuring the distance to every point.
+fn lof_with_distance<T, F>(points: &[Point<T>], kneighbours: usize, distance: F) -> Vec<T>
 where
     T: GeoFloat + Sum,
+    F: Fn(&Point<T>, &Point<T>) -> T,
 {
     debug_assert!(kneighbours > 0);
     if points.len() <= kneighbours || kneighbours < 1 {
-        // no point in trying to run the algorithm in this case
         return points.iter().map(|_| T::one()).collect();
     }
-    let knn_dists = points
+    let knn_dists: Vec<Vec<(usize, T)>> = points
         .iter()
         .map(|point| {
-            tree.nearest_neighbor_iter_with_distance_2(point)
-                .take(kneighbours)
-                .collect()
+            let mut distances: Vec<(usize, T)> = points
+                .iter()
+                .enumerate()
+                .map(|(idx, other)| (idx, distance(point, other)))
+                .collect();
+            let by_distance =
+                |a: &(usize, T), b: &(usize, T)| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal);
+            distances.select_nth_unstable_by(kneighbours - 1, by_distance);
+            distances.truncate(kneighbours);
+            distances.sort_by(by_distance);
+            distances
         })
-        .collect::<Vec<Vec<_>>>();
+        .collect();
+    lof(&knn_dists)
+}
+
+/// The LOF score of each point, from the indices and distances of its nearest neighbours, in
+/// order of distance.
+fn lof<T>(knn_dists: &[Vec<(usize, T)>]) -> Vec<T>
+where
+    T: GeoFloat + Sum,
+{
     // calculate LRD (local reachability density) of each point
     // LRD is the estimated distance at which a point can be found by its neighbours:
     // count(neighbour_set) / sum(max(point.kTh_dist, point.dist2(other point)) for all points in neighbour_set)
@@ -252,7 +438,7 @@ where
             // sum neighbour set LRD scores
             let lrd_scores: T = neighbours
                 .iter()
-                .map(|(neighbour, _)| local_reachability_densities[neighbour.data])
+                .map(|(neighbour, _)| local_reachability_densities[*neighbour])
                 .sum();
             // sum neighbour set reachDistance
             let sum_rd: T = neighbours
@@ -274,7 +460,11 @@ where
     }
 
     fn prepared_detector(&self) -> PreparedDetector<T> {
-        PreparedDetector::new(&self.0)
+        PreparedDetector::squared_euclidean(&self.0)
+    }
+
+    fn scored_points(&self) -> &[Point<T>] {
+        &self.0
     }
 
     fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<T>> {
@@ -308,7 +498,11 @@ where
     }
 
     fn prepared_detector(&self) -> PreparedDetector<T> {
-        PreparedDetector::new(self)
+        PreparedDetector::squared_euclidean(self)
+    }
+
+    fn scored_points(&self) -> &[Point<T>] {
+        self
     }
 
     fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<T>> {
@@ -443,4 +637,57 @@ mod tests {
         // different neighbour sizes give different scores
         assert_ne!(s1[2], s2[2]);
     }
+    #[test]
+    fn test_lof_with_distance() {
+        let v = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(3.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        let squared_euclidean = |a: &Point<f64>, b: &Point<f64>| {
+            let delta = *a - *b;
+            delta.x() * delta.x() + delta.y() * delta.y()
+        };
+        assert_eq!(
+            v.outliers_with_distance(2, squared_euclidean),
+            v.outliers(2)
+        );
+        let euclidean = |a: &Point<f64>, b: &Point<f64>| squared_euclidean(a, b).sqrt();
+        assert_eq!(
+            v.outliers_with_distance(3, euclidean),
+            v.outliers_with_metric(3, OutlierMetric::Euclidean)
+        );
+        // too few points for the neighbour size
+        assert_eq!(v.outliers_with_distance(4, squared_euclidean), [1.0; 4]);
+    }
+    #[test]
+    fn test_lof_haversine() {
+        // the same pattern of points on the ground, at the equator and at 70° north, where a
+        // degree of longitude is about a third as long
+        let pattern = [(0.0, 0.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0), (0.5, 3.0)];
+        let at_equator: MultiPoint<f64> = pattern.iter().map(|&(x, y)| (x, y)).collect();
+        let lon_scale = 1.0 / 70.0_f64.to_radians().cos();
+        let at_70: MultiPoint<f64> = pattern
+            .iter()
+            .map(|&(x, y)| (x * lon_scale, 70.0 + y))
+            .collect();
+
+        let equator_scores = at_equator.outliers_with_metric(2, OutlierMetric::Haversine);
+        let haversine_scores = at_70.outliers_with_metric(2, OutlierMetric::Haversine);
+        for (equator, haversine) in equator_scores.iter().zip(&haversine_scores) {
+            assert_relative_eq!(equator, haversine, max_relative = 0.05);
+        }
+        // near the equator, distances in degrees are in proportion to those on the ground
+        let euclidean_scores = at_equator.outliers_with_metric(2, OutlierMetric::Euclidean);
+        for (euclidean, haversine) in euclidean_scores.iter().zip(&equator_scores) {
+            assert_relative_eq!(euclidean, haversine, max_relative = 0.01);
+        }
+        // in degrees, the stretched longitudes make the same outlier look more isolated at 70°
+        let planar_scores = at_70.outliers(2);
+        assert!(planar_scores[4] > at_equator.outliers(2)[4] * 1.2);
+
+        let prepared = at_70.prepared_detector_with_metric(OutlierMetric::Haversine);
+        assert_eq!(prepared.outliers(2), haversine_scores);
+    }
 }
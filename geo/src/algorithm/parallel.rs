@@ -0,0 +1,536 @@
+//! Parallel variants of algorithms, which process the members of collections on all available
+//! cores using [rayon](https://docs.rs/rayon).
+//!
+//! The traits here are implemented for `MultiPoint`, `MultiLineString`, `MultiPolygon` and
+//! `GeometryCollection`, which split their work by member, and for slices (and so `Vec`s) of
+//! geometries, which is how large datasets are usually held.
+//!
+//! Results are identical to the sequential algorithms', up to the order in which floating point
+//! sums are accumulated.
+//!
+//! Requires the `rayon` feature.
+
+use rayon::prelude::*;
+
+use crate::{
+    Area, Contains, Coord, CoordFloat, CoordNum, EuclideanLength, GeoFloat, GeodesicLength,
+    Geometry, GeometryCollection, HaversineLength, Intersects, LineString, MapCoords,
+    MapCoordsInPlace, MultiLineString, MultiPoint, MultiPolygon, Polygon, Simplify, SimplifyVw,
+};
+
+/// Map a function over all the coordinates of a collection in parallel, returning a new
+/// collection. See [`MapCoords`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon, ParMapCoords};
+///
+/// let squares: MultiPolygon = (0..1000)
+///     .map(|i| {
+///         let x = i as f64;
+///         polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+///     })
+///     .collect();
+///
+/// let flipped = squares.par_map_coords(|coord| (coord.y, coord.x).into());
+/// assert_eq!(flipped.0[999].exterior().0[2], (1., 1000.).into());
+/// ```
+pub trait ParMapCoords<T: CoordNum, NT: CoordNum> {
+    type Output;
+
+    /// Apply a function to all the coordinates in a collection, in parallel, returning a new
+    /// collection.
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output;
+}
+
+/// Map a function over all the coordinates of a collection in parallel, in place. See
+/// [`MapCoordsInPlace`].
+pub trait ParMapCoordsInPlace<T: CoordNum> {
+    /// Apply a function to all the coordinates in a collection, in parallel, in place.
+    fn par_map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync);
+}
+
+macro_rules! impl_par_map_coords {
+    ($type:ident) => {
+        impl<T, NT> ParMapCoords<T, NT> for $type<T>
+        where
+            T: CoordNum + Send + Sync,
+            NT: CoordNum + Send,
+        {
+            type Output = $type<NT>;
+
+            fn par_map_coords(
+                &self,
+                func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+            ) -> Self::Output {
+                $type(
+                    self.0
+                        .par_iter()
+                        .map(|member| member.map_coords(func))
+                        .collect(),
+                )
+            }
+        }
+
+        impl<T> ParMapCoordsInPlace<T> for $type<T>
+        where
+            T: CoordNum + Send + Sync,
+        {
+            fn par_map_coords_in_place(
+                &mut self,
+                func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+            ) {
+                self.0
+                    .par_iter_mut()
+                    .for_each(|member| member.map_coords_in_place(func));
+            }
+        }
+    };
+}
+
+impl_par_map_coords!(MultiPoint);
+impl_par_map_coords!(MultiLineString);
+impl_par_map_coords!(MultiPolygon);
+impl_par_map_coords!(GeometryCollection);
+
+impl<T, NT, G> ParMapCoords<T, NT> for [G]
+where
+    T: CoordNum,
+    NT: CoordNum,
+    G: MapCoords<T, NT> + Sync,
+    G::Output: Send,
+{
+    type Output = Vec<G::Output>;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output {
+        self.par_iter()
+            .map(|geometry| geometry.map_coords(func))
+            .collect()
+    }
+}
+
+impl<T, G> ParMapCoordsInPlace<T> for [G]
+where
+    T: CoordNum,
+    G: MapCoordsInPlace<T> + Send,
+{
+    fn par_map_coords_in_place(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+    ) {
+        self.par_iter_mut()
+            .for_each(|geometry| geometry.map_coords_in_place(func));
+    }
+}
+
+/// Calculate the total area of a collection in parallel. See [`Area`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, MultiPolygon, ParArea};
+///
+/// let squares: MultiPolygon = (0..1000)
+///     .map(|i| {
+///         let x = i as f64 * 2.;
+///         polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+///     })
+///     .collect();
+///
+/// assert_eq!(squares.par_unsigned_area(), 1000.);
+/// assert_eq!(squares.par_signed_area(), squares.signed_area());
+/// ```
+pub trait ParArea<T: CoordNum> {
+    /// The sum of the members' [signed areas](Area::signed_area).
+    fn par_signed_area(&self) -> T;
+
+    /// The sum of the members' [unsigned areas](Area::unsigned_area).
+    fn par_unsigned_area(&self) -> T;
+}
+
+fn par_sum<T, G>(members: &[G], f: impl Fn(&G) -> T + Send + Sync) -> T
+where
+    T: CoordNum + Send,
+    G: Sync,
+{
+    members
+        .par_iter()
+        .map(f)
+        .reduce(T::zero, |total, value| total + value)
+}
+
+impl<T> ParArea<T> for MultiPolygon<T>
+where
+    T: CoordFloat + Send + Sync,
+{
+    fn par_signed_area(&self) -> T {
+        par_sum(&self.0, Polygon::signed_area)
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        par_sum(&self.0, Polygon::unsigned_area)
+    }
+}
+
+impl<T> ParArea<T> for GeometryCollection<T>
+where
+    T: CoordFloat + Send + Sync,
+{
+    fn par_signed_area(&self) -> T {
+        par_sum(&self.0, Geometry::signed_area)
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        par_sum(&self.0, Geometry::unsigned_area)
+    }
+}
+
+impl<T, G> ParArea<T> for [G]
+where
+    T: CoordNum + Send,
+    G: Area<T> + Sync,
+{
+    fn par_signed_area(&self) -> T {
+        par_sum(self, G::signed_area)
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        par_sum(self, G::unsigned_area)
+    }
+}
+
+/// Calculate the total length of a collection of `LineString`s in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, GeodesicLength, MultiLineString, ParLength};
+///
+/// let routes: MultiLineString = (0..100)
+///     .map(|i| {
+///         let lat = i as f64 / 10.;
+///         line_string![(x: 0., y: lat), (x: 1., y: lat), (x: 1., y: lat + 1.)]
+///     })
+///     .collect();
+///
+/// approx::assert_relative_eq!(
+///     routes.par_geodesic_length(),
+///     routes.geodesic_length(),
+///     max_relative = 1e-12
+/// );
+/// ```
+pub trait ParLength<T: CoordNum> {
+    /// The sum of the members' [Euclidean lengths](EuclideanLength).
+    fn par_euclidean_length(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>;
+
+    /// The sum of the members' [haversine lengths](HaversineLength), in meters.
+    fn par_haversine_length(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>;
+
+    /// The sum of the members' [geodesic lengths](GeodesicLength), in meters.
+    fn par_geodesic_length(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>;
+}
+
+impl<T> ParLength<T> for [LineString<T>]
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_euclidean_length(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>,
+    {
+        par_sum(self, LineString::euclidean_length)
+    }
+
+    fn par_haversine_length(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>,
+    {
+        par_sum(self, LineString::haversine_length)
+    }
+
+    fn par_geodesic_length(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>,
+    {
+        par_sum(self, LineString::geodesic_length)
+    }
+}
+
+impl<T> ParLength<T> for MultiLineString<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_euclidean_length(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>,
+    {
+        self.0.par_euclidean_length()
+    }
+
+    fn par_haversine_length(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>,
+    {
+        self.0.par_haversine_length()
+    }
+
+    fn par_geodesic_length(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>,
+    {
+        self.0.par_geodesic_length()
+    }
+}
+
+/// Simplify the members of a collection in parallel. See [`Simplify`] and [`SimplifyVw`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, MultiLineString, ParSimplify, Simplify};
+///
+/// let tracks: MultiLineString = (0..100)
+///     .map(|i| {
+///         let y = i as f64;
+///         line_string![(x: 0., y: y), (x: 5., y: y + 0.1), (x: 10., y: y)]
+///     })
+///     .collect();
+///
+/// let simplified = tracks.par_simplify(&1.);
+/// assert_eq!(simplified, tracks.simplify(&1.));
+/// assert_eq!(simplified.0[0].0.len(), 2);
+/// ```
+pub trait ParSimplify<T, Epsilon = T> {
+    /// Simplify each member using the [Ramer–Douglas–Peucker](Simplify) algorithm.
+    #[must_use]
+    fn par_simplify(&self, epsilon: &T) -> Self;
+
+    /// Simplify each member using the [Visvalingam-Whyatt](SimplifyVw) algorithm.
+    #[must_use]
+    fn par_simplify_vw(&self, epsilon: &T) -> Self;
+}
+
+macro_rules! impl_par_simplify {
+    ($type:ident) => {
+        impl<T> ParSimplify<T> for $type<T>
+        where
+            T: GeoFloat + Send + Sync,
+        {
+            fn par_simplify(&self, epsilon: &T) -> Self {
+                $type(
+                    self.0
+                        .par_iter()
+                        .map(|member| member.simplify(epsilon))
+                        .collect(),
+                )
+            }
+
+            fn par_simplify_vw(&self, epsilon: &T) -> Self {
+                $type(
+                    self.0
+                        .par_iter()
+                        .map(|member| member.simplify_vw(epsilon))
+                        .collect(),
+                )
+            }
+        }
+    };
+}
+
+impl_par_simplify!(MultiLineString);
+impl_par_simplify!(MultiPolygon);
+
+/// Triangulate the polygons of a `MultiPolygon` in parallel. See
+/// [`TriangulateEarcut`](crate::TriangulateEarcut).
+///
+/// Requires the `earcutr` feature, which is enabled by default.
+#[cfg(feature = "earcutr")]
+pub trait ParTriangulateEarcut<T: CoordFloat> {
+    /// The triangles of all the polygons, in the order of the polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Area, MultiPolygon, ParTriangulateEarcut};
+    ///
+    /// let squares: MultiPolygon = (0..100)
+    ///     .map(|i| {
+    ///         let x = i as f64 * 2.;
+    ///         polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+    ///     })
+    ///     .collect();
+    ///
+    /// let triangles = squares.par_earcut_triangles();
+    /// assert_eq!(triangles.len(), 200);
+    /// assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 100.);
+    /// ```
+    fn par_earcut_triangles(&self) -> Vec<crate::Triangle<T>>;
+}
+
+#[cfg(feature = "earcutr")]
+impl<T> ParTriangulateEarcut<T> for MultiPolygon<T>
+where
+    T: CoordFloat + Send + Sync,
+{
+    fn par_earcut_triangles(&self) -> Vec<crate::Triangle<T>> {
+        use crate::TriangulateEarcut;
+
+        self.0
+            .par_iter()
+            .flat_map_iter(|polygon| polygon.earcut_triangles())
+            .collect()
+    }
+}
+
+/// Evaluate a predicate between each of many geometries and a single other geometry in
+/// parallel, as when classifying points against a region.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, ParPredicates, Point};
+///
+/// let region = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let points: Vec<Point> = (0..20).map(|i| point!(x: i as f64, y: 5.)).collect();
+///
+/// let inside = points.par_within(&region);
+/// assert_eq!(inside.iter().filter(|inside| **inside).count(), 9);
+/// assert!(!inside[0] && inside[1] && !inside[10]);
+///
+/// let touching = points.par_intersects(&region);
+/// assert_eq!(touching.iter().filter(|touching| **touching).count(), 11);
+/// ```
+pub trait ParPredicates<G> {
+    /// Whether each member is [contained](Contains) by `container`.
+    fn par_within<C>(&self, container: &C) -> Vec<bool>
+    where
+        C: Contains<G> + Sync;
+
+    /// Whether each member [intersects](Intersects) `other`.
+    fn par_intersects<O>(&self, other: &O) -> Vec<bool>
+    where
+        G: Intersects<O>,
+        O: Sync;
+}
+
+impl<G> ParPredicates<G> for [G]
+where
+    G: Sync,
+{
+    fn par_within<C>(&self, container: &C) -> Vec<bool>
+    where
+        C: Contains<G> + Sync,
+    {
+        self.par_iter()
+            .map(|geometry| container.contains(geometry))
+            .collect()
+    }
+
+    fn par_intersects<O>(&self, other: &O) -> Vec<bool>
+    where
+        G: Intersects<O>,
+        O: Sync,
+    {
+        self.par_iter()
+            .map(|geometry| geometry.intersects(other))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt, Point, Rect};
+
+    fn squares(n: usize) -> MultiPolygon {
+        (0..n)
+            .map(|i| {
+                let x = i as f64 * 2.;
+                polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn map_coords_matches_sequential() {
+        let polygons = squares(500);
+        let shift = |coord: Coord| coord + (1., 2.).into();
+        assert_eq!(polygons.par_map_coords(shift), polygons.map_coords(shift));
+
+        let mut in_place = polygons.clone();
+        in_place.par_map_coords_in_place(shift);
+        assert_eq!(in_place, polygons.map_coords(shift));
+
+        let geometries: Vec<Geometry> = polygons.iter().cloned().map(Geometry::from).collect();
+        let mapped: Vec<Geometry<f32>> =
+            geometries.par_map_coords(|coord| (coord.x as f32, coord.y as f32).into());
+        assert_eq!(mapped.len(), 500);
+        assert_eq!(
+            mapped[1],
+            Geometry::from(polygons.0[1].map_coords(|c| (c.x as f32, c.y as f32).into()))
+        );
+
+        let collection = GeometryCollection::new_from(geometries);
+        assert_eq!(
+            collection.par_map_coords(shift),
+            collection.map_coords(shift)
+        );
+    }
+
+    #[test]
+    fn area_and_length() {
+        let polygons = squares(300);
+        assert_eq!(polygons.par_unsigned_area(), 300.);
+        let collection: GeometryCollection = polygons.iter().cloned().collect();
+        assert_eq!(collection.par_signed_area(), 300.);
+
+        let rects = vec![Rect::new((0., 0.), (2., 3.)); 10];
+        assert_eq!(rects.par_unsigned_area(), 60.);
+
+        let lines = MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 3., y: 4.)]; 25]);
+        assert_eq!(lines.par_euclidean_length(), 125.);
+        assert_relative_eq!(
+            lines.par_haversine_length(),
+            lines.haversine_length(),
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            MultiLineString::<f64>::new(vec![]).par_geodesic_length(),
+            0.
+        );
+    }
+
+    #[test]
+    fn simplify_matches_sequential() {
+        let polygon = wkt! { POLYGON((0. 0.,5. 0.1,10. 0.,10. 10.,5. 9.9,0. 10.,0. 0.)) };
+        let polygons = MultiPolygon::new(vec![polygon; 50]);
+        assert_eq!(polygons.par_simplify(&1.), polygons.simplify(&1.));
+        assert_eq!(polygons.par_simplify_vw(&1.), polygons.simplify_vw(&1.));
+    }
+
+    #[test]
+    fn predicates() {
+        let region = Rect::new((0., 0.), (10., 10.));
+        let points: Vec<Point> = vec![
+            point!(x: 5., y: 5.),
+            point!(x: 10., y: 5.),
+            point!(x: 15., y: 5.),
+        ];
+        assert_eq!(points.par_within(&region), vec![true, false, false]);
+        assert_eq!(points.par_intersects(&region), vec![true, true, false]);
+        assert!(Vec::<Point>::new().par_within(&region).is_empty());
+    }
+}
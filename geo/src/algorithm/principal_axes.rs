@@ -0,0 +1,293 @@
+use crate::{
+    Coord, GeoFloat, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+    SecondMoments, Triangle,
+};
+
+/// The principal axes of a geometry, from [`PrincipalAxes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Axes<T: GeoFloat> {
+    /// The centroid, where the axes cross.
+    pub center: Point<T>,
+    /// The direction of the major axis, in degrees counter-clockwise from the x-axis, in the
+    /// range (-90°, 90°].
+    pub angle: T,
+    /// The standard deviation of the geometry along the major axis.
+    pub major: T,
+    /// The standard deviation of the geometry along the minor axis, which is perpendicular to the
+    /// major axis.
+    pub minor: T,
+}
+
+impl<T: GeoFloat> Axes<T> {
+    /// The ratio of the spread of the geometry along its major axis to that along its minor axis,
+    /// which is 1 for geometries without a dominant direction, such as squares and circles, and
+    /// the ratio of length to width for rectangles. It's infinite for geometries on a line.
+    pub fn elongation(&self) -> T {
+        self.major / self.minor
+    }
+}
+
+/// Calculate the principal axes of a geometry, as in principal component analysis: the
+/// direction in which it's most spread out, how much, and how much it's spread out across that.
+///
+/// Polygons are measured by their [`SecondMoments`] of area, lines by the second moments of their
+/// length, and points by the covariance of their coordinates, so that the result doesn't depend
+/// on how densely lines and polygons are sampled by their vertices. Returns `None` for empty
+/// geometries, and for polygons without area and lines without length.
+///
+/// When there's no dominant direction, as for a square, the angle is arbitrary.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, PrincipalAxes, Rotate};
+///
+/// // A building footprint, 20 by 5 meters, rotated by 30°
+/// let footprint = polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 5.), (x: 0., y: 5.)]
+///     .rotate_around_centroid(30.);
+/// let axes = footprint.principal_axes().unwrap();
+/// assert_relative_eq!(axes.angle, 30., epsilon = 1e-9);
+/// assert_relative_eq!(axes.elongation(), 4., epsilon = 1e-9);
+///
+/// // Rotating it back to square it up
+/// let normalized = footprint.rotate_around_centroid(-axes.angle);
+/// assert_relative_eq!(normalized.principal_axes().unwrap().angle, 0., epsilon = 1e-9);
+/// ```
+pub trait PrincipalAxes<T: GeoFloat> {
+    fn principal_axes(&self) -> Option<Axes<T>>;
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for MultiPoint<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        let origin = self.0.first()?.0;
+        let mut moments = Moments::default();
+        for point in self {
+            let c = point.0 - origin;
+            moments.add(T::one(), c, c.x * c.x, c.y * c.y, c.x * c.y);
+        }
+        moments.axes(origin)
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for LineString<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        let origin = *self.0.first()?;
+        let mut moments = Moments::default();
+        moments.add_line_string(self, origin);
+        moments.axes(origin)
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for MultiLineString<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        let origin = *self.iter().find_map(|line_string| line_string.0.first())?;
+        let mut moments = Moments::default();
+        for line_string in self {
+            moments.add_line_string(line_string, origin);
+        }
+        moments.axes(origin)
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for Polygon<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        let moments = self.second_moments()?;
+        axes(
+            moments.centroid,
+            moments.iyy / moments.area,
+            moments.ixx / moments.area,
+            moments.ixy / moments.area,
+        )
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for MultiPolygon<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        let moments = self.second_moments()?;
+        axes(
+            moments.centroid,
+            moments.iyy / moments.area,
+            moments.ixx / moments.area,
+            moments.ixy / moments.area,
+        )
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for Rect<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        self.to_polygon().principal_axes()
+    }
+}
+
+impl<T: GeoFloat> PrincipalAxes<T> for Triangle<T> {
+    fn principal_axes(&self) -> Option<Axes<T>> {
+        self.to_polygon().principal_axes()
+    }
+}
+
+/// The weight, and first and second moments, of points or lines relative to an origin.
+struct Moments<T> {
+    weight: T,
+    x: T,
+    y: T,
+    xx: T,
+    yy: T,
+    xy: T,
+}
+
+impl<T: GeoFloat> Default for Moments<T> {
+    fn default() -> Self {
+        Moments {
+            weight: T::zero(),
+            x: T::zero(),
+            y: T::zero(),
+            xx: T::zero(),
+            yy: T::zero(),
+            xy: T::zero(),
+        }
+    }
+}
+
+impl<T: GeoFloat> Moments<T> {
+    fn add(&mut self, weight: T, c: Coord<T>, xx: T, yy: T, xy: T) {
+        self.weight = self.weight + weight;
+        self.x = self.x + c.x * weight;
+        self.y = self.y + c.y * weight;
+        self.xx = self.xx + xx * weight;
+        self.yy = self.yy + yy * weight;
+        self.xy = self.xy + xy * weight;
+    }
+
+    /// Add each line of `line_string`, weighted by its length, with the moments of a uniform
+    /// distribution along it.
+    fn add_line_string(&mut self, line_string: &LineString<T>, origin: Coord<T>) {
+        let (two, three, six) = (
+            T::from(2).unwrap(),
+            T::from(3).unwrap(),
+            T::from(6).unwrap(),
+        );
+        for line in line_string.lines() {
+            let (a, b) = (line.start - origin, line.end - origin);
+            let length = (b.x - a.x).hypot(b.y - a.y);
+            self.add(
+                length,
+                (a + b) / two,
+                (a.x * a.x + a.x * b.x + b.x * b.x) / three,
+                (a.y * a.y + a.y * b.y + b.y * b.y) / three,
+                (two * a.x * a.y + a.x * b.y + b.x * a.y + two * b.x * b.y) / six,
+            );
+        }
+    }
+
+    fn axes(&self, origin: Coord<T>) -> Option<Axes<T>> {
+        if self.weight <= T::zero() {
+            return None;
+        }
+        let (cx, cy) = (self.x / self.weight, self.y / self.weight);
+        axes(
+            Point::new(cx + origin.x, cy + origin.y),
+            self.xx / self.weight - cx * cx,
+            self.yy / self.weight - cy * cy,
+            self.xy / self.weight - cx * cy,
+        )
+    }
+}
+
+/// The axes of the covariance matrix `[[xx, xy], [xy, yy]]`, centered on `center`.
+fn axes<T: GeoFloat>(center: Point<T>, xx: T, yy: T, xy: T) -> Option<Axes<T>> {
+    let two = T::from(2).unwrap();
+    let mean = (xx + yy) / two;
+    let radius = ((xx - yy) / two).hypot(xy);
+    let angle = (two * xy).atan2(xx - yy).to_degrees() / two;
+    Some(Axes {
+        center,
+        angle: if angle <= T::from(-90).unwrap() {
+            angle + T::from(180).unwrap()
+        } else {
+            angle
+        },
+        major: (mean + radius).max(T::zero()).sqrt(),
+        minor: (mean - radius).max(T::zero()).sqrt(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Rotate, Translate};
+
+    #[test]
+    fn polygon_axes() {
+        // For a w x h rectangle, the variance along the sides is w² / 12 and h² / 12
+        let rect = Rect::new((0., 0.), (6., 2.));
+        let axes = rect.principal_axes().unwrap();
+        assert_relative_eq!(axes.center, Point::new(3., 1.));
+        assert_relative_eq!(axes.angle, 0.);
+        assert_relative_eq!(axes.major, (36.0_f64 / 12.).sqrt());
+        assert_relative_eq!(axes.minor, (4.0_f64 / 12.).sqrt());
+        assert_relative_eq!(axes.elongation(), 3.);
+
+        let tall = Rect::new((0., 0.), (2., 6.)).principal_axes().unwrap();
+        assert_relative_eq!(tall.angle, 90.);
+        for degrees in [-60., 45., 100.] {
+            let rotated = rect.to_polygon().rotate_around_centroid(degrees);
+            let axes = rotated.principal_axes().unwrap();
+            let expected = if degrees > 90. {
+                degrees - 180.
+            } else {
+                degrees
+            };
+            assert_relative_eq!(axes.angle, expected, epsilon = 1e-9);
+            assert_relative_eq!(axes.elongation(), 3., epsilon = 1e-9);
+        }
+
+        // A square with a square hole has no dominant direction
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        ];
+        assert_relative_eq!(
+            square.principal_axes().unwrap().elongation(),
+            1.,
+            epsilon = 1e-9
+        );
+        let multi_polygon = MultiPolygon::new(vec![square.clone(), square.translate(10., 0.)]);
+        let axes = multi_polygon.principal_axes().unwrap();
+        assert_relative_eq!(axes.angle, 0., epsilon = 1e-9);
+        assert!(axes.elongation() > 3.);
+    }
+
+    #[test]
+    fn line_axes() {
+        // The variance along a line of length l is l² / 12, however many vertices it has
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 4., y: 4.)];
+        let axes = line_string.principal_axes().unwrap();
+        assert_relative_eq!(axes.center, Point::new(2., 2.));
+        assert_relative_eq!(axes.angle, 45., epsilon = 1e-9);
+        assert_relative_eq!(axes.major, (32.0_f64 / 12.).sqrt(), epsilon = 1e-9);
+        assert_relative_eq!(axes.minor, 0., epsilon = 1e-7);
+        assert!(axes.elongation() > 1e6);
+
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 0., y: 2.)],
+            line_string![(x: 4., y: 0.), (x: 4., y: 2.)],
+        ]);
+        let axes = multi_line_string.principal_axes().unwrap();
+        assert_relative_eq!(axes.center, Point::new(2., 1.));
+        assert_relative_eq!(axes.major, 2.);
+        assert_relative_eq!(axes.minor, (4.0_f64 / 12.).sqrt());
+
+        assert_eq!(line_string![(x: 1., y: 1.)].principal_axes(), None);
+    }
+
+    #[test]
+    fn point_axes() {
+        let points = MultiPoint::from(vec![(-2., 1.), (2., -1.), (-1., -1.), (1., 1.)]);
+        let axes = points.principal_axes().unwrap();
+        assert_relative_eq!(axes.center, Point::new(0., 0.));
+        assert!(axes.angle < 0. && axes.angle > -45.);
+        assert!(axes.major > axes.minor);
+        assert_eq!(MultiPoint::<f64>::new(vec![]).principal_axes(), None);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Area, Centroid, Coord, Intersects, Line, MapCoords, Point};
+
+/// Compute the area, centroid and intersections of `f32` geometries in `f64`.
+///
+/// Storing coordinates as `f32` halves the memory of a geometry, but the intermediate
+/// computations of an algorithm, such as the sums of cross products for an area or centroid,
+/// can lose much more precision than the coordinates themselves, especially far from the
+/// origin. The `promoted_*` methods copy a geometry's coordinates into a temporary `f64`
+/// geometry, run the algorithm on that copy and cast the result back to `f32`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Centroid, Point, PromoteF64};
+///
+/// // A 1m square, 10km from the origin
+/// let square = polygon![
+///     (x: 10_000f32, y: 10_000.),
+///     (x: 10_001., y: 10_000.),
+///     (x: 10_001., y: 10_001.),
+///     (x: 10_000., y: 10_001.),
+/// ];
+/// assert_eq!(square.promoted_unsigned_area(), 1.);
+/// assert_eq!(
+///     square.promoted_centroid(),
+///     Some(Point::new(10_000.5, 10_000.5))
+/// );
+/// ```
+pub trait PromoteF64 {
+    type Output;
+
+    /// Copy the geometry's coordinates into an `f64` geometry.
+    fn promote_f64(&self) -> Self::Output;
+
+    /// The signed area of the geometry, computed in `f64`. See [`Area::signed_area`].
+    fn promoted_signed_area(&self) -> f32
+    where
+        Self::Output: Area<f64>,
+    {
+        self.promote_f64().signed_area() as f32
+    }
+
+    /// The unsigned area of the geometry, computed in `f64`. See [`Area::unsigned_area`].
+    fn promoted_unsigned_area(&self) -> f32
+    where
+        Self::Output: Area<f64>,
+    {
+        self.promote_f64().unsigned_area() as f32
+    }
+
+    /// The centroid of the geometry, computed in `f64`, or `None` if the geometry is empty.
+    /// See [`Centroid`].
+    fn promoted_centroid(&self) -> Option<Point<f32>>
+    where
+        Self::Output: Centroid,
+        <Self::Output as Centroid>::Output: Into<Option<Point<f64>>>,
+    {
+        self.promote_f64()
+            .centroid()
+            .into()
+            .map(|centroid| centroid.map_coords(demote))
+    }
+
+    /// Whether the geometry intersects `rhs`, computed in `f64`. See [`Intersects`].
+    fn promoted_intersects<Rhs>(&self, rhs: &Rhs) -> bool
+    where
+        Rhs: PromoteF64,
+        Self::Output: Intersects<Rhs::Output>,
+    {
+        self.promote_f64().intersects(&rhs.promote_f64())
+    }
+}
+
+impl<G> PromoteF64 for G
+where
+    G: MapCoords<f32, f64>,
+{
+    type Output = G::Output;
+
+    fn promote_f64(&self) -> Self::Output {
+        self.map_coords(promote)
+    }
+}
+
+/// The intersection of two `f32` `Line`s, computed in `f64`. See [`line_intersection`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_intersection::LineIntersection;
+/// use geo::{coord, promoted_line_intersection, Line};
+///
+/// let p = Line::new(coord! { x: 10_000f32, y: 10_000. }, coord! { x: 10_003., y: 10_001. });
+/// let q = Line::new(coord! { x: 10_000f32, y: 10_001. }, coord! { x: 10_003., y: 10_000. });
+/// assert_eq!(
+///     promoted_line_intersection(p, q),
+///     Some(LineIntersection::SinglePoint {
+///         intersection: coord! { x: 10_001.5, y: 10_000.5 },
+///         is_proper: true,
+///     })
+/// );
+/// ```
+pub fn promoted_line_intersection(p: Line<f32>, q: Line<f32>) -> Option<LineIntersection<f32>> {
+    let intersection = line_intersection(p.promote_f64(), q.promote_f64())?;
+    Some(match intersection {
+        LineIntersection::SinglePoint {
+            intersection,
+            is_proper,
+        } => LineIntersection::SinglePoint {
+            intersection: demote(intersection),
+            is_proper,
+        },
+        LineIntersection::Collinear { intersection } => LineIntersection::Collinear {
+            intersection: intersection.map_coords(demote),
+        },
+    })
+}
+
+fn promote(coord: Coord<f32>) -> Coord<f64> {
+    Coord {
+        x: coord.x.into(),
+        y: coord.y.into(),
+    }
+}
+
+fn demote(coord: Coord<f64>) -> Coord<f32> {
+    Coord {
+        x: coord.x as f32,
+        y: coord.y as f32,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        coord, line_string, point, polygon, EuclideanDistance, LineString, MultiPolygon, Polygon,
+        Scale, Translate,
+    };
+
+    fn far_from_origin<G: Translate<f32>>(geometry: &G) -> G {
+        geometry.translate(1_000_000., -1_000_000.)
+    }
+
+    #[test]
+    fn centroid_far_from_origin() {
+        // Louisiana's border in meters, rather than degrees
+        let exterior = geo_test_fixtures::louisiana::<f32>().scale(100_000.);
+        let polygon = Polygon::new(exterior, vec![]);
+        let expected = polygon.promote_f64().centroid().unwrap();
+        let error = |centroid: Point<f32>| centroid.promote_f64().euclidean_distance(&expected);
+
+        let promoted = error(polygon.promoted_centroid().unwrap());
+        let unpromoted = error(polygon.centroid().unwrap());
+        assert!(promoted < 0.01);
+        assert!(promoted * 10. < unpromoted);
+    }
+
+    #[test]
+    fn area() {
+        let polygon = polygon![
+            exterior: [(x: 0f32, y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let far = MultiPolygon::new(vec![far_from_origin(&polygon)]);
+        assert_eq!(far.promoted_signed_area(), 99.);
+        assert_eq!(far.promoted_unsigned_area(), 99.);
+        assert_eq!(
+            Polygon::<f32>::new(LineString::new(vec![]), vec![]).promoted_centroid(),
+            None
+        );
+    }
+
+    #[test]
+    fn intersects() {
+        let line_string: LineString<f32> = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let polygon = polygon![(x: 1f32, y: 0.), (x: 3., y: 0.), (x: 1., y: 3.)];
+        assert!(line_string.promoted_intersects(&polygon));
+        assert!(!line_string.promoted_intersects(&point! { x: 1f32, y: 0. }));
+    }
+
+    #[test]
+    fn collinear_line_intersection() {
+        let p = Line::new(coord! { x: 0f32, y: 0. }, coord! { x: 2., y: 2. });
+        let q = Line::new(coord! { x: 1f32, y: 1. }, coord! { x: 3., y: 3. });
+        assert_eq!(
+            promoted_line_intersection(p, q),
+            Some(LineIntersection::Collinear {
+                intersection: Line::new(coord! { x: 1., y: 1. }, coord! { x: 2., y: 2. }),
+            })
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    coord, BoundingRect, Coord, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+    Rect,
+};
+
+/// A grid of cells covering a [`Rect`], each with a value.
+///
+/// Rows run from the top (maximum `y`) of `bounds` to its bottom, and columns from its left
+/// (minimum `x`) to its right, as in most raster formats. `values` are in row-major order, so the
+/// value of the cell at `row` and `column` is `values[row * columns + column]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Raster<V, T: GeoFloat = f64> {
+    /// The area covered by the grid.
+    pub bounds: Rect<T>,
+    pub rows: usize,
+    pub columns: usize,
+    /// The value of each cell, in row-major order.
+    pub values: Vec<V>,
+}
+
+impl<V, T: GeoFloat> Raster<V, T> {
+    /// A grid of `rows` by `columns` cells covering `bounds`, all with `value`.
+    pub fn new(bounds: Rect<T>, rows: usize, columns: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        Raster {
+            bounds,
+            rows,
+            columns,
+            values: vec![value; rows * columns],
+        }
+    }
+
+    /// The value of the cell at `row` and `column`, if it's in the grid.
+    pub fn get(&self, row: usize, column: usize) -> Option<&V> {
+        if row < self.rows && column < self.columns {
+            self.values.get(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    /// The width and height of each cell.
+    pub fn cell_size(&self) -> Coord<T> {
+        coord! {
+            x: self.bounds.width() / T::from(self.columns).unwrap(),
+            y: self.bounds.height() / T::from(self.rows).unwrap(),
+        }
+    }
+
+    /// The area covered by the cell at `row` and `column`.
+    pub fn cell_rect(&self, row: usize, column: usize) -> Rect<T> {
+        let size = self.cell_size();
+        let min = coord! {
+            x: self.bounds.min().x + size.x * T::from(column).unwrap(),
+            y: self.bounds.max().y - size.y * T::from(row + 1).unwrap(),
+        };
+        Rect::new(min, min + size)
+    }
+}
+
+/// Scan-convert a geometry into a [`Raster`] covering a given [`Rect`].
+///
+/// For areal geometries, a cell is in the mask if its center is inside the geometry, and its
+/// coverage is the fraction of its area which is inside the geometry. Polygons of a
+/// `MultiPolygon` are expected not to overlap: the coverage of a cell is the sum of their
+/// coverages, up to `1`.
+///
+/// For linear geometries, a cell is in the mask if the geometry passes through it, including
+/// along its edges, and their coverage is `1` in those cells and `0` elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Rasterize, Rect};
+///
+/// let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+/// let bounds = Rect::new((0., 0.), (4., 4.));
+///
+/// let mask = triangle.rasterize_mask(bounds, 4, 4);
+/// // Row 0 is at the top
+/// assert_eq!(mask.get(3, 0), Some(&true));
+/// assert_eq!(mask.get(0, 3), Some(&false));
+/// assert_eq!(mask.values.iter().filter(|inside| **inside).count(), 6);
+///
+/// let coverage = triangle.rasterize_coverage(bounds, 4, 4);
+/// assert_eq!(coverage.get(0, 0), Some(&0.5));
+/// assert_eq!(coverage.get(3, 0), Some(&1.));
+/// assert_eq!(coverage.values.iter().sum::<f32>(), 8.);
+/// ```
+pub trait Rasterize<T: GeoFloat> {
+    /// A grid of `rows` by `columns` cells covering `bounds`, true in the cells covered by `self`.
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T>;
+
+    /// A grid of `rows` by `columns` cells covering `bounds`, with the fraction of each cell's
+    /// area covered by `self`.
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T>;
+}
+
+impl<T: GeoFloat> Rasterize<T> for Polygon<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        polygons_mask(&[self], bounds, rows, columns)
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        polygons_coverage(&[self], bounds, rows, columns)
+    }
+}
+
+impl<T: GeoFloat> Rasterize<T> for MultiPolygon<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        polygons_mask(&self.iter().collect::<Vec<_>>(), bounds, rows, columns)
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        polygons_coverage(&self.iter().collect::<Vec<_>>(), bounds, rows, columns)
+    }
+}
+
+impl<T: GeoFloat> Rasterize<T> for Rect<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        self.to_polygon().rasterize_mask(bounds, rows, columns)
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        self.to_polygon().rasterize_coverage(bounds, rows, columns)
+    }
+}
+
+impl<T: GeoFloat> Rasterize<T> for Line<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        lines_mask(core::iter::once(*self), bounds, rows, columns)
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        mask_coverage(self.rasterize_mask(bounds, rows, columns))
+    }
+}
+
+impl<T: GeoFloat> Rasterize<T> for LineString<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        lines_mask(self.lines(), bounds, rows, columns)
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        mask_coverage(self.rasterize_mask(bounds, rows, columns))
+    }
+}
+
+impl<T: GeoFloat> Rasterize<T> for MultiLineString<T> {
+    fn rasterize_mask(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<bool, T> {
+        lines_mask(
+            self.iter().flat_map(|line_string| line_string.lines()),
+            bounds,
+            rows,
+            columns,
+        )
+    }
+
+    fn rasterize_coverage(&self, bounds: Rect<T>, rows: usize, columns: usize) -> Raster<f32, T> {
+        mask_coverage(self.rasterize_mask(bounds, rows, columns))
+    }
+}
+
+fn mask_coverage<T: GeoFloat>(mask: Raster<bool, T>) -> Raster<f32, T> {
+    Raster {
+        bounds: mask.bounds,
+        rows: mask.rows,
+        columns: mask.columns,
+        values: mask.values.into_iter().map(f32::from).collect(),
+    }
+}
+
+/// The rows and columns of `raster` which overlap the range `min..=max`, clamped to the grid.
+fn cell_range<V, T: GeoFloat>(raster: &Raster<V, T>, min: Coord<T>, max: Coord<T>) -> CellRange {
+    let size = raster.cell_size();
+    let (left, top) = (raster.bounds.min().x, raster.bounds.max().y);
+    let clamp = |value: T, count: usize| {
+        value
+            .floor()
+            .max(T::zero())
+            .to_usize()
+            .unwrap_or(0)
+            .min(count.saturating_sub(1))
+    };
+    CellRange {
+        rows: clamp((top - max.y) / size.y, raster.rows)
+            ..=clamp((top - min.y) / size.y, raster.rows),
+        columns: clamp((min.x - left) / size.x, raster.columns)
+            ..=clamp((max.x - left) / size.x, raster.columns),
+    }
+}
+
+struct CellRange {
+    rows: core::ops::RangeInclusive<usize>,
+    columns: core::ops::RangeInclusive<usize>,
+}
+
+/// Whether the raster has no cells, or cells without any area.
+fn is_degenerate<V, T: GeoFloat>(raster: &Raster<V, T>) -> bool {
+    raster.values.is_empty()
+        || raster.bounds.width() <= T::zero()
+        || raster.bounds.height() <= T::zero()
+}
+
+fn polygons_mask<T: GeoFloat>(
+    polygons: &[&Polygon<T>],
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+) -> Raster<bool, T> {
+    let mut raster = Raster::new(bounds, rows, columns, false);
+    if is_degenerate(&raster) {
+        return raster;
+    }
+    let size = raster.cell_size();
+    let half = T::from(0.5).unwrap();
+    let rings: Vec<&LineString<T>> = polygons
+        .iter()
+        .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .collect();
+
+    let mut crossings = vec![];
+    for row in 0..rows {
+        let y = bounds.max().y - size.y * (T::from(row).unwrap() + half);
+        // Where the edges cross the line through the centers of the row's cells, with the
+        // half-open rule so that vertices on the line are counted once
+        crossings.clear();
+        for line in rings.iter().flat_map(|ring| ring.lines()) {
+            if (line.start.y > y) != (line.end.y > y) {
+                let f = (y - line.start.y) / (line.end.y - line.start.y);
+                crossings.push(line.start.x + (line.end.x - line.start.x) * f);
+            }
+        }
+        crossings.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        // Fill the cells whose centers are between each pair of crossings
+        for span in crossings.chunks_exact(2) {
+            let first = ((span[0] - bounds.min().x) / size.x - half).ceil();
+            let last = ((span[1] - bounds.min().x) / size.x - half).ceil();
+            let clamp = |column: T| {
+                column
+                    .max(T::zero())
+                    .min(T::from(columns).unwrap())
+                    .to_usize()
+                    .unwrap()
+            };
+            for column in clamp(first)..clamp(last) {
+                raster.values[row * columns + column] = true;
+            }
+        }
+    }
+    raster
+}
+
+fn polygons_coverage<T: GeoFloat>(
+    polygons: &[&Polygon<T>],
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+) -> Raster<f32, T> {
+    let mut areas = Raster::new(bounds, rows, columns, T::zero());
+    if is_degenerate(&areas) {
+        return mask_coverage(Raster::new(bounds, rows, columns, false));
+    }
+    let size = areas.cell_size();
+    for polygon in polygons {
+        let rings = core::iter::once((polygon.exterior(), T::one()))
+            .chain(polygon.interiors().iter().map(|ring| (ring, -T::one())));
+        for (ring, sign) in rings {
+            let Some(ring_bounds) = ring.bounding_rect() else {
+                continue;
+            };
+            let range = cell_range(&areas, ring_bounds.min(), ring_bounds.max());
+            for row in range.rows {
+                let cell = areas.cell_rect(row, 0);
+                let strip = clip(&ring.0, |c| c.y - cell.min().y);
+                let strip = clip(&strip, |c| cell.max().y - c.y);
+                if strip.is_empty() {
+                    continue;
+                }
+                for column in range.columns.clone() {
+                    let left = bounds.min().x + size.x * T::from(column).unwrap();
+                    let right = left + size.x;
+                    let part = clip(&strip, |c| c.x - left);
+                    let part = clip(&part, |c| right - c.x);
+                    areas.values[row * columns + column] =
+                        areas.values[row * columns + column] + sign * signed_area(&part).abs();
+                }
+            }
+        }
+    }
+
+    let cell_area = size.x * size.y;
+    Raster {
+        bounds,
+        rows,
+        columns,
+        values: areas
+            .values
+            .into_iter()
+            .map(|area| {
+                (area / cell_area)
+                    .max(T::zero())
+                    .min(T::one())
+                    .to_f32()
+                    .unwrap()
+            })
+            .collect(),
+    }
+}
+
+/// Clip the closed ring `coords` to the half-plane where `distance` isn't negative, with the
+/// Sutherland-Hodgman algorithm.
+///
+/// The result may have edges along the boundary of the half-plane, but its area is that of the
+/// part of the ring which is inside the half-plane, even if the ring isn't convex.
+fn clip<T: GeoFloat>(coords: &[Coord<T>], distance: impl Fn(Coord<T>) -> T) -> Vec<Coord<T>> {
+    let mut out = Vec::with_capacity(coords.len());
+    for window in coords.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        let (da, db) = (distance(a), distance(b));
+        if da >= T::zero() {
+            out.push(a);
+        }
+        if (da >= T::zero()) != (db >= T::zero()) {
+            out.push(a + (b - a) * (da / (da - db)));
+        }
+    }
+    if let Some(&first) = out.first() {
+        out.push(first);
+    }
+    out
+}
+
+fn signed_area<T: GeoFloat>(coords: &[Coord<T>]) -> T {
+    let twice: T = coords
+        .windows(2)
+        .map(|window| window[0].x * window[1].y - window[1].x * window[0].y)
+        .fold(T::zero(), |sum, cross| sum + cross);
+    twice / T::from(2).unwrap()
+}
+
+fn lines_mask<T: GeoFloat>(
+    lines: impl IntoIterator<Item = Line<T>>,
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+) -> Raster<bool, T> {
+    let mut raster = Raster::new(bounds, rows, columns, false);
+    if is_degenerate(&raster) {
+        return raster;
+    }
+    let (min, max) = (bounds.min(), bounds.max());
+    for line in lines {
+        // Clip the line to the bounds
+        let Some(line) = clip_line(line, |c| c.x - min.x)
+            .and_then(|line| clip_line(line, |c| max.x - c.x))
+            .and_then(|line| clip_line(line, |c| c.y - min.y))
+            .and_then(|line| clip_line(line, |c| max.y - c.y))
+        else {
+            continue;
+        };
+        let range = cell_range(&raster, lower(line), upper(line));
+        for row in range.rows {
+            // The part of the line within the row, and the columns it spans
+            let cell = raster.cell_rect(row, 0);
+            let part = clip_line(line, |c| c.y - cell.min().y)
+                .and_then(|line| clip_line(line, |c| cell.max().y - c.y));
+            if let Some(part) = part {
+                let columns = cell_range(&raster, lower(part), upper(part)).columns;
+                for column in columns {
+                    raster.values[row * raster.columns + column] = true;
+                }
+            }
+        }
+    }
+    raster
+}
+
+fn lower<T: GeoFloat>(line: Line<T>) -> Coord<T> {
+    coord! { x: line.start.x.min(line.end.x), y: line.start.y.min(line.end.y) }
+}
+
+fn upper<T: GeoFloat>(line: Line<T>) -> Coord<T> {
+    coord! { x: line.start.x.max(line.end.x), y: line.start.y.max(line.end.y) }
+}
+
+/// The part of `line` where `distance` isn't negative, if there is one.
+fn clip_line<T: GeoFloat>(line: Line<T>, distance: impl Fn(Coord<T>) -> T) -> Option<Line<T>> {
+    let (ds, de) = (distance(line.start), distance(line.end));
+    let at = |f: T| line.start + line.delta() * f;
+    match (ds >= T::zero(), de >= T::zero()) {
+        (true, true) => Some(line),
+        (false, false) => None,
+        (true, false) => Some(Line::new(line.start, at(ds / (ds - de)))),
+        (false, true) => Some(Line::new(at(ds / (ds - de)), line.end)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area};
+
+    fn unit_grid(size: usize) -> Rect {
+        Rect::new((0., 0.), (size as f64, size as f64))
+    }
+
+    #[test]
+    fn square_with_hole() {
+        let polygon = polygon![
+            exterior: [(x: 0.25, y: 0.25), (x: 3.75, y: 0.25), (x: 3.75, y: 3.75), (x: 0.25, y: 3.75)],
+            interiors: [[(x: 1.25, y: 1.25), (x: 2.75, y: 1.25), (x: 2.75, y: 2.75), (x: 1.25, y: 2.75)]],
+        ];
+        let mask = polygon.rasterize_mask(unit_grid(4), 4, 4);
+        #[rustfmt::skip]
+        let expected = vec![
+            true, true, true, true,
+            true, false, false, true,
+            true, false, false, true,
+            true, true, true, true,
+        ];
+        assert_eq!(mask.values, expected);
+
+        let coverage = polygon.rasterize_coverage(unit_grid(4), 4, 4);
+        #[rustfmt::skip]
+        let expected = vec![
+            0.5625, 0.75, 0.75, 0.5625,
+            0.75, 0.4375, 0.4375, 0.75,
+            0.75, 0.4375, 0.4375, 0.75,
+            0.5625, 0.75, 0.75, 0.5625,
+        ];
+        assert_eq!(coverage.values, expected);
+        assert_relative_eq!(
+            coverage.values.iter().sum::<f32>() as f64,
+            polygon.unsigned_area()
+        );
+    }
+
+    #[test]
+    fn coverage_of_any_shape() {
+        let polygon: Polygon = polygon![
+            (x: 0.3, y: 0.1),
+            (x: 7.7, y: 1.3),
+            (x: 3.1, y: 2.9),
+            (x: 6.2, y: 7.4),
+            (x: 0.9, y: 5.5),
+        ];
+        let bounds = Rect::new((-1., -1.), (9., 9.));
+        let coverage = polygon.rasterize_coverage(bounds, 25, 20);
+        let cell_area = 0.5 * 0.4;
+        assert_relative_eq!(
+            coverage.values.iter().map(|&c| c as f64).sum::<f64>() * cell_area,
+            polygon.unsigned_area(),
+            epsilon = 1e-4
+        );
+        // The mask is within the cells that are at least partly covered
+        let mask = polygon.rasterize_mask(bounds, 25, 20);
+        for (inside, coverage) in mask.values.iter().zip(&coverage.values) {
+            if *inside {
+                assert!(*coverage > 0.);
+            }
+            if *coverage == 1. {
+                assert!(*inside);
+            }
+        }
+        let count = mask.values.iter().filter(|inside| **inside).count() as f64;
+        assert!((count * cell_area - polygon.unsigned_area()).abs() < 2.);
+    }
+
+    #[test]
+    fn lines() {
+        let line_string = line_string![(x: 0.5, y: 0.5), (x: 3.5, y: 0.5), (x: 3.5, y: 3.5)];
+        let mask = line_string.rasterize_mask(unit_grid(4), 4, 4);
+        #[rustfmt::skip]
+        let expected = vec![
+            false, false, false, true,
+            false, false, false, true,
+            false, false, false, true,
+            true, true, true, true,
+        ];
+        assert_eq!(mask.values, expected);
+
+        // A diagonal passes through the cells along it, and touches their neighbours at corners
+        let diagonal = Line::new((0.5, 0.5), (3.5, 3.5));
+        let coverage = diagonal.rasterize_coverage(unit_grid(4), 4, 4);
+        for row in 0..4 {
+            assert_eq!(coverage.get(row, 3 - row), Some(&1.));
+        }
+        assert!(coverage.values.iter().filter(|c| **c == 1.).count() <= 10);
+
+        // Parts outside the bounds are ignored
+        let outside = Line::new((-5., 2.5), (1.5, 2.5));
+        let mask = outside.rasterize_mask(unit_grid(4), 4, 4);
+        assert_eq!(&mask.values[4..8], &[true, true, false, false]);
+    }
+
+    #[test]
+    fn raster() {
+        let raster = Raster::new(Rect::new((10., 20.), (14., 22.)), 2, 4, 0_u8);
+        assert_eq!(raster.cell_size(), coord! { x: 1., y: 1. });
+        assert_eq!(raster.cell_rect(0, 1), Rect::new((11., 21.), (12., 22.)));
+        assert_eq!(raster.get(1, 3), Some(&0));
+        assert_eq!(raster.get(2, 0), None);
+        assert_eq!(raster.get(0, 4), None);
+    }
+
+    #[test]
+    fn empty() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let mask = polygon.rasterize_mask(Rect::new((0., 0.), (0., 1.)), 2, 2);
+        assert_eq!(mask.values, vec![false; 4]);
+        assert!(polygon
+            .rasterize_coverage(unit_grid(1), 0, 0)
+            .values
+            .is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{CoordNum, GeoNum, Line, MultiPolygon, Polygon, Rect};
+
+/// Decompose a rectilinear polygon, all of whose edges are horizontal or vertical, such as a
+/// floorplan or the copper of a circuit board, into non-overlapping [`Rect`]s which cover it,
+/// for processing with algorithms specialized to rectangles.
+///
+/// The polygon is divided into a grid by the `x` and `y` coordinates of its vertices, and the
+/// cells inside it are merged greedily into rectangles, as wide as possible, then as tall as
+/// possible, or the other way round, whichever gives fewer. This is exact, also for integer
+/// coordinates, and usually gives the fewest rectangles, or close to it, but takes time
+/// proportional to the number of cells of the grid, which is up to the square of the number of
+/// vertices.
+///
+/// Polygons with a slanted edge have no decomposition.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Rect, RectDecomposition};
+///
+/// // An L-shaped room
+/// let room = polygon![
+///     (x: 0, y: 0),
+///     (x: 6, y: 0),
+///     (x: 6, y: 2),
+///     (x: 2, y: 2),
+///     (x: 2, y: 5),
+///     (x: 0, y: 5),
+/// ];
+/// let rects = room.rect_decomposition().unwrap();
+/// assert_eq!(rects, vec![Rect::new((0, 0), (6, 2)), Rect::new((0, 2), (2, 5))]);
+///
+/// let slanted = polygon![(x: 0, y: 0), (x: 4, y: 0), (x: 0, y: 4)];
+/// assert_eq!(slanted.rect_decomposition(), None);
+/// ```
+pub trait RectDecomposition<T: CoordNum> {
+    /// Non-overlapping rectangles whose union is `self`, or `None` if it has an edge which is
+    /// neither horizontal nor vertical.
+    fn rect_decomposition(&self) -> Option<Vec<Rect<T>>>;
+}
+
+impl<T: GeoNum> RectDecomposition<T> for Polygon<T> {
+    fn rect_decomposition(&self) -> Option<Vec<Rect<T>>> {
+        decompose(core::slice::from_ref(self))
+    }
+}
+
+/// The polygons are expected not to overlap.
+impl<T: GeoNum> RectDecomposition<T> for MultiPolygon<T> {
+    fn rect_decomposition(&self) -> Option<Vec<Rect<T>>> {
+        decompose(&self.0)
+    }
+}
+
+impl<T: GeoNum> RectDecomposition<T> for Rect<T> {
+    fn rect_decomposition(&self) -> Option<Vec<Rect<T>>> {
+        if self.width() == T::zero() || self.height() == T::zero() {
+            Some(vec![])
+        } else {
+            Some(vec![*self])
+        }
+    }
+}
+
+fn sorted<T: GeoNum>(mut values: Vec<T>) -> Vec<T> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    values.dedup();
+    values
+}
+
+fn decompose<T: GeoNum>(polygons: &[Polygon<T>]) -> Option<Vec<Rect<T>>> {
+    let lines: Vec<Line<T>> = polygons
+        .iter()
+        .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .flat_map(|ring| ring.lines())
+        .collect();
+    if lines
+        .iter()
+        .any(|line| line.start.x != line.end.x && line.start.y != line.end.y)
+    {
+        return None;
+    }
+    let xs = sorted(lines.iter().map(|line| line.start.x).collect());
+    let ys = sorted(lines.iter().map(|line| line.start.y).collect());
+    let index = |values: &[T], value: T| {
+        values
+            .binary_search_by(|probe| probe.partial_cmp(&value).unwrap_or(Ordering::Equal))
+            .unwrap()
+    };
+    let (columns, rows) = (xs.len().saturating_sub(1), ys.len().saturating_sub(1));
+
+    // The vertical edges cross whole rows of cells, so each row is inside between alternate
+    // crossings
+    let mut inside = vec![vec![false; columns]; rows];
+    for line in lines.iter().filter(|line| line.start.y != line.end.y) {
+        let column = index(&xs, line.start.x);
+        let (a, b) = (index(&ys, line.start.y), index(&ys, line.end.y));
+        for row in &mut inside[a.min(b)..a.max(b)] {
+            if let Some(cell) = row.get_mut(column) {
+                *cell = !*cell;
+            }
+        }
+    }
+    for row in &mut inside {
+        for column in 1..columns {
+            row[column] ^= row[column - 1];
+        }
+    }
+
+    let wide = merge(&inside, rows, columns, |row, column| (row, column));
+    let transposed: Vec<Vec<bool>> = (0..columns)
+        .map(|column| (0..rows).map(|row| inside[row][column]).collect())
+        .collect();
+    let tall = merge(&transposed, columns, rows, |column, row| (row, column));
+    let cells = if tall.len() < wide.len() { tall } else { wide };
+    Some(
+        cells
+            .into_iter()
+            .map(|((row, column), (end_row, end_column))| {
+                Rect::new((xs[column], ys[row]), (xs[end_column], ys[end_row]))
+            })
+            .collect(),
+    )
+}
+
+type Cells = ((usize, usize), (usize, usize));
+
+/// Merge the cells of `inside` greedily into rectangles, each as long as possible along its
+/// first row, then as many rows as possible, as the `(row, column)` of their first and past the
+/// last cells, mapped by `cell`.
+fn merge(
+    inside: &[Vec<bool>],
+    rows: usize,
+    columns: usize,
+    cell: impl Fn(usize, usize) -> (usize, usize),
+) -> Vec<Cells> {
+    let mut used = vec![vec![false; columns]; rows];
+    let free =
+        |used: &[Vec<bool>], row: usize, column: usize| inside[row][column] && !used[row][column];
+    let mut rects = vec![];
+    for row in 0..rows {
+        for column in 0..columns {
+            if !free(&used, row, column) {
+                continue;
+            }
+            let mut end_column = column + 1;
+            while end_column < columns && free(&used, row, end_column) {
+                end_column += 1;
+            }
+            let mut end_row = row + 1;
+            while end_row < rows && (column..end_column).all(|c| free(&used, end_row, c)) {
+                end_row += 1;
+            }
+            for used_row in &mut used[row..end_row] {
+                for used_cell in &mut used_row[column..end_column] {
+                    *used_cell = true;
+                }
+            }
+            let (start, end) = (cell(row, column), cell(end_row, end_column));
+            rects.push((start, end));
+        }
+    }
+    rects
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Intersects};
+
+    fn check(polygon: &Polygon<f64>, count: usize) {
+        let rects = polygon.rect_decomposition().unwrap();
+        assert_eq!(rects.len(), count, "{rects:?}");
+        let area: f64 = rects.iter().map(|rect| rect.unsigned_area()).sum();
+        assert_relative_eq!(area, polygon.unsigned_area());
+        // Rectangles may only touch each other
+        for (i, a) in rects.iter().enumerate() {
+            for b in &rects[i + 1..] {
+                if a.intersects(b) {
+                    let overlap = Rect::new(
+                        (a.min().x.max(b.min().x), a.min().y.max(b.min().y)),
+                        (a.max().x.min(b.max().x), a.max().y.min(b.max().y)),
+                    );
+                    assert_eq!(overlap.unsigned_area(), 0.);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn square_with_hole() {
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.)]],
+        ];
+        check(&frame, 4);
+    }
+
+    #[test]
+    fn orientation_with_fewer_rects() {
+        // A comb with three teeth pointing up takes four rectangles if cut horizontally first,
+        // and five if cut vertically first
+        let comb = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 3.),
+            (x: 4., y: 3.),
+            (x: 4., y: 1.),
+            (x: 3., y: 1.),
+            (x: 3., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        check(&comb, 4);
+        let rotated = crate::Rotate::rotate_around_point(&comb, 90., (0., 0.).into());
+        let rotated = crate::MapCoords::map_coords(&rotated, |c| {
+            crate::coord! {
+                x: c.x.round(),
+                y: c.y.round(),
+            }
+        });
+        // And the other way round on its side
+        check(&rotated, 4);
+    }
+
+    #[test]
+    fn plus() {
+        let plus = polygon![
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 3., y: 1.),
+            (x: 3., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 3.),
+            (x: 1., y: 3.),
+            (x: 1., y: 2.),
+            (x: 0., y: 2.),
+            (x: 0., y: 1.),
+            (x: 1., y: 1.),
+        ];
+        check(&plus, 3);
+    }
+
+    #[test]
+    fn multi_polygon_and_rect() {
+        let rooms = MultiPolygon::new(vec![
+            Rect::new((0, 0), (2, 2)).to_polygon(),
+            Rect::new((2, 0), (5, 1)).to_polygon(),
+        ]);
+        let rects = rooms.rect_decomposition().unwrap();
+        assert_eq!(
+            rects,
+            vec![Rect::new((0, 0), (5, 1)), Rect::new((0, 1), (2, 2))]
+        );
+        assert_eq!(
+            Rect::new((1, 1), (3, 4)).rect_decomposition(),
+            Some(vec![Rect::new((1, 1), (3, 4))])
+        );
+        assert_eq!(
+            Polygon::<i32>::new(crate::LineString::new(vec![]), vec![]).rect_decomposition(),
+            Some(vec![])
+        );
+    }
+}
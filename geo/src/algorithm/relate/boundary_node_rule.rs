@@ -0,0 +1,65 @@
+/// The rule which decides whether the endpoints of the lines of a geometry are on its boundary
+/// or in its interior, given by how many of its lines end at them.
+///
+/// The boundary of a linear geometry is made of some of the endpoints of its lines. Which ones
+/// is a matter of how the lines are modelled: the OGC Simple Features rule, [`Mod2`], counts
+/// an endpoint into the boundary if an odd number of lines end there, so that two lines which
+/// join at a point make a single line through it. In a network, such as of roads or pipes,
+/// every junction may instead be a boundary node, or only the dead ends or only the junctions.
+///
+/// A rule is used with [`RelateWithBoundaryNodeRule`], and all the predicates are available on
+/// the [`IntersectionMatrix`](super::IntersectionMatrix) it calculates.
+///
+/// Based on [JTS's `BoundaryNodeRule` as of 1.18.1](https://github.com/locationtech/jts/blob/jts-1.18.1/modules/core/src/main/java/org/locationtech/jts/algorithm/BoundaryNodeRule.java)
+///
+/// [`Mod2`]: BoundaryNodeRule::Mod2
+/// [`RelateWithBoundaryNodeRule`]: super::RelateWithBoundaryNodeRule
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, BoundaryNodeRule, MultiLineString, Relate, RelateWithBoundaryNodeRule};
+///
+/// // Two pipes which join at a valve
+/// let pipes = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+/// ]);
+/// let valve = point!(x: 1., y: 0.);
+///
+/// // By the OGC rule, the pipes make a single line through the valve
+/// assert!(pipes.relate(&valve).is_contains());
+///
+/// // By the endpoint rule, the valve is on the boundary of the pipes
+/// let intersection_matrix = pipes.relate_with_boundary_node_rule(&valve, BoundaryNodeRule::Endpoint);
+/// assert!(intersection_matrix.is_touches());
+/// assert!(!intersection_matrix.is_contains());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryNodeRule {
+    /// An endpoint is on the boundary if an odd number of lines end at it, as in the OGC
+    /// Simple Features specification. This is the rule [`Relate::relate`](super::Relate::relate)
+    /// uses.
+    #[default]
+    Mod2,
+    /// Every endpoint is on the boundary, however many lines end at it.
+    Endpoint,
+    /// An endpoint is on the boundary if more than one line ends at it, such as the junctions
+    /// of a network.
+    MultivalentEndpoint,
+    /// An endpoint is on the boundary if only one line ends at it, such as the dead ends of a
+    /// network.
+    MonovalentEndpoint,
+}
+
+impl BoundaryNodeRule {
+    /// Whether a point at which `boundary_count` lines end is on the boundary.
+    pub fn is_in_boundary(&self, boundary_count: usize) -> bool {
+        match self {
+            BoundaryNodeRule::Mod2 => boundary_count % 2 == 1,
+            BoundaryNodeRule::Endpoint => boundary_count > 0,
+            BoundaryNodeRule::MultivalentEndpoint => boundary_count > 1,
+            BoundaryNodeRule::MonovalentEndpoint => boundary_count == 1,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use super::{CoordPos, Direction, Edge, EdgeEnd, GeometryGraph, IntersectionMatrix, Label};
+use crate::relate::BoundaryNodeRule;
 use crate::{Coord, GeoFloat};
+use alloc::{vec, vec::Vec};
 
 /// A collection of [`EdgeEnds`](EdgeEnd) which obey the following invariant:
 /// They originate at the same node and have the same direction.
@@ -37,7 +39,10 @@ where
         self.edge_ends.push(edge_end);
     }
 
-    pub(crate) fn into_labeled(mut self) -> LabeledEdgeEndBundle<F> {
+    pub(crate) fn into_labeled(
+        mut self,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> LabeledEdgeEndBundle<F> {
         let is_area = self
             .edge_ends_iter()
             .any(|edge_end| edge_end.label().is_area());
@@ -49,7 +54,7 @@ where
         };
 
         for i in 0..2 {
-            self.compute_label_on(&mut label, i);
+            self.compute_label_on(&mut label, i, boundary_node_rule);
             if is_area {
                 self.compute_label_side(&mut label, i, Direction::Left);
                 self.compute_label_side(&mut label, i, Direction::Right);
@@ -77,12 +82,18 @@ where
     /// top of a Polygon edge.) In this case the Boundary is given precedence.
     ///
     /// These observations result in the following rules for computing the ON location:
-    /// - if there are an odd number of Bdy edges, the attribute is Bdy
-    /// - if there are an even number >= 2 of Bdy edges, the attribute is Int
+    /// - if the boundary node rule puts the number of Bdy edges in the boundary, the attribute
+    ///   is Bdy (by the default Mod-2 rule, if there are an odd number of them)
+    /// - if there are Bdy edges but the rule leaves them out of the boundary, the attribute is Int
     /// - if there are any Int edges, the attribute is Int
     /// - otherwise, the attribute is None
     ///
-    fn compute_label_on(&mut self, label: &mut Label, geom_index: usize) {
+    fn compute_label_on(
+        &mut self,
+        label: &mut Label,
+        geom_index: usize,
+        boundary_node_rule: BoundaryNodeRule,
+    ) {
         let mut boundary_count = 0;
         let mut found_interior = false;
 
@@ -104,7 +115,10 @@ where
         }
 
         if boundary_count > 0 {
-            position = Some(GeometryGraph::<'_, F>::determine_boundary(boundary_count));
+            position = Some(GeometryGraph::<'_, F>::determine_boundary(
+                boundary_node_rule,
+                boundary_count,
+            ));
         }
 
         if let Some(location) = position {
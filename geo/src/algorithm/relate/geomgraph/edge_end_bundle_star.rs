@@ -4,6 +4,7 @@ use super::{
 };
 use crate::coordinate_position::{CoordPos, CoordinatePosition};
 use crate::{Coord, GeoFloat, GeometryCow};
+use alloc::vec::Vec;
 
 /// An ordered list of [`EdgeEndBundle`]s around a [`RelateNodeFactory::Node`].
 ///
@@ -16,7 +17,7 @@ pub(crate) struct EdgeEndBundleStar<F>
 where
     F: GeoFloat,
 {
-    edge_map: std::collections::BTreeMap<EdgeEndKey<F>, EdgeEndBundle<F>>,
+    edge_map: alloc::collections::BTreeMap<EdgeEndKey<F>, EdgeEndBundle<F>>,
     point_in_area_location: Option<[CoordPos; 2]>,
 }
 
@@ -146,7 +147,7 @@ where
 {
     pub(crate) fn new() -> Self {
         EdgeEndBundleStar {
-            edge_map: std::collections::BTreeMap::new(),
+            edge_map: alloc::collections::BTreeMap::new(),
             point_in_area_location: None,
         }
     }
@@ -193,7 +194,7 @@ where
         let labeled_edges = self
             .edge_map
             .into_values()
-            .map(|edge_end_bundle| edge_end_bundle.into_labeled())
+            .map(|edge_end_bundle| edge_end_bundle.into_labeled(graph_a.boundary_node_rule()))
             .collect();
         LabeledEdgeEndBundleStar::new(labeled_edges, graph_a, graph_b)
     }
@@ -2,14 +2,16 @@ use super::{
     index::{
         EdgeSetIntersector, RstarEdgeSetIntersector, SegmentIntersector, SimpleEdgeSetIntersector,
     },
+    node_map::{NodeFactory, NodeMap},
     CoordNode, CoordPos, Direction, Edge, Label, LineIntersector, PlanarGraph, TopologyPosition,
 };
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
 
-use crate::HasDimensions;
+use crate::dimensions::{Dimensions, HasDimensions};
+use crate::relate::BoundaryNodeRule;
 use crate::{Coord, GeoFloat, GeometryCow, Line, LineString, Point, Polygon};
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use core::cell::RefCell;
 
 /// The computation of the [`IntersectionMatrix`] relies on the use of a
 /// structure called a "topology graph". The topology graph contains [nodes](CoordNode) and
@@ -33,9 +35,24 @@ where
     arg_index: usize,
     parent_geometry: &'a GeometryCow<'a, F>,
     use_boundary_determination_rule: bool,
+    boundary_node_rule: BoundaryNodeRule,
+    boundary_counts: NodeMap<F, BoundaryCount>,
     planar_graph: PlanarGraph<F>,
 }
 
+/// The number of lines which end at a node
+struct BoundaryCount;
+
+impl<F> NodeFactory<F> for BoundaryCount
+where
+    F: GeoFloat,
+{
+    type Node = usize;
+    fn create_node(_coordinate: Coord<F>) -> Self::Node {
+        0
+    }
+}
+
 ///  PlanarGraph delegations
 ///
 /// In JTS, which is written in Java, GeometryGraph inherits from PlanarGraph. Here in Rust land we
@@ -69,11 +86,17 @@ impl<'a, F> GeometryGraph<'a, F>
 where
     F: GeoFloat,
 {
-    pub fn new(arg_index: usize, parent_geometry: &'a GeometryCow<F>) -> Self {
+    pub fn new(
+        arg_index: usize,
+        parent_geometry: &'a GeometryCow<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> Self {
         let mut graph = GeometryGraph {
             arg_index,
             parent_geometry,
             use_boundary_determination_rule: true,
+            boundary_node_rule,
+            boundary_counts: NodeMap::new(),
             planar_graph: PlanarGraph::new(),
         };
         graph.add_geometry(parent_geometry);
@@ -84,12 +107,30 @@ where
         self.parent_geometry
     }
 
+    pub fn boundary_node_rule(&self) -> BoundaryNodeRule {
+        self.boundary_node_rule
+    }
+
+    /// The dimensions of the boundary of the geometry, which for linear geometries depends on
+    /// the boundary node rule.
+    pub fn boundary_dimensions(&self) -> Dimensions {
+        let geometry = self.geometry();
+        if geometry.dimensions() != Dimensions::OneDimensional {
+            geometry.boundary_dimensions()
+        } else if self.boundary_nodes().next().is_some() {
+            Dimensions::ZeroDimensional
+        } else {
+            Dimensions::Empty
+        }
+    }
+
     /// Determine whether a component (node or edge) that appears multiple times in elements
     /// of a Multi-Geometry is in the boundary or the interior of the Geometry
-    pub fn determine_boundary(boundary_count: usize) -> CoordPos {
-        // For now, we only support the SFS "Mod-2 Rule"
-        // We could make this configurable if we wanted to support alternative boundary rules.
-        if boundary_count % 2 == 1 {
+    pub fn determine_boundary(
+        boundary_node_rule: BoundaryNodeRule,
+        boundary_count: usize,
+    ) -> CoordPos {
+        if boundary_node_rule.is_in_boundary(boundary_count) {
             CoordPos::OnBoundary
         } else {
             CoordPos::Inside
@@ -330,23 +371,19 @@ where
     /// Add the boundary points of 1-dim (line) geometries.
     fn insert_boundary_point(&mut self, coord: Coord<F>) {
         let arg_index = self.arg_index;
-        let node: &mut CoordNode<F> = self.add_node_with_coordinate(coord);
+        let boundary_node_rule = self.boundary_node_rule;
+        let count: &mut usize = self.boundary_counts.insert_node_with_coordinate(coord);
+        let node: &mut CoordNode<F> = self.planar_graph.add_node_with_coordinate(coord);
 
         let label: &mut Label = node.label_mut();
 
-        // determine the current location for the point (if any)
-        let boundary_count = {
-            #[allow(clippy::bool_to_int_with_if)]
-            let prev_boundary_count =
-                if Some(CoordPos::OnBoundary) == label.position(arg_index, Direction::On) {
-                    1
-                } else {
-                    0
-                };
-            prev_boundary_count + 1
-        };
+        // A point already on the boundary, such as that of a ring, counts as one line ending at it
+        if *count == 0 && Some(CoordPos::OnBoundary) == label.position(arg_index, Direction::On) {
+            *count = 1;
+        }
+        *count += 1;
 
-        let new_position = Self::determine_boundary(boundary_count);
+        let new_position = Self::determine_boundary(boundary_node_rule, *count);
         label.set_on_position(arg_index, new_position);
     }
 
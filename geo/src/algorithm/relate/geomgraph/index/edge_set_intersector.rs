@@ -2,8 +2,8 @@ use super::super::Edge;
 use super::SegmentIntersector;
 use crate::{Coord, GeoFloat};
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use core::cell::RefCell;
 
 pub(crate) trait EdgeSetIntersector<F: GeoFloat> {
     /// Compute all intersections between the edges within a set, recording those intersections on
@@ -1,9 +1,9 @@
 use super::super::Edge;
 use super::{EdgeSetIntersector, SegmentIntersector};
 use crate::GeoFloat;
+use alloc::{rc::Rc, vec::Vec};
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use core::cell::RefCell;
 
 use rstar::RTree;
 
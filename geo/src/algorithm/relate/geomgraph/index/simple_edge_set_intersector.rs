@@ -2,8 +2,8 @@ use super::super::Edge;
 use super::{EdgeSetIntersector, SegmentIntersector};
 use crate::GeoFloat;
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use core::cell::RefCell;
 
 pub(crate) struct SimpleEdgeSetIntersector;
 
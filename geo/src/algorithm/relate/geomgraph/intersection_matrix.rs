@@ -1,7 +1,8 @@
 use crate::{coordinate_position::CoordPos, dimensions::Dimensions};
+use alloc::{format, string::String, vec::Vec};
 
 use crate::geometry_cow::GeometryCow::Point;
-use std::str::FromStr;
+use core::str::FromStr;
 
 /// Models a *Dimensionally Extended Nine-Intersection Model (DE-9IM)* matrix.
 ///
@@ -42,7 +43,7 @@ impl<T> LocationArray<T> {
     }
 }
 
-impl<T> std::ops::Index<CoordPos> for LocationArray<T> {
+impl<T> core::ops::Index<CoordPos> for LocationArray<T> {
     type Output = T;
 
     fn index(&self, index: CoordPos) -> &Self::Output {
@@ -54,7 +55,7 @@ impl<T> std::ops::Index<CoordPos> for LocationArray<T> {
     }
 }
 
-impl<T> std::ops::IndexMut<CoordPos> for LocationArray<T> {
+impl<T> core::ops::IndexMut<CoordPos> for LocationArray<T> {
     fn index_mut(&mut self, index: CoordPos) -> &mut Self::Output {
         match index {
             CoordPos::Inside => &mut self.0[0],
@@ -75,15 +76,16 @@ impl InvalidInputError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for InvalidInputError {}
-impl std::fmt::Display for InvalidInputError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for InvalidInputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "invalid input:  {}", self.message)
     }
 }
 
-impl std::fmt::Debug for IntersectionMatrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for IntersectionMatrix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fn char_for_dim(dim: &Dimensions) -> &'static str {
             match dim {
                 Dimensions::Empty => "F",
@@ -591,7 +593,7 @@ impl IntersectionMatrix {
 /// Build an IntersectionMatrix based on a string specification.
 /// ```
 /// use geo::algorithm::relate::IntersectionMatrix;
-/// use std::str::FromStr;
+/// use core::str::FromStr;
 ///
 /// let intersection_matrix = IntersectionMatrix::from_str("212101212").expect("valid DE-9IM specification");
 /// assert!(intersection_matrix.is_intersects());
@@ -609,6 +611,7 @@ impl FromStr for IntersectionMatrix {
 pub(crate) mod dimension_matcher {
     use super::Dimensions;
     use super::InvalidInputError;
+    use alloc::format;
 
     /// A single letter from a DE-9IM matching specification like "1*T**FFF*"
     pub(crate) enum DimensionMatcher {
@@ -3,9 +3,9 @@ use super::{
     CoordNode, CoordPos, Edge, Label,
 };
 use crate::{Coord, GeoFloat};
+use alloc::{rc::Rc, vec, vec::Vec};
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use core::cell::RefCell;
 
 pub(crate) struct PlanarGraphNode;
 
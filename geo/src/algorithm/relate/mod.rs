@@ -1,9 +1,11 @@
+pub use boundary_node_rule::BoundaryNodeRule;
 pub(crate) use edge_end_builder::EdgeEndBuilder;
 pub use geomgraph::intersection_matrix::IntersectionMatrix;
 
 use crate::geometry::*;
 use crate::{GeoFloat, GeometryCow};
 
+mod boundary_node_rule;
 mod edge_end_builder;
 mod geomgraph;
 mod relate_operation;
@@ -52,12 +54,39 @@ mod relate_operation;
 ///
 /// Note: `Relate` must not be called on geometries containing `NaN` coordinates.
 pub trait Relate<F, T> {
+    /// Relate the geometries, with the OGC [`BoundaryNodeRule::Mod2`] rule for the boundaries
+    /// of linear geometries.
     fn relate(&self, other: &T) -> IntersectionMatrix;
 }
 
+/// Topologically relate two geometries like [`Relate`], with an alternate [`BoundaryNodeRule`]
+/// deciding which endpoints of the lines of linear geometries are on their boundaries.
+///
+/// See [`BoundaryNodeRule`] for an example.
+pub trait RelateWithBoundaryNodeRule<F, T> {
+    /// Relate the geometries, with `boundary_node_rule` deciding which endpoints of the lines
+    /// of linear geometries are on their boundaries.
+    fn relate_with_boundary_node_rule(
+        &self,
+        other: &T,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix;
+}
+
 impl<F: GeoFloat> Relate<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
     fn relate(&self, other: &GeometryCow<F>) -> IntersectionMatrix {
-        let mut relate_computer = relate_operation::RelateOperation::new(self, other);
+        self.relate_with_boundary_node_rule(other, BoundaryNodeRule::default())
+    }
+}
+
+impl<F: GeoFloat> RelateWithBoundaryNodeRule<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
+    fn relate_with_boundary_node_rule(
+        &self,
+        other: &GeometryCow<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        let mut relate_computer =
+            relate_operation::RelateOperation::new(self, other, boundary_node_rule);
         relate_computer.compute_intersection_matrix()
     }
 }
@@ -73,6 +102,17 @@ macro_rules! relate_impl {
                     GeometryCow::from(self).relate(&GeometryCow::from(other))
                 }
             }
+
+            impl<F: GeoFloat> RelateWithBoundaryNodeRule<F, $t> for $k {
+                fn relate_with_boundary_node_rule(
+                    &self,
+                    other: &$t,
+                    boundary_node_rule: BoundaryNodeRule,
+                ) -> IntersectionMatrix {
+                    GeometryCow::from(self)
+                        .relate_with_boundary_node_rule(&GeometryCow::from(other), boundary_node_rule)
+                }
+            }
         )*
     };
 }
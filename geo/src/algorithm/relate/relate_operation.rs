@@ -1,4 +1,4 @@
-use super::{EdgeEndBuilder, IntersectionMatrix};
+use super::{BoundaryNodeRule, EdgeEndBuilder, IntersectionMatrix};
 use crate::dimensions::{Dimensions, HasDimensions};
 use crate::relate::geomgraph::{
     index::SegmentIntersector,
@@ -8,9 +8,9 @@ use crate::relate::geomgraph::{
 };
 use crate::CoordinatePosition;
 use crate::{Coord, GeoFloat, GeometryCow};
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use core::cell::RefCell;
 
 /// Computes an [`IntersectionMatrix`] describing the topological relationship between two
 /// Geometries.
@@ -50,10 +50,11 @@ where
     pub(crate) fn new(
         geom_a: &'a GeometryCow<'a, F>,
         geom_b: &'a GeometryCow<'a, F>,
+        boundary_node_rule: BoundaryNodeRule,
     ) -> RelateOperation<'a, F> {
         Self {
-            graph_a: GeometryGraph::new(0, geom_a),
-            graph_b: GeometryGraph::new(1, geom_b),
+            graph_a: GeometryGraph::new(0, geom_a, boundary_node_rule),
+            graph_b: GeometryGraph::new(1, geom_b, boundary_node_rule),
             nodes: NodeMap::new(),
             isolated_edges: vec![],
             line_intersector: RobustLineIntersector::new(),
@@ -117,7 +118,7 @@ where
         self.insert_edge_ends(edge_ends_b);
 
         let mut nodes = NodeMap::new();
-        std::mem::swap(&mut self.nodes, &mut nodes);
+        core::mem::swap(&mut self.nodes, &mut nodes);
         let labeled_node_edges = nodes
             .into_iter()
             .map(|(node, edges)| (node, edges.into_labeled(&self.graph_a, &self.graph_b)))
@@ -302,7 +303,7 @@ where
             if dimensions != Dimensions::Empty {
                 intersection_matrix.set(CoordPos::Inside, CoordPos::Outside, dimensions);
 
-                let boundary_dimensions = geometry_a.boundary_dimensions();
+                let boundary_dimensions = self.graph_a.boundary_dimensions();
                 if boundary_dimensions != Dimensions::Empty {
                     intersection_matrix.set(
                         CoordPos::OnBoundary,
@@ -319,7 +320,7 @@ where
             if dimensions != Dimensions::Empty {
                 intersection_matrix.set(CoordPos::Outside, CoordPos::Inside, dimensions);
 
-                let boundary_dimensions = geometry_b.boundary_dimensions();
+                let boundary_dimensions = self.graph_b.boundary_dimensions();
                 if boundary_dimensions != Dimensions::Empty {
                     intersection_matrix.set(
                         CoordPos::Outside,
@@ -431,11 +432,11 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::Relate;
+    use crate::{Relate, RelateWithBoundaryNodeRule};
 
     use super::*;
+    use core::str::FromStr;
     use geo_types::{line_string, polygon, Geometry};
-    use std::str::FromStr;
 
     #[test]
     fn test_disjoint() {
@@ -459,7 +460,7 @@ mod test {
 
         let gc1 = GeometryCow::from(&square_a);
         let gc2 = GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(&gc1, &gc2);
+        let mut relate_computer = RelateOperation::new(&gc1, &gc2, BoundaryNodeRule::Mod2);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
@@ -489,7 +490,7 @@ mod test {
 
         let gca = GeometryCow::from(&square_a);
         let gcb = GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(&gca, &gcb);
+        let mut relate_computer = RelateOperation::new(&gca, &gcb, BoundaryNodeRule::Mod2);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
@@ -519,7 +520,7 @@ mod test {
 
         let gca = &GeometryCow::from(&square_a);
         let gcb = &GeometryCow::from(&square_b);
-        let mut relate_computer = RelateOperation::new(gca, gcb);
+        let mut relate_computer = RelateOperation::new(gca, gcb, BoundaryNodeRule::Mod2);
         let intersection_matrix = relate_computer.compute_intersection_matrix();
         assert_eq!(
             intersection_matrix,
@@ -553,4 +554,79 @@ mod test {
         assert!(polyrelation.matches(de9im_eq).unwrap());
         assert!(lsrelation.matches(de9im_eq).unwrap());
     }
+
+    #[test]
+    fn boundary_node_rules() {
+        // Three lines which meet at a junction
+        let network = geo_types::MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+        ]);
+        let junction = geo_types::point!(x: 1., y: 0.);
+        let dead_end = geo_types::point!(x: 0., y: 0.);
+
+        let touches = |rule| {
+            (
+                network
+                    .relate_with_boundary_node_rule(&junction, rule)
+                    .is_touches(),
+                network
+                    .relate_with_boundary_node_rule(&dead_end, rule)
+                    .is_touches(),
+            )
+        };
+        assert_eq!(touches(BoundaryNodeRule::Mod2), (true, true));
+        assert_eq!(touches(BoundaryNodeRule::Endpoint), (true, true));
+        assert_eq!(
+            touches(BoundaryNodeRule::MultivalentEndpoint),
+            (true, false)
+        );
+        assert_eq!(touches(BoundaryNodeRule::MonovalentEndpoint), (false, true));
+        assert!(network
+            .relate_with_boundary_node_rule(&junction, BoundaryNodeRule::MonovalentEndpoint)
+            .is_contains());
+
+        // Two lines which join, and a line which is closed
+        let joined = geo_types::MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+        ]);
+        let line = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        // The join is in the interior of the line by either rule, but only on the boundary of
+        // the lines by the endpoint rule
+        let boundary_inside = |rule| {
+            joined
+                .relate_with_boundary_node_rule(&line, rule)
+                .get(CoordPos::OnBoundary, CoordPos::Inside)
+        };
+        assert_eq!(boundary_inside(BoundaryNodeRule::Mod2), Dimensions::Empty);
+        assert_eq!(
+            boundary_inside(BoundaryNodeRule::Endpoint),
+            Dimensions::ZeroDimensional
+        );
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert!(ring.relate(&geo_types::point!(x: 0., y: 0.)).is_contains());
+        assert!(ring
+            .relate_with_boundary_node_rule(
+                &geo_types::point!(x: 0., y: 0.),
+                BoundaryNodeRule::Endpoint
+            )
+            .is_touches());
+    }
+
+    #[test]
+    fn disjoint_boundary_node_rules() {
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let point = geo_types::point!(x: 5., y: 5.);
+        assert_eq!(
+            line.relate(&point),
+            IntersectionMatrix::from_str("FF1FF00F2").unwrap()
+        );
+        // A single line has no boundary when only junctions are boundary nodes
+        assert_eq!(
+            line.relate_with_boundary_node_rule(&point, BoundaryNodeRule::MultivalentEndpoint),
+            IntersectionMatrix::from_str("FF1FFF0F2").unwrap()
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
     Polygon, Rect, Triangle,
 };
+use alloc::vec;
 use geo_types::GeometryCollection;
 use num_traits::FromPrimitive;
 
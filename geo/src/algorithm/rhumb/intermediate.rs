@@ -1,4 +1,5 @@
 use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use alloc::vec::Vec;
 use num_traits::FromPrimitive;
 
 use super::RhumbCalculations;
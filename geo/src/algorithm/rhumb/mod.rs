@@ -7,6 +7,7 @@
 //! Veness; both are available under an MIT license.
 
 use crate::{point, utils::normalize_longitude, CoordFloat, Point};
+use alloc::{vec, vec::Vec};
 use num_traits::FromPrimitive;
 
 mod distance;
@@ -35,7 +36,7 @@ struct RhumbCalculations<T: CoordFloat + FromPrimitive> {
 
 impl<T: CoordFloat + FromPrimitive> RhumbCalculations<T> {
     fn new(from: &Point<T>, to: &Point<T>) -> Self {
-        let pi = T::from(std::f64::consts::PI).unwrap();
+        let pi = T::from(core::f64::consts::PI).unwrap();
         let two = T::one() + T::one();
         let four = two + two;
 
@@ -130,7 +131,7 @@ fn calculate_destination<T: CoordFloat + FromPrimitive>(
     phi1: T,
     theta: T,
 ) -> Point<T> {
-    let pi = T::from(std::f64::consts::PI).unwrap();
+    let pi = T::from(core::f64::consts::PI).unwrap();
     let two = T::one() + T::one();
     let four = two + two;
     let threshold = T::from(10.0e-12).unwrap();
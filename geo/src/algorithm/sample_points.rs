@@ -0,0 +1,127 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::triangulate_earcut::RawTriangulation;
+use crate::{CoordFloat, MultiPoint, MultiPolygon, Polygon, TriangulateEarcut};
+
+/// Generate uniformly distributed random points inside a geometry.
+///
+/// The geometry is triangulated, triangles are chosen with a probability proportional to their
+/// area, and a point is then drawn uniformly from within the chosen triangle using barycentric
+/// coordinates.
+///
+/// Requires the `rand` and `earcutr` features.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Contains, SamplePoints};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let points = polygon.sample_points(100, Some(42));
+/// assert_eq!(points.len(), 100);
+/// assert!(points.iter().all(|point| polygon.contains(point)));
+///
+/// // The same seed produces the same points
+/// assert_eq!(points, polygon.sample_points(100, Some(42)));
+/// ```
+pub trait SamplePoints<T: CoordFloat> {
+    /// Generate `n` random points inside `self`.
+    ///
+    /// If `seed` is `Some`, the points are generated deterministically from it. Otherwise, a
+    /// thread-local random number generator is used.
+    ///
+    /// Returns an empty `MultiPoint` if `self` has no area.
+    fn sample_points(&self, n: usize, seed: Option<u64>) -> MultiPoint<T> {
+        match seed {
+            Some(seed) => self.sample_points_with_rng(n, &mut StdRng::seed_from_u64(seed)),
+            None => self.sample_points_with_rng(n, &mut rand::thread_rng()),
+        }
+    }
+
+    /// Generate `n` random points inside `self`, drawing randomness from `rng`.
+    fn sample_points_with_rng<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T>;
+}
+
+impl<T: CoordFloat> SamplePoints<T> for Polygon<T> {
+    fn sample_points_with_rng<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        self.earcut_triangles_raw()
+            .random_points(rng)
+            .take(n)
+            .collect()
+    }
+}
+
+impl<T: CoordFloat> SamplePoints<T> for MultiPolygon<T> {
+    fn sample_points_with_rng<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        let mut triangulation = RawTriangulation {
+            vertices: vec![],
+            triangle_indices: vec![],
+        };
+        for polygon in self {
+            let raw = polygon.earcut_triangles_raw();
+            let offset = triangulation.vertices.len() / 2;
+            triangulation.vertices.extend(raw.vertices);
+            triangulation
+                .triangle_indices
+                .extend(raw.triangle_indices.into_iter().map(|index| index + offset));
+        }
+        triangulation.random_points(rng).take(n).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Contains, Triangle};
+
+    #[test]
+    fn points_are_inside_polygon_with_hole() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let points = polygon.sample_points(1000, Some(1));
+        assert_eq!(points.len(), 1000);
+        assert!(points.iter().all(|point| polygon.contains(point)));
+    }
+
+    #[test]
+    fn distribution_is_area_weighted() {
+        let small = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let large = polygon![(x: 2., y: 0.), (x: 5., y: 0.), (x: 5., y: 3.), (x: 2., y: 3.)];
+        let multi = MultiPolygon::new(vec![small.clone(), large]);
+        let points = multi.sample_points(10_000, Some(7));
+        let in_small = points.iter().filter(|point| small.contains(*point)).count();
+        // The small square holds a tenth of the total area.
+        assert!((800..1200).contains(&in_small), "{in_small}");
+    }
+
+    #[test]
+    fn seeds_are_deterministic() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 3.)]).to_polygon();
+        assert_eq!(
+            triangle.sample_points(10, Some(3)),
+            triangle.sample_points(10, Some(3))
+        );
+        assert_ne!(
+            triangle.sample_points(10, Some(3)),
+            triangle.sample_points(10, Some(4))
+        );
+        assert_eq!(triangle.sample_points(10, None).len(), 10);
+    }
+
+    #[test]
+    fn degenerate_input() {
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert!(empty.sample_points(10, Some(0)).0.is_empty());
+        let flat = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert!(flat.sample_points(10, Some(0)).0.is_empty());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{Coord, GeoFloat, LineString, MultiPolygon, Point, Polygon, Rect, Triangle};
+
+/// The area, centroid and second moments of area of a polygonal geometry, from
+/// [`SecondMoments`].
+///
+/// The second moments are about axes through the centroid, parallel to the x and y axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaMoments<T: GeoFloat> {
+    /// The unsigned area.
+    pub area: T,
+    /// The centroid.
+    pub centroid: Point<T>,
+    /// The second moment of area about the horizontal axis, ∫ y² dA.
+    pub ixx: T,
+    /// The second moment of area about the vertical axis, ∫ x² dA.
+    pub iyy: T,
+    /// The product moment of area, ∫ xy dA.
+    pub ixy: T,
+}
+
+impl<T: GeoFloat> AreaMoments<T> {
+    /// The polar moment of area about the centroid, `ixx + iyy`.
+    pub fn polar(&self) -> T {
+        self.ixx + self.iyy
+    }
+}
+
+/// Calculate the second moments of area of a polygonal geometry, also known as its moments of
+/// inertia, as used for the section properties of beams.
+///
+/// They're calculated exactly with Green's theorem, as sums over the edges of the rings. Holes
+/// are subtracted, and the orientation of the rings doesn't matter. Returns `None` for
+/// geometries without area.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, polygon, SecondMoments};
+///
+/// // A 4 x 2 rectangle: ixx = b h³ / 12 and iyy = h b³ / 12
+/// let rect = polygon![(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 3.), (x: 1., y: 3.)];
+/// let moments = rect.second_moments().unwrap();
+/// assert_eq!(moments.area, 8.);
+/// assert_eq!(moments.centroid, point!(x: 3., y: 2.));
+/// assert_relative_eq!(moments.ixx, 4. * 8. / 12.);
+/// assert_relative_eq!(moments.iyy, 2. * 64. / 12.);
+/// assert_relative_eq!(moments.ixy, 0.);
+/// assert_relative_eq!(moments.polar(), 40. / 3., epsilon = 1e-12);
+/// ```
+pub trait SecondMoments<T: GeoFloat> {
+    fn second_moments(&self) -> Option<AreaMoments<T>>;
+}
+
+impl<T: GeoFloat> SecondMoments<T> for Polygon<T> {
+    fn second_moments(&self) -> Option<AreaMoments<T>> {
+        let origin = *self.exterior().0.first()?;
+        let mut sums = RingSums::from_ring(self.exterior(), origin).oriented(true);
+        for interior in self.interiors() {
+            sums.add(RingSums::from_ring(interior, origin).oriented(false));
+        }
+        sums.moments(origin)
+    }
+}
+
+impl<T: GeoFloat> SecondMoments<T> for MultiPolygon<T> {
+    fn second_moments(&self) -> Option<AreaMoments<T>> {
+        let origin = *self
+            .iter()
+            .find_map(|polygon| polygon.exterior().0.first())?;
+        let mut sums = RingSums::default();
+        for polygon in self {
+            sums.add(RingSums::from_ring(polygon.exterior(), origin).oriented(true));
+            for interior in polygon.interiors() {
+                sums.add(RingSums::from_ring(interior, origin).oriented(false));
+            }
+        }
+        sums.moments(origin)
+    }
+}
+
+impl<T: GeoFloat> SecondMoments<T> for Rect<T> {
+    fn second_moments(&self) -> Option<AreaMoments<T>> {
+        self.to_polygon().second_moments()
+    }
+}
+
+impl<T: GeoFloat> SecondMoments<T> for Triangle<T> {
+    fn second_moments(&self) -> Option<AreaMoments<T>> {
+        self.to_polygon().second_moments()
+    }
+}
+
+/// The integrals over a ring, or a combination of rings, relative to an origin.
+#[derive(Debug, Clone, Copy)]
+struct RingSums<T> {
+    area: T,
+    /// ∫ x dA and ∫ y dA
+    x: T,
+    y: T,
+    /// ∫ x² dA, ∫ y² dA and ∫ xy dA
+    xx: T,
+    yy: T,
+    xy: T,
+}
+
+impl<T: GeoFloat> Default for RingSums<T> {
+    fn default() -> Self {
+        RingSums {
+            area: T::zero(),
+            x: T::zero(),
+            y: T::zero(),
+            xx: T::zero(),
+            yy: T::zero(),
+            xy: T::zero(),
+        }
+    }
+}
+
+impl<T: GeoFloat> RingSums<T> {
+    /// The signed integrals over `ring`, which are positive if it's counter-clockwise. The
+    /// coordinates are shifted to `origin` first, for numerical stability.
+    fn from_ring(ring: &LineString<T>, origin: Coord<T>) -> Self {
+        let mut sums = RingSums::<T>::default();
+        for line in ring.lines() {
+            let (a, b) = (line.start - origin, line.end - origin);
+            let cross = a.x * b.y - b.x * a.y;
+            sums.area = sums.area + cross;
+            sums.x = sums.x + (a.x + b.x) * cross;
+            sums.y = sums.y + (a.y + b.y) * cross;
+            sums.xx = sums.xx + (a.x * a.x + a.x * b.x + b.x * b.x) * cross;
+            sums.yy = sums.yy + (a.y * a.y + a.y * b.y + b.y * b.y) * cross;
+            let two = T::one() + T::one();
+            sums.xy = sums.xy + (a.x * b.y + two * a.x * a.y + two * b.x * b.y + b.x * a.y) * cross;
+        }
+        let f = |n: f64| T::from(n).unwrap();
+        RingSums {
+            area: sums.area / f(2.),
+            x: sums.x / f(6.),
+            y: sums.y / f(6.),
+            xx: sums.xx / f(12.),
+            yy: sums.yy / f(12.),
+            xy: sums.xy / f(24.),
+        }
+    }
+
+    /// These integrals with the sign of an exterior ring, whose area is positive, or an interior
+    /// ring, whose area is negative.
+    fn oriented(self, exterior: bool) -> Self {
+        if (self.area < T::zero()) == exterior {
+            self.scaled(-T::one())
+        } else {
+            self
+        }
+    }
+
+    fn scaled(self, factor: T) -> Self {
+        RingSums {
+            area: self.area * factor,
+            x: self.x * factor,
+            y: self.y * factor,
+            xx: self.xx * factor,
+            yy: self.yy * factor,
+            xy: self.xy * factor,
+        }
+    }
+
+    fn add(&mut self, other: Self) {
+        self.area = self.area + other.area;
+        self.x = self.x + other.x;
+        self.y = self.y + other.y;
+        self.xx = self.xx + other.xx;
+        self.yy = self.yy + other.yy;
+        self.xy = self.xy + other.xy;
+    }
+
+    /// The moments about the centroid, by the parallel axis theorem.
+    fn moments(self, origin: Coord<T>) -> Option<AreaMoments<T>> {
+        if self.area <= T::zero() {
+            return None;
+        }
+        let (cx, cy) = (self.x / self.area, self.y / self.area);
+        Some(AreaMoments {
+            area: self.area,
+            centroid: Point::new(cx + origin.x, cy + origin.y),
+            ixx: self.yy - self.area * cy * cy,
+            iyy: self.xx - self.area * cx * cx,
+            ixy: self.xy - self.area * cx * cy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Centroid, Rotate, Translate};
+
+    #[test]
+    fn rectangle_with_hole() {
+        // A hollow square section: (10⁴ - 6⁴) / 12 about both axes
+        let section = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+        ];
+        let moments = section.second_moments().unwrap();
+        assert_relative_eq!(moments.area, 64.);
+        assert_relative_eq!(moments.centroid, Point::new(5., 5.));
+        assert_relative_eq!(moments.ixx, (10_000. - 1296.) / 12.);
+        assert_relative_eq!(moments.iyy, moments.ixx);
+        assert_relative_eq!(moments.ixy, 0., epsilon = 1e-9);
+
+        // Neither the orientation of the rings nor the position of the section matter
+        let reversed = Polygon::new(
+            section.exterior().0.iter().rev().copied().collect(),
+            vec![section.interiors()[0].0.iter().rev().copied().collect()],
+        );
+        let moved = reversed.translate(1e6, -1e6);
+        let moved_moments = moved.second_moments().unwrap();
+        assert_relative_eq!(moved_moments.ixx, moments.ixx, max_relative = 1e-9);
+        assert_relative_eq!(moved_moments.area, moments.area);
+    }
+
+    #[test]
+    fn right_triangle() {
+        // Legs of b = 3 along x and h = 6 along y from the right angle
+        let triangle = Triangle::new((0., 0.).into(), (3., 0.).into(), (0., 6.).into());
+        let moments = triangle.second_moments().unwrap();
+        assert_relative_eq!(moments.area, 9.);
+        assert_relative_eq!(moments.ixx, 3. * 216. / 36.);
+        assert_relative_eq!(moments.iyy, 6. * 27. / 36.);
+        assert_relative_eq!(moments.ixy, -(9. * 36.) / 72.);
+    }
+
+    #[test]
+    fn rotation() {
+        // Rotating a section preserves its polar moment and matches the centroid and area
+        let polygon: Polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        let moments = polygon.second_moments().unwrap();
+        assert_relative_eq!(moments.area, polygon.unsigned_area());
+        assert_relative_eq!(moments.centroid, polygon.centroid().unwrap());
+        let rotated = polygon.rotate_around_centroid(37.);
+        let rotated_moments = rotated.second_moments().unwrap();
+        assert_relative_eq!(rotated_moments.polar(), moments.polar(), epsilon = 1e-9);
+        assert!((rotated_moments.ixx - moments.ixx).abs() > 0.1);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        // Two unit squares, with centroids 4 apart
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let multi_polygon = MultiPolygon::new(vec![square.clone(), square.translate(4., 0.)]);
+        let moments = multi_polygon.second_moments().unwrap();
+        assert_relative_eq!(moments.centroid, Point::new(2.5, 0.5));
+        assert_relative_eq!(moments.ixx, 2. / 12.);
+        assert_relative_eq!(moments.iyy, 2. / 12. + 2. * 4.);
+
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).second_moments(), None);
+        let flat = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(flat.second_moments(), None);
+    }
+}
@@ -0,0 +1,260 @@
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeNum};
+
+use crate::kernels::{Kernel, Orientation};
+use crate::{Coord, GeoFloat, Line, LineString, MultiLineString};
+
+/// Find where a `LineString` doubles back over itself, retracing a part of it which it has
+/// travelled before, such as the way back of a GPS trace of an out-and-back route.
+///
+/// Only collinear overlaps count, not places where the line merely crosses or touches itself.
+/// The first pass along each stretch is kept, and later passes over it are the overlaps, so
+/// that the length of what's left is the length of the ground the line covers.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, EuclideanLength, SelfOverlap};
+///
+/// // Out along a street, round a block, and back along the street
+/// let trace = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 5.),
+///     (x: 15., y: 5.),
+///     (x: 15., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 0., y: 0.),
+/// ];
+///
+/// let overlaps = trace.self_overlaps();
+/// assert_eq!(overlaps.0, vec![line_string![(x: 10., y: 0.), (x: 0., y: 0.)]]);
+///
+/// let covered = trace.remove_self_overlaps();
+/// assert_eq!(covered.0, vec![line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 5.),
+///     (x: 15., y: 5.),
+///     (x: 15., y: 0.),
+///     (x: 10., y: 0.),
+/// ]]);
+/// assert_eq!(covered.euclidean_length(), 30.);
+/// ```
+pub trait SelfOverlap<T: GeoFloat + RTreeNum> {
+    /// The runs of the line which retrace parts of it travelled before, in order along it.
+    fn self_overlaps(&self) -> MultiLineString<T>;
+
+    /// The line without the runs which retrace parts of it travelled before, split where they
+    /// were removed.
+    fn remove_self_overlaps(&self) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat + RTreeNum> SelfOverlap<T> for LineString<T> {
+    fn self_overlaps(&self) -> MultiLineString<T> {
+        let overlaps = overlapping_intervals(self);
+        runs(self, &overlaps)
+    }
+
+    fn remove_self_overlaps(&self) -> MultiLineString<T> {
+        let kept = overlapping_intervals(self)
+            .iter()
+            .map(|intervals| complement(intervals))
+            .collect::<Vec<_>>();
+        runs(self, &kept)
+    }
+}
+
+/// For each line of `line_string`, the parts of it, as sorted and disjoint intervals of the
+/// fraction of the way along it, which overlap earlier lines.
+fn overlapping_intervals<T: GeoFloat + RTreeNum>(line_string: &LineString<T>) -> Vec<Vec<(T, T)>> {
+    let lines = RTree::bulk_load(
+        line_string
+            .lines()
+            .enumerate()
+            .map(|(i, line)| GeomWithData::new(line, i))
+            .collect(),
+    );
+    let mut intervals = vec![Vec::new(); line_string.0.len().saturating_sub(1)];
+    for (earlier, later) in lines.intersection_candidates_with_other_tree(&lines) {
+        if earlier.data >= later.data {
+            continue;
+        }
+        if let Some(interval) = collinear_overlap(*later.geom(), *earlier.geom()) {
+            intervals[later.data].push(interval);
+        }
+    }
+    for line_intervals in &mut intervals {
+        line_intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(line_intervals.len());
+        for &(start, end) in line_intervals.iter() {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        *line_intervals = merged;
+    }
+    intervals
+}
+
+/// The part of `line` which `other` overlaps, as fractions of the way along `line`, if they're
+/// collinear and overlap by more than a point.
+fn collinear_overlap<T: GeoFloat>(line: Line<T>, other: Line<T>) -> Option<(T, T)> {
+    let collinear = |coord| T::Ker::orient2d(line.start, line.end, coord) == Orientation::Collinear;
+    if line.start == line.end || !collinear(other.start) || !collinear(other.end) {
+        return None;
+    }
+    let delta = line.delta();
+    let length_squared = delta.x * delta.x + delta.y * delta.y;
+    let fraction = |coord: Coord<T>| {
+        let offset = coord - line.start;
+        (offset.x * delta.x + offset.y * delta.y) / length_squared
+    };
+    let (a, b) = (fraction(other.start), fraction(other.end));
+    let start = a.min(b).max(T::zero());
+    let end = a.max(b).min(T::one());
+    (start < end).then_some((start, end))
+}
+
+/// The parts of a line which aren't in `intervals`.
+fn complement<T: GeoFloat>(intervals: &[(T, T)]) -> Vec<(T, T)> {
+    let mut complement = Vec::with_capacity(intervals.len() + 1);
+    let mut start = T::zero();
+    for &(overlap_start, overlap_end) in intervals {
+        if overlap_start > start {
+            complement.push((start, overlap_start));
+        }
+        start = overlap_end;
+    }
+    if start < T::one() {
+        complement.push((start, T::one()));
+    }
+    complement
+}
+
+/// Join the `intervals` of each line of `line_string` into runs, which continue from one line
+/// to the next when an interval reaches the end of one line and the next starts at its start.
+fn runs<T: GeoFloat>(line_string: &LineString<T>, intervals: &[Vec<(T, T)>]) -> MultiLineString<T> {
+    let mut runs = Vec::new();
+    let mut run: Vec<Coord<T>> = Vec::new();
+    for (line, line_intervals) in line_string.lines().zip(intervals) {
+        // Lines without length neither continue nor end runs
+        if line.start == line.end {
+            continue;
+        }
+        let at = |fraction: T| {
+            if fraction == T::one() {
+                line.end
+            } else {
+                line.start + line.delta() * fraction
+            }
+        };
+        for &(start, end) in line_intervals {
+            if start > T::zero() && run.len() > 1 {
+                runs.push(LineString::new(core::mem::take(&mut run)));
+            }
+            if run.is_empty() || start > T::zero() {
+                run = vec![at(start)];
+            }
+            run.push(at(end));
+            if end < T::one() {
+                runs.push(LineString::new(core::mem::take(&mut run)));
+            }
+        }
+        if line_intervals
+            .last()
+            .map(|&(_, end)| end < T::one())
+            .unwrap_or(true)
+        {
+            if run.len() > 1 {
+                runs.push(LineString::new(core::mem::take(&mut run)));
+            }
+            run.clear();
+        }
+    }
+    if run.len() > 1 {
+        runs.push(LineString::new(run));
+    }
+    MultiLineString::new(runs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, EuclideanLength};
+
+    #[test]
+    fn partial_overlaps() {
+        // Back over part of the first line, then out beyond its start
+        let line_string: LineString = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 3., y: 0.),
+            (x: 1., y: 0.),
+            (x: -2., y: 0.),
+        ];
+        assert_eq!(
+            line_string.self_overlaps().0,
+            vec![line_string![(x: 3., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)]]
+        );
+        let kept = line_string.remove_self_overlaps();
+        assert_eq!(
+            kept.0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 3., y: 0.)],
+                line_string![(x: 0., y: 0.), (x: -2., y: 0.)],
+            ]
+        );
+        assert_relative_eq!(
+            kept.euclidean_length() + line_string.self_overlaps().euclidean_length(),
+            line_string.euclidean_length()
+        );
+    }
+
+    #[test]
+    fn overlap_within_a_line() {
+        // The middle of the second line retraces the first, which is inside it
+        let line_string: LineString = line_string![
+            (x: 1., y: 1.),
+            (x: 2., y: 2.),
+            (x: 2., y: 2.),
+            (x: 0., y: 0.),
+            (x: 5., y: 5.),
+        ];
+        assert_eq!(
+            line_string.self_overlaps().0,
+            vec![
+                line_string![(x: 2., y: 2.), (x: 1., y: 1.)],
+                line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+            ]
+        );
+        assert_eq!(
+            line_string.remove_self_overlaps().0,
+            vec![
+                line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+                line_string![(x: 1., y: 1.), (x: 0., y: 0.)],
+                line_string![(x: 2., y: 2.), (x: 5., y: 5.)],
+            ]
+        );
+    }
+
+    #[test]
+    fn crossings_are_not_overlaps() {
+        let line_string: LineString = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        assert!(line_string.self_overlaps().0.is_empty());
+        assert_eq!(line_string.remove_self_overlaps().0, vec![line_string]);
+
+        let empty: LineString = line_string![];
+        assert!(empty.remove_self_overlaps().0.is_empty());
+    }
+}
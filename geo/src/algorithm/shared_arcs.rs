@@ -0,0 +1,313 @@
+use alloc::collections::BTreeMap;
+use alloc::{vec, vec::Vec};
+use core::iter;
+
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, LineString, Polygon};
+
+/// A set of polygons, decomposed into the arcs of their boundaries, so that a boundary shared by
+/// neighbouring polygons is stored once.
+///
+/// An arc is a part of a ring between two _nodes_, the vertices at which three or more boundary
+/// edges meet. A ring without any node, such as an island or a hole which isn't shared with
+/// anything else, is a single closed arc. Each ring is then a sequence of [`ArcRef`]s, and the
+/// rings on either side of a shared boundary refer to the same arc, in opposite directions.
+///
+/// Changing an arc, for example simplifying or smoothing it, and then rebuilding the polygons with
+/// [`SharedArcs::polygons`], changes every polygon bounded by it in the same way, so that no gaps
+/// or overlaps open up between neighbours.
+///
+/// Boundaries are only recognised as shared if they have the same vertices, as is the case for a
+/// polygonal coverage: polygons which were cut from one another, or snapped together.
+/// Consecutive repeated vertices are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, SharedArcs};
+///
+/// let left = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let right = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)];
+/// let arcs = SharedArcs::new(&[left, right]);
+///
+/// // The boundary between the squares, and the rest of each of their exteriors
+/// assert_eq!(arcs.arcs().len(), 3);
+/// let shared = arcs.rings(0)[0]
+///     .iter()
+///     .find(|arc| arcs.rings(1)[0].iter().any(|other| other.index == arc.index))
+///     .unwrap();
+/// assert_eq!(arcs.arcs()[shared.index].0.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedArcs<T: GeoFloat> {
+    arcs: Vec<LineString<T>>,
+    /// For each polygon, for each of its rings, exterior first, its arcs
+    rings: Vec<Vec<Vec<ArcRef>>>,
+}
+
+/// A reference from a ring of [`SharedArcs`] to one of its arcs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArcRef {
+    /// The index of the arc in [`SharedArcs::arcs`].
+    pub index: usize,
+    /// Whether the ring follows the arc from its end to its start.
+    pub reversed: bool,
+}
+
+impl<T: GeoFloat> SharedArcs<T> {
+    pub fn new(polygons: &[Polygon<T>]) -> Self {
+        let rings: Vec<Vec<Vec<Coord<T>>>> = polygons
+            .iter()
+            .map(|polygon| {
+                iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .map(ring_coords)
+                    .collect()
+            })
+            .collect();
+
+        let mut vertices: Vec<Coord<T>> = rings.iter().flatten().flatten().copied().collect();
+        vertices.sort_unstable_by(lex_cmp);
+        vertices.dedup();
+        let id = |coord: &Coord<T>| {
+            vertices
+                .binary_search_by(|vertex| lex_cmp(vertex, coord))
+                .unwrap()
+        };
+        let rings: Vec<Vec<Vec<usize>>> = rings
+            .iter()
+            .map(|polygon| {
+                polygon
+                    .iter()
+                    .map(|ring| ring.iter().map(id).collect())
+                    .collect()
+            })
+            .collect();
+
+        // A vertex is a node unless exactly two distinct edges meet at it
+        let mut edges: Vec<(usize, usize)> = rings
+            .iter()
+            .flatten()
+            .flat_map(|ring| ring.windows(2))
+            .map(|edge| (edge[0].min(edge[1]), edge[0].max(edge[1])))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        let mut degrees = vec![0_usize; vertices.len()];
+        for (a, b) in edges {
+            degrees[a] += 1;
+            degrees[b] += 1;
+        }
+        let is_node = |vertex: usize| degrees[vertex] != 2;
+
+        let mut arcs = Vec::new();
+        let mut arc_indices: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+        let mut arc_ref = |ids: Vec<usize>| {
+            let mut reversed_ids = ids.clone();
+            reversed_ids.reverse();
+            let reversed = reversed_ids < ids;
+            let key = if reversed { reversed_ids } else { ids };
+            let index = *arc_indices.entry(key).or_insert_with_key(|key| {
+                arcs.push(key.iter().map(|&id| vertices[id]).collect());
+                arcs.len() - 1
+            });
+            ArcRef { index, reversed }
+        };
+
+        let rings = rings
+            .into_iter()
+            .map(|polygon| {
+                polygon
+                    .into_iter()
+                    .map(|ring| {
+                        // Without its closing vertex
+                        let open = match ring.split_last() {
+                            Some((_, open)) if !open.is_empty() => open,
+                            _ => return vec![],
+                        };
+                        match open.iter().position(|&id| is_node(id)) {
+                            None => {
+                                // A single closed arc, which starts at its lowest vertex so that
+                                // it's found again from other rings
+                                let start = (0..open.len()).min_by_key(|&i| open[i]).unwrap();
+                                let ids = open[start..]
+                                    .iter()
+                                    .chain(&open[..=start])
+                                    .copied()
+                                    .collect();
+                                vec![arc_ref(ids)]
+                            }
+                            Some(start) => {
+                                let mut refs = vec![];
+                                let mut ids = vec![open[start]];
+                                for &id in open[start + 1..].iter().chain(&open[..=start]) {
+                                    ids.push(id);
+                                    if is_node(id) {
+                                        refs.push(arc_ref(core::mem::replace(&mut ids, vec![id])));
+                                    }
+                                }
+                                refs
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        SharedArcs { arcs, rings }
+    }
+
+    /// The arcs of the boundaries of the polygons.
+    pub fn arcs(&self) -> &[LineString<T>] {
+        &self.arcs
+    }
+
+    /// The number of polygons.
+    pub fn len(&self) -> usize {
+        self.rings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rings.is_empty()
+    }
+
+    /// The arcs of each ring of the polygon at `index`, exterior first.
+    ///
+    /// An empty ring has no arcs.
+    pub fn rings(&self, index: usize) -> &[Vec<ArcRef>] {
+        &self.rings[index]
+    }
+
+    /// Build the polygons from `arcs`, in place of [`SharedArcs::arcs`].
+    ///
+    /// Each arc must start and end at the same coordinates as the arc it replaces.
+    ///
+    /// # Panics
+    ///
+    /// If the number of `arcs` doesn't match the number of arcs of these polygons.
+    pub fn polygons(&self, arcs: &[LineString<T>]) -> Vec<Polygon<T>> {
+        assert_eq!(arcs.len(), self.arcs.len(), "wrong number of arcs");
+        self.rings
+            .iter()
+            .map(|rings| {
+                let mut rings = rings.iter().map(|refs| ring_from_arcs(arcs, refs));
+                let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+                Polygon::new(exterior, rings.collect())
+            })
+            .collect()
+    }
+}
+
+/// The coordinates of `ring`, closed, without consecutive repeated coordinates.
+fn ring_coords<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coord<T>> {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    if coords.len() > 1 && coords[0] == coords[coords.len() - 1] {
+        coords.pop();
+    }
+    if let Some(&first) = coords.first() {
+        coords.push(first);
+    }
+    coords
+}
+
+pub(crate) fn ring_from_arcs<T: GeoFloat>(
+    arcs: &[LineString<T>],
+    refs: &[ArcRef],
+) -> LineString<T> {
+    let mut coords: Vec<Coord<T>> = vec![];
+    for arc_ref in refs {
+        let arc = &arcs[arc_ref.index].0;
+        // Each arc starts where the last one ended
+        let skip = usize::from(!coords.is_empty());
+        if arc_ref.reversed {
+            coords.extend(arc.iter().rev().skip(skip));
+        } else {
+            coords.extend(arc.iter().skip(skip));
+        }
+    }
+    LineString::new(coords)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Relate};
+
+    fn assert_round_trip(polygons: &[Polygon]) {
+        let arcs = SharedArcs::new(polygons);
+        let rebuilt = arcs.polygons(arcs.arcs());
+        assert_eq!(rebuilt.len(), polygons.len());
+        for (polygon, rebuilt) in polygons.iter().zip(&rebuilt) {
+            assert!(polygon.relate(rebuilt).is_equal_topo());
+            assert_eq!(polygon.signed_area(), rebuilt.signed_area());
+        }
+    }
+
+    #[test]
+    fn adjacent_squares() {
+        let squares: Vec<Polygon> = (0..3)
+            .flat_map(|x| (0..2).map(move |y| (x as f64, y as f64)))
+            .map(|(x, y)| {
+                polygon![(x: x, y: y), (x: x + 1., y: y), (x: x + 1., y: y + 1.), (x: x, y: y + 1.)]
+            })
+            .collect();
+        let arcs = SharedArcs::new(&squares);
+        assert_eq!(arcs.len(), 6);
+        // The 7 edges inside the grid, and the 6 arcs of its boundary between the nodes on it
+        assert_eq!(arcs.arcs().len(), 13);
+        assert_round_trip(&squares);
+
+        // Each inner edge is used once in each direction
+        let mut uses = vec![vec![]; arcs.arcs().len()];
+        for polygon in 0..arcs.len() {
+            for arc_ref in &arcs.rings(polygon)[0] {
+                uses[arc_ref.index].push(arc_ref.reversed);
+            }
+        }
+        assert_eq!(uses.iter().filter(|uses| uses.len() == 2).count(), 7);
+        assert!(uses
+            .iter()
+            .all(|uses| uses.len() == 1 || uses[0] != uses[1]));
+    }
+
+    #[test]
+    fn island_in_hole() {
+        let with_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+        ];
+        // The island starts at another vertex, and is wound the other way
+        let island = polygon![(x: 8., y: 8.), (x: 2., y: 8.), (x: 2., y: 2.), (x: 8., y: 2.)];
+        let polygons = [with_hole, island];
+        let arcs = SharedArcs::new(&polygons);
+        assert_eq!(arcs.arcs().len(), 2);
+        assert_eq!(arcs.rings(0)[1][0].index, arcs.rings(1)[0][0].index);
+        assert_ne!(arcs.rings(0)[1][0].reversed, arcs.rings(1)[0][0].reversed);
+        assert_round_trip(&polygons);
+    }
+
+    #[test]
+    fn touching_at_a_vertex() {
+        let polygons = [
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)],
+            polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)],
+        ];
+        let arcs = SharedArcs::new(&polygons);
+        assert_eq!(arcs.arcs().len(), 2);
+        assert_round_trip(&polygons);
+    }
+
+    #[test]
+    fn degenerate_and_repeated() {
+        let polygons = [
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            Polygon::new(LineString::new(vec![]), vec![]),
+        ];
+        let arcs = SharedArcs::new(&polygons);
+        assert_eq!(arcs.rings(1)[0], vec![]);
+        let rebuilt = arcs.polygons(arcs.arcs());
+        assert_eq!(rebuilt[0].exterior().0.len(), 4);
+        assert_eq!(rebuilt[1], polygons[1]);
+    }
+}
@@ -1,8 +1,10 @@
+use crate::error::check_finite;
 use crate::{Coord, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
-use crate::{CoordsIter, EuclideanDistance};
+use crate::{CoordsIter, Error, EuclideanDistance, SimplifyRadial};
+use alloc::{vec, vec::Vec};
 
-const LINE_STRING_INITIAL_MIN: usize = 2;
-const POLYGON_INITIAL_MIN: usize = 4;
+pub(crate) const LINE_STRING_INITIAL_MIN: usize = 2;
+pub(crate) const POLYGON_INITIAL_MIN: usize = 4;
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
 // instead, we wrap a simple struct around index and point in a wrapper function,
@@ -16,11 +18,28 @@ where
     coord: Coord<T>,
 }
 
+fn planar_distance<T: GeoFloat>(coord: Coord<T>, line: Line<T>) -> T {
+    coord.euclidean_distance(&line)
+}
+
 // Wrapper for the RDP algorithm, returning simplified points
 fn rdp<T, I: Iterator<Item = Coord<T>>, const INITIAL_MIN: usize>(
     coords: I,
     epsilon: &T,
 ) -> Vec<Coord<T>>
+where
+    T: GeoFloat,
+{
+    rdp_with::<_, _, INITIAL_MIN>(coords, epsilon, &planar_distance)
+}
+
+// Wrapper for the RDP algorithm, returning simplified points, measuring the distance of points
+// from segments with `distance`
+pub(crate) fn rdp_with<T, I: Iterator<Item = Coord<T>>, const INITIAL_MIN: usize>(
+    coords: I,
+    epsilon: &T,
+    distance: &impl Fn(Coord<T>, Line<T>) -> T,
+) -> Vec<Coord<T>>
 where
     T: GeoFloat,
 {
@@ -34,7 +53,7 @@ where
         .collect::<Vec<RdpIndex<T>>>();
     let mut simplified_len = rdp_indices.len();
     let simplified_coords: Vec<_> =
-        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon)
+        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon, distance)
             .into_iter()
             .map(|rdpindex| rdpindex.coord)
             .collect();
@@ -59,7 +78,7 @@ where
 
     let mut simplified_len = rdp_indices.len();
     let simplified_coords =
-        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon)
+        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon, &planar_distance)
             .into_iter()
             .map(|rdpindex| rdpindex.index)
             .collect::<Vec<usize>>();
@@ -74,6 +93,7 @@ fn compute_rdp<T, const INITIAL_MIN: usize>(
     rdp_indices: &[RdpIndex<T>],
     simplified_len: &mut usize,
     epsilon: &T,
+    distance: &impl Fn(Coord<T>, Line<T>) -> T,
 ) -> Vec<RdpIndex<T>>
 where
     T: GeoFloat,
@@ -82,11 +102,11 @@ where
         return vec![];
     }
 
+    if rdp_indices.len() <= 2 {
+        return rdp_indices.to_vec();
+    }
     let first = rdp_indices[0];
     let last = rdp_indices[rdp_indices.len() - 1];
-    if rdp_indices.len() == 2 {
-        return vec![first, last];
-    }
 
     let first_last_line = Line::new(first.coord, last.coord);
 
@@ -96,7 +116,7 @@ where
         .enumerate()
         .take(rdp_indices.len() - 1) // Don't include the last index
         .skip(1) // Don't include the first index
-        .map(|(index, rdp_index)| (index, rdp_index.coord.euclidean_distance(&first_last_line)))
+        .map(|(index, rdp_index)| (index, distance(rdp_index.coord, first_last_line)))
         .fold(
             (0usize, T::zero()),
             |(farthest_index, farthest_distance), (index, distance)| {
@@ -112,8 +132,12 @@ where
     if farthest_distance > *epsilon {
         // The farthest index was larger than epsilon, so we will recursively simplify subsegments
         // split by the farthest index.
-        let mut intermediate =
-            compute_rdp::<T, INITIAL_MIN>(&rdp_indices[..=farthest_index], simplified_len, epsilon);
+        let mut intermediate = compute_rdp::<T, INITIAL_MIN>(
+            &rdp_indices[..=farthest_index],
+            simplified_len,
+            epsilon,
+            distance,
+        );
 
         intermediate.pop(); // Don't include the farthest index twice
 
@@ -121,6 +145,7 @@ where
             &rdp_indices[farthest_index..],
             simplified_len,
             epsilon,
+            distance,
         ));
         return intermediate;
     }
@@ -136,7 +161,7 @@ where
     // If `simplified_len` is now lower than the minimum number of indices needed, then don't
     // perform the culling and return the original input.
     if new_length < INITIAL_MIN {
-        return rdp_indices.to_owned();
+        return rdp_indices.to_vec();
     }
     *simplified_len = new_length;
 
@@ -191,6 +216,73 @@ pub trait Simplify<T, Epsilon = T> {
     fn simplify(&self, epsilon: &T) -> Self
     where
         T: GeoFloat;
+
+    /// The same as [`simplify`](Self::simplify), but returns an error rather than panicking
+    /// on a coordinate which isn't finite, or an `epsilon` which is NaN or negative. An
+    /// infinite `epsilon` is accepted, and removes every vertex which can be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, Error, Simplify};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)];
+    /// assert_eq!(
+    ///     line_string.try_simplify(&1.).unwrap(),
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)]
+    /// );
+    /// assert_eq!(
+    ///     line_string.try_simplify(&f64::INFINITY).unwrap(),
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)]
+    /// );
+    /// assert!(matches!(
+    ///     line_string.try_simplify(&f64::NAN),
+    ///     Err(Error::InvalidParameter("epsilon"))
+    /// ));
+    /// ```
+    fn try_simplify(&self, epsilon: &T) -> Result<Self, Error>
+    where
+        T: GeoFloat,
+        Self: CoordsIter<Scalar = T> + Sized,
+    {
+        if epsilon.is_nan() || *epsilon < T::zero() {
+            return Err(Error::InvalidParameter("epsilon"));
+        }
+        check_finite(self)?;
+        Ok(self.simplify(epsilon))
+    }
+
+    /// The same as [`simplify`](Self::simplify), after first dropping the points closer than
+    /// `radial_tolerance` to the point before them with
+    /// [`simplify_radial`](crate::SimplifyRadial::simplify_radial), which is much faster for
+    /// densely sampled geometries.
+    ///
+    /// A `radial_tolerance` which is a fraction of `epsilon` doesn't visibly change the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Simplify, SimplifyRadial};
+    ///
+    /// // A trace with a point every 10 centimetres along two sides of a square kilometre
+    /// let trace: LineString = (0..=20000)
+    ///     .map(|i| (f64::from(i.min(10000)) / 10., f64::from(i.max(10000) - 10000) / 10.))
+    ///     .collect();
+    ///
+    /// // Half a metre apart, only one point in five is left to simplify
+    /// assert_eq!(trace.simplify_radial(&0.5).0.len(), 4001);
+    ///
+    /// let simplified = trace.simplify_prefiltered(&0.5, &1.);
+    /// assert_eq!(simplified, trace.simplify(&1.));
+    /// assert_eq!(simplified.0.len(), 3);
+    /// ```
+    fn simplify_prefiltered(&self, radial_tolerance: &T, epsilon: &T) -> Self
+    where
+        T: GeoFloat,
+        Self: SimplifyRadial<T> + Sized,
+    {
+        self.simplify_radial(radial_tolerance).simplify(epsilon)
+    }
 }
 
 /// Simplifies a geometry, returning the retained _indices_ of the input.
@@ -509,4 +601,24 @@ mod test {
         ];
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn single_coordinate() {
+        let line_string = line_string![(x: 1.0, y: 2.0)];
+        assert_eq!(line_string.simplify(&1.0), line_string);
+
+        let nan = line_string![(x: 0.0, y: 0.0), (x: f64::NAN, y: 1.0), (x: 2.0, y: 0.0)];
+        assert!(matches!(
+            nan.try_simplify(&1.0),
+            Err(Error::NonFiniteCoordinate)
+        ));
+        assert_eq!(
+            line_string.try_simplify(&f64::INFINITY).unwrap(),
+            line_string
+        );
+        assert!(matches!(
+            line_string.try_simplify(&-1.0),
+            Err(Error::InvalidParameter("epsilon"))
+        ));
+    }
 }
@@ -0,0 +1,325 @@
+use alloc::collections::BTreeSet;
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeNum, AABB};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{
+    BoundingRect, EuclideanDistance, GeoFloat, Line, LineString, MultiPolygon, Point, Polygon,
+    SharedArcs, SimplifyIdx,
+};
+
+/// Simplifies a set of adjacent polygons, such as administrative boundaries, so that the
+/// boundaries they share are simplified identically.
+///
+/// Simplifying neighbouring polygons one by one, with [`Simplify`](crate::Simplify), simplifies
+/// each side of a shared boundary differently, opening up gaps and overlaps between them. Here the
+/// polygons are decomposed into the [`SharedArcs`] of their boundaries, and each arc is
+/// simplified once, with the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), keeping the nodes
+/// at which boundaries meet.
+///
+/// The topology of the polygons is preserved: vertices are restored to a simplified arc where
+/// it would otherwise cross another arc, or itself, or pass over any vertex of another arc, so
+/// that small islands and holes stay on their side of the boundaries near them. Rings keep at
+/// least three distinct vertices.
+///
+/// Boundaries are only recognised as shared if they have the same vertices, which is the case
+/// for polygons cut from one another or snapped together.
+///
+/// An `epsilon` less than or equal to zero will return an unaltered version of the polygons.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, SimplifyCoverage};
+///
+/// // Two parcels either side of a meandering boundary
+/// let west = polygon![
+///     (x: 0., y: 0.), (x: 5., y: 0.), (x: 5.1, y: 2.), (x: 4.9, y: 4.), (x: 5.1, y: 6.),
+///     (x: 4.9, y: 8.), (x: 5., y: 10.), (x: 0., y: 10.),
+/// ];
+/// let east = polygon![
+///     (x: 10., y: 10.), (x: 5., y: 10.), (x: 4.9, y: 8.), (x: 5.1, y: 6.), (x: 4.9, y: 4.),
+///     (x: 5.1, y: 2.), (x: 5., y: 0.), (x: 10., y: 0.),
+/// ];
+///
+/// let simplified = [west, east].simplify_coverage(&0.5);
+/// assert_eq!(simplified[0].exterior().0.len(), 5);
+/// assert_relative_eq!(
+///     simplified[0].unsigned_area() + simplified[1].unsigned_area(),
+///     100.
+/// );
+/// ```
+pub trait SimplifyCoverage<T, Epsilon = T> {
+    type Output;
+
+    fn simplify_coverage(&self, epsilon: &T) -> Self::Output;
+}
+
+impl<T> SimplifyCoverage<T> for [Polygon<T>]
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Output = Vec<Polygon<T>>;
+
+    fn simplify_coverage(&self, epsilon: &T) -> Self::Output {
+        if *epsilon <= T::zero() {
+            return self.to_vec();
+        }
+        let shared = SharedArcs::new(self);
+        let arcs = simplify_arcs(&shared, epsilon);
+        shared.polygons(&arcs)
+    }
+}
+
+impl<T> SimplifyCoverage<T> for MultiPolygon<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Output = MultiPolygon<T>;
+
+    fn simplify_coverage(&self, epsilon: &T) -> Self::Output {
+        MultiPolygon::new(self.0.simplify_coverage(epsilon))
+    }
+}
+
+/// Simplify each arc of `shared`, then restore vertices until the simplified arcs have the
+/// topology of the originals.
+fn simplify_arcs<T>(shared: &SharedArcs<T>, epsilon: &T) -> Vec<LineString<T>>
+where
+    T: GeoFloat + RTreeNum,
+{
+    let arcs = shared.arcs();
+    // The indices of the vertices kept of each arc
+    let mut kept: Vec<Vec<usize>> = arcs.iter().map(|arc| arc.simplify_idx(epsilon)).collect();
+
+    let vertices = RTree::bulk_load(
+        arcs.iter()
+            .enumerate()
+            .flat_map(|(a, arc)| {
+                arc.0
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, &coord)| GeomWithData::new(Point::from(coord), (a, i)))
+            })
+            .collect(),
+    );
+
+    loop {
+        // Segments to split, as the arc and the position of their start in `kept`
+        let mut split: BTreeSet<(usize, usize)> = BTreeSet::new();
+
+        // Rings which collapsed below three distinct vertices
+        for polygon in 0..shared.len() {
+            for refs in shared.rings(polygon) {
+                let count = |arc: &[usize]| arc.len() - 1;
+                let simplified: usize = refs.iter().map(|r| count(&kept[r.index])).sum();
+                let original: usize = refs.iter().map(|r| arcs[r.index].0.len() - 1).sum();
+                if simplified >= original.min(3) {
+                    continue;
+                }
+                let widest = refs
+                    .iter()
+                    .flat_map(|r| {
+                        let arc = &kept[r.index];
+                        (0..arc.len() - 1).map(move |s| (arc[s + 1] - arc[s], (r.index, s)))
+                    })
+                    .max_by_key(|&(span, _)| span);
+                if let Some((span, segment)) = widest {
+                    if span > 1 {
+                        split.insert(segment);
+                    }
+                }
+            }
+        }
+
+        // Segments which sweep over the vertices of other arcs
+        for (a, arc) in kept.iter().enumerate() {
+            let coords = &arcs[a].0;
+            for s in 0..arc.len() - 1 {
+                let (i, j) = (arc[s], arc[s + 1]);
+                if j == i + 1 {
+                    continue;
+                }
+                let swept = Polygon::new(coords[i..=j].iter().copied().collect(), vec![]);
+                let rect = swept.bounding_rect().unwrap();
+                let envelope = AABB::from_corners(rect.min().into(), rect.max().into());
+                let sweeps_over =
+                    vertices
+                        .locate_in_envelope_intersecting(&envelope)
+                        .any(|vertex| {
+                            let (b, k) = vertex.data;
+                            let coord = vertex.geom().0;
+                            !(b == a && (i..=j).contains(&k))
+                                && coord != coords[i]
+                                && coord != coords[j]
+                                && swept.coordinate_position(&coord) != CoordPos::Outside
+                        });
+                if sweeps_over {
+                    split.insert((a, s));
+                }
+            }
+        }
+
+        // Segments which cross one another
+        let segments = RTree::bulk_load(
+            kept.iter()
+                .enumerate()
+                .flat_map(|(a, arc)| {
+                    let coords = &arcs[a].0;
+                    arc.windows(2).enumerate().map(move |(s, w)| {
+                        GeomWithData::new(Line::new(coords[w[0]], coords[w[1]]), (a, s))
+                    })
+                })
+                .collect(),
+        );
+        for (x, y) in segments.intersection_candidates_with_other_tree(&segments) {
+            if x.data >= y.data || !crosses(*x.geom(), *y.geom()) {
+                continue;
+            }
+            for (a, s) in [x.data, y.data] {
+                if kept[a][s + 1] > kept[a][s] + 1 {
+                    split.insert((a, s));
+                }
+            }
+        }
+
+        if split.is_empty() {
+            break;
+        }
+        // From the last segment of each arc, so that the positions of the others don't change
+        for &(a, s) in split.iter().rev() {
+            let (i, j) = (kept[a][s], kept[a][s + 1]);
+            let coords = &arcs[a].0;
+            let segment = Line::new(coords[i], coords[j]);
+            let farthest = (i + 1..j)
+                .max_by(|&k, &l| {
+                    let k = coords[k].euclidean_distance(&segment);
+                    let l = coords[l].euclidean_distance(&segment);
+                    k.partial_cmp(&l).unwrap_or(core::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            kept[a].insert(s + 1, farthest);
+        }
+    }
+
+    kept.iter()
+        .zip(arcs)
+        .map(|(kept, arc)| kept.iter().map(|&i| arc.0[i]).collect())
+        .collect()
+}
+
+/// Whether the segments intersect other than at an endpoint of both.
+fn crosses<T: GeoFloat>(a: Line<T>, b: Line<T>) -> bool {
+    match line_intersection(a, b) {
+        None => false,
+        Some(LineIntersection::SinglePoint {
+            intersection,
+            is_proper,
+        }) => {
+            is_proper
+                || !([a.start, a.end].contains(&intersection)
+                    && [b.start, b.end].contains(&intersection))
+        }
+        Some(LineIntersection::Collinear { .. }) => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, BooleanOps, Coord, Simplify};
+
+    /// Two polygons either side of a boundary along `x = 5`, which wiggles by `amplitude`.
+    fn neighbours(amplitude: f64) -> Vec<Polygon> {
+        let boundary: Vec<_> = (0..=20)
+            .map(|i| {
+                let x = if i % 20 == 0 {
+                    5.
+                } else {
+                    5. + amplitude * if i % 2 == 0 { 1. } else { -1. }
+                };
+                (x, i as f64 / 2.)
+            })
+            .collect();
+        let mut west = vec![(0., 0.)];
+        west.extend(&boundary);
+        west.push((0., 10.));
+        let mut east: Vec<_> = boundary.iter().rev().copied().collect();
+        east.extend([(10., 0.), (10., 10.)]);
+        vec![
+            Polygon::new(west.into(), vec![]),
+            Polygon::new(east.into(), vec![]),
+        ]
+    }
+
+    fn overlap(a: &Polygon, b: &Polygon) -> f64 {
+        a.intersection(b).unsigned_area()
+    }
+
+    #[test]
+    fn shared_boundary() {
+        let polygons = neighbours(0.3);
+        let simplified = polygons.simplify_coverage(&0.5);
+        assert_eq!(simplified[0].exterior().0.len(), 5);
+        assert_eq!(simplified[1].exterior().0.len(), 5);
+        let area: f64 = simplified.iter().map(|p| p.unsigned_area()).sum();
+        assert_relative_eq!(area, 100.);
+        assert_relative_eq!(overlap(&simplified[0], &simplified[1]), 0.);
+
+        // Independently, the boundary is simplified differently on each side
+        let independent: Vec<_> = polygons.iter().map(|p| p.simplify(&0.5)).collect();
+        let area: f64 = independent.iter().map(|p| p.unsigned_area()).sum();
+        assert!((area - 100.).abs() > 0.5);
+    }
+
+    #[test]
+    fn keeps_islands_on_their_side() {
+        // A square whose top edge has a notch, with a small island in the notch
+        let notched = polygon![
+            (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 6., y: 10.), (x: 5., y: 9.),
+            (x: 4., y: 10.), (x: 0., y: 10.),
+        ];
+        let island =
+            polygon![(x: 4.9, y: 9.8), (x: 5.1, y: 9.8), (x: 5.1, y: 9.9), (x: 4.9, y: 9.9)];
+        assert!(overlap(&notched.simplify(&2.), &island) > 0.);
+
+        let simplified = [notched, island.clone()].simplify_coverage(&2.);
+        assert_relative_eq!(overlap(&simplified[0], &simplified[1]), 0.);
+        assert!(simplified[0].exterior().0.contains(&Coord { x: 5., y: 9. }));
+        // The island isn't simplified away
+        assert_eq!(simplified[1], island);
+    }
+
+    fn self_crosses(ring: &LineString) -> bool {
+        let lines: Vec<_> = ring.lines().collect();
+        (0..lines.len()).any(|i| (i + 1..lines.len()).any(|j| crosses(lines[i], lines[j])))
+    }
+
+    #[test]
+    fn no_crossings() {
+        let polygon = polygon![
+            (x: 5.3, y: 2.4), (x: 1.2, y: 1.2), (x: -2.0, y: 8.0), (x: -1.1, y: 1.3),
+            (x: -8.8, y: -1.0), (x: -2.1, y: -1.6), (x: -0.5, y: -1.2), (x: 0.4, y: -1.3),
+            (x: 3.3, y: -0.9),
+        ];
+        assert!(self_crosses(polygon.simplify(&3.).exterior()));
+        let simplified = [polygon.clone()].simplify_coverage(&3.);
+        assert!(!self_crosses(simplified[0].exterior()));
+        assert!(simplified[0].exterior().0.len() < polygon.exterior().0.len());
+    }
+
+    #[test]
+    fn no_tolerance() {
+        let polygons = MultiPolygon::new(neighbours(0.1));
+        assert_eq!(polygons.simplify_coverage(&0.), polygons);
+        assert_eq!(
+            polygons.simplify_coverage(&0.01).0[0].exterior().0.len(),
+            polygons.0[0].exterior().0.len()
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::simplify::{rdp_with, LINE_STRING_INITIAL_MIN, POLYGON_INITIAL_MIN};
+use crate::simplify_vw::visvalingam_with;
+use crate::{
+    Coord, GeodesicArea, GeodesicCrossTrack, GeodesicDistance, Line, LineString, MultiLineString,
+    MultiPolygon, Point, Polygon, Triangle,
+};
+
+/// Simplifies a geometry of longitude/latitude coordinates with the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), measuring
+/// distances on the WGS84 ellipsoid.
+///
+/// This is [`Simplify`](crate::Simplify), but points are discarded if they are closer than
+/// `epsilon` _meters_ from the geodesic segments of the simplified output. It's more accurate, and
+/// slower, than [`SimplifyHaversine`](crate::SimplifyHaversine): distances are measured with
+/// [`GeodesicCrossTrack`](crate::GeodesicCrossTrack).
+///
+/// An `epsilon` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Units
+///
+/// - `epsilon`: meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SimplifyGeodesic};
+///
+/// // Offsets of about 30 meters, and 300 meters, to the east of a meridian, at latitude 60°
+/// let line_string = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.00054, y: 60.1),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// let simplified = line_string.simplify_geodesic(&100.0);
+///
+/// let expected = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// assert_eq!(expected, simplified);
+/// ```
+pub trait SimplifyGeodesic<T, Epsilon = T> {
+    fn simplify_geodesic(&self, epsilon: &T) -> Self;
+}
+
+/// Simplifies a geometry of longitude/latitude coordinates with the
+/// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263)
+/// algorithm, measuring areas on the WGS84 ellipsoid.
+///
+/// This is [`SimplifyVw`](crate::SimplifyVw), but a point is removed if the triangle it forms
+/// with its neighbours has an area of less than `epsilon` _square meters_. Areas are measured with
+/// [`GeodesicArea`](crate::GeodesicArea).
+///
+/// An `epsilon` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Units
+///
+/// - `epsilon`: square meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SimplifyVwGeodesic};
+///
+/// let line_string = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.00054, y: 60.1),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// // The triangle at the first offset has an area of about 330,000 m²
+/// let simplified = line_string.simplify_vw_geodesic(&1e6);
+///
+/// let expected = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// assert_eq!(expected, simplified);
+/// ```
+pub trait SimplifyVwGeodesic<T, Epsilon = T> {
+    fn simplify_vw_geodesic(&self, epsilon: &T) -> Self;
+}
+
+/// The geodesic distance from `coord` to the closest point of the geodesic segment `line`.
+fn geodesic_segment_distance(coord: Coord, line: Line) -> f64 {
+    let (p, a, b) = (Point::from(coord), line.start_point(), line.end_point());
+    let length = a.geodesic_distance(&b);
+    if length == 0. {
+        return p.geodesic_distance(&a);
+    }
+    let along_track = p.geodesic_along_track_distance(&a, &b);
+    if along_track <= 0. {
+        p.geodesic_distance(&a)
+    } else if along_track >= length {
+        p.geodesic_distance(&b)
+    } else {
+        p.geodesic_cross_track_distance(&a, &b)
+    }
+}
+
+fn geodesic_area(triangle: Triangle) -> f64 {
+    // The unsigned area of a clockwise triangle is that of the rest of the earth
+    triangle.to_polygon().geodesic_area_signed().abs()
+}
+
+impl SimplifyGeodesic<f64> for LineString {
+    fn simplify_geodesic(&self, epsilon: &f64) -> Self {
+        LineString::from(rdp_with::<_, _, LINE_STRING_INITIAL_MIN>(
+            self.0.iter().copied(),
+            epsilon,
+            &geodesic_segment_distance,
+        ))
+    }
+}
+
+impl SimplifyGeodesic<f64> for MultiLineString {
+    fn simplify_geodesic(&self, epsilon: &f64) -> Self {
+        MultiLineString::new(self.iter().map(|l| l.simplify_geodesic(epsilon)).collect())
+    }
+}
+
+impl SimplifyGeodesic<f64> for Polygon {
+    fn simplify_geodesic(&self, epsilon: &f64) -> Self {
+        let simplify = |ring: &LineString| {
+            LineString::from(rdp_with::<_, _, POLYGON_INITIAL_MIN>(
+                ring.0.iter().copied(),
+                epsilon,
+                &geodesic_segment_distance,
+            ))
+        };
+        Polygon::new(
+            simplify(self.exterior()),
+            self.interiors().iter().map(simplify).collect(),
+        )
+    }
+}
+
+impl SimplifyGeodesic<f64> for MultiPolygon {
+    fn simplify_geodesic(&self, epsilon: &f64) -> Self {
+        MultiPolygon::new(self.iter().map(|p| p.simplify_geodesic(epsilon)).collect())
+    }
+}
+
+impl SimplifyVwGeodesic<f64> for LineString {
+    fn simplify_vw_geodesic(&self, epsilon: &f64) -> Self {
+        LineString::from(visvalingam_with(self, epsilon, &geodesic_area))
+    }
+}
+
+impl SimplifyVwGeodesic<f64> for MultiLineString {
+    fn simplify_vw_geodesic(&self, epsilon: &f64) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|l| l.simplify_vw_geodesic(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl SimplifyVwGeodesic<f64> for Polygon {
+    fn simplify_vw_geodesic(&self, epsilon: &f64) -> Self {
+        Polygon::new(
+            self.exterior().simplify_vw_geodesic(epsilon),
+            self.interiors()
+                .iter()
+                .map(|l| l.simplify_vw_geodesic(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl SimplifyVwGeodesic<f64> for MultiPolygon {
+    fn simplify_vw_geodesic(&self, epsilon: &f64) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_vw_geodesic(epsilon))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, SimplifyHaversine, SimplifyVwHaversine};
+
+    #[test]
+    fn matches_haversine() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        // The ellipsoid and the sphere differ by less than a percent, so only the points whose
+        // distance is very close to the tolerance are treated differently
+        for epsilon in [100., 1000.] {
+            let geodesic = line_string.simplify_geodesic(&epsilon).0.len();
+            let haversine = line_string.simplify_haversine(&epsilon).0.len();
+            assert!(geodesic.abs_diff(haversine) <= haversine / 50);
+        }
+        let geodesic = line_string.simplify_vw_geodesic(&1e6).0.len();
+        let haversine = line_string.simplify_vw_haversine(&1e6).0.len();
+        assert!(geodesic.abs_diff(haversine) <= haversine / 50);
+        assert!(geodesic < line_string.0.len() / 2);
+    }
+
+    #[test]
+    fn beyond_segment_ends() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.0001), (x: 1., y: 0.)];
+        assert_eq!(line_string.simplify_geodesic(&1000.), line_string);
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon![
+            (x: 0., y: 60.),
+            (x: 1., y: 60.),
+            (x: 1.00001, y: 60.5),
+            (x: 1., y: 61.),
+            (x: 0., y: 61.),
+        ];
+        let expected = polygon![(x: 0., y: 60.), (x: 1., y: 60.), (x: 1., y: 61.), (x: 0., y: 61.)];
+        assert_eq!(polygon.simplify_geodesic(&10.), expected);
+        assert_eq!(polygon.simplify_geodesic(&0.1), polygon);
+        assert_eq!(polygon.simplify_vw_geodesic(&1e5), expected);
+        assert_eq!(polygon.simplify_vw_geodesic(&1.), polygon);
+    }
+}
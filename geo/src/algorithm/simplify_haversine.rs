@@ -0,0 +1,330 @@
+use num_traits::FromPrimitive;
+
+use crate::simplify::{rdp_with, LINE_STRING_INITIAL_MIN, POLYGON_INITIAL_MIN};
+use crate::simplify_vw::visvalingam_with;
+use crate::{
+    AlongTrackDistance, ChamberlainDuquetteArea, Coord, CrossTrackDistance, GeoFloat,
+    HaversineDistance, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Triangle,
+};
+
+/// Simplifies a geometry of longitude/latitude coordinates with the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), measuring
+/// distances on a sphere.
+///
+/// This is [`Simplify`](crate::Simplify), but points are discarded if they are closer than
+/// `epsilon` _meters_ from the great circle segments of the simplified output, rather than
+/// `epsilon` degrees, whose length on the ground varies with latitude. Distances are measured with
+/// the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula), using a mean earth
+/// radius of 6371.088 km.
+///
+/// As with `Simplify`, polygons are simplified by simplifying each of their rings, which may
+/// result in invalid polygons, and Multi* objects by simplifying each of their geometries.
+///
+/// An `epsilon` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Units
+///
+/// - `epsilon`: meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SimplifyHaversine};
+///
+/// // Offsets of about 30 meters, and 300 meters, to the east of a meridian, at latitude 60°
+/// let line_string = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.00054, y: 60.1),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// let simplified = line_string.simplify_haversine(&100.0);
+///
+/// let expected = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// assert_eq!(expected, simplified);
+/// ```
+pub trait SimplifyHaversine<T, Epsilon = T> {
+    fn simplify_haversine(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+/// Simplifies a geometry of longitude/latitude coordinates with the
+/// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263)
+/// algorithm, measuring areas on a sphere.
+///
+/// This is [`SimplifyVw`](crate::SimplifyVw), but a point is removed if the triangle it forms
+/// with its neighbours has an area of less than `epsilon` _square meters_, rather than square
+/// degrees. Areas are measured with
+/// [`ChamberlainDuquetteArea`](crate::ChamberlainDuquetteArea).
+///
+/// An `epsilon` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Units
+///
+/// - `epsilon`: square meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SimplifyVwHaversine};
+///
+/// let line_string = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.00054, y: 60.1),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// // The triangle at the first offset has an area of about 330,000 m²
+/// let simplified = line_string.simplify_vw_haversine(&1e6);
+///
+/// let expected = line_string![
+///     (x: 0.0, y: 60.0),
+///     (x: 0.0, y: 60.2),
+///     (x: 0.0054, y: 60.3),
+///     (x: 0.0, y: 60.4),
+/// ];
+///
+/// assert_eq!(expected, simplified);
+/// ```
+pub trait SimplifyVwHaversine<T, Epsilon = T> {
+    fn simplify_vw_haversine(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+/// The haversine distance from `coord` to the closest point of the great circle segment `line`.
+fn haversine_segment_distance<T>(coord: Coord<T>, line: Line<T>) -> T
+where
+    T: GeoFloat + FromPrimitive,
+{
+    let (p, a, b) = (Point::from(coord), line.start_point(), line.end_point());
+    let length = a.haversine_distance(&b);
+    if length == T::zero() {
+        return p.haversine_distance(&a);
+    }
+    let along_track = p.along_track_distance(&a, &b);
+    if along_track <= T::zero() {
+        p.haversine_distance(&a)
+    } else if along_track >= length {
+        p.haversine_distance(&b)
+    } else {
+        p.cross_track_distance(&a, &b)
+    }
+}
+
+fn haversine_area<T>(triangle: Triangle<T>) -> T
+where
+    T: GeoFloat,
+{
+    triangle.to_polygon().chamberlain_duquette_unsigned_area()
+}
+
+impl<T> SimplifyHaversine<T> for LineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn simplify_haversine(&self, epsilon: &T) -> Self {
+        LineString::from(rdp_with::<_, _, LINE_STRING_INITIAL_MIN>(
+            self.0.iter().copied(),
+            epsilon,
+            &haversine_segment_distance,
+        ))
+    }
+}
+
+impl<T> SimplifyHaversine<T> for MultiLineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn simplify_haversine(&self, epsilon: &T) -> Self {
+        MultiLineString::new(self.iter().map(|l| l.simplify_haversine(epsilon)).collect())
+    }
+}
+
+impl<T> SimplifyHaversine<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn simplify_haversine(&self, epsilon: &T) -> Self {
+        let simplify = |ring: &LineString<T>| {
+            LineString::from(rdp_with::<_, _, POLYGON_INITIAL_MIN>(
+                ring.0.iter().copied(),
+                epsilon,
+                &haversine_segment_distance,
+            ))
+        };
+        Polygon::new(
+            simplify(self.exterior()),
+            self.interiors().iter().map(simplify).collect(),
+        )
+    }
+}
+
+impl<T> SimplifyHaversine<T> for MultiPolygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn simplify_haversine(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(self.iter().map(|p| p.simplify_haversine(epsilon)).collect())
+    }
+}
+
+impl<T> SimplifyVwHaversine<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_vw_haversine(&self, epsilon: &T) -> Self {
+        LineString::from(visvalingam_with(self, epsilon, &haversine_area))
+    }
+}
+
+impl<T> SimplifyVwHaversine<T> for MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_vw_haversine(&self, epsilon: &T) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|l| l.simplify_vw_haversine(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyVwHaversine<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_vw_haversine(&self, epsilon: &T) -> Self {
+        Polygon::new(
+            self.exterior().simplify_vw_haversine(epsilon),
+            self.interiors()
+                .iter()
+                .map(|l| l.simplify_vw_haversine(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SimplifyVwHaversine<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_vw_haversine(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_vw_haversine(epsilon))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Simplify, SimplifyVw};
+
+    /// A line along the meridian at longitude 0°, from `latitude`, with offsets to the east of
+    /// roughly `offsets` meters at every 0.1°.
+    fn meridian_with_offsets(latitude: f64, offsets: &[f64]) -> LineString {
+        // The length of a degree of longitude at the equator
+        let degree = 111_195.;
+        let mut coords = vec![Coord { x: 0., y: latitude }];
+        for (i, offset) in offsets.iter().enumerate() {
+            let y = latitude + 0.1 * (i + 1) as f64;
+            coords.push(Coord {
+                x: offset / degree / y.to_radians().cos(),
+                y,
+            });
+        }
+        coords.push(Coord {
+            x: 0.,
+            y: latitude + 0.1 * (offsets.len() + 1) as f64,
+        });
+        coords.into()
+    }
+
+    /// The indices of the vertices kept by `simplify`, of a meridian with offsets from
+    /// `latitude`.
+    fn kept(latitude: f64, simplify: impl Fn(&LineString) -> LineString) -> Vec<usize> {
+        let line_string = meridian_with_offsets(latitude, &[20., 300., -50., 40., 10., -300.]);
+        simplify(&line_string)
+            .0
+            .iter()
+            .map(|c| ((c.y - latitude) * 10.).round() as usize)
+            .collect()
+    }
+
+    #[test]
+    fn tolerance_in_meters() {
+        let simplified = kept(0., |l| l.simplify_haversine(&200.));
+        assert_eq!(simplified, vec![0, 2, 6, 7]);
+        for latitude in [30., 60., -75.] {
+            assert_eq!(kept(latitude, |l| l.simplify_haversine(&200.)), simplified);
+        }
+
+        // In degrees, the same tolerance keeps more points further from the equator
+        let epsilon = 200. / 111_195.;
+        assert_eq!(kept(0., |l| l.simplify(&epsilon)), simplified);
+        assert!(kept(75., |l| l.simplify(&epsilon)).len() > simplified.len());
+    }
+
+    #[test]
+    fn vw_tolerance_in_square_meters() {
+        // The vertices are 0.1° apart, which is about 11 km
+        let simplified = kept(0., |l| l.simplify_vw_haversine(&3e6));
+        assert_eq!(simplified, vec![0, 2, 3, 5, 6, 7]);
+        for latitude in [30., 60., -75.] {
+            assert_eq!(
+                kept(latitude, |l| l.simplify_vw_haversine(&3e6)),
+                simplified
+            );
+        }
+
+        let epsilon = 3e6 / 111_195_f64.powi(2);
+        assert_eq!(kept(0., |l| l.simplify_vw(&epsilon)), simplified);
+        assert!(kept(75., |l| l.simplify_vw(&epsilon)).len() > simplified.len());
+    }
+
+    #[test]
+    fn beyond_segment_ends() {
+        // The middle point is beyond the end of the segment between the others, and far from
+        // it, but close to the great circle through them
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.0001), (x: 1., y: 0.)];
+        assert_eq!(line_string.simplify_haversine(&1000.), line_string);
+    }
+
+    #[test]
+    fn polygon() {
+        let polygon = polygon![
+            (x: 0., y: 60.),
+            (x: 1., y: 60.),
+            (x: 1.00001, y: 60.5),
+            (x: 1., y: 61.),
+            (x: 0., y: 61.),
+        ];
+        let expected = polygon![(x: 0., y: 60.), (x: 1., y: 60.), (x: 1., y: 61.), (x: 0., y: 61.)];
+        assert_eq!(polygon.simplify_haversine(&10.), expected);
+        assert_eq!(polygon.simplify_haversine(&0.1), polygon);
+        assert_eq!(polygon.simplify_vw_haversine(&1e5), expected);
+        assert_eq!(polygon.simplify_vw_haversine(&1.), polygon);
+    }
+
+    #[test]
+    fn no_tolerance() {
+        let line_string = meridian_with_offsets(45., &[1., 2., 3.]);
+        assert_eq!(line_string.simplify_haversine(&0.), line_string);
+        assert_eq!(line_string.simplify_vw_haversine(&0.), line_string);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::algorithm::simplify::{LINE_STRING_INITIAL_MIN, POLYGON_INITIAL_MIN};
+use crate::{Coord, CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+use alloc::vec::Vec;
+
+/// Drop the coordinates which are closer than `tolerance` to the last one kept, always keeping
+/// the first and the last, unless that would leave fewer than `INITIAL_MIN` of them.
+fn radial<T: CoordFloat, const INITIAL_MIN: usize>(
+    coords: &[Coord<T>],
+    tolerance: T,
+) -> Vec<Coord<T>> {
+    if tolerance <= T::zero() || coords.len() <= INITIAL_MIN {
+        return coords.to_vec();
+    }
+    let tolerance_squared = tolerance * tolerance;
+    let squared_distance = |a: Coord<T>, b: Coord<T>| {
+        let delta = a - b;
+        delta.x * delta.x + delta.y * delta.y
+    };
+
+    let (first, rest) = coords.split_first().unwrap();
+    let (last, middle) = rest.split_last().unwrap();
+    let mut kept = Vec::with_capacity(coords.len());
+    kept.push(*first);
+    let mut previous = *first;
+    for &coord in middle {
+        if squared_distance(coord, previous) >= tolerance_squared {
+            kept.push(coord);
+            previous = coord;
+        }
+    }
+    kept.push(*last);
+
+    if kept.len() < INITIAL_MIN {
+        return coords.to_vec();
+    }
+    kept
+}
+
+/// Simplifies a geometry by dropping the points which are closer than a tolerance to the point
+/// before them, which is known as radial distance simplification.
+///
+/// This is much cheaper than the [Ramer–Douglas–Peucker](crate::Simplify) or
+/// [Visvalingam-Whyatt](crate::SimplifyVw) algorithms, and, since it only removes points in
+/// clusters much smaller than the tolerance of those algorithms, it's a good first pass before
+/// them for densely sampled geometries, such as GPS traces of slow or stationary devices: see
+/// [`simplify_prefiltered`](crate::Simplify::simplify_prefiltered) and
+/// [`simplify_vw_prefiltered`](crate::SimplifyVw::simplify_vw_prefiltered).
+///
+/// The first and last points of each line string and ring are always kept. A ring which would
+/// be left with fewer than four points, or a line string of fewer than two, is unaltered, and
+/// a `tolerance` less than or equal to zero returns an unaltered version of the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SimplifyRadial};
+///
+/// // A trace which pauses at (1, 0)
+/// let trace = line_string![
+///     (x: 0., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 1.02, y: 0.01),
+///     (x: 0.99, y: 0.02),
+///     (x: 2., y: 0.),
+/// ];
+///
+/// assert_eq!(
+///     trace.simplify_radial(&0.1),
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+/// );
+/// ```
+pub trait SimplifyRadial<T> {
+    /// Returns the geometry without the points closer than `tolerance` to the point kept before
+    /// them.
+    fn simplify_radial(&self, tolerance: &T) -> Self
+    where
+        T: CoordFloat;
+}
+
+impl<T> SimplifyRadial<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_radial(&self, tolerance: &T) -> Self {
+        LineString::new(radial::<_, LINE_STRING_INITIAL_MIN>(&self.0, *tolerance))
+    }
+}
+
+impl<T> SimplifyRadial<T> for MultiLineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_radial(&self, tolerance: &T) -> Self {
+        MultiLineString::new(self.iter().map(|l| l.simplify_radial(tolerance)).collect())
+    }
+}
+
+impl<T> SimplifyRadial<T> for Polygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_radial(&self, tolerance: &T) -> Self {
+        let ring =
+            |l: &LineString<T>| LineString::new(radial::<_, POLYGON_INITIAL_MIN>(&l.0, *tolerance));
+        Polygon::new(
+            ring(self.exterior()),
+            self.interiors().iter().map(ring).collect(),
+        )
+    }
+}
+
+impl<T> SimplifyRadial<T> for MultiPolygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_radial(&self, tolerance: &T) -> Self {
+        MultiPolygon::new(self.iter().map(|p| p.simplify_radial(tolerance)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Simplify, SimplifyVw};
+
+    #[test]
+    fn rings_keep_their_minimum() {
+        // Every vertex is within the tolerance of the first
+        let small = polygon![(x: 0., y: 0.), (x: 0.1, y: 0.), (x: 0.1, y: 0.1), (x: 0., y: 0.1)];
+        assert_eq!(small.simplify_radial(&1.), small);
+
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 0.05, y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 10., y: 10.05),
+            (x: 0., y: 10.),
+        ];
+        let simplified = square.simplify_radial(&0.1);
+        assert_eq!(
+            simplified,
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)]
+        );
+        assert!(simplified.exterior().is_closed());
+
+        assert_eq!(square.simplify_radial(&0.), square);
+        assert_eq!(square.simplify_radial(&-1.), square);
+    }
+
+    #[test]
+    fn prefiltered() {
+        // A dense trace, along a zig-zag with a jitter much smaller than its teeth
+        let trace: LineString = (0..1000)
+            .map(|i| {
+                let x = i as f64 / 100.;
+                let tooth = if (i / 100) % 2 == 0 {
+                    x % 1.
+                } else {
+                    1. - x % 1.
+                };
+                let jitter = if i % 2 == 0 { 0.001 } else { -0.001 };
+                (x, tooth + jitter)
+            })
+            .collect();
+
+        let prefiltered = trace.simplify_radial(&0.05);
+        assert!(prefiltered.0.len() < trace.0.len() / 3);
+        assert_eq!(
+            trace.simplify_prefiltered(&0.05, &0.1),
+            prefiltered.simplify(&0.1)
+        );
+        assert_eq!(
+            trace.simplify_vw_prefiltered(&0.05, &0.01),
+            prefiltered.simplify_vw(&0.01)
+        );
+        // The prefilter doesn't change the shape at the tolerance of the simplification
+        assert_eq!(
+            trace.simplify_prefiltered(&0.05, &0.1).0.len(),
+            trace.simplify(&0.1).0.len()
+        );
+
+        let short = line_string![(x: 0., y: 0.), (x: 0.01, y: 0.)];
+        assert_eq!(short.simplify_radial(&1.), short);
+    }
+}
@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+
+use crate::{Coord, EuclideanDistance, GeoFloat, Line};
+
+/// The default maximum number of coordinates a [`StreamingSimplifier`] holds back.
+const DEFAULT_MAX_WINDOW: usize = 64;
+
+/// Simplify a stream of coordinates as they arrive, such as GPS telemetry, without buffering
+/// the whole path, emitting each coordinate to keep as soon as it's known to be needed.
+///
+/// The simplifier holds back the coordinates since the last one it kept, and drops them for as
+/// long as they all lie within `epsilon` of the segment from the last kept coordinate to the
+/// newest one. When a new coordinate would take one of them further away, the previous
+/// coordinate is kept, and the window starts again from it. So, as for
+/// [`Simplify`](crate::Simplify), every dropped coordinate is within `epsilon` of the simplified
+/// path, although this sliding window variant of Douglas–Peucker generally keeps more
+/// coordinates than simplifying the whole path at once would.
+///
+/// At most [`max_window`](Self::max_window) coordinates are held back, which bounds the memory
+/// used, and the time taken by each [`push`](Self::push), at the cost of keeping a coordinate
+/// at least that often along straight stretches.
+///
+/// The first coordinate is emitted as soon as it's pushed, and the last when the stream is
+/// [finished](Self::finish). Repeated coordinates are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, StreamingSimplifier};
+///
+/// let mut simplifier = StreamingSimplifier::new(0.5);
+/// let readings = [
+///     coord! { x: 0., y: 0. },
+///     coord! { x: 1., y: 0.1 },
+///     coord! { x: 2., y: -0.1 },
+///     coord! { x: 3., y: 0. },
+///     coord! { x: 3., y: 1. },
+///     coord! { x: 3., y: 2. },
+/// ];
+///
+/// let mut kept: Vec<_> = readings
+///     .into_iter()
+///     .filter_map(|reading| simplifier.push(reading))
+///     .collect();
+/// kept.extend(simplifier.finish());
+///
+/// assert_eq!(
+///     kept,
+///     [coord! { x: 0., y: 0. }, coord! { x: 3., y: 0. }, coord! { x: 3., y: 2. }]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamingSimplifier<T: GeoFloat = f64> {
+    epsilon: T,
+    max_window: usize,
+    /// The last coordinate kept
+    key: Option<Coord<T>>,
+    /// The coordinates since `key`, the last of which is the end of the candidate segment
+    window: Vec<Coord<T>>,
+}
+
+impl<T: GeoFloat> StreamingSimplifier<T> {
+    /// A simplifier which drops coordinates within `epsilon` of the simplified path, holding
+    /// back at most 64 coordinates.
+    pub fn new(epsilon: T) -> Self {
+        StreamingSimplifier {
+            epsilon,
+            max_window: DEFAULT_MAX_WINDOW,
+            key: None,
+            window: Vec::new(),
+        }
+    }
+
+    /// Hold back at most `max_window` coordinates, which must be at least one.
+    #[must_use]
+    pub fn max_window(mut self, max_window: usize) -> Self {
+        self.max_window = max_window.max(1);
+        self
+    }
+
+    /// Add the next coordinate of the stream, returning the coordinate it showed needs to be
+    /// kept, if any, which is the first coordinate or one from before this one.
+    pub fn push(&mut self, coord: Coord<T>) -> Option<Coord<T>> {
+        let Some(key) = self.key else {
+            self.key = Some(coord);
+            return Some(coord);
+        };
+        if self.window.last().unwrap_or(&key) == &coord {
+            return None;
+        }
+
+        let segment = Line::new(key, coord);
+        if self.window.len() < self.max_window
+            && self
+                .window
+                .iter()
+                .all(|held| held.euclidean_distance(&segment) <= self.epsilon)
+        {
+            self.window.push(coord);
+            return None;
+        }
+
+        // The window isn't empty, or the segment would have been accepted
+        let kept = self.window.pop()?;
+        self.window.clear();
+        self.window.push(coord);
+        self.key = Some(kept);
+        Some(kept)
+    }
+
+    /// End the stream, returning its last coordinate, unless it's already been returned, and
+    /// resetting the simplifier for a new stream.
+    pub fn finish(&mut self) -> Option<Coord<T>> {
+        self.key = None;
+        let last = self.window.pop();
+        self.window.clear();
+        last
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, LineString, Point};
+
+    fn simplify(simplifier: &mut StreamingSimplifier, coords: &[Coord]) -> Vec<Coord> {
+        let mut kept: Vec<_> = coords
+            .iter()
+            .filter_map(|&coord| simplifier.push(coord))
+            .collect();
+        kept.extend(simplifier.finish());
+        kept
+    }
+
+    #[test]
+    fn within_epsilon() {
+        // A noisy spiral
+        let coords: Vec<Coord> = (0..500)
+            .map(|i| {
+                let angle = f64::from(i) * 0.05;
+                let radius = 10. + angle + 0.05 * (f64::from(i) * 7.).sin();
+                coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+            })
+            .collect();
+        let epsilon = 0.3;
+        let kept = simplify(&mut StreamingSimplifier::new(epsilon), &coords);
+
+        assert!(kept.len() < coords.len() / 4, "{}", kept.len());
+        assert_eq!(kept.first(), coords.first());
+        assert_eq!(kept.last(), coords.last());
+        // Kept in order, and every coordinate is within epsilon of the simplified path
+        let mut remaining = coords.iter();
+        assert!(kept.iter().all(|k| remaining.any(|coord| coord == k)));
+        let simplified = LineString::new(kept);
+        for coord in &coords {
+            assert!(Point::from(*coord).euclidean_distance(&simplified) <= epsilon + 1e-12);
+        }
+    }
+
+    #[test]
+    fn doubling_back() {
+        let coords = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 5., y: 0. },
+            coord! { x: 10., y: 0. },
+            coord! { x: 5., y: 0.1 },
+            coord! { x: 0., y: 0.1 },
+        ];
+        assert_eq!(
+            simplify(&mut StreamingSimplifier::new(0.5), &coords),
+            [coords[0], coords[2], coords[4]]
+        );
+    }
+
+    #[test]
+    fn window_and_repeats() {
+        let straight: Vec<Coord> = (0..10).map(|x| coord! { x: f64::from(x), y: 0. }).collect();
+        let mut simplifier = StreamingSimplifier::new(0.1).max_window(3);
+        assert_eq!(
+            simplify(&mut simplifier, &straight),
+            [straight[0], straight[3], straight[6], straight[9]]
+        );
+
+        // The simplifier is reset by finishing
+        let repeated = [coord! { x: 1., y: 1. }; 3];
+        assert_eq!(simplify(&mut simplifier, &repeated), [repeated[0]]);
+        assert_eq!(simplify(&mut simplifier, &[]), []);
+    }
+}
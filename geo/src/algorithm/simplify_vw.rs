@@ -3,8 +3,9 @@ use crate::{
     Coord, CoordFloat, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon,
     Triangle,
 };
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+use core::marker::PhantomData;
 
 use rstar::primitives::CachedEnvelope;
 use rstar::{RTree, RTreeNum};
@@ -72,7 +73,14 @@ where
 // then recalculate the new triangle area and push it onto the heap
 // based on Huon Wilson's original implementation:
 // https://github.com/huonw/isrustfastyet/blob/25e7a68ff26673a8556b170d3c9af52e1c818288/mem/line_simplify.rs
-fn visvalingam_indices<T>(orig: &LineString<T>, epsilon: &T) -> Vec<usize>
+//
+// `area` measures the triangles, so that epsilon can be given in other units than those of the
+// coordinates.
+fn visvalingam_indices<T>(
+    orig: &LineString<T>,
+    epsilon: &T,
+    area: &impl Fn(Triangle<T>) -> T,
+) -> Vec<usize>
 where
     T: CoordFloat,
 {
@@ -107,7 +115,7 @@ where
         .triangles()
         .enumerate()
         .map(|(i, triangle)| VScore {
-            area: triangle.unsigned_area(),
+            area: area(triangle),
             current: i + 1,
             left: i,
             right: i + 2,
@@ -139,7 +147,9 @@ where
 
         // Recompute the adjacent triangle(s), using left and right adjacent points
         // this may add new triangles to the heap
-        recompute_triangles(&smallest, orig, &mut pq, ll, left, right, rr, max, epsilon);
+        recompute_triangles(
+            &smallest, orig, &mut pq, ll, left, right, rr, max, epsilon, area,
+        );
     }
     // Filter out the points that have been deleted, returning remaining point indices
     orig.0
@@ -164,6 +174,7 @@ fn recompute_triangles<T>(
     rr: i32,
     max: usize,
     epsilon: &T,
+    area: &impl Fn(Triangle<T>) -> T,
 ) where
     T: CoordFloat,
 {
@@ -173,12 +184,11 @@ fn recompute_triangles<T>(
             // Out of bounds, i.e. we're on one edge
             continue;
         }
-        let area = Triangle::new(
+        let area = area(Triangle::new(
             orig.0[ai as usize],
             orig.0[current_point as usize],
             orig.0[bi as usize],
-        )
-        .unsigned_area();
+        ));
 
         // This logic only applies to VW-Preserve
         // smallest.current's removal causes a self-intersection, and this point precedes it
@@ -203,8 +213,25 @@ fn recompute_triangles<T>(
     }
 }
 
+fn planar_area<T: CoordFloat>(triangle: Triangle<T>) -> T {
+    triangle.unsigned_area()
+}
+
 // Wrapper for visvalingam_indices, mapping indices back to points
 fn visvalingam<T>(orig: &LineString<T>, epsilon: &T) -> Vec<Coord<T>>
+where
+    T: CoordFloat,
+{
+    visvalingam_with(orig, epsilon, &planar_area)
+}
+
+/// Simplify `orig` with the Visvalingam-Whyatt algorithm, measuring the area of its triangles
+/// with `area`.
+pub(crate) fn visvalingam_with<T>(
+    orig: &LineString<T>,
+    epsilon: &T,
+    area: &impl Fn(Triangle<T>) -> T,
+) -> Vec<Coord<T>>
 where
     T: CoordFloat,
 {
@@ -212,7 +239,7 @@ where
     if *epsilon <= T::zero() {
         return orig.0.to_vec();
     }
-    let subset = visvalingam_indices(orig, epsilon);
+    let subset = visvalingam_indices(orig, epsilon, area);
     // filter orig using the indices
     // using get would be more robust here, but the input subset is guaranteed to be valid in this case
     orig.0
@@ -380,7 +407,18 @@ where
 
         // Recompute the adjacent triangle(s), using left and right adjacent points
         // this may add new triangles to the heap
-        recompute_triangles(&smallest, orig, &mut pq, ll, left, right, rr, max, epsilon);
+        recompute_triangles(
+            &smallest,
+            orig,
+            &mut pq,
+            ll,
+            left,
+            right,
+            rr,
+            max,
+            epsilon,
+            &planar_area,
+        );
     }
     // Filter out the points that have been deleted, returning remaining points
     orig.0
@@ -472,6 +510,18 @@ pub trait SimplifyVw<T, Epsilon = T> {
     fn simplify_vw(&self, epsilon: &T) -> Self
     where
         T: CoordFloat;
+
+    /// The same as [`simplify_vw`](Self::simplify_vw), after first dropping the points closer
+    /// than `radial_tolerance` to the point before them with
+    /// [`simplify_radial`](crate::SimplifyRadial::simplify_radial), which is much faster for
+    /// densely sampled geometries.
+    fn simplify_vw_prefiltered(&self, radial_tolerance: &T, epsilon: &T) -> Self
+    where
+        T: CoordFloat,
+        Self: SimplifyRadial<T> + Sized,
+    {
+        self.simplify_radial(radial_tolerance).simplify_vw(epsilon)
+    }
 }
 
 /// Simplifies a geometry, returning the retained _indices_ of the output
@@ -655,7 +705,7 @@ where
     T: CoordFloat,
 {
     fn simplify_vw_idx(&self, epsilon: &T) -> Vec<usize> {
-        visvalingam_indices(self, epsilon)
+        visvalingam_indices(self, epsilon, &planar_area)
     }
 }
 
@@ -692,11 +742,548 @@ where
     }
 }
 
+/// Simplifies a geometry with the Visvalingam-Whyatt algorithm until it's small enough, rather
+/// than until the triangles of its points are large enough.
+///
+/// Points are removed in increasing order of the area of the triangle they form with their
+/// neighbours, as with [`SimplifyVw`], but across all the rings or parts of the geometry at once,
+/// until the geometry has at most a given number of coordinates, or until its
+/// [WKB](https://libgeos.org/specifications/wkb/) representation, with 2D coordinates, fits in a
+/// given number of bytes. This gives predictable output sizes without searching for an
+/// `epsilon`.
+///
+/// The first and last points of each `LineString` and ring are kept, and no ring is simplified
+/// below four coordinates, so the budget may not be met if it's too small for the geometry.
+pub trait SimplifyVwBudget {
+    /// Returns the geometry simplified to at most `max_coords` coordinates, counting the closing
+    /// coordinate of each ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, SimplifyVwBudget};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 3.0, y: 8.0),
+    ///     (x: 6.0, y: 20.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// let simplified = line_string.simplify_vw_to_count(3);
+    ///
+    /// let expected = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// assert_eq!(expected, simplified);
+    /// ```
+    fn simplify_vw_to_count(&self, max_coords: usize) -> Self;
+
+    /// Returns the geometry simplified so that its WKB representation takes at most `max_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, SimplifyVwBudget};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 3.0, y: 8.0),
+    ///     (x: 6.0, y: 20.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// // A WKB LineString has a 9 byte header, and 16 bytes per coordinate
+    /// let simplified = line_string.simplify_vw_to_wkb_size(60);
+    /// assert_eq!(simplified.0.len(), 3);
+    /// ```
+    fn simplify_vw_to_wkb_size(&self, max_bytes: usize) -> Self;
+}
+
+/// The size of a WKB byte order and geometry type
+const WKB_HEADER: usize = 5;
+/// The size of a WKB count of rings, parts or points
+const WKB_COUNT: usize = 4;
+/// The size of a 2D WKB coordinate
+const WKB_COORD: usize = 16;
+
+/// The number of coordinates which fit in `max_bytes` of WKB, besides `overhead`.
+fn wkb_coords(max_bytes: usize, overhead: usize) -> usize {
+    max_bytes.saturating_sub(overhead) / WKB_COORD
+}
+
+/// A candidate for removal from one of several rings
+#[derive(Debug)]
+struct RingVScore<T: CoordFloat> {
+    ring: usize,
+    left: usize,
+    current: usize,
+    right: usize,
+    area: T,
+}
+
+// These impls give us a min-heap
+impl<T: CoordFloat> Ord for RingVScore<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.partial_cmp(&self.area).unwrap()
+    }
+}
+
+impl<T: CoordFloat> PartialOrd for RingVScore<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CoordFloat> Eq for RingVScore<T> {}
+
+impl<T: CoordFloat> PartialEq for RingVScore<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area
+    }
+}
+
+/// Remove the points of smallest triangle area from any of `rings` until at most `max_coords`
+/// remain in total, keeping at least `min_coords` in each ring.
+fn visvalingam_to_count<T>(
+    rings: &[&LineString<T>],
+    min_coords: usize,
+    max_coords: usize,
+) -> Vec<LineString<T>>
+where
+    T: CoordFloat,
+{
+    let total: usize = rings.iter().map(|ring| ring.0.len()).sum();
+    let mut removed: Vec<Vec<bool>> = rings.iter().map(|ring| vec![false; ring.0.len()]).collect();
+    for removal in visvalingam_removals(rings, min_coords, total.saturating_sub(max_coords)) {
+        removed[removal.ring][removal.current] = true;
+    }
+
+    rings
+        .iter()
+        .zip(removed)
+        .map(|(ring, removed)| {
+            ring.0
+                .iter()
+                .zip(removed)
+                .filter_map(|(coord, removed)| (!removed).then_some(*coord))
+                .collect()
+        })
+        .collect()
+}
+
+impl<T> SimplifyVwBudget for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_to_count(&self, max_coords: usize) -> Self {
+        visvalingam_to_count(&[self], 2, max_coords).remove(0)
+    }
+
+    fn simplify_vw_to_wkb_size(&self, max_bytes: usize) -> Self {
+        self.simplify_vw_to_count(wkb_coords(max_bytes, WKB_HEADER + WKB_COUNT))
+    }
+}
+
+impl<T> SimplifyVwBudget for MultiLineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_to_count(&self, max_coords: usize) -> Self {
+        let line_strings: Vec<_> = self.iter().collect();
+        MultiLineString::new(visvalingam_to_count(&line_strings, 2, max_coords))
+    }
+
+    fn simplify_vw_to_wkb_size(&self, max_bytes: usize) -> Self {
+        let overhead = WKB_HEADER + WKB_COUNT + self.0.len() * (WKB_HEADER + WKB_COUNT);
+        self.simplify_vw_to_count(wkb_coords(max_bytes, overhead))
+    }
+}
+
+/// Rebuild polygons, whose numbers of rings are `ring_counts`, from their simplified rings.
+fn polygons_from_rings<T: CoordFloat>(
+    rings: Vec<LineString<T>>,
+    ring_counts: impl IntoIterator<Item = usize>,
+) -> Vec<Polygon<T>> {
+    let mut rings = rings.into_iter();
+    ring_counts
+        .into_iter()
+        .map(|count| {
+            let exterior = rings.next().unwrap();
+            Polygon::new(exterior, rings.by_ref().take(count - 1).collect())
+        })
+        .collect()
+}
+
+impl<T> SimplifyVwBudget for Polygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_to_count(&self, max_coords: usize) -> Self {
+        let rings: Vec<_> = core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        polygons_from_rings(visvalingam_to_count(&rings, 4, max_coords), [rings.len()]).remove(0)
+    }
+
+    fn simplify_vw_to_wkb_size(&self, max_bytes: usize) -> Self {
+        let overhead = WKB_HEADER + WKB_COUNT + (1 + self.interiors().len()) * WKB_COUNT;
+        self.simplify_vw_to_count(wkb_coords(max_bytes, overhead))
+    }
+}
+
+impl<T> SimplifyVwBudget for MultiPolygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_to_count(&self, max_coords: usize) -> Self {
+        let rings: Vec<_> = self
+            .iter()
+            .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        let ring_counts = self.iter().map(|polygon| 1 + polygon.interiors().len());
+        MultiPolygon::new(polygons_from_rings(
+            visvalingam_to_count(&rings, 4, max_coords),
+            ring_counts,
+        ))
+    }
+
+    fn simplify_vw_to_wkb_size(&self, max_bytes: usize) -> Self {
+        let overhead = WKB_HEADER
+            + WKB_COUNT
+            + self
+                .iter()
+                .map(|polygon| WKB_HEADER + WKB_COUNT + (1 + polygon.interiors().len()) * WKB_COUNT)
+                .sum::<usize>();
+        self.simplify_vw_to_count(wkb_coords(max_bytes, overhead))
+    }
+}
+
+/// No more important coordinate to follow: the first of a ring.
+const RING_START: usize = usize::MAX;
+
+/// A geometry with the order in which the Visvalingam-Whyatt algorithm removes its coordinates,
+/// so that it can be simplified to any number of coordinates, or any effective area, without
+/// running the algorithm again.
+///
+/// The coordinates are kept most important first, each with its effective area: the area of
+/// its triangle when it's removed, or the largest area removed before it, if that's larger, so
+/// that the areas only decrease. Taking the `count` most important coordinates gives the
+/// geometry those `count` coordinates, in `O(count)` time, so a zoomable map can switch between
+/// levels of detail on every frame.
+///
+/// Points are removed across all the rings or parts of the geometry at once, as with
+/// [`SimplifyVwBudget`]. The first and last coordinates of each `LineString` and ring are
+/// always kept, and no ring has fewer than four coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LevelOfDetail, LineString};
+///
+/// let line_string = line_string![
+///     (x: 5.0, y: 2.0),
+///     (x: 3.0, y: 8.0),
+///     (x: 6.0, y: 20.0),
+///     (x: 7.0, y: 25.0),
+///     (x: 10.0, y: 10.0),
+/// ];
+/// let levels = LevelOfDetail::<LineString>::new(&line_string);
+///
+/// assert_eq!(
+///     levels.with_coords(3),
+///     line_string![(x: 5.0, y: 2.0), (x: 7.0, y: 25.0), (x: 10.0, y: 10.0)]
+/// );
+/// assert_eq!(levels.with_coords(5), line_string);
+/// // The ends are always kept
+/// assert_eq!(levels.with_coords(0).0.len(), 2);
+/// // The coordinates whose effective areas are above 30
+/// assert_eq!(levels.coords_above_area(30.), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelOfDetail<G: CoordsIter> {
+    /// The coordinates, most important first
+    coords: Vec<Coord<G::Scalar>>,
+    /// The effective area of each coordinate, infinite for those which are always kept
+    areas: Vec<G::Scalar>,
+    /// The more important coordinate which each coordinate follows in its ring, or
+    /// `RING_START`
+    follows: Vec<usize>,
+    /// The first coordinate of each ring, or `None` if it's empty
+    ring_starts: Vec<Option<usize>>,
+    /// The number of coordinates which are always kept
+    kept: usize,
+    /// The number of rings of each polygon
+    ring_counts: Vec<usize>,
+    geometry: PhantomData<G>,
+}
+
+impl<G: CoordsIter> LevelOfDetail<G>
+where
+    G::Scalar: CoordFloat,
+{
+    fn from_rings(
+        rings: &[&LineString<G::Scalar>],
+        min_coords: usize,
+        ring_counts: Vec<usize>,
+    ) -> Self {
+        let removals = visvalingam_removals(rings, min_coords, usize::MAX);
+        let mut removed: Vec<Vec<bool>> =
+            rings.iter().map(|ring| vec![false; ring.0.len()]).collect();
+        for removal in &removals {
+            removed[removal.ring][removal.current] = true;
+        }
+        let total = rings.iter().map(|ring| ring.0.len()).sum();
+        let mut levels = LevelOfDetail {
+            coords: Vec::with_capacity(total),
+            areas: Vec::with_capacity(total),
+            follows: Vec::with_capacity(total),
+            ring_starts: vec![None; rings.len()],
+            kept: total - removals.len(),
+            ring_counts,
+            geometry: PhantomData,
+        };
+        let mut ranks: Vec<Vec<usize>> = rings.iter().map(|ring| vec![0; ring.0.len()]).collect();
+        // The coordinates which are always kept come first, in order, each following the one
+        // before it in its ring
+        for (ring, line_string) in rings.iter().enumerate() {
+            let mut previous = RING_START;
+            for (i, coord) in line_string.0.iter().enumerate() {
+                if removed[ring][i] {
+                    continue;
+                }
+                ranks[ring][i] = levels.coords.len();
+                if previous == RING_START {
+                    levels.ring_starts[ring] = Some(levels.coords.len());
+                }
+                previous = levels.push(*coord, num_traits::Float::infinity(), previous);
+            }
+        }
+        // A point's effective area is at least that of every point removed before it, so that
+        // points removed later are never less important
+        let mut effective_areas = Vec::with_capacity(removals.len());
+        let mut floor = num_traits::Zero::zero();
+        for removal in &removals {
+            floor = num_traits::Float::max(removal.area, floor);
+            effective_areas.push(floor);
+        }
+        // Then the removed ones, in the reverse of the order they were removed, so that each
+        // follows its neighbour from when it was removed, which comes before it
+        for (removal, area) in removals.iter().zip(effective_areas).rev() {
+            ranks[removal.ring][removal.current] = levels.coords.len();
+            let follows = ranks[removal.ring][removal.left];
+            levels.push(rings[removal.ring].0[removal.current], area, follows);
+        }
+        levels
+    }
+
+    fn push(&mut self, coord: Coord<G::Scalar>, area: G::Scalar, follows: usize) -> usize {
+        self.coords.push(coord);
+        self.areas.push(area);
+        self.follows.push(follows);
+        self.coords.len() - 1
+    }
+
+    /// The rings with their `count` most important coordinates, in order.
+    fn rings(&self, count: usize) -> Vec<LineString<G::Scalar>> {
+        let count = count.clamp(self.kept, self.coords.len());
+        let mut next = vec![RING_START; count];
+        for rank in 0..count {
+            let follows = self.follows[rank];
+            if follows != RING_START {
+                next[rank] = next[follows];
+                next[follows] = rank;
+            }
+        }
+        self.ring_starts
+            .iter()
+            .map(|start| {
+                let mut coords = vec![];
+                let mut rank = start.unwrap_or(RING_START);
+                while rank != RING_START {
+                    coords.push(self.coords[rank]);
+                    rank = next[rank];
+                }
+                LineString::new(coords)
+            })
+            .collect()
+    }
+
+    /// The number of coordinates of the geometry.
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Whether the geometry has no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// The number of coordinates which are always kept, however few are asked for.
+    pub fn min_coords(&self) -> usize {
+        self.kept
+    }
+
+    /// The effective areas of the coordinates, most important, and largest, first.
+    pub fn effective_areas(&self) -> &[G::Scalar] {
+        &self.areas
+    }
+
+    /// The number of coordinates whose effective area is greater than `epsilon`, to pass to
+    /// `with_coords` for a similar level of detail to [`SimplifyVw`] with `epsilon`.
+    pub fn coords_above_area(&self, epsilon: G::Scalar) -> usize {
+        self.areas.partition_point(|area| *area > epsilon)
+    }
+}
+
+impl<T: CoordFloat> LevelOfDetail<LineString<T>> {
+    /// Order the coordinates of `line_string` by importance.
+    pub fn new(line_string: &LineString<T>) -> Self {
+        Self::from_rings(&[line_string], 2, vec![])
+    }
+
+    /// The line string with its `count` most important coordinates.
+    pub fn with_coords(&self, count: usize) -> LineString<T> {
+        self.rings(count).remove(0)
+    }
+}
+
+impl<T: CoordFloat> LevelOfDetail<MultiLineString<T>> {
+    /// Order the coordinates of `multi_line_string` by importance.
+    pub fn new(multi_line_string: &MultiLineString<T>) -> Self {
+        let line_strings: Vec<_> = multi_line_string.iter().collect();
+        Self::from_rings(&line_strings, 2, vec![])
+    }
+
+    /// The line strings with their `count` most important coordinates in total.
+    pub fn with_coords(&self, count: usize) -> MultiLineString<T> {
+        MultiLineString::new(self.rings(count))
+    }
+}
+
+impl<T: CoordFloat> LevelOfDetail<Polygon<T>> {
+    /// Order the coordinates of `polygon` by importance.
+    pub fn new(polygon: &Polygon<T>) -> Self {
+        let rings: Vec<_> = core::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .collect();
+        let ring_counts = vec![rings.len()];
+        Self::from_rings(&rings, 4, ring_counts)
+    }
+
+    /// The polygon with its `count` most important coordinates in total.
+    pub fn with_coords(&self, count: usize) -> Polygon<T> {
+        polygons_from_rings(self.rings(count), self.ring_counts.iter().copied()).remove(0)
+    }
+}
+
+impl<T: CoordFloat> LevelOfDetail<MultiPolygon<T>> {
+    /// Order the coordinates of `multi_polygon` by importance.
+    pub fn new(multi_polygon: &MultiPolygon<T>) -> Self {
+        let rings: Vec<_> = multi_polygon
+            .iter()
+            .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        let ring_counts = multi_polygon
+            .iter()
+            .map(|polygon| 1 + polygon.interiors().len())
+            .collect();
+        Self::from_rings(&rings, 4, ring_counts)
+    }
+
+    /// The polygons with their `count` most important coordinates in total.
+    pub fn with_coords(&self, count: usize) -> MultiPolygon<T> {
+        MultiPolygon::new(polygons_from_rings(
+            self.rings(count),
+            self.ring_counts.iter().copied(),
+        ))
+    }
+}
+
+/// Remove up to `max_removals` of the points of smallest triangle area from any of `rings`,
+/// keeping at least `min_coords` in each ring, and return them in the order they were removed.
+fn visvalingam_removals<T>(
+    rings: &[&LineString<T>],
+    min_coords: usize,
+    max_removals: usize,
+) -> Vec<RingVScore<T>>
+where
+    T: CoordFloat,
+{
+    // The previous and next retained points of each point, or `None` at the ends
+    let mut adjacent: Vec<Vec<(Option<usize>, Option<usize>)>> = rings
+        .iter()
+        .map(|ring| {
+            (0..ring.0.len())
+                .map(|i| (i.checked_sub(1), Some(i + 1).filter(|&j| j < ring.0.len())))
+                .collect()
+        })
+        .collect();
+    let mut removed: Vec<Vec<bool>> = rings.iter().map(|ring| vec![false; ring.0.len()]).collect();
+    let mut counts: Vec<usize> = rings.iter().map(|ring| ring.0.len()).collect();
+
+    let score = |ring: usize, left: usize, current: usize, right: usize| {
+        let coords = &rings[ring].0;
+        RingVScore {
+            ring,
+            left,
+            current,
+            right,
+            area: planar_area(Triangle::new(coords[left], coords[current], coords[right])),
+        }
+    };
+    let mut pq: BinaryHeap<RingVScore<T>> = rings
+        .iter()
+        .enumerate()
+        .flat_map(|(ring, line_string)| {
+            (1..line_string.0.len().saturating_sub(1)).map(move |i| score(ring, i - 1, i, i + 1))
+        })
+        .collect();
+
+    let mut removals = vec![];
+    while removals.len() < max_removals {
+        let Some(smallest) = pq.pop() else {
+            break;
+        };
+        let ring = smallest.ring;
+        // A point in this triangle has been removed since this score was created, or the ring
+        // can't lose any more points
+        if removed[ring][smallest.current]
+            || adjacent[ring][smallest.current] != (Some(smallest.left), Some(smallest.right))
+            || counts[ring] <= min_coords
+        {
+            continue;
+        }
+        let (left, right) = (smallest.left, smallest.right);
+        removed[ring][smallest.current] = true;
+        adjacent[ring][left].1 = Some(right);
+        adjacent[ring][right].0 = Some(left);
+        counts[ring] -= 1;
+
+        if let Some(ll) = adjacent[ring][left].0 {
+            pq.push(score(ring, ll, left, right));
+        }
+        if let Some(rr) = adjacent[ring][right].1 {
+            pq.push(score(ring, left, right, rr));
+        }
+        removals.push(smallest);
+    }
+    removals
+}
+
 #[cfg(test)]
 mod test {
-    use super::{visvalingam, vwp_wrapper, SimplifyVw, SimplifyVwPreserve};
+    use super::{
+        visvalingam, vwp_wrapper, LevelOfDetail, SimplifyVw, SimplifyVwBudget, SimplifyVwPreserve,
+    };
     use crate::{
-        line_string, polygon, Coord, LineString, MultiLineString, MultiPolygon, Point, Polygon,
+        line_string, polygon, Coord, CoordsIter, LineString, MultiLineString, MultiPolygon, Point,
+        Polygon,
     };
 
     // See https://github.com/georust/geo/issues/1049
@@ -943,4 +1530,129 @@ mod test {
             epsilon = 1e-6
         );
     }
+
+    #[test]
+    fn budget_matches_epsilon() {
+        let line_string = geo_test_fixtures::louisiana::<f64>();
+        let simplified = line_string.simplify_vw(&0.0005);
+        assert_eq!(
+            line_string.simplify_vw_to_count(simplified.0.len()),
+            simplified
+        );
+        assert_eq!(line_string.simplify_vw_to_count(usize::MAX), line_string);
+        assert_eq!(line_string.simplify_vw_to_count(0).0.len(), 2);
+    }
+
+    #[test]
+    fn budget_polygon_minimum() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 5., y: 6.1), (x: 4., y: 6.)]],
+        ];
+        // The small bumps of both rings are removed first
+        let simplified = polygon.simplify_vw_to_count(10);
+        assert_eq!(simplified.exterior().0.len(), 5);
+        assert_eq!(simplified.interiors()[0].0.len(), 5);
+        // No ring goes below four coordinates
+        let simplified = polygon.simplify_vw_to_count(0);
+        assert_eq!(simplified.exterior().0.len(), 4);
+        assert_eq!(simplified.interiors()[0].0.len(), 4);
+    }
+
+    #[test]
+    fn budget_shared_between_parts() {
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.1), (x: 2., y: 0.), (x: 3., y: 0.3), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 1., y: 7.), (x: 2., y: 5.)],
+        ]);
+        // The points of the flatter line are removed first
+        let simplified = mls.simplify_vw_to_count(6);
+        assert_eq!(simplified.0[0].0.len(), 3);
+        assert_eq!(simplified.0[1], mls.0[1]);
+        let simplified = mls.simplify_vw_to_count(5);
+        assert_eq!(simplified.0[0].0.len(), 2);
+        assert_eq!(simplified.0[1], mls.0[1]);
+    }
+
+    #[test]
+    fn budget_wkb_size() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 25., y: 9.), (x: 20., y: 10.)],
+        ]);
+        // 9 bytes for the MultiPolygon, 13 for each polygon and its ring, and 16 per coordinate
+        let size = |mp: &MultiPolygon| 9 + 13 * 2 + 16 * mp.coords_count();
+        assert_eq!(size(&multi_polygon), 227);
+        assert_eq!(multi_polygon.simplify_vw_to_wkb_size(227), multi_polygon);
+        let simplified = multi_polygon.simplify_vw_to_wkb_size(226);
+        assert_eq!(size(&simplified), 211);
+        assert_eq!(simplified.0[0].exterior().0.len(), 5);
+        assert_eq!(simplified.0[1].exterior().0.len(), 6);
+    }
+
+    #[test]
+    fn levels_of_detail_are_nested() {
+        let line_string = geo_test_fixtures::louisiana::<f64>();
+        let levels = LevelOfDetail::<LineString>::new(&line_string);
+        assert_eq!(levels.len(), line_string.0.len());
+        assert_eq!(levels.min_coords(), 2);
+        assert_eq!(levels.with_coords(usize::MAX), line_string);
+        let mut previous = levels.with_coords(0);
+        assert_eq!(previous.0.len(), 2);
+        for count in 3..=line_string.0.len() {
+            let level = levels.with_coords(count);
+            assert_eq!(level.0.len(), count);
+            // Each level adds one coordinate to the one before it
+            let mut coords = level.0.iter().filter(|c| !previous.0.contains(c));
+            assert!(coords.next().is_some() && coords.next().is_none());
+            previous = level;
+        }
+        let areas = levels.effective_areas();
+        assert!(areas.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn levels_of_detail_polygons() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 5., y: 6.1), (x: 4., y: 6.)]],
+            ],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 25., y: 9.), (x: 20., y: 10.)],
+            Polygon::new(LineString::new(vec![]), vec![]),
+        ]);
+        let levels = LevelOfDetail::<MultiPolygon>::new(&multi_polygon);
+        assert_eq!(levels.len(), 18);
+        assert_eq!(levels.min_coords(), 12);
+        assert_eq!(levels.with_coords(18), multi_polygon);
+        for count in 0..=18 {
+            assert_eq!(
+                levels.with_coords(count),
+                multi_polygon.simplify_vw_to_count(count)
+            );
+        }
+        // The empty polygon is kept
+        assert_eq!(levels.with_coords(0).0.len(), 3);
+
+        let polygon = &multi_polygon.0[0];
+        let levels = LevelOfDetail::<Polygon>::new(polygon);
+        assert_eq!(levels.with_coords(9), polygon.simplify_vw_to_count(9));
+        // All but the two bumps
+        assert_eq!(levels.coords_above_area(1.), 10);
+        assert_eq!(levels.coords_above_area(f64::INFINITY), 0);
+    }
+
+    #[test]
+    fn levels_of_detail_multi_line_string() {
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.1), (x: 2., y: 0.), (x: 3., y: 0.3), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 1., y: 7.), (x: 2., y: 5.)],
+            line_string![(x: 9., y: 9.)],
+        ]);
+        let levels = LevelOfDetail::<MultiLineString>::new(&mls);
+        assert_eq!(levels.min_coords(), 5);
+        for count in 5..=9 {
+            assert_eq!(levels.with_coords(count), mls.simplify_vw_to_count(count));
+        }
+    }
 }
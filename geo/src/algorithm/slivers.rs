@@ -0,0 +1,529 @@
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, AABB};
+
+use crate::area::get_linestring_area;
+use crate::{
+    coord, Area, BoundingRect, Contains, Coord, Dissolve, DissolveMode, EuclideanLength, GeoFloat,
+    InwardOffsets, Line, LineString, MultiPolygon, Polygon, SharedArcs,
+};
+
+/// Remove the rings of polygons which enclose less than an area, such as the tiny holes and
+/// islands which overlay operations leave behind.
+///
+/// Holes which are too small are filled in, and members of a `MultiPolygon` whose exterior
+/// is too small are removed. A ring's area is the area it encloses, regardless of its
+/// winding order and of any holes in it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon, RemoveSmallRings};
+///
+/// let parcel = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 1., y: 5.), (x: 5., y: 5.), (x: 5., y: 1.)],
+///         [(x: 7., y: 7.), (x: 7., y: 7.1), (x: 7.1, y: 7.1), (x: 7.1, y: 7.)],
+///     ],
+/// ];
+/// let speck = polygon![(x: 20., y: 0.), (x: 20.1, y: 0.), (x: 20., y: 0.1)];
+///
+/// let cleaned = MultiPolygon::new(vec![parcel, speck]).remove_small_rings(1.);
+/// assert_eq!(cleaned.0.len(), 1);
+/// assert_eq!(cleaned.0[0].interiors().len(), 1);
+/// ```
+pub trait RemoveSmallRings<T: GeoFloat> {
+    /// Remove the rings which enclose less than `min_area`.
+    #[must_use]
+    fn remove_small_rings(&self, min_area: T) -> Self;
+}
+
+impl<T: GeoFloat> RemoveSmallRings<T> for Polygon<T> {
+    fn remove_small_rings(&self, min_area: T) -> Self {
+        Polygon::new(
+            self.exterior().clone(),
+            self.interiors()
+                .iter()
+                .filter(|interior| ring_area(interior) >= min_area)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> RemoveSmallRings<T> for MultiPolygon<T> {
+    fn remove_small_rings(&self, min_area: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .filter(|polygon| ring_area(polygon.exterior()) >= min_area)
+                .map(|polygon| polygon.remove_small_rings(min_area))
+                .collect(),
+        )
+    }
+}
+
+fn ring_area<T: GeoFloat>(ring: &LineString<T>) -> T {
+    get_linestring_area(&ring.0).abs()
+}
+
+/// Merge the slivers of a polygonal coverage, the polygons smaller than an area, into their
+/// largest neighbours, so that they're cleaned up without leaving gaps.
+///
+/// Neighbours are polygons which share part of their boundaries, with the same vertices along
+/// it, as in a coverage. A polygon which absorbs slivers, through neighbouring slivers too,
+/// is replaced by the polygon they make together, and the other polygons are unchanged and in
+/// the same order. Slivers without any neighbours, which have nothing to be merged into, are
+/// kept.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, MergeSlivers};
+///
+/// let fields = [
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     polygon![(x: 10., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 10.1, y: 10.)],
+///     // A sliver left between the fields by an overlay
+///     polygon![(x: 10., y: 0.), (x: 10.1, y: 10.), (x: 10., y: 10.)],
+/// ];
+///
+/// let merged = fields.merge_slivers(1.);
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!(merged[0], fields[0]);
+/// assert_relative_eq!(merged[1].unsigned_area(), 200.);
+/// ```
+pub trait MergeSlivers<T: GeoFloat> {
+    type Output;
+
+    /// Merge the polygons smaller than `min_area` into their largest neighbours.
+    fn merge_slivers(&self, min_area: T) -> Self::Output;
+}
+
+impl<T: GeoFloat> MergeSlivers<T> for [Polygon<T>] {
+    type Output = Vec<Polygon<T>>;
+
+    fn merge_slivers(&self, min_area: T) -> Self::Output {
+        merge_slivers(self, min_area)
+    }
+}
+
+impl<T: GeoFloat> MergeSlivers<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn merge_slivers(&self, min_area: T) -> Self::Output {
+        MultiPolygon::new(merge_slivers(&self.0, min_area))
+    }
+}
+
+fn merge_slivers<T: GeoFloat>(polygons: &[Polygon<T>], min_area: T) -> Vec<Polygon<T>> {
+    let areas: Vec<T> = polygons
+        .iter()
+        .map(|polygon| polygon.unsigned_area())
+        .collect();
+
+    // The polygons on either side of each arc
+    let shared = SharedArcs::new(polygons);
+    let mut arc_polygons = vec![Vec::new(); shared.arcs().len()];
+    for i in 0..shared.len() {
+        for arc_ref in shared.rings(i).iter().flatten() {
+            arc_polygons[arc_ref.index].push(i);
+        }
+    }
+
+    // Slivers join the groups of their largest neighbours, the smallest slivers first
+    let mut groups: Vec<usize> = (0..polygons.len()).collect();
+    let mut slivers: Vec<usize> = (0..polygons.len())
+        .filter(|&i| areas[i] < min_area)
+        .collect();
+    slivers.sort_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+    for sliver in slivers {
+        let largest = shared
+            .rings(sliver)
+            .iter()
+            .flatten()
+            .flat_map(|arc_ref| &arc_polygons[arc_ref.index])
+            .filter(|&&neighbour| neighbour != sliver)
+            .max_by(|&&a, &&b| areas[a].total_cmp(&areas[b]));
+        if let Some(&neighbour) = largest {
+            let (a, b) = (find(&mut groups, sliver), find(&mut groups, neighbour));
+            groups[a] = b;
+        }
+    }
+
+    // Each group is in the place of its largest polygon
+    let mut members: Vec<Vec<usize>> = vec![Vec::new(); polygons.len()];
+    for i in 0..polygons.len() {
+        let group = find(&mut groups, i);
+        members[group].push(i);
+    }
+    let mut placed: Vec<(usize, &[usize])> = members
+        .iter()
+        .filter(|members| !members.is_empty())
+        .map(|members| {
+            let largest = members
+                .iter()
+                .copied()
+                .max_by(|&a, &b| areas[a].total_cmp(&areas[b]).then(b.cmp(&a)))
+                .unwrap();
+            (largest, members.as_slice())
+        })
+        .collect();
+    placed.sort_by_key(|&(largest, _)| largest);
+
+    placed
+        .into_iter()
+        .flat_map(|(largest, members)| match members {
+            [_] => vec![polygons[largest].clone()],
+            _ => {
+                let group: Vec<Polygon<T>> = members.iter().map(|&i| polygons[i].clone()).collect();
+                group.dissolve(DissolveMode::SharedEdges).0
+            }
+        })
+        .collect()
+}
+
+/// The group of `i`, compressing the path to it along the way.
+fn find(groups: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while groups[root] != root {
+        root = groups[root];
+    }
+    let mut i = i;
+    while groups[i] != root {
+        i = core::mem::replace(&mut groups[i], root);
+    }
+    root
+}
+
+/// Fill the gaps of a polygonal coverage, the holes between neighbouring polygons narrower
+/// than a width, such as those left where digitized boundaries don't quite meet, by merging
+/// each of them into a neighbour, so that the coverage is left without gaps.
+///
+/// Gaps are the holes of the union of the coverage which vanish when offset inwards by half of
+/// the width, so long and thin holes are gaps however long they are, and holes wider than the
+/// width, such as lakes, are kept. Holes with other polygons inside them aren't gaps. Each gap
+/// is merged into the neighbour it shares the longest boundary with, measured along the edges
+/// of the neighbour which run along the gap's, so their vertices needn't match. Each polygon
+/// is replaced by exactly one polygon, with the gaps it absorbs, and the polygons are in the
+/// same order. Gaps open to the outside of the coverage are left alone.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, FillGaps};
+///
+/// let districts = [
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     // Its boundary with its neighbour was digitized a little way off
+///     polygon![
+///         (x: 10., y: 0.),
+///         (x: 20., y: 0.),
+///         (x: 20., y: 10.),
+///         (x: 10., y: 10.),
+///         (x: 10., y: 6.),
+///         (x: 10.2, y: 5.),
+///         (x: 10., y: 4.),
+///     ],
+/// ];
+///
+/// assert_eq!(districts.gaps(0.5).len(), 1);
+/// let filled = districts.fill_gaps(0.5);
+/// assert_eq!(filled[0], districts[0]);
+/// assert_relative_eq!(filled[1].unsigned_area(), 100.);
+/// ```
+pub trait FillGaps<T: GeoFloat> {
+    type Output;
+
+    /// The gaps narrower than `max_width`, as polygons.
+    fn gaps(&self, max_width: T) -> Vec<Polygon<T>>;
+
+    /// Merge the gaps narrower than `max_width` into the neighbours they share the longest
+    /// boundaries with.
+    fn fill_gaps(&self, max_width: T) -> Self::Output;
+}
+
+impl<T: GeoFloat> FillGaps<T> for [Polygon<T>] {
+    type Output = Vec<Polygon<T>>;
+
+    fn gaps(&self, max_width: T) -> Vec<Polygon<T>> {
+        gaps(self, max_width)
+    }
+
+    fn fill_gaps(&self, max_width: T) -> Self::Output {
+        fill_gaps(self, max_width)
+    }
+}
+
+impl<T: GeoFloat> FillGaps<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn gaps(&self, max_width: T) -> Vec<Polygon<T>> {
+        gaps(&self.0, max_width)
+    }
+
+    fn fill_gaps(&self, max_width: T) -> Self::Output {
+        MultiPolygon::new(fill_gaps(&self.0, max_width))
+    }
+}
+
+fn gaps<T: GeoFloat>(polygons: &[Polygon<T>], max_width: T) -> Vec<Polygon<T>> {
+    let union = polygons.dissolve(DissolveMode::Intersecting);
+    let half_width = max_width / (T::one() + T::one());
+    union
+        .iter()
+        .flat_map(|polygon| polygon.interiors())
+        .map(|interior| Polygon::new(interior.clone(), vec![]))
+        .filter(|hole| {
+            !union.iter().any(|other| {
+                other
+                    .exterior()
+                    .0
+                    .first()
+                    .is_some_and(|coord| hole.contains(coord))
+            })
+        })
+        .filter(|hole| hole.inward_offset(half_width).0.is_empty())
+        .collect()
+}
+
+fn fill_gaps<T: GeoFloat>(polygons: &[Polygon<T>], max_width: T) -> Vec<Polygon<T>> {
+    let gaps = gaps(polygons, max_width);
+    if gaps.is_empty() {
+        return polygons.to_vec();
+    }
+
+    // The edges of the polygons, to find those which run along the boundary of each gap
+    let edges = RTree::bulk_load(
+        polygons
+            .iter()
+            .enumerate()
+            .flat_map(|(i, polygon)| {
+                core::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .flat_map(LineString::lines)
+                    .map(move |line| GeomWithData::new(line, i))
+            })
+            .collect(),
+    );
+
+    // Each gap goes to the polygon it shares the longest boundary with
+    let mut absorbed: Vec<Vec<&Polygon<T>>> = vec![Vec::new(); polygons.len()];
+    for gap in &gaps {
+        let mut lengths = vec![T::zero(); polygons.len()];
+        for line in gap.exterior().lines() {
+            // Allow for the rounding of the vertices which the union computes
+            let tolerance = line.euclidean_length() * T::epsilon().sqrt();
+            let rect = line.bounding_rect();
+            let envelope = AABB::from_corners(
+                (rect.min() - coord! { x: tolerance, y: tolerance }).into(),
+                (rect.max() + coord! { x: tolerance, y: tolerance }).into(),
+            );
+            for edge in edges.locate_in_envelope_intersecting(&envelope) {
+                let length = overlap_length(line, *edge.geom(), tolerance);
+                lengths[edge.data] = lengths[edge.data] + length;
+            }
+        }
+        let longest = (0..polygons.len())
+            .filter(|&i| lengths[i] > T::zero())
+            .max_by(|&a, &b| lengths[a].total_cmp(&lengths[b]).then(b.cmp(&a)));
+        if let Some(neighbour) = longest {
+            absorbed[neighbour].push(gap);
+        }
+    }
+
+    polygons
+        .iter()
+        .zip(absorbed)
+        .map(|(polygon, gaps)| {
+            if gaps.is_empty() {
+                return polygon.clone();
+            }
+            let group: Vec<Polygon<T>> = core::iter::once(polygon).chain(gaps).cloned().collect();
+            let mut merged = group.dissolve(DissolveMode::Intersecting).0;
+            // The gaps share boundaries with the polygon, so they merge with it, unless rounding
+            // leaves them apart, in which case they're left unfilled
+            match merged.len() {
+                1 => merged.pop().unwrap(),
+                _ => {
+                    debug!("gaps didn't merge into one polygon with their neighbour");
+                    polygon.clone()
+                }
+            }
+        })
+        .collect()
+}
+
+/// The length of `line` along which `other` runs, within `tolerance` of it.
+fn overlap_length<T: GeoFloat>(line: Line<T>, other: Line<T>, tolerance: T) -> T {
+    let length = line.euclidean_length();
+    if length == T::zero() {
+        return T::zero();
+    }
+    let (dx, dy) = (line.dx() / length, line.dy() / length);
+    // The offset of a coordinate from the line through `line`, and its position along it
+    let project = |coord: Coord<T>| {
+        let (x, y) = (coord.x - line.start.x, coord.y - line.start.y);
+        (dx * y - dy * x, dx * x + dy * y)
+    };
+    let (start_offset, start) = project(other.start);
+    let (end_offset, end) = project(other.end);
+    if start_offset.abs() > tolerance || end_offset.abs() > tolerance {
+        return T::zero();
+    }
+    let overlap = start.max(end).min(length) - start.min(end).max(T::zero());
+    overlap.max(T::zero())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn small_rings() {
+        let polygon = Polygon::new(
+            square(0., 0., 10.).exterior().clone(),
+            vec![
+                square(1., 1., 1.).exterior().clone(),
+                square(3., 3., 0.5).exterior().clone(),
+            ],
+        );
+        assert_eq!(polygon.remove_small_rings(0.25), polygon);
+        assert_eq!(polygon.remove_small_rings(1.).interiors().len(), 1);
+        assert_eq!(polygon.remove_small_rings(100.).interiors().len(), 0);
+        // A polygon keeps its exterior however small
+        assert_eq!(
+            polygon.remove_small_rings(1000.).exterior(),
+            polygon.exterior()
+        );
+
+        let multi_polygon = MultiPolygon::new(vec![polygon, square(20., 0., 2.)]);
+        let cleaned = multi_polygon.remove_small_rings(5.);
+        assert_eq!(cleaned.0, vec![square(0., 0., 10.)]);
+        // The exterior's area, not the polygon's, is compared
+        assert_eq!(multi_polygon.remove_small_rings(99.9).0.len(), 1);
+    }
+
+    #[test]
+    fn chained_slivers() {
+        // A row of a large square and two slivers, and an isolated square and sliver
+        let polygons = [
+            square(0., 0., 4.),
+            polygon![(x: 4., y: 0.), (x: 4.5, y: 0.), (x: 4.5, y: 4.), (x: 4., y: 4.)],
+            polygon![(x: 4.5, y: 0.), (x: 4.6, y: 0.), (x: 4.6, y: 4.), (x: 4.5, y: 4.)],
+            square(10., 0., 3.),
+            square(20., 0., 0.5),
+        ];
+        let merged = polygons.merge_slivers(2.5);
+        assert_eq!(merged.len(), 3);
+        // The first sliver merges into the large square, and the second into the first
+        assert_relative_eq!(merged[0].unsigned_area(), 18.4);
+        assert_eq!(merged[1], polygons[3]);
+        assert_eq!(merged[2], polygons[4]);
+
+        let multi_polygon = MultiPolygon::new(polygons.to_vec());
+        assert_eq!(multi_polygon.merge_slivers(2.5).0, merged);
+        assert_eq!(multi_polygon.merge_slivers(0.1), multi_polygon);
+    }
+
+    #[test]
+    fn gaps() {
+        // Two strips along the top of a square, with a narrow wedge between them and the
+        // square, and another square apart with a wide lake
+        let polygons = [
+            polygon![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 6., y: 10.),
+                (x: 5., y: 9.7),
+                (x: 4., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            polygon![
+                (x: 0., y: 10.),
+                (x: 4., y: 10.),
+                (x: 5., y: 10.),
+                (x: 5., y: 12.),
+                (x: 0., y: 12.),
+            ],
+            polygon![
+                (x: 5., y: 10.),
+                (x: 6., y: 10.),
+                (x: 10., y: 10.),
+                (x: 10., y: 12.),
+                (x: 5., y: 12.),
+            ],
+            Polygon::new(
+                square(20., 0., 12.).exterior().clone(),
+                vec![square(22., 2., 6.).exterior().clone()],
+            ),
+        ];
+        // The wedge goes to the square, which shares more of its boundary than either strip
+        let gaps = polygons.gaps(1.);
+        assert_eq!(gaps.len(), 1);
+        assert_relative_eq!(gaps[0].unsigned_area(), 0.3, epsilon = 1e-9);
+
+        let filled = polygons.fill_gaps(1.);
+        assert_eq!(filled.len(), 4);
+        assert_relative_eq!(filled[0].unsigned_area(), 100.);
+        assert!(filled[0].interiors().is_empty());
+        assert_eq!(filled[1..], polygons[1..]);
+        let union = filled.dissolve(DissolveMode::SharedEdges);
+        assert_eq!(union.0.len(), 2);
+        assert!(union.0[0].interiors().is_empty());
+        assert_eq!(union.0[1].interiors().len(), 1);
+
+        // Nothing is narrower than a tenth
+        assert!(polygons.gaps(0.1).is_empty());
+        assert_eq!(polygons.fill_gaps(0.1), polygons);
+        let multi_polygon = MultiPolygon::new(polygons.to_vec());
+        assert_eq!(multi_polygon.fill_gaps(1.).0, filled);
+    }
+
+    #[test]
+    fn gap_without_matching_vertices() {
+        // A narrow triangle between a square and the two polygons to its right, whose vertices
+        // lie along the square's edge rather than on its vertices
+        let polygons = [
+            square(0., 0., 10.),
+            polygon![
+                (x: 10.3, y: 5.),
+                (x: 20., y: 5.),
+                (x: 20., y: 10.),
+                (x: 10., y: 10.),
+                (x: 10., y: 9.),
+            ],
+            polygon![
+                (x: 10., y: 0.),
+                (x: 20., y: 0.),
+                (x: 20., y: 5.),
+                (x: 10.3, y: 5.),
+                (x: 10., y: 1.),
+            ],
+        ];
+        let gaps = polygons.gaps(0.5);
+        assert_eq!(gaps.len(), 1);
+        assert_relative_eq!(gaps[0].unsigned_area(), 1.2, epsilon = 1e-9);
+
+        // The square's edge is the longest side of the gap
+        let filled = polygons.fill_gaps(0.5);
+        assert_eq!(filled.len(), 3);
+        assert_relative_eq!(filled[0].unsigned_area(), 101.2, epsilon = 1e-9);
+        assert!(filled[0].interiors().is_empty());
+        assert_eq!(filled[1..], polygons[1..]);
+    }
+}
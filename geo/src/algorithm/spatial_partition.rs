@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::bounding_rect::bounding_rect_merge;
+use crate::{BoundingRect, Coord, GeoFloat, Rect};
+
+/// How [`SpatialPartition`] orders geometries into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionStrategy {
+    /// Sort-tile-recursive: sort the geometries into vertical slices by the x of their centers,
+    /// and each slice into chunks by their y, so that the chunks form a grid of roughly square
+    /// tiles with the same number of geometries, as for the nodes of a bulk loaded R-tree.
+    #[default]
+    SortTileRecursive,
+    /// Sort the geometries along a Hilbert curve through their centers, and cut it into
+    /// chunks, which are less regular than those of sort-tile-recursive, but whose consecutive
+    /// chunks are next to each other, which suits a streaming or out-of-core pass.
+    Hilbert,
+}
+
+/// A chunk of a [`SpatialPartition`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialChunk<T: GeoFloat> {
+    /// The indices of the geometries in the chunk, in the order of the partitioning strategy.
+    pub members: Vec<usize>,
+    /// The bounding rectangle of the geometries in the chunk.
+    pub bounding_rect: Rect<T>,
+}
+
+/// Split a collection of geometries into chunks of nearby geometries, with at most a number of
+/// geometries in each, so that each chunk can be processed separately, such as in parallel, or
+/// one at a time from disk, with the bounding rectangle of each chunk to find those which a
+/// query or another chunk needs.
+///
+/// Each geometry is in exactly one chunk, except for empty geometries, which have no bounding
+/// rectangle, and aren't in any. Geometries are placed by the centers of their bounding
+/// rectangles, so the bounding rectangles of chunks of large geometries can overlap.
+/// Every chunk but the last of each slice, or of the curve, is full, and a `max_chunk_size` of
+/// 0 is taken as 1.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Point, PartitionStrategy, SpatialPartition};
+///
+/// // A 10 by 10 grid of points
+/// let points: Vec<Point> = (0..100)
+///     .map(|i| point!(x: (i % 10) as f64, y: (i / 10) as f64))
+///     .collect();
+///
+/// let chunks = points.spatial_partition(25, PartitionStrategy::SortTileRecursive);
+/// assert_eq!(chunks.len(), 4);
+/// for chunk in &chunks {
+///     assert_eq!(chunk.members.len(), 25);
+///     // Each chunk is a quarter of the grid
+///     assert_eq!(chunk.bounding_rect.width(), 4.);
+///     assert_eq!(chunk.bounding_rect.height(), 4.);
+/// }
+///
+/// let chunks = points.spatial_partition(25, PartitionStrategy::Hilbert);
+/// assert_eq!(chunks.len(), 4);
+/// assert_eq!(chunks[0].bounding_rect.width(), 4.);
+/// ```
+pub trait SpatialPartition<T: GeoFloat> {
+    /// Split the geometries into chunks of at most `max_chunk_size` of them, by `strategy`.
+    fn spatial_partition(
+        &self,
+        max_chunk_size: usize,
+        strategy: PartitionStrategy,
+    ) -> Vec<SpatialChunk<T>>;
+}
+
+impl<T, G> SpatialPartition<T> for [G]
+where
+    T: GeoFloat,
+    G: BoundingRect<T>,
+{
+    fn spatial_partition(
+        &self,
+        max_chunk_size: usize,
+        strategy: PartitionStrategy,
+    ) -> Vec<SpatialChunk<T>> {
+        let max_chunk_size = max_chunk_size.max(1);
+        let mut entries: Vec<Entry<T>> = self
+            .iter()
+            .enumerate()
+            .filter_map(|(index, geometry)| {
+                let rect = geometry.bounding_rect().into()?;
+                Some(Entry {
+                    index,
+                    rect,
+                    center: rect.center(),
+                })
+            })
+            .collect();
+        match strategy {
+            PartitionStrategy::SortTileRecursive => {
+                sort_tile_recursive(&mut entries, max_chunk_size)
+            }
+            PartitionStrategy::Hilbert => {
+                sort_hilbert(&mut entries);
+                entries.chunks(max_chunk_size).map(chunk).collect()
+            }
+        }
+    }
+}
+
+/// A geometry to partition.
+#[derive(Clone, Copy)]
+struct Entry<T: GeoFloat> {
+    index: usize,
+    rect: Rect<T>,
+    center: Coord<T>,
+}
+
+fn chunk<T: GeoFloat>(entries: &[Entry<T>]) -> SpatialChunk<T> {
+    // Chunks are never empty
+    let bounding_rect = entries[1..].iter().fold(entries[0].rect, |rect, entry| {
+        bounding_rect_merge(rect, entry.rect)
+    });
+    SpatialChunk {
+        members: entries.iter().map(|entry| entry.index).collect(),
+        bounding_rect,
+    }
+}
+
+fn sort_tile_recursive<T: GeoFloat>(
+    entries: &mut [Entry<T>],
+    max_chunk_size: usize,
+) -> Vec<SpatialChunk<T>> {
+    // Rounded up without overflowing, as `usize::div_ceil` is newer than the minimum Rust version
+    let chunks = entries.len() / max_chunk_size + usize::from(entries.len() % max_chunk_size != 0);
+    // The number of slices, and of chunks in each, is the square root of the number of chunks
+    let slices = Float::ceil(Float::sqrt(chunks as f64)) as usize;
+    let slice_size = slices.max(1).saturating_mul(max_chunk_size);
+
+    entries.sort_by(|a, b| a.center.x.total_cmp(&b.center.x));
+    let mut result = Vec::with_capacity(chunks);
+    for slice in entries.chunks_mut(slice_size) {
+        slice.sort_by(|a, b| a.center.y.total_cmp(&b.center.y));
+        result.extend(slice.chunks(max_chunk_size).map(chunk));
+    }
+    result
+}
+
+/// The number of bits of each coordinate of the grid the Hilbert curve is computed on.
+const HILBERT_ORDER: u32 = 16;
+
+fn sort_hilbert<T: GeoFloat>(entries: &mut [Entry<T>]) {
+    let Some(first) = entries.first() else {
+        return;
+    };
+    let extent = entries[1..]
+        .iter()
+        .fold(Rect::new(first.center, first.center), |rect, entry| {
+            bounding_rect_merge(rect, Rect::new(entry.center, entry.center))
+        });
+    let cells = T::from((1_u32 << HILBERT_ORDER) - 1).unwrap();
+    let to_cell = |value: T, min: T, size: T| {
+        if size > T::zero() {
+            ((value - min) / size * cells).to_u32().unwrap_or(0)
+        } else {
+            0
+        }
+    };
+    let min = extent.min();
+    let (width, height) = (extent.width(), extent.height());
+    // Sorting by a cached key, which is stable, keeps geometries with the same center in order
+    entries.sort_by_cached_key(|entry| {
+        hilbert_index(
+            to_cell(entry.center.x, min.x, width),
+            to_cell(entry.center.y, min.y, height),
+        )
+    });
+}
+
+/// The distance along the Hilbert curve of order [`HILBERT_ORDER`] of the cell at `x` and `y`.
+fn hilbert_index(mut x: u32, mut y: u32) -> u64 {
+    let side = 1_u32 << HILBERT_ORDER;
+    let mut index = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let rx = u32::from(x & s > 0);
+        let ry = u32::from(y & s > 0);
+        index += u64::from(s) * u64::from(s) * u64::from((3 * rx) ^ ry);
+        // Rotate the quadrant, so that the curve through it starts and ends next to its
+        // neighbours
+        if ry == 0 {
+            if rx == 1 {
+                x = side - 1 - x;
+                y = side - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    index
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, Intersects, LineString, Point};
+
+    #[test]
+    fn every_geometry_in_one_chunk() {
+        // Points from a linear congruential generator
+        let mut state = 7_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 10_000) as f64 / 100.
+        };
+        let points: Vec<Point> = (0..1000).map(|_| point!(x: next(), y: next())).collect();
+
+        for strategy in [
+            PartitionStrategy::SortTileRecursive,
+            PartitionStrategy::Hilbert,
+        ] {
+            let chunks = points.spatial_partition(64, strategy);
+            assert_eq!(chunks.len(), 16, "{strategy:?}");
+            let mut members: Vec<usize> = chunks
+                .iter()
+                .flat_map(|chunk| chunk.members.iter().copied())
+                .collect();
+            members.sort_unstable();
+            assert_eq!(members, (0..1000).collect::<Vec<_>>());
+            for chunk in &chunks {
+                assert!(chunk.members.len() <= 64);
+                for &member in &chunk.members {
+                    assert!(chunk.bounding_rect.intersects(&points[member]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hilbert_curve() {
+        // The curve of order 1 visits the cells clockwise from the bottom left
+        let cells = [(0, 0), (0, 1), (1, 1), (1, 0)]
+            .map(|(x, y)| hilbert_index(x << (HILBERT_ORDER - 1), y << (HILBERT_ORDER - 1)));
+        assert!(cells.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(hilbert_index(0, 0), 0);
+
+        // Consecutive cells along the curve are neighbours
+        let side = 1 << HILBERT_ORDER;
+        let mut path: Vec<(u64, (u32, u32))> = (0..16)
+            .flat_map(|x| (0..16).map(move |y| (x * side / 16, y * side / 16)))
+            .map(|(x, y)| (hilbert_index(x, y), (x, y)))
+            .collect();
+        path.sort_unstable();
+        assert!(path.windows(2).all(|pair| {
+            let ((_, a), (_, b)) = (pair[0], pair[1]);
+            a.0.abs_diff(b.0) + a.1.abs_diff(b.1) == side / 16
+        }));
+    }
+
+    #[test]
+    fn empty_and_degenerate() {
+        let lines: Vec<LineString> = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            LineString::new(vec![]),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+        ];
+        for strategy in [
+            PartitionStrategy::SortTileRecursive,
+            PartitionStrategy::Hilbert,
+        ] {
+            let chunks = lines.spatial_partition(0, strategy);
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks[0].members, [0]);
+            assert_eq!(chunks[1].members, [2]);
+        }
+        for strategy in [
+            PartitionStrategy::SortTileRecursive,
+            PartitionStrategy::Hilbert,
+        ] {
+            let chunks = lines.spatial_partition(usize::MAX, strategy);
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks[0].members, [0, 2]);
+        }
+        let none: [Point; 0] = [];
+        assert!(none
+            .spatial_partition(10, PartitionStrategy::Hilbert)
+            .is_empty());
+    }
+}
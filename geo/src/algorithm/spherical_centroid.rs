@@ -0,0 +1,403 @@
+use crate::algorithm::nvector::NVector;
+use crate::{
+    ChamberlainDuquetteArea, Contains, CoordFloat, CoordsIter, GeoFloat, InteriorPoint, LineString,
+    MapCoords, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Winding,
+};
+
+/// Calculation of the centroid of a geometry on the surface of a sphere.
+///
+/// Coordinates are longitude (`x`) and latitude (`y`) in degrees, and edges are great-circle
+/// arcs. The centroid is the direction of the mean position, in 3D, of the geometry's surface
+/// (for polygons), arcs (for lines) or points, projected back onto the sphere. Unlike the planar
+/// [`Centroid`](crate::Centroid), it's unaffected by the distortion of longitude and latitude
+/// away from the equator.
+///
+/// Polygons are taken to have counter-clockwise exteriors and clockwise interiors, when seen in
+/// longitude and latitude, and are reoriented if necessary.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, polygon, Centroid, SphericalCentroid};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 60.),
+///     (x: 90., y: 60.),
+///     (x: 90., y: 70.),
+///     (x: 0., y: 70.),
+/// ];
+///
+/// // The great-circle edges bulge towards the pole
+/// let centroid = polygon.spherical_centroid().unwrap();
+/// assert_relative_eq!(centroid.x(), 45., epsilon = 1e-9);
+/// assert!(centroid.y() > 67.);
+/// assert!(polygon.centroid().unwrap().y() < 65.1);
+/// ```
+pub trait SphericalCentroid<T: CoordFloat> {
+    type Output;
+
+    /// The centroid of `self` on the sphere.
+    ///
+    /// Returns `None` if `self` is empty, or if its mean position is the center of the sphere, as
+    /// with a pair of antipodal points.
+    fn spherical_centroid(&self) -> Self::Output;
+}
+
+/// Calculation of a point inside a polygon on the surface of a sphere.
+///
+/// Coordinates are longitude (`x`) and latitude (`y`) in degrees, and edges are great-circle
+/// arcs. The [spherical centroid](SphericalCentroid) is returned when it's inside the polygon,
+/// and otherwise a point which is placed as centrally as [`InteriorPoint`] allows.
+///
+/// The point is guaranteed to be inside polygons which are within a hemisphere. Larger polygons
+/// fall back to the planar [`InteriorPoint`] of their longitudes and latitudes.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, SphericalCentroid, SphericalInteriorPoint};
+///
+/// // A crescent, whose centroid is outside it
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+///     (x: 0., y: 8.),
+///     (x: 8., y: 8.),
+///     (x: 8., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+///
+/// let interior_point = polygon.spherical_interior_point().unwrap();
+/// assert!(interior_point.x() > 8. && interior_point.x() < 10.);
+/// ```
+pub trait SphericalInteriorPoint<T: GeoFloat> {
+    /// A point inside `self` on the sphere, or `None` if `self` is empty.
+    fn spherical_interior_point(&self) -> Option<Point<T>>;
+}
+
+impl<T: CoordFloat> SphericalCentroid<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl<T: CoordFloat> SphericalCentroid<T> for MultiPoint<T> {
+    type Output = Option<Point<T>>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        let sum = self
+            .iter()
+            .fold(zero(), |sum, point| sum + NVector::from_point(*point));
+        Some(sum.normalize()?.to_point())
+    }
+}
+
+impl<T: CoordFloat> SphericalCentroid<T> for LineString<T> {
+    type Output = Option<Point<T>>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        if self.0.len() == 1 {
+            return Some(Point::from(self.0[0]));
+        }
+        Some(line_string_moment(self).normalize()?.to_point())
+    }
+}
+
+impl<T: CoordFloat> SphericalCentroid<T> for MultiLineString<T> {
+    type Output = Option<Point<T>>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        let sum = self.iter().fold(zero(), |sum, line_string| {
+            sum + line_string_moment(line_string)
+        });
+        Some(sum.normalize()?.to_point())
+    }
+}
+
+impl<T: GeoFloat> SphericalCentroid<T> for Polygon<T> {
+    type Output = Option<Point<T>>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        Some(polygon_moment(self).normalize()?.to_point())
+    }
+}
+
+impl<T: GeoFloat> SphericalCentroid<T> for MultiPolygon<T> {
+    type Output = Option<Point<T>>;
+
+    fn spherical_centroid(&self) -> Self::Output {
+        let sum = self
+            .iter()
+            .fold(zero(), |sum, polygon| sum + polygon_moment(polygon));
+        Some(sum.normalize()?.to_point())
+    }
+}
+
+impl<T: GeoFloat> SphericalInteriorPoint<T> for Polygon<T> {
+    fn spherical_interior_point(&self) -> Option<Point<T>> {
+        let centroid = match self.spherical_centroid() {
+            Some(centroid) => centroid,
+            None => return self.interior_point(),
+        };
+
+        // The gnomonic projection maps great circles to straight lines, so planar operations on
+        // the projected polygon are exact on the sphere.
+        let projection = Gnomonic::new(centroid);
+        let projected = match projection.forward_polygon(self) {
+            Some(projected) => projected,
+            None => return self.interior_point(),
+        };
+        if projected.contains(&Point::new(T::zero(), T::zero())) {
+            return Some(centroid);
+        }
+        projected
+            .interior_point()
+            .map(|point| projection.inverse(point))
+    }
+}
+
+impl<T: GeoFloat> SphericalInteriorPoint<T> for MultiPolygon<T> {
+    /// The spherical centroid if it's inside one of the polygons, and otherwise the interior
+    /// point of the polygon with the largest area.
+    fn spherical_interior_point(&self) -> Option<Point<T>> {
+        if let Some(centroid) = self.spherical_centroid() {
+            let projection = Gnomonic::new(centroid);
+            let origin = Point::new(T::zero(), T::zero());
+            let contains_centroid = self.iter().any(|polygon| {
+                projection
+                    .forward_polygon(polygon)
+                    .is_some_and(|projected| projected.contains(&origin))
+            });
+            if contains_centroid {
+                return Some(centroid);
+            }
+        }
+        self.iter()
+            .max_by(|a, b| {
+                a.chamberlain_duquette_unsigned_area()
+                    .total_cmp(&b.chamberlain_duquette_unsigned_area())
+            })?
+            .spherical_interior_point()
+    }
+}
+
+fn zero<T: CoordFloat>() -> NVector<T> {
+    NVector::new(T::zero(), T::zero(), T::zero())
+}
+
+/// The integral of the position over the arcs of `line_string`, on the unit sphere.
+fn line_string_moment<T: CoordFloat>(line_string: &LineString<T>) -> NVector<T> {
+    let two = T::one() + T::one();
+    line_string.lines().fold(zero(), |sum, line| {
+        let start = NVector::from_point(line.start_point());
+        let end = NVector::from_point(line.end_point());
+        match (start + end).normalize() {
+            // The integral over an arc of angle θ points to its midpoint, with magnitude
+            // 2 sin(θ / 2).
+            Some(midpoint) => sum + midpoint * (two * (start.angle_to(end) / two).sin()),
+            None => sum,
+        }
+    })
+}
+
+/// The integral of the position over the surface of `polygon`, on the unit sphere.
+fn polygon_moment<T: GeoFloat>(polygon: &Polygon<T>) -> NVector<T> {
+    let exterior = ring_moment(polygon.exterior());
+    let exterior = if polygon.exterior().is_cw() {
+        -exterior
+    } else {
+        exterior
+    };
+    polygon.interiors().iter().fold(exterior, |sum, interior| {
+        let moment = ring_moment(interior);
+        if interior.is_cw() {
+            sum + moment
+        } else {
+            sum - moment
+        }
+    })
+}
+
+/// The integral of the position over the area to the left of the closed `ring`.
+///
+/// By the divergence theorem, this is half the sum, over the ring's arcs, of each arc's angle
+/// times the unit normal of its great circle.
+fn ring_moment<T: CoordFloat>(ring: &LineString<T>) -> NVector<T> {
+    let two = T::one() + T::one();
+    ring.lines().fold(zero(), |sum, line| {
+        let start = NVector::from_point(line.start_point());
+        let end = NVector::from_point(line.end_point());
+        let normal = start.cross(end);
+        let norm = normal.norm();
+        if norm == T::zero() {
+            return sum;
+        }
+        sum + normal * (start.angle_to(end) / (two * norm))
+    })
+}
+
+/// The gnomonic projection centered on a point, whose plane is tangent to the unit sphere there.
+struct Gnomonic<T: CoordFloat> {
+    center: NVector<T>,
+    east: NVector<T>,
+    north: NVector<T>,
+}
+
+impl<T: CoordFloat> Gnomonic<T> {
+    fn new(center: Point<T>) -> Self {
+        let center = NVector::from_point(center);
+        let pole = NVector::new(T::zero(), T::zero(), T::one());
+        let east = pole
+            .cross(center)
+            .normalize()
+            .unwrap_or_else(|| NVector::new(T::zero(), T::one(), T::zero()));
+        let north = center.cross(east);
+        Gnomonic {
+            center,
+            east,
+            north,
+        }
+    }
+
+    /// Project `polygon`, or return `None` if it isn't within the hemisphere around the center.
+    fn forward_polygon(&self, polygon: &Polygon<T>) -> Option<Polygon<T>> {
+        let in_hemisphere = polygon
+            .coords_iter()
+            .all(|coord| NVector::from_point(coord.into()).dot(self.center) > T::epsilon());
+        if !in_hemisphere {
+            return None;
+        }
+        Some(polygon.map_coords(|coord| {
+            let position = NVector::from_point(coord.into());
+            let height = position.dot(self.center);
+            crate::coord! {
+                x: position.dot(self.east) / height,
+                y: position.dot(self.north) / height,
+            }
+        }))
+    }
+
+    fn inverse(&self, point: Point<T>) -> Point<T> {
+        (self.center + self.east * point.x() + self.north * point.y())
+            .normalize()
+            .expect("the tangent plane doesn't pass through the center of the sphere")
+            .to_point()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Centroid};
+
+    #[test]
+    fn small_polygon_matches_planar_centroid() {
+        let polygon = polygon![
+            (x: 10., y: 20.),
+            (x: 10.01, y: 20.),
+            (x: 10.01, y: 20.01),
+            (x: 10., y: 20.01),
+        ];
+        assert_relative_eq!(
+            polygon.spherical_centroid().unwrap(),
+            polygon.centroid().unwrap(),
+            epsilon = 1e-6
+        );
+
+        // Winding is normalized.
+        let mut reversed = polygon.clone();
+        reversed.exterior_mut(|exterior| exterior.0.reverse());
+        assert_relative_eq!(
+            reversed.spherical_centroid().unwrap(),
+            polygon.spherical_centroid().unwrap(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn octant() {
+        let octant = polygon![(x: 0., y: 0.), (x: 90., y: 0.), (x: 0., y: 90.)];
+        let centroid = octant.spherical_centroid().unwrap();
+        // By symmetry, the centroid is equidistant from the three axes.
+        let expected = NVector::new(1., 1., 1.).normalize().unwrap().to_point();
+        assert_relative_eq!(centroid, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn hole_shifts_centroid() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 10., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 10., y: 10.)]],
+        ];
+        let centroid = polygon.spherical_centroid().unwrap();
+        assert!(centroid.x() > 4. && centroid.x() < 6., "{centroid:?}");
+
+        let symmetric = polygon![
+            exterior: [(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)],
+            interiors: [[(x: -5., y: -5.), (x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: -5.)]],
+        ];
+        assert_relative_eq!(
+            symmetric.spherical_centroid().unwrap(),
+            point!(x: 0., y: 0.),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn lines_and_points() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 90., y: 0.)];
+        assert_relative_eq!(
+            line_string.spherical_centroid().unwrap(),
+            point!(x: 45., y: 0.),
+            epsilon = 1e-12
+        );
+
+        // Weighted by arc length
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 30., y: 0.), (x: 31., y: 0.)],
+        ]);
+        let centroid = multi_line_string.spherical_centroid().unwrap();
+        assert!(centroid.x() > 5. && centroid.x() < 10.);
+
+        let multi_point = MultiPoint::new(vec![point!(x: 170., y: 0.), point!(x: -170., y: 0.)]);
+        assert_relative_eq!(
+            multi_point.spherical_centroid().unwrap(),
+            point!(x: 180., y: 0.),
+            epsilon = 1e-12
+        );
+        let antipodal = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 180., y: 0.)]);
+        assert!(antipodal.spherical_centroid().is_none());
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .spherical_centroid()
+            .is_none());
+    }
+
+    #[test]
+    fn interior_point() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(
+            square.spherical_interior_point(),
+            square.spherical_centroid()
+        );
+
+        let ring = polygon![
+            exterior: [(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)],
+            interiors: [[(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)]],
+        ];
+        let interior_point = ring.spherical_interior_point().unwrap();
+        assert!(ring.contains(&interior_point));
+
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)],
+        ]);
+        let interior_point = multi_polygon.spherical_interior_point().unwrap();
+        assert!(multi_polygon.0[1].contains(&interior_point));
+    }
+}
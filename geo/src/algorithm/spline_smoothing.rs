@@ -0,0 +1,379 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::FromPrimitive;
+
+use crate::angles::turn_angle;
+use crate::{Coord, CoordFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// The maximum number of times a spline segment is halved while sampling it.
+const MAX_DEPTH: usize = 16;
+
+/// Parameters of [`SplineSmoothing`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplineSmoothingConfig<T> {
+    /// The maximum distance between the sampled curve and the sampled `LineString`. Smaller values
+    /// give more points.
+    pub tolerance: T,
+    /// Vertices at which the `LineString` turns by more than this angle, in degrees, are kept as
+    /// sharp corners, rather than smoothed. `None` smooths every vertex.
+    pub corner_angle: Option<T>,
+}
+
+impl<T: CoordFloat> SplineSmoothingConfig<T> {
+    /// Smooth every vertex, sampling the curve within `tolerance`.
+    pub fn new(tolerance: T) -> Self {
+        Self {
+            tolerance,
+            corner_angle: None,
+        }
+    }
+}
+
+/// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` by fitting a
+/// [centripetal Catmull-Rom spline](https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline)
+/// through their vertices.
+///
+/// Unlike [`ChaikinSmoothing`](crate::ChaikinSmoothing), the smoothed curve passes through every
+/// vertex of the geometry, and its direction changes continuously at them. The curve is sampled
+/// adaptively, with more points where it bends more, so that no part of it is further than
+/// `tolerance` from the output.
+///
+/// The start and end vertices of an open linestring are preserved, and a closed linestring is
+/// smoothed through its closing vertex. Vertices sharper than the `corner_angle` of the
+/// [`SplineSmoothingConfig`] are preserved as corners: the spline is split at them, and each part
+/// is smoothed like an open linestring.
+///
+/// A `tolerance` less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, SplineSmoothing, SplineSmoothingConfig};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 3., y: 1.)];
+///
+/// let smooth = line_string.spline_smoothing(&SplineSmoothingConfig::new(0.01));
+/// assert_eq!(smooth.0.first(), line_string.0.first());
+/// assert_eq!(smooth.0.last(), line_string.0.last());
+/// assert!(smooth.0.contains(&line_string.0[1]));
+/// assert!(smooth.0.len() > line_string.0.len());
+///
+/// // The corners turn by 90°, so they're kept
+/// let config = SplineSmoothingConfig {
+///     tolerance: 0.01,
+///     corner_angle: Some(60.),
+/// };
+/// assert_eq!(line_string.spline_smoothing(&config), line_string);
+/// ```
+pub trait SplineSmoothing<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    /// Create a new geometry, smoothed with a spline.
+    fn spline_smoothing(&self, config: &SplineSmoothingConfig<T>) -> Self;
+}
+
+impl<T> SplineSmoothing<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn spline_smoothing(&self, config: &SplineSmoothingConfig<T>) -> Self {
+        if config.tolerance <= T::zero() {
+            return self.clone();
+        }
+        let mut coords = self.0.clone();
+        coords.dedup();
+        if coords.len() < 3 {
+            return coords.into();
+        }
+
+        let is_corner =
+            |prev: Coord<T>, current: Coord<T>, next: Coord<T>| match config.corner_angle {
+                Some(corner_angle) => turn_angle(prev, current, next).abs() > corner_angle,
+                None => false,
+            };
+
+        if !self.is_closed() {
+            let mut out = vec![coords[0]];
+            let mut start = 0;
+            for i in 1..coords.len() - 1 {
+                if is_corner(coords[i - 1], coords[i], coords[i + 1]) {
+                    smooth_open(&coords[start..=i], config.tolerance, &mut out);
+                    start = i;
+                }
+            }
+            smooth_open(&coords[start..], config.tolerance, &mut out);
+            return out.into();
+        }
+
+        // Without the closing vertex
+        coords.pop();
+        let n = coords.len();
+        let corners: Vec<usize> = (0..n)
+            .filter(|&i| is_corner(coords[(i + n - 1) % n], coords[i], coords[(i + 1) % n]))
+            .collect();
+        let mut out = Vec::new();
+        match corners.first() {
+            None => {
+                out.push(coords[0]);
+                for i in 0..n {
+                    let segment = [
+                        coords[(i + n - 1) % n],
+                        coords[i],
+                        coords[(i + 1) % n],
+                        coords[(i + 2) % n],
+                    ];
+                    sample_segment(segment, config.tolerance, &mut out);
+                }
+            }
+            Some(&first) => {
+                // Start from a corner, so that the ring is a sequence of open parts
+                coords.rotate_left(first);
+                coords.push(coords[0]);
+                out.push(coords[0]);
+                let mut start = 0;
+                for i in corners
+                    .iter()
+                    .skip(1)
+                    .map(|corner| corner - first)
+                    .chain([n])
+                {
+                    smooth_open(&coords[start..=i], config.tolerance, &mut out);
+                    start = i;
+                }
+            }
+        }
+        out.into()
+    }
+}
+
+impl<T> SplineSmoothing<T> for MultiLineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn spline_smoothing(&self, config: &SplineSmoothingConfig<T>) -> Self {
+        MultiLineString::new(
+            self.0
+                .iter()
+                .map(|ls| ls.spline_smoothing(config))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SplineSmoothing<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn spline_smoothing(&self, config: &SplineSmoothingConfig<T>) -> Self {
+        Polygon::new(
+            self.exterior().spline_smoothing(config),
+            self.interiors()
+                .iter()
+                .map(|ls| ls.spline_smoothing(config))
+                .collect(),
+        )
+    }
+}
+
+impl<T> SplineSmoothing<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn spline_smoothing(&self, config: &SplineSmoothingConfig<T>) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|poly| poly.spline_smoothing(config))
+                .collect(),
+        )
+    }
+}
+
+/// Append the samples of the spline through the open `coords`, except the first one, to `out`.
+fn smooth_open<T>(coords: &[Coord<T>], tolerance: T, out: &mut Vec<Coord<T>>)
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let n = coords.len();
+    if n == 2 {
+        out.push(coords[1]);
+        return;
+    }
+    // Mirror the neighbours of the ends, so that the spline heads straight for them
+    let two = T::from(2).unwrap();
+    let before = coords[1] - (coords[1] - coords[0]) * two;
+    let after = coords[n - 2] + (coords[n - 1] - coords[n - 2]) * two;
+    for i in 0..n - 1 {
+        let segment = [
+            if i == 0 { before } else { coords[i - 1] },
+            coords[i],
+            coords[i + 1],
+            if i + 2 == n { after } else { coords[i + 2] },
+        ];
+        sample_segment(segment, tolerance, out);
+    }
+}
+
+/// Append the samples of the spline segment between the middle two of `points`, except its
+/// start, to `out`.
+fn sample_segment<T>(points: [Coord<T>; 4], tolerance: T, out: &mut Vec<Coord<T>>)
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let half = T::from(0.5).unwrap();
+    let mut knots = [T::zero(); 4];
+    for i in 1..4 {
+        let d = points[i] - points[i - 1];
+        knots[i] = knots[i - 1] + d.x.hypot(d.y).sqrt();
+    }
+    let spline = |t: T| evaluate(&points, &knots, t);
+
+    // Halve the parameter range until three points along each part are within tolerance of its
+    // chord
+    let mut stack = vec![(knots[2], points[2], 0)];
+    let (mut t0, mut c0) = (knots[1], points[1]);
+    while let Some(&(t1, c1, depth)) = stack.last() {
+        let within = depth >= MAX_DEPTH
+            || [0.25, 0.5, 0.75].iter().all(|&f| {
+                let f = T::from(f).unwrap();
+                let c = spline(t0 + (t1 - t0) * f);
+                segment_distance(c, Line::new(c0, c1)) <= tolerance
+            });
+        if within {
+            out.push(c1);
+            stack.pop();
+            (t0, c0) = (t1, c1);
+        } else {
+            let t = (t0 + t1) * half;
+            stack.push((t, spline(t), depth + 1));
+        }
+    }
+}
+
+/// The point at `t` of the centripetal Catmull-Rom segment with control `points` and `knots`,
+/// using the Barry and Goldman pyramidal formulation.
+fn evaluate<T: CoordFloat>(points: &[Coord<T>; 4], knots: &[T; 4], t: T) -> Coord<T> {
+    let lerp = |a: Coord<T>, b: Coord<T>, t0: T, t1: T| {
+        let f = (t - t0) / (t1 - t0);
+        a + (b - a) * f
+    };
+    let [p0, p1, p2, p3] = *points;
+    let [t0, t1, t2, t3] = *knots;
+    let a1 = lerp(p0, p1, t0, t1);
+    let a2 = lerp(p1, p2, t1, t2);
+    let a3 = lerp(p2, p3, t2, t3);
+    let b1 = lerp(a1, a2, t0, t2);
+    let b2 = lerp(a2, a3, t1, t3);
+    lerp(b1, b2, t1, t2)
+}
+
+fn segment_distance<T: CoordFloat>(c: Coord<T>, line: Line<T>) -> T {
+    let d = line.delta();
+    let length_squared = d.x * d.x + d.y * d.y;
+    let closest = if length_squared == T::zero() {
+        line.start
+    } else {
+        let f = ((c - line.start).x * d.x + (c - line.start).y * d.y) / length_squared;
+        line.start + d * f.max(T::zero()).min(T::one())
+    };
+    let offset = c - closest;
+    offset.x.hypot(offset.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, EuclideanDistance, EuclideanLength};
+
+    fn config(tolerance: f64) -> SplineSmoothingConfig<f64> {
+        SplineSmoothingConfig::new(tolerance)
+    }
+
+    #[test]
+    fn passes_through_vertices() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 1.),
+            (x: 3., y: 4.),
+            (x: 6., y: 4.),
+            (x: 7., y: 0.),
+        ];
+        let smooth = line_string.spline_smoothing(&config(0.01));
+        for coord in &line_string.0 {
+            assert!(smooth.0.contains(coord));
+        }
+        assert_eq!(smooth.0.first(), line_string.0.first());
+        assert_eq!(smooth.0.last(), line_string.0.last());
+        assert!(smooth.euclidean_length() > line_string.euclidean_length());
+    }
+
+    #[test]
+    fn straight_line() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_eq!(line_string.spline_smoothing(&config(0.01)), line_string);
+    }
+
+    #[test]
+    fn tolerance() {
+        // The vertices of a square, whose smoothed curve is close to a circle
+        let square = line_string![
+            (x: 1., y: 0.),
+            (x: 0., y: 1.),
+            (x: -1., y: 0.),
+            (x: 0., y: -1.),
+            (x: 1., y: 0.),
+        ];
+        let coarse = square.spline_smoothing(&config(0.1));
+        let fine = square.spline_smoothing(&config(0.001));
+        assert!(coarse.is_closed());
+        assert!(fine.is_closed());
+        assert!(fine.0.len() > coarse.0.len() * 3);
+
+        // The samples of the coarse curve are on the fine one
+        for coord in &coarse.0 {
+            assert!(fine.euclidean_distance(&crate::Point::from(*coord)) < 0.001);
+        }
+        // The curve bulges out from the square, towards its circumcircle
+        for coord in &fine.0 {
+            assert!(coord.x.abs() + coord.y.abs() > 1. - 1e-9);
+            assert!(coord.x.hypot(coord.y) < 1. + 1e-9);
+        }
+    }
+
+    #[test]
+    fn corners() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 6., y: 12.),
+            (x: 4., y: 12.),
+            (x: 0., y: 10.),
+        ];
+        let config = SplineSmoothingConfig {
+            tolerance: 0.01,
+            corner_angle: Some(60.),
+        };
+        let smooth = polygon.spline_smoothing(&config);
+        let exterior = &smooth.exterior().0;
+        assert!(smooth.exterior().is_closed());
+        // The bottom edge is between corners, so is kept straight
+        let bottom = exterior
+            .windows(2)
+            .position(|w| w == [(0., 0.).into(), (10., 0.).into()])
+            .or_else(|| {
+                exterior
+                    .windows(2)
+                    .position(|w| w == [(10., 0.).into(), (0., 0.).into()])
+            });
+        assert!(bottom.is_some());
+        assert!(exterior.len() > polygon.exterior().0.len());
+    }
+
+    #[test]
+    fn no_tolerance() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        assert_eq!(line_string.spline_smoothing(&config(0.)), line_string);
+    }
+}
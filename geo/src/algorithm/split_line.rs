@@ -0,0 +1,233 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Coord, GeoFloat, LineString, MultiLineString, Point};
+
+/// Cut a `LineString` into pieces, at distances along it or at points on it, such as mileposts
+/// along a road or its intersections with other roads.
+///
+/// The pieces are returned in order along the line, each starting where the last one ended.
+/// Cuts at the ends of the line, or beyond them, are ignored, as are repeated cuts, so there
+/// are no empty pieces. An empty `LineString` has no pieces.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, SplitLine};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// let pieces = road.split_at_distances(&[5., 15.]);
+/// assert_eq!(pieces.0, vec![
+///     line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+///     line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)],
+///     line_string![(x: 10., y: 5.), (x: 10., y: 10.)],
+/// ]);
+///
+/// // An intersection just off the road, and another too far from it to count
+/// let intersections = [point!(x: 10.1, y: 2.), point!(x: 3., y: 3.)];
+/// let pieces = road.split_at_points(&intersections, 0.5);
+/// assert_eq!(pieces.0, vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 2.)],
+///     line_string![(x: 10., y: 2.), (x: 10., y: 10.)],
+/// ]);
+/// ```
+pub trait SplitLine<T: GeoFloat> {
+    /// Cut the line at each of `distances` along it from its start, in the units of its
+    /// coordinates.
+    fn split_at_distances(&self, distances: &[T]) -> MultiLineString<T>;
+
+    /// Cut the line at each of `points` within `tolerance` of it, where it's closest to the
+    /// point.
+    fn split_at_points(&self, points: &[Point<T>], tolerance: T) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> SplitLine<T> for LineString<T> {
+    fn split_at_distances(&self, distances: &[T]) -> MultiLineString<T> {
+        let measured = Measured::new(self);
+        let cuts = distances
+            .iter()
+            .filter_map(|&distance| Some((distance, measured.coord_at(distance)?)))
+            .collect();
+        measured.split(cuts)
+    }
+
+    fn split_at_points(&self, points: &[Point<T>], tolerance: T) -> MultiLineString<T> {
+        let measured = Measured::new(self);
+        let cuts = points
+            .iter()
+            .filter_map(|point| {
+                let (distance, along, coord) = measured.closest(point.0)?;
+                (distance <= tolerance).then_some((along, coord))
+            })
+            .collect();
+        measured.split(cuts)
+    }
+}
+
+/// A `LineString` with the distance along it to each of its coordinates.
+struct Measured<'a, T: GeoFloat> {
+    line_string: &'a LineString<T>,
+    distances: Vec<T>,
+}
+
+impl<'a, T: GeoFloat> Measured<'a, T> {
+    fn new(line_string: &'a LineString<T>) -> Self {
+        let mut distances = Vec::with_capacity(line_string.0.len());
+        let mut total = T::zero();
+        for (i, coord) in line_string.0.iter().enumerate() {
+            if i > 0 {
+                total = total + distance(line_string.0[i - 1], *coord);
+            }
+            distances.push(total);
+        }
+        Measured {
+            line_string,
+            distances,
+        }
+    }
+
+    fn length(&self) -> T {
+        self.distances.last().copied().unwrap_or_else(T::zero)
+    }
+
+    /// The coordinate at `along` the line, if it's between its ends.
+    fn coord_at(&self, along: T) -> Option<Coord<T>> {
+        if along <= T::zero() || along >= self.length() {
+            return None;
+        }
+        // The first line which ends beyond `along`
+        let i = self.distances.partition_point(|&d| d <= along);
+        let (start, end) = (self.line_string.0[i - 1], self.line_string.0[i]);
+        let fraction =
+            (along - self.distances[i - 1]) / (self.distances[i] - self.distances[i - 1]);
+        Some(start + (end - start) * fraction)
+    }
+
+    /// The distance from `coord` to the closest point on the line, the distance along the line
+    /// to that point, and the point itself.
+    fn closest(&self, coord: Coord<T>) -> Option<(T, T, Coord<T>)> {
+        let coords = &self.line_string.0;
+        let mut closest = coords
+            .first()
+            .map(|&first| (distance(first, coord), T::zero(), first))?;
+        for i in 1..coords.len() {
+            let (start, end) = (coords[i - 1], coords[i]);
+            let delta = end - start;
+            let length_squared = delta.x * delta.x + delta.y * delta.y;
+            if length_squared == T::zero() {
+                continue;
+            }
+            let offset = coord - start;
+            let fraction = ((offset.x * delta.x + offset.y * delta.y) / length_squared)
+                .max(T::zero())
+                .min(T::one());
+            let on_line = start + delta * fraction;
+            let d = distance(on_line, coord);
+            if d < closest.0 {
+                let along =
+                    self.distances[i - 1] + (self.distances[i] - self.distances[i - 1]) * fraction;
+                closest = (d, along, on_line);
+            }
+        }
+        Some(closest)
+    }
+
+    /// Cut the line at `cuts`, each of which is a distance along the line and the coordinate
+    /// there.
+    fn split(&self, mut cuts: Vec<(T, Coord<T>)>) -> MultiLineString<T> {
+        let coords = &self.line_string.0;
+        if coords.is_empty() {
+            return MultiLineString::new(vec![]);
+        }
+        let length = self.length();
+        cuts.retain(|&(along, _)| along > T::zero() && along < length);
+        cuts.sort_by(|a, b| a.0.total_cmp(&b.0));
+        cuts.dedup_by(|a, b| a.0 == b.0);
+
+        let mut pieces = Vec::with_capacity(cuts.len() + 1);
+        let mut piece = vec![coords[0]];
+        let mut next = 1;
+        for (along, coord) in cuts {
+            while next < coords.len() && self.distances[next] < along {
+                piece.push(coords[next]);
+                next += 1;
+            }
+            piece.push(coord);
+            pieces.push(LineString::new(core::mem::replace(&mut piece, vec![coord])));
+            // Skip a vertex exactly at the cut
+            while next < coords.len() && self.distances[next] <= along {
+                next += 1;
+            }
+        }
+        piece.extend(&coords[next..]);
+        pieces.push(LineString::new(piece));
+        MultiLineString::new(pieces)
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, EuclideanLength};
+
+    #[test]
+    fn at_distances() {
+        let line_string: LineString = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.)];
+        let pieces = line_string.split_at_distances(&[6., 4., 2., 4., 0., 7., -1., 12.]);
+        assert_eq!(
+            pieces.0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 4., y: 0.)],
+                line_string![(x: 4., y: 0.), (x: 4., y: 2.)],
+                line_string![(x: 4., y: 2.), (x: 4., y: 3.)],
+            ]
+        );
+        // Without any cuts, the line is its only piece
+        assert_eq!(
+            line_string.split_at_distances(&[]).0,
+            vec![line_string.clone()]
+        );
+        // The pieces add up to the whole
+        let pieces = line_string.split_at_distances(&[0.5, 1.5, 3.9, 4.1, 6.5]);
+        assert_eq!(pieces.0.len(), 6);
+        assert_relative_eq!(pieces.euclidean_length(), 7.);
+
+        let empty: LineString = line_string![];
+        assert!(empty.split_at_distances(&[1.]).0.is_empty());
+    }
+
+    #[test]
+    fn at_points() {
+        // A loop which passes itself, with a repeated vertex
+        let line_string: LineString = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 1., y: 2.),
+            (x: 1., y: -1.),
+        ];
+        let pieces = line_string.split_at_points(
+            &[
+                point!(x: 2.2, y: 0.),
+                point!(x: 0., y: 0.),
+                point!(x: 1.5, y: 2.1),
+                point!(x: 5., y: 5.),
+            ],
+            0.25,
+        );
+        assert_eq!(
+            pieces.0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 2., y: 2.), (x: 1.5, y: 2.)],
+                line_string![(x: 1.5, y: 2.), (x: 1., y: 2.), (x: 1., y: -1.)],
+            ]
+        );
+    }
+}
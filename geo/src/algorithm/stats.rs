@@ -0,0 +1,292 @@
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// A summary of a geometry, as given by [`Stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryStats<T: GeoFloat> {
+    /// The number of coordinates, as counted by
+    /// [`CoordsIter::coords_count`](crate::CoordsIter::coords_count).
+    pub vertices: usize,
+    /// The number of `Point`s, including those of `MultiPoint`s.
+    pub points: usize,
+    /// The number of `Line`s.
+    pub lines: usize,
+    /// The number of `LineString`s, including those of `MultiLineString`s.
+    pub line_strings: usize,
+    /// The number of `Polygon`s, including those of `MultiPolygon`s.
+    pub polygons: usize,
+    /// The number of `Rect`s.
+    pub rects: usize,
+    /// The number of `Triangle`s.
+    pub triangles: usize,
+    /// The bounding rectangle of all the coordinates, or `None` if there aren't any.
+    pub bounding_rect: Option<Rect<T>>,
+    /// The total planar length of the `Line`s and `LineString`s.
+    pub length: T,
+    /// The total unsigned planar area of the `Polygon`s, `Rect`s and `Triangle`s.
+    pub area: T,
+    /// The length of the shortest segment, of lines, line strings or the rings of polygons, or
+    /// `None` if there aren't any.
+    pub min_segment_length: Option<T>,
+    /// The length of the longest segment, or `None` if there aren't any.
+    pub max_segment_length: Option<T>,
+}
+
+impl<T: GeoFloat> Default for GeometryStats<T> {
+    fn default() -> Self {
+        GeometryStats {
+            vertices: 0,
+            points: 0,
+            lines: 0,
+            line_strings: 0,
+            polygons: 0,
+            rects: 0,
+            triangles: 0,
+            bounding_rect: None,
+            length: T::zero(),
+            area: T::zero(),
+            min_segment_length: None,
+            max_segment_length: None,
+        }
+    }
+}
+
+impl<T: GeoFloat> GeometryStats<T> {
+    fn add_coord(&mut self, coord: Coord<T>) {
+        self.vertices += 1;
+        self.bounding_rect = Some(match self.bounding_rect {
+            Some(rect) => Rect::new(
+                Coord {
+                    x: rect.min().x.min(coord.x),
+                    y: rect.min().y.min(coord.y),
+                },
+                Coord {
+                    x: rect.max().x.max(coord.x),
+                    y: rect.max().y.max(coord.y),
+                },
+            ),
+            None => Rect::new(coord, coord),
+        });
+    }
+
+    fn add_segment_length(&mut self, length: T) {
+        let min = self
+            .min_segment_length
+            .map_or(length, |min| min.min(length));
+        let max = self
+            .max_segment_length
+            .map_or(length, |max| max.max(length));
+        self.min_segment_length = Some(min);
+        self.max_segment_length = Some(max);
+    }
+
+    /// Add the coordinates and segments of `coords`, returning their length and twice their
+    /// signed area as a ring.
+    fn add_path(&mut self, coords: &[Coord<T>]) -> (T, T) {
+        coords.iter().for_each(|&coord| self.add_coord(coord));
+        let (mut length, mut twice_area) = (T::zero(), T::zero());
+        // Relative to the first coordinate, for precision
+        let origin = coords.first().copied().unwrap_or_else(Coord::zero);
+        for segment in coords.windows(2) {
+            let (start, end) = (segment[0] - origin, segment[1] - origin);
+            let segment_length = (end.x - start.x).hypot(end.y - start.y);
+            self.add_segment_length(segment_length);
+            length = length + segment_length;
+            twice_area = twice_area + start.x * end.y - end.x * start.y;
+        }
+        (length, twice_area)
+    }
+}
+
+/// Summarize a geometry in a single traversal of its coordinates: how many vertices and
+/// components of each type it has, its bounding rectangle, its total length and area, and the
+/// lengths of its shortest and longest segments, for checking the quality of data.
+///
+/// To summarize many geometries together, such as a whole dataset, add each of them to the
+/// same [`GeometryStats`] with [`add_stats`](Self::add_stats).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, Geometry, GeometryCollection, Rect, Stats};
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 10., y: 10.)),
+///     Geometry::LineString(line_string![(x: 0., y: 0.), (x: 3., y: 4.), (x: 3., y: 5.)]),
+///     Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]),
+/// ]);
+///
+/// let stats = collection.stats();
+/// assert_eq!(stats.vertices, 9);
+/// assert_eq!((stats.points, stats.line_strings, stats.polygons), (1, 1, 1));
+/// assert_eq!(stats.bounding_rect, Some(Rect::new((0., 0.), (10., 10.))));
+/// assert_eq!(stats.length, 6.);
+/// assert_eq!(stats.area, 4.);
+/// assert_eq!(stats.min_segment_length, Some(1.));
+/// assert_eq!(stats.max_segment_length, Some(5.));
+/// ```
+pub trait Stats<T: GeoFloat> {
+    /// Add this geometry to the summary `stats`.
+    fn add_stats(&self, stats: &mut GeometryStats<T>);
+
+    /// A summary of this geometry.
+    fn stats(&self) -> GeometryStats<T> {
+        let mut stats = GeometryStats::default();
+        self.add_stats(&mut stats);
+        stats
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for Point<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.points += 1;
+        stats.add_coord(self.0);
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for Line<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.lines += 1;
+        let (length, _) = stats.add_path(&[self.start, self.end]);
+        stats.length = stats.length + length;
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for LineString<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.line_strings += 1;
+        let (length, _) = stats.add_path(&self.0);
+        stats.length = stats.length + length;
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for Polygon<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.polygons += 1;
+        let two = T::one() + T::one();
+        let (_, exterior) = stats.add_path(&self.exterior().0);
+        let mut area = exterior.abs() / two;
+        for interior in self.interiors() {
+            let (_, hole) = stats.add_path(&interior.0);
+            area = area - hole.abs() / two;
+        }
+        stats.area = stats.area + area;
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for Rect<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.rects += 1;
+        let (min, max) = (self.min(), self.max());
+        for coord in [
+            min,
+            Coord { x: max.x, y: min.y },
+            max,
+            Coord { x: min.x, y: max.y },
+        ] {
+            stats.add_coord(coord);
+        }
+        for length in [self.width(), self.height(), self.width(), self.height()] {
+            stats.add_segment_length(length);
+        }
+        stats.area = stats.area + self.width() * self.height();
+    }
+}
+
+impl<T: GeoFloat> Stats<T> for Triangle<T> {
+    fn add_stats(&self, stats: &mut GeometryStats<T>) {
+        stats.triangles += 1;
+        // As a ring, whose closing coordinate isn't a vertex of the triangle
+        let (_, twice_area) = stats.add_path(&[self.0, self.1, self.2, self.0]);
+        stats.vertices -= 1;
+        stats.area = stats.area + twice_area.abs() / (T::one() + T::one());
+    }
+}
+
+macro_rules! impl_stats_for_collection {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T: GeoFloat> Stats<T> for $geometry<T> {
+                fn add_stats(&self, stats: &mut GeometryStats<T>) {
+                    self.iter().for_each(|g| g.add_stats(stats));
+                }
+            }
+        )*
+    };
+}
+
+impl_stats_for_collection!(
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: GeoFloat> Stats<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn add_stats(&self, stats: &mut GeometryStats<T>) -> ();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area, BoundingRect, CoordsIter, EuclideanLength};
+
+    #[test]
+    fn matches_separate_traversals() {
+        let polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        ];
+        let line_string: LineString =
+            line_string![(x: -5., y: 0.), (x: -5., y: 0.), (x: -5., y: 20.)];
+        let geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            MultiPolygon::new(vec![polygon.clone(), polygon.clone()]).into(),
+            MultiLineString::new(vec![line_string.clone()]).into(),
+            MultiPoint::new(vec![point!(x: 3., y: -1.)]).into(),
+            Line::new((0., 0.), (0., 0.5)).into(),
+            Rect::new((20., 20.), (21., 23.)).into(),
+            Triangle::new((0., 0.).into(), (4., 0.).into(), (0., 4.).into()).into(),
+        ]));
+
+        let stats = geometry.stats();
+        assert_eq!(stats.vertices, geometry.coords_count());
+        assert_eq!(
+            (
+                stats.points,
+                stats.lines,
+                stats.line_strings,
+                stats.polygons
+            ),
+            (1, 1, 1, 2)
+        );
+        assert_eq!((stats.rects, stats.triangles), (1, 1));
+        assert_eq!(stats.bounding_rect, geometry.bounding_rect());
+        assert_relative_eq!(stats.length, line_string.euclidean_length() + 0.5);
+        assert_relative_eq!(stats.area, geometry.unsigned_area());
+        // The repeated vertex makes a segment without length
+        assert_eq!(stats.min_segment_length, Some(0.));
+        assert_eq!(stats.max_segment_length, Some(20.));
+    }
+
+    #[test]
+    fn accumulates() {
+        let mut stats = GeometryStats::default();
+        assert_eq!(stats.bounding_rect, None);
+        assert_eq!(stats.min_segment_length, None);
+
+        point!(x: 1., y: 1.).add_stats(&mut stats);
+        assert_eq!(stats.min_segment_length, None);
+        Line::new((2., 2.), (5., 6.)).add_stats(&mut stats);
+        assert_eq!(stats.vertices, 3);
+        assert_eq!(stats.bounding_rect, Some(Rect::new((1., 1.), (5., 6.))));
+        assert_eq!(stats.min_segment_length, Some(5.));
+        assert_eq!(stats.length, 5.);
+
+        let empty: GeometryStats<f64> = MultiPolygon::new(vec![]).stats();
+        assert_eq!(empty, GeometryStats::default());
+    }
+}
@@ -1,4 +1,7 @@
-use std::{borrow::Borrow, cmp::Ordering, fmt::Debug, ops::Deref};
+use alloc::borrow::Borrow;
+use core::{cmp::Ordering, fmt::Debug, ops::Deref};
+
+use super::SweepError;
 
 /// A segment currently active in the sweep.
 ///
@@ -15,12 +18,6 @@ use std::{borrow::Borrow, cmp::Ordering, fmt::Debug, ops::Deref};
 #[repr(transparent)]
 pub(in crate::algorithm) struct Active<T>(pub(in crate::algorithm) T);
 
-impl<T> Active<T> {
-    pub(in crate::algorithm) fn active_ref(t: &T) -> &Active<T> {
-        unsafe { std::mem::transmute(t) }
-    }
-}
-
 impl<T> Borrow<T> for Active<T> {
     fn borrow(&self) -> &T {
         &self.0
@@ -59,24 +56,16 @@ impl<T: PartialOrd + Debug> PartialOrd for Active<T> {
 }
 
 /// Trait abstracting a container of active segments.
+///
+/// The methods fail if `segment` can't be compared with the active segments.
 pub(in crate::algorithm) trait ActiveSet: Default {
     type Seg;
     fn previous_find<F: FnMut(&Active<Self::Seg>) -> bool>(
         &self,
         segment: &Self::Seg,
         f: F,
-    ) -> Option<&Active<Self::Seg>>;
-    fn previous(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>> {
+    ) -> Result<Option<&Active<Self::Seg>>, SweepError>;
+    fn previous(&self, segment: &Self::Seg) -> Result<Option<&Active<Self::Seg>>, SweepError> {
         self.previous_find(segment, |_| true)
     }
-    fn next_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        f: F,
-    ) -> Option<&Active<Self::Seg>>;
-    fn next(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>> {
-        self.next_find(segment, |_| true)
-    }
-    fn insert_active(&mut self, segment: Self::Seg);
-    fn remove_active(&mut self, segment: &Self::Seg);
 }
@@ -0,0 +1,214 @@
+use super::*;
+use crate::{GeoFloat, Line, LineIntersection};
+
+/// An intersection of two of the lines given to [`SegmentIntersections`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentIntersection<T: GeoFloat> {
+    /// The index of the first line: the lesser of the two indices for the lines of a single
+    /// source, or the index in the first source.
+    pub first: usize,
+    /// The index of the second line: the greater of the two indices for the lines of a single
+    /// source, or the index in the second source.
+    pub second: usize,
+    /// Where the lines intersect, at a point or along a segment they overlap.
+    pub intersection: LineIntersection<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IndexedLine<T: GeoFloat> {
+    line: Line<T>,
+    source: usize,
+    index: usize,
+}
+
+impl<T: GeoFloat> Cross for IndexedLine<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.line.into()
+    }
+}
+
+/// Iterator over the intersections of lines, identified by their indices, from one source or
+/// between two sources.
+///
+/// Every pair of lines which intersect, whether they cross, touch or overlap, is yielded
+/// once, as a [`SegmentIntersection`]. This is the building block for noding lines, matching
+/// up the lines of two datasets, or checking that a network is planar, in the same
+/// O((n + k) log n) time as [`Intersections`], which it's built on.
+///
+/// # Examples
+///
+/// ```
+/// use geo::sweep::{SegmentIntersection, SegmentIntersections};
+/// use geo::{coord, Line, LineIntersection};
+///
+/// let roads = [
+///     Line::new((0., 0.), (10., 0.)),
+///     Line::new((5., -5.), (5., 5.)),
+///     Line::new((20., 0.), (30., 0.)),
+/// ];
+/// let intersections: Vec<_> = SegmentIntersections::new(roads).collect();
+/// assert_eq!(
+///     intersections,
+///     vec![SegmentIntersection {
+///         first: 0,
+///         second: 1,
+///         intersection: LineIntersection::SinglePoint {
+///             intersection: coord! { x: 5., y: 0. },
+///             is_proper: true,
+///         },
+///     }]
+/// );
+///
+/// // Only the rivers which cross the roads, not each other
+/// let rivers = [
+///     Line::new((25., -5.), (25., 5.)),
+///     Line::new((20., -5.), (30., 5.)),
+/// ];
+/// let crossings: Vec<_> = SegmentIntersections::between(roads, rivers)
+///     .map(|intersection| (intersection.first, intersection.second))
+///     .collect();
+/// assert_eq!(crossings.len(), 2);
+/// assert!(crossings.contains(&(2, 0)) && crossings.contains(&(2, 1)));
+/// ```
+pub struct SegmentIntersections<T: GeoFloat> {
+    intersections: Intersections<IndexedLine<T>>,
+    between: bool,
+}
+
+impl<T: GeoFloat> SegmentIntersections<T> {
+    /// The intersections of every pair of `lines`.
+    pub fn new<I: IntoIterator<Item = Line<T>>>(lines: I) -> Self {
+        Self {
+            intersections: indexed(lines, 0).collect(),
+            between: false,
+        }
+    }
+
+    /// The intersections of every line of `first` with every line of `second`, but not those
+    /// of lines from the same source.
+    pub fn between<I, J>(first: I, second: J) -> Self
+    where
+        I: IntoIterator<Item = Line<T>>,
+        J: IntoIterator<Item = Line<T>>,
+    {
+        Self {
+            intersections: indexed(first, 0).chain(indexed(second, 1)).collect(),
+            between: true,
+        }
+    }
+}
+
+fn indexed<T: GeoFloat, I: IntoIterator<Item = Line<T>>>(
+    lines: I,
+    source: usize,
+) -> impl Iterator<Item = IndexedLine<T>> {
+    lines
+        .into_iter()
+        .enumerate()
+        .map(move |(index, line)| IndexedLine {
+            line,
+            source,
+            index,
+        })
+}
+
+impl<T: GeoFloat> Iterator for SegmentIntersections<T> {
+    type Item = SegmentIntersection<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.intersections.find_map(|(a, b, intersection)| {
+            let (first, second) = if (a.source, a.index) <= (b.source, b.index) {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            (!self.between || first.source != second.source).then_some(SegmentIntersection {
+                first: first.index,
+                second: second.index,
+                intersection,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_intersection::line_intersection;
+    use alloc::{vec, vec::Vec};
+
+    fn sorted(
+        intersections: impl Iterator<Item = SegmentIntersection<f64>>,
+    ) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<_> = intersections
+            .map(|intersection| (intersection.first, intersection.second))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    fn lines() -> Vec<Line<f64>> {
+        vec![
+            Line::new((0., 0.), (4., 4.)),
+            Line::new((4., 0.), (0., 4.)),
+            // Overlaps the first
+            Line::new((2., 2.), (6., 6.)),
+            // Touches the end of the second
+            Line::new((0., 4.), (-2., 4.)),
+            Line::new((10., 10.), (11., 10.)),
+            // A point on the first
+            Line::new((1., 1.), (1., 1.)),
+            Line::new((0., 1.), (5., 1.)),
+        ]
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let lines = lines();
+        let mut expected = Vec::new();
+        for (i, a) in lines.iter().enumerate() {
+            for (j, b) in lines.iter().enumerate().skip(i + 1) {
+                if line_intersection(*a, *b).is_some() {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(sorted(SegmentIntersections::new(lines.clone())), expected);
+
+        let overlap = SegmentIntersections::new(lines)
+            .find(|intersection| (intersection.first, intersection.second) == (0, 2))
+            .unwrap();
+        assert_eq!(
+            overlap.intersection,
+            LineIntersection::Collinear {
+                intersection: Line::new((2., 2.), (4., 4.))
+            }
+        );
+    }
+
+    #[test]
+    fn between_sources() {
+        let lines = lines();
+        let (first, second) = lines.split_at(3);
+        let mut expected = Vec::new();
+        for (i, a) in first.iter().enumerate() {
+            for (j, b) in second.iter().enumerate() {
+                if line_intersection(*a, *b).is_some() {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(
+            sorted(SegmentIntersections::between(
+                first.to_vec(),
+                second.to_vec()
+            )),
+            expected
+        );
+        assert!(SegmentIntersections::between(first.to_vec(), [])
+            .next()
+            .is_none());
+    }
+}
@@ -1,4 +1,5 @@
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 use super::*;
 use crate::{line_intersection::line_intersection, Coord, LineIntersection};
@@ -42,15 +43,14 @@ pub(crate) struct Crossing<C: Cross> {
     pub(super) segment: IMSegment<C>,
 }
 
-pub(crate) fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Ordering {
-    a.at_left.cmp(&b.at_left).then_with(|| {
-        let ord = a.segment.partial_cmp(&b.segment).unwrap();
-        if a.at_left {
-            ord
-        } else {
-            ord.reverse()
+fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Option<Ordering> {
+    match a.at_left.cmp(&b.at_left) {
+        Ordering::Equal => {
+            let ord = a.segment.partial_cmp(&b.segment)?;
+            Some(if a.at_left { ord } else { ord.reverse() })
         }
-    })
+        ord => Some(ord),
+    }
 }
 
 impl<C: Cross + Clone> Crossing<C> {
@@ -67,6 +67,9 @@ impl<C: Cross + Clone> Crossing<C> {
     }
 }
 
+/// The geometry and payload of an active segment.
+type ActivePiece<C> = (LineOrPoint<<C as Cross>::Scalar>, C);
+
 /// Iterator that yields all crossings.
 ///
 /// Yields all end points, intersections and overlaps of a set of
@@ -87,7 +90,7 @@ impl<C: Cross + Clone> Crossing<C> {
 /// ```rust,ignore
 /// use geo::Line;
 /// use geo::sweep::CrossingsIter;
-/// use std::iter::FromIterator;
+/// use core::iter::FromIterator;
 /// let input = vec![
 ///     Line::from([(1., 0.), (0., 1.)]),
 ///     Line::from([(0., 0.75), (1., 0.25)]),
@@ -106,6 +109,8 @@ where
 {
     sweep: Sweep<C>,
     segments: Vec<Crossing<C>>,
+    /// Why the sweep stopped early, if it did.
+    error: Option<SweepError>,
 }
 
 impl<C> CrossingsIter<C>
@@ -128,11 +133,40 @@ where
         &self.segments
     }
 
-    pub(crate) fn prev_active(&self, c: &Crossing<C>) -> Option<(LineOrPoint<C::Scalar>, C)> {
+    /// Sorts the segments that intersect the last point yielded by the iterator, so that the
+    /// segments which end there come before those which start there, and each of those are
+    /// ordered from bottom to top.
+    pub(crate) fn sort_intersections(&mut self) -> Result<(), SweepError> {
+        // Check every pair first, so the sort is never given an order that isn't total
+        let segments = &self.segments;
+        for (index, a) in segments.iter().enumerate() {
+            if segments[index + 1..]
+                .iter()
+                .any(|b| compare_crossings(a, b).is_none())
+            {
+                warn!("could not compare intersecting segments at {:?}", a.line);
+                return Err(SweepError);
+            }
+        }
+        self.segments.sort_unstable_by(|a, b| {
+            compare_crossings(a, b).expect("every pair of segments was compared")
+        });
+        Ok(())
+    }
+
+    pub(crate) fn prev_active(
+        &self,
+        c: &Crossing<C>,
+    ) -> Result<Option<ActivePiece<C>>, SweepError> {
         self.sweep
             .with_prev_active(c, |s| (s.geom, s.cross.clone()))
     }
 
+    /// Whether the sweep stopped early, as the iterator ends if the sweep fails.
+    pub(crate) fn check(&self) -> Result<(), SweepError> {
+        self.error.map_or(Ok(()), Err)
+    }
+
     fn new_ex<T: IntoIterator<Item = C>>(iter: T, is_simple: bool) -> Self {
         let iter = iter.into_iter();
         let size = {
@@ -141,7 +175,11 @@ where
         };
         let sweep = Sweep::new(iter, is_simple);
         let segments = Vec::with_capacity(4 * size);
-        Self { sweep, segments }
+        Self {
+            sweep,
+            segments,
+            error: None,
+        }
     }
 }
 
@@ -164,16 +202,27 @@ where
         let segments = &mut self.segments;
 
         segments.clear();
+        if self.error.is_some() {
+            return None;
+        }
         let mut last_point = self.sweep.peek_point();
         debug!("pt: {last_point:?}");
         while last_point == self.sweep.peek_point() && self.sweep.peek_point().is_some() {
-            last_point = self.sweep.next_event(|seg, ty| {
+            let next_point = self.sweep.next_event(|seg, ty| {
                 trace!(
                     "cb: {seg:?} {ty:?} (crossable = {cross:?})",
                     cross = seg.cross_cloned().line()
                 );
                 segments.push(Crossing::from_segment(seg, ty))
             });
+            match next_point {
+                Ok(point) => last_point = point,
+                Err(error) => {
+                    self.error = Some(error);
+                    segments.clear();
+                    return None;
+                }
+            }
         }
 
         if segments.is_empty() {
@@ -205,7 +254,7 @@ where
 /// ```rust
 /// use geo::Line;
 /// use geo::sweep::Intersections;
-/// use std::iter::FromIterator;
+/// use core::iter::FromIterator;
 /// let input = vec![
 ///     Line::from([(1., 0.), (0., 1.)]),
 ///     Line::from([(0., 0.75), (1., 0.25)]),
@@ -298,6 +347,9 @@ where
                 loop {
                     self.pt = self.inner.next();
                     if self.pt.is_none() {
+                        if self.inner.check().is_err() {
+                            panic!("unable to compare active segments!");
+                        }
                         return false;
                     }
                     if self.inner.intersections_mut().len() > 1 {
@@ -16,6 +16,11 @@ use segment::{Segment, SplitSegments};
 mod active;
 pub(super) use active::{Active, ActiveSet};
 
+/// The sweep couldn't order the segments it's crossing, which can happen when floating-point
+/// error in their intersections leaves them inconsistent with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SweepError;
+
 mod im_segment;
 use im_segment::IMSegment;
 
@@ -27,4 +32,7 @@ use proc::Sweep;
 
 mod iter;
 pub use iter::Intersections;
-pub(crate) use iter::{compare_crossings, Crossing, CrossingsIter};
+pub(crate) use iter::{Crossing, CrossingsIter};
+
+mod indexed;
+pub use indexed::{SegmentIntersection, SegmentIntersections};
@@ -1,4 +1,5 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
+use alloc::collections::BinaryHeap;
+use core::cmp::Ordering;
 
 use crate::GeoFloat;
 
@@ -37,16 +38,19 @@ impl<C: Cross + Clone> Sweep<C> {
     ///
     /// Calls the callback unless the event is spurious.
     #[inline]
-    pub(super) fn next_event<F>(&mut self, mut cb: F) -> Option<SweepPoint<C::Scalar>>
+    pub(super) fn next_event<F>(
+        &mut self,
+        mut cb: F,
+    ) -> Result<Option<SweepPoint<C::Scalar>>, SweepError>
     where
         F: for<'a> FnMut(&'a IMSegment<C>, EventType),
     {
-        self.events.pop().map(|event| {
-            let pt = event.point;
-            self.handle_event(event, &mut cb);
-
-            pt
-        })
+        let Some(event) = self.events.pop() else {
+            return Ok(None);
+        };
+        let pt = event.point;
+        self.handle_event(event, &mut cb)?;
+        Ok(Some(pt))
     }
 
     /// Process two adjacent segments.
@@ -57,7 +61,7 @@ impl<C: Cross + Clone> Sweep<C> {
         &mut self,
         active: Active<IMSegment<C>>,
         other: &IMSegment<C>,
-    ) -> AdjProcOutput<C::Scalar> {
+    ) -> Result<AdjProcOutput<C::Scalar>, SweepError> {
         // NOTE: The below logic is a loop instead of a
         // conditional due to FP issues. Specifically,
         // sometimes, two non-overlapping lines may become
@@ -87,30 +91,31 @@ impl<C: Cross + Clone> Sweep<C> {
             // 2. Split segment, adding extra segments as needed.
             let seg_overlap = other.adjust_one_segment(isec, |e| self.events.push(e));
 
-            assert_eq!(
-                adj_overlap.is_some(),
-                seg_overlap.is_some(),
-                "one of the intersecting segments had an overlap, but not the other!"
-            );
-            if let Some(adj_ovl) = adj_overlap {
-                let tgt = seg_overlap.unwrap();
-                trace!("setting overlap: {adj_ovl:?} -> {tgt:?}");
-                adj_ovl.chain_overlap(tgt.clone());
-
-                if &tgt == other {
-                    // The whole event segment is now overlapping
-                    // some other active segment.
-                    //
-                    // We do not need to continue iteration, but
-                    // should callback if the left event of the
-                    // now-parent has already been processed.
-                    out.should_callback = adj_ovl.is_left_event_done();
-                    out.should_continue = false;
-                }
+            match (adj_overlap, seg_overlap) {
+                (Some(adj_ovl), Some(tgt)) => {
+                    trace!("setting overlap: {adj_ovl:?} -> {tgt:?}");
+                    adj_ovl.chain_overlap(tgt.clone());
+
+                    if &tgt == other {
+                        // The whole event segment is now overlapping
+                        // some other active segment.
+                        //
+                        // We do not need to continue iteration, but
+                        // should callback if the left event of the
+                        // now-parent has already been processed.
+                        out.should_callback = adj_ovl.is_left_event_done();
+                        out.should_continue = false;
+                    }
 
-                // Overlaps are exact compute, so we do not need
-                // to re-run the loop.
-                return out;
+                    // Overlaps are exact compute, so we do not need
+                    // to re-run the loop.
+                    return Ok(out);
+                }
+                (None, None) => {}
+                _ => {
+                    warn!("one of the intersecting segments had an overlap, but not the other!");
+                    return Err(SweepError);
+                }
             }
 
             if active.geom().partial_cmp(&other.geom()) == Some(Ordering::Equal) {
@@ -119,16 +124,20 @@ impl<C: Cross + Clone> Sweep<C> {
                 break;
             }
         }
-        out
+        Ok(out)
     }
 
-    fn handle_event<F>(&mut self, event: Event<C::Scalar, IMSegment<C>>, cb: &mut F) -> bool
+    fn handle_event<F>(
+        &mut self,
+        event: Event<C::Scalar, IMSegment<C>>,
+        cb: &mut F,
+    ) -> Result<bool, SweepError>
     where
         F: for<'a> FnMut(&'a IMSegment<C>, EventType),
     {
         use EventType::*;
         let segment = match IMSegment::is_correct(&event) {
-            false => return false,
+            false => return Ok(false),
             _ => event.payload,
         };
         trace!(
@@ -144,7 +153,7 @@ impl<C: Cross + Clone> Sweep<C> {
         match &event.ty {
             LineLeft => {
                 let mut should_add = true;
-                let mut insert_idx = self.active_segments.index_not_of(&segment);
+                let mut insert_idx = self.active_segments.index_not_of(&segment)?;
                 if !self.is_simple {
                     for is_next in [true, false].into_iter() {
                         let active = if is_next {
@@ -162,7 +171,7 @@ impl<C: Cross + Clone> Sweep<C> {
                             isec,
                             should_continue,
                             should_callback,
-                        } = self.process_adjacent_segments(active.clone(), &segment);
+                        } = self.process_adjacent_segments(active.clone(), &segment)?;
                         let isec = match isec {
                             Some(isec) => isec,
                             None => continue,
@@ -179,8 +188,8 @@ impl<C: Cross + Clone> Sweep<C> {
                             int_pt != active.geom().left() && int_pt == segment.geom().left()
                         };
                         if handle_end_event {
-                            let event = self.events.pop().unwrap();
-                            let done = self.handle_event(event, cb);
+                            let event = self.events.pop().ok_or(SweepError)?;
+                            let done = self.handle_event(event, cb)?;
                             debug_assert!(done, "special right-end event handling failed");
                             if !is_next {
                                 // The prev-segment is now removed
@@ -191,7 +200,7 @@ impl<C: Cross + Clone> Sweep<C> {
                         if !should_continue {
                             should_add = false;
                             if !should_callback {
-                                return true;
+                                return Ok(true);
                             }
                             break;
                         }
@@ -229,11 +238,14 @@ impl<C: Cross + Clone> Sweep<C> {
                 // Safety: `self.segments` is a `Box` that is not
                 // de-allocated until `self` is dropped.
                 debug!("remove_active: {segment:?}");
-                let el_idx = self.active_segments.index_of(&segment);
+                let el_idx = self.active_segments.index_of(&segment)?;
                 let prev = (el_idx > 0).then(|| self.active_segments[el_idx - 1].clone());
                 let next = (1 + el_idx < self.active_segments.len())
                     .then(|| self.active_segments[el_idx + 1].clone());
-                assert_eq!(self.active_segments.remove_at(el_idx), segment);
+                if self.active_segments.remove_at(el_idx) != segment {
+                    warn!("removed the wrong segment from active-vec-set");
+                    return Err(SweepError);
+                }
 
                 let mut cb_seg = Some(segment);
                 while let Some(seg) = cb_seg {
@@ -264,7 +276,7 @@ impl<C: Cross + Clone> Sweep<C> {
             }
             PointLeft => {
                 if !self.is_simple {
-                    let insert_idx = self.active_segments.index_not_of(&segment);
+                    let insert_idx = self.active_segments.index_not_of(&segment)?;
                     let prev =
                         (insert_idx > 0).then(|| self.active_segments[insert_idx - 1].clone());
                     let next = (insert_idx < self.active_segments.len())
@@ -294,7 +306,7 @@ impl<C: Cross + Clone> Sweep<C> {
                 // are confident about the logic.
             }
         }
-        true
+        Ok(true)
     }
 
     #[inline]
@@ -302,7 +314,7 @@ impl<C: Cross + Clone> Sweep<C> {
         &self,
         c: &Crossing<C>,
         f: F,
-    ) -> Option<R> {
+    ) -> Result<Option<R>, SweepError> {
         debug_assert!(c.at_left);
         // {
         //     // Expensive debug block
@@ -318,8 +330,9 @@ impl<C: Cross + Clone> Sweep<C> {
         //         false
         //     });
         // }
-        self.active_segments
-            .previous(&c.segment)
+        Ok(self
+            .active_segments
+            .previous(&c.segment)?
             // .previous_find(&c.segment, |aseg| {
             //     let is_ovl = aseg.0.geom().partial_cmp(&c.line) == Some(Ordering::Equal);
             //     if is_ovl {
@@ -331,7 +344,7 @@ impl<C: Cross + Clone> Sweep<C> {
             //     }
             //     !is_ovl
             // })
-            .map(|aseg| aseg.with_segment(f))
+            .map(|aseg| aseg.with_segment(f)))
     }
 
     #[inline]
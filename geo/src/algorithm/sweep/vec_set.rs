@@ -1,5 +1,6 @@
-use super::{Active, ActiveSet};
-use std::{cmp::Ordering, fmt::Debug, ops::Index};
+use super::{Active, ActiveSet, SweepError};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt::Debug, ops::Index};
 
 /// A simple ordered set implementation backed by a `Vec`.
 #[derive(Debug, Clone)]
@@ -23,16 +24,43 @@ impl<T: PartialOrd + Debug> VecSet<Active<T>> {
         self.data.partition_point(|s| pred(&s.0))
     }
 
-    pub fn index_of(&self, segment: &T) -> usize {
-        self.data
-            .binary_search(Active::active_ref(segment))
-            .expect("segment not found in active-vec-set")
+    /// Binary searches for `segment`, failing if it can't be compared with the active segments.
+    fn search(&self, segment: &T) -> Result<Result<usize, usize>, SweepError> {
+        // As `binary_search_by`, but stopping at the first segment it can't compare with
+        let (mut low, mut high) = (0, self.data.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let active = &self.data[mid].0;
+            match active.partial_cmp(segment) {
+                Some(Ordering::Less) => low = mid + 1,
+                Some(Ordering::Greater) => high = mid,
+                Some(Ordering::Equal) => return Ok(Ok(mid)),
+                None => {
+                    warn!("could not compare segments:\n\t{active:?}\n\t{segment:?}");
+                    return Err(SweepError);
+                }
+            }
+        }
+        Ok(Err(low))
+    }
+
+    /// The index of `segment`, which must be active.
+    pub fn index_of(&self, segment: &T) -> Result<usize, SweepError> {
+        self.search(segment)?.map_err(|_| {
+            warn!("segment not found in active-vec-set");
+            SweepError
+        })
     }
 
-    pub fn index_not_of(&self, segment: &T) -> usize {
-        self.data
-            .binary_search(Active::active_ref(segment))
-            .expect_err("segment already found in active-vec-set")
+    /// The index at which to insert `segment`, which must not be active.
+    pub fn index_not_of(&self, segment: &T) -> Result<usize, SweepError> {
+        match self.search(segment)? {
+            Ok(_) => {
+                warn!("segment already found in active-vec-set");
+                Err(SweepError)
+            }
+            Err(idx) => Ok(idx),
+        }
     }
     pub fn len(&self) -> usize {
         self.data.len()
@@ -72,44 +100,11 @@ impl<T: PartialOrd + Debug> ActiveSet for VecSet<Active<T>> {
         &self,
         segment: &Self::Seg,
         mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        let segment = Active::active_ref(segment);
-        let ub = match self.data.binary_search(segment) {
+    ) -> Result<Option<&Active<Self::Seg>>, SweepError> {
+        let ub = match self.search(segment)? {
             Ok(i) => i,
             Err(i) => i,
         };
-        self.data[..ub].iter().rev().find(|s| f(s))
-    }
-
-    fn next_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        let segment = Active::active_ref(segment);
-        let start = match self.data.binary_search(segment) {
-            Ok(i) => i + 1,
-            Err(i) => i,
-        };
-        self.data[start..].iter().find(|s| f(s))
-    }
-
-    fn insert_active(&mut self, segment: Self::Seg) {
-        let idx = {
-            let segment = Active::active_ref(&segment);
-            self.data
-                .binary_search(segment)
-                .expect_err("element already in active-vec-set")
-        };
-        self.data.insert(idx, Active(segment));
-    }
-
-    fn remove_active(&mut self, segment: &Self::Seg) {
-        let segment = Active::active_ref(segment);
-        let idx = self
-            .data
-            .binary_search(segment)
-            .expect("element not found in active-vec-set");
-        self.data.remove(idx);
+        Ok(self.data[..ub].iter().rev().find(|s| f(s)))
     }
 }
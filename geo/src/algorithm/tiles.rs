@@ -0,0 +1,847 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{
+    coord, BoundingRect, ClipToRect, Coord, Geometry, GeometryCollection, Line, LineString,
+    MapCoords, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rasterize, Rect,
+    Simplify, Triangle, WebMercator,
+};
+
+/// Half the width of the Web Mercator square, in meters.
+const HALF_WORLD: f64 = 20_037_508.342_789_244;
+
+/// A tile of the [XYZ](https://en.wikipedia.org/wiki/Tiled_web_map) scheme used by web maps,
+/// also known as slippy map tiles.
+///
+/// At zoom level `z`, the Web Mercator square is split into `2^z` by `2^z` tiles. Column `x`
+/// counts from the west, and row `y` from the north.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Tile, WebMercator};
+///
+/// let london = point!(x: -0.1278, y: 51.5074).to_web_mercator();
+/// let tile = Tile::at(london.0, 10);
+/// assert_eq!(tile, Tile::new(10, 511, 340));
+///
+/// let bounds = tile.lon_lat_rect();
+/// assert!(bounds.min().x < -0.1278 && -0.1278 < bounds.max().x);
+/// assert!(bounds.min().y < 51.5074 && 51.5074 < bounds.max().y);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tile {
+    /// The zoom level, at most 31.
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    pub fn new(z: u8, x: u32, y: u32) -> Self {
+        Tile { z, x, y }
+    }
+
+    /// The tile at zoom level `z` which contains the Web Mercator coordinate `coord`.
+    ///
+    /// Coordinates beyond the Web Mercator square are clamped to it, and coordinates on the
+    /// boundary between tiles are in the tile to their east or south.
+    pub fn at(coord: Coord, z: u8) -> Self {
+        let (column, row) = tile_position(coord, z);
+        let last = tile_count(z) - 1;
+        let clamp = |position: f64| Float::floor(position).max(0.).min(last as f64) as u32;
+        Tile::new(z, clamp(column), clamp(row))
+    }
+
+    /// The area of the tile, in Web Mercator coordinates.
+    pub fn web_mercator_rect(&self) -> Rect {
+        let size = tile_size(self.z);
+        let min = coord! {
+            x: -HALF_WORLD + size * self.x as f64,
+            y: HALF_WORLD - size * (self.y + 1) as f64,
+        };
+        Rect::new(min, min + coord! { x: size, y: size })
+    }
+
+    /// The area of the tile, expanded on every side by `buffer` times its size, in Web Mercator
+    /// coordinates.
+    ///
+    /// Vector tiles usually include a small buffer around each tile, so that lines and the
+    /// outlines of polygons aren't cut off at tile edges when rendered.
+    pub fn buffered_web_mercator_rect(&self, buffer: f64) -> Rect {
+        let rect = self.web_mercator_rect();
+        let margin = tile_size(self.z) * buffer;
+        let margin = coord! { x: margin, y: margin };
+        Rect::new(rect.min() - margin, rect.max() + margin)
+    }
+
+    /// The area of the tile, in longitude and latitude.
+    pub fn lon_lat_rect(&self) -> Rect {
+        self.web_mercator_rect().web_mercator_to_lon_lat()
+    }
+
+    /// The tile at the zoom level below which contains this one, unless this is the tile at zoom
+    /// level `0`.
+    pub fn parent(&self) -> Option<Tile> {
+        (self.z > 0).then(|| Tile::new(self.z - 1, self.x / 2, self.y / 2))
+    }
+
+    /// The four tiles at the zoom level above which this one contains.
+    pub fn children(&self) -> [Tile; 4] {
+        let (z, x, y) = (self.z + 1, self.x * 2, self.y * 2);
+        [
+            Tile::new(z, x, y),
+            Tile::new(z, x + 1, y),
+            Tile::new(z, x, y + 1),
+            Tile::new(z, x + 1, y + 1),
+        ]
+    }
+}
+
+fn tile_count(z: u8) -> u32 {
+    1 << z
+}
+
+fn tile_size(z: u8) -> f64 {
+    2. * HALF_WORLD / tile_count(z) as f64
+}
+
+/// The fractional column and row of `coord` at zoom level `z`.
+fn tile_position(coord: Coord, z: u8) -> (f64, f64) {
+    let size = tile_size(z);
+    ((coord.x + HALF_WORLD) / size, (HALF_WORLD - coord.y) / size)
+}
+
+/// The [`Tile`]s which intersect a geometry in Web Mercator coordinates, at a zoom level.
+///
+/// A tile intersects a geometry if they have any point in common, including on the tile's edges.
+/// Parts of the geometry beyond the Web Mercator square are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Tile, TileCoverage, WebMercator};
+///
+/// // Along the equator, from the prime meridian to 100° east
+/// let line = line_string![(x: 1., y: 1.), (x: 100., y: 1.)].to_web_mercator();
+///
+/// // At zoom level 2, the tiles are 90° wide
+/// assert_eq!(
+///     line.tiles(2),
+///     vec![Tile::new(2, 2, 1), Tile::new(2, 3, 1)]
+/// );
+/// ```
+pub trait TileCoverage {
+    /// The tiles at zoom level `z`, sorted by column and then row.
+    fn tiles(&self, z: u8) -> Vec<Tile>;
+}
+
+/// The tiles which cover `bounds`, and a raster of them, to rasterize a geometry within `bounds`
+/// onto.
+fn tile_window(bounds: Rect, z: u8) -> (Tile, Rect, usize, usize) {
+    let (first, last) = (Tile::at(bounds.min(), z), Tile::at(bounds.max(), z));
+    // The minimum corner is in the bottom row, and the maximum one in the top row
+    let top_left = Tile::new(z, first.x, last.y);
+    let bottom_right = Tile::new(z, last.x, first.y);
+    let window = Rect::new(
+        coord! {
+            x: top_left.web_mercator_rect().min().x,
+            y: bottom_right.web_mercator_rect().min().y,
+        },
+        coord! {
+            x: bottom_right.web_mercator_rect().max().x,
+            y: top_left.web_mercator_rect().max().y,
+        },
+    );
+    let rows = (bottom_right.y - top_left.y + 1) as usize;
+    let columns = (bottom_right.x - top_left.x + 1) as usize;
+    (top_left, window, rows, columns)
+}
+
+/// Add the tiles which are set in `mask`, from the tile at the `top_left` of the mask.
+fn add_tiles(tiles: &mut BTreeSet<Tile>, top_left: Tile, mask: &[bool], columns: usize) {
+    for (i, _) in mask.iter().enumerate().filter(|(_, set)| **set) {
+        let (row, column) = (i / columns, i % columns);
+        tiles.insert(Tile::new(
+            top_left.z,
+            top_left.x + column as u32,
+            top_left.y + row as u32,
+        ));
+    }
+}
+
+fn add_point_tiles(tiles: &mut BTreeSet<Tile>, point: Point, z: u8) {
+    let (column, row) = tile_position(point.0, z);
+    if (0.0..=tile_count(z) as f64).contains(&column) && (0.0..=tile_count(z) as f64).contains(&row)
+    {
+        tiles.insert(Tile::at(point.0, z));
+    }
+}
+
+fn add_line_tiles(tiles: &mut BTreeSet<Tile>, lines: &MultiLineString, z: u8) {
+    let Some(bounds) = lines.bounding_rect() else {
+        return;
+    };
+    let (top_left, window, rows, columns) = tile_window(bounds, z);
+    let mask = lines.rasterize_mask(window, rows, columns);
+    add_tiles(tiles, top_left, &mask.values, columns);
+}
+
+fn add_polygon_tiles(tiles: &mut BTreeSet<Tile>, polygons: &MultiPolygon, z: u8) {
+    let Some(bounds) = polygons.bounding_rect() else {
+        return;
+    };
+    let (top_left, window, rows, columns) = tile_window(bounds, z);
+    // The tiles along the boundary, and those inside it. A tile which the boundary doesn't pass
+    // through is either completely inside or completely outside, so rounding errors in its
+    // coverage don't matter.
+    let coverage = polygons.rasterize_coverage(window, rows, columns);
+    let inside: Vec<bool> = coverage.values.iter().map(|&c| c > 0.5).collect();
+    add_tiles(tiles, top_left, &inside, columns);
+    let boundary = MultiLineString::new(
+        polygons
+            .iter()
+            .flat_map(|polygon| core::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .cloned()
+            .collect(),
+    );
+    add_line_tiles(tiles, &boundary, z);
+}
+
+fn add_geometry_tiles(tiles: &mut BTreeSet<Tile>, geometry: &Geometry, z: u8) {
+    match geometry {
+        Geometry::Point(point) => add_point_tiles(tiles, *point, z),
+        Geometry::Line(line) => add_line_tiles(tiles, &LineString::from(*line).into(), z),
+        Geometry::LineString(line_string) => add_line_tiles(tiles, &line_string.clone().into(), z),
+        Geometry::Polygon(polygon) => add_polygon_tiles(tiles, &polygon.clone().into(), z),
+        Geometry::MultiPoint(points) => {
+            for point in points {
+                add_point_tiles(tiles, *point, z);
+            }
+        }
+        Geometry::MultiLineString(lines) => add_line_tiles(tiles, lines, z),
+        Geometry::MultiPolygon(polygons) => add_polygon_tiles(tiles, polygons, z),
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                add_geometry_tiles(tiles, geometry, z);
+            }
+        }
+        Geometry::Rect(rect) => add_polygon_tiles(tiles, &rect.to_polygon().into(), z),
+        Geometry::Triangle(triangle) => add_polygon_tiles(tiles, &triangle.to_polygon().into(), z),
+    }
+}
+
+macro_rules! impl_tile_coverage {
+    ($($type:ident),*) => {
+        $(
+            impl TileCoverage for $type {
+                fn tiles(&self, z: u8) -> Vec<Tile> {
+                    let mut tiles = BTreeSet::new();
+                    add_geometry_tiles(&mut tiles, &Geometry::from(self.clone()), z);
+                    tiles.into_iter().collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_tile_coverage!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+impl TileCoverage for GeometryCollection {
+    fn tiles(&self, z: u8) -> Vec<Tile> {
+        let mut tiles = BTreeSet::new();
+        for geometry in self {
+            add_geometry_tiles(&mut tiles, geometry, z);
+        }
+        tiles.into_iter().collect()
+    }
+}
+
+/// Clip a geometry in Web Mercator coordinates to a [`Tile`], with a buffer around it.
+///
+/// Clipping is done with [`ClipToRect`], so as is usual for vector tiles, polygons which leave
+/// the tile and come back in are joined by edges along its boundary.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, ClipToTile, Tile};
+///
+/// let tile = Tile::new(1, 0, 0);
+/// let polygon = polygon![
+///     (x: -1e7, y: -1e7),
+///     (x: 1e7, y: -1e7),
+///     (x: 1e7, y: 1e7),
+///     (x: -1e7, y: 1e7),
+/// ];
+///
+/// // The north-western quarter of the polygon
+/// let clipped = polygon.clip_to_tile(&tile, 0.);
+/// approx::assert_relative_eq!(clipped.unsigned_area(), 1e14, max_relative = 1e-12);
+///
+/// // A buffer of a 16th of the tile, which is about 1.25e6 meters
+/// let clipped = polygon.clip_to_tile(&tile, 1. / 16.);
+/// assert!(clipped.unsigned_area() > 1.2e14);
+/// ```
+pub trait ClipToTile {
+    type Output;
+
+    /// The part of `self` within `buffer` times the size of the tile from `tile`.
+    fn clip_to_tile(&self, tile: &Tile, buffer: f64) -> Self::Output;
+}
+
+impl ClipToTile for Polygon {
+    type Output = MultiPolygon;
+
+    fn clip_to_tile(&self, tile: &Tile, buffer: f64) -> Self::Output {
+        self.clip_to_rect(&tile.buffered_web_mercator_rect(buffer))
+            .into_iter()
+            .collect()
+    }
+}
+
+impl ClipToTile for MultiPolygon {
+    type Output = MultiPolygon;
+
+    fn clip_to_tile(&self, tile: &Tile, buffer: f64) -> Self::Output {
+        self.clip_to_rect(&tile.buffered_web_mercator_rect(buffer))
+    }
+}
+
+impl ClipToTile for LineString {
+    type Output = MultiLineString;
+
+    fn clip_to_tile(&self, tile: &Tile, buffer: f64) -> Self::Output {
+        self.clip_to_rect(&tile.buffered_web_mercator_rect(buffer))
+    }
+}
+
+impl ClipToTile for MultiLineString {
+    type Output = MultiLineString;
+
+    fn clip_to_tile(&self, tile: &Tile, buffer: f64) -> Self::Output {
+        self.clip_to_rect(&tile.buffered_web_mercator_rect(buffer))
+    }
+}
+
+/// The tolerance, in units of the tile's extent, within which geometries are simplified by
+/// [`PrepareTile`].
+const TILE_SIMPLIFY_TOLERANCE: f64 = 1.;
+
+/// Prepare a geometry in Web Mercator coordinates for encoding in a [`Tile`] of a vector tile
+/// format, such as [Mapbox Vector Tiles](https://github.com/mapbox/vector-tile-spec).
+///
+/// This is all the steps between a geometry and the coordinates which are encoded:
+///
+/// 1. The geometry is [clipped to the tile](ClipToTile), with a buffer of `buffer` times the
+///    size of the tile around it.
+/// 2. It's converted to tile coordinates, in which the tile's north-western corner is at
+///    `(0, 0)`, and its south-eastern corner is at `(extent, extent)`, such as 4096, so that `y`
+///    increases to the south.
+/// 3. It's [simplified](crate::Simplify) to within one unit of those coordinates, which is the
+///    resolution of the tile.
+/// 4. Its coordinates are rounded to integers, and any which round to the same coordinate as the
+///    one before them are removed.
+/// 5. Parts which have become degenerate are dropped: line strings with fewer than two
+///    coordinates, and polygon rings which enclose no area. A polygon whose exterior is dropped
+///    is dropped with its interiors.
+///
+/// The rings of polygons are oriented as vector tiles expect: exteriors have a positive area by
+/// the shoelace formula in tile coordinates, so they're clockwise on the map, and interiors a
+/// negative one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, LineString, PrepareTile, Tile};
+///
+/// let tile = Tile::new(1, 0, 0);
+/// let rect = tile.web_mercator_rect();
+/// let (width, height) = (rect.width(), rect.height());
+///
+/// // A line across the tile, which wiggles less than a unit of its extent of 4096
+/// let line = line_string![
+///     (x: rect.min().x - width, y: rect.max().y - height / 4.),
+///     (x: rect.min().x + width / 2., y: rect.max().y - height / 4. + 1.),
+///     (x: rect.max().x + width, y: rect.max().y - height / 4.),
+/// ];
+/// let prepared = line.prepare_tile(&tile, 4096, 1. / 64.);
+/// let expected: LineString<i32> = line_string![(x: -64, y: 1024), (x: 4160, y: 1024)];
+/// assert_eq!(prepared.0, [expected]);
+///
+/// // A polygon too thin to show at this zoom level is dropped
+/// let sliver = polygon![
+///     (x: rect.min().x, y: rect.center().y),
+///     (x: rect.max().x, y: rect.center().y),
+///     (x: rect.max().x, y: rect.center().y + 1.),
+/// ];
+/// assert!(sliver.prepare_tile(&tile, 4096, 0.).0.is_empty());
+/// ```
+pub trait PrepareTile {
+    type Output;
+
+    /// The part of `self` within `buffer` times the size of the tile from `tile`, in the
+    /// integer coordinates of a tile of size `extent`.
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output;
+}
+
+/// The conversion from Web Mercator coordinates to the coordinates of a tile.
+struct TileTransform {
+    origin: Coord,
+    scale: f64,
+}
+
+impl TileTransform {
+    fn new(tile: &Tile, extent: u32) -> Self {
+        let rect = tile.web_mercator_rect();
+        TileTransform {
+            origin: coord! { x: rect.min().x, y: rect.max().y },
+            scale: f64::from(extent) / tile_size(tile.z),
+        }
+    }
+
+    fn apply(&self, coord: Coord) -> Coord {
+        coord! {
+            x: (coord.x - self.origin.x) * self.scale,
+            y: (self.origin.y - coord.y) * self.scale,
+        }
+    }
+}
+
+fn quantize(coord: Coord) -> Coord<i32> {
+    // Casting saturates, and clipped coordinates are only beyond `i32` for an enormous buffer
+    coord! { x: Float::round(coord.x) as i32, y: Float::round(coord.y) as i32 }
+}
+
+/// Round `coords`, which are in tile coordinates, removing consecutive duplicates.
+fn quantize_coords(coords: &[Coord]) -> Vec<Coord<i32>> {
+    let mut quantized: Vec<Coord<i32>> = Vec::with_capacity(coords.len());
+    for &coord in coords {
+        let coord = quantize(coord);
+        if quantized.last() != Some(&coord) {
+            quantized.push(coord);
+        }
+    }
+    quantized
+}
+
+/// Twice the signed area of a closed ring, which can't overflow, as tile coordinates are `i32`s.
+fn twice_signed_area(ring: &[Coord<i32>]) -> i128 {
+    ring.windows(2)
+        .map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y)
+        })
+        .sum()
+}
+
+/// Quantize a ring in tile coordinates, oriented to have a positive area if it's an
+/// `exterior`, or `None` if it encloses no area.
+fn prepare_ring(ring: &LineString, exterior: bool) -> Option<LineString<i32>> {
+    let mut ring = quantize_coords(&ring.0);
+    if ring.len() < 4 {
+        return None;
+    }
+    let area = twice_signed_area(&ring);
+    if area == 0 {
+        return None;
+    }
+    if (area > 0) != exterior {
+        ring.reverse();
+    }
+    Some(LineString::new(ring))
+}
+
+fn prepare_points(
+    points: impl IntoIterator<Item = Point>,
+    tile: &Tile,
+    extent: u32,
+    buffer: f64,
+) -> MultiPoint<i32> {
+    let rect = tile.buffered_web_mercator_rect(buffer);
+    let transform = TileTransform::new(tile, extent);
+    points
+        .into_iter()
+        .filter(|point| {
+            (rect.min().x..=rect.max().x).contains(&point.x())
+                && (rect.min().y..=rect.max().y).contains(&point.y())
+        })
+        .map(|point| Point(quantize(transform.apply(point.0))))
+        .collect()
+}
+
+fn prepare_lines(
+    lines: &MultiLineString,
+    tile: &Tile,
+    extent: u32,
+    buffer: f64,
+) -> MultiLineString<i32> {
+    let transform = TileTransform::new(tile, extent);
+    lines
+        .clip_to_tile(tile, buffer)
+        .into_iter()
+        .filter_map(|line_string| {
+            let line_string: LineString = line_string
+                .0
+                .iter()
+                .map(|&coord| transform.apply(coord))
+                .collect();
+            let coords = quantize_coords(&line_string.simplify(&TILE_SIMPLIFY_TOLERANCE).0);
+            (coords.len() > 1).then(|| LineString::new(coords))
+        })
+        .collect()
+}
+
+fn prepare_polygons(
+    polygons: &MultiPolygon,
+    tile: &Tile,
+    extent: u32,
+    buffer: f64,
+) -> MultiPolygon<i32> {
+    let transform = TileTransform::new(tile, extent);
+    polygons
+        .clip_to_tile(tile, buffer)
+        .into_iter()
+        .filter_map(|polygon| {
+            let polygon = polygon
+                .map_coords(|coord| transform.apply(coord))
+                .simplify(&TILE_SIMPLIFY_TOLERANCE);
+            let exterior = prepare_ring(polygon.exterior(), true)?;
+            let interiors = polygon
+                .interiors()
+                .iter()
+                .filter_map(|ring| prepare_ring(ring, false))
+                .collect();
+            Some(Polygon::new(exterior, interiors))
+        })
+        .collect()
+}
+
+impl PrepareTile for Point {
+    type Output = MultiPoint<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_points([*self], tile, extent, buffer)
+    }
+}
+
+impl PrepareTile for MultiPoint {
+    type Output = MultiPoint<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_points(self.iter().copied(), tile, extent, buffer)
+    }
+}
+
+impl PrepareTile for LineString {
+    type Output = MultiLineString<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_lines(&self.clone().into(), tile, extent, buffer)
+    }
+}
+
+impl PrepareTile for MultiLineString {
+    type Output = MultiLineString<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_lines(self, tile, extent, buffer)
+    }
+}
+
+impl PrepareTile for Polygon {
+    type Output = MultiPolygon<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_polygons(&self.clone().into(), tile, extent, buffer)
+    }
+}
+
+impl PrepareTile for MultiPolygon {
+    type Output = MultiPolygon<i32>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        prepare_polygons(self, tile, extent, buffer)
+    }
+}
+
+/// Single-part geometries are prepared as multi-part ones, and collections part by part. `None`
+/// if nothing is left.
+impl PrepareTile for Geometry {
+    type Output = Option<Geometry<i32>>;
+
+    fn prepare_tile(&self, tile: &Tile, extent: u32, buffer: f64) -> Self::Output {
+        let prepared: Geometry<i32> = match self {
+            Geometry::Point(point) => point.prepare_tile(tile, extent, buffer).into(),
+            Geometry::MultiPoint(points) => points.prepare_tile(tile, extent, buffer).into(),
+            Geometry::Line(line) => LineString::from(*line)
+                .prepare_tile(tile, extent, buffer)
+                .into(),
+            Geometry::LineString(line_string) => {
+                line_string.prepare_tile(tile, extent, buffer).into()
+            }
+            Geometry::MultiLineString(lines) => lines.prepare_tile(tile, extent, buffer).into(),
+            Geometry::Polygon(polygon) => polygon.prepare_tile(tile, extent, buffer).into(),
+            Geometry::MultiPolygon(polygons) => polygons.prepare_tile(tile, extent, buffer).into(),
+            Geometry::Rect(rect) => rect.to_polygon().prepare_tile(tile, extent, buffer).into(),
+            Geometry::Triangle(triangle) => triangle
+                .to_polygon()
+                .prepare_tile(tile, extent, buffer)
+                .into(),
+            Geometry::GeometryCollection(collection) => Geometry::GeometryCollection(
+                collection
+                    .iter()
+                    .filter_map(|geometry| geometry.prepare_tile(tile, extent, buffer))
+                    .collect(),
+            ),
+        };
+        let empty = match &prepared {
+            Geometry::MultiPoint(points) => points.0.is_empty(),
+            Geometry::MultiLineString(lines) => lines.0.is_empty(),
+            Geometry::MultiPolygon(polygons) => polygons.0.is_empty(),
+            Geometry::GeometryCollection(collection) => collection.0.is_empty(),
+            _ => false,
+        };
+        (!empty).then_some(prepared)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, EuclideanLength, Intersects};
+
+    #[test]
+    fn tile_rects() {
+        assert_relative_eq!(
+            Tile::new(0, 0, 0).web_mercator_rect(),
+            Rect::new((-HALF_WORLD, -HALF_WORLD), (HALF_WORLD, HALF_WORLD))
+        );
+        let rect = Tile::new(2, 3, 0).lon_lat_rect();
+        assert_relative_eq!(rect.min().x, 90.);
+        assert_relative_eq!(rect.max().x, 180.);
+        assert_relative_eq!(rect.min().y, 66.513_260_443_111_84, epsilon = 1e-9);
+        assert_relative_eq!(rect.max().y, 85.051_128_779_806_59, epsilon = 1e-9);
+
+        let tile = Tile::new(5, 10, 12);
+        for child in tile.children() {
+            assert_eq!(child.parent(), Some(tile));
+            let center = child.web_mercator_rect().center();
+            assert_eq!(Tile::at(center, 5), tile);
+        }
+        assert_eq!(Tile::new(0, 0, 0).parent(), None);
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(Tile::at(coord! { x: 1e8, y: -1e8 }, 3), Tile::new(3, 7, 7));
+        assert_eq!(
+            Tile::at(coord! { x: HALF_WORLD, y: HALF_WORLD }, 3),
+            Tile::new(3, 7, 0)
+        );
+        // Points outside the square don't cover any tile
+        assert!(Point::new(0., 3e7).tiles(3).is_empty());
+        assert_eq!(point!(x: 0., y: 0.).tiles(1), vec![Tile::new(1, 1, 1)]);
+    }
+
+    /// The tiles at zoom level `z` which intersect `polygon`, found by testing every tile.
+    fn intersecting_tiles(polygon: &Polygon, z: u8) -> Vec<Tile> {
+        let mut tiles = vec![];
+        for x in 0..tile_count(z) {
+            for y in 0..tile_count(z) {
+                let tile = Tile::new(z, x, y);
+                if tile.web_mercator_rect().intersects(polygon) {
+                    tiles.push(tile);
+                }
+            }
+        }
+        tiles
+    }
+
+    #[test]
+    fn polygon_coverage() {
+        // Tiles 1 to 4 in both directions at zoom level 3, and a bit of their neighbours
+        let size = tile_size(3);
+        let (left, top) = (-HALF_WORLD + size, HALF_WORLD - size);
+        let rect = Rect::new(
+            (left - 1., top - 4. * size - 1.),
+            (left + 4. * size + 1., top + 1.),
+        );
+        let tiles = rect.tiles(3);
+        assert_eq!(tiles.len(), 36);
+        assert_eq!(tiles[0], Tile::new(3, 0, 0));
+        assert_eq!(tiles[35], Tile::new(3, 5, 5));
+
+        let triangle = polygon![
+            (x: left + 0.3 * size, y: top - 0.2 * size),
+            (x: left + 0.7 * size, y: top - 3.9 * size),
+            (x: left + 3.6 * size, y: top - 3.1 * size),
+        ];
+        let tiles = triangle.tiles(3);
+        assert_eq!(tiles, intersecting_tiles(&triangle, 3));
+        assert_eq!(tiles.len(), 13);
+
+        // A hole which covers four tiles
+        let with_hole = polygon![
+            exterior: [
+                (x: left + 1., y: top - 1.),
+                (x: left + 1., y: top - 4. * size + 1.),
+                (x: left + 4. * size - 1., y: top - 4. * size + 1.),
+                (x: left + 4. * size - 1., y: top - 1.),
+            ],
+            interiors: [[
+                (x: left + size - 1., y: top - size + 1.),
+                (x: left + 3. * size + 1., y: top - size + 1.),
+                (x: left + 3. * size + 1., y: top - 3. * size - 1.),
+                (x: left + size - 1., y: top - 3. * size - 1.),
+            ]],
+        ];
+        let tiles = with_hole.tiles(3);
+        assert_eq!(tiles, intersecting_tiles(&with_hole, 3));
+        assert_eq!(tiles.len(), 12);
+        assert!(!tiles.contains(&Tile::new(3, 2, 2)));
+    }
+
+    #[test]
+    fn clip_lines() {
+        let tile = Tile::new(2, 1, 1);
+        let rect = tile.web_mercator_rect();
+        let line = line_string![
+            (x: rect.min().x - 2e6, y: rect.center().y),
+            (x: rect.max().x + 2e6, y: rect.center().y),
+        ];
+        assert_relative_eq!(
+            line.clip_to_tile(&tile, 0.).euclidean_length(),
+            rect.width(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            line.clip_to_tile(&tile, 0.1).euclidean_length(),
+            rect.width() * 1.2,
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            line.tiles(2),
+            vec![Tile::new(2, 0, 1), Tile::new(2, 1, 1), Tile::new(2, 2, 1)]
+        );
+    }
+
+    #[test]
+    fn prepare_polygons() {
+        let tile = Tile::new(3, 2, 5);
+        let rect = tile.web_mercator_rect();
+        let size = rect.width();
+        let at = |x: f64, y: f64| coord! { x: rect.min().x + x * size, y: rect.max().y - y * size };
+        // Counter clockwise on the map, which is the wrong way round for an exterior, with holes
+        // which are clipped away, too small to keep, and also the wrong way round
+        let polygon = Polygon::new(
+            LineString::new(vec![
+                at(-1., -1.),
+                at(-1., 0.5),
+                at(2., 0.5),
+                at(2., -1.),
+                at(-1., -1.),
+            ]),
+            vec![
+                LineString::new(vec![at(1.5, 0.), at(1.8, 0.), at(1.8, 0.3), at(1.5, 0.)]),
+                LineString::new(vec![
+                    at(0.5, 0.2),
+                    at(0.5001, 0.2),
+                    at(0.5, 0.2001),
+                    at(0.5, 0.2),
+                ]),
+                LineString::new(vec![
+                    at(0.25, 0.1),
+                    at(0.25, 0.2),
+                    at(0.75, 0.2),
+                    at(0.75, 0.1),
+                    at(0.25, 0.1),
+                ]),
+            ],
+        );
+        let prepared = polygon.prepare_tile(&tile, 256, 0.125);
+        assert_eq!(prepared.0.len(), 1);
+        let polygon = &prepared.0[0];
+        assert_eq!(
+            polygon.exterior(),
+            &LineString::from(vec![
+                (-32, 128),
+                (-32, -32),
+                (288, -32),
+                (288, 128),
+                (-32, 128)
+            ])
+        );
+        assert_eq!(
+            polygon.interiors(),
+            [LineString::from(vec![
+                (64, 26),
+                (64, 51),
+                (192, 51),
+                (192, 26),
+                (64, 26)
+            ])]
+        );
+        assert!(twice_signed_area(&polygon.exterior().0) > 0);
+        assert!(twice_signed_area(&polygon.interiors()[0].0) < 0);
+
+        // Outside the tile and its buffer
+        let far = Rect::new(at(3., 3.), at(4., 4.));
+        assert!(far
+            .to_polygon()
+            .prepare_tile(&tile, 256, 0.125)
+            .0
+            .is_empty());
+        assert_eq!(Geometry::Rect(far).prepare_tile(&tile, 256, 0.125), None);
+    }
+
+    #[test]
+    fn prepare_points_and_lines() {
+        let tile = Tile::new(0, 0, 0);
+        let points = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: -HALF_WORLD, y: HALF_WORLD),
+            point!(x: 0., y: 1.5 * HALF_WORLD),
+        ]);
+        assert_eq!(
+            points.prepare_tile(&tile, 4096, 0.),
+            MultiPoint::from(vec![(2048, 2048), (0, 0)])
+        );
+
+        // A line which simplifies to a point is dropped, and the rest are simplified
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 1e6, y: 1e3), (x: 2e6, y: 0.), (x: 2e6, y: -1e6)],
+        ]);
+        let prepared = lines.prepare_tile(&tile, 4096, 0.);
+        assert_eq!(
+            prepared,
+            MultiLineString::new(vec![
+                line_string![(x: 2048, y: 2048), (x: 2252, y: 2048), (x: 2252, y: 2150)]
+            ])
+        );
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            lines.into(),
+            point!(x: 0., y: 1.5 * HALF_WORLD).into(),
+        ]));
+        assert_eq!(
+            collection.prepare_tile(&tile, 4096, 0.),
+            Some(Geometry::GeometryCollection(GeometryCollection::new_from(
+                vec![prepared.into()]
+            )))
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+use crate::{AffineTransform, Coord, CoordFloat, MapCoords, MapCoordsInPlace};
+
+/// A sequence of coordinate operations, applied to each coordinate of a geometry in a single
+/// pass.
+///
+/// Chaining calls like [`affine_transform`](crate::AffineOps::affine_transform) and
+/// [`map_coords`](MapCoords::map_coords) walks every coordinate of the geometry, and allocates
+/// a new geometry, once for each call. A pipeline is built up from the same operations, and
+/// then applies all of them to each coordinate in turn, in one traversal. Consecutive affine
+/// operations, including [axis swaps](Self::swap_xy) and [unit scaling](Self::scale_units),
+/// are also composed into a single matrix as they're added.
+///
+/// The operations are applied in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, AffineTransform, Coord, TransformPipeline};
+///
+/// // Survey coordinates in feet, as (northing, easting) from a local origin
+/// let survey = line_string![(x: 100., y: 200.), (x: 150.5, y: 260.25)];
+///
+/// let pipeline = TransformPipeline::new()
+///     .swap_xy()
+///     .scale_units(0.3048)
+///     .affine(AffineTransform::translate(500_000., 4_000_000.))
+///     .snap_to_grid(0.01)
+///     .map(|Coord { x, y }| Coord { x, y: y + 0.5 });
+///
+/// let projected = pipeline.apply(&survey);
+/// assert_relative_eq!(
+///     projected,
+///     line_string![(x: 500_060.96, y: 4_000_030.98), (x: 500_079.32, y: 4_000_046.37)],
+///     epsilon = 1e-6
+/// );
+/// ```
+pub struct TransformPipeline<'a, T: CoordFloat = f64> {
+    steps: Vec<Step<'a, T>>,
+}
+
+enum Step<'a, T: CoordFloat> {
+    Affine(AffineTransform<T>),
+    SnapToGrid(T),
+    ToRadians,
+    ToDegrees,
+    Map(Box<dyn Fn(Coord<T>) -> Coord<T> + 'a>),
+}
+
+impl<T: CoordFloat> fmt::Debug for Step<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Affine(transform) => f.debug_tuple("Affine").field(transform).finish(),
+            Step::SnapToGrid(size) => f.debug_tuple("SnapToGrid").field(size).finish(),
+            Step::ToRadians => f.write_str("ToRadians"),
+            Step::ToDegrees => f.write_str("ToDegrees"),
+            Step::Map(_) => f.write_str("Map(..)"),
+        }
+    }
+}
+
+impl<T: CoordFloat> fmt::Debug for TransformPipeline<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransformPipeline")
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+impl<T: CoordFloat> Default for TransformPipeline<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: CoordFloat> TransformPipeline<'a, T> {
+    /// A pipeline which leaves coordinates unchanged.
+    pub fn new() -> Self {
+        TransformPipeline { steps: Vec::new() }
+    }
+
+    /// Apply an [`AffineTransform`].
+    #[must_use]
+    pub fn affine(mut self, transform: AffineTransform<T>) -> Self {
+        if let Some(Step::Affine(previous)) = self.steps.last_mut() {
+            // The transform on the left of a composition is applied last
+            *previous = transform.compose(previous);
+        } else {
+            self.steps.push(Step::Affine(transform));
+        }
+        self
+    }
+
+    /// Swap the `x` and `y` of each coordinate, such as for data in (latitude, longitude)
+    /// order.
+    #[must_use]
+    pub fn swap_xy(self) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        self.affine(AffineTransform::new(zero, one, zero, one, zero, zero))
+    }
+
+    /// Multiply coordinates by `factor`, such as `0.3048` to convert feet to metres.
+    #[must_use]
+    pub fn scale_units(self, factor: T) -> Self {
+        self.affine(AffineTransform::scale(factor, factor, Coord::zero()))
+    }
+
+    /// Round coordinates to the nearest multiple of `grid_size`, such as to limit their
+    /// precision before serializing them. A `grid_size` less than or equal to zero leaves them
+    /// unchanged.
+    #[must_use]
+    pub fn snap_to_grid(mut self, grid_size: T) -> Self {
+        if grid_size > T::zero() {
+            self.steps.push(Step::SnapToGrid(grid_size));
+        }
+        self
+    }
+
+    /// Convert coordinates from degrees to radians, as [`ToRadians`](crate::ToRadians) does.
+    #[must_use]
+    pub fn to_radians(mut self) -> Self {
+        self.steps.push(Step::ToRadians);
+        self
+    }
+
+    /// Convert coordinates from radians to degrees, as [`ToDegrees`](crate::ToDegrees) does.
+    #[must_use]
+    pub fn to_degrees(mut self) -> Self {
+        self.steps.push(Step::ToDegrees);
+        self
+    }
+
+    /// Apply a function to each coordinate.
+    #[must_use]
+    pub fn map(mut self, func: impl Fn(Coord<T>) -> Coord<T> + 'a) -> Self {
+        self.steps.push(Step::Map(Box::new(func)));
+        self
+    }
+
+    /// Apply every operation of the pipeline, in order, to `coord`.
+    pub fn transform_coord(&self, coord: Coord<T>) -> Coord<T> {
+        self.steps.iter().fold(coord, |coord, step| match step {
+            Step::Affine(transform) => transform.apply(coord),
+            Step::SnapToGrid(size) => Coord {
+                x: (coord.x / *size).round() * *size,
+                y: (coord.y / *size).round() * *size,
+            },
+            Step::ToRadians => Coord {
+                x: coord.x.to_radians(),
+                y: coord.y.to_radians(),
+            },
+            Step::ToDegrees => Coord {
+                x: coord.x.to_degrees(),
+                y: coord.y.to_degrees(),
+            },
+            Step::Map(func) => func(coord),
+        })
+    }
+
+    /// Apply the pipeline to `geometry`, outputting a new geometry.
+    pub fn apply<G: MapCoords<T, T>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| self.transform_coord(coord))
+    }
+
+    /// Apply the pipeline to mutate `geometry`.
+    pub fn apply_in_place<G: MapCoordsInPlace<T>>(&self, geometry: &mut G) {
+        geometry.map_coords_in_place(|coord| self.transform_coord(coord))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, AffineOps, ToDegrees, ToRadians};
+
+    #[test]
+    fn matches_separate_passes() {
+        let polygon = polygon![(x: 1., y: 2.), (x: 5., y: 2.), (x: 3., y: 7.)];
+        let rotate = AffineTransform::rotate(30., (1., 1.));
+        let translate = AffineTransform::translate(10., -3.);
+        let shift = |Coord { x, y }: Coord| Coord { x: x + 1., y };
+
+        let pipeline = TransformPipeline::new()
+            .affine(rotate)
+            .affine(translate)
+            .swap_xy()
+            .to_radians()
+            .map(shift)
+            .to_degrees()
+            .scale_units(2.);
+        // The three affine operations are composed
+        assert_eq!(pipeline.steps.len(), 5);
+
+        let expected = polygon
+            .affine_transform(&rotate)
+            .affine_transform(&translate)
+            .map_coords(|Coord { x, y }| Coord { x: y, y: x })
+            .to_radians()
+            .map_coords(shift)
+            .to_degrees()
+            .map_coords(|coord| coord * 2.);
+        assert_relative_eq!(pipeline.apply(&polygon), expected, epsilon = 1e-9);
+
+        let mut in_place = polygon.clone();
+        pipeline.apply_in_place(&mut in_place);
+        assert_relative_eq!(in_place, expected, epsilon = 1e-9);
+
+        assert_eq!(TransformPipeline::new().apply(&polygon), polygon);
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let pipeline = TransformPipeline::new().snap_to_grid(0.5);
+        assert_eq!(
+            pipeline.apply(&point!(x: 1.2, y: -0.8)),
+            point!(x: 1., y: -1.)
+        );
+        let pipeline = TransformPipeline::new().snap_to_grid(0.);
+        assert_eq!(
+            pipeline.apply(&point!(x: 1.2, y: -0.8)),
+            point!(x: 1.2, y: -0.8)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{Coord, CoordNum, MapCoords, MapCoordsInPlace};
+
+/// A fallible conversion of coordinates from one coordinate reference system to another, such
+/// as a map projection, or a datum shift interpolated from a grid, for [`TransformWith`].
+///
+/// This is implemented for closures which take a `Coord` and return a `Result`, and, with the
+/// `use-proj` feature, for `proj::Proj`, so projection engines other than PROJ can be plugged
+/// into the same API.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Coord, CoordinateTransformer};
+///
+/// /// Shift coordinates by the offset of the grid cell they're in
+/// struct GridShift {
+///     cell_size: f64,
+///     offsets: Vec<Vec<Coord>>,
+/// }
+///
+/// impl CoordinateTransformer for GridShift {
+///     type Error = &'static str;
+///
+///     fn transform(&self, coord: Coord) -> Result<Coord, Self::Error> {
+///         let (column, row) = (coord.x / self.cell_size, coord.y / self.cell_size);
+///         if column < 0. || row < 0. {
+///             return Err("outside the grid");
+///         }
+///         let offset = self
+///             .offsets
+///             .get(row as usize)
+///             .and_then(|row| row.get(column as usize))
+///             .ok_or("outside the grid")?;
+///         Ok(coord + *offset)
+///     }
+/// }
+///
+/// let shift = GridShift {
+///     cell_size: 10.,
+///     offsets: vec![vec![coord! { x: 0.5, y: -0.25 }, coord! { x: 0.75, y: -0.5 }]],
+/// };
+/// assert_eq!(shift.transform(coord! { x: 15., y: 5. }), Ok(coord! { x: 15.75, y: 4.5 }));
+/// assert!(shift.transform(coord! { x: 25., y: 5. }).is_err());
+/// ```
+pub trait CoordinateTransformer<T: CoordNum = f64> {
+    /// Why a coordinate couldn't be transformed.
+    type Error;
+
+    /// Transform `coord`.
+    fn transform(&self, coord: Coord<T>) -> Result<Coord<T>, Self::Error>;
+}
+
+impl<T, E, F> CoordinateTransformer<T> for F
+where
+    T: CoordNum,
+    F: Fn(Coord<T>) -> Result<Coord<T>, E>,
+{
+    type Error = E;
+
+    fn transform(&self, coord: Coord<T>) -> Result<Coord<T>, E> {
+        self(coord)
+    }
+}
+
+#[cfg(feature = "use-proj")]
+impl CoordinateTransformer<f64> for proj::Proj {
+    type Error = proj::ProjError;
+
+    fn transform(&self, coord: Coord<f64>) -> Result<Coord<f64>, Self::Error> {
+        self.convert(coord)
+    }
+}
+
+/// Transform every coordinate of a geometry with a [`CoordinateTransformer`], stopping at the
+/// first error.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, Coord, TransformWith};
+///
+/// // Swap the axes of coordinates which are in range, as latitude and longitude
+/// let to_lon_lat = |coord: Coord| {
+///     if coord.x.abs() > 90. {
+///         return Err(format!("{} isn't a latitude", coord.x));
+///     }
+///     Ok(coord! { x: coord.y, y: coord.x })
+/// };
+///
+/// let mut track = line_string![(x: 51.5, y: -0.12), (x: 48.85, y: 2.35)];
+/// assert_eq!(
+///     track.transform_with(&to_lon_lat),
+///     Ok(line_string![(x: -0.12, y: 51.5), (x: 2.35, y: 48.85)])
+/// );
+///
+/// track.0.push(coord! { x: 120., y: 0. });
+/// assert_eq!(
+///     track.transform_with_in_place(&to_lon_lat),
+///     Err("120 isn't a latitude".to_string())
+/// );
+/// ```
+pub trait TransformWith<T: CoordNum> {
+    type Output;
+
+    /// A copy of the geometry, with each of its coordinates transformed by `transformer`.
+    fn transform_with<C>(&self, transformer: &C) -> Result<Self::Output, C::Error>
+    where
+        C: CoordinateTransformer<T>;
+
+    /// Transform each coordinate of the geometry by `transformer`.
+    ///
+    /// If a coordinate can't be transformed, those before it will have been, and those after it
+    /// won't be.
+    fn transform_with_in_place<C>(&mut self, transformer: &C) -> Result<(), C::Error>
+    where
+        C: CoordinateTransformer<T>;
+}
+
+impl<T, G> TransformWith<T> for G
+where
+    T: CoordNum,
+    G: MapCoords<T, T> + MapCoordsInPlace<T>,
+{
+    type Output = G::Output;
+
+    fn transform_with<C>(&self, transformer: &C) -> Result<Self::Output, C::Error>
+    where
+        C: CoordinateTransformer<T>,
+    {
+        self.try_map_coords(|coord| transformer.transform(coord))
+    }
+
+    fn transform_with_in_place<C>(&mut self, transformer: &C) -> Result<(), C::Error>
+    where
+        C: CoordinateTransformer<T>,
+    {
+        self.try_map_coords_in_place(|coord| transformer.transform(coord))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Geometry, GeometryCollection, Polygon};
+
+    struct Scale(f64);
+
+    impl CoordinateTransformer for Scale {
+        type Error = ();
+
+        fn transform(&self, coord: Coord) -> Result<Coord, ()> {
+            Ok(coord * self.0)
+        }
+    }
+
+    #[test]
+    fn transformers() {
+        let polygon: Polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Polygon(polygon.clone()),
+            point!(x: 2., y: 3.).into(),
+        ]);
+
+        let expected = GeometryCollection::new_from(vec![
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]),
+            point!(x: 4., y: 6.).into(),
+        ]);
+        assert_eq!(collection.transform_with(&Scale(2.)), Ok(expected.clone()));
+        let closure = |coord: Coord| Ok::<_, ()>(coord * 2.);
+        assert_eq!(collection.transform_with(&closure), Ok(expected));
+    }
+
+    #[test]
+    fn errors() {
+        let mut point = point!(x: 1., y: 2.);
+        let fail = |_: Coord| Err::<Coord, _>("failed");
+        assert_eq!(point.transform_with(&fail), Err("failed"));
+        assert_eq!(point.transform_with_in_place(&fail), Err("failed"));
+        assert_eq!(point, point!(x: 1., y: 2.));
+
+        let mut polygon: Polygon = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+        let positive_y = |coord: Coord| {
+            if coord.y > 0. {
+                Ok(coord * 10.)
+            } else {
+                Err(coord)
+            }
+        };
+        assert_eq!(
+            polygon.transform_with_in_place(&positive_y),
+            Err(Coord { x: 0., y: 0. })
+        );
+    }
+}
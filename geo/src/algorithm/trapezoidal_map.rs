@@ -0,0 +1,460 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{Coord, GeoNum, LineString, Point, Polygon};
+
+/// A point location structure over a set of polygons, answering "which polygon contains this
+/// point" in expected `O(log n)` time, where `n` is the total number of polygon edges.
+///
+/// The map is built once using the randomized incremental construction described in
+/// _Computational Geometry: Algorithms and Applications_ (de Berg et al., 2008), chapter 6: the
+/// plane is decomposed into trapezoids bounded by polygon edges and vertical walls, and a search
+/// structure over those trapezoids is constructed alongside. Compared to scanning the polygons and
+/// calling [`Contains`](crate::Contains) on each, this is a large win when many points are located
+/// against the same set of polygons, e.g. when reverse-geocoding against administrative boundaries.
+///
+/// The polygons must form a valid coverage: their interiors must not overlap, and edges may only
+/// meet at shared vertices. Edges shared by adjacent polygons are supported.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, TrapezoidalMap};
+///
+/// let west = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let east = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)];
+/// let map = TrapezoidalMap::new(&[west, east]);
+///
+/// assert_eq!(map.locate(&point!(x: 0.5, y: 0.5)), Some(0));
+/// assert_eq!(map.locate(&point!(x: 1.5, y: 0.5)), Some(1));
+/// assert_eq!(map.locate(&point!(x: 3.0, y: 0.5)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrapezoidalMap<T: GeoNum> {
+    segments: Vec<Segment<T>>,
+    trapezoids: Vec<Trapezoid<T>>,
+    nodes: Vec<Node<T>>,
+}
+
+/// A polygon edge, stored with its lexicographically smaller endpoint first, along with the
+/// indices of the polygons lying directly above and below it.
+#[derive(Debug, Clone, Copy)]
+struct Segment<T: GeoNum> {
+    left: Coord<T>,
+    right: Coord<T>,
+    above: Option<usize>,
+    below: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Trapezoid<T: GeoNum> {
+    top: Option<usize>,
+    bottom: Option<usize>,
+    left_point: Option<Coord<T>>,
+    right_point: Option<Coord<T>>,
+    /// The leaf of the search structure referring to this trapezoid.
+    node: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Node<T: GeoNum> {
+    Point {
+        point: Coord<T>,
+        left: usize,
+        right: usize,
+    },
+    Segment {
+        segment: usize,
+        above: usize,
+        below: usize,
+    },
+    Trapezoid(usize),
+}
+
+impl<T: GeoNum> TrapezoidalMap<T> {
+    /// Build a map over `polygons`. [`TrapezoidalMap::locate`] reports polygons by their position
+    /// in this iterator.
+    pub fn new<'a, I>(polygons: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Polygon<T>>,
+        T: 'a,
+    {
+        let mut segments = vec![];
+        for (index, polygon) in polygons.into_iter().enumerate() {
+            add_ring(
+                &mut segments,
+                polygon.exterior(),
+                index,
+                WindingOrder::CounterClockwise,
+            );
+            for interior in polygon.interiors() {
+                add_ring(&mut segments, interior, index, WindingOrder::Clockwise);
+            }
+        }
+
+        // Merge edges shared between adjacent polygons.
+        segments.sort_by(|a, b| lex_cmp(a.left, b.left).then_with(|| lex_cmp(a.right, b.right)));
+        segments.dedup_by(|duplicate, kept| {
+            let same = kept.left == duplicate.left && kept.right == duplicate.right;
+            if same {
+                kept.above = kept.above.or(duplicate.above);
+                kept.below = kept.below.or(duplicate.below);
+            }
+            same
+        });
+
+        // The expected size and query time only hold for a random insertion order. A fixed seed
+        // keeps construction deterministic.
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for i in (1..segments.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            segments.swap(i, (state % (i as u64 + 1)) as usize);
+        }
+
+        let mut map = TrapezoidalMap {
+            segments: Vec::with_capacity(segments.len()),
+            trapezoids: vec![],
+            nodes: vec![],
+        };
+        map.add_trapezoid(None, None, None);
+        for segment in segments {
+            map.insert(segment);
+        }
+        map
+    }
+
+    /// Return the index of the polygon containing `point`, or `None` if it lies outside of all
+    /// polygons.
+    ///
+    /// Points lying on an edge are reported as belonging to either of the polygons adjacent to
+    /// the edge.
+    pub fn locate(&self, point: &Point<T>) -> Option<usize> {
+        let coord = point.0;
+        let mut node = 0;
+        loop {
+            match self.nodes[node] {
+                Node::Trapezoid(index) => {
+                    let trapezoid = &self.trapezoids[index];
+                    return match (trapezoid.top, trapezoid.bottom) {
+                        (Some(top), _) => self.segments[top].below,
+                        (None, Some(bottom)) => self.segments[bottom].above,
+                        (None, None) => None,
+                    };
+                }
+                Node::Point { point, left, right } => {
+                    node = if lex_cmp(coord, point) == Ordering::Less {
+                        left
+                    } else {
+                        right
+                    };
+                }
+                Node::Segment {
+                    segment,
+                    above,
+                    below,
+                } => {
+                    let segment = &self.segments[segment];
+                    node = match T::Ker::orient2d(segment.left, segment.right, coord) {
+                        Orientation::Clockwise => below,
+                        _ => above,
+                    };
+                }
+            }
+        }
+    }
+
+    fn add_trapezoid(
+        &mut self,
+        top: Option<usize>,
+        bottom: Option<usize>,
+        left_point: Option<Coord<T>>,
+    ) -> usize {
+        let index = self.trapezoids.len();
+        self.trapezoids.push(Trapezoid {
+            top,
+            bottom,
+            left_point,
+            right_point: None,
+            node: self.nodes.len(),
+        });
+        self.nodes.push(Node::Trapezoid(index));
+        index
+    }
+
+    fn insert(&mut self, segment: Segment<T>) {
+        let s = self.segments.len();
+        self.segments.push(segment);
+        let (p, q) = (segment.left, segment.right);
+
+        // Find the trapezoids crossed by the new segment, from left to right.
+        let mut crossed = vec![self.locate_along(s, p)];
+        while let Some(wall) = self.trapezoids[*crossed.last().unwrap()].right_point {
+            if lex_cmp(wall, q) != Ordering::Less {
+                break;
+            }
+            crossed.push(self.locate_along(s, wall));
+        }
+        let first = self.trapezoids[crossed[0]];
+        let last = self.trapezoids[*crossed.last().unwrap()];
+
+        // The parts of the first and last trapezoids beyond the segment's endpoints.
+        let left = match first.left_point {
+            Some(left_point) if lex_cmp(left_point, p) != Ordering::Less => None,
+            _ => {
+                let left = self.add_trapezoid(first.top, first.bottom, first.left_point);
+                self.trapezoids[left].right_point = Some(p);
+                Some(left)
+            }
+        };
+        let right = match last.right_point {
+            Some(right_point) if lex_cmp(right_point, q) != Ordering::Greater => None,
+            _ => {
+                let right = self.add_trapezoid(last.top, last.bottom, Some(q));
+                self.trapezoids[right].right_point = last.right_point;
+                Some(right)
+            }
+        };
+
+        // Split the crossed trapezoids into the parts above and below the segment. Vertical walls
+        // on the far side of the segment from the point which defines them are removed, merging
+        // adjacent parts.
+        let mut upper = self.add_trapezoid(first.top, Some(s), Some(p));
+        let mut lower = self.add_trapezoid(Some(s), first.bottom, Some(p));
+        let mut parts = vec![(upper, lower)];
+        for pair in crossed.windows(2) {
+            let wall = self.trapezoids[pair[0]].right_point.unwrap();
+            let next = self.trapezoids[pair[1]];
+            let orientation = T::Ker::orient2d(p, q, wall);
+            if orientation != Orientation::Clockwise {
+                self.trapezoids[upper].right_point = Some(wall);
+                upper = self.add_trapezoid(next.top, Some(s), Some(wall));
+            }
+            if orientation != Orientation::CounterClockwise {
+                self.trapezoids[lower].right_point = Some(wall);
+                lower = self.add_trapezoid(Some(s), next.bottom, Some(wall));
+            }
+            parts.push((upper, lower));
+        }
+        self.trapezoids[upper].right_point = Some(q);
+        self.trapezoids[lower].right_point = Some(q);
+
+        // Replace the leaves of the crossed trapezoids in the search structure.
+        let count = crossed.len();
+        for (i, (trapezoid, (upper, lower))) in crossed.into_iter().zip(parts).enumerate() {
+            let mut node = Node::Segment {
+                segment: s,
+                above: self.trapezoids[upper].node,
+                below: self.trapezoids[lower].node,
+            };
+            if let (true, Some(right)) = (i == count - 1, right) {
+                self.nodes.push(node);
+                node = Node::Point {
+                    point: q,
+                    left: self.nodes.len() - 1,
+                    right: self.trapezoids[right].node,
+                };
+            }
+            if let (true, Some(left)) = (i == 0, left) {
+                self.nodes.push(node);
+                node = Node::Point {
+                    point: p,
+                    left: self.trapezoids[left].node,
+                    right: self.nodes.len() - 1,
+                };
+            }
+            let leaf = self.trapezoids[trapezoid].node;
+            self.nodes[leaf] = node;
+        }
+    }
+
+    /// Find the trapezoid containing the part of segment `s` immediately to the right of the
+    /// vertical wall through `wall`.
+    fn locate_along(&self, s: usize, wall: Coord<T>) -> usize {
+        let segment = self.segments[s];
+        let mut node = 0;
+        loop {
+            match self.nodes[node] {
+                Node::Trapezoid(index) => return index,
+                Node::Point { point, left, right } => {
+                    node = if lex_cmp(point, wall) == Ordering::Greater {
+                        left
+                    } else {
+                        right
+                    };
+                }
+                Node::Segment {
+                    segment: other,
+                    above,
+                    below,
+                } => {
+                    node = if is_above(&segment, &self.segments[other]) {
+                        above
+                    } else {
+                        below
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Lexicographic ordering of coordinates. Comparing by `y` when `x` is equal is equivalent to
+/// applying an infinitesimal shear, which lets the map treat vertical edges and vertically aligned
+/// vertices like any others.
+fn lex_cmp<T: GeoNum>(a: Coord<T>, b: Coord<T>) -> Ordering {
+    a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y))
+}
+
+/// Add the edges of `ring`, whose interior belongs to polygon `index` when the ring has the
+/// `interior_on_left` winding order.
+fn add_ring<T: GeoNum>(
+    segments: &mut Vec<Segment<T>>,
+    ring: &LineString<T>,
+    index: usize,
+    interior_on_left: WindingOrder,
+) {
+    let winding = match ring.winding_order() {
+        Some(winding) => winding,
+        None => return,
+    };
+    for line in ring.lines() {
+        let (mut above, mut below) = (Some(index), None);
+        if winding != interior_on_left {
+            core::mem::swap(&mut above, &mut below);
+        }
+        match lex_cmp(line.start, line.end) {
+            Ordering::Less => segments.push(Segment {
+                left: line.start,
+                right: line.end,
+                above,
+                below,
+            }),
+            Ordering::Greater => segments.push(Segment {
+                left: line.end,
+                right: line.start,
+                above: below,
+                below: above,
+            }),
+            Ordering::Equal => {}
+        }
+    }
+}
+
+/// Whether segment `s` lies above segment `t` over their common extent. The segments must not
+/// cross, though they may share endpoints.
+fn is_above<T: GeoNum>(s: &Segment<T>, t: &Segment<T>) -> bool {
+    use Orientation::*;
+    match (
+        T::Ker::orient2d(t.left, t.right, s.left),
+        T::Ker::orient2d(t.left, t.right, s.right),
+    ) {
+        (CounterClockwise, Clockwise) | (Clockwise, CounterClockwise) => {
+            // `s` straddles the line through `t`, so `t` lies entirely to one side of `s`.
+            match (
+                T::Ker::orient2d(s.left, s.right, t.left),
+                T::Ker::orient2d(s.left, s.right, t.right),
+            ) {
+                (Collinear, orientation) | (orientation, _) => orientation == Clockwise,
+            }
+        }
+        (Collinear, orientation) | (orientation, _) => orientation == CounterClockwise,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains, Rect};
+
+    fn grid(rows: usize, columns: usize) -> Vec<Polygon<f64>> {
+        let mut cells = vec![];
+        for row in 0..rows {
+            for column in 0..columns {
+                let (x, y) = (column as f64, row as f64);
+                cells.push(Rect::new((x, y), (x + 1., y + 1.)).to_polygon());
+            }
+        }
+        cells
+    }
+
+    fn assert_matches_contains(polygons: &[Polygon<f64>], points: impl Iterator<Item = Point>) {
+        let map = TrapezoidalMap::new(polygons);
+        for point in points {
+            let expected = polygons.iter().position(|polygon| polygon.contains(&point));
+            assert_eq!(map.locate(&point), expected, "{point:?}");
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let map = TrapezoidalMap::<f64>::new(&[]);
+        assert_eq!(map.locate(&point!(x: 0., y: 0.)), None);
+    }
+
+    #[test]
+    fn grid_coverage() {
+        let cells = grid(7, 9);
+        let points = (0..200).flat_map(|i| {
+            (0..160)
+                .map(move |j| point!(x: -0.95 + i as f64 * 0.0555, y: -0.95 + j as f64 * 0.0555))
+        });
+        assert_matches_contains(
+            &cells,
+            points.filter(|p| p.x().fract() != 0. && p.y().fract() != 0.),
+        );
+    }
+
+    #[test]
+    fn fan_sharing_a_vertex() {
+        // Triangles around a common center vertex, with arbitrary winding orders.
+        let center = (0., 0.);
+        let ring: Vec<(f64, f64)> = (0..12)
+            .map(|i| {
+                let angle = i as f64 * core::f64::consts::TAU / 12.;
+                (angle.cos() * 10., angle.sin() * 10.)
+            })
+            .collect();
+        let triangles: Vec<Polygon<f64>> = (0..12)
+            .map(|i| {
+                let (a, b) = (ring[i], ring[(i + 1) % 12]);
+                if i % 2 == 0 {
+                    polygon![center.into(), a.into(), b.into()]
+                } else {
+                    polygon![center.into(), b.into(), a.into()]
+                }
+            })
+            .collect();
+        let points = (0..50).flat_map(|i| {
+            (0..50).map(move |j| point!(x: -12.1 + i as f64 * 0.49, y: -12.3 + j as f64 * 0.49))
+        });
+        assert_matches_contains(&triangles, points);
+    }
+
+    #[test]
+    fn holes_and_islands() {
+        let with_hole = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let island = polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 5., y: 6.)];
+        let map = TrapezoidalMap::new(&[with_hole, island]);
+        assert_eq!(map.locate(&point!(x: 1., y: 5.)), Some(0));
+        assert_eq!(map.locate(&point!(x: 3., y: 5.)), None);
+        assert_eq!(map.locate(&point!(x: 5., y: 5.)), Some(1));
+        assert_eq!(map.locate(&point!(x: 11., y: 5.)), None);
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let west = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2)];
+        let east = polygon![(x: 2, y: 0), (x: 4, y: 0), (x: 4, y: 2), (x: 2, y: 2)];
+        let map = TrapezoidalMap::new(&[west, east]);
+        assert_eq!(map.locate(&point!(x: 1, y: 1)), Some(0));
+        assert_eq!(map.locate(&point!(x: 3, y: 1)), Some(1));
+        assert_eq!(map.locate(&point!(x: 5, y: 1)), None);
+    }
+}
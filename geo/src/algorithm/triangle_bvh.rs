@@ -0,0 +1,465 @@
+use alloc::vec::Vec;
+
+use geo_types::private_utils::point_line_euclidean_distance;
+use rstar::{ParentNode, PointDistance, RTree, RTreeNode, RTreeNum, RTreeObject, AABB};
+
+use crate::kernels::{Kernel, Orientation};
+use crate::{Coord, GeoFloat, Line, Point, Triangle};
+
+/// A bounding volume hierarchy over a set of triangles, such as the output of
+/// [`TriangulateEarcut`](crate::TriangulateEarcut) or the faces of a TIN, for picking the
+/// triangle under a point, casting rays, finding the nearest triangle, and interpolating
+/// elevations, in logarithmic rather than linear time.
+///
+/// The hierarchy is an R-tree of the bounding rectangles of the triangles, built once by bulk
+/// loading. Triangles are referred to by their indices, in the order they were given, and
+/// elevations, as in [`TerrainSlope`](crate::TerrainSlope), are three for each triangle in
+/// turn, one for each of its vertices.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, point, Triangle, TriangleBvh};
+///
+/// // A square of terrain, rising by 1m for each meter to the north
+/// let bvh = TriangleBvh::new(vec![
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. }, coord! { x: 10., y: 10. }),
+///     Triangle::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. }, coord! { x: 0., y: 10. }),
+/// ]);
+/// let elevations = [0., 0., 10., 0., 10., 10.];
+///
+/// assert_eq!(bvh.locate(&point!(x: 2., y: 8.)), Some(1));
+/// assert_relative_eq!(bvh.elevation_at(&point!(x: 2., y: 8.), &elevations).unwrap(), 8.);
+/// assert_eq!(bvh.nearest(&point!(x: 15., y: 1.)), Some((0, 5.)));
+///
+/// // A ray from the west, along y = 4, enters the second triangle first
+/// let hit = bvh.cast_ray(&point!(x: -5., y: 4.), coord! { x: 1., y: 0. }).unwrap();
+/// assert_eq!(hit.triangle, 1);
+/// assert_eq!(hit.point, point!(x: 0., y: 4.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriangleBvh<T: GeoFloat + RTreeNum = f64> {
+    tree: RTree<IndexedTriangle<T>>,
+    triangles: Vec<Triangle<T>>,
+}
+
+/// Where a ray cast with [`TriangleBvh::cast_ray`] first meets a triangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T: GeoFloat> {
+    /// The index of the triangle.
+    pub triangle: usize,
+    /// The distance along the ray, in multiples of its direction.
+    pub distance: T,
+    /// The point where the ray meets the triangle.
+    pub point: Point<T>,
+}
+
+impl<T: GeoFloat + RTreeNum> TriangleBvh<T> {
+    /// Build the hierarchy over `triangles`.
+    pub fn new(triangles: Vec<Triangle<T>>) -> Self {
+        let tree = RTree::bulk_load(
+            triangles
+                .iter()
+                .enumerate()
+                .map(|(index, triangle)| IndexedTriangle {
+                    triangle: *triangle,
+                    index,
+                })
+                .collect(),
+        );
+        TriangleBvh { tree, triangles }
+    }
+
+    /// Build the hierarchy over the triangles of an ear-cutting triangulation.
+    #[cfg(feature = "earcutr")]
+    pub fn from_raw(raw: &crate::triangulate_earcut::RawTriangulation<T>) -> Self {
+        Self::new((0..raw.len()).map(|index| raw.triangle(index)).collect())
+    }
+
+    /// The triangles, in order.
+    pub fn triangles(&self) -> &[Triangle<T>] {
+        &self.triangles
+    }
+
+    /// The number of triangles.
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Whether there are no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+
+    /// The index of the triangle which `point` is in, or on the boundary of, with the least
+    /// index if there are several, as along a shared edge.
+    pub fn locate(&self, point: &Point<T>) -> Option<usize> {
+        self.tree
+            .locate_all_at_point(&[point.x(), point.y()])
+            .map(|candidate| candidate.index)
+            .min()
+    }
+
+    /// The index of the triangle nearest to `point`, and its distance from it, which is zero if
+    /// the point is in it.
+    pub fn nearest(&self, point: &Point<T>) -> Option<(usize, T)> {
+        let query = [point.x(), point.y()];
+        self.tree
+            .nearest_neighbor(&query)
+            .map(|nearest| (nearest.index, nearest.distance_2(&query).sqrt()))
+    }
+
+    /// Where the ray from `origin` in `direction` first meets a triangle, which is at the
+    /// origin itself if it's in one.
+    ///
+    /// Returns `None` if the ray misses every triangle, or `direction` is zero and the origin
+    /// isn't in a triangle.
+    pub fn cast_ray(&self, origin: &Point<T>, direction: Coord<T>) -> Option<RayHit<T>> {
+        let ray = Ray {
+            origin: origin.0,
+            direction,
+        };
+        let mut best = None;
+        ray.visit(self.tree.root(), &mut best);
+        best.map(|(distance, triangle)| RayHit {
+            triangle,
+            distance,
+            point: Point::from(ray.origin + ray.direction * distance),
+        })
+    }
+
+    /// The elevation at `point`, interpolated linearly over the triangle it's in, as found by
+    /// [`locate`](Self::locate), from the `elevations` of its vertices.
+    ///
+    /// Returns `None` if the point isn't in any triangle with an area.
+    ///
+    /// # Panics
+    ///
+    /// If there aren't three elevations for each triangle.
+    pub fn elevation_at(&self, point: &Point<T>, elevations: &[T]) -> Option<T> {
+        assert_eq!(
+            elevations.len(),
+            3 * self.triangles.len(),
+            "there must be an elevation for each vertex"
+        );
+        let index = self.locate(point)?;
+        let [a, b, c] = self.triangles[index].to_array();
+        let area = cross(b - a, c - a);
+        if area == T::zero() {
+            return None;
+        }
+        let p = point.0;
+        let weights = [
+            cross(b - p, c - p) / area,
+            cross(c - p, a - p) / area,
+            cross(a - p, b - p) / area,
+        ];
+        Some(
+            weights
+                .iter()
+                .zip(&elevations[3 * index..3 * index + 3])
+                .fold(T::zero(), |sum, (weight, elevation)| {
+                    sum + *weight * *elevation
+                }),
+        )
+    }
+}
+
+/// A triangle stored in the R-tree with its index.
+#[derive(Debug, Clone, Copy)]
+struct IndexedTriangle<T: GeoFloat> {
+    triangle: Triangle<T>,
+    index: usize,
+}
+
+impl<T: GeoFloat + RTreeNum> RTreeObject for IndexedTriangle<T> {
+    type Envelope = AABB<[T; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        let [a, b, c] = self.triangle.to_array();
+        AABB::from_corners(
+            [a.x.min(b.x).min(c.x), a.y.min(b.y).min(c.y)],
+            [a.x.max(b.x).max(c.x), a.y.max(b.y).max(c.y)],
+        )
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> PointDistance for IndexedTriangle<T> {
+    fn distance_2(&self, point: &[T; 2]) -> T {
+        let coord = Coord::from(*point);
+        if self.contains_point(point) {
+            return T::zero();
+        }
+        self.triangle
+            .to_lines()
+            .iter()
+            .map(|line| point_line_euclidean_distance(Point::from(coord), *line))
+            .fold(T::infinity(), T::min)
+            .powi(2)
+    }
+
+    fn contains_point(&self, point: &[T; 2]) -> bool {
+        let coord = Coord::from(*point);
+        let [a, b, c] = self.triangle.to_array();
+        let orientations = [
+            T::Ker::orient2d(a, b, coord),
+            T::Ker::orient2d(b, c, coord),
+            T::Ker::orient2d(c, a, coord),
+        ];
+        let turns = |orientation| orientations.contains(&orientation);
+        if orientations.iter().all(|&o| o == Orientation::Collinear) {
+            // A degenerate triangle, which contains the points of its longest edge
+            return self
+                .triangle
+                .to_lines()
+                .iter()
+                .any(|line| on_line(*line, coord));
+        }
+        !(turns(Orientation::Clockwise) && turns(Orientation::CounterClockwise))
+    }
+}
+
+/// Whether `coord`, which is collinear with `line`, is on it.
+fn on_line<T: GeoFloat>(line: Line<T>, coord: Coord<T>) -> bool {
+    let (min, max) = (line.start.x.min(line.end.x), line.start.x.max(line.end.x));
+    let (min_y, max_y) = (line.start.y.min(line.end.y), line.start.y.max(line.end.y));
+    (min..=max).contains(&coord.x) && (min_y..=max_y).contains(&coord.y)
+}
+
+fn cross<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+struct Ray<T: GeoFloat> {
+    origin: Coord<T>,
+    direction: Coord<T>,
+}
+
+impl<T: GeoFloat + RTreeNum> Ray<T> {
+    /// Update `best`, the least distance and index of a triangle the ray meets, with those in
+    /// `node`, visiting its children in order of where the ray enters their envelopes, and
+    /// skipping those it enters beyond the best so far.
+    fn visit(&self, node: &ParentNode<IndexedTriangle<T>>, best: &mut Option<(T, usize)>) {
+        let mut children: Vec<(T, &RTreeNode<IndexedTriangle<T>>)> = node
+            .children()
+            .iter()
+            .filter_map(|child| {
+                let envelope = match child {
+                    RTreeNode::Leaf(triangle) => triangle.envelope(),
+                    RTreeNode::Parent(parent) => parent.envelope(),
+                };
+                Some((self.entry(&envelope)?, child))
+            })
+            .collect();
+        children.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (entry, child) in children {
+            if best.is_some_and(|(distance, _)| entry > distance) {
+                break;
+            }
+            match child {
+                RTreeNode::Leaf(triangle) => {
+                    if let Some(distance) = self.hit(triangle) {
+                        let better = match *best {
+                            None => true,
+                            Some((best, index)) => {
+                                distance < best || distance == best && triangle.index < index
+                            }
+                        };
+                        if better {
+                            *best = Some((distance, triangle.index));
+                        }
+                    }
+                }
+                RTreeNode::Parent(parent) => self.visit(parent, best),
+            }
+        }
+    }
+
+    /// The distance along the ray at which it enters `envelope`, by the slab method.
+    fn entry(&self, envelope: &AABB<[T; 2]>) -> Option<T> {
+        let (lower, upper) = (envelope.lower(), envelope.upper());
+        let (mut near, mut far) = (T::zero(), T::infinity());
+        for axis in 0..2 {
+            let (origin, direction) = match axis {
+                0 => (self.origin.x, self.direction.x),
+                _ => (self.origin.y, self.direction.y),
+            };
+            if direction == T::zero() {
+                if origin < lower[axis] || origin > upper[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let a = (lower[axis] - origin) / direction;
+            let b = (upper[axis] - origin) / direction;
+            near = near.max(a.min(b));
+            far = far.min(a.max(b));
+        }
+        (near <= far).then_some(near)
+    }
+
+    /// The distance along the ray at which it first meets `triangle`.
+    fn hit(&self, triangle: &IndexedTriangle<T>) -> Option<T> {
+        let origin = [self.origin.x, self.origin.y];
+        if triangle.contains_point(&origin) {
+            return Some(T::zero());
+        }
+        triangle
+            .triangle
+            .to_lines()
+            .iter()
+            .filter_map(|line| self.hit_line(*line))
+            .reduce(T::min)
+    }
+
+    /// The distance along the ray at which it first meets `line`.
+    fn hit_line(&self, line: Line<T>) -> Option<T> {
+        let (d, e) = (self.direction, line.delta());
+        let to_start = line.start - self.origin;
+        let denominator = cross(d, e);
+        if denominator == T::zero() {
+            // Parallel, so only met if they're on the same line, at the nearer end ahead
+            if cross(to_start, d) != T::zero() || (d.x == T::zero() && d.y == T::zero()) {
+                return None;
+            }
+            let length_2 = d.x * d.x + d.y * d.y;
+            let along = |to: Coord<T>| (to.x * d.x + to.y * d.y) / length_2;
+            let (start, end) = (along(to_start), along(line.end - self.origin));
+            return match (start >= T::zero(), end >= T::zero()) {
+                (true, true) => Some(start.min(end)),
+                (false, false) => None,
+                _ => Some(T::zero()),
+            };
+        }
+        let distance = cross(to_start, e) / denominator;
+        let fraction = cross(to_start, d) / denominator;
+        (distance >= T::zero() && fraction >= T::zero() && fraction <= T::one()).then_some(distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, EuclideanDistance, Intersects};
+
+    /// Random triangles, from a linear congruential generator
+    fn triangles(count: usize) -> Vec<Triangle> {
+        let mut state = 7_u64;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            ((state >> 33) % 1000) as f64 / 10.
+        };
+        (0..count)
+            .map(|_| {
+                let (x, y) = (next(), next());
+                Triangle::new(
+                    coord! { x: x, y: y },
+                    coord! { x: x + next() / 10., y: y + next() / 20. },
+                    coord! { x: x + next() / 20., y: y + next() / 10. },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let triangles = triangles(300);
+        let bvh = TriangleBvh::new(triangles.clone());
+        assert_eq!(bvh.len(), 300);
+
+        for i in 0..200 {
+            let point = point!(x: f64::from(i % 20) * 5.3, y: f64::from(i / 20) * 11.1);
+            let located = triangles
+                .iter()
+                .position(|triangle| triangle.intersects(&point));
+            assert_eq!(bvh.locate(&point), located, "{point:?}");
+
+            let (_, distance) = bvh.nearest(&point).unwrap();
+            let expected = triangles
+                .iter()
+                .map(|triangle| triangle.euclidean_distance(&point))
+                .fold(f64::INFINITY, f64::min);
+            assert_relative_eq!(distance, expected, epsilon = 1e-9);
+
+            let direction = coord! { x: f64::from(i % 7) - 3., y: f64::from(i % 5) - 2. };
+            let hit = bvh.cast_ray(&point, direction);
+            let ray = Ray {
+                origin: point.0,
+                direction,
+            };
+            let expected = (0..triangles.len())
+                .filter_map(|index| {
+                    let indexed = IndexedTriangle {
+                        triangle: triangles[index],
+                        index,
+                    };
+                    Some((ray.hit(&indexed)?, index))
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            assert_eq!(
+                hit.map(|hit| (hit.distance, hit.triangle)),
+                expected,
+                "{point:?} {direction:?}"
+            );
+            if let Some(hit) = hit {
+                assert!(triangles[hit.triangle].euclidean_distance(&hit.point) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn rays_and_elevations() {
+        let bvh = TriangleBvh::new(vec![
+            Triangle::new(
+                coord! { x: 0., y: 0. },
+                coord! { x: 4., y: 0. },
+                coord! { x: 0., y: 4. },
+            ),
+            Triangle::new(
+                coord! { x: 10., y: 0. },
+                coord! { x: 14., y: 0. },
+                coord! { x: 10., y: 4. },
+            ),
+        ]);
+        // Along the bottom edges, so parallel to them
+        let hit = bvh
+            .cast_ray(&point!(x: -2., y: 0.), coord! { x: 2., y: 0. })
+            .unwrap();
+        assert_eq!((hit.triangle, hit.distance), (0, 1.));
+        let hit = bvh
+            .cast_ray(&point!(x: 20., y: 1.), coord! { x: -1., y: 0. })
+            .unwrap();
+        assert_eq!((hit.triangle, hit.point), (1, point!(x: 13., y: 1.)));
+        assert!(bvh
+            .cast_ray(&point!(x: 5., y: 5.), coord! { x: 0., y: 1. })
+            .is_none());
+        assert!(bvh
+            .cast_ray(&point!(x: 5., y: 1.), coord! { x: 0., y: 0. })
+            .is_none());
+        assert_eq!(
+            bvh.cast_ray(&point!(x: 1., y: 1.), coord! { x: 0., y: 0. })
+                .map(|hit| hit.distance),
+            Some(0.)
+        );
+
+        let elevations = [0., 4., 8., 1., 1., 1.];
+        assert_relative_eq!(
+            bvh.elevation_at(&point!(x: 1., y: 1.), &elevations)
+                .unwrap(),
+            3.
+        );
+        assert_eq!(
+            bvh.elevation_at(&point!(x: 11., y: 1.), &elevations),
+            Some(1.)
+        );
+        assert_eq!(bvh.elevation_at(&point!(x: 5., y: 1.), &elevations), None);
+
+        let empty = TriangleBvh::<f64>::new(vec![]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(&point!(x: 0., y: 0.)), None);
+        assert!(empty
+            .cast_ray(&point!(x: 0., y: 0.), coord! { x: 1., y: 0. })
+            .is_none());
+    }
+}
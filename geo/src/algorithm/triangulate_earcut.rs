@@ -1,4 +1,5 @@
-use crate::{coord, CoordFloat, CoordsIter, Polygon, Triangle};
+use crate::error::check_finite;
+use crate::{coord, Area, CoordFloat, CoordsIter, Error, MultiPolygon, Point, Polygon, Triangle};
 
 /// Triangulate polygons using an [ear-cutting algorithm](https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf).
 pub trait TriangulateEarcut<T: CoordFloat> {
@@ -116,6 +117,39 @@ pub trait TriangulateEarcut<T: CoordFloat> {
     /// );
     /// ```
     fn earcut_triangles_raw(&self) -> RawTriangulation<T>;
+
+    /// The same as [`earcut_triangles`](Self::earcut_triangles), but returns an error rather
+    /// than triangles with NaN coordinates, or panicking, on bad input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Error, TriangulateEarcut};
+    ///
+    /// let triangle = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+    /// assert_eq!(triangle.try_earcut_triangles().unwrap().len(), 1);
+    ///
+    /// let nan = polygon![(x: 0., y: 0.), (x: f64::NAN, y: 0.), (x: 0., y: 1.)];
+    /// assert!(matches!(
+    ///     nan.try_earcut_triangles(),
+    ///     Err(Error::NonFiniteCoordinate)
+    /// ));
+    /// ```
+    fn try_earcut_triangles(&self) -> Result<Vec<Triangle<T>>, Error> {
+        self.try_earcut_triangles_raw()
+            .map(|raw| Iter(raw).collect())
+    }
+
+    /// The same as [`earcut_triangles_raw`](Self::earcut_triangles_raw), but returns an error
+    /// rather than vertices with NaN coordinates, or panicking, on bad input.
+    fn try_earcut_triangles_raw(&self) -> Result<RawTriangulation<T>, Error> {
+        let raw = self.earcut_triangles_raw();
+        if raw.vertices.iter().all(|value| value.is_finite()) {
+            Ok(raw)
+        } else {
+            Err(Error::NonFiniteCoordinate)
+        }
+    }
 }
 
 impl<T: CoordFloat> TriangulateEarcut<T> for Polygon<T> {
@@ -128,6 +162,30 @@ impl<T: CoordFloat> TriangulateEarcut<T> for Polygon<T> {
             triangle_indices,
         }
     }
+
+    fn try_earcut_triangles_raw(&self) -> Result<RawTriangulation<T>, Error> {
+        check_finite(self)?;
+        if self.exterior().0.is_empty() && self.interiors().is_empty() {
+            return Ok(RawTriangulation {
+                vertices: vec![],
+                triangle_indices: vec![],
+            });
+        }
+        // Rings too short to be closed, which earcutr can't triangulate
+        if core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .any(|ring| ring.0.len() < 4)
+        {
+            return Err(Error::TriangulationFailed);
+        }
+        let input = polygon_to_earcutr_input(self);
+        let triangle_indices = earcutr::earcut(&input.vertices, &input.interior_indexes, 2)
+            .map_err(|_| Error::TriangulationFailed)?;
+        Ok(RawTriangulation {
+            vertices: input.vertices,
+            triangle_indices,
+        })
+    }
 }
 
 /// The raw result of triangulating a polygon from `earcutr`.
@@ -140,6 +198,427 @@ pub struct RawTriangulation<T: CoordFloat> {
     pub triangle_indices: Vec<usize>,
 }
 
+impl<T: CoordFloat> RawTriangulation<T> {
+    /// The number of triangles.
+    pub fn len(&self) -> usize {
+        self.triangle_indices.len() / 3
+    }
+
+    /// Whether the triangulation contains no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.triangle_indices.is_empty()
+    }
+
+    /// The triangle at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not less than [`RawTriangulation::len`].
+    pub fn triangle(&self, index: usize) -> Triangle<T> {
+        let vertex = |i: usize| {
+            let vertex_index = self.triangle_indices[index * 3 + i];
+            coord! {
+                x: self.vertices[vertex_index * 2],
+                y: self.vertices[vertex_index * 2 + 1],
+            }
+        };
+        Triangle(vertex(0), vertex(1), vertex(2))
+    }
+
+    /// The unsigned area of each triangle, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, TriangulateEarcut};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+    /// let triangulation = polygon.earcut_triangles_raw();
+    ///
+    /// assert_eq!(triangulation.triangle_areas(), vec![4., 4.]);
+    /// assert_eq!(triangulation.total_area(), 8.);
+    /// ```
+    pub fn triangle_areas(&self) -> Vec<T> {
+        (0..self.len())
+            .map(|index| self.triangle(index).unsigned_area())
+            .collect()
+    }
+
+    /// The sum of the areas of all triangles.
+    pub fn total_area(&self) -> T {
+        (0..self.len()).fold(T::zero(), |total, index| {
+            total + self.triangle(index).unsigned_area()
+        })
+    }
+
+    /// The point with the given barycentric `weights` within the triangle at position `index`.
+    ///
+    /// The weights apply to the triangle's vertices in order and should sum to one; the point
+    /// lies inside the triangle if they are all non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, polygon, TriangulateEarcut};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 3.)];
+    /// let triangulation = polygon.earcut_triangles_raw();
+    ///
+    /// let third = 1. / 3.;
+    /// assert_eq!(
+    ///     triangulation.barycentric_point(0, [third, third, third]),
+    ///     point!(x: 1., y: 1.),
+    /// );
+    /// ```
+    pub fn barycentric_point(&self, index: usize, weights: [T; 3]) -> Point<T> {
+        let Triangle(a, b, c) = self.triangle(index);
+        Point::from(a * weights[0] + b * weights[1] + c * weights[2])
+    }
+
+    /// An endless iterator of random triangle positions, where each triangle is chosen with a
+    /// probability proportional to its area.
+    ///
+    /// The iterator is empty if the triangulation has no area.
+    ///
+    /// Requires the `rand` feature.
+    #[cfg(feature = "rand")]
+    pub fn random_triangles<'a, R>(&'a self, rng: &'a mut R) -> impl Iterator<Item = usize> + 'a
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let chooser = self.area_weighted_chooser();
+        core::iter::from_fn(move || chooser.choose(T::from(rng.gen::<f64>()).unwrap()))
+    }
+
+    /// An endless iterator of points uniformly distributed over the area of the triangulation.
+    ///
+    /// The iterator is empty if the triangulation has no area.
+    ///
+    /// Requires the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, Contains, TriangulateEarcut};
+    /// use rand::SeedableRng;
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+    /// let triangulation = polygon.earcut_triangles_raw();
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    /// let points: Vec<_> = triangulation.random_points(&mut rng).take(10).collect();
+    /// assert!(points.iter().all(|point| polygon.contains(point)));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_points<'a, R>(&'a self, rng: &'a mut R) -> impl Iterator<Item = Point<T>> + 'a
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let chooser = self.area_weighted_chooser();
+        core::iter::from_fn(move || {
+            let mut random = || T::from(rng.gen::<f64>()).unwrap();
+            let index = chooser.choose(random())?;
+            let (mut u, mut v) = (random(), random());
+            // Reflect points from the far half of the parallelogram back into the triangle.
+            if u + v > T::one() {
+                u = T::one() - u;
+                v = T::one() - v;
+            }
+            Some(self.barycentric_point(index, [T::one() - u - v, u, v]))
+        })
+    }
+
+    #[cfg(feature = "rand")]
+    fn area_weighted_chooser(&self) -> AreaWeightedChooser<T> {
+        let mut total = T::zero();
+        let cumulative_areas = self
+            .triangle_areas()
+            .into_iter()
+            .map(|area| {
+                total = total + area;
+                total
+            })
+            .collect();
+        AreaWeightedChooser {
+            cumulative_areas,
+            total,
+        }
+    }
+}
+
+/// Picks triangles with a probability proportional to their area.
+#[cfg(feature = "rand")]
+struct AreaWeightedChooser<T: CoordFloat> {
+    cumulative_areas: Vec<T>,
+    total: T,
+}
+
+#[cfg(feature = "rand")]
+impl<T: CoordFloat> AreaWeightedChooser<T> {
+    /// The triangle covering `fraction` (in `[0, 1)`) of the cumulative area.
+    fn choose(&self, fraction: T) -> Option<usize> {
+        if self.total <= T::zero() {
+            return None;
+        }
+        let target = fraction * self.total;
+        let index = self
+            .cumulative_areas
+            .partition_point(|area| *area <= target);
+        Some(index.min(self.cumulative_areas.len() - 1))
+    }
+}
+
+// Seal the index trait, so that only the index types of GPU APIs implement it.
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+}
+
+/// An integer type for the indices of a [`VertexBuffer`]: the `u16` and `u32` of GPU index
+/// buffers.
+pub trait VertexIndex: private::Sealed + Copy + core::fmt::Debug + PartialEq {
+    /// The greatest index of this type.
+    const MAX: usize;
+
+    /// `index` as this type, if it's at most [`MAX`](Self::MAX).
+    fn from_usize(index: usize) -> Option<Self>;
+}
+
+impl VertexIndex for u16 {
+    const MAX: usize = u16::MAX as usize;
+
+    fn from_usize(index: usize) -> Option<Self> {
+        u16::try_from(index).ok()
+    }
+}
+
+impl VertexIndex for u32 {
+    const MAX: usize = u32::MAX as usize;
+
+    fn from_usize(index: usize) -> Option<Self> {
+        u32::try_from(index).ok()
+    }
+}
+
+/// The triangulations of polygons, in the `f32` vertex and `u16` or `u32` index buffers which
+/// GPU APIs such as wgpu take, written directly rather than converted from a
+/// [`RawTriangulation`].
+///
+/// The positions are interleaved, in XY order, as in [`RawTriangulation::vertices`], and cast
+/// to `f32`. Each polygon added is triangulated by ear clipping, as with [`TriangulateEarcut`],
+/// but of its positions in the buffer, and its vertices are appended after those of the
+/// polygons before it, with the indices of its triangles offset to match, so that the buffer
+/// can be drawn as one triangle list.
+///
+/// It can also record, in buffers alongside the positions, the polygon each vertex belongs to,
+/// as the number of polygons added before it, and the ring, as `0` for the exterior and `1`
+/// onwards for the interiors in order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::triangulate_earcut::VertexBuffer;
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let triangle = polygon![(x: 3., y: 0.), (x: 4., y: 0.), (x: 3., y: 1.)];
+///
+/// let mut buffer = VertexBuffer::<u16>::new().with_polygon_ids();
+/// buffer.push_polygon(&square).unwrap();
+/// buffer.push_polygon(&triangle).unwrap();
+///
+/// // The closed rings of 5 and 4 vertices, as two triangles and one
+/// assert_eq!(buffer.vertex_count(), 9);
+/// assert_eq!(buffer.positions()[10..12], [3_f32, 0.]);
+/// assert_eq!(buffer.indices().len(), 9);
+/// assert!(buffer.indices()[6..].iter().all(|index| *index >= 5));
+/// assert_eq!(buffer.polygon_ids(), Some(&[0, 0, 0, 0, 0, 1, 1, 1, 1][..]));
+/// assert_eq!(buffer.ring_ids(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VertexBuffer<I: VertexIndex = u32> {
+    positions: Vec<f32>,
+    indices: Vec<I>,
+    polygon_ids: Option<Vec<u32>>,
+    ring_ids: Option<Vec<u32>>,
+    polygon_count: u32,
+}
+
+impl<I: VertexIndex> Default for VertexBuffer<I> {
+    fn default() -> Self {
+        VertexBuffer::new()
+    }
+}
+
+impl<I: VertexIndex> VertexBuffer<I> {
+    /// An empty buffer, of positions only.
+    pub fn new() -> Self {
+        VertexBuffer {
+            positions: Vec::new(),
+            indices: Vec::new(),
+            polygon_ids: None,
+            ring_ids: None,
+            polygon_count: 0,
+        }
+    }
+
+    /// Record the polygon of each vertex, in [`polygon_ids`](Self::polygon_ids).
+    ///
+    /// # Panics
+    ///
+    /// If polygons have been added already, whose ids weren't recorded.
+    #[must_use]
+    pub fn with_polygon_ids(mut self) -> Self {
+        assert!(
+            self.polygon_count == 0,
+            "polygon ids must be recorded from the first polygon"
+        );
+        self.polygon_ids.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Record the ring of each vertex, in [`ring_ids`](Self::ring_ids).
+    ///
+    /// # Panics
+    ///
+    /// If polygons have been added already, whose ids weren't recorded.
+    #[must_use]
+    pub fn with_ring_ids(mut self) -> Self {
+        assert!(
+            self.polygon_count == 0,
+            "ring ids must be recorded from the first polygon"
+        );
+        self.ring_ids.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Triangulate `polygon`, and append its vertices and triangles.
+    ///
+    /// # Errors
+    ///
+    /// The buffer is left as it was if:
+    ///
+    /// - [`Error::IndexOverflow`]: its vertices would take the indices past [`VertexIndex::MAX`].
+    /// - [`Error::NonFiniteCoordinate`]: a coordinate isn't finite, including once cast to `f32`.
+    /// - [`Error::TriangulationFailed`]: it can't be triangulated, as from
+    ///   [`try_earcut_triangles_raw`](TriangulateEarcut::try_earcut_triangles_raw).
+    pub fn push_polygon<T: CoordFloat>(&mut self, polygon: &Polygon<T>) -> Result<(), Error> {
+        let first = self.vertex_count();
+        // Checked before triangulating, since every coordinate becomes a vertex
+        if polygon.coords_count() > 0 && first + polygon.coords_count() - 1 > I::MAX {
+            return Err(Error::IndexOverflow);
+        }
+        // Rings too short to be closed, which earcutr can't triangulate
+        if !(polygon.exterior().0.is_empty() && polygon.interiors().is_empty())
+            && core::iter::once(polygon.exterior())
+                .chain(polygon.interiors())
+                .any(|ring| ring.0.len() < 4)
+        {
+            return Err(Error::TriangulationFailed);
+        }
+        let triangle_indices = match self.push_positions(polygon) {
+            Ok(triangle_indices) => triangle_indices,
+            Err(error) => {
+                self.positions.truncate(first * 2);
+                return Err(error);
+            }
+        };
+
+        self.indices.extend(triangle_indices.iter().map(|index| {
+            I::from_usize(first + index).expect("indices are checked before triangulating")
+        }));
+        if let Some(polygon_ids) = &mut self.polygon_ids {
+            polygon_ids.resize(self.positions.len() / 2, self.polygon_count);
+        }
+        if let Some(ring_ids) = &mut self.ring_ids {
+            let rings = core::iter::once(polygon.exterior()).chain(polygon.interiors());
+            for (ring_id, ring) in (0..).zip(rings) {
+                ring_ids.extend(core::iter::repeat(ring_id).take(ring.0.len()));
+            }
+        }
+        self.polygon_count += 1;
+        Ok(())
+    }
+
+    /// Append the positions of the vertices of `polygon`, and triangulate them, returning the
+    /// indices of its triangles among them.
+    fn push_positions<T: CoordFloat>(&mut self, polygon: &Polygon<T>) -> Result<Vec<usize>, Error> {
+        let start = self.positions.len();
+        let mut interior_indexes = Vec::with_capacity(polygon.interiors().len());
+        for (ring_id, ring) in core::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .enumerate()
+        {
+            if ring_id > 0 {
+                interior_indexes.push((self.positions.len() - start) / 2);
+            }
+            for coord in &ring.0 {
+                let (x, y) = coord
+                    .x
+                    .to_f32()
+                    .zip(coord.y.to_f32())
+                    .filter(|(x, y)| x.is_finite() && y.is_finite())
+                    .ok_or(Error::NonFiniteCoordinate)?;
+                self.positions.push(x);
+                self.positions.push(y);
+            }
+        }
+        if self.positions.len() == start {
+            return Ok(Vec::new());
+        }
+        earcutr::earcut(&self.positions[start..], &interior_indexes, 2)
+            .map_err(|_| Error::TriangulationFailed)
+    }
+
+    /// Triangulate each polygon of `multi_polygon`, as with
+    /// [`push_polygon`](Self::push_polygon), stopping at the first which fails.
+    pub fn push_multi_polygon<T: CoordFloat>(
+        &mut self,
+        multi_polygon: &MultiPolygon<T>,
+    ) -> Result<(), Error> {
+        multi_polygon
+            .iter()
+            .try_for_each(|polygon| self.push_polygon(polygon))
+    }
+
+    /// The positions of the vertices, interleaved in XY order.
+    pub fn positions(&self) -> &[f32] {
+        &self.positions
+    }
+
+    /// The indices of the vertices of the triangles, three per triangle.
+    pub fn indices(&self) -> &[I] {
+        &self.indices
+    }
+
+    /// The polygon of each vertex, if they're recorded.
+    pub fn polygon_ids(&self) -> Option<&[u32]> {
+        self.polygon_ids.as_deref()
+    }
+
+    /// The ring of each vertex, if they're recorded.
+    pub fn ring_ids(&self) -> Option<&[u32]> {
+        self.ring_ids.as_deref()
+    }
+
+    /// The number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / 2
+    }
+
+    /// The number of polygons added.
+    pub fn polygon_count(&self) -> usize {
+        self.polygon_count as usize
+    }
+
+    /// The positions and indices, without copying them.
+    pub fn into_buffers(self) -> (Vec<f32>, Vec<I>) {
+        (self.positions, self.indices)
+    }
+}
+
 #[derive(Debug)]
 pub struct Iter<T: CoordFloat>(RawTriangulation<T>);
 
@@ -203,8 +682,8 @@ fn flat_line_string_coords_2<T: CoordFloat>(
 
 #[cfg(test)]
 mod test {
-    use super::TriangulateEarcut;
-    use crate::{coord, polygon, Triangle};
+    use super::{TriangulateEarcut, VertexBuffer};
+    use crate::{coord, polygon, Error, LineString, MultiPolygon, Polygon, Triangle};
 
     #[test]
     fn test_triangle() {
@@ -227,6 +706,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_try_earcut_triangles() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.try_earcut_triangles().unwrap(), vec![]);
+
+        let open = Polygon::new(
+            LineString::new(vec![coord! { x: 0., y: 0. }, coord! { x: 1., y: 0. }]),
+            vec![],
+        );
+        let mut open_with_hole = open.clone();
+        open_with_hole.interiors_push(LineString::new(vec![]));
+        assert!(matches!(
+            open_with_hole.try_earcut_triangles(),
+            Err(Error::TriangulationFailed)
+        ));
+
+        let infinite = polygon![(x: 0., y: 0.), (x: 1., y: f64::INFINITY), (x: 0., y: 1.)];
+        assert!(matches!(
+            infinite.try_earcut_triangles_raw(),
+            Err(Error::NonFiniteCoordinate)
+        ));
+    }
+
     #[test]
     fn test_square() {
         let square_polygon = polygon![
@@ -256,4 +758,113 @@ mod test {
             triangles,
         );
     }
+
+    #[test]
+    fn test_mesh_areas() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let triangulation = polygon.earcut_triangles_raw();
+        assert_eq!(triangulation.len(), polygon.earcut_triangles().len());
+        assert_relative_eq!(triangulation.total_area(), 64.);
+        assert_relative_eq!(triangulation.triangle_areas().iter().sum::<f64>(), 64.);
+        for index in 0..triangulation.len() {
+            let triangle = triangulation.triangle(index);
+            assert_eq!(
+                triangulation.barycentric_point(index, [1., 0., 0.]),
+                triangle.0.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_vertex_buffer() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let mut buffer = VertexBuffer::<u32>::new()
+            .with_polygon_ids()
+            .with_ring_ids();
+        buffer
+            .push_multi_polygon(&MultiPolygon::new(vec![square.clone(), square.clone()]))
+            .unwrap();
+        assert_eq!(buffer.polygon_count(), 2);
+        assert_eq!(buffer.vertex_count(), 20);
+        assert_eq!(
+            buffer.ring_ids().unwrap()[..10],
+            [0, 0, 0, 0, 0, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(buffer.polygon_ids().unwrap()[9..11], [0, 1]);
+
+        let raw = square.earcut_triangles_raw();
+        let (positions, indices) = buffer.into_buffers();
+        let cast: Vec<f32> = raw.vertices.iter().map(|value| *value as f32).collect();
+        assert_eq!(positions, [cast.clone(), cast].concat());
+        let offset: Vec<u32> = raw
+            .triangle_indices
+            .iter()
+            .map(|i| *i as u32 + 10)
+            .collect();
+        assert_eq!(indices[indices.len() / 2..], offset);
+
+        // A ring with more vertices than u16 indices can count
+        let circle: LineString = (0..70_000)
+            .map(|i| {
+                let angle = f64::from(i) / 70_000. * core::f64::consts::TAU;
+                coord! { x: angle.cos(), y: angle.sin() }
+            })
+            .collect();
+        let mut buffer = VertexBuffer::<u16>::new().with_ring_ids();
+        buffer.push_polygon(&square).unwrap();
+        assert!(matches!(
+            buffer.push_polygon(&Polygon::new(circle, vec![])),
+            Err(Error::IndexOverflow)
+        ));
+        let huge = polygon![(x: 0., y: 0.), (x: 1e300, y: 0.), (x: 0., y: 1.)];
+        assert!(matches!(
+            buffer.push_polygon(&huge),
+            Err(Error::NonFiniteCoordinate)
+        ));
+        assert_eq!(buffer.vertex_count(), 10);
+        assert_eq!(buffer.ring_ids().unwrap().len(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vertex_buffer_ids_after_polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let mut buffer = VertexBuffer::<u32>::new();
+        buffer.push_polygon(&square).unwrap();
+        let _ = buffer.with_polygon_ids();
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_triangles_are_area_weighted() {
+        use rand::SeedableRng;
+
+        // Two triangles of different areas
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 4., y: 1.), (x: 0., y: 1.)];
+        let triangulation = polygon.earcut_triangles_raw();
+        let areas = triangulation.triangle_areas();
+        let larger = if areas[0] > areas[1] { 0 } else { 1 };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let hits = triangulation
+            .random_triangles(&mut rng)
+            .take(10_000)
+            .filter(|index| *index == larger)
+            .count();
+        let expected = areas[larger] / triangulation.total_area() * 10_000.;
+        assert!((hits as f64 - expected).abs() < 300., "{hits}");
+
+        let empty = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        assert_eq!(
+            empty.earcut_triangles_raw().random_points(&mut rng).count(),
+            0
+        );
+    }
 }
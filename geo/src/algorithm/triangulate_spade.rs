@@ -1,4 +1,5 @@
-use geo_types::{Coord, Line, Point, Triangle};
+use alloc::{boxed::Box, vec, vec::Vec};
+use geo_types::{Coord, Line, MultiLineString, Point, Triangle};
 use spade::{
     ConstrainedDelaunayTriangulation, DelaunayTriangulation, Point2, SpadeNum, Triangulation,
 };
@@ -28,7 +29,7 @@ where
 {
     fn default() -> Self {
         Self {
-            snap_radius: <T as std::convert::From<f32>>::from(0.000_1),
+            snap_radius: <T as core::convert::From<f32>>::from(0.000_1),
         }
     }
 }
@@ -42,12 +43,13 @@ pub enum TriangulationError {
     ConstraintFailure,
 }
 
-impl std::fmt::Display for TriangulationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for TriangulationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for TriangulationError {}
 
 pub type TriangulationResult<T> = Result<T, TriangulationError>;
@@ -204,23 +206,7 @@ where
     ) -> TriangulationResult<Triangles<T>> {
         let lines = self.lines();
         let lines = Self::cleanup_lines(lines, config.snap_radius)?;
-        lines
-            .into_iter()
-            .map(to_spade_line)
-            .try_fold(
-                ConstrainedDelaunayTriangulation::<Point2<T>>::new(),
-                |mut cdt, [start, end]| {
-                    let start = cdt.insert(start).map_err(TriangulationError::SpadeError)?;
-                    let end = cdt.insert(end).map_err(TriangulationError::SpadeError)?;
-                    // safety check (to prevent panic) whether we can add the line
-                    if !cdt.can_add_constraint(start, end) {
-                        return Err(TriangulationError::ConstraintFailure);
-                    }
-                    cdt.add_constraint(start, end);
-                    Ok(cdt)
-                },
-            )
-            .map(triangulation_to_triangles)
+        constrained_triangulation_of_lines(lines)
     }
 
     /// returns triangulation that's based on the points of the geometric object and also
@@ -286,6 +272,98 @@ where
                     .collect::<Vec<_>>()
             })
     }
+
+    /// returns triangulation that's based on the points of the geometric object and of the
+    /// `breaklines`, and also incorporates the lines of both
+    ///
+    /// Breaklines are lines which the edges of the triangles are forced to follow, in addition
+    /// to the lines of the input geometry, such as the ridgelines and streams of a terrain
+    /// model, or the internal seams of a mesh. They may cross each other and the lines of the
+    /// input geometry: they're split where they do.
+    ///
+    /// As for `constrained_outer_triangulation`, this also contains triangles that are not
+    /// included in the input geometry if it wasn't convex, or if breaklines extend beyond it.
+    fn constrained_outer_triangulation_with_breaklines(
+        &'a self,
+        breaklines: &MultiLineString<T>,
+        config: SpadeTriangulationConfig<T>,
+    ) -> TriangulationResult<Triangles<T>> {
+        let mut lines = self.lines();
+        lines.extend(breaklines.lines_iter());
+        let lines = Self::cleanup_lines(lines, config.snap_radius)?;
+        constrained_triangulation_of_lines(lines)
+    }
+
+    /// returns triangulation that's based on the points of the geometric object and of the
+    /// `breaklines`, and also incorporates the lines of both
+    ///
+    /// This is `constrained_triangulation`, whose triangles also have edges along the
+    /// breaklines, as for `constrained_outer_triangulation_with_breaklines`. The parts of
+    /// breaklines outside the input geometry are ignored.
+    ///
+    /// ```rust
+    /// use geo::TriangulateSpade;
+    /// use geo::{line_string, polygon, Line, MultiLineString};
+    ///
+    /// let terrain = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    /// // A stream across the terrain
+    /// let stream = line_string![(x: 1., y: 2.), (x: 2., y: 2.5), (x: 3., y: 2.)];
+    ///
+    /// let triangles = terrain
+    ///     .constrained_triangulation_with_breaklines(
+    ///         &MultiLineString::new(vec![stream.clone()]),
+    ///         Default::default(),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// // Every segment of the stream is an edge of a triangle
+    /// for segment in stream.lines() {
+    ///     assert!(triangles.iter().any(|triangle| triangle
+    ///         .to_lines()
+    ///         .iter()
+    ///         .any(|edge| *edge == segment || *edge == Line::new(segment.end, segment.start))));
+    /// }
+    /// ```
+    fn constrained_triangulation_with_breaklines(
+        &'a self,
+        breaklines: &MultiLineString<T>,
+        config: SpadeTriangulationConfig<T>,
+    ) -> TriangulationResult<Triangles<T>> {
+        self.constrained_outer_triangulation_with_breaklines(breaklines, config)
+            .map(|triangles| {
+                triangles
+                    .into_iter()
+                    .filter(|triangle| {
+                        let center = triangle.centroid();
+                        self.contains_point(center)
+                    })
+                    .collect::<Vec<_>>()
+            })
+    }
+}
+
+/// the constrained triangulation whose constraints are `lines`, which mustn't intersect except
+/// at their ends
+fn constrained_triangulation_of_lines<T: SpadeTriangulationFloat>(
+    lines: Vec<Line<T>>,
+) -> TriangulationResult<Triangles<T>> {
+    lines
+        .into_iter()
+        .map(to_spade_line)
+        .try_fold(
+            ConstrainedDelaunayTriangulation::<Point2<T>>::new(),
+            |mut cdt, [start, end]| {
+                let start = cdt.insert(start).map_err(TriangulationError::SpadeError)?;
+                let end = cdt.insert(end).map_err(TriangulationError::SpadeError)?;
+                // safety check (to prevent panic) whether we can add the line
+                if !cdt.can_add_constraint(start, end) {
+                    return Err(TriangulationError::ConstraintFailure);
+                }
+                cdt.add_constraint(start, end);
+                Ok(cdt)
+            },
+        )
+        .map(triangulation_to_triangles)
 }
 
 /// conversion from spade triangulation back to geo triangles
@@ -586,6 +664,7 @@ fn to_spade_point<T: SpadeTriangulationFloat>(coord: Coord<T>) -> Point2<T> {
 #[cfg(test)]
 mod spade_triangulation {
     use super::*;
+    use crate::Area;
     use geo_types::*;
 
     fn assert_num_triangles<T: SpadeTriangulationFloat>(
@@ -782,4 +861,63 @@ mod spade_triangulation {
             assert_num_triangles(&constrained_triangulation, 6);
         }
     }
+
+    #[test]
+    fn breaklines_are_edges() {
+        let square = Rect::new(Coord { x: 0.0, y: 0.0 }, Coord { x: 4.0, y: 4.0 });
+        // crossing each other in the middle, and the second crossing the edges of the square
+        let breaklines = MultiLineString::new(vec![
+            LineString::from(vec![(1.0, 2.0), (3.0, 2.0)]),
+            LineString::from(vec![(2.0, -1.0), (2.0, 5.0)]),
+        ]);
+        let has_edge = |triangles: &Triangles<f64>, start: (f64, f64), end: (f64, f64)| {
+            let edge = Line::new(start, end);
+            let reversed = Line::new(end, start);
+            triangles.iter().any(|triangle| {
+                triangle
+                    .to_lines()
+                    .iter()
+                    .any(|line| *line == edge || *line == reversed)
+            })
+        };
+
+        let triangles = square
+            .constrained_triangulation_with_breaklines(&breaklines, Default::default())
+            .unwrap();
+        for (start, end) in [
+            ((1.0, 2.0), (2.0, 2.0)),
+            ((2.0, 2.0), (3.0, 2.0)),
+            ((2.0, 0.0), (2.0, 2.0)),
+            ((2.0, 2.0), (2.0, 4.0)),
+        ] {
+            assert!(has_edge(&triangles, start, end), "{start:?} {end:?}");
+        }
+        let area: f64 = triangles
+            .iter()
+            .map(|triangle| triangle.unsigned_area())
+            .sum();
+        assert_relative_eq!(area, 16.0);
+
+        // the outer triangulation also covers the parts of the breaklines outside the square
+        let outer = square
+            .constrained_outer_triangulation_with_breaklines(&breaklines, Default::default())
+            .unwrap();
+        assert!(outer.len() > triangles.len());
+        assert!(has_edge(&outer, (2.0, -1.0), (2.0, 0.0)));
+
+        // without breaklines, it's the same as the constrained triangulation
+        assert_eq!(
+            square
+                .constrained_triangulation_with_breaklines(
+                    &MultiLineString::new(vec![]),
+                    Default::default()
+                )
+                .unwrap()
+                .len(),
+            square
+                .constrained_triangulation(Default::default())
+                .unwrap()
+                .len()
+        );
+    }
 }
@@ -0,0 +1,211 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum};
+
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, MultiPolygon, Point, Polygon, Triangle, TriangulateEarcut};
+
+/// A polygon triangulated once, with [`TriangulateEarcut`], for answering whether it contains
+/// points by finding the triangle they're in.
+///
+/// Each test only looks at the few triangles, indexed in an R-tree, whose bounding rectangles
+/// hold the point, rather than at all the edges of the polygon as
+/// [`Contains`](crate::Contains) does. For a huge polygon which doesn't change, such as a
+/// country's border, tested against millions of points, the triangulation soon pays for
+/// itself.
+///
+/// As with [`Contains`](crate::Contains), a point on the boundary of the polygon isn't
+/// contained by it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, TriangulatedPolygon};
+///
+/// let polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+/// ];
+/// let triangulated = TriangulatedPolygon::new(&polygon);
+///
+/// assert!(triangulated.contains(&point!(x: 2., y: 2.)));
+/// // In the hole
+/// assert!(!triangulated.contains(&point!(x: 5., y: 5.)));
+/// // On the boundary
+/// assert!(!triangulated.contains(&point!(x: 10., y: 5.)));
+/// assert!(!triangulated.contains(&point!(x: 20., y: 5.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriangulatedPolygon<T: GeoFloat + RTreeNum> {
+    triangles: Vec<Triangle<T>>,
+    // For each triangle, whether each of its edges is on the boundary of the polygon, rather
+    // than shared with another triangle
+    boundary_edges: Vec<[bool; 3]>,
+    tree: RTree<GeomWithData<Rectangle<[T; 2]>, usize>>,
+}
+
+impl<T: GeoFloat + RTreeNum> TriangulatedPolygon<T> {
+    /// Triangulate `polygon`.
+    pub fn new(polygon: &Polygon<T>) -> Self {
+        Self::from_triangles(polygon.earcut_triangles())
+    }
+
+    /// Triangulate each of the polygons of `multi_polygon`, which mustn't overlap.
+    pub fn from_multi_polygon(multi_polygon: &MultiPolygon<T>) -> Self {
+        Self::from_triangles(
+            multi_polygon
+                .iter()
+                .flat_map(|polygon| polygon.earcut_triangles_iter())
+                .collect(),
+        )
+    }
+
+    fn from_triangles(triangles: Vec<Triangle<T>>) -> Self {
+        // Edges which two triangles share are the diagonals of the triangulation, and the
+        // others are the edges of the polygon
+        let mut edges: Vec<(Coord<T>, Coord<T>, usize, usize)> = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, triangle)| {
+                let [a, b, c] = triangle.to_array();
+                [(a, b), (b, c), (c, a)]
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(j, (start, end))| match lex_cmp(&start, &end) {
+                        Ordering::Greater => (end, start, i, j),
+                        _ => (start, end, i, j),
+                    })
+            })
+            .collect();
+        let compare = |a: &(Coord<T>, Coord<T>, usize, usize),
+                       b: &(Coord<T>, Coord<T>, usize, usize)| {
+            lex_cmp(&a.0, &b.0).then_with(|| lex_cmp(&a.1, &b.1))
+        };
+        edges.sort_by(compare);
+        let mut boundary_edges = vec![[true; 3]; triangles.len()];
+        for (k, edge) in edges.iter().enumerate() {
+            let shared = (k > 0 && compare(&edges[k - 1], edge).is_eq())
+                || (k + 1 < edges.len() && compare(&edges[k + 1], edge).is_eq());
+            boundary_edges[edge.2][edge.3] = !shared;
+        }
+
+        let tree = RTree::bulk_load(
+            triangles
+                .iter()
+                .enumerate()
+                .map(|(i, triangle)| {
+                    let [a, b, c] = triangle.to_array();
+                    let min = [a.x.min(b.x).min(c.x), a.y.min(b.y).min(c.y)];
+                    let max = [a.x.max(b.x).max(c.x), a.y.max(b.y).max(c.y)];
+                    GeomWithData::new(Rectangle::from_corners(min, max), i)
+                })
+                .collect(),
+        );
+        TriangulatedPolygon {
+            triangles,
+            boundary_edges,
+            tree,
+        }
+    }
+
+    /// The triangles of the polygon.
+    pub fn triangles(&self) -> &[Triangle<T>] {
+        &self.triangles
+    }
+
+    /// Whether the interior of the polygon contains `point`.
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        let coord = point.0;
+        for candidate in self.tree.locate_all_at_point(&[coord.x, coord.y]) {
+            let i = candidate.data;
+            let [a, b, c] = self.triangles[i].to_array();
+            let orientations = [
+                T::Ker::orient2d(a, b, coord),
+                T::Ker::orient2d(b, c, coord),
+                T::Ker::orient2d(c, a, coord),
+            ];
+            let turns = |orientation| orientations.contains(&orientation);
+            // Degenerate triangles, and triangles which the point is outside of, can't tell
+            if turns(Orientation::Clockwise) && turns(Orientation::CounterClockwise)
+                || orientations.iter().all(|&o| o == Orientation::Collinear)
+            {
+                continue;
+            }
+            let mut on_edges = (0..3).filter(|&j| orientations[j] == Orientation::Collinear);
+            return match (on_edges.next(), on_edges.next()) {
+                (None, _) => true,
+                // On an edge, which is either a diagonal, inside the polygon, or its boundary
+                (Some(j), None) => !self.boundary_edges[i][j],
+                // On a vertex, which is on the boundary
+                (Some(_), Some(_)) => false,
+            };
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains};
+
+    #[test]
+    fn matches_contains() {
+        // A comb, with a hole in its spine
+        let polygon: Polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 8., y: 10.),
+                (x: 8., y: 3.),
+                (x: 6., y: 3.),
+                (x: 6., y: 10.),
+                (x: 4., y: 10.),
+                (x: 4., y: 3.),
+                (x: 2., y: 3.),
+                (x: 2., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 9., y: 2.), (x: 9., y: 1.)]],
+        ];
+        let triangulated = TriangulatedPolygon::new(&polygon);
+        for x in 0..=40 {
+            for y in 0..=44 {
+                let point = point!(x: x as f64 * 0.25 - 0.125, y: y as f64 * 0.25 - 0.5);
+                assert_eq!(
+                    triangulated.contains(&point),
+                    polygon.contains(&point),
+                    "{point:?}"
+                );
+                // On the grid of the vertices, edges and diagonals
+                let point = point!(x: x as f64 * 0.25, y: y as f64 * 0.25 - 0.5);
+                assert_eq!(
+                    triangulated.contains(&point),
+                    polygon.contains(&point),
+                    "{point:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+        ]);
+        let triangulated = TriangulatedPolygon::from_multi_polygon(&multi_polygon);
+        assert_eq!(triangulated.triangles().len(), 4);
+        assert!(triangulated.contains(&point!(x: 0.5, y: 0.5)));
+        assert!(triangulated.contains(&point!(x: 1.5, y: 1.5)));
+        assert!(!triangulated.contains(&point!(x: 1., y: 1.)));
+        assert!(!triangulated.contains(&point!(x: 1.5, y: 0.5)));
+
+        let empty = TriangulatedPolygon::from_multi_polygon(&MultiPolygon::<f64>::new(vec![]));
+        assert!(!empty.contains(&point!(x: 0., y: 0.)));
+    }
+}
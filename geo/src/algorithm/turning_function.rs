@@ -0,0 +1,246 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Coord, GeoFloat, LineString, Polygon};
+
+/// Determine the similarity between the shapes of two outlines with the distance between their
+/// [turning functions], as in [An Efficiently Computable Metric for Comparing Polygonal Shapes]
+/// by E. Arkin et al.
+///
+/// The turning function of an outline is the direction of its edges as a function of the
+/// distance along it, with its length scaled to 1. The distance is the L2 distance between the
+/// turning functions, minimised over rotations of the outlines and over the vertex at which
+/// each closed outline starts. It's 0 for shapes which are the same apart from their position,
+/// scale and rotation, and grows as they differ, which makes it suited to matching footprints
+/// between datasets which don't align exactly.
+///
+/// Rings are compared counter-clockwise, whichever way they're oriented, so mirror images of a
+/// shape are generally at a positive distance from it. Open `LineString`s are compared from
+/// their first coordinates. Returns zero if either outline has no length.
+///
+/// [turning functions]: https://en.wikipedia.org/wiki/Turning_function
+/// [An Efficiently Computable Metric for Comparing Polygonal Shapes]: https://doi.org/10.1109/34.75509
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Rotate, Scale, Translate, TurningDistance};
+///
+/// let footprint = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 6.),
+///     (x: 4., y: 6.),
+///     (x: 4., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+/// // The same building in another dataset, in different units and rotated
+/// let matched = footprint.scale(0.3).rotate_around_centroid(20.).translate(100., 50.);
+/// assert!(footprint.turning_distance(&matched) < 1e-6);
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 8.), (x: 0., y: 8.)];
+/// assert!(footprint.turning_distance(&square) > 0.5);
+/// ```
+pub trait TurningDistance<T: GeoFloat, Rhs = Self> {
+    fn turning_distance(&self, other: &Rhs) -> T;
+}
+
+impl<T: GeoFloat> TurningDistance<T> for Polygon<T> {
+    fn turning_distance(&self, other: &Self) -> T {
+        self.exterior().turning_distance(other.exterior())
+    }
+}
+
+impl<T: GeoFloat> TurningDistance<T> for LineString<T> {
+    fn turning_distance(&self, other: &Self) -> T {
+        let closed = self.is_closed() && other.is_closed();
+        let (Some(a), Some(b)) = (
+            TurningFunction::new(self, closed),
+            TurningFunction::new(other, closed),
+        ) else {
+            return T::zero();
+        };
+
+        // The distance only has minima where a vertex of one function lines up with a vertex of
+        // the other
+        let shifts: Vec<T> = if closed {
+            a.starts
+                .iter()
+                .flat_map(|&s| b.starts.iter().map(move |&t| t - s))
+                .collect()
+        } else {
+            vec![T::zero()]
+        };
+        shifts
+            .into_iter()
+            .map(|shift| a.squared_distance(&b, shift))
+            .fold(T::infinity(), T::min)
+            .max(T::zero())
+            .sqrt()
+    }
+}
+
+/// A step function from the distance along an outline, from 0 to 1, to the direction of the
+/// outline there.
+struct TurningFunction<T> {
+    /// The distance at which each edge starts, in increasing order from 0.
+    starts: Vec<T>,
+    /// The direction of each edge, in radians, without jumps between them.
+    angles: Vec<T>,
+    /// The amount by which the direction grows in one circuit of a closed outline.
+    period_turn: T,
+}
+
+impl<T: GeoFloat> TurningFunction<T> {
+    fn new(line_string: &LineString<T>, closed: bool) -> Option<Self> {
+        let mut coords: Vec<Coord<T>> = line_string.0.clone();
+        coords.dedup();
+        if closed && signed_area(&coords) < T::zero() {
+            coords.reverse();
+        }
+        let edges: Vec<Coord<T>> = coords.windows(2).map(|w| w[1] - w[0]).collect();
+        let total = edges
+            .iter()
+            .fold(T::zero(), |sum, edge| sum + edge.x.hypot(edge.y));
+        if edges.is_empty() || total <= T::zero() {
+            return None;
+        }
+
+        let turn = |u: Coord<T>, v: Coord<T>| (u.x * v.y - u.y * v.x).atan2(u.x * v.x + u.y * v.y);
+        let mut starts = Vec::with_capacity(edges.len());
+        let mut angles = Vec::with_capacity(edges.len());
+        let (mut start, mut angle) = (T::zero(), edges[0].y.atan2(edges[0].x));
+        for (i, edge) in edges.iter().enumerate() {
+            if i > 0 {
+                angle = angle + turn(edges[i - 1], *edge);
+            }
+            starts.push(start);
+            angles.push(angle);
+            start = start + edge.x.hypot(edge.y) / total;
+        }
+        let period_turn = if closed {
+            angle + turn(edges[edges.len() - 1], edges[0]) - angles[0]
+        } else {
+            T::zero()
+        };
+        Some(TurningFunction {
+            starts,
+            angles,
+            period_turn,
+        })
+    }
+
+    /// The direction at `s`, which is extended beyond 0 to 1 for closed outlines by adding a
+    /// full turn for each circuit.
+    fn at(&self, s: T) -> T {
+        let circuits = s.floor();
+        let s = s - circuits;
+        let i = self.starts.partition_point(|&start| start <= s).max(1) - 1;
+        self.angles[i] + circuits * self.period_turn
+    }
+
+    /// The squared L2 distance between `self` and `other` shifted by `shift`, minimised over
+    /// rotations.
+    fn squared_distance(&self, other: &Self, shift: T) -> T {
+        // Both functions are constant between these
+        let mut breaks: Vec<T> = self.starts.clone();
+        breaks.extend(other.starts.iter().map(|&t| {
+            let s = t - shift;
+            s - s.floor()
+        }));
+        breaks.push(T::one());
+        breaks.sort_by(|a, b| a.total_cmp(b));
+
+        let two = T::from(2).unwrap();
+        let differences: Vec<(T, T)> = breaks
+            .windows(2)
+            .filter(|w| w[1] > w[0])
+            .map(|w| {
+                let middle = (w[0] + w[1]) / two;
+                (self.at(middle) - other.at(middle + shift), w[1] - w[0])
+            })
+            .collect();
+        // The best rotation offsets the mean difference
+        let mean = differences
+            .iter()
+            .fold(T::zero(), |sum, &(difference, width)| {
+                sum + difference * width
+            });
+        differences
+            .iter()
+            .fold(T::zero(), |sum, &(difference, width)| {
+                sum + (difference - mean) * (difference - mean) * width
+            })
+    }
+}
+
+fn signed_area<T: GeoFloat>(coords: &[Coord<T>]) -> T {
+    coords.windows(2).fold(T::zero(), |sum, w| {
+        sum + (w[0].x * w[1].y - w[1].x * w[0].y)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Rotate, Scale};
+
+    fn rect(width: f64, height: f64) -> Polygon {
+        polygon![(x: 0., y: 0.), (x: width, y: 0.), (x: width, y: height), (x: 0., y: height)]
+    }
+
+    #[test]
+    fn invariance() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 3., y: -1.),
+            (x: 5., y: 2.),
+            (x: 2., y: 4.),
+            (x: 1., y: 2.),
+        ];
+        let moved = polygon.scale(2.5).rotate_around_centroid(123.);
+        assert_relative_eq!(polygon.turning_distance(&moved), 0., epsilon = 1e-6);
+
+        // Starting at another vertex, and going the other way round
+        let mut coords = polygon.exterior().0.clone();
+        coords.pop();
+        coords.rotate_left(2);
+        coords.reverse();
+        let reordered = Polygon::new(coords.into(), vec![]);
+        assert_relative_eq!(polygon.turning_distance(&reordered), 0., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn grows_with_difference() {
+        let square = rect(1., 1.);
+        let slightly = rect(1.2, 1.);
+        let very = rect(5., 1.);
+        let a = square.turning_distance(&slightly);
+        let b = square.turning_distance(&very);
+        assert!(a > 0.);
+        assert!(b > a);
+        // Symmetric
+        assert_relative_eq!(b, very.turning_distance(&square), epsilon = 1e-9);
+        // Extra collinear vertices don't change the shape
+        let subdivided = polygon![
+            (x: 0., y: 0.),
+            (x: 0.5, y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        assert_relative_eq!(square.turning_distance(&subdivided), 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn open_line_strings() {
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let rotated = a.rotate_around_point(90., (0., 0.).into());
+        assert_relative_eq!(a.turning_distance(&rotated), 0., epsilon = 1e-9);
+        // A left turn followed by a right turn, against the same turns the other way round
+        let b = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: -1.)];
+        assert!(a.turning_distance(&b) > 1.);
+
+        let empty: LineString = line_string![];
+        assert_eq!(a.turning_distance(&empty), 0.);
+    }
+}
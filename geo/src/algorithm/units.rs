@@ -0,0 +1,462 @@
+//! Measurements in typed units, which record the metric which produced them, so that a planar
+//! length in degrees can't be mistaken for a length in meters on the earth.
+//!
+//! A [`Quantity`] is a value in a [`Unit`], such as [`Meters`] or [`SquareDegrees`], measured
+//! by a metric, such as [`Euclidean`] or [`Haversine`]. Quantities only add to, subtract from
+//! and compare with quantities of the same unit and metric, and only [convert](Quantity::to)
+//! to units of the same kind, so meters convert to kilometers but not to degrees. The methods of
+//! [`Measure`] return the results of the crate's distance, length and area algorithms as
+//! quantities.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::units::{Degrees, Haversine, Kilometers, Meters};
+//! use geo::{line_string, Measure, Quantity};
+//!
+//! let route = line_string![(x: -0.1278, y: 51.5074), (x: 2.3522, y: 48.8566)];
+//!
+//! let along: Quantity<Meters, Haversine> = route.measured_haversine_length();
+//! assert_eq!(along.to::<Kilometers>().value().round(), 344.);
+//!
+//! // The planar length of longitude/latitude coordinates is in degrees
+//! let planar = route.measured_euclidean_length::<Degrees>();
+//! assert_eq!(format!("{planar:.2}"), "3.63°");
+//! ```
+//!
+//! Mixing them up doesn't compile:
+//!
+//! ```compile_fail
+//! use geo::units::{Degrees, Meters};
+//! use geo::{line_string, Measure};
+//!
+//! let route = line_string![(x: -0.1278, y: 51.5074), (x: 2.3522, y: 48.8566)];
+//! let sum = route.measured_haversine_length() + route.measured_euclidean_length::<Degrees>();
+//! ```
+
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::{CoordFloat, EuclideanDistance, EuclideanLength, HaversineDistance, HaversineLength};
+#[cfg(feature = "std")]
+use crate::{GeodesicArea, GeodesicDistance, GeodesicLength};
+
+/// A unit of measurement, which converts to the other units of its [`Kind`](Self::Kind).
+pub trait Unit {
+    /// The kind of quantity the unit measures, such as [`kind::Length`].
+    type Kind;
+
+    /// The size of the unit, in the base unit of its kind.
+    const SCALE: f64;
+
+    /// The symbol of the unit, with which quantities in it are displayed.
+    const SYMBOL: &'static str;
+}
+
+/// A [`Unit`] of a distance or length, in which coordinates can be given.
+pub trait LinearUnit: Unit {
+    /// The unit of area whose side is this unit.
+    type Squared: Unit;
+}
+
+/// The kinds of [`Unit`]s, which only convert between units of the same kind.
+pub mod kind {
+    /// A distance on the ground, or in projected coordinates, with the base unit of meters.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Length {}
+
+    /// An area on the ground, or in projected coordinates, with the base unit of square meters.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Area {}
+
+    /// A distance in angular coordinates, such as longitudes and latitudes, with the base unit
+    /// of degrees.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Angle {}
+
+    /// An area in angular coordinates, with the base unit of square degrees.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum AngularArea {}
+}
+
+macro_rules! units {
+    ($($(#[$attr:meta])* $unit:ident: $kind:ident, $scale:expr, $symbol:expr;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct $unit;
+
+            impl Unit for $unit {
+                type Kind = kind::$kind;
+                const SCALE: f64 = $scale;
+                const SYMBOL: &'static str = $symbol;
+            }
+        )*
+    };
+}
+
+units! {
+    /// Meters, the SI unit of length.
+    Meters: Length, 1., "m";
+    /// Kilometers, 1000 meters.
+    Kilometers: Length, 1000., "km";
+    /// Square meters, the SI unit of area.
+    SquareMeters: Area, 1., "m²";
+    /// Hectares, 10,000 square meters.
+    Hectares: Area, 10_000., "ha";
+    /// Square kilometers, 1,000,000 square meters.
+    SquareKilometers: Area, 1_000_000., "km²";
+    /// Degrees, the unit of longitude and latitude coordinates.
+    Degrees: Angle, 1., "°";
+    /// Square degrees, the unit of planar areas of longitude and latitude coordinates.
+    SquareDegrees: AngularArea, 1., "deg²";
+}
+
+impl LinearUnit for Meters {
+    type Squared = SquareMeters;
+}
+
+impl LinearUnit for Kilometers {
+    type Squared = SquareKilometers;
+}
+
+impl LinearUnit for Degrees {
+    type Squared = SquareDegrees;
+}
+
+/// Planar measurements, of coordinates in any unit, such as the meters of a projected
+/// coordinate reference system, or the degrees of longitude and latitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Euclidean;
+
+/// Measurements on a sphere of the mean radius of the earth, of longitude and latitude
+/// coordinates, such as those of [`HaversineDistance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Haversine;
+
+/// Measurements on an ellipsoid, of longitude and latitude coordinates, such as those of
+/// `GeodesicDistance`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Geodesic;
+
+/// A value in the unit `U`, measured by the metric `M`, such as [`Euclidean`].
+///
+/// See the [module documentation](self).
+pub struct Quantity<U, M, T = f64> {
+    value: T,
+    unit: PhantomData<fn() -> (U, M)>,
+}
+
+impl<U, M, T> Quantity<U, M, T> {
+    /// A quantity of `value` in the unit `U`.
+    pub const fn new(value: T) -> Self {
+        Quantity {
+            value,
+            unit: PhantomData,
+        }
+    }
+
+    /// The value of the quantity, in its unit.
+    pub fn value(self) -> T {
+        self.value
+    }
+}
+
+impl<U: Unit, M, T: CoordFloat> Quantity<U, M, T> {
+    /// The quantity in the unit `V`, which is of the same kind as its unit.
+    pub fn to<V>(self) -> Quantity<V, M, T>
+    where
+        V: Unit<Kind = U::Kind>,
+    {
+        // Through the base unit, so that a whole number of base units is only rounded once
+        let base = self.value * T::from(U::SCALE).unwrap();
+        Quantity::new(base / T::from(V::SCALE).unwrap())
+    }
+
+    /// The absolute value of the quantity.
+    pub fn abs(self) -> Self {
+        Quantity::new(self.value.abs())
+    }
+}
+
+impl<U, M, T: Clone> Clone for Quantity<U, M, T> {
+    fn clone(&self) -> Self {
+        Quantity::new(self.value.clone())
+    }
+}
+
+impl<U, M, T: Copy> Copy for Quantity<U, M, T> {}
+
+impl<U, M, T: PartialEq> PartialEq for Quantity<U, M, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<U, M, T: PartialOrd> PartialOrd for Quantity<U, M, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<U, M, T: Default> Default for Quantity<U, M, T> {
+    fn default() -> Self {
+        Quantity::new(T::default())
+    }
+}
+
+impl<U: Unit, M, T: fmt::Debug> fmt::Debug for Quantity<U, M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Quantity({:?} {})", self.value, U::SYMBOL)
+    }
+}
+
+/// Displays the value, formatted as specified, and then the symbol of the unit, which is
+/// separated by a space, unless it's a symbol of degrees.
+impl<U: Unit, M, T: fmt::Display> fmt::Display for Quantity<U, M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        if U::SYMBOL != "°" {
+            f.write_str(" ")?;
+        }
+        f.write_str(U::SYMBOL)
+    }
+}
+
+impl<U, M, T: Add<Output = T>> Add for Quantity<U, M, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Quantity::new(self.value + rhs.value)
+    }
+}
+
+impl<U, M, T: Sub<Output = T>> Sub for Quantity<U, M, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl<U, M, T: AddAssign> AddAssign for Quantity<U, M, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<U, M, T: SubAssign> SubAssign for Quantity<U, M, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<U, M, T: Neg<Output = T>> Neg for Quantity<U, M, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.value)
+    }
+}
+
+/// Scaling by a number.
+impl<U, M, T: CoordFloat> Mul<T> for Quantity<U, M, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+/// Scaling by the inverse of a number.
+impl<U, M, T: CoordFloat> Div<T> for Quantity<U, M, T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        Quantity::new(self.value / rhs)
+    }
+}
+
+/// The ratio of two quantities, which is a number.
+impl<U, M, T: CoordFloat> Div for Quantity<U, M, T> {
+    type Output = T;
+
+    fn div(self, rhs: Self) -> T {
+        self.value / rhs.value
+    }
+}
+
+impl<U, M, T: CoordFloat> Sum for Quantity<U, M, T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Quantity::new(iter.fold(T::zero(), |total, quantity| total + quantity.value))
+    }
+}
+
+/// The distances, lengths and areas of geometries as [`Quantity`]s, in typed units, with the
+/// metric which measured them.
+///
+/// Each method is available for the geometries which the algorithm it wraps is implemented for.
+/// Planar measurements are in the unit of the coordinates, which is given as a type parameter,
+/// and those on the earth are in meters or square meters. See the
+/// [module documentation](crate::units).
+///
+/// # Examples
+///
+/// ```
+/// use geo::units::{Euclidean, Meters, SquareKilometers};
+/// use geo::{point, polygon, Measure, Quantity};
+///
+/// // A field in a projected coordinate reference system, in meters
+/// let field = polygon![(x: 0., y: 0.), (x: 500., y: 0.), (x: 500., y: 400.), (x: 0., y: 400.)];
+///
+/// let area = field.measured_euclidean_area::<Meters>();
+/// assert_eq!(area.to::<SquareKilometers>().value(), 0.2);
+///
+/// let gate = point!(x: 250., y: 0.);
+/// let to_gate: Quantity<Meters, Euclidean> =
+///     point!(x: 250., y: 100.).measured_euclidean_distance(&gate);
+/// assert_eq!(to_gate.value(), 100.);
+/// ```
+pub trait Measure<T: CoordFloat>: Sized {
+    /// The planar distance to `rhs`, as for [`EuclideanDistance`], with coordinates in the unit
+    /// `U`.
+    fn measured_euclidean_distance<U, Rhs>(&self, rhs: &Rhs) -> Quantity<U, Euclidean, T>
+    where
+        U: LinearUnit,
+        Self: EuclideanDistance<T, Rhs>,
+    {
+        Quantity::new(self.euclidean_distance(rhs))
+    }
+
+    /// The planar length, as for [`EuclideanLength`], with coordinates in the unit `U`.
+    fn measured_euclidean_length<U>(&self) -> Quantity<U, Euclidean, T>
+    where
+        U: LinearUnit,
+        Self: EuclideanLength<T>,
+    {
+        Quantity::new(self.euclidean_length())
+    }
+
+    /// The planar area, as for [`Area::unsigned_area`](crate::Area::unsigned_area), with
+    /// coordinates in the unit `U`.
+    fn measured_euclidean_area<U>(&self) -> Quantity<U::Squared, Euclidean, T>
+    where
+        U: LinearUnit,
+        Self: crate::Area<T>,
+    {
+        Quantity::new(self.unsigned_area())
+    }
+
+    /// The distance to `rhs` on a sphere, as for [`HaversineDistance`].
+    fn measured_haversine_distance<Rhs>(&self, rhs: &Rhs) -> Quantity<Meters, Haversine, T>
+    where
+        Self: HaversineDistance<T, Rhs>,
+    {
+        Quantity::new(self.haversine_distance(rhs))
+    }
+
+    /// The length on a sphere, as for [`HaversineLength`].
+    fn measured_haversine_length(&self) -> Quantity<Meters, Haversine, T>
+    where
+        Self: HaversineLength<T>,
+    {
+        Quantity::new(self.haversine_length())
+    }
+
+    /// The distance to `rhs` on the WGS84 ellipsoid, as for [`GeodesicDistance`].
+    #[cfg(feature = "std")]
+    fn measured_geodesic_distance<Rhs>(&self, rhs: &Rhs) -> Quantity<Meters, Geodesic, T>
+    where
+        Self: GeodesicDistance<T, Rhs>,
+    {
+        Quantity::new(self.geodesic_distance(rhs))
+    }
+
+    /// The length on the WGS84 ellipsoid, as for [`GeodesicLength`].
+    #[cfg(feature = "std")]
+    fn measured_geodesic_length(&self) -> Quantity<Meters, Geodesic, T>
+    where
+        Self: GeodesicLength<T>,
+    {
+        Quantity::new(self.geodesic_length())
+    }
+
+    /// The area on the WGS84 ellipsoid, as for
+    /// [`GeodesicArea::geodesic_area_unsigned`].
+    #[cfg(feature = "std")]
+    fn measured_geodesic_area(&self) -> Quantity<SquareMeters, Geodesic, T>
+    where
+        Self: GeodesicArea<T>,
+    {
+        Quantity::new(self.geodesic_area_unsigned())
+    }
+}
+
+impl<T: CoordFloat, G> Measure<T> for G {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+
+    #[test]
+    fn conversions() {
+        let length: Quantity<Kilometers, Haversine> = Quantity::new(1.5);
+        assert_eq!(length.to::<Meters>(), Quantity::new(1500.));
+        assert_eq!(length.to::<Meters>().to::<Kilometers>(), length);
+
+        let area: Quantity<Hectares, Geodesic> = Quantity::new(250.);
+        assert_eq!(area.to::<SquareKilometers>().value(), 2.5);
+        assert_eq!(area.to::<SquareMeters>().value(), 2_500_000.);
+
+        let total: Quantity<Meters, Euclidean> = [1., 2., 3.5].into_iter().map(Quantity::new).sum();
+        assert_eq!(total, Quantity::new(6.5));
+        assert_eq!(total * 2. - total, total);
+        assert_eq!(total / Quantity::new(13.), 0.5);
+        assert!(-total < total);
+
+        assert_eq!(format!("{total}"), "6.5 m");
+        assert_eq!(format!("{:?}", area), "Quantity(250.0 ha)");
+        assert_eq!(
+            format!("{:.1}", Quantity::<Degrees, Euclidean>::new(0.25)),
+            "0.2°"
+        );
+    }
+
+    #[test]
+    fn measurements() {
+        let line = line_string![(x: 0., y: 0.), (x: 3., y: 4.)];
+        let planar: Quantity<Degrees, Euclidean> = line.measured_euclidean_length();
+        assert_eq!(planar.value(), 5.);
+
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let area: Quantity<SquareKilometers, Euclidean> =
+            square.measured_euclidean_area::<Kilometers>();
+        assert_eq!(area.value(), square.unsigned_area());
+
+        let (a, b) = (point!(x: 0., y: 0.), point!(x: 1., y: 0.));
+        assert_eq!(
+            a.measured_haversine_distance(&b).value(),
+            a.haversine_distance(&b)
+        );
+        assert_eq!(
+            line.measured_haversine_length().value(),
+            line.haversine_length()
+        );
+        #[cfg(feature = "std")]
+        {
+            // A degree of longitude on the equator is longer on the ellipsoid than the sphere
+            assert!(a.measured_geodesic_distance(&b).value() > a.haversine_distance(&b));
+            assert_eq!(
+                line.measured_geodesic_length().value(),
+                line.geodesic_length()
+            );
+            assert_eq!(
+                square.measured_geodesic_area().value(),
+                square.geodesic_area_unsigned()
+            );
+        }
+    }
+}
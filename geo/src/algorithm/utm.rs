@@ -0,0 +1,302 @@
+use crate::{coord, BoundingRect, Coord, Ellipsoid, MapCoords, Point};
+
+/// The scale factor on each zone's central meridian.
+const SCALE: f64 = 0.9996;
+
+/// The easting of each zone's central meridian, in meters.
+const FALSE_EASTING: f64 = 500_000.;
+
+/// The northing of the equator in the southern hemisphere, in meters.
+const FALSE_NORTHING: f64 = 10_000_000.;
+
+/// A [Universal Transverse Mercator] zone, for converting geometries between WGS84
+/// longitude/latitude and that zone's easting/northing coordinates, in meters.
+///
+/// The transverse Mercator projection is calculated with Krüger's series, which are accurate to
+/// a few nanometers within a zone, and remain usable a few zones away from it.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, Utm};
+///
+/// let eiffel_tower = point!(x: 2.2945, y: 48.8583);
+/// let zone = Utm::for_point(eiffel_tower);
+/// assert_eq!(zone.zone(), 31);
+/// assert!(zone.is_north());
+/// assert_eq!(zone.epsg(), 32631);
+///
+/// let projected = zone.project(&eiffel_tower);
+/// assert_relative_eq!(projected, point!(x: 448_251.898, y: 5_411_943.794), epsilon = 1e-3);
+/// assert_relative_eq!(zone.unproject(&projected), eiffel_tower, epsilon = 1e-9);
+/// ```
+///
+/// [Universal Transverse Mercator]: https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utm {
+    zone: u8,
+    north: bool,
+}
+
+impl Utm {
+    /// The given zone, from 1 to 60, in the northern or southern hemisphere.
+    ///
+    /// # Panics
+    ///
+    /// If `zone` isn't between 1 and 60.
+    pub fn new(zone: u8, north: bool) -> Self {
+        assert!((1..=60).contains(&zone), "UTM zones are numbered 1 to 60");
+        Utm { zone, north }
+    }
+
+    /// The zone containing `point`, including the exceptions around Norway and Svalbard.
+    pub fn for_point(point: Point) -> Self {
+        let (lon, lat) = point.x_y();
+        let lon = (lon + 180.).rem_euclid(360.) - 180.;
+        let zone = match (lat, lon) {
+            (lat, lon) if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) => 32,
+            (lat, lon) if (72.0..84.0).contains(&lat) && (0.0..42.0).contains(&lon) => match lon {
+                lon if lon < 9. => 31,
+                lon if lon < 21. => 33,
+                lon if lon < 33. => 35,
+                _ => 37,
+            },
+            _ => (((lon + 180.) / 6.).floor() as u8 + 1).min(60),
+        };
+        Utm::new(zone, lat >= 0.)
+    }
+
+    /// The zone containing the center of `geometry`'s bounding rectangle, or `None` if it's
+    /// empty.
+    pub fn around<G>(geometry: &G) -> Option<Self>
+    where
+        G: BoundingRect<f64>,
+    {
+        let bounds = geometry.bounding_rect().into()?;
+        Some(Self::for_point(bounds.center().into()))
+    }
+
+    /// The zone's number, from 1 to 60.
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Whether the zone is in the northern hemisphere.
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// The zone's EPSG code, such as 32631 for zone 31 north, or 32731 for zone 31 south.
+    pub fn epsg(&self) -> u32 {
+        (if self.north { 32600 } else { 32700 }) + self.zone as u32
+    }
+
+    /// The longitude of the zone's central meridian, in degrees.
+    pub fn central_meridian(&self) -> f64 {
+        self.zone as f64 * 6. - 183.
+    }
+
+    /// Convert longitude and latitude, in degrees, to easting and northing, in meters.
+    pub fn forward(&self, coord: Coord) -> Coord {
+        let series = Kruger::wgs84();
+        let lat = coord.y.to_radians();
+        let lon = (coord.x - self.central_meridian()).to_radians();
+
+        // Conformal latitude
+        let e = series.eccentricity;
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi = t.atan2(lon.cos());
+        let eta = (lon.sin() / t.hypot(1.)).atanh();
+
+        let (mut x, mut y) = (eta, xi);
+        for (j, alpha) in series.alpha.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            x += alpha * (k * xi).cos() * (k * eta).sinh();
+            y += alpha * (k * xi).sin() * (k * eta).cosh();
+        }
+
+        coord! {
+            x: FALSE_EASTING + SCALE * series.rectifying_radius * x,
+            y: self.false_northing() + SCALE * series.rectifying_radius * y,
+        }
+    }
+
+    /// Convert easting and northing, in meters, to longitude and latitude, in degrees.
+    pub fn inverse(&self, coord: Coord) -> Coord {
+        let series = Kruger::wgs84();
+        let xi = (coord.y - self.false_northing()) / (SCALE * series.rectifying_radius);
+        let eta = (coord.x - FALSE_EASTING) / (SCALE * series.rectifying_radius);
+
+        let (mut xi_prime, mut eta_prime) = (xi, eta);
+        for (j, beta) in series.beta.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            xi_prime -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let mut lat = chi;
+        for (j, delta) in series.delta.iter().enumerate() {
+            lat += delta * (2. * (j + 1) as f64 * chi).sin();
+        }
+        let lon = eta_prime.sinh().atan2(xi_prime.cos());
+
+        coord! {
+            x: self.central_meridian() + lon.to_degrees(),
+            y: lat.to_degrees(),
+        }
+    }
+
+    /// [`forward`](Self::forward) as a closure for [`MapCoords`] and
+    /// [`MapCoordsInPlace`](crate::MapCoordsInPlace).
+    pub fn forward_fn(&self) -> impl Fn(Coord) -> Coord + Copy + '_ {
+        move |coord| self.forward(coord)
+    }
+
+    /// [`inverse`](Self::inverse) as a closure for [`MapCoords`] and
+    /// [`MapCoordsInPlace`](crate::MapCoordsInPlace).
+    pub fn inverse_fn(&self) -> impl Fn(Coord) -> Coord + Copy + '_ {
+        move |coord| self.inverse(coord)
+    }
+
+    /// Convert all the coordinates of `geometry` to easting and northing.
+    pub fn project<G>(&self, geometry: &G) -> G::Output
+    where
+        G: MapCoords<f64, f64>,
+    {
+        geometry.map_coords(self.forward_fn())
+    }
+
+    /// Convert all the coordinates of a projected `geometry` back to longitude and latitude.
+    pub fn unproject<G>(&self, geometry: &G) -> G::Output
+    where
+        G: MapCoords<f64, f64>,
+    {
+        geometry.map_coords(self.inverse_fn())
+    }
+
+    fn false_northing(&self) -> f64 {
+        if self.north {
+            0.
+        } else {
+            FALSE_NORTHING
+        }
+    }
+}
+
+/// The coefficients of Krüger's series for the transverse Mercator projection, to fourth order in
+/// the third flattening, as given by [Karney (2011)](https://arxiv.org/pdf/1002.1417.pdf).
+struct Kruger {
+    eccentricity: f64,
+    rectifying_radius: f64,
+    alpha: [f64; 4],
+    beta: [f64; 4],
+    delta: [f64; 4],
+}
+
+impl Kruger {
+    fn wgs84() -> Self {
+        let Ellipsoid {
+            semi_major_axis: a,
+            flattening: f,
+        } = Ellipsoid::WGS84;
+        let n = f / (2. - f);
+        let (n2, n3, n4) = (n.powi(2), n.powi(3), n.powi(4));
+        Kruger {
+            eccentricity: (f * (2. - f)).sqrt(),
+            rectifying_radius: a / (1. + n) * (1. + n2 / 4. + n4 / 64.),
+            alpha: [
+                n / 2. - 2. / 3. * n2 + 5. / 16. * n3 + 41. / 180. * n4,
+                13. / 48. * n2 - 3. / 5. * n3 + 557. / 1440. * n4,
+                61. / 240. * n3 - 103. / 140. * n4,
+                49561. / 161280. * n4,
+            ],
+            beta: [
+                n / 2. - 2. / 3. * n2 + 37. / 96. * n3 - 1. / 360. * n4,
+                n2 / 48. + n3 / 15. - 437. / 1440. * n4,
+                17. / 480. * n3 - 37. / 840. * n4,
+                4397. / 161280. * n4,
+            ],
+            delta: [
+                2. * n - 2. / 3. * n2 - 2. * n3 + 116. / 45. * n4,
+                7. / 3. * n2 - 8. / 5. * n3 - 227. / 45. * n4,
+                56. / 15. * n3 - 136. / 35. * n4,
+                4279. / 630. * n4,
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Area, GeodesicArea};
+
+    #[test]
+    fn central_meridian() {
+        let zone = Utm::new(31, true);
+        assert_relative_eq!(
+            zone.forward(coord! { x: 3., y: 0. }),
+            coord! { x: 500_000., y: 0. },
+            epsilon = 1e-6
+        );
+        // The meridian arc from the equator to 45° is 4,984,944.378m
+        assert_relative_eq!(
+            zone.forward(coord! { x: 3., y: 45. }),
+            coord! { x: 500_000., y: SCALE * 4_984_944.378 },
+            epsilon = 1e-3
+        );
+
+        let south = Utm::new(31, false);
+        assert_relative_eq!(
+            south.forward(coord! { x: 3., y: -45. }),
+            coord! { x: 500_000., y: FALSE_NORTHING - SCALE * 4_984_944.378 },
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn zone_selection() {
+        assert_eq!(Utm::for_point(point!(x: -180., y: 0.)), Utm::new(1, true));
+        assert_eq!(Utm::for_point(point!(x: 180., y: 0.)), Utm::new(1, true));
+        assert_eq!(
+            Utm::for_point(point!(x: 179.9, y: -10.)),
+            Utm::new(60, false)
+        );
+        assert_eq!(Utm::for_point(point!(x: -74.006, y: 40.7128)).epsg(), 32618);
+        // Norway
+        assert_eq!(Utm::for_point(point!(x: 5.3221, y: 60.3913)).zone(), 32);
+        // Svalbard
+        assert_eq!(Utm::for_point(point!(x: 15.6356, y: 78.2232)).zone(), 33);
+        assert_eq!(Utm::for_point(point!(x: 7., y: 79.)).zone(), 31);
+    }
+
+    #[test]
+    fn round_trip_across_zone() {
+        let zone = Utm::new(33, false);
+        for lon in [12., 14.5, 15., 17.9] {
+            for lat in [-79.9, -45., -10., -0.1] {
+                let point = point!(x: lon, y: lat);
+                assert_relative_eq!(zone.unproject(&zone.project(&point)), point, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn area_scale() {
+        let block = polygon![
+            (x: -73.990, y: 40.750),
+            (x: -73.980, y: 40.750),
+            (x: -73.980, y: 40.758),
+            (x: -73.990, y: 40.758),
+        ];
+        let zone = Utm::around(&block).unwrap();
+        // Areas are distorted by the square of the point scale, which is about 0.99969 here.
+        assert_relative_eq!(
+            zone.project(&block).unsigned_area() / block.geodesic_area_unsigned(),
+            0.99938,
+            epsilon = 1e-5
+        );
+    }
+}
@@ -0,0 +1,551 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Deref;
+
+use crate::coordinate_position::CoordPos;
+use crate::dimensions::Dimensions;
+use crate::sweep::SegmentIntersections;
+use crate::utils::lex_cmp;
+use crate::{
+    BoundingRect, Coord, CoordsIter, GeoFloat, Geometry, GeometryCollection, Intersects, Line,
+    LineIntersection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+    Relate, Triangle,
+};
+
+/// Check that a geometry is valid, following the rules of the [OGC Simple Feature Access]
+/// standard, which most algorithms assume of their input.
+///
+/// - Every coordinate is finite.
+/// - A `LineString` is empty, or has at least two distinct points.
+/// - A ring of a `Polygon` has at least three distinct points, and doesn't cross or touch
+///   itself.
+/// - The interior rings of a `Polygon` are within its exterior ring, and don't overlap each
+///   other, and no two rings meet along a line, though they may touch at points, as long as
+///   they don't cut the interior of the polygon in pieces.
+/// - The polygons of a `MultiPolygon` are valid, and their interiors don't overlap, and they
+///   don't meet along a line, though they may touch at points.
+/// - A `Triangle` isn't degenerate.
+///
+/// [OGC Simple Feature Access]: https://www.ogc.org/standard/sfa/
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, InvalidGeometry, Validation};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// assert!(square.is_valid());
+///
+/// // A bow tie, whose exterior crosses itself
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert_eq!(bow_tie.check_validation(), Err(InvalidGeometry::SelfIntersection));
+/// ```
+pub trait Validation {
+    /// Check that the geometry is valid, returning the first problem found if it isn't.
+    fn check_validation(&self) -> Result<(), InvalidGeometry>;
+
+    /// Whether the geometry is valid.
+    fn is_valid(&self) -> bool {
+        self.check_validation().is_ok()
+    }
+}
+
+/// Why a geometry isn't valid, as found by [`Validation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidGeometry {
+    /// A coordinate is NaN or infinite.
+    NonFiniteCoordinate,
+    /// A `LineString` has fewer than two distinct points, or a ring fewer than three.
+    TooFewPoints,
+    /// A ring crosses or touches itself, or a `Triangle` is degenerate.
+    SelfIntersection,
+    /// An interior ring of a `Polygon` isn't within its exterior ring.
+    InteriorRingNotContainedInExteriorRing,
+    /// Two interior rings of a `Polygon` overlap, or one is within the other.
+    OverlappingInteriorRings,
+    /// Two rings of a `Polygon` meet along a line.
+    IntersectingRingsOnALine,
+    /// The interior of a `Polygon` is cut in pieces by rings which touch each other at several
+    /// points.
+    DisconnectedInterior,
+    /// Two polygons of a `MultiPolygon` overlap, or meet along a line.
+    OverlappingPolygons,
+}
+
+impl core::fmt::Display for InvalidGeometry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            InvalidGeometry::NonFiniteCoordinate => write!(f, "a coordinate is not finite"),
+            InvalidGeometry::TooFewPoints => write!(f, "too few distinct points"),
+            InvalidGeometry::SelfIntersection => write!(f, "a ring intersects itself"),
+            InvalidGeometry::InteriorRingNotContainedInExteriorRing => {
+                write!(f, "an interior ring is not within the exterior ring")
+            }
+            InvalidGeometry::OverlappingInteriorRings => write!(f, "interior rings overlap"),
+            InvalidGeometry::IntersectingRingsOnALine => {
+                write!(f, "rings intersect along a line")
+            }
+            InvalidGeometry::DisconnectedInterior => write!(f, "the interior is disconnected"),
+            InvalidGeometry::OverlappingPolygons => {
+                write!(f, "polygons overlap or intersect along a line")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGeometry {}
+
+/// A geometry which is known to be [valid](Validation).
+///
+/// The only ways to make one are to check a geometry, with [`Validated::new`], `TryFrom`, or
+/// by deserializing it with the `use-serde` feature, or to assert that it's valid with
+/// [`Validated::new_unchecked`]. It can't be mutated, since that could make it invalid, but it
+/// dereferences to the geometry for every algorithm which only reads it.
+///
+/// Taking a `Validated` geometry makes the validity an algorithm needs explicit, at an API
+/// boundary such as the geometries read from a file, so it can be checked once there, rather
+/// than being assumed, or handled defensively over and over again.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, InvalidGeometry, Polygon, Validated};
+///
+/// fn parcel_area(parcel: &Validated<Polygon>) -> f64 {
+///     parcel.unsigned_area()
+/// }
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let parcel = Validated::try_from(square).unwrap();
+/// assert_eq!(parcel_area(&parcel), 4.);
+///
+/// let flat = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 0.)];
+/// assert_eq!(Validated::new(flat), Err(InvalidGeometry::TooFewPoints));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use-serde", derive(Serialize), serde(transparent))]
+pub struct Validated<G>(G);
+
+impl<G: Validation> Validated<G> {
+    /// Check that `geometry` is valid.
+    pub fn new(geometry: G) -> Result<Self, InvalidGeometry> {
+        geometry.check_validation()?;
+        Ok(Validated(geometry))
+    }
+}
+
+impl<G> Validated<G> {
+    /// Wrap `geometry` without checking it, such as the output of an algorithm which always
+    /// makes valid geometries. Algorithms may give wrong answers, or panic, if it isn't valid.
+    pub fn new_unchecked(geometry: G) -> Self {
+        Validated(geometry)
+    }
+
+    /// The geometry, which can then be mutated.
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G> Deref for Validated<G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.0
+    }
+}
+
+impl<G> AsRef<G> for Validated<G> {
+    fn as_ref(&self) -> &G {
+        &self.0
+    }
+}
+
+macro_rules! impl_try_from {
+    ($($geometry:ident),*) => {
+        $(
+            impl<T: GeoFloat> TryFrom<$geometry<T>> for Validated<$geometry<T>> {
+                type Error = InvalidGeometry;
+
+                fn try_from(geometry: $geometry<T>) -> Result<Self, InvalidGeometry> {
+                    Validated::new(geometry)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(
+    Coord,
+    Point,
+    MultiPoint,
+    Line,
+    LineString,
+    MultiLineString,
+    Polygon,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+#[cfg(feature = "use-serde")]
+impl<'de, G> serde::Deserialize<'de> for Validated<G>
+where
+    G: serde::Deserialize<'de> + Validation,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let geometry = G::deserialize(deserializer)?;
+        Validated::new(geometry).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<G: Validation> Validation for Validated<G> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        Ok(())
+    }
+}
+
+impl<T: GeoFloat> Validation for Coord<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        if self.x.is_finite() && self.y.is_finite() {
+            Ok(())
+        } else {
+            Err(InvalidGeometry::NonFiniteCoordinate)
+        }
+    }
+}
+
+impl<T: GeoFloat> Validation for Point<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.0.check_validation()
+    }
+}
+
+impl<T: GeoFloat> Validation for MultiPoint<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.iter().try_for_each(Validation::check_validation)
+    }
+}
+
+impl<T: GeoFloat> Validation for Line<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.start.check_validation()?;
+        self.end.check_validation()
+    }
+}
+
+impl<T: GeoFloat> Validation for Rect<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.min().check_validation()?;
+        self.max().check_validation()
+    }
+}
+
+impl<T: GeoFloat> Validation for Triangle<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.to_polygon().check_validation()
+    }
+}
+
+impl<T: GeoFloat> Validation for LineString<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        check_finite(self)?;
+        match self.0.split_first() {
+            Some((first, rest)) if rest.iter().all(|coord| coord == first) => {
+                Err(InvalidGeometry::TooFewPoints)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: GeoFloat> Validation for MultiLineString<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.iter().try_for_each(Validation::check_validation)
+    }
+}
+
+impl<T: GeoFloat> Validation for Polygon<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        check_finite(self)?;
+        // Empty rings are ignored, though a polygon can't have holes without an exterior
+        let holes: Vec<&LineString<T>> = self
+            .interiors()
+            .iter()
+            .filter(|ring| !ring.0.is_empty())
+            .collect();
+        if self.exterior().0.is_empty() {
+            return match holes.is_empty() {
+                true => Ok(()),
+                false => Err(InvalidGeometry::InteriorRingNotContainedInExteriorRing),
+            };
+        }
+        check_rings(self.exterior(), &holes)?;
+
+        // The rings don't cross each other, but a hole may still be outside the exterior, or
+        // inside another hole
+        let exterior = Polygon::new(self.exterior().clone(), vec![]);
+        let holes: Vec<Polygon<T>> = holes
+            .into_iter()
+            .map(|ring| Polygon::new(ring.clone(), vec![]))
+            .collect();
+        for hole in &holes {
+            if !exterior.relate(hole).is_covers() {
+                return Err(InvalidGeometry::InteriorRingNotContainedInExteriorRing);
+            }
+        }
+        for (a, b) in overlapping_pairs(&holes) {
+            if a.relate(b).get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty {
+                return Err(InvalidGeometry::OverlappingInteriorRings);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: GeoFloat> Validation for MultiPolygon<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.iter().try_for_each(Validation::check_validation)?;
+        for (a, b) in overlapping_pairs(&self.0) {
+            let matrix = a.relate(b);
+            if matrix.get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty
+                || matrix.get(CoordPos::OnBoundary, CoordPos::OnBoundary)
+                    == Dimensions::OneDimensional
+            {
+                return Err(InvalidGeometry::OverlappingPolygons);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: GeoFloat> Validation for GeometryCollection<T> {
+    fn check_validation(&self) -> Result<(), InvalidGeometry> {
+        self.iter().try_for_each(Validation::check_validation)
+    }
+}
+
+impl<T: GeoFloat> Validation for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn check_validation(&self) -> Result<(), InvalidGeometry>;
+    }
+}
+
+fn check_finite<T: GeoFloat, G: CoordsIter<Scalar = T>>(
+    geometry: &G,
+) -> Result<(), InvalidGeometry> {
+    geometry
+        .coords_iter()
+        .try_for_each(|coord| coord.check_validation())
+}
+
+/// Check that each ring has at least three distinct points, and doesn't cross or touch itself,
+/// that no two rings cross or meet along a line, and that the rings don't touch each other in
+/// a cycle, which would cut the interior of the polygon in pieces.
+fn check_rings<T: GeoFloat>(
+    exterior: &LineString<T>,
+    holes: &[&LineString<T>],
+) -> Result<(), InvalidGeometry> {
+    let mut lines = Vec::new();
+    // The ring of each line, its position in the ring, and the number of lines of the ring
+    let mut positions = Vec::new();
+    for (ring, line_string) in core::iter::once(exterior)
+        .chain(holes.iter().copied())
+        .enumerate()
+    {
+        let mut coords = line_string.0.clone();
+        coords.dedup();
+        if coords.len() < 4 {
+            return Err(InvalidGeometry::TooFewPoints);
+        }
+        let count = coords.len() - 1;
+        for (position, pair) in coords.windows(2).enumerate() {
+            lines.push(Line::new(pair[0], pair[1]));
+            positions.push((ring, position, count));
+        }
+    }
+
+    let mut touches = Vec::new();
+    for intersection in SegmentIntersections::new(lines) {
+        let (ring, first, count) = positions[intersection.first];
+        let (other_ring, second, _) = positions[intersection.second];
+        match intersection.intersection {
+            LineIntersection::Collinear { .. } if ring == other_ring => {
+                return Err(InvalidGeometry::SelfIntersection)
+            }
+            LineIntersection::Collinear { .. } => {
+                return Err(InvalidGeometry::IntersectingRingsOnALine)
+            }
+            // Consecutive lines of a ring meet at the vertex between them, and nowhere else
+            LineIntersection::SinglePoint { .. }
+                if ring == other_ring
+                    && (second == first + 1 || (first == 0 && second + 1 == count)) => {}
+            LineIntersection::SinglePoint { .. } if ring == other_ring => {
+                return Err(InvalidGeometry::SelfIntersection)
+            }
+            LineIntersection::SinglePoint {
+                is_proper: true, ..
+            } if ring == 0 => return Err(InvalidGeometry::InteriorRingNotContainedInExteriorRing),
+            LineIntersection::SinglePoint {
+                is_proper: true, ..
+            } => return Err(InvalidGeometry::OverlappingInteriorRings),
+            LineIntersection::SinglePoint { intersection, .. } => {
+                touches.push((intersection, ring));
+                touches.push((intersection, other_ring));
+            }
+        }
+    }
+
+    // In the graph of the rings and the points where they touch, a cycle of rings encloses a
+    // part of the interior
+    touches.sort_by(|a, b| lex_cmp(&a.0, &b.0).then(a.1.cmp(&b.1)));
+    touches.dedup();
+    let rings = holes.len() + 1;
+    let mut sets: Vec<usize> = (0..rings + touches.len()).collect();
+    let mut point = rings;
+    for (k, &(coord, ring)) in touches.iter().enumerate() {
+        if k > 0 && touches[k - 1].0 != coord {
+            point += 1;
+        }
+        let (a, b) = (find(&mut sets, point), find(&mut sets, ring));
+        if a == b {
+            return Err(InvalidGeometry::DisconnectedInterior);
+        }
+        sets[a] = b;
+    }
+    Ok(())
+}
+
+/// The representative of the set of `element`, in a disjoint-set forest.
+fn find(sets: &mut [usize], mut element: usize) -> usize {
+    while sets[element] != element {
+        sets[element] = sets[sets[element]];
+        element = sets[element];
+    }
+    element
+}
+
+/// The pairs of the polygons whose bounding rectangles intersect.
+fn overlapping_pairs<T: GeoFloat>(polygons: &[Polygon<T>]) -> Vec<(&Polygon<T>, &Polygon<T>)> {
+    let bounds: Vec<Option<Rect<T>>> = polygons.iter().map(BoundingRect::bounding_rect).collect();
+    let mut pairs = Vec::new();
+    for (i, a) in bounds.iter().enumerate() {
+        for (j, b) in bounds.iter().enumerate().skip(i + 1) {
+            if let (Some(a), Some(b)) = (a, b) {
+                if a.intersects(b) {
+                    pairs.push((&polygons[i], &polygons[j]));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, wkt};
+
+    #[test]
+    fn polygons() {
+        let square = |holes: Vec<LineString>| {
+            Polygon::new(
+                line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                holes,
+            )
+        };
+        // A hole touching the exterior at a point
+        let touching = line_string![(x: 0., y: 5.), (x: 5., y: 2.), (x: 5., y: 8.)];
+        assert_eq!(square(vec![touching]).check_validation(), Ok(()));
+        // A hole touching the exterior at two points, cutting off its corner
+        let corner = line_string![(x: 0., y: 5.), (x: 5., y: 0.), (x: 5., y: 5.)];
+        assert_eq!(
+            square(vec![corner]).check_validation(),
+            Err(InvalidGeometry::DisconnectedInterior)
+        );
+
+        let outside = line_string![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.)];
+        assert_eq!(
+            square(vec![outside]).check_validation(),
+            Err(InvalidGeometry::InteriorRingNotContainedInExteriorRing)
+        );
+        let outer = line_string![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)];
+        let inner = line_string![(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.)];
+        assert_eq!(
+            square(vec![outer, inner]).check_validation(),
+            Err(InvalidGeometry::OverlappingInteriorRings)
+        );
+        let on_edge = line_string![(x: 2., y: 0.), (x: 4., y: 0.), (x: 3., y: 1.)];
+        assert_eq!(
+            square(vec![on_edge]).check_validation(),
+            Err(InvalidGeometry::IntersectingRingsOnALine)
+        );
+        // Touching itself, at the start of a spike
+        let spike: Polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.), (x: -1., y: -1.)];
+        assert_eq!(
+            spike.check_validation(),
+            Err(InvalidGeometry::SelfIntersection)
+        );
+        assert_eq!(
+            square(vec![LineString::new(vec![])]).check_validation(),
+            Ok(())
+        );
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).check_validation(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let touching: MultiPolygon =
+            wkt! { MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((1. 1.,2. 1.,2. 2.,1. 1.))) };
+        assert!(touching.is_valid());
+        let sharing_an_edge: MultiPolygon = wkt! { MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),((1. 0.,2. 0.,2. 1.,1. 1.,1. 0.))) };
+        assert_eq!(
+            sharing_an_edge.check_validation(),
+            Err(InvalidGeometry::OverlappingPolygons)
+        );
+        // An island in a lake
+        let island: MultiPolygon = wkt! { MULTIPOLYGON(((0. 0.,9. 0.,9. 9.,0. 9.,0. 0.),(1. 1.,8. 1.,8. 8.,1. 8.,1. 1.)),((2. 2.,3. 2.,3. 3.,2. 2.))) };
+        assert!(island.is_valid());
+    }
+
+    #[test]
+    fn other_geometries() {
+        assert!(line_string![(x: 0., y: 0.), (x: 1., y: 1.)].is_valid());
+        assert_eq!(
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.)].check_validation(),
+            Err(InvalidGeometry::TooFewPoints)
+        );
+        assert!(LineString::<f64>::new(vec![]).is_valid());
+        assert_eq!(
+            point!(x: f64::NAN, y: 0.).check_validation(),
+            Err(InvalidGeometry::NonFiniteCoordinate)
+        );
+        let flat = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+        );
+        assert_eq!(
+            flat.check_validation(),
+            Err(InvalidGeometry::SelfIntersection)
+        );
+        assert!(Rect::new((0., 0.), (0., 1.)).is_valid());
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 0., y: 0.).into(),
+            line_string![(x: 0., y: f64::INFINITY), (x: 1., y: 1.)].into(),
+        ]));
+        assert_eq!(
+            Validated::try_from(collection),
+            Err(InvalidGeometry::NonFiniteCoordinate)
+        );
+        let validated = Validated::try_from(point!(x: 1., y: 2.)).unwrap();
+        assert_eq!(validated.x(), 1.);
+        assert_eq!(validated.into_inner(), point!(x: 1., y: 2.));
+    }
+}
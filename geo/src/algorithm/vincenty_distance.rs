@@ -5,8 +5,8 @@
 // - https://github.com/janantala/GPS-distance/blob/master/java/Distance.java
 
 use crate::{CoordFloat, Point, EARTH_FLATTENING, EQUATORIAL_EARTH_RADIUS, POLAR_EARTH_RADIUS};
+use core::fmt;
 use num_traits::FromPrimitive;
-use std::{error, fmt};
 
 /// Determine the distance between two geometries using [Vincenty’s formulae].
 ///
@@ -171,7 +171,8 @@ impl fmt::Display for FailedToConvergeError {
     }
 }
 
-impl error::Error for FailedToConvergeError {
+#[cfg(feature = "std")]
+impl std::error::Error for FailedToConvergeError {
     fn description(&self) -> &str {
         "Vincenty algorithm failed to converge"
     }
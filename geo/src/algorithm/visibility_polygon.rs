@@ -0,0 +1,193 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{BoundingRect, Coord, GeoFloat, Line, LineString, LinesIter, Point, Polygon};
+use alloc::{vec, vec::Vec};
+
+/// Compute the region of a polygon that is visible from a viewpoint.
+///
+/// The visibility polygon contains every location that can be connected to
+/// the viewpoint by a straight line which doesn't cross the boundary of the
+/// polygon (including the boundaries of its holes).
+///
+/// This is computed with an angular sweep: a ray is cast from the viewpoint
+/// towards every vertex of the polygon, and additionally slightly to either
+/// side of it, so that the rays can "slip past" corners. The nearest
+/// intersection of each ray with the polygon's boundary is a vertex of the
+/// visibility polygon. This runs in O(n²) time for a polygon with `n`
+/// vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Area, VisibilityPolygon};
+///
+/// // An L-shaped room
+/// let room = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 2.),
+///     (x: 2., y: 2.),
+///     (x: 2., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+///
+/// // From the inner corner of the L, the whole room is visible
+/// let visible = room.visibility_polygon(&point!(x: 1., y: 1.)).unwrap();
+/// approx::assert_relative_eq!(visible.unsigned_area(), room.unsigned_area(), epsilon = 1e-6);
+///
+/// // From the end of one of the arms, part of the other arm is hidden
+/// let visible = room.visibility_polygon(&point!(x: 3.5, y: 1.)).unwrap();
+/// assert!(visible.unsigned_area() < room.unsigned_area());
+///
+/// // Viewpoints outside of the room have no visibility polygon
+/// assert!(room.visibility_polygon(&point!(x: 5., y: 5.)).is_none());
+/// ```
+pub trait VisibilityPolygon<T: GeoFloat> {
+    /// Return the region visible from `viewpoint`, with a counter-clockwise
+    /// exterior ring.
+    ///
+    /// Returns `None` if `viewpoint` doesn't lie in the interior of `self`.
+    fn visibility_polygon(&self, viewpoint: &Point<T>) -> Option<Polygon<T>>;
+}
+
+impl<T: GeoFloat> VisibilityPolygon<T> for Polygon<T> {
+    fn visibility_polygon(&self, viewpoint: &Point<T>) -> Option<Polygon<T>> {
+        let origin = viewpoint.0;
+        if self.coordinate_position(&origin) != CoordPos::Inside {
+            return None;
+        }
+
+        let segments: Vec<Line<T>> = self.lines_iter().collect();
+        let bounds = self.bounding_rect()?;
+        // Any ray at least this long will exit the polygon.
+        let ray_length = (bounds.width() + bounds.height()) * (T::one() + T::one());
+        let offset = T::epsilon().sqrt();
+
+        let mut angles: Vec<T> = segments
+            .iter()
+            .flat_map(|segment| {
+                let delta = segment.start - origin;
+                let angle = delta.y.atan2(delta.x);
+                [angle - offset, angle, angle + offset]
+            })
+            .collect();
+        angles.sort_by(|a, b| a.total_cmp(b));
+        angles.dedup();
+
+        let mut ring: Vec<Coord<T>> = angles
+            .into_iter()
+            .filter_map(|angle| {
+                let (sin, cos) = angle.sin_cos();
+                let ray = Line::new(
+                    origin,
+                    origin
+                        + Coord {
+                            x: cos * ray_length,
+                            y: sin * ray_length,
+                        },
+                );
+                nearest_hit(ray, &segments)
+            })
+            .collect();
+        ring.dedup();
+
+        Some(Polygon::new(LineString::new(ring), vec![]))
+    }
+}
+
+/// The closest intersection of `ray` with any of `segments`.
+fn nearest_hit<T: GeoFloat>(ray: Line<T>, segments: &[Line<T>]) -> Option<Coord<T>> {
+    let origin = ray.start;
+    let distance = |c: Coord<T>| {
+        let delta = c - origin;
+        delta.x * delta.x + delta.y * delta.y
+    };
+
+    segments
+        .iter()
+        .filter_map(|segment| match line_intersection(ray, *segment)? {
+            LineIntersection::SinglePoint { intersection, .. } => Some(intersection),
+            LineIntersection::Collinear { intersection } => {
+                if distance(intersection.start) <= distance(intersection.end) {
+                    Some(intersection.start)
+                } else {
+                    Some(intersection.end)
+                }
+            }
+        })
+        .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Area, Contains, Winding};
+
+    #[test]
+    fn convex_polygon_is_fully_visible() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let visible = square.visibility_polygon(&point!(x: 3., y: 7.)).unwrap();
+        assert_relative_eq!(visible.unsigned_area(), 100., epsilon = 1e-6);
+        assert!(visible.exterior().is_ccw());
+    }
+
+    #[test]
+    fn occluded_by_reflex_corner() {
+        let room = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let visible = room.visibility_polygon(&point!(x: 3., y: 1.)).unwrap();
+        // The reflex corner at (2, 2) hides the triangle (2, 2), (2, 4), (0, 4)
+        // of the upper arm.
+        let hidden_area = room.unsigned_area() - visible.unsigned_area();
+        assert_relative_eq!(hidden_area, 2., epsilon = 1e-6);
+        assert!(!visible.contains(&point!(x: 1., y: 3.5)));
+        assert!(visible.contains(&point!(x: 1., y: 1.)));
+    }
+
+    #[test]
+    fn holes_cast_shadows() {
+        let room = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [
+                [
+                    (x: 4., y: 4.),
+                    (x: 6., y: 4.),
+                    (x: 6., y: 6.),
+                    (x: 4., y: 6.),
+                ],
+            ],
+        ];
+        let visible = room.visibility_polygon(&point!(x: 5., y: 1.)).unwrap();
+        assert!(visible.contains(&point!(x: 1., y: 9.)));
+        assert!(!visible.contains(&point!(x: 5., y: 9.)));
+        assert!(visible.unsigned_area() < room.unsigned_area());
+    }
+
+    #[test]
+    fn viewpoint_not_in_interior() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        assert!(square.visibility_polygon(&point!(x: 11., y: 5.)).is_none());
+        assert!(square.visibility_polygon(&point!(x: 10., y: 5.)).is_none());
+    }
+}
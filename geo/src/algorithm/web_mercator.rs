@@ -0,0 +1,111 @@
+use crate::{coord, Coord, CoordFloat, MapCoords};
+
+/// The radius of the sphere used by Web Mercator, which is WGS84's equatorial radius.
+const RADIUS: f64 = 6_378_137.;
+
+/// The latitude at which Web Mercator's square world tile ends, in degrees.
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Convert geometries between WGS84 longitude/latitude and [Web Mercator] (EPSG:3857)
+/// coordinates, as used by most web map tiles.
+///
+/// Web Mercator coordinates are in meters, and range from about −20037508.34 to 20037508.34 on
+/// both axes. Latitudes beyond ±85.0511°, where that square ends, are clamped to it.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, WebMercator};
+///
+/// let route = line_string![
+///     (x: 0., y: 0.),
+///     (x: -0.1278, y: 51.5074),
+/// ];
+///
+/// let projected = route.to_web_mercator();
+/// assert_relative_eq!(
+///     projected,
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: -14_226.630, y: 6_711_542.475),
+///     ],
+///     epsilon = 1e-3
+/// );
+///
+/// assert_relative_eq!(projected.web_mercator_to_lon_lat(), route, epsilon = 1e-9);
+/// ```
+///
+/// [Web Mercator]: https://en.wikipedia.org/wiki/Web_Mercator_projection
+pub trait WebMercator<T: CoordFloat> {
+    type Output;
+
+    /// Convert longitude and latitude, in degrees, to Web Mercator coordinates.
+    fn to_web_mercator(&self) -> Self::Output;
+
+    /// Convert Web Mercator coordinates to longitude and latitude, in degrees.
+    fn web_mercator_to_lon_lat(&self) -> Self::Output;
+}
+
+impl<T, G> WebMercator<T> for G
+where
+    T: CoordFloat,
+    G: MapCoords<T, T>,
+{
+    type Output = G::Output;
+
+    fn to_web_mercator(&self) -> Self::Output {
+        self.map_coords(to_web_mercator)
+    }
+
+    fn web_mercator_to_lon_lat(&self) -> Self::Output {
+        self.map_coords(web_mercator_to_lon_lat)
+    }
+}
+
+fn to_web_mercator<T: CoordFloat>(coord: Coord<T>) -> Coord<T> {
+    let radius = T::from(RADIUS).unwrap();
+    let max_latitude = T::from(MAX_LATITUDE).unwrap();
+    let lat = coord.y.max(-max_latitude).min(max_latitude).to_radians();
+    coord! {
+        x: radius * coord.x.to_radians(),
+        y: radius * lat.tan().asinh(),
+    }
+}
+
+fn web_mercator_to_lon_lat<T: CoordFloat>(coord: Coord<T>) -> Coord<T> {
+    let radius = T::from(RADIUS).unwrap();
+    coord! {
+        x: (coord.x / radius).to_degrees(),
+        y: (coord.y / radius).sinh().atan().to_degrees(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Point};
+
+    #[test]
+    fn world_bounds() {
+        let corner = point!(x: 180., y: MAX_LATITUDE).to_web_mercator();
+        assert_relative_eq!(
+            corner,
+            point!(x: 20_037_508.342_789_244, y: 20_037_508.342_789_244),
+            epsilon = 1e-6
+        );
+
+        let clamped = point!(x: -180., y: -90.).to_web_mercator();
+        assert_relative_eq!(clamped, -corner, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn f32_round_trip() {
+        let point: Point<f32> = point!(x: 2.2945, y: 48.8583);
+        assert_relative_eq!(
+            point.to_web_mercator().web_mercator_to_lon_lat(),
+            point,
+            epsilon = 1e-4
+        );
+    }
+}
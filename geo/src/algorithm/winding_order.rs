@@ -2,8 +2,8 @@ use super::kernels::*;
 use crate::coords_iter::CoordsIter;
 use crate::utils::EitherIter;
 use crate::{CoordNum, GeoNum, LineString, Point};
+use core::iter::Rev;
 use geo_types::PointsIter;
-use std::iter::Rev;
 
 /// Iterates through a list of `Point`s
 #[allow(missing_debug_implementations)]
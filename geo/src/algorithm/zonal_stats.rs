@@ -0,0 +1,255 @@
+use alloc::{vec, vec::Vec};
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, AABB};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::{BoundingRect, GeoFloat, MultiPolygon, Point, Polygon, Rect};
+
+/// Statistics of the weights of the points in a zone, from [`ZonalStats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoneStats<T> {
+    /// The number of points in the zone.
+    pub count: usize,
+    /// The sum of their weights.
+    pub sum: T,
+    /// The smallest of their weights.
+    pub min: T,
+    /// The largest of their weights.
+    pub max: T,
+}
+
+impl<T: GeoFloat> ZoneStats<T> {
+    /// The mean of the weights of the points in the zone.
+    pub fn mean(&self) -> T {
+        self.sum / T::from(self.count).unwrap()
+    }
+
+    fn new(weight: T) -> Self {
+        ZoneStats {
+            count: 1,
+            sum: weight,
+            min: weight,
+            max: weight,
+        }
+    }
+
+    fn add(&mut self, weight: T) {
+        self.count += 1;
+        self.sum = self.sum + weight;
+        self.min = self.min.min(weight);
+        self.max = self.max.max(weight);
+    }
+}
+
+/// Aggregate weighted points into statistics for each of a set of polygonal zones.
+///
+/// The zones are indexed together in an R-tree, so that each point is only tested against the
+/// zones whose bounding rectangles contain it. A point is counted in every zone whose interior
+/// contains it. A point which is on the boundary of zones, but in none of their interiors, is
+/// counted in the first of them, so that a point on the boundary between neighbouring zones is
+/// counted once.
+///
+/// The result has an entry for each zone, in order, which is `None` if no points are in it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, ZonalStats};
+///
+/// let zones = [
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+///     polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)],
+///     polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+/// ];
+/// // Points weighted by population, one of them on the boundary between the first two zones
+/// let points = [
+///     (point!(x: 0.5, y: 0.5), 100.),
+///     (point!(x: 0.2, y: 0.8), 300.),
+///     (point!(x: 1., y: 0.5), 50.),
+///     (point!(x: 1.5, y: 0.5), 20.),
+/// ];
+/// let stats = zones.zonal_stats(points);
+///
+/// let first = stats[0].unwrap();
+/// assert_eq!(first.count, 3);
+/// assert_eq!(first.sum, 450.);
+/// assert_eq!(first.mean(), 150.);
+/// assert_eq!((first.min, first.max), (50., 300.));
+/// assert_eq!(stats[1].unwrap().count, 1);
+/// assert_eq!(stats[2], None);
+/// ```
+pub trait ZonalStats<T: GeoFloat> {
+    /// The statistics of the weights of `points` in each zone of `self`.
+    fn zonal_stats<I>(&self, points: I) -> Vec<Option<ZoneStats<T>>>
+    where
+        I: IntoIterator<Item = (Point<T>, T)>;
+}
+
+impl<T: GeoFloat + RTreeNum> ZonalStats<T> for [Polygon<T>] {
+    fn zonal_stats<I>(&self, points: I) -> Vec<Option<ZoneStats<T>>>
+    where
+        I: IntoIterator<Item = (Point<T>, T)>,
+    {
+        zonal_stats(self, points)
+    }
+}
+
+impl<T: GeoFloat + RTreeNum> ZonalStats<T> for [MultiPolygon<T>] {
+    fn zonal_stats<I>(&self, points: I) -> Vec<Option<ZoneStats<T>>>
+    where
+        I: IntoIterator<Item = (Point<T>, T)>,
+    {
+        zonal_stats(self, points)
+    }
+}
+
+fn zonal_stats<T, Z, I>(zones: &[Z], points: I) -> Vec<Option<ZoneStats<T>>>
+where
+    T: GeoFloat + RTreeNum,
+    Z: CoordinatePosition<Scalar = T> + BoundingRect<T, Output = Option<Rect<T>>>,
+    I: IntoIterator<Item = (Point<T>, T)>,
+{
+    let tree = RTree::bulk_load(
+        zones
+            .iter()
+            .enumerate()
+            .filter_map(|(i, zone)| {
+                let rect = zone.bounding_rect()?;
+                let rectangle = Rectangle::from_corners(rect.min().into(), rect.max().into());
+                Some(GeomWithData::new(rectangle, i))
+            })
+            .collect(),
+    );
+
+    let mut stats: Vec<Option<ZoneStats<T>>> = vec![None; zones.len()];
+    let mut add = |zone: usize, weight: T| match &mut stats[zone] {
+        Some(zone_stats) => zone_stats.add(weight),
+        None => stats[zone] = Some(ZoneStats::new(weight)),
+    };
+    let mut candidates = Vec::new();
+    for (point, weight) in points {
+        candidates.clear();
+        candidates.extend(
+            tree.locate_in_envelope_intersecting(&AABB::from_point(point))
+                .map(|zone| zone.data),
+        );
+        candidates.sort_unstable();
+
+        let mut inside = false;
+        let mut boundary = None;
+        for &zone in &candidates {
+            match zones[zone].coordinate_position(&point.0) {
+                CoordPos::Inside => {
+                    inside = true;
+                    add(zone, weight);
+                }
+                CoordPos::OnBoundary if boundary.is_none() => boundary = Some(zone),
+                _ => {}
+            }
+        }
+        if let (false, Some(zone)) = (inside, boundary) {
+            add(zone, weight);
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains, Intersects};
+
+    #[test]
+    fn matches_brute_force() {
+        // A grid of zones, one of them with a hole, and a diagonal line of points
+        let mut zones = Vec::new();
+        for i in 0..4 {
+            for j in 0..4 {
+                let (x, y) = (i as f64, j as f64);
+                zones.push(polygon![
+                    (x: x, y: y),
+                    (x: x + 1., y: y),
+                    (x: x + 1., y: y + 1.),
+                    (x: x, y: y + 1.),
+                ]);
+            }
+        }
+        zones[0] = polygon![
+            exterior: [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            interiors: [[(x: 0.1, y: 0.1), (x: 0.4, y: 0.1), (x: 0.4, y: 0.4), (x: 0.1, y: 0.4)]],
+        ];
+        let points: Vec<_> = (0..100)
+            .map(|i| {
+                let t = i as f64 / 25.;
+                (point!(x: t, y: (t * 1.7).rem_euclid(4.)), i as f64)
+            })
+            .collect();
+        let stats = zones.zonal_stats(points.iter().copied());
+
+        for (zone, stats) in zones.iter().zip(&stats) {
+            let inside: Vec<f64> = points
+                .iter()
+                .filter(|(point, _)| zone.contains(point))
+                .map(|(_, weight)| *weight)
+                .collect();
+            let Some(stats) = stats else {
+                assert!(inside.is_empty());
+                continue;
+            };
+            // Points on the boundary are counted in one of the zones which they're on
+            assert!(stats.count >= inside.len());
+            assert!(stats.sum >= inside.iter().sum::<f64>());
+        }
+        // Every point is counted once, because the zones don't overlap
+        let count: usize = stats.iter().flatten().map(|stats| stats.count).sum();
+        let expected = points
+            .iter()
+            .filter(|(point, _)| zones.iter().any(|zone| zone.intersects(point)))
+            .count();
+        assert_eq!(count, expected);
+    }
+
+    #[test]
+    fn overlapping_multi_polygons() {
+        let square = |min: f64, max: f64| polygon![(x: min, y: min), (x: max, y: min), (x: max, y: max), (x: min, y: max)];
+        let zones = [
+            MultiPolygon::new(vec![square(0., 2.), square(5., 6.)]),
+            MultiPolygon::new(vec![square(1., 3.)]),
+        ];
+        let points = [
+            (point!(x: 1.5, y: 1.5), 1.),
+            (point!(x: 5.5, y: 5.5), 2.),
+            (point!(x: 2.5, y: 2.5), 4.),
+            // On the boundary of both zones
+            (point!(x: 1., y: 2.), 8.),
+        ];
+        let stats = zones.zonal_stats(points);
+        assert_eq!(
+            stats[0],
+            Some(ZoneStats {
+                count: 3,
+                sum: 11.,
+                min: 1.,
+                max: 8.,
+            })
+        );
+        assert_eq!(
+            stats[1],
+            Some(ZoneStats {
+                count: 2,
+                sum: 5.,
+                min: 1.,
+                max: 4.,
+            })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let zones: [Polygon; 0] = [];
+        assert!(zones.zonal_stats([(point!(x: 0., y: 0.), 1.)]).is_empty());
+        let zones = [polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]];
+        assert_eq!(zones.zonal_stats([]), vec![None]);
+    }
+}
@@ -0,0 +1,542 @@
+//! Generate valid geometries from unstructured data with [arbitrary], for property based
+//! testing and fuzzing of code built on `geo`.
+//!
+//! The [`Arbitrary`] implementations of `geo-types` build geometries from any coordinates at
+//! all, including NaNs, and polygons whose rings cross themselves and each other, which is what
+//! a parser should be fuzzed with, but not what most algorithms are specified for. The
+//! [`GeometryGenerator`] here instead builds geometries which are [valid](crate::Validation),
+//! with coordinates in a bounding rectangle, for any input: convex and star-shaped polygons,
+//! polygons with holes, line strings, and collections of them. The [`Valid`], [`Convex`] and
+//! [`StarShaped`] wrappers implement `Arbitrary` with a default generator, to be used as the
+//! inputs of fuzz targets, or of property tests with any framework which can drive
+//! `Arbitrary`.
+//!
+//! This module requires the `arbitrary` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use geo::arbitrary::{GeometryGenerator, Valid};
+//! use geo::{coord, Area, Contains, Polygon, Rect, Validation};
+//!
+//! // Any bytes, such as those from a fuzzer
+//! let bytes: Vec<u8> = (0..1024_u32).map(|i| (i * 7919 % 251) as u8).collect();
+//! let mut u = Unstructured::new(&bytes);
+//!
+//! let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+//! let generator = GeometryGenerator::new(bounds).max_vertices(8).max_holes(2);
+//! let polygon = generator.polygon_with_holes(&mut u).unwrap();
+//! assert!(polygon.is_valid());
+//! assert!(bounds.contains(&polygon));
+//! assert!(polygon.unsigned_area() > 0.);
+//!
+//! let Valid(polygon) = Valid::<Polygon>::arbitrary(&mut u).unwrap();
+//! assert!(polygon.is_valid());
+//! ```
+//!
+//! [arbitrary]: https://docs.rs/arbitrary
+
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+
+pub use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect,
+};
+
+/// The number of sides of the exterior of a polygon with holes, which is enough for its
+/// vertices, which are at least at 0.6 of its radius, to keep it clear of the disc at half its
+/// radius, in which the holes are placed.
+const MIN_SIDES_WITH_HOLES: usize = 8;
+
+/// Generates valid geometries, with coordinates in a bounding rectangle, from [`Unstructured`]
+/// data.
+///
+/// Every method succeeds for any data, however short: when the data runs out, the generator
+/// carries on with the value `arbitrary` gives for exhausted data, so the geometries become
+/// regular, but stay valid.
+///
+/// Polygons have counter-clockwise exteriors, and clockwise interiors. They're generated in an
+/// ellipse inside the bounding rectangle, with their vertices at increasing angles around its
+/// center, so that they're star-shaped with respect to it, and never cross themselves. The
+/// polygons of a `MultiPolygon` are in separate cells of a grid over the bounding rectangle, so
+/// that they don't intersect.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::Unstructured;
+/// use geo::arbitrary::GeometryGenerator;
+/// use geo::{coord, IsConvex, Rect, Validation};
+///
+/// let bytes = [0x5a_u8; 256];
+/// let mut u = Unstructured::new(&bytes);
+/// let generator =
+///     GeometryGenerator::new(Rect::new(coord! { x: -1., y: -1. }, coord! { x: 1., y: 1. }));
+///
+/// let convex = generator.convex_polygon(&mut u).unwrap();
+/// assert!(convex.exterior().is_strictly_convex());
+///
+/// let parcels = generator.max_members(6).multi_polygon(&mut u).unwrap();
+/// assert!(parcels.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryGenerator<T: GeoFloat = f64> {
+    bounds: Rect<T>,
+    max_vertices: usize,
+    max_holes: usize,
+    max_members: usize,
+}
+
+impl<T: GeoFloat> Default for GeometryGenerator<T> {
+    /// A generator of geometries in the square from -1000 to 1000 on each axis.
+    fn default() -> Self {
+        let size = T::from(1000).unwrap();
+        GeometryGenerator::new(Rect::new(
+            Coord { x: -size, y: -size },
+            Coord { x: size, y: size },
+        ))
+    }
+}
+
+impl<T: GeoFloat> GeometryGenerator<T> {
+    /// A generator of geometries in `bounds`, with at most 16 vertices in each line string or
+    /// ring, 3 holes in each polygon, and 4 members in each collection.
+    ///
+    /// # Panics
+    ///
+    /// If `bounds` has no area.
+    pub fn new(bounds: Rect<T>) -> Self {
+        assert!(
+            bounds.width() > T::zero() && bounds.height() > T::zero(),
+            "the bounds of generated geometries must have an area"
+        );
+        GeometryGenerator {
+            bounds,
+            max_vertices: 16,
+            max_holes: 3,
+            max_members: 4,
+        }
+    }
+
+    /// Generate line strings, and the rings of polygons, with at most `max_vertices` distinct
+    /// vertices, which is at least 3, except for the exteriors of polygons with holes, which
+    /// have at least 8.
+    #[must_use]
+    pub fn max_vertices(mut self, max_vertices: usize) -> Self {
+        self.max_vertices = max_vertices.max(3);
+        self
+    }
+
+    /// Generate polygons with holes with at most `max_holes` holes.
+    #[must_use]
+    pub fn max_holes(mut self, max_holes: usize) -> Self {
+        self.max_holes = max_holes;
+        self
+    }
+
+    /// Generate collections with at most `max_members` members.
+    #[must_use]
+    pub fn max_members(mut self, max_members: usize) -> Self {
+        self.max_members = max_members;
+        self
+    }
+
+    /// The rectangle which the generated geometries are in.
+    pub fn bounds(&self) -> Rect<T> {
+        self.bounds
+    }
+
+    /// A coordinate in the bounding rectangle.
+    pub fn coord(&self, u: &mut Unstructured) -> Result<Coord<T>> {
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        Ok(Coord {
+            x: between(u, min.x, max.x)?,
+            y: between(u, min.y, max.y)?,
+        })
+    }
+
+    /// A point in the bounding rectangle.
+    pub fn point(&self, u: &mut Unstructured) -> Result<Point<T>> {
+        self.coord(u).map(Point)
+    }
+
+    /// A line string of between 2 and the maximum number of vertices, without consecutive
+    /// repeated coordinates, which may cross itself.
+    pub fn line_string(&self, u: &mut Unstructured) -> Result<LineString<T>> {
+        let len = u.int_in_range(2..=self.max_vertices)?;
+        let mut coords: Vec<Coord<T>> = Vec::with_capacity(len);
+        for _ in 0..len {
+            let coord = self.coord(u)?;
+            if coords.last() != Some(&coord) {
+                coords.push(coord);
+            }
+        }
+        if coords.len() < 2 {
+            // Only when the data ran out, so that every coordinate is the same
+            let (min, max) = (self.bounds.min(), self.bounds.max());
+            let other = if coords[0] == max { min } else { max };
+            coords.push(other);
+        }
+        Ok(LineString::new(coords))
+    }
+
+    /// A convex polygon, without holes.
+    pub fn convex_polygon(&self, u: &mut Unstructured) -> Result<Polygon<T>> {
+        let ellipse = self.ellipse(u)?;
+        let sides = u.int_in_range(3..=self.max_vertices)?;
+        let exterior = ring(u, sides, |_| Ok(1.), ellipse)?;
+        Ok(Polygon::new(exterior, Vec::new()))
+    }
+
+    /// A polygon which is star-shaped with respect to a point inside it, without holes.
+    pub fn star_polygon(&self, u: &mut Unstructured) -> Result<Polygon<T>> {
+        let ellipse = self.ellipse(u)?;
+        let sides = u.int_in_range(3..=self.max_vertices)?;
+        let exterior = ring(u, sides, |u| fraction(u, 0.2, 1.), ellipse)?;
+        Ok(Polygon::new(exterior, Vec::new()))
+    }
+
+    /// A star-shaped polygon with up to the maximum number of holes, which are convex, and
+    /// don't touch each other or the exterior.
+    pub fn polygon_with_holes(&self, u: &mut Unstructured) -> Result<Polygon<T>> {
+        let ellipse = self.ellipse(u)?;
+        let sides =
+            u.int_in_range(MIN_SIDES_WITH_HOLES..=self.max_vertices.max(MIN_SIDES_WITH_HOLES))?;
+        let exterior = ring(u, sides, |u| fraction(u, 0.6, 1.), ellipse)?;
+
+        let holes = u.int_in_range(0..=self.max_holes)?;
+        let mut interiors = Vec::with_capacity(holes);
+        // Each hole is in a disc of its own inside the disc at 0.45 of the radius, where the
+        // exterior can't reach, around the center for one hole, and evenly around it for more
+        let (distance, max_radius) = if holes == 1 {
+            (0., 0.4)
+        } else {
+            (0.25, 0.2 * (core::f64::consts::PI / holes as f64).sin())
+        };
+        for hole in 0..holes {
+            let angle = TAU * hole as f64 / holes as f64;
+            let radius = fraction(u, 0.25, 1.)? * max_radius;
+            let disc = Ellipse {
+                center: (distance * angle.cos(), distance * angle.sin()),
+                radii: (radius, radius),
+            };
+            let sides = u.int_in_range(3..=self.max_vertices)?;
+            let mut interior = ring(u, sides, |_| Ok(1.), disc.within(ellipse))?;
+            interior.0.reverse();
+            interiors.push(interior);
+        }
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    /// A convex or star-shaped polygon, or a polygon with holes.
+    pub fn polygon(&self, u: &mut Unstructured) -> Result<Polygon<T>> {
+        match u.int_in_range(0..=2_u8)? {
+            0 => self.convex_polygon(u),
+            1 => self.star_polygon(u),
+            _ => self.polygon_with_holes(u),
+        }
+    }
+
+    /// Up to the maximum number of points, which may be repeated.
+    pub fn multi_point(&self, u: &mut Unstructured) -> Result<MultiPoint<T>> {
+        let len = u.int_in_range(0..=self.max_members)?;
+        (0..len).map(|_| self.point(u)).collect()
+    }
+
+    /// Up to the maximum number of line strings, which may cross each other.
+    pub fn multi_line_string(&self, u: &mut Unstructured) -> Result<MultiLineString<T>> {
+        let len = u.int_in_range(0..=self.max_members)?;
+        (0..len).map(|_| self.line_string(u)).collect()
+    }
+
+    /// Up to the maximum number of polygons, which don't intersect each other.
+    pub fn multi_polygon(&self, u: &mut Unstructured) -> Result<MultiPolygon<T>> {
+        let len = u.int_in_range(0..=self.max_members)?;
+        let columns = (1..).find(|columns| columns * columns >= len).unwrap_or(1);
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let cell_width = (max.x - min.x) / T::from(columns).unwrap();
+        let cell_height = (max.y - min.y) / T::from(columns).unwrap();
+        let margin = T::from(0.05).unwrap();
+
+        let mut polygons = Vec::with_capacity(len);
+        for cell in 0..len {
+            let (column, row) = (
+                T::from(cell % columns).unwrap(),
+                T::from(cell / columns).unwrap(),
+            );
+            let cell_min = Coord {
+                x: min.x + cell_width * (column + margin),
+                y: min.y + cell_height * (row + margin),
+            };
+            let cell_max = Coord {
+                x: min.x + cell_width * (column + T::one() - margin),
+                y: min.y + cell_height * (row + T::one() - margin),
+            };
+            let generator = GeometryGenerator {
+                bounds: Rect::new(cell_min, cell_max),
+                ..*self
+            };
+            polygons.push(generator.polygon(u)?);
+        }
+        Ok(MultiPolygon::new(polygons))
+    }
+
+    /// A point, line string, polygon, or a collection of any one of them.
+    pub fn geometry(&self, u: &mut Unstructured) -> Result<Geometry<T>> {
+        Ok(match u.int_in_range(0..=5_u8)? {
+            0 => self.point(u)?.into(),
+            1 => self.line_string(u)?.into(),
+            2 => self.polygon(u)?.into(),
+            3 => self.multi_point(u)?.into(),
+            4 => self.multi_line_string(u)?.into(),
+            _ => self.multi_polygon(u)?.into(),
+        })
+    }
+
+    /// Up to the maximum number of geometries, as from [`geometry`](Self::geometry), which
+    /// may intersect each other.
+    pub fn geometry_collection(&self, u: &mut Unstructured) -> Result<GeometryCollection<T>> {
+        let len = u.int_in_range(0..=self.max_members)?;
+        let geometries = (0..len)
+            .map(|_| self.geometry(u))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GeometryCollection::new_from(geometries))
+    }
+
+    /// An ellipse inside the bounding rectangle, of between a fifth and nearly all of its width
+    /// and height.
+    fn ellipse(&self, u: &mut Unstructured) -> Result<Ellipse> {
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let (width, height) = (
+            (max.x - min.x).to_f64().unwrap(),
+            (max.y - min.y).to_f64().unwrap(),
+        );
+        let radii = (
+            fraction(u, 0.1, 0.495)? * width,
+            fraction(u, 0.1, 0.495)? * height,
+        );
+        // Kept off the edges, so that rounding can't take the vertices out of the bounds
+        let center = (
+            min.x.to_f64().unwrap()
+                + width / 2.
+                + fraction(u, -0.99, 0.99)? * (width / 2. - radii.0),
+            min.y.to_f64().unwrap()
+                + height / 2.
+                + fraction(u, -0.99, 0.99)? * (height / 2. - radii.1),
+        );
+        Ok(Ellipse { center, radii })
+    }
+}
+
+/// An axis-aligned ellipse, which the unit disc is mapped to.
+#[derive(Debug, Clone, Copy)]
+struct Ellipse {
+    center: (f64, f64),
+    radii: (f64, f64),
+}
+
+impl Ellipse {
+    fn map(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            self.center.0 + x * self.radii.0,
+            self.center.1 + y * self.radii.1,
+        )
+    }
+
+    /// This ellipse, as a part of the unit disc, mapped to `outer`.
+    fn within(&self, outer: Ellipse) -> Ellipse {
+        Ellipse {
+            center: outer.map(self.center),
+            radii: (self.radii.0 * outer.radii.0, self.radii.1 * outer.radii.1),
+        }
+    }
+}
+
+/// A value from `min` to `max`.
+fn between<T: GeoFloat>(u: &mut Unstructured, min: T, max: T) -> Result<T> {
+    let fraction = T::from(u.arbitrary::<u32>()?).unwrap() / T::from(u32::MAX).unwrap();
+    // Clamped, since the interpolation can round past `max`
+    Ok((min + (max - min) * fraction).min(max))
+}
+
+/// A value from `min` to `max`, in `f64`.
+fn fraction(u: &mut Unstructured, min: f64, max: f64) -> Result<f64> {
+    between(u, min, max)
+}
+
+/// A counter-clockwise closed ring of `sides` vertices in `ellipse`, at increasing angles
+/// around its center, each with a sector of its own, and at the distance by `radius`, which is
+/// at most 1, from it.
+fn ring<T: GeoFloat>(
+    u: &mut Unstructured,
+    sides: usize,
+    mut radius: impl FnMut(&mut Unstructured) -> Result<f64>,
+    ellipse: Ellipse,
+) -> Result<LineString<T>> {
+    let rotation = fraction(u, 0., 1.)?;
+    let mut coords = Vec::with_capacity(sides + 1);
+    for side in 0..sides {
+        // In the middle half of the sector, so that consecutive vertices are well apart
+        let offset = fraction(u, 0.25, 0.75)?;
+        let angle = TAU * (side as f64 + offset + rotation) / sides as f64;
+        let radius = radius(u)?;
+        let (x, y) = ellipse.map((radius * angle.cos(), radius * angle.sin()));
+        coords.push(Coord {
+            x: T::from(x).unwrap(),
+            y: T::from(y).unwrap(),
+        });
+    }
+    coords.push(coords[0]);
+    Ok(LineString::new(coords))
+}
+
+/// A valid geometry, in the square from -1000 to 1000 on each axis, from the default
+/// [`GeometryGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use geo::arbitrary::Valid;
+/// use geo::{Geometry, Validation};
+///
+/// let mut u = Unstructured::new(b"any bytes at all, such as those of a fuzzer");
+/// let Valid(geometry) = Valid::<Geometry>::arbitrary(&mut u).unwrap();
+/// assert!(geometry.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valid<G>(pub G);
+
+/// A convex polygon, as from [`GeometryGenerator::convex_polygon`] with the default generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convex<T: GeoFloat = f64>(pub Polygon<T>);
+
+/// A star-shaped polygon, as from [`GeometryGenerator::star_polygon`] with the default
+/// generator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StarShaped<T: GeoFloat = f64>(pub Polygon<T>);
+
+macro_rules! impl_arbitrary {
+    ($($wrapper:ident<$geometry:ty> => $generate:ident,)*) => {
+        $(
+            impl<'a, T: GeoFloat> Arbitrary<'a> for $wrapper<$geometry> {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    GeometryGenerator::default().$generate(u).map($wrapper)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary! {
+    Valid<Coord<T>> => coord,
+    Valid<Point<T>> => point,
+    Valid<LineString<T>> => line_string,
+    Valid<Polygon<T>> => polygon,
+    Valid<MultiPoint<T>> => multi_point,
+    Valid<MultiLineString<T>> => multi_line_string,
+    Valid<MultiPolygon<T>> => multi_polygon,
+    Valid<Geometry<T>> => geometry,
+    Valid<GeometryCollection<T>> => geometry_collection,
+}
+
+impl<'a, T: GeoFloat> Arbitrary<'a> for Convex<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        GeometryGenerator::default().convex_polygon(u).map(Convex)
+    }
+}
+
+impl<'a, T: GeoFloat> Arbitrary<'a> for StarShaped<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        GeometryGenerator::default().star_polygon(u).map(StarShaped)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, Contains, CoordsIter, Intersects, IsConvex, Validation, Winding};
+
+    /// Pseudo-random bytes, from a linear congruential generator
+    fn bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_for_any_data() {
+        let bounds = Rect::new(coord! { x: 10., y: -5. }, coord! { x: 30., y: 5. });
+        let generator = GeometryGenerator::new(bounds);
+        for seed in 0..200 {
+            // Including data which runs out part way through, or is empty
+            let data = bytes(seed, (seed as usize * 37) % 2000);
+            let mut u = Unstructured::new(&data);
+
+            let convex = generator.convex_polygon(&mut u).unwrap();
+            assert!(convex.exterior().is_strictly_convex(), "{seed}: {convex:?}");
+            let star = generator.star_polygon(&mut u).unwrap();
+            let holes = generator.polygon_with_holes(&mut u).unwrap();
+            let lines = generator.multi_line_string(&mut u).unwrap();
+            let polygons = generator.multi_polygon(&mut u).unwrap();
+            let collection = generator.geometry_collection(&mut u).unwrap();
+            for geometry in [
+                Geometry::from(convex),
+                star.into(),
+                holes.into(),
+                lines.into(),
+                polygons.into(),
+                Geometry::GeometryCollection(collection),
+            ] {
+                assert!(geometry.is_valid(), "{seed}: {geometry:?}");
+                assert!(
+                    geometry
+                        .coords_iter()
+                        .all(|coord| bounds.to_polygon().intersects(&coord)),
+                    "{seed}: {geometry:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn counts_and_orientation() {
+        let generator = GeometryGenerator::<f32>::default()
+            .max_vertices(5)
+            .max_holes(4)
+            .max_members(2);
+        let mut saw_holes = false;
+        for seed in 0..100 {
+            let data = bytes(seed, 4000);
+            let mut u = Unstructured::new(&data);
+            let polygon = generator.polygon_with_holes(&mut u).unwrap();
+            assert!(polygon.exterior().0.len() <= MIN_SIDES_WITH_HOLES + 1);
+            assert!(polygon.interiors().len() <= 4);
+            saw_holes |= polygon.interiors().len() > 1;
+            assert!(polygon.exterior().is_ccw());
+            let exterior = Polygon::new(polygon.exterior().clone(), vec![]);
+            for interior in polygon.interiors() {
+                assert!(interior.is_cw());
+                assert!(interior.0.len() <= 6);
+                assert!(exterior.contains(interior));
+            }
+            let lines = generator.multi_line_string(&mut u).unwrap();
+            assert!(lines.0.len() <= 2);
+            assert!(lines.iter().all(|line| line.0.len() <= 5));
+        }
+        assert!(saw_holes);
+
+        let Valid(polygon) = Valid::<Polygon>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert!(polygon.is_valid());
+        let Convex(convex) = Convex::<f64>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert!(convex.is_valid());
+    }
+}
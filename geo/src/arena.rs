@@ -0,0 +1,145 @@
+//! Geometries whose coordinates are allocated in a [`Bump`] arena.
+//!
+//! Pipelines which cut many small geometries, such as the features of map tiles, can spend much
+//! of their time allocating and freeing the `Vec`s of owned geometries. Allocating the
+//! coordinates of a [`LineStringSlice`] or [`PolygonRef`] in a `Bump` instead is a pointer bump,
+//! and all of the geometries of, say, a tile are freed at once by resetting the arena.
+//!
+//! The [`ArenaBooleanOps`](crate::ArenaBooleanOps) trait computes boolean operations and
+//! clipping of `PolygonRef`s, with their output allocated in a `Bump`.
+//!
+//! This module requires the `bumpalo` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::arena::Bump;
+//! use geo::view::PolygonRef;
+//! use geo::{polygon, Area};
+//!
+//! let mut bump = Bump::new();
+//! for tile in 0..4 {
+//!     let min = tile as f64;
+//!     let square = PolygonRef::alloc_in(
+//!         &bump,
+//!         [(min, min), (min + 1., min), (min + 1., min + 1.), (min, min + 1.)].map(Into::into),
+//!         [] as [[_; 0]; 0],
+//!     );
+//!     assert_eq!(square.unsigned_area(), 1.);
+//!     // The arena's memory is reused for the next tile
+//!     bump.reset();
+//! }
+//!
+//! // Owned geometries can be copied into an arena through the view of their coordinates
+//! let bump = Bump::new();
+//! let owned = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+//! let polygon = PolygonRef::from(&owned).clone_in(&bump);
+//! assert_eq!(polygon.to_polygon(), owned);
+//! ```
+
+pub use bumpalo::Bump;
+
+use crate::view::{LineStringSlice, PolygonRef};
+use crate::{Coord, CoordNum};
+use bumpalo::collections::Vec as BumpVec;
+
+impl<'bump, T: CoordNum> LineStringSlice<'bump, T> {
+    /// Allocate `coords` in `bump`, and view them as a `LineString`.
+    pub fn alloc_in(bump: &'bump Bump, coords: impl IntoIterator<Item = Coord<T>>) -> Self {
+        LineStringSlice(BumpVec::from_iter_in(coords, bump).into_bump_slice())
+    }
+
+    /// Copy the coordinates into `bump`.
+    pub fn clone_in<'b>(&self, bump: &'b Bump) -> LineStringSlice<'b, T> {
+        LineStringSlice(bump.alloc_slice_copy(self.0))
+    }
+}
+
+impl<'bump, T: CoordNum> PolygonRef<'bump, T> {
+    /// Allocate the coordinates of the exterior and interior rings in `bump`, and view them as a
+    /// `Polygon`.
+    ///
+    /// As for [`Polygon::new`](crate::Polygon::new), the rings are closed if they aren't already.
+    pub fn alloc_in<R>(
+        bump: &'bump Bump,
+        exterior: impl IntoIterator<Item = Coord<T>>,
+        interiors: impl IntoIterator<Item = R>,
+    ) -> Self
+    where
+        R: IntoIterator<Item = Coord<T>>,
+    {
+        let interiors = interiors
+            .into_iter()
+            .map(|interior| alloc_ring_in(bump, interior));
+        PolygonRef::with_interiors(
+            alloc_ring_in(bump, exterior),
+            BumpVec::from_iter_in(interiors, bump).into_bump_slice(),
+        )
+    }
+
+    /// Copy the coordinates of the rings into `bump`.
+    pub fn clone_in<'b>(&self, bump: &'b Bump) -> PolygonRef<'b, T> {
+        let interiors = self
+            .interiors()
+            .iter()
+            .map(|interior| interior.clone_in(bump));
+        PolygonRef::with_interiors(
+            self.exterior().clone_in(bump),
+            BumpVec::from_iter_in(interiors, bump).into_bump_slice(),
+        )
+    }
+}
+
+/// Allocate the coordinates of a ring in `bump`, closing it if it isn't already.
+pub(crate) fn alloc_ring_in<T: CoordNum>(
+    bump: &Bump,
+    coords: impl IntoIterator<Item = Coord<T>>,
+) -> LineStringSlice<'_, T> {
+    let mut ring = BumpVec::from_iter_in(coords, bump);
+    if let (Some(&first), Some(&last)) = (ring.first(), ring.last()) {
+        if first != last {
+            ring.push(first);
+        }
+    }
+    LineStringSlice(ring.into_bump_slice())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, Area, Centroid, Polygon};
+
+    #[test]
+    fn alloc_in_closes_rings() {
+        let bump = Bump::new();
+        let polygon = PolygonRef::alloc_in(
+            &bump,
+            [(0., 0.), (4., 0.), (4., 4.), (0., 4.)].map(Coord::from),
+            [[(1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.)].map(Coord::from)],
+        );
+        let expected = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        ];
+        assert_eq!(polygon.to_polygon(), expected);
+        assert_eq!(polygon.unsigned_area(), 15.);
+        assert_eq!(polygon.centroid(), expected.centroid());
+
+        let empty = PolygonRef::<f64>::alloc_in(&bump, [], [] as [[Coord; 0]; 0]);
+        assert_eq!(empty.to_polygon(), Polygon::new(line_string![], vec![]));
+    }
+
+    #[test]
+    fn clone_in_another_arena() {
+        let line_string = {
+            let bump = Bump::new();
+            let coords = [coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }];
+            let in_bump = LineStringSlice::alloc_in(&bump, coords);
+            let other = Bump::new();
+            let cloned = in_bump.clone_in(&other);
+            drop(bump);
+            cloned.to_line_string()
+        };
+        assert_eq!(line_string, line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::algorithm::compress::CompressionError;
+use crate::algorithm::linear_referencing::CalibrationError;
+#[cfg(feature = "spade")]
+use crate::algorithm::triangulate_spade::TriangulationError;
+use crate::algorithm::validation::InvalidGeometry;
+use crate::algorithm::vincenty_distance::FailedToConvergeError;
+use crate::{CoordNum, CoordsIter};
+
+/// Why an algorithm couldn't produce a result.
+///
+/// The fallible `try_` variants of algorithms which would otherwise panic, or silently return
+/// nonsense, on bad input return this, as do conversions from the errors of the algorithms
+/// which already return their own, so that they can all be handled with `?`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Error, Simplify};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.), (x: 2., y: 0.)];
+/// assert!(matches!(
+///     line_string.try_simplify(&1.),
+///     Err(Error::NonFiniteCoordinate)
+/// ));
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A coordinate of the input is NaN or infinite.
+    NonFiniteCoordinate,
+    /// A parameter, named here, is out of the range the algorithm accepts, such as a NaN
+    /// tolerance.
+    InvalidParameter(&'static str),
+    /// A geometry isn't valid.
+    InvalidGeometry(InvalidGeometry),
+    /// A boolean operation failed, generally because an input is invalid, such as a polygon
+    /// whose rings cross.
+    BooleanOpFailed,
+    /// An ear-cutting triangulation failed.
+    TriangulationFailed,
+    /// The vertices of a mesh don't fit in the integer type of its indices.
+    IndexOverflow,
+    /// A constrained Delaunay triangulation failed.
+    #[cfg(feature = "spade")]
+    Triangulation(TriangulationError),
+    /// A geometry couldn't be compressed or decompressed.
+    Compression(CompressionError),
+    /// A route couldn't be calibrated.
+    Calibration(CalibrationError),
+    /// An iterative algorithm failed to converge.
+    FailedToConverge,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::NonFiniteCoordinate => write!(f, "a coordinate is not finite"),
+            Error::InvalidParameter(name) => write!(f, "invalid parameter: {name}"),
+            Error::InvalidGeometry(error) => write!(f, "invalid geometry: {error}"),
+            Error::BooleanOpFailed => write!(f, "the boolean operation failed"),
+            Error::TriangulationFailed => write!(f, "the triangulation failed"),
+            Error::IndexOverflow => write!(f, "too many vertices for the index type"),
+            #[cfg(feature = "spade")]
+            Error::Triangulation(error) => write!(f, "the triangulation failed: {error}"),
+            Error::Compression(error) => write!(f, "{error}"),
+            Error::Calibration(error) => write!(f, "{error}"),
+            Error::FailedToConverge => write!(f, "the algorithm failed to converge"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "spade")]
+            Error::Triangulation(error) => Some(error),
+            Error::InvalidGeometry(error) => Some(error),
+            Error::Compression(error) => Some(error),
+            Error::Calibration(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "spade")]
+impl From<TriangulationError> for Error {
+    fn from(error: TriangulationError) -> Self {
+        Error::Triangulation(error)
+    }
+}
+
+impl From<InvalidGeometry> for Error {
+    fn from(error: InvalidGeometry) -> Self {
+        Error::InvalidGeometry(error)
+    }
+}
+
+impl From<CompressionError> for Error {
+    fn from(error: CompressionError) -> Self {
+        Error::Compression(error)
+    }
+}
+
+impl From<CalibrationError> for Error {
+    fn from(error: CalibrationError) -> Self {
+        Error::Calibration(error)
+    }
+}
+
+impl From<FailedToConvergeError> for Error {
+    fn from(_: FailedToConvergeError) -> Self {
+        Error::FailedToConverge
+    }
+}
+
+/// Check that every coordinate of `geometry` is finite, which for integer coordinates they
+/// always are.
+pub(crate) fn check_finite<T: CoordNum, G: CoordsIter<Scalar = T> + ?Sized>(
+    geometry: &G,
+) -> Result<(), Error> {
+    let finite = |value: T| value.to_f64().is_some_and(f64::is_finite);
+    if geometry
+        .coords_iter()
+        .all(|coord| finite(coord.x) && finite(coord.y))
+    {
+        Ok(())
+    } else {
+        Err(Error::NonFiniteCoordinate)
+    }
+}
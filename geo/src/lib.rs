@@ -1,4 +1,5 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! The `geo` crate provides geospatial primitive types and algorithms.
 //!
@@ -34,18 +35,35 @@
 //! ## Area
 //!
 //! - **[`Area`]**: Calculate the planar area of a geometry
+//! - **[`RingAreas`]**: Calculate the signed planar area of each ring of a polygon, in order
 //! - **[`ChamberlainDuquetteArea`]**: Calculate the geodesic area of a geometry on a sphere using the algorithm presented in _Some Algorithms for Polygons on a Sphere_ by Chamberlain and Duquette (2007)
 //! - **[`GeodesicArea`]**: Calculate the geodesic area and perimeter of a geometry on an ellipsoid using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
+//! - **[`SurfaceArea3D`]**: Calculate the area of a sloping surface, such as a TIN, with an elevation for each vertex
+//! - **[`SecondMoments`]**: Calculate the second moments of area, or moments of inertia, of a polygonal geometry about its centroid
+//! - **[`Compactness`]**: Calculate the Polsby–Popper, Schwartzberg, Reock and convex hull compactness scores of a polygonal geometry
+//! - **[`Holes`]**: Calculate the areas of the holes of a polygon, and fill in the small ones
 //!
 //! ## Boolean Operations
 //!
 //! - **[`BooleanOps`]**: combine or split (Multi)Polygons using intersecton, union, xor, or difference operations
+//! - **[`BooleanOpsWith`]**: the operations of [`BooleanOps`], with a choice of [`OverlayBackend`] as a fallback for inputs the default sweep fails on
+//! - **[`ClassifiedBooleanOps`]**: the operations of [`BooleanOps`], with the boundary of the result split by the input each part of it came from
+//! - **[`ConvexBooleanOps`]**: the operations of [`BooleanOps`] on [`Rect`]s and [`Triangle`]s, without the general sweep
+//! - **[`Dissolve`]**: merge polygons which share edges, or intersect, optionally grouped by a key
+//! - **[`RemoveSmallRings`]**, **[`MergeSlivers`]**: clean up overlay outputs by removing rings smaller than an area, or merging slivers into their largest neighbours
+//! - **[`FillGaps`]**: fill the narrow gaps between the polygons of a coverage, merging each into a neighbour
+//! - **[`ClipToRect`]**: clip lines and polygons to a rectangle, much faster than [`BooleanOps`]
+//! - **[`EqualAreaPartition`]**: divide polygons into parts of equal area with straight cuts
 //!
 //! ## Distance
 //!
+//! - **[`AlongTrackDistance`]**: Calculate the distance along a great circle path to the point closest to a point, and that closest point
+//! - **[`CrossTrackDistance`]**: Calculate the minimum distance between a point and a great circle path
 //! - **[`EuclideanDistance`]**: Calculate the minimum euclidean distance between geometries
 //! - **[`GeodesicDistance`]**: Calculate the minimum geodesic distance between geometries using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
+//! - **[`GeodesicCrossTrack`]**: Calculate the cross track and along track distances between a point and a geodesic path on an ellipsoid
 //! - **[`HausdorffDistance`]**: Calculate "the maximum of the distances from a point in any of the sets to the nearest point in the other set." (Rote, 1991)
+//! - **[`DirectedHausdorffDistance`]**: Calculate the one-sided Hausdorff distance from one geometry to another, or how far it protrudes outside of the other, and the point it's farthest at
 //! - **[`HaversineDistance`]**: Calculate the minimum geodesic distance between geometries using the haversine formula
 //! - **[`RhumbDistance`]**: Calculate the length of a rhumb line connecting the two geometries
 //! - **[`VincentyDistance`]**: Calculate the minimum geodesic distance between geometries using Vincenty’s formula
@@ -53,14 +71,20 @@
 //! ## Length
 //!
 //! - **[`EuclideanLength`]**: Calculate the euclidean length of a geometry
+//! - **[`Length3D`]**: Calculate the length of a line in three dimensions, with an elevation for each coordinate
 //! - **[`GeodesicLength`]**: Calculate the geodesic length of a geometry using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HaversineLength`]**: Calculate the geodesic length of a geometry using the haversine formula
+//! - **[`LongestChord`]**: Find the longest straight line within a polygon, such as to measure the length of a lake
 //! - **[`RhumbLength`]**: Calculate the length of a geometry assuming it's composed of rhumb lines
 //! - **[`VincentyLength`]**: Calculate the geodesic length of a geometry using Vincenty’s formula
 //!
+//! ## Units
+//!
+//! - **[`Measure`]**: Calculate distances, lengths and areas as [`Quantity`]s in typed [`units`], such as meters or degrees, which record the metric which measured them
+//!
 //! ## Outlier Detection
 //!
-//! - **[`OutlierDetection`]**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
+//! - **[`OutlierDetection`]**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor), with Euclidean, haversine or custom distances
 //!
 //! ## Simplification
 //!
@@ -69,50 +93,97 @@
 //! - **[`SimplifyVw`]**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwPreserve`]**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`]**: Calculate a simplified geometry using the Visvalingam-Whyatt algorithm, returning coordinate indices
+//! - **[`SimplifyVwBudget`]**: Simplify a geometry using the Visvalingam-Whyatt algorithm, down to a number of coordinates or a WKB size
+//! - **[`LevelOfDetail`]**: Order the coordinates of a geometry by their Visvalingam-Whyatt importance once, to extract any level of detail in time proportional to its size
+//! - **[`SimplifyRadial`]**: Simplify a geometry by dropping points closer than a tolerance to the point before them, as a cheap first pass before the other algorithms
+//! - **[`SimplifyHaversine`]**, **[`SimplifyVwHaversine`]**: Simplify a geometry of longitude/latitude coordinates, with a tolerance in meters on a sphere
+//! - **[`SimplifyGeodesic`]**, **[`SimplifyVwGeodesic`]**: Simplify a geometry of longitude/latitude coordinates, with a tolerance in meters on an ellipsoid
+//! - **[`StreamingSimplifier`]**: Simplify a stream of coordinates as they arrive, with a sliding window variant of the Ramer–Douglas–Peucker algorithm in bounded memory
+//! - **[`SimplifyCoverage`]**: Simplify adjacent polygons, so that their shared boundaries are simplified identically
 //!
 //! ## Query
 //!
 //! - **[`HaversineBearing`]**: Calculate the bearing between points using great circle calculations.
 //! - **[`GeodesicBearing`]**: Calculate the bearing between points on a [geodesic](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid)
 //! - **[`RhumbBearing`]**: Calculate the angle from north of the rhumb line connecting two points.
+//! - **[`angles`]**: Normalize angles, bearings and longitudes, take differences between them the short way round, and measure the signed and turn angles at a vertex and the interior angles of a ring
+//! - **[`Bearings`]**: Calculate the bearing of each segment of a line string, and the turn angles between them, in the plane, on a sphere or on an ellipsoid
 //! - **[`ClosestPoint`]**: Find the point on a geometry
 //!   closest to a given point
 //! - **[`HaversineClosestPoint`]**: Find the point on a geometry
 //!   closest to a given point on a sphere using spherical coordinates and lines being great arcs.
+//! - **[`GeodesicClosestPoint`]**: Find the point on a geometry
+//!   closest to a given point on an ellipsoid, with lines being geodesics.
+//! - **[`PrincipalAxes`]**: Calculate the dominant direction and elongation of a geometry, from its second moments
 //! - **[`IsConvex`]**: Calculate the convexity of a
 //!   [`LineString`]
+//! - **[`Curvature`]**: Calculate the sinuosity of a line string, and the curvature and radius of curvature at each of its vertices
 //! - **[`LineInterpolatePoint`]**:
 //!   Generates a point that lies a given fraction along the line
 //! - **[`LineLocatePoint`]**: Calculate the
 //!   fraction of a line’s total length representing the location of the closest point on the
 //!   line to the given point
+//! - **[`Route`]**: Calibrate a line with measures, such as mileposts, and locate measures,
+//!   extract ranges of measures and project points to measures along it
+//! - **[`IntervalIndex`]**: Index ranges of measures, such as events along a [`Route`] or time
+//!   ranges along trajectories, to find those containing a measure or overlapping a range
+//! - **[`LabelPoint`]**: Find the middle of a line, or its longest straight enough stretch, and its angle there, to place a label
+//! - **[`ZonalStats`]**: Aggregate weighted points into count, sum, mean, min and max for each of a set of polygonal zones
+//! - **[`SpatialPartition`]**: Split a collection of geometries into chunks of nearby geometries of bounded size, by sort-tile-recursive or a Hilbert curve, for parallel or out-of-core processing
+//! - **[`Stats`]**: Summarize the vertices, components, bounding rectangle, length, area and segment lengths of a geometry in one traversal
 //!
 //! ## Similarity
 //!
 //! - **[`FrechetDistance`]**: Calculate the similarity between [`LineString`]s using the Fréchet distance
+//! - **[`FeatureMatcher`]**: Pair up the features of two datasets which are alike by their
+//!   Hausdorff and Fréchet distances, overlap and orientation, for conflation
+//! - **[`CanonicalForm`]**: Compare geometries up to snapping to a grid and the way they're
+//!   written down, and [`deduplicate`] datasets of them
+//! - **[`TurningDistance`]**: Calculate the similarity between the shapes of outlines, independently of position, scale and rotation, with their turning functions
 //!
 //! ## Topology
 //!
 //! - **[`Contains`]**: Calculate if a geometry contains another
 //!   geometry
+//! - **[`ContainsWith`]**: Calculate if a geometry contains another, with its boundary explicitly included or excluded
 //! - **[`CoordinatePosition`]**: Calculate
 //!   the position of a coordinate relative to a geometry
+//! - **[`ContainsPoints`]**: Test whether many coordinates are inside a geometry at once, using vectorizable kernels
+//! - **[`SegmentsInRect`]**: Find the segments of a `LineString` whose bounding rectangles intersect a rectangle
 //! - **[`HasDimensions`]**: Determine the dimensions of a geometry
 //! - **[`Intersects`]**: Calculate if a geometry intersects
 //!   another geometry
+//! - **[`IntersectionIndex`]**: Index a set of geometries once, to test whether other geometries
+//!   intersect any of them, the first of them, or all of them
 //! - **[`line_intersection`]**: Calculates the
 //!   intersection, if any, between two lines.
+//! - **[`SegmentIntersections`](sweep::SegmentIntersections)**: Find all the intersections
+//!   of many lines, or between two sets of lines, by their indices
+//! - **[`LineNetwork`]**: Node line strings into a graph, for connected components and
+//!   shortest paths
 //! - **[`Relate`]**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//!   The boundaries of linear geometries can follow an alternate [`BoundaryNodeRule`].
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry.
+//! - **[`Validation`]**: Check that a geometry is valid, and wrap it in a [`Validated`] which guarantees it
+//! - **[`Geofencer`]**: Track entities moving through many polygons, reporting when they
+//!   enter, exit or dwell in them
+//! - **[`TrapezoidalMap`]**: Locate the polygon containing a point in a polygon coverage
+//! - **[`TriangulatedPolygon`]**: Test whether a polygon contains many points by looking up
+//!   the triangles of its triangulation
 //!
 //! ## Triangulation
 //!
 //! - **[`TriangulateEarcut`](triangulate_earcut)**: Triangulate polygons using the earcut algorithm (requires the `earcutr` feature).
+//! - **[`TriangleBvh`]**: Pick the triangle under a point, cast rays, find the nearest triangle
+//!   and interpolate elevations over a large set of triangles
+//! - **[`RectDecomposition`]**: Decompose a rectilinear polygon, such as a floorplan, into few
+//!   non-overlapping rectangles
 //!
 //! ## Winding
 //!
-//! - **[`Orient`]**: Apply a specified winding [`Direction`](orient::Direction) to a [`Polygon`]’s interior and exterior rings
+//! - **[`Orient`]**: Apply a specified winding [`Direction`](orient::Direction) to the interior and exterior rings of the polygons in a geometry, including nested ones
+//! - **[`RingWindings`]**: Report the winding orders of the rings of every polygon in a geometry, including nested ones
 //! - **[`Winding`]**: Calculate and manipulate the [`WindingOrder`](winding_order::WindingOrder) of a [`LineString`]
 //!
 //! ## Iteration
@@ -123,19 +194,29 @@
 //! - **[`MapCoordsInPlace`]**: Map a function over all the
 //!   coordinates in a geometry in-place
 //! - **[`LinesIter`]**: Iterate over lines of a geometry
+//! - **[`Explode`]**: Iterate over the single-part geometries of multi-part geometries, flattening collections
+//! - **[`NormalizeCollection`]**: Flatten a `GeometryCollection`, drop its empty members, or merge them into multi-part geometries
 //!
 //! ## Boundary
 //!
 //! - **[`BoundingRect`]**: Calculate the axis-aligned
 //!   bounding rectangle of a geometry
+//! - **[`Bounded`]**: A geometry with its bounding rectangle calculated once, and kept up to date through transformations, for fast rejection in predicates and spatial indexes
+//! - **[`GeodesicBoundingRect`]**: Calculate the bounding
+//!   rectangle of a geometry whose edges are geodesics
 //! - **[`MinimumRotatedRect`]**: Calculate the
 //!   minimum bounding box of a geometry
+//! - **[`MinimumBoundingCircle`]**: Calculate the smallest circle enclosing a geometry
 //! - **[`ConcaveHull`]**: Calculate the concave hull of a
 //!   geometry
 //! - **[`ConvexHull`]**: Calculate the convex hull of a
 //!   geometry
+//! - **[`InwardOffsets`]**: Offset polygons inwards at many distances at once, by
+//!   propagating their straight skeleton
 //! - **[`Extremes`]**: Calculate the extreme coordinates and
 //!   indices of a geometry
+//! - **[`ExtremesAlong`]**: Calculate the extreme coordinates and
+//!   indices of a geometry along any direction
 //!
 //! ## Affine transformations
 //!
@@ -143,7 +224,10 @@
 //! - **[`Scale`]**: Scale a geometry up or down by a factor
 //! - **[`Skew`]**: Skew a geometry by shearing angles along the `x` and `y` dimension
 //! - **[`Translate`]**: Translate a geometry along its axis
+//! - **[`FitInto`]**: Scale and translate a geometry so that its bounding rectangle fits into a target rectangle
 //! - **[`AffineOps`]**: generalised composable affine operations
+//! - **[`ProjectiveTransform`]**: Map geometries between planes in perspective, such as from a photograph to a map, using four control points
+//! - **[`TransformPipeline`]**: Apply a sequence of affine transforms, axis swaps, unit conversions, grid snapping and custom functions in a single pass over the coordinates
 //!
 //! ## Conversion
 //!
@@ -151,33 +235,80 @@
 //! - **[`TryConvert`]**: Convert (falliby) the type of a geometry’s coordinate value
 //! - **[`ToDegrees`]**: Radians to degrees coordinate transforms for a given geometry.
 //! - **[`ToRadians`]**: Degrees to radians coordinate transforms for a given geometry.
+//! - **[`Compress`]**: Compress a geometry into a compact byte format, quantized to a grid and delta encoded, with a maximum error for each coordinate
 //!
 //! ## Miscellaneous
 //!
+//! - **[`Antimeridian`]**: Split geometries crossing the antimeridian into multi-part geometries, and merge them back together
+//! - **[`AzimuthalEquidistant`]**: Project small geometries to meters around a local origin for metric operations, and back
 //! - **[`Centroid`]**: Calculate the centroid of a geometry
-//! - **[`ChaikinSmoothing`]**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikin's algorithm.
+//! - **[`WeightedCentroid`]**: Calculate the mean center of a collection whose members carry weights, such as populations
+//! - **[`SphericalCentroid`]**: Calculate the centroid of a geometry on a sphere
+//! - **[`SphericalInteriorPoint`]**: Calculate a point inside a polygon on a sphere
+//! - **[`ChaikinSmoothing`]**, **[`ChaikinSmoothingWithTolerance`]**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikin's algorithm, a number of times or until it converges within a tolerance.
+//! - **[`SplineSmoothing`]**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` with a Catmull-Rom spline through their vertices.
+//! - **[`Fillet`]**: Round the corners of `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` with circular arcs of a given radius.
+//! - **[`Rasterize`]**: Scan-convert a geometry into a grid mask, or a grid of the fraction of each cell it covers.
+//! - **[`Contour`]**: Extract lines of equal value, and bands of values between levels, from a [`Raster`] of values.
+//! - **[`DistanceRaster`]**: Calculate a [`Raster`] of the distance from each cell to the nearest point, line or polygon boundary, as a proximity surface.
 //! - **[`Densify`]**: Densify linear geometry components by interpolating points
+//! - **[`DensifyGeodesic`]**: Densify geometry on an ellipsoid by interpolating points along geodesics
 //! - **[`DensifyHaversine`]**: Densify spherical geometry by interpolating points on a sphere
+//! - **[`GreatCirclePath`]**: Convert lines into polylines following great circles, split at the antimeridian, for drawing on a map
+//! - **[`Ellipsoid`]**: Choose the ellipsoid used by the geodesic algorithms, such as WGS84, GRS80, or a sphere
 //! - **[`GeodesicDestination`]**: Given a start point, bearing, and distance, calculate the destination point on a [geodesic](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid)
 //! - **[`GeodesicIntermediate`]**: Calculate intermediate points on a [geodesic](https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid)
+//! - **[`Grid`]**: Tile the bounding rectangle of a geometry with square or hexagonal cells
 //! - **[`HaversineDestination`]**: Given a start point, bearing, and distance, calculate the destination point on a sphere assuming travel on a great circle
 //! - **[`HaversineIntermediate`]**: Calculate intermediate points on a sphere along a great-circle line
+//! - **[`HaversineIntersection`]**: Calculate the intersection of two great-circle arcs
 //! - **[`RhumbDestination`]**: Given a start point, bearing, and distance, calculate the destination point on a sphere assuming travel along a rhumb line
 //! - **[`RhumbIntermediate`]**: Calculate intermediate points on a sphere along a rhumb line
 //! - **[`proj`]**: Project geometries with the `proj` crate (requires the `use-proj` feature)
 //! - **[`LineStringSegmentize`]**: Segment a LineString into `n` segments.
 //! - **[`LineStringSegmentizeHaversine`]**: Segment a LineString using Haversine distance.
+//! - **[`SplitLine`]**: Split a LineString into pieces at distances along it, or at points within a tolerance of it
+//! - **[`SelfOverlap`]**: Find and remove the parts of a LineString which double back over it, as on the way back of an out-and-back GPS trace
 //! - **[`Transform`]**: Transform a geometry using Proj.
+//! - **[`TransformWith`]**: Transform a geometry with any [`CoordinateTransformer`], such as a closure, a custom projection engine or Proj
+//! - **[`Utm`]**: Convert geometries between longitude/latitude and UTM zones, without Proj
+//! - **[`WebMercator`]**: Convert geometries between longitude/latitude and Web Mercator, without Proj
+//! - **[`TileCoverage`]**, **[`ClipToTile`]**: Find the XYZ web map [`Tile`]s which a Web Mercator geometry intersects, and clip it to them
+//! - **[`PrepareTile`]**: Clip, convert to integer tile coordinates and simplify a Web Mercator geometry for a vector tile
 //! - **[`RemoveRepeatedPoints`]**: Remove repeated points from a geometry.
+//! - **[`SamplePoints`](sample_points)**: Generate uniformly distributed random points inside a polygon (requires the `rand` and `earcutr` features)
+//! - **[`VisibilityPolygon`]**: Calculate the region of a polygon visible from a point inside it
+//! - **[`TerrainSlope`]**: Calculate the slope and aspect of triangles of terrain, and their area-weighted mean over a region
+//! - **[`parallel`]**: Parallel variants of coordinate mapping, area, length, simplification, triangulation, and bulk predicates (requires the `rayon` feature)
+//!
+//! # Errors
+//!
+//! Algorithms which can panic, or silently return nonsense, on bad input, such as NaN
+//! coordinates, have fallible `try_` variants returning a [`Error`], like
+//! [`try_union`](BooleanOps::try_union), [`try_convex_hull`](ConvexHull::try_convex_hull),
+//! [`try_concave_hull`](ConcaveHull::try_concave_hull), [`try_simplify`](Simplify::try_simplify)
+//! and [`try_earcut_triangles`](TriangulateEarcut::try_earcut_triangles). The errors of the other
+//! fallible algorithms convert into it.
 //!
 //! # Features
 //!
 //! The following optional [Cargo features] are available:
 //!
+//! - `arbitrary`: Enables generating valid geometries from unstructured data with [`arbitrary`], for fuzzing and property testing. See [`arbitrary`](crate::arbitrary)
+//! - `bumpalo`: Enables allocating geometries, and the output of boolean operations, in a [`bumpalo`] arena. See [`arena`](crate::arena)
 //! - `proj-network`: Enables [network grid] support for the [`proj` crate]. After enabling this feature, [further configuration][proj crate file download] is required to use the network grid
+//! - `rand`: Enables random sampling of points within geometries using the [`rand` crate]
+//! - `rayon`: Enables parallel variants of algorithms for large collections using the [`rayon` crate]
+//! - `std`: Enables use of the full `std` library, and the algorithms which require it. Enabled by default.
 //! - `use-proj`: Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
 //! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde]
 //!
+//! This library can be used in `#![no_std]` environments, with the `alloc` crate, if the default
+//! `std` feature is disabled. The geodesic algorithms, [`Ellipsoid`], [`AzimuthalEquidistant`] and
+//! [`Utm`] require `std`, as do the `arbitrary`, `earcutr`, `rand`, `rayon`, `use-proj` and
+//! `use-serde` features, which enable it. The `spade` feature doesn't enable `std`, but its
+//! dependencies need a target which has it.
+//!
 //! # Ecosystem
 //!
 //! There’s a wide variety of `geo`-compatible crates in the ecosystem that offer functionality not
@@ -192,6 +323,8 @@
 //!
 //! [`geo-types`]: https://crates.io/crates/geo-types
 //! [`proj` crate]: https://github.com/georust/proj
+//! [`rand` crate]: https://crates.io/crates/rand
+//! [`rayon` crate]: https://crates.io/crates/rayon
 //! [geojson crate]: https://crates.io/crates/geojson
 //! [wkt crate]: https://crates.io/crates/wkt
 //! [shapefile crate]: https://crates.io/crates/shapefile
@@ -205,15 +338,19 @@
 //! [network grid]: https://proj.org/usage/network.html
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
 //! [proj crate file download]: https://docs.rs/proj/*/proj/#grid-file-download
+//! [`arbitrary`]: https://crates.io/crates/arbitrary
+//! [`bumpalo`]: https://crates.io/crates/bumpalo
 //! [Serde]: https://serde.rs/
 
+extern crate alloc;
+
 #[cfg(feature = "use-serde")]
 #[macro_use]
 extern crate serde;
 
 pub use crate::algorithm::*;
 pub use crate::types::Closest;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 pub use geo_types::{coord, line_string, point, polygon, wkt, CoordFloat, CoordNum};
 
@@ -222,9 +359,19 @@ pub use geometry::*;
 
 /// This module includes all the functions of geometric calculations
 pub mod algorithm;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "bumpalo")]
+pub mod arena;
+mod error;
+pub use error::Error;
 mod geometry_cow;
+pub mod soa;
+#[cfg(feature = "std")]
+pub mod stream;
 mod types;
 mod utils;
+pub mod view;
 use crate::kernels::{RobustKernel, SimpleKernel};
 pub(crate) use geometry_cow::GeometryCow;
 
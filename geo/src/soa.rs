@@ -0,0 +1,378 @@
+//! Geometries which store their coordinates as a structure of arrays.
+//!
+//! [`SoaCoords`] holds the `x` and `y` values of a sequence of coordinates in separate `Vec`s,
+//! rather than interleaved in a `Vec<Coord>`. Loops over the values of one axis, or over both
+//! axes in step, can then be vectorized by the compiler, which makes bulk calculations, such as
+//! the lengths, bounds and centroids of [`SoaLineString`]s and [`SoaMultiPoint`]s, faster than
+//! those of their `Vec<Coord>` based counterparts.
+//!
+//! Both implement [`Area`](crate::Area), [`BoundingRect`](crate::BoundingRect),
+//! [`Centroid`](crate::Centroid) and [`CoordsIter`](crate::CoordsIter), and so the algorithms
+//! built on `CoordsIter`, such as [`ConvexHull`](crate::ConvexHull). An `SoaLineString` also
+//! implements [`EuclideanLength`](crate::EuclideanLength).
+//!
+//! Because their results are summed in a different order, floating point results may differ
+//! from those for a `LineString` or `MultiPoint` with the same coordinates by rounding error.
+//!
+//! # Examples
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use geo::soa::SoaLineString;
+//! use geo::{line_string, point, BoundingRect, Centroid, EuclideanLength, Rect};
+//!
+//! let line_string = SoaLineString::from(line_string![
+//!     (x: 0., y: 0.),
+//!     (x: 3., y: 4.),
+//!     (x: 3., y: 10.),
+//! ]);
+//! assert_eq!(line_string.0.xs(), [0., 3., 3.]);
+//! assert_eq!(line_string.euclidean_length(), 11.);
+//! assert_eq!(line_string.bounding_rect(), Some(Rect::new((0., 0.), (3., 10.))));
+//!
+//! // The mean of the midpoints of the segments, weighted by their lengths
+//! let centroid = line_string.centroid().unwrap();
+//! assert_relative_eq!(centroid, point!(x: (1.5 * 5. + 3. * 6.) / 11., y: (2. * 5. + 7. * 6.) / 11.));
+//! ```
+
+use crate::{Coord, CoordFloat, CoordNum, LineString, MultiPoint, Point, Rect};
+use alloc::vec::Vec;
+
+/// The number of values processed together by the vectorizable loops.
+const LANES: usize = 8;
+
+/// A sequence of coordinates, stored as separate arrays of their `x` and `y` values.
+#[derive(Eq, PartialEq, Clone, Debug, Hash, Default)]
+pub struct SoaCoords<T: CoordNum = f64> {
+    xs: Vec<T>,
+    ys: Vec<T>,
+}
+
+impl<T: CoordNum> SoaCoords<T> {
+    /// Create coordinates from their `x` and `y` values.
+    ///
+    /// # Panics
+    ///
+    /// If `xs` and `ys` have different lengths.
+    pub fn new(xs: Vec<T>, ys: Vec<T>) -> Self {
+        assert_eq!(xs.len(), ys.len(), "xs and ys must have the same length");
+        SoaCoords { xs, ys }
+    }
+
+    /// The `x` values of the coordinates.
+    pub fn xs(&self) -> &[T] {
+        &self.xs
+    }
+
+    /// The `y` values of the coordinates.
+    pub fn ys(&self) -> &[T] {
+        &self.ys
+    }
+
+    /// The number of coordinates.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Whether there are no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// The coordinate at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<Coord<T>> {
+        Some(Coord {
+            x: *self.xs.get(index)?,
+            y: self.ys[index],
+        })
+    }
+
+    /// Append a coordinate.
+    pub fn push(&mut self, coord: Coord<T>) {
+        self.xs.push(coord.x);
+        self.ys.push(coord.y);
+    }
+
+    /// Iterate over the coordinates.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Coord<T>> + DoubleEndedIterator + '_ {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(x, y)| Coord { x: *x, y: *y })
+    }
+
+    /// Take the `x` and `y` values of the coordinates.
+    pub fn into_inner(self) -> (Vec<T>, Vec<T>) {
+        (self.xs, self.ys)
+    }
+
+    /// The bounding rectangle of the coordinates, or `None` if there are none.
+    pub(crate) fn bounding_rect(&self) -> Option<Rect<T>> {
+        let (x_min, x_max) = min_max(&self.xs)?;
+        let (y_min, y_max) = min_max(&self.ys)?;
+        Some(Rect::new(
+            Coord { x: x_min, y: y_min },
+            Coord { x: x_max, y: y_max },
+        ))
+    }
+
+    /// The sums of the `x` and `y` values.
+    pub(crate) fn sums(&self) -> Coord<T> {
+        Coord {
+            x: sum(&self.xs),
+            y: sum(&self.ys),
+        }
+    }
+
+    /// The sums of the values of `f(start, end)` over the segments between consecutive
+    /// coordinates.
+    pub(crate) fn segment_sums<const N: usize>(
+        &self,
+        f: impl Fn(Coord<T>, Coord<T>) -> [T; N],
+    ) -> [T; N] {
+        let mut totals = [T::zero(); N];
+        if self.len() < 2 {
+            return totals;
+        }
+        let segments = self.len() - 1;
+        let (x0, x1) = (&self.xs[..segments], &self.xs[1..]);
+        let (y0, y1) = (&self.ys[..segments], &self.ys[1..]);
+        let segment =
+            |x0: T, y0: T, x1: T, y1: T| f(Coord { x: x0, y: y0 }, Coord { x: x1, y: y1 });
+
+        let mut sums = [[T::zero(); N]; LANES];
+        let chunks = (x0.chunks_exact(LANES).zip(y0.chunks_exact(LANES)))
+            .zip(x1.chunks_exact(LANES).zip(y1.chunks_exact(LANES)));
+        for ((x0, y0), (x1, y1)) in chunks {
+            for (lane, sums) in sums.iter_mut().enumerate() {
+                add(sums, segment(x0[lane], y0[lane], x1[lane], y1[lane]));
+            }
+        }
+        for i in segments - segments % LANES..segments {
+            add(&mut totals, segment(x0[i], y0[i], x1[i], y1[i]));
+        }
+        for sums in sums {
+            add(&mut totals, sums);
+        }
+        totals
+    }
+}
+
+impl<T: CoordNum> FromIterator<Coord<T>> for SoaCoords<T> {
+    fn from_iter<I: IntoIterator<Item = Coord<T>>>(iter: I) -> Self {
+        let (xs, ys) = iter.into_iter().map(|coord| (coord.x, coord.y)).unzip();
+        SoaCoords { xs, ys }
+    }
+}
+
+impl<T: CoordNum> From<&[Coord<T>]> for SoaCoords<T> {
+    fn from(coords: &[Coord<T>]) -> Self {
+        coords.iter().copied().collect()
+    }
+}
+
+/// A [`LineString`] whose coordinates are stored as [`SoaCoords`].
+#[derive(Eq, PartialEq, Clone, Debug, Hash, Default)]
+pub struct SoaLineString<T: CoordNum = f64>(pub SoaCoords<T>);
+
+impl<T: CoordNum> SoaLineString<T> {
+    /// Create an `SoaLineString` with the given coordinates.
+    pub fn new(coords: SoaCoords<T>) -> Self {
+        SoaLineString(coords)
+    }
+
+    /// Whether the first and last coordinates are equal, as for [`LineString::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.0.get(0) == self.0.get(self.0.len().wrapping_sub(1))
+    }
+
+    /// Copy the coordinates into a `LineString`.
+    pub fn to_line_string(&self) -> LineString<T> {
+        self.0.iter().collect()
+    }
+}
+
+impl<T: CoordNum> From<LineString<T>> for SoaLineString<T> {
+    fn from(line_string: LineString<T>) -> Self {
+        SoaLineString(SoaCoords::from(&line_string.0[..]))
+    }
+}
+
+impl<T: CoordNum> From<&LineString<T>> for SoaLineString<T> {
+    fn from(line_string: &LineString<T>) -> Self {
+        SoaLineString(SoaCoords::from(&line_string.0[..]))
+    }
+}
+
+impl<T: CoordNum> FromIterator<Coord<T>> for SoaLineString<T> {
+    fn from_iter<I: IntoIterator<Item = Coord<T>>>(iter: I) -> Self {
+        SoaLineString(iter.into_iter().collect())
+    }
+}
+
+/// A [`MultiPoint`] whose coordinates are stored as [`SoaCoords`].
+#[derive(Eq, PartialEq, Clone, Debug, Hash, Default)]
+pub struct SoaMultiPoint<T: CoordNum = f64>(pub SoaCoords<T>);
+
+impl<T: CoordNum> SoaMultiPoint<T> {
+    /// Create an `SoaMultiPoint` with the given coordinates.
+    pub fn new(coords: SoaCoords<T>) -> Self {
+        SoaMultiPoint(coords)
+    }
+
+    /// Iterate over the points.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = Point<T>> + DoubleEndedIterator + '_ {
+        self.0.iter().map(Point::from)
+    }
+
+    /// Copy the coordinates into a `MultiPoint`.
+    pub fn to_multi_point(&self) -> MultiPoint<T> {
+        self.points().collect()
+    }
+}
+
+impl<T: CoordNum> From<&MultiPoint<T>> for SoaMultiPoint<T> {
+    fn from(multi_point: &MultiPoint<T>) -> Self {
+        multi_point.iter().map(|point| point.0).collect()
+    }
+}
+
+impl<T: CoordNum> From<MultiPoint<T>> for SoaMultiPoint<T> {
+    fn from(multi_point: MultiPoint<T>) -> Self {
+        SoaMultiPoint::from(&multi_point)
+    }
+}
+
+impl<T: CoordNum> FromIterator<Coord<T>> for SoaMultiPoint<T> {
+    fn from_iter<I: IntoIterator<Item = Coord<T>>>(iter: I) -> Self {
+        SoaMultiPoint(iter.into_iter().collect())
+    }
+}
+
+/// The length of the segment from `start` to `end`.
+pub(crate) fn segment_length<T: CoordFloat>(start: Coord<T>, end: Coord<T>) -> T {
+    let delta = end - start;
+    (delta.x * delta.x + delta.y * delta.y).sqrt()
+}
+
+fn add<T: CoordNum, const N: usize>(sums: &mut [T; N], values: [T; N]) {
+    for (sum, value) in sums.iter_mut().zip(values) {
+        *sum = *sum + value;
+    }
+}
+
+fn sum<T: CoordNum>(values: &[T]) -> T {
+    let mut sums = [T::zero(); LANES];
+    let mut chunks = values.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for (sum, value) in sums.iter_mut().zip(chunk) {
+            *sum = *sum + *value;
+        }
+    }
+    let remainder = chunks
+        .remainder()
+        .iter()
+        .fold(T::zero(), |sum, value| sum + *value);
+    sums.into_iter().fold(remainder, |total, sum| total + sum)
+}
+
+fn min_max<T: CoordNum>(values: &[T]) -> Option<(T, T)> {
+    let first = *values.first()?;
+    let (mut mins, mut maxs) = ([first; LANES], [first; LANES]);
+    let mut chunks = values.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for lane in 0..LANES {
+            mins[lane] = if chunk[lane] < mins[lane] {
+                chunk[lane]
+            } else {
+                mins[lane]
+            };
+            maxs[lane] = if chunk[lane] > maxs[lane] {
+                chunk[lane]
+            } else {
+                maxs[lane]
+            };
+        }
+    }
+    let lanes = mins.into_iter().zip(maxs);
+    let values = chunks.remainder().iter().map(|value| (*value, *value));
+    Some(
+        lanes
+            .chain(values)
+            .fold((first, first), |(min, max), (lane_min, lane_max)| {
+                (
+                    if lane_min < min { lane_min } else { min },
+                    if lane_max > max { lane_max } else { max },
+                )
+            }),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, Area, BoundingRect, Centroid, ConvexHull, CoordsIter, EuclideanLength};
+
+    #[test]
+    fn line_string_matches_line_string() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let soa = SoaLineString::from(&line_string);
+        assert_eq!(soa.0.len(), line_string.0.len());
+        assert_relative_eq!(
+            soa.euclidean_length(),
+            line_string.euclidean_length(),
+            epsilon = 1e-12
+        );
+        assert_eq!(soa.bounding_rect(), line_string.bounding_rect());
+        assert_relative_eq!(
+            soa.centroid().unwrap(),
+            line_string.centroid().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_eq!(soa.convex_hull(), line_string.convex_hull());
+        assert_eq!(soa.unsigned_area(), 0.);
+        assert!(soa.is_closed());
+        assert_eq!(soa.to_line_string(), line_string);
+        assert_eq!(soa.coords_iter().nth(3), Some(line_string.0[3]));
+    }
+
+    #[test]
+    fn degenerate_line_strings() {
+        let empty = SoaLineString::<f64>::default();
+        assert_eq!(empty.euclidean_length(), 0.);
+        assert_eq!(empty.bounding_rect(), None);
+        assert_eq!(empty.centroid(), None);
+
+        let point = coord! { x: 1., y: 2. };
+        for n in 1..3 {
+            let repeated: SoaLineString = core::iter::repeat(point).take(n).collect();
+            assert_eq!(repeated.centroid(), Some(point.into()));
+            assert_eq!(repeated.centroid(), repeated.to_line_string().centroid());
+        }
+    }
+
+    #[test]
+    fn multi_point_matches_multi_point() {
+        let multi_point: MultiPoint = (0..100)
+            .map(|i| coord! { x: (i * 7 % 13) as f64, y: (i * 3 % 11) as f64 - 5. })
+            .map(Point::from)
+            .collect();
+        let soa = SoaMultiPoint::from(&multi_point);
+        assert_eq!(soa.bounding_rect(), multi_point.bounding_rect());
+        assert_relative_eq!(
+            soa.centroid().unwrap(),
+            multi_point.centroid().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_eq!(soa.convex_hull(), multi_point.convex_hull());
+        assert_eq!(soa.to_multi_point(), multi_point);
+
+        assert_eq!(SoaMultiPoint::<f64>::default().centroid(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths() {
+        SoaCoords::new(vec![1., 2.], vec![1.]);
+    }
+}
@@ -0,0 +1,304 @@
+//! Streaming parsers for WKT and WKB, which report geometries to a visitor.
+//!
+//! [`WktReader`] and [`WkbReader`] read a sequence of geometries from an [`io::Read`]er, calling
+//! the methods of a [`GeometryVisitor`] as they parse each point, each coordinate of a line
+//! string or ring, and the beginning and end of each geometry and of its parts. No geometry is
+//! materialized unless the visitor builds it, so dumps much larger than memory can be filtered,
+//! reprojected or counted in a single pass. [`GeometryBuilder`] is a visitor which builds
+//! `Geometry`s.
+//!
+//! The parsers read 2D coordinates. Z and M ordinates, in both ISO and extended (PostGIS) WKB and
+//! WKT, are read and discarded, as is the SRID of extended WKB.
+//!
+//! # Examples
+//!
+//! Count the vertices of each geometry of a dump, without building them.
+//!
+//! ```
+//! use geo::stream::{GeometryType, GeometryVisitor, WktReader};
+//! use geo::Coord;
+//!
+//! #[derive(Default)]
+//! struct CountVertices {
+//!     depth: usize,
+//!     count: usize,
+//!     counts: Vec<usize>,
+//! }
+//!
+//! impl GeometryVisitor for CountVertices {
+//!     fn on_geometry_begin(&mut self, _: GeometryType) {
+//!         self.depth += 1;
+//!     }
+//!     fn on_geometry_end(&mut self, _: GeometryType) {
+//!         self.depth -= 1;
+//!         if self.depth == 0 {
+//!             self.counts.push(std::mem::take(&mut self.count));
+//!         }
+//!     }
+//!     fn on_point(&mut self, _: Coord) {
+//!         self.count += 1;
+//!     }
+//!     fn on_line_string_coord(&mut self, _: Coord) {
+//!         self.count += 1;
+//!     }
+//!     fn on_ring_coord(&mut self, _: Coord) {
+//!         self.count += 1;
+//!     }
+//! }
+//!
+//! let dump = "
+//!     POINT (1 2)
+//!     LINESTRING (0 0, 1 1, 2 0)
+//!     POLYGON ((0 0, 1 0, 1 1, 0 0), (0.2 0.1, 0.8 0.1, 0.8 0.7, 0.2 0.1))
+//! ";
+//! let mut reader = WktReader::new(dump.as_bytes());
+//! let mut visitor = CountVertices::default();
+//! while reader.read_next(&mut visitor)? {}
+//! assert_eq!(visitor.counts, vec![1, 3, 8]);
+//! # Ok::<(), geo::stream::ParseError>(())
+//! ```
+//!
+//! Parse WKB into a `Geometry`.
+//!
+//! ```
+//! use geo::stream::{GeometryBuilder, WkbReader};
+//! use geo::{point, Geometry};
+//!
+//! let wkb: &[u8] = &[
+//!     1, 1, 0, 0, 0, // little endian point
+//!     0, 0, 0, 0, 0, 0, 240, 63, // 1.
+//!     0, 0, 0, 0, 0, 0, 0, 64, // 2.
+//! ];
+//! let mut builder = GeometryBuilder::new();
+//! assert!(WkbReader::new(wkb).read_next(&mut builder)?);
+//! assert_eq!(builder.take(), Some(Geometry::Point(point! { x: 1., y: 2. })));
+//! # Ok::<(), geo::stream::ParseError>(())
+//! ```
+
+use std::{error, fmt, io};
+
+use crate::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+mod wkb;
+mod wkt;
+pub use wkb::WkbReader;
+pub use wkt::WktReader;
+
+/// The type of a geometry, as reported to [`GeometryVisitor::on_geometry_begin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeometryType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+/// Receives the parts of the geometries read by a [`WktReader`] or [`WkbReader`].
+///
+/// Every method does nothing by default, so a visitor only needs to implement the methods it's
+/// interested in. For each geometry, the methods are called in this order:
+///
+/// - `Point` and `MultiPoint`: `on_geometry_begin`, `on_point` for each point (none if the
+///   geometry is empty), then `on_geometry_end`.
+/// - `LineString` and `MultiLineString`: `on_geometry_begin`, then for each line string
+///   `on_line_string_begin`, `on_line_string_coord` for each coordinate and
+///   `on_line_string_end`, then `on_geometry_end`.
+/// - `Polygon` and `MultiPolygon`: `on_geometry_begin`, then for each polygon
+///   `on_polygon_begin`, for each ring, exterior first, `on_ring_begin`, `on_ring_coord` for
+///   each coordinate and `on_ring_end`, then `on_polygon_end`, then `on_geometry_end`.
+/// - `GeometryCollection`: `on_geometry_begin`, the methods for each of its geometries, then
+///   `on_geometry_end`.
+#[allow(unused_variables)]
+pub trait GeometryVisitor {
+    /// Called before the parts of a geometry.
+    fn on_geometry_begin(&mut self, ty: GeometryType) {}
+    /// Called after the parts of a geometry.
+    fn on_geometry_end(&mut self, ty: GeometryType) {}
+    /// Called for each point of a `Point` or `MultiPoint`.
+    fn on_point(&mut self, coord: Coord) {}
+    /// Called before the coordinates of a line string.
+    fn on_line_string_begin(&mut self) {}
+    /// Called for each coordinate of a line string.
+    fn on_line_string_coord(&mut self, coord: Coord) {}
+    /// Called after the coordinates of a line string.
+    fn on_line_string_end(&mut self) {}
+    /// Called before the rings of a polygon.
+    fn on_polygon_begin(&mut self) {}
+    /// Called before the coordinates of a ring of a polygon, with the index of the ring. The
+    /// exterior ring has index 0.
+    fn on_ring_begin(&mut self, index: usize) {}
+    /// Called for each coordinate of a ring of a polygon.
+    fn on_ring_coord(&mut self, coord: Coord) {}
+    /// Called after the coordinates of a ring of a polygon.
+    fn on_ring_end(&mut self) {}
+    /// Called after the rings of a polygon.
+    fn on_polygon_end(&mut self) {}
+}
+
+/// A [`GeometryVisitor`] which builds each geometry it visits.
+///
+/// A `POINT EMPTY` is skipped, since a [`Point`] can't be empty.
+#[derive(Debug, Default)]
+pub struct GeometryBuilder {
+    stack: Vec<PartialGeometry>,
+    coords: Vec<Coord>,
+    rings: Vec<LineString>,
+    geometry: Option<Geometry>,
+}
+
+#[derive(Debug)]
+enum PartialGeometry {
+    Point(Option<Coord>),
+    LineString(LineString),
+    Polygon(Polygon),
+    MultiPoint(Vec<Point>),
+    MultiLineString(Vec<LineString>),
+    MultiPolygon(Vec<Polygon>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl GeometryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the last geometry which was built, if any.
+    pub fn take(&mut self) -> Option<Geometry> {
+        self.geometry.take()
+    }
+}
+
+impl GeometryVisitor for GeometryBuilder {
+    fn on_geometry_begin(&mut self, ty: GeometryType) {
+        self.stack.push(match ty {
+            GeometryType::Point => PartialGeometry::Point(None),
+            GeometryType::LineString => PartialGeometry::LineString(LineString::new(vec![])),
+            GeometryType::Polygon => {
+                PartialGeometry::Polygon(Polygon::new(LineString::new(vec![]), vec![]))
+            }
+            GeometryType::MultiPoint => PartialGeometry::MultiPoint(vec![]),
+            GeometryType::MultiLineString => PartialGeometry::MultiLineString(vec![]),
+            GeometryType::MultiPolygon => PartialGeometry::MultiPolygon(vec![]),
+            GeometryType::GeometryCollection => PartialGeometry::GeometryCollection(vec![]),
+        });
+    }
+
+    fn on_geometry_end(&mut self, _ty: GeometryType) {
+        let geometry = match self.stack.pop() {
+            Some(PartialGeometry::Point(coord)) => coord.map(|c| Geometry::Point(c.into())),
+            Some(PartialGeometry::LineString(ls)) => Some(Geometry::LineString(ls)),
+            Some(PartialGeometry::Polygon(polygon)) => Some(Geometry::Polygon(polygon)),
+            Some(PartialGeometry::MultiPoint(points)) => {
+                Some(Geometry::MultiPoint(MultiPoint::new(points)))
+            }
+            Some(PartialGeometry::MultiLineString(line_strings)) => Some(
+                Geometry::MultiLineString(MultiLineString::new(line_strings)),
+            ),
+            Some(PartialGeometry::MultiPolygon(polygons)) => {
+                Some(Geometry::MultiPolygon(MultiPolygon::new(polygons)))
+            }
+            Some(PartialGeometry::GeometryCollection(geometries)) => Some(
+                Geometry::GeometryCollection(GeometryCollection::new_from(geometries)),
+            ),
+            None => None,
+        };
+        match self.stack.last_mut() {
+            Some(PartialGeometry::GeometryCollection(geometries)) => {
+                geometries.extend(geometry);
+            }
+            _ => self.geometry = geometry,
+        }
+    }
+
+    fn on_point(&mut self, coord: Coord) {
+        match self.stack.last_mut() {
+            Some(PartialGeometry::Point(point)) => *point = Some(coord),
+            Some(PartialGeometry::MultiPoint(points)) => points.push(coord.into()),
+            _ => {}
+        }
+    }
+
+    fn on_line_string_begin(&mut self) {
+        self.coords.clear();
+    }
+
+    fn on_line_string_coord(&mut self, coord: Coord) {
+        self.coords.push(coord);
+    }
+
+    fn on_line_string_end(&mut self) {
+        let line_string = LineString::new(std::mem::take(&mut self.coords));
+        match self.stack.last_mut() {
+            Some(PartialGeometry::LineString(ls)) => *ls = line_string,
+            Some(PartialGeometry::MultiLineString(line_strings)) => line_strings.push(line_string),
+            _ => {}
+        }
+    }
+
+    fn on_polygon_begin(&mut self) {
+        self.rings.clear();
+    }
+
+    fn on_ring_begin(&mut self, _index: usize) {
+        self.coords.clear();
+    }
+
+    fn on_ring_coord(&mut self, coord: Coord) {
+        self.coords.push(coord);
+    }
+
+    fn on_ring_end(&mut self) {
+        self.rings
+            .push(LineString::new(std::mem::take(&mut self.coords)));
+    }
+
+    fn on_polygon_end(&mut self) {
+        let mut rings = std::mem::take(&mut self.rings).into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        let polygon = Polygon::new(exterior, rings.collect());
+        match self.stack.last_mut() {
+            Some(PartialGeometry::Polygon(p)) => *p = polygon,
+            Some(PartialGeometry::MultiPolygon(polygons)) => polygons.push(polygon),
+            _ => {}
+        }
+    }
+}
+
+/// An error reading a geometry with a [`WktReader`] or [`WkbReader`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// The underlying reader failed, or ended in the middle of a geometry.
+    Io(io::Error),
+    /// The input isn't valid WKT or WKB.
+    InvalidInput(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io(err) => write!(f, "failed to read geometry: {err}"),
+            ParseError::InvalidInput(message) => write!(f, "invalid input: {message}"),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseError::Io(err) => Some(err),
+            ParseError::InvalidInput(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        ParseError::Io(err)
+    }
+}
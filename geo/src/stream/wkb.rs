@@ -0,0 +1,389 @@
+use std::io::{self, BufReader, Read};
+
+use super::{GeometryType, GeometryVisitor, ParseError};
+use crate::Coord;
+
+/// Reads a sequence of concatenated WKB geometries with a [`GeometryVisitor`].
+///
+/// ISO WKB and extended (PostGIS) WKB are both supported. The reader is buffered internally.
+pub struct WkbReader<R: Read> {
+    reader: BufReader<R>,
+}
+
+/// The byte order, and dimensions of the coordinates, of a WKB geometry.
+#[derive(Clone, Copy)]
+struct Header {
+    ty: GeometryType,
+    little_endian: bool,
+    dimensions: usize,
+}
+
+impl<R: Read> WkbReader<R> {
+    pub fn new(reader: R) -> Self {
+        WkbReader {
+            reader: BufReader::new(reader),
+        }
+    }
+
+    /// Read the next geometry, calling the methods of `visitor` for its parts.
+    ///
+    /// Returns `false`, without calling `visitor`, if there are no more geometries.
+    pub fn read_next<V: GeometryVisitor>(&mut self, visitor: &mut V) -> Result<bool, ParseError> {
+        let mut byte_order = [0];
+        if self.reader.read(&mut byte_order)? == 0 {
+            return Ok(false);
+        }
+        let header = self.read_header(byte_order[0])?;
+        self.read_geometry(header, visitor)?;
+        Ok(true)
+    }
+
+    fn read_header(&mut self, byte_order: u8) -> Result<Header, ParseError> {
+        let little_endian = match byte_order {
+            0 => false,
+            1 => true,
+            _ => {
+                return Err(ParseError::InvalidInput(format!(
+                    "invalid byte order {byte_order}"
+                )))
+            }
+        };
+        let code = self.read_u32(little_endian)?;
+        let (base, has_z, has_m) = if code & 0xE000_0000 != 0 {
+            // Extended WKB, with flags for Z, M and an SRID
+            if code & 0x2000_0000 != 0 {
+                self.read_u32(little_endian)?;
+            }
+            (
+                code & 0xFFFF,
+                code & 0x8000_0000 != 0,
+                code & 0x4000_0000 != 0,
+            )
+        } else {
+            // ISO WKB, with the dimensions in the thousands
+            (
+                code % 1000,
+                matches!(code / 1000, 1 | 3),
+                matches!(code / 1000, 2 | 3),
+            )
+        };
+        let ty = match base {
+            1 => GeometryType::Point,
+            2 => GeometryType::LineString,
+            3 => GeometryType::Polygon,
+            4 => GeometryType::MultiPoint,
+            5 => GeometryType::MultiLineString,
+            6 => GeometryType::MultiPolygon,
+            7 => GeometryType::GeometryCollection,
+            _ => {
+                return Err(ParseError::InvalidInput(format!(
+                    "unsupported geometry type {code}"
+                )))
+            }
+        };
+        Ok(Header {
+            ty,
+            little_endian,
+            dimensions: 2 + has_z as usize + has_m as usize,
+        })
+    }
+
+    fn read_geometry<V: GeometryVisitor>(
+        &mut self,
+        header: Header,
+        visitor: &mut V,
+    ) -> Result<(), ParseError> {
+        visitor.on_geometry_begin(header.ty);
+        match header.ty {
+            GeometryType::Point => {
+                let coord = self.read_coord(header)?;
+                // An empty point is encoded with NaN coordinates
+                if !(coord.x.is_nan() && coord.y.is_nan()) {
+                    visitor.on_point(coord);
+                }
+            }
+            GeometryType::LineString => self.read_line_string(header, visitor)?,
+            GeometryType::Polygon => self.read_polygon(header, visitor)?,
+            GeometryType::MultiPoint => {
+                self.read_parts(header, GeometryType::Point, |reader, part| {
+                    let coord = reader.read_coord(part)?;
+                    if !(coord.x.is_nan() && coord.y.is_nan()) {
+                        visitor.on_point(coord);
+                    }
+                    Ok(())
+                })?
+            }
+            GeometryType::MultiLineString => {
+                self.read_parts(header, GeometryType::LineString, |reader, part| {
+                    reader.read_line_string(part, visitor)
+                })?
+            }
+            GeometryType::MultiPolygon => {
+                self.read_parts(header, GeometryType::Polygon, |reader, part| {
+                    reader.read_polygon(part, visitor)
+                })?
+            }
+            GeometryType::GeometryCollection => {
+                for _ in 0..self.read_u32(header.little_endian)? {
+                    let mut byte_order = [0];
+                    self.reader.read_exact(&mut byte_order)?;
+                    let part = self.read_header(byte_order[0])?;
+                    self.read_geometry(part, visitor)?;
+                }
+            }
+        }
+        visitor.on_geometry_end(header.ty);
+        Ok(())
+    }
+
+    /// Read the parts of a multi-geometry, each with its own header, which must be of type `ty`.
+    fn read_parts(
+        &mut self,
+        header: Header,
+        ty: GeometryType,
+        mut read_part: impl FnMut(&mut Self, Header) -> Result<(), ParseError>,
+    ) -> Result<(), ParseError> {
+        for _ in 0..self.read_u32(header.little_endian)? {
+            let mut byte_order = [0];
+            self.reader.read_exact(&mut byte_order)?;
+            let part = self.read_header(byte_order[0])?;
+            if part.ty != ty {
+                return Err(ParseError::InvalidInput(format!(
+                    "expected {ty:?} in {:?}, found {:?}",
+                    header.ty, part.ty
+                )));
+            }
+            read_part(self, part)?;
+        }
+        Ok(())
+    }
+
+    fn read_line_string<V: GeometryVisitor>(
+        &mut self,
+        header: Header,
+        visitor: &mut V,
+    ) -> Result<(), ParseError> {
+        visitor.on_line_string_begin();
+        for _ in 0..self.read_u32(header.little_endian)? {
+            visitor.on_line_string_coord(self.read_coord(header)?);
+        }
+        visitor.on_line_string_end();
+        Ok(())
+    }
+
+    fn read_polygon<V: GeometryVisitor>(
+        &mut self,
+        header: Header,
+        visitor: &mut V,
+    ) -> Result<(), ParseError> {
+        visitor.on_polygon_begin();
+        for index in 0..self.read_u32(header.little_endian)? as usize {
+            visitor.on_ring_begin(index);
+            for _ in 0..self.read_u32(header.little_endian)? {
+                visitor.on_ring_coord(self.read_coord(header)?);
+            }
+            visitor.on_ring_end();
+        }
+        visitor.on_polygon_end();
+        Ok(())
+    }
+
+    /// Read a coordinate, discarding any Z and M ordinates.
+    fn read_coord(&mut self, header: Header) -> io::Result<Coord> {
+        let x = self.read_f64(header.little_endian)?;
+        let y = self.read_f64(header.little_endian)?;
+        for _ in 2..header.dimensions {
+            self.read_f64(header.little_endian)?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> io::Result<u32> {
+        let mut bytes = [0; 4];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> io::Result<f64> {
+        let mut bytes = [0; 8];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stream::{GeometryBuilder, WktReader};
+    use crate::Geometry;
+
+    /// Write `geometry` as WKB with the given byte order, or as extended WKB with Z ordinates and
+    /// an SRID if `extended`.
+    fn write_wkb(geometry: &Geometry, little_endian: bool, extended: bool, out: &mut Vec<u8>) {
+        let u32_bytes = |n: u32| {
+            if little_endian {
+                n.to_le_bytes()
+            } else {
+                n.to_be_bytes()
+            }
+        };
+        let write_header = |code: u32, out: &mut Vec<u8>| {
+            out.push(little_endian as u8);
+            if extended {
+                out.extend(u32_bytes(code | 0x8000_0000 | 0x2000_0000));
+                out.extend(u32_bytes(4326));
+            } else {
+                out.extend(u32_bytes(code));
+            }
+        };
+        let write_coords = |coords: &[Coord], out: &mut Vec<u8>| {
+            out.extend(u32_bytes(coords.len() as u32));
+            for coord in coords {
+                for ordinate in [coord.x, coord.y, 7.].iter().take(2 + extended as usize) {
+                    if little_endian {
+                        out.extend(ordinate.to_le_bytes());
+                    } else {
+                        out.extend(ordinate.to_be_bytes());
+                    }
+                }
+            }
+        };
+        let write_point = |coord: Coord, out: &mut Vec<u8>| {
+            write_header(1, out);
+            let mut coords = vec![];
+            write_coords(&[coord], &mut coords);
+            // A point has no count
+            out.extend(&coords[4..]);
+        };
+        let write_polygon = |polygon: &crate::Polygon, out: &mut Vec<u8>| {
+            write_header(3, out);
+            out.extend(u32_bytes(1 + polygon.interiors().len() as u32));
+            write_coords(&polygon.exterior().0, out);
+            for interior in polygon.interiors() {
+                write_coords(&interior.0, out);
+            }
+        };
+        match geometry {
+            Geometry::Point(point) => write_point(point.0, out),
+            Geometry::LineString(ls) => {
+                write_header(2, out);
+                write_coords(&ls.0, out);
+            }
+            Geometry::Polygon(polygon) => write_polygon(polygon, out),
+            Geometry::MultiPoint(points) => {
+                write_header(4, out);
+                out.extend(u32_bytes(points.0.len() as u32));
+                points.iter().for_each(|point| write_point(point.0, out));
+            }
+            Geometry::MultiLineString(line_strings) => {
+                write_header(5, out);
+                out.extend(u32_bytes(line_strings.0.len() as u32));
+                for ls in line_strings {
+                    write_header(2, out);
+                    write_coords(&ls.0, out);
+                }
+            }
+            Geometry::MultiPolygon(polygons) => {
+                write_header(6, out);
+                out.extend(u32_bytes(polygons.0.len() as u32));
+                polygons
+                    .iter()
+                    .for_each(|polygon| write_polygon(polygon, out));
+            }
+            Geometry::GeometryCollection(geometries) => {
+                write_header(7, out);
+                out.extend(u32_bytes(geometries.0.len() as u32));
+                for geometry in geometries {
+                    write_wkb(geometry, little_endian, extended, out);
+                }
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let wkt = "
+            POINT (1 2)
+            LINESTRING (0 0, 1 1, 2 0.5)
+            POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))
+            MULTIPOINT ((0 0), (1 -1e3))
+            MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 3))
+            MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))
+            GEOMETRYCOLLECTION (POINT (1 2), GEOMETRYCOLLECTION (LINESTRING (0 0, 1 1)))
+            MULTIPOLYGON EMPTY
+        ";
+        let mut builder = GeometryBuilder::new();
+        let mut reader = WktReader::new(wkt.as_bytes());
+        let mut geometries = vec![];
+        while reader.read_next(&mut builder).unwrap() {
+            geometries.extend(builder.take());
+        }
+
+        for (little_endian, extended) in [(true, false), (false, false), (true, true)] {
+            let mut wkb = vec![];
+            for geometry in &geometries {
+                write_wkb(geometry, little_endian, extended, &mut wkb);
+            }
+            let mut reader = WkbReader::new(&wkb[..]);
+            let mut read = vec![];
+            while reader.read_next(&mut builder).unwrap() {
+                read.extend(builder.take());
+            }
+            assert_eq!(read, geometries);
+        }
+    }
+
+    #[test]
+    fn iso_dimensions_and_empty_point() {
+        let mut wkb = vec![1];
+        wkb.extend(3001u32.to_le_bytes()); // POINT ZM
+        for ordinate in [1f64, 2., 3., 4.] {
+            wkb.extend(ordinate.to_le_bytes());
+        }
+        wkb.push(0);
+        wkb.extend(1u32.to_be_bytes());
+        wkb.extend(f64::NAN.to_be_bytes());
+        wkb.extend(f64::NAN.to_be_bytes());
+
+        let mut builder = GeometryBuilder::new();
+        let mut reader = WkbReader::new(&wkb[..]);
+        assert!(reader.read_next(&mut builder).unwrap());
+        assert_eq!(builder.take(), Some(Geometry::Point((1., 2.).into())));
+        assert!(reader.read_next(&mut builder).unwrap());
+        assert_eq!(builder.take(), None);
+        assert!(!reader.read_next(&mut builder).unwrap());
+    }
+
+    #[test]
+    fn errors() {
+        let mut builder = GeometryBuilder::new();
+        let truncated = [1, 2, 0, 0, 0, 3, 0, 0, 0];
+        assert!(matches!(
+            WkbReader::new(&truncated[..]).read_next(&mut builder),
+            Err(ParseError::Io(_))
+        ));
+        let invalid_type = [1, 17, 0, 0, 0];
+        assert!(matches!(
+            WkbReader::new(&invalid_type[..]).read_next(&mut builder),
+            Err(ParseError::InvalidInput(_))
+        ));
+        let mut wrong_part = vec![1];
+        wrong_part.extend(4u32.to_le_bytes());
+        wrong_part.extend(1u32.to_le_bytes());
+        wrong_part.push(1);
+        wrong_part.extend(2u32.to_le_bytes());
+        assert!(matches!(
+            WkbReader::new(&wrong_part[..]).read_next(&mut builder),
+            Err(ParseError::InvalidInput(_))
+        ));
+    }
+}
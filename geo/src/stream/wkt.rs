@@ -0,0 +1,348 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use super::{GeometryType, GeometryVisitor, ParseError};
+use crate::Coord;
+
+/// Reads a sequence of WKT geometries, separated by whitespace, with a [`GeometryVisitor`].
+///
+/// The reader is buffered internally, and only the text of a single token is held in memory at
+/// once.
+pub struct WktReader<R: Read> {
+    lexer: Lexer<BufReader<R>>,
+}
+
+impl<R: Read> WktReader<R> {
+    pub fn new(reader: R) -> Self {
+        WktReader {
+            lexer: Lexer::new(BufReader::new(reader)),
+        }
+    }
+
+    /// Read the next geometry, calling the methods of `visitor` for its parts.
+    ///
+    /// Returns `false`, without calling `visitor`, if there are no more geometries.
+    pub fn read_next<V: GeometryVisitor>(&mut self, visitor: &mut V) -> Result<bool, ParseError> {
+        if self.lexer.peek()? == Token::Eof {
+            return Ok(false);
+        }
+        self.read_geometry(visitor)?;
+        Ok(true)
+    }
+
+    fn read_geometry<V: GeometryVisitor>(&mut self, visitor: &mut V) -> Result<(), ParseError> {
+        let ty = match self.lexer.next_word()?.as_str() {
+            "POINT" => GeometryType::Point,
+            "LINESTRING" => GeometryType::LineString,
+            "POLYGON" => GeometryType::Polygon,
+            "MULTIPOINT" => GeometryType::MultiPoint,
+            "MULTILINESTRING" => GeometryType::MultiLineString,
+            "MULTIPOLYGON" => GeometryType::MultiPolygon,
+            "GEOMETRYCOLLECTION" => GeometryType::GeometryCollection,
+            word => return Err(self.lexer.error(&format!("unknown geometry type {word}"))),
+        };
+        if self.lexer.peek()? == Token::Word && ["Z", "M", "ZM"].contains(&self.lexer.word()) {
+            self.lexer.next()?;
+        }
+
+        visitor.on_geometry_begin(ty);
+        if !self.read_empty()? {
+            match ty {
+                GeometryType::Point => {
+                    self.expect(Token::LeftParen)?;
+                    visitor.on_point(self.read_coord()?);
+                    self.expect(Token::RightParen)?;
+                }
+                GeometryType::LineString => self.read_line_string(visitor)?,
+                GeometryType::Polygon => self.read_polygon(visitor)?,
+                GeometryType::MultiPoint => self.read_list(|reader| {
+                    // The points of a MULTIPOINT may or may not be parenthesized
+                    if reader.read_empty()? {
+                        return Ok(());
+                    }
+                    let parenthesized = reader.lexer.peek()? == Token::LeftParen;
+                    if parenthesized {
+                        reader.lexer.next()?;
+                    }
+                    visitor.on_point(reader.read_coord()?);
+                    if parenthesized {
+                        reader.expect(Token::RightParen)?;
+                    }
+                    Ok(())
+                })?,
+                GeometryType::MultiLineString => {
+                    self.read_list(|reader| reader.read_line_string(visitor))?
+                }
+                GeometryType::MultiPolygon => {
+                    self.read_list(|reader| reader.read_polygon(visitor))?
+                }
+                GeometryType::GeometryCollection => {
+                    self.read_list(|reader| reader.read_geometry(visitor))?
+                }
+            }
+        }
+        visitor.on_geometry_end(ty);
+        Ok(())
+    }
+
+    fn read_line_string<V: GeometryVisitor>(&mut self, visitor: &mut V) -> Result<(), ParseError> {
+        visitor.on_line_string_begin();
+        if !self.read_empty()? {
+            self.read_list(|reader| {
+                visitor.on_line_string_coord(reader.read_coord()?);
+                Ok(())
+            })?;
+        }
+        visitor.on_line_string_end();
+        Ok(())
+    }
+
+    fn read_polygon<V: GeometryVisitor>(&mut self, visitor: &mut V) -> Result<(), ParseError> {
+        visitor.on_polygon_begin();
+        if !self.read_empty()? {
+            let mut index = 0;
+            self.read_list(|reader| {
+                visitor.on_ring_begin(index);
+                reader.read_list(|reader| {
+                    visitor.on_ring_coord(reader.read_coord()?);
+                    Ok(())
+                })?;
+                visitor.on_ring_end();
+                index += 1;
+                Ok(())
+            })?;
+        }
+        visitor.on_polygon_end();
+        Ok(())
+    }
+
+    /// Read a parenthesized, comma-separated list, calling `read_item` to read each item.
+    fn read_list(
+        &mut self,
+        mut read_item: impl FnMut(&mut Self) -> Result<(), ParseError>,
+    ) -> Result<(), ParseError> {
+        self.expect(Token::LeftParen)?;
+        loop {
+            read_item(self)?;
+            match self.lexer.next()? {
+                Token::Comma => {}
+                Token::RightParen => return Ok(()),
+                _ => return Err(self.lexer.error("expected ',' or ')'")),
+            }
+        }
+    }
+
+    /// Read two to four numbers, returning the first two as a coordinate.
+    fn read_coord(&mut self) -> Result<Coord, ParseError> {
+        let x = self.read_number()?;
+        let y = self.read_number()?;
+        for _ in 0..2 {
+            if let Token::Number(_) = self.lexer.peek()? {
+                self.lexer.next()?;
+            }
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn read_number(&mut self) -> Result<f64, ParseError> {
+        match self.lexer.next()? {
+            Token::Number(number) => Ok(number),
+            _ => Err(self.lexer.error("expected a number")),
+        }
+    }
+
+    /// Read `EMPTY`, if it's next.
+    fn read_empty(&mut self) -> Result<bool, ParseError> {
+        if self.lexer.peek()? == Token::Word && self.lexer.word() == "EMPTY" {
+            self.lexer.next()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+        if self.lexer.next()? != expected {
+            return Err(self.lexer.error(&format!("expected {expected:?}")));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    /// A word, whose upper-cased text is [`Lexer::word`].
+    Word,
+    Number(f64),
+    LeftParen,
+    RightParen,
+    Comma,
+    Eof,
+}
+
+struct Lexer<R: BufRead> {
+    reader: R,
+    /// The number of bytes consumed from `reader`.
+    position: usize,
+    /// The text of the last word or number.
+    text: String,
+    peeked: Option<Token>,
+}
+
+impl<R: BufRead> Lexer<R> {
+    fn new(reader: R) -> Self {
+        Lexer {
+            reader,
+            position: 0,
+            text: String::new(),
+            peeked: None,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Token, ParseError> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.lex()?);
+        }
+        Ok(self.peeked.unwrap())
+    }
+
+    fn next(&mut self) -> Result<Token, ParseError> {
+        let token = self.peek()?;
+        self.peeked = None;
+        Ok(token)
+    }
+
+    fn next_word(&mut self) -> Result<String, ParseError> {
+        match self.next()? {
+            Token::Word => Ok(self.text.clone()),
+            _ => Err(self.error("expected a geometry type")),
+        }
+    }
+
+    fn word(&self) -> &str {
+        &self.text
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::InvalidInput(format!("{message} at byte {}", self.position))
+    }
+
+    fn peek_byte(&mut self) -> io::Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn consume_byte(&mut self) {
+        self.reader.consume(1);
+        self.position += 1;
+    }
+
+    /// Consume bytes into `text` while `predicate` holds.
+    fn consume_while(&mut self, predicate: impl Fn(u8) -> bool) -> io::Result<()> {
+        self.text.clear();
+        while let Some(byte) = self.peek_byte()? {
+            if !predicate(byte) {
+                break;
+            }
+            self.text.push(byte.to_ascii_uppercase() as char);
+            self.consume_byte();
+        }
+        Ok(())
+    }
+
+    fn lex(&mut self) -> Result<Token, ParseError> {
+        while let Some(byte) = self.peek_byte()? {
+            if !byte.is_ascii_whitespace() {
+                break;
+            }
+            self.consume_byte();
+        }
+        let token = match self.peek_byte()? {
+            None => Token::Eof,
+            Some(b'(') => Token::LeftParen,
+            Some(b')') => Token::RightParen,
+            Some(b',') => Token::Comma,
+            Some(byte) if byte.is_ascii_alphabetic() => {
+                self.consume_while(|b| b.is_ascii_alphabetic())?;
+                return Ok(Token::Word);
+            }
+            Some(byte) if byte.is_ascii_digit() || b"+-.".contains(&byte) => {
+                self.consume_while(|b| b.is_ascii_digit() || b"+-.eE".contains(&b))?;
+                return match self.text.parse() {
+                    Ok(number) => Ok(Token::Number(number)),
+                    Err(_) => Err(self.error(&format!("invalid number {}", self.text))),
+                };
+            }
+            Some(byte) => {
+                return Err(self.error(&format!("unexpected character {:?}", byte as char)))
+            }
+        };
+        self.consume_byte();
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stream::GeometryBuilder;
+    use crate::Geometry;
+    use wkt::TryFromWkt;
+
+    fn read_all(input: &str) -> Result<Vec<Geometry>, ParseError> {
+        let mut reader = WktReader::new(input.as_bytes());
+        let mut builder = GeometryBuilder::new();
+        let mut geometries = vec![];
+        while reader.read_next(&mut builder)? {
+            geometries.extend(builder.take());
+        }
+        Ok(geometries)
+    }
+
+    #[test]
+    fn matches_wkt_crate() {
+        let inputs = [
+            "POINT (1 2)",
+            "LINESTRING (0 0, 1 1, 2 0.5)",
+            "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT ((0 0), (1 -1e3))",
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3, 4 3))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5), (5.1 5.1, 5.2 5.1, 5.2 5.2, 5.1 5.1)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), GEOMETRYCOLLECTION (LINESTRING (0 0, 1 1)))",
+            "LINESTRING EMPTY",
+            "MULTIPOLYGON EMPTY",
+        ];
+        let geometries = read_all(&inputs.join("\n")).unwrap();
+        assert_eq!(geometries.len(), inputs.len());
+        for (input, geometry) in inputs.iter().zip(geometries) {
+            assert_eq!(
+                geometry,
+                Geometry::try_from_wkt_str(input).unwrap(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            read_all("point z (1 2 3) MultiPoint M (1 2 3, 4 5 6) POINT ZM(1 2 3 4)").unwrap(),
+            read_all("POINT (1 2) MULTIPOINT (1 2, 4 5) POINT (1 2)").unwrap(),
+        );
+        assert_eq!(read_all("POINT EMPTY").unwrap(), vec![]);
+        assert_eq!(read_all("  \n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn errors() {
+        for input in [
+            "POINT (1)",
+            "LINESTRING (0 0, 1 1",
+            "TRIANGLE ((0 0, 1 0, 0 1, 0 0))",
+            "POLYGON ((0 0; 1 1))",
+            "POINT (1 2e)",
+        ] {
+            assert!(
+                matches!(read_all(input), Err(ParseError::InvalidInput(_))),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Geometries which borrow their coordinates, rather than owning them.
+//!
+//! [`LineStringSlice`] and [`PolygonRef`] view coordinates stored elsewhere, such as in an Arrow
+//! array or a memory-mapped file, as geometries, so that algorithms can run over them without
+//! first copying the coordinates into the `Vec`s of a [`LineString`] or [`Polygon`].
+//!
+//! They implement [`Area`](crate::Area), [`BoundingRect`](crate::BoundingRect),
+//! [`Centroid`](crate::Centroid), [`CoordinatePosition`](crate::CoordinatePosition),
+//! [`CoordsIter`](crate::CoordsIter) and [`EuclideanLength`](crate::EuclideanLength), and so
+//! the algorithms built on `CoordsIter`, such as [`ConvexHull`](crate::ConvexHull) and
+//! [`Extremes`](crate::Extremes). A `PolygonRef` also implements [`Contains`](crate::Contains)
+//! and [`Intersects`](crate::Intersects) for coordinates and points.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::view::{LineStringSlice, PolygonRef};
+//! use geo::{coord, Area, Centroid, Contains, Coord, EuclideanLength};
+//!
+//! // Coordinates of a square with a hole, in some externally owned buffer
+//! let buffer: Vec<Coord> = [
+//!     (0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.),
+//!     (1., 1.), (1., 2.), (2., 2.), (2., 1.), (1., 1.),
+//! ]
+//! .map(Coord::from)
+//! .to_vec();
+//!
+//! let exterior = LineStringSlice::new(&buffer[..5]);
+//! assert_eq!(exterior.euclidean_length(), 16.);
+//!
+//! let polygon = PolygonRef::new(exterior, [&buffer[5..]]);
+//! assert_eq!(polygon.unsigned_area(), 15.);
+//! assert!(polygon.contains(&coord! { x: 3., y: 3. }));
+//! assert!(!polygon.contains(&coord! { x: 1.5, y: 1.5 }));
+//! assert_eq!(polygon.centroid(), polygon.to_polygon().centroid());
+//! ```
+
+use crate::{Coord, CoordNum, Line, LineString, Point, Polygon};
+use alloc::borrow::Cow;
+
+/// A [`LineString`] whose coordinates are borrowed from a slice.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub struct LineStringSlice<'a, T: CoordNum = f64>(pub &'a [Coord<T>]);
+
+impl<'a, T: CoordNum> LineStringSlice<'a, T> {
+    /// View `coords` as a `LineString`.
+    pub fn new(coords: &'a [Coord<T>]) -> Self {
+        LineStringSlice(coords)
+    }
+
+    /// Iterate over the coordinates.
+    pub fn coords(&self) -> impl DoubleEndedIterator<Item = &'a Coord<T>> {
+        self.0.iter()
+    }
+
+    /// Iterate over the coordinates, as `Point`s.
+    pub fn points(&self) -> impl DoubleEndedIterator<Item = Point<T>> + 'a {
+        self.0.iter().map(|coord| Point::from(*coord))
+    }
+
+    /// Iterate over the segments between consecutive coordinates, as for
+    /// [`LineString::lines`].
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.0.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// Whether the first and last coordinates are equal, as for [`LineString::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Copy the coordinates into an owned `LineString`.
+    pub fn to_line_string(&self) -> LineString<T> {
+        LineString::new(self.0.to_vec())
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a [Coord<T>]> for LineStringSlice<'a, T> {
+    fn from(coords: &'a [Coord<T>]) -> Self {
+        LineStringSlice(coords)
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a LineString<T>> for LineStringSlice<'a, T> {
+    fn from(line_string: &'a LineString<T>) -> Self {
+        LineStringSlice(&line_string.0)
+    }
+}
+
+/// A [`Polygon`] whose rings are borrowed from slices of coordinates.
+///
+/// Unlike `Polygon::new`, `PolygonRef::new` can't close its rings, so they should already be
+/// closed, with equal first and last coordinates, as they are in formats such as GeoArrow and
+/// WKB. As for a `Polygon` whose rings aren't closed, the results of algorithms are otherwise
+/// unspecified.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct PolygonRef<'a, T: CoordNum = f64> {
+    exterior: LineStringSlice<'a, T>,
+    interiors: Cow<'a, [LineStringSlice<'a, T>]>,
+}
+
+impl<'a, T: CoordNum> PolygonRef<'a, T> {
+    /// View a `Polygon` with the given exterior and interior rings.
+    ///
+    /// Only the slices of the interior rings are collected; their coordinates aren't copied.
+    pub fn new<R>(
+        exterior: impl Into<LineStringSlice<'a, T>>,
+        interiors: impl IntoIterator<Item = R>,
+    ) -> Self
+    where
+        R: Into<LineStringSlice<'a, T>>,
+    {
+        PolygonRef {
+            exterior: exterior.into(),
+            interiors: interiors.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// View a `Polygon` with the given exterior ring, and interior rings borrowed from a slice,
+    /// such as one allocated in an arena.
+    pub fn with_interiors(
+        exterior: impl Into<LineStringSlice<'a, T>>,
+        interiors: &'a [LineStringSlice<'a, T>],
+    ) -> Self {
+        PolygonRef {
+            exterior: exterior.into(),
+            interiors: Cow::Borrowed(interiors),
+        }
+    }
+
+    /// The exterior ring.
+    pub fn exterior(&self) -> LineStringSlice<'a, T> {
+        self.exterior
+    }
+
+    /// The interior rings.
+    pub fn interiors(&self) -> &[LineStringSlice<'a, T>] {
+        &self.interiors
+    }
+
+    /// Copy the coordinates into an owned `Polygon`.
+    pub fn to_polygon(&self) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.to_line_string(),
+            self.interiors
+                .iter()
+                .map(LineStringSlice::to_line_string)
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a Polygon<T>> for PolygonRef<'a, T> {
+    fn from(polygon: &'a Polygon<T>) -> Self {
+        PolygonRef::new(polygon.exterior(), polygon.interiors())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coordinate_position::{CoordPos, CoordinatePosition};
+    use crate::{
+        coord, polygon, Area, BoundingRect, Centroid, ConvexHull, CoordsIter, EuclideanLength,
+        Intersects,
+    };
+
+    #[test]
+    fn line_string_slice_matches_line_string() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let slice = LineStringSlice::from(&line_string);
+        assert_eq!(slice.euclidean_length(), line_string.euclidean_length());
+        assert_eq!(slice.bounding_rect(), line_string.bounding_rect());
+        assert_eq!(slice.centroid(), line_string.centroid());
+        assert_eq!(slice.convex_hull(), line_string.convex_hull());
+        assert_eq!(slice.coords_count(), line_string.coords_count());
+        assert_eq!(slice.lines().len(), line_string.lines().len());
+        assert_eq!(slice.to_line_string(), line_string);
+
+        let coord = line_string.0[10];
+        assert_eq!(
+            slice.coordinate_position(&coord),
+            line_string.coordinate_position(&coord)
+        );
+        let part = LineStringSlice::new(&line_string.0[..20]);
+        assert_eq!(part.coordinate_position(&coord), CoordPos::Inside);
+        assert_eq!(
+            part.coordinate_position(&line_string.0[19]),
+            CoordPos::OnBoundary
+        );
+    }
+
+    #[test]
+    fn polygon_ref_matches_polygon() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 6.), (x: 0., y: 6.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 3., y: 2.), (x: 3., y: 1.)],
+                [(x: 5., y: 1.), (x: 8., y: 4.), (x: 8., y: 1.)],
+            ],
+        ];
+        let view = PolygonRef::from(&polygon);
+        assert_eq!(view.signed_area(), polygon.signed_area());
+        assert_eq!(view.centroid(), polygon.centroid());
+        assert_eq!(view.bounding_rect(), polygon.bounding_rect());
+        assert_eq!(
+            view.coords_iter().collect::<Vec<_>>(),
+            polygon.coords_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(view.to_polygon(), polygon);
+
+        for x in 0..=20 {
+            for y in 0..=12 {
+                let coord = coord! { x: x as f64 / 2., y: y as f64 / 2. };
+                assert_eq!(
+                    view.coordinate_position(&coord),
+                    polygon.coordinate_position(&coord)
+                );
+                assert_eq!(view.intersects(&coord), polygon.intersects(&coord));
+            }
+        }
+    }
+}